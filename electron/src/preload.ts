@@ -334,6 +334,12 @@ const electronAPI = {
   get_status: () => apiCall('get_status'),
   get_disk_space: () => apiCall('get_disk_space'),
   get_system_resources: () => apiCall('get_system_resources'),
+  get_capabilities: () => apiCall('get_capabilities'),
+  get_readiness: () => apiCall('get_readiness'),
+  diagnose_and_repair: () => apiCall('diagnose_and_repair'),
+  run_maintenance: () => apiCall('run_maintenance'),
+  generate_diagnostic_bundle: (dest: string) => apiCall('generate_diagnostic_bundle', { dest }),
+  get_health_summary: () => apiCall('get_health_summary'),
 
   // ========================================
   // Dependencies
@@ -363,6 +369,10 @@ const electronAPI = {
     apiCall('install_version', { tag, app_id: appId }),
   remove_version: (tag: string, appId?: string) =>
     apiCall('remove_version', { tag, app_id: appId }),
+  uninstall_app: (appId: string, keepModels?: boolean) =>
+    apiCall('uninstall_app', { app_id: appId, keep_models: keepModels }),
+  get_reclaimable_size: (tag?: string, appId?: string) =>
+    apiCall('get_reclaimable_size', { tag, app_id: appId }),
   switch_version: (tag: string, appId?: string) =>
     apiCall('switch_version', { tag, app_id: appId }),
   validate_installations: (appId?: string) =>
@@ -405,6 +415,8 @@ const electronAPI = {
   // ========================================
   launch_comfyui: () => apiCall('launch_comfyui'),
   stop_comfyui: () => apiCall('stop_comfyui'),
+  set_native_process_title: (enabled: boolean) =>
+    apiCall('set_native_process_title', { enabled }),
   launch_ollama: () => apiCall('launch_ollama'),
   stop_ollama: () => apiCall('stop_ollama'),
   get_runtime_profiles_snapshot: () =>
@@ -512,10 +524,20 @@ const electronAPI = {
   // ========================================
   // Model Management
   // ========================================
-  get_models: () => apiCall('get_models'),
-  refresh_model_index: () => apiCall('refresh_model_index'),
+  get_models: (includeIncomplete?: boolean) =>
+    apiCall('get_models', { include_incomplete: includeIncomplete }),
+  refresh_model_index: (modelId?: string) =>
+    apiCall('refresh_model_index', modelId ? { model_id: modelId } : {}),
+  start_rebuild_model_index: () => apiCall('start_rebuild_model_index'),
+  get_rebuild_progress: (rebuildId: string) =>
+    apiCall('get_rebuild_progress', { rebuild_id: rebuildId }),
+  cancel_rebuild_model_index: (rebuildId: string) =>
+    apiCall('cancel_rebuild_model_index', { rebuild_id: rebuildId }),
   refresh_model_mappings: (appId?: string) => apiCall('refresh_model_mappings', { app_id: appId }),
-  scan_shared_storage: () => apiCall('scan_shared_storage'),
+  scan_shared_storage: (paths?: string[]) =>
+    apiCall('scan_shared_storage', paths ? { paths } : {}),
+  security_audit: () => apiCall('security_audit'),
+  verify_local_model: (modelId: string) => apiCall('verify_local_model', { model_id: modelId }),
   search_hf_models: (
     query: string,
     kind?: string | null,
@@ -579,6 +601,8 @@ const electronAPI = {
     apiCall('pause_model_download', { download_id: downloadId }),
   resume_model_download: (downloadId: string) =>
     apiCall('resume_model_download', { download_id: downloadId }),
+  reorder_model_download: (downloadId: string, priority: string) =>
+    apiCall('reorder_model_download', { download_id: downloadId, priority }),
   list_model_downloads: () =>
     apiCall('list_model_downloads'),
   list_interrupted_downloads: () =>
@@ -609,6 +633,8 @@ const electronAPI = {
     apiCall('update_inference_settings', { model_id: modelId, settings: inferenceSettings }),
   update_model_notes: (modelId: string, notes?: string | null) =>
     apiCall('update_model_notes', { model_id: modelId, notes }),
+  update_model_tags: (modelId: string, add: string[] = [], remove: string[] = []) =>
+    apiCall('update_model_tags', { model_id: modelId, add, remove }),
 
   // HuggingFace Authentication
   set_hf_token: (token: string) => apiCall('set_hf_token', { token }),
@@ -623,14 +649,18 @@ const electronAPI = {
     limit?: number,
     offset?: number,
     modelType?: string | null,
-    tags?: string[] | null
+    tags?: string[] | null,
+    includeIncomplete?: boolean,
+    options?: Record<string, unknown>
   ) =>
     apiCall('search_models_fts', {
+      ...options,
       query,
       limit,
       offset,
       model_type: modelType,
-      tags,
+      tags: tags ?? (options?.['tags'] as string[] | undefined),
+      include_incomplete: includeIncomplete,
     }),
   import_batch: (importSpecs: Array<Record<string, unknown>>) =>
     apiCall('import_batch', { imports: importSpecs }),
@@ -639,6 +669,8 @@ const electronAPI = {
   classify_model_import_paths: (paths: string[]) =>
     apiCall('classify_model_import_paths', { paths }),
   get_network_status: () => apiCall('get_network_status'),
+  set_global_bandwidth_limit: (bytesPerSec: number | null) =>
+    apiCall('set_global_bandwidth_limit', { bytes_per_sec: bytesPerSec }),
 
   // ========================================
   // Model Import (Phase 2)
@@ -650,6 +682,16 @@ const electronAPI = {
       official_name: officialName,
       repo_id: repoId,
     }),
+  validate_import_spec: (spec: Record<string, unknown>) => apiCall('validate_import_spec', spec),
+  preview_model_path: (modelType: string, family: string, officialName: string) =>
+    apiCall('preview_model_path', {
+      model_type: modelType,
+      family,
+      official_name: officialName,
+    }),
+  start_import: (spec: Record<string, unknown>) => apiCall('start_import', spec),
+  get_import_progress: (importId: string) =>
+    apiCall('get_import_progress', { import_id: importId }),
   lookup_hf_metadata_for_file: (filename: string, filePath?: string | null) =>
     apiCall('lookup_hf_metadata_for_file', { filename, file_path: filePath }),
   lookup_hf_metadata_for_bundle_directory: (directoryPath: string) =>
@@ -659,8 +701,10 @@ const electronAPI = {
   validate_file_type: (filePath: string) => apiCall('validate_file_type', { file_path: filePath }),
   get_library_status: () => apiCall('get_library_status'),
   get_file_link_count: (filePath: string) => apiCall('get_file_link_count', { file_path: filePath }),
-  check_files_writable: (filePaths: string[]) =>
-    apiCall('check_files_writable', { file_paths: filePaths }),
+  get_model_file_link_counts: (modelId: string) =>
+    apiCall('get_file_link_count', { model_id: modelId }),
+  check_files_writable: (filePaths: string[], versionTag?: string) =>
+    apiCall('check_files_writable', { file_paths: filePaths, version_tag: versionTag }),
   get_embedded_metadata: (filePath: string) =>
     apiCall('get_embedded_metadata', { file_path: filePath }),
 
@@ -683,6 +727,13 @@ const electronAPI = {
     apiCall('preview_model_mapping', { version_tag: versionTag }),
   sync_models_incremental: (versionTag: string, modelIds: string[]) =>
     apiCall('sync_models_incremental', { version_tag: versionTag, model_ids: modelIds }),
+  validate_mapping_configs: () => apiCall('validate_mapping_configs'),
+  set_auto_sync: (enabled: boolean, conflictResolution?: string) =>
+    apiCall('set_auto_sync', { enabled, conflict_resolution: conflictResolution }),
+  get_auto_sync: () => apiCall('get_auto_sync'),
+  get_default_link_type: () => apiCall('get_default_link_type'),
+  set_default_link_type: (linkType: string) =>
+    apiCall('set_default_link_type', { link_type: linkType }),
   get_cross_filesystem_warning: (versionTag: string) =>
     apiCall('get_cross_filesystem_warning', { version_tag: versionTag }),
   apply_model_mapping: (versionTag: string) =>
@@ -756,7 +807,10 @@ const electronAPI = {
   get_launcher_version: () => apiCall('get_launcher_version'),
   check_launcher_updates: (forceRefresh?: boolean) =>
     apiCall('check_launcher_updates', { force_refresh: forceRefresh }),
+  preview_launcher_update: () => apiCall('preview_launcher_update'),
   apply_launcher_update: () => apiCall('apply_launcher_update'),
+  stage_launcher_update: () => apiCall('stage_launcher_update'),
+  build_launcher_update: () => apiCall('build_launcher_update'),
   restart_launcher: () => apiCall('restart_launcher'),
 
   // ========================================
@@ -814,7 +868,8 @@ const electronAPI = {
   launch_app: (appId: string, versionTag: string) =>
     launchAppVersion(appId, versionTag),
   stop_app: (appId: string) => stopApp(appId),
-  get_app_status: (appId: string) => apiCall('get_app_status', { app_id: appId }),
+  get_app_status: (appId: string, includeStats?: boolean) =>
+    apiCall('get_app_status', { app_id: appId, include_stats: includeStats }),
 
   // ========================================
   // Window Controls (Electron-specific)
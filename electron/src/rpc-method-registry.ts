@@ -16,15 +16,25 @@ export const RPC_METHOD_REGISTRY = {
     'get_system_resources',
     'get_launcher_version',
     'check_launcher_updates',
+    'preview_launcher_update',
     'apply_launcher_update',
+    'stage_launcher_update',
+    'build_launcher_update',
     'restart_launcher',
     'get_sandbox_info',
     'check_git',
     'check_brave',
     'check_setproctitle',
     'get_network_status',
+    'get_health_summary',
+    'set_global_bandwidth_limit',
     'get_library_status',
     'get_app_status',
+    'get_capabilities',
+    'get_readiness',
+    'diagnose_and_repair',
+    'run_maintenance',
+    'generate_diagnostic_bundle',
     'get_runtime_profiles_snapshot',
     'list_runtime_profile_updates_since',
     'upsert_runtime_profile',
@@ -46,6 +56,8 @@ export const RPC_METHOD_REGISTRY = {
     'switch_version',
     'install_version',
     'remove_version',
+    'uninstall_app',
+    'get_reclaimable_size',
     'cancel_installation',
     'get_installation_progress',
     'validate_installations',
@@ -62,17 +74,28 @@ export const RPC_METHOD_REGISTRY = {
     'install_version_dependencies',
     'get_release_dependencies',
     'is_patched',
+    'get_patch_state',
     'toggle_patch',
+    'restore_patch_backup',
+    'apply_patch_to_all',
     'get_models',
     'refresh_model_index',
+    'start_rebuild_model_index',
+    'get_rebuild_progress',
+    'cancel_rebuild_model_index',
     'refresh_model_mappings',
     'import_model',
+    'validate_import_spec',
+    'preview_model_path',
+    'start_import',
+    'get_import_progress',
     'download_model_from_hf',
     'start_model_download_from_hf',
     'get_model_download_status',
     'cancel_model_download',
     'pause_model_download',
     'resume_model_download',
+    'reorder_model_download',
     'list_model_downloads',
     'list_interrupted_downloads',
     'recover_download',
@@ -101,9 +124,12 @@ export const RPC_METHOD_REGISTRY = {
     'adopt_orphan_models',
     'import_model_in_place',
     'scan_shared_storage',
+    'security_audit',
+    'verify_local_model',
     'get_inference_settings',
     'update_inference_settings',
     'update_model_notes',
+    'update_model_tags',
     'resolve_model_dependency_requirements',
     'audit_dependency_pin_compliance',
     'list_models_needing_review',
@@ -120,6 +146,7 @@ export const RPC_METHOD_REGISTRY = {
     'is_comfyui_running',
     'stop_comfyui',
     'launch_comfyui',
+    'set_native_process_title',
     'launch_ollama',
     'stop_ollama',
     'is_ollama_running',
@@ -146,6 +173,7 @@ export const RPC_METHOD_REGISTRY = {
     'torch_get_status',
     'torch_list_devices',
     'torch_configure',
+    'torch_get_saved_config',
     'get_link_health',
     'clean_broken_links',
     'remove_orphaned_links',
@@ -154,6 +182,11 @@ export const RPC_METHOD_REGISTRY = {
     'preview_model_mapping',
     'apply_model_mapping',
     'sync_models_incremental',
+    'validate_mapping_configs',
+    'set_auto_sync',
+    'get_auto_sync',
+    'get_default_link_type',
+    'set_default_link_type',
     'sync_with_resolutions',
     'get_cross_filesystem_warning',
     'get_file_link_count',
@@ -216,12 +249,19 @@ export const RPC_METHOD_PARAM_VALIDATION = {
   get_status_telemetry_snapshot: 'empty-record',
   get_disk_space: 'empty-record',
   get_system_resources: 'empty-record',
+  get_capabilities: 'empty-record',
+  get_readiness: 'empty-record',
+  diagnose_and_repair: 'empty-record',
+  run_maintenance: 'empty-record',
+  get_health_summary: 'empty-record',
   get_all_shortcut_states: 'empty-record',
   has_background_fetch_completed: 'empty-record',
   reset_background_fetch_flag: 'empty-record',
-  get_models: 'empty-record',
-  refresh_model_index: 'empty-record',
-  scan_shared_storage: 'empty-record',
+  security_audit: 'empty-record',
+  start_rebuild_model_index: 'empty-record',
+  validate_mapping_configs: 'empty-record',
+  get_auto_sync: 'empty-record',
+  get_default_link_type: 'empty-record',
   list_model_downloads: 'empty-record',
   list_interrupted_downloads: 'empty-record',
   get_hf_auth_status: 'empty-record',
@@ -279,6 +319,20 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
   open_active_install: OPTIONAL_APP_ID_SCHEMA,
   install_version: REQUIRED_TAG_OPTIONAL_APP_ID_SCHEMA,
   remove_version: REQUIRED_TAG_OPTIONAL_APP_ID_SCHEMA,
+  uninstall_app: {
+    required: {
+      app_id: 'string',
+    },
+    optional: {
+      keep_models: 'boolean',
+    },
+  },
+  get_reclaimable_size: {
+    optional: {
+      tag: 'string',
+      app_id: 'string',
+    },
+  },
   switch_version: REQUIRED_TAG_OPTIONAL_APP_ID_SCHEMA,
   get_version_info: REQUIRED_TAG_OPTIONAL_APP_ID_SCHEMA,
   check_version_dependencies: REQUIRED_TAG_OPTIONAL_APP_ID_SCHEMA,
@@ -291,6 +345,21 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
       tag: 'string',
     },
   },
+  set_native_process_title: {
+    required: {
+      enabled: 'boolean',
+    },
+  },
+  apply_patch_to_all: {
+    required: {
+      enable: 'boolean',
+    },
+  },
+  set_global_bandwidth_limit: {
+    nullable: {
+      bytes_per_sec: 'number',
+    },
+  },
   get_version_shortcuts: {
     required: {
       tag: 'string',
@@ -307,6 +376,19 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
     },
   },
   get_model_download_status: REQUIRED_DOWNLOAD_ID_SCHEMA,
+  set_default_link_type: {
+    required: {
+      link_type: 'string',
+    },
+  },
+  scan_shared_storage: {
+    optional: { paths: 'string-array' },
+  },
+  refresh_model_index: {
+    optional: {
+      model_id: 'string',
+    },
+  },
   resolve_model_execution_descriptor: {
     required: {
       model_id: 'string',
@@ -317,6 +399,16 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
       model_id: 'string',
     },
   },
+  verify_local_model: {
+    required: {
+      model_id: 'string',
+    },
+  },
+  get_models: {
+    optional: {
+      include_incomplete: 'boolean',
+    },
+  },
   resolve_model_package_facts_summary: {
     required: {
       model_id: 'string',
@@ -444,6 +536,12 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
   cancel_model_download: REQUIRED_DOWNLOAD_ID_SCHEMA,
   pause_model_download: REQUIRED_DOWNLOAD_ID_SCHEMA,
   resume_model_download: REQUIRED_DOWNLOAD_ID_SCHEMA,
+  reorder_model_download: {
+    required: {
+      download_id: 'string',
+      priority: 'string',
+    },
+  },
   recover_download: {
     required: {
       repo_id: 'string',
@@ -489,6 +587,9 @@ export const RPC_METHOD_REQUEST_SCHEMAS = {
     required: {
       app_id: 'string',
     },
+    optional: {
+      include_stats: 'boolean',
+    },
   },
   get_custom_nodes: {
     required: {
@@ -19,8 +19,10 @@ import type {
   HFMetadataLookupResponse,
   ImportPathClassification,
   ImportBatchResponse,
+  ModelFileLinkCountsResponse,
   ModelImportResult,
   ModelImportSpec,
+  SearchModelsOptions,
   NetworkStatusResponse,
 } from '../types/api';
 
@@ -34,17 +36,28 @@ class ImportAPI {
 
   /**
    * Search local model library using FTS5 full-text search.
-   * Provides fast sub-20ms queries for large libraries.
+   * Provides fast sub-20ms queries for large libraries. `options` adds
+   * family/tag filters and sorting.
    */
   async searchModelsFTS(
     query: string,
     limit = 100,
     offset = 0,
     modelType?: string | null,
-    tags?: string[] | null
+    tags?: string[] | null,
+    includeIncomplete?: boolean,
+    options?: SearchModelsOptions
   ): Promise<FTSSearchResponse> {
     const api = this.getAPI();
-    return await api.search_models_fts(query, limit, offset, modelType, tags);
+    return await api.search_models_fts(
+      query,
+      limit,
+      offset,
+      modelType,
+      tags,
+      includeIncomplete,
+      options
+    );
   }
 
   /**
@@ -141,12 +154,24 @@ class ImportAPI {
   }
 
   /**
-   * Check if files can be safely deleted.
-   * Returns writability status for each file.
+   * Get hard link counts for every file in a model.
+   * Used to warn that deleting a model will not free hard-linked space.
    */
-  async checkFilesWritable(filePaths: string[]): Promise<CheckFilesWritableResponse> {
+  async getModelFileLinkCounts(modelId: string): Promise<ModelFileLinkCountsResponse> {
     const api = this.getAPI();
-    return await api.check_files_writable(filePaths);
+    return await api.get_model_file_link_counts(modelId);
+  }
+
+  /**
+   * Check write access before files are modified.
+   * Returns writability status and the reason for each path.
+   */
+  async checkFilesWritable(
+    filePaths: string[],
+    versionTag?: string
+  ): Promise<CheckFilesWritableResponse> {
+    const api = this.getAPI();
+    return await api.check_files_writable(filePaths, versionTag);
   }
 
   /**
@@ -17,6 +17,7 @@ import type {
   PumasModelRef,
   ResolvedModelPackageFacts,
   UpdateModelNotesResponse,
+  UpdateModelTagsResponse,
   UpdateInferenceSettingsResponse,
 } from '../types/api';
 
@@ -28,14 +29,14 @@ class ModelsAPI {
     return api;
   }
 
-  async getModels() {
+  async getModels(includeIncomplete?: boolean) {
     const api = this.getAPI();
-    return await api.get_models();
+    return await api.get_models(includeIncomplete);
   }
 
-  async scanSharedStorage() {
+  async scanSharedStorage(paths?: string[]) {
     const api = this.getAPI();
-    return await api.scan_shared_storage();
+    return await api.scan_shared_storage(paths);
   }
 
   async searchHuggingFace(
@@ -201,6 +202,15 @@ class ModelsAPI {
     const api = this.getAPI();
     return await api.update_model_notes(modelId, notes);
   }
+
+  async updateModelTags(
+    modelId: string,
+    add: string[] = [],
+    remove: string[] = []
+  ): Promise<UpdateModelTagsResponse> {
+    const api = this.getAPI();
+    return await api.update_model_tags(modelId, add, remove);
+  }
 }
 
 export const modelsAPI = new ModelsAPI();
@@ -1,5 +1,20 @@
-import { fireEvent, render, screen } from '@testing-library/react';
+import { fireEvent, render, screen, waitFor, within } from '@testing-library/react';
 import { describe, expect, it, vi } from 'vitest';
+
+const { getReclaimableSizeMock } = vi.hoisted(() => ({
+  getReclaimableSizeMock: vi.fn(),
+}));
+
+vi.mock('../api/adapter', () => ({
+  api: {
+    calculate_release_size: vi.fn().mockResolvedValue({}),
+    cancel_installation: vi.fn().mockResolvedValue({ success: true }),
+    get_installation_progress: vi.fn().mockResolvedValue(null),
+    get_reclaimable_size: getReclaimableSizeMock,
+  },
+  isAPIAvailable: () => true,
+}));
+
 import { InstallDialog } from './InstallDialog';
 import type { VersionRelease } from '../hooks/useVersions';
 
@@ -76,4 +91,39 @@ describe('InstallDialog', () => {
     expect(screen.getByText('0.20.7')).toBeInTheDocument();
     expect(screen.queryByText('0.20.6')).not.toBeInTheDocument();
   });
+
+  it('confirms uninstall with the space the removal frees', async () => {
+    getReclaimableSizeMock.mockResolvedValue({
+      success: true,
+      reclaimable_bytes: 3 * 1024 * 1024,
+      shared_bytes: 2 * 1024 * 1024 * 1024,
+    });
+    const onRemoveVersion = vi.fn().mockResolvedValue(true);
+
+    render(
+      <InstallDialog
+        isOpen={true}
+        onClose={vi.fn()}
+        availableVersions={ollamaPatchReleases.slice(0, 1)}
+        installedVersions={['v0.22.1']}
+        isLoading={false}
+        onInstallVersion={vi.fn().mockResolvedValue(true)}
+        onRefreshAll={vi.fn().mockResolvedValue(undefined)}
+        onRemoveVersion={onRemoveVersion}
+        appId="ollama"
+      />
+    );
+
+    fireEvent.pointerEnter(screen.getByText('0.22.1'));
+    fireEvent.click(screen.getByText('Uninstall'));
+
+    const dialog = await screen.findByRole('alertdialog', { name: 'Uninstall v0.22.1' });
+    expect(getReclaimableSizeMock).toHaveBeenCalledWith('v0.22.1', 'ollama');
+    expect(dialog).toHaveTextContent('free about 3.0 MB');
+    expect(dialog).toHaveTextContent('2.00 GB of hardlinked files stays on disk');
+    expect(onRemoveVersion).not.toHaveBeenCalled();
+
+    fireEvent.click(within(dialog).getByRole('button', { name: 'Uninstall' }));
+    await waitFor(() => expect(onRemoveVersion).toHaveBeenCalledWith('v0.22.1'));
+  });
 });
@@ -28,11 +28,28 @@ import {
   reportCancelError,
   reportInstallationError,
 } from './InstallDialogHelpers';
+import { formatBytes } from '../utils/formatters';
 import { getLogger } from '../utils/logger';
 import { APIError } from '../errors';
 
 const logger = getLogger('InstallDialog');
 
+interface PendingRemoval {
+  tag: string;
+  reclaimableBytes: number | null;
+  sharedBytes: number;
+}
+
+function getRemovalMessage({ tag, reclaimableBytes, sharedBytes }: PendingRemoval): string {
+  if (reclaimableBytes === null) {
+    return `This will delete ${tag} and its installed files.`;
+  }
+  const shared = sharedBytes > 0
+    ? ` ${formatBytes(sharedBytes)} of hardlinked files stays on disk because it is shared elsewhere.`
+    : '';
+  return `This will delete ${tag} and free about ${formatBytes(reclaimableBytes)}.${shared}`;
+}
+
 interface InstallDialogProps {
   isOpen: boolean;
   onClose: () => void;
@@ -81,6 +98,7 @@ export function InstallDialog({
   const [errorVersion, setErrorVersion] = useState<string | null>(null);
   const [errorMessage, setErrorMessage] = useState<string | null>(null);
   const [showCancelConfirmation, setShowCancelConfirmation] = useState(false);
+  const [pendingRemoval, setPendingRemoval] = useState<PendingRemoval | null>(null);
   const cancellationRef = useRef(false);
 
   // Custom hooks
@@ -239,6 +257,35 @@ export function InstallDialog({
     }
   };
 
+  const requestRemoveVersion = async (tag: string) => {
+    let reclaimableBytes: number | null = null;
+    let sharedBytes = 0;
+    try {
+      const estimate = await api.get_reclaimable_size(tag, appId);
+      if (estimate.success && typeof estimate.reclaimable_bytes === 'number') {
+        reclaimableBytes = estimate.reclaimable_bytes;
+        sharedBytes = estimate.shared_bytes ?? 0;
+      }
+    } catch (error) {
+      logger.warn('Failed to estimate reclaimable space', {
+        error: error instanceof Error ? error.message : String(error),
+        tag,
+      });
+    }
+    setPendingRemoval({ tag, reclaimableBytes, sharedBytes });
+  };
+
+  const confirmRemoveVersion = () => {
+    if (!pendingRemoval) {
+      return;
+    }
+    const { tag } = pendingRemoval;
+    setPendingRemoval(null);
+    onRemoveVersion(tag).catch((error: unknown) => {
+      reportRemoveError(tag, error);
+    });
+  };
+
   return (
     <InstallDialogFrame
       isOpen={isOpen}
@@ -267,7 +314,7 @@ export function InstallDialog({
         onCancelInstallation={handleCancelInstallation}
         onOpenLogPath={openLogPath}
         onOpenReleaseLink={openReleaseLink}
-        onRemoveVersion={onRemoveVersion}
+        onRemoveVersion={requestRemoveVersion}
         onSetCancelHoverTag={setCancelHoverTag}
         onSetHoveredTag={setHoveredTag}
         onToggleCompletedItems={() => setShowCompletedItems(!showCompletedItems)}
@@ -286,6 +333,15 @@ export function InstallDialog({
         onCancel={() => setShowCancelConfirmation(false)}
         onConfirm={() => void confirmCancelInstallation()}
       />
+
+      <ConfirmationDialog
+        isOpen={pendingRemoval !== null}
+        title={`Uninstall ${pendingRemoval?.tag ?? ''}`}
+        message={pendingRemoval ? getRemovalMessage(pendingRemoval) : ''}
+        confirmLabel="Uninstall"
+        onCancel={() => setPendingRemoval(null)}
+        onConfirm={confirmRemoveVersion}
+      />
     </InstallDialogFrame>
   );
 }
@@ -1,5 +1,17 @@
-import { render, screen } from '@testing-library/react';
+import { fireEvent, render, screen, waitFor } from '@testing-library/react';
 import { describe, expect, it, vi } from 'vitest';
+
+const { getModelFileLinkCountsMock } = vi.hoisted(() => ({
+  getModelFileLinkCountsMock: vi.fn(),
+}));
+
+vi.mock('../api/adapter', () => ({
+  api: {
+    get_model_file_link_counts: getModelFileLinkCountsMock,
+  },
+  isAPIAvailable: () => true,
+}));
+
 import type { ModelInfo } from '../types/apps';
 import type { LocalModelRowState } from './LocalModelRowState';
 import { LocalModelInstalledActions } from './LocalModelInstalledActions';
@@ -79,6 +91,33 @@ describe('LocalModelInstalledActions', () => {
     expect(button.querySelector('.download-progress-ring.is-retained')).not.toBeNull();
   });
 
+  it('warns on delete hover when hard links keep space in use', async () => {
+    getModelFileLinkCountsMock.mockResolvedValue({
+      success: true,
+      model_id: 'model-1',
+      files: [{ path: 'model.gguf', size: 2 * 1024 ** 3, link_count: 2 }],
+      shared_bytes: 2 * 1024 ** 3,
+    });
+    render(
+      <LocalModelInstalledActions
+        model={createModel()}
+        rowState={createRowState()}
+        selectedAppId="comfyui"
+        onDeleteModel={vi.fn()}
+        onToggleLink={vi.fn()}
+      />
+    );
+
+    fireEvent.pointerEnter(screen.getByRole('button', { name: /hold to delete/i }));
+
+    await waitFor(() => {
+      expect(screen.getByRole('tooltip')).toHaveTextContent(
+        'Hold to delete (2.00 GB stays on disk via hard links)'
+      );
+    });
+    expect(getModelFileLinkCountsMock).toHaveBeenCalledWith('model-1');
+  });
+
   it('renders a serve action for installed models', () => {
     render(
       <LocalModelInstalledActions
@@ -1,9 +1,15 @@
+import { useCallback, useState } from 'react';
 import { ArrowRightLeft, Download, Link2, Play, Square } from 'lucide-react';
+import { api } from '../api/adapter';
 import type { ModelInfo } from '../types/apps';
 import type { ServedModelStatus } from '../types/api-serving';
 import { LocalModelDownloadProgressRing } from './LocalModelDownloadProgressRing';
 import { HoldToDeleteButton, IconButton } from './ui';
 import type { LocalModelRowState } from './LocalModelRowState';
+import { formatSize } from '../utils/modelFormatters';
+import { getLogger } from '../utils/logger';
+
+const logger = getLogger('LocalModelInstalledActions');
 
 interface LocalModelInstalledActionsProps {
   model: ModelInfo;
@@ -24,6 +30,13 @@ function getConvertTooltip(model: ModelInfo): string {
   return 'Convert / Re-quantize';
 }
 
+function getDeleteTooltip(sharedBytes: number | null): string {
+  if (!sharedBytes) {
+    return 'Hold to delete';
+  }
+  return `Hold to delete (${formatSize(sharedBytes)} stays on disk via hard links)`;
+}
+
 function RecoverPartialDownloadIcon({ rowState }: { rowState: LocalModelRowState }) {
   return (
     <>
@@ -47,6 +60,25 @@ export function LocalModelInstalledActions({
   onToggleLink,
 }: LocalModelInstalledActionsProps) {
   const showRetainedDownloadIndicator = rowState.hasRetainedProgressRing;
+  const [sharedBytes, setSharedBytes] = useState<number | null>(null);
+
+  const loadSharedBytes = useCallback(() => {
+    if (sharedBytes !== null) {
+      return;
+    }
+    api.get_model_file_link_counts(model.id)
+      .then((response) => {
+        if (response.success) {
+          setSharedBytes(response.shared_bytes);
+        }
+      })
+      .catch((error: unknown) => {
+        logger.warn('Failed to load hard link counts', {
+          error: error instanceof Error ? error.message : String(error),
+          modelId: model.id,
+        });
+      });
+  }, [model.id, sharedBytes]);
 
   return (
     <>
@@ -110,7 +142,11 @@ export function LocalModelInstalledActions({
         />
       )}
       {onDeleteModel && (
-        <HoldToDeleteButton onDelete={() => onDeleteModel(model.id)} />
+        <HoldToDeleteButton
+          onDelete={() => onDeleteModel(model.id)}
+          onHoverStart={loadSharedBytes}
+          tooltip={getDeleteTooltip(sharedBytes)}
+        />
       )}
     </>
   );
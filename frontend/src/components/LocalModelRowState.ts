@@ -147,6 +147,7 @@ export function getLocalModelRowState({
 }): LocalModelRowState {
   const isPartialDownload = Boolean(model.isPartialDownload);
   const isDownloading = Boolean(model.isDownloading);
+  const isNotReady = model.readiness !== undefined && model.readiness !== 'ready';
   const relatedState = relatedModelsById[model.id];
   const download = getDownloadCapabilities({
     canPauseDownload,
@@ -167,10 +168,16 @@ export function getLocalModelRowState({
     canPause: download.canPause,
     canRecoverPartial: partial.canRecoverPartial,
     canResume: download.canResume,
-    canShowRelated: Boolean(model.relatedAvailable) && !isDownloading && !isPartialDownload,
+    canShowRelated:
+      Boolean(model.relatedAvailable) && !isDownloading && !isPartialDownload && !isNotReady,
     hasRetainedProgressRing: download.hasRetainedProgressRing,
     isActiveDownload: download.isActiveDownload,
-    isConvertible: !isDownloading && !isPartialDownload && Boolean(model.primaryFormat) && canConvertModel,
+    isConvertible:
+      !isDownloading &&
+      !isPartialDownload &&
+      !isNotReady &&
+      Boolean(model.primaryFormat) &&
+      canConvertModel,
     isDownloading,
     isExpanded: expandedRelated.has(model.id),
     isLinked: !excludedModels.has(model.id),
@@ -15,12 +15,15 @@ interface HoldToDeleteButtonProps {
   onDelete: () => void | Promise<void>;
   disabled?: boolean;
   tooltip?: string;
+  /** Called when the pointer enters, so callers can load tooltip details lazily */
+  onHoverStart?: () => void;
 }
 
 export const HoldToDeleteButton: React.FC<HoldToDeleteButtonProps> = ({
   onDelete,
   disabled = false,
   tooltip = 'Hold to delete',
+  onHoverStart,
 }) => {
   const [isHolding, setIsHolding] = useState(false);
   const [holdProgress, setHoldProgress] = useState(0);
@@ -30,7 +33,10 @@ export const HoldToDeleteButton: React.FC<HoldToDeleteButtonProps> = ({
   const startTimeRef = useRef<number>(0);
   const [isHovered, setIsHovered] = useState(false);
   const { hoverProps } = useHover({
-    onHoverStart: () => setIsHovered(true),
+    onHoverStart: () => {
+      setIsHovered(true);
+      onHoverStart?.();
+    },
     onHoverEnd: () => setIsHovered(false),
     isDisabled: disabled || isDeleting,
   });
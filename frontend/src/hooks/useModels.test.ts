@@ -481,7 +481,14 @@ describe('useModels', () => {
     });
 
     expect(searchModelsFTSMock).toHaveBeenCalledTimes(2);
-    expect(searchModelsFTSMock).toHaveBeenLastCalledWith('qwen', 100, 0, 'checkpoint', ['tag-a']);
+    expect(searchModelsFTSMock).toHaveBeenLastCalledWith(
+      'qwen',
+      100,
+      0,
+      'checkpoint',
+      ['tag-a'],
+      true
+    );
     expect(getModelsMock).toHaveBeenCalledTimes(1);
     expect(result.current.modelGroups).toEqual(grouped(['refreshed-search']));
   });
@@ -60,7 +60,9 @@ export function useModels() {
     const currentSequence = ++fetchSequenceRef.current;
 
     try {
-      const result = await modelsAPI.getModels();
+      // The local list renders download progress and partial-download
+      // recovery, so it asks for models that are not ready yet.
+      const result = await modelsAPI.getModels(true);
       if (currentSequence !== fetchSequenceRef.current || activeSearchRef.current) {
         logger.debug('Discarding stale model list response', {
           currentSequence,
@@ -191,7 +193,7 @@ export function useModels() {
       // Debounce the search (revalidation happens in background)
       searchTimeoutRef.current = setTimeout(async () => {
         try {
-          const result = await importAPI.searchModelsFTS(query, 100, 0, modelType, tags);
+          const result = await importAPI.searchModelsFTS(query, 100, 0, modelType, tags, true);
 
           // Sequence guard: discard stale responses
           if (currentSequence !== searchSequenceRef.current) {
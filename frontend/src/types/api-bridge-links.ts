@@ -12,18 +12,23 @@ import type {
   DeleteModelCascadeResponse,
   GetLinksForModelResponse,
   LinkHealthResponse,
+  LinkType,
   RemoveOrphanedLinksResponse,
 } from './api-links';
 import type {
   ApplyModelMappingResponse,
+  AutoSyncResponse,
+  ConflictResolutionAction,
   ConflictResolutions,
   CrossFilesystemWarningResponse,
+  DefaultLinkTypeResponse,
   DeleteModelMigrationReportResponse,
   ExecuteModelMigrationResponse,
   GenerateModelMigrationDryRunReportResponse,
   IncrementalSyncResponse,
   LinkExclusionsResponse,
   ListModelMigrationReportsResponse,
+  MappingConfigValidationResponse,
   MappingPreviewResponse,
   PruneModelMigrationReportsResponse,
   SandboxInfoResponse,
@@ -75,6 +80,34 @@ export interface DesktopBridgeLinkMappingAPI {
     modelIds: string[]
   ): Promise<IncrementalSyncResponse>;
 
+  /**
+   * Validate mapping-config files, reporting problems with file and field
+   */
+  validate_mapping_configs(): Promise<MappingConfigValidationResponse>;
+
+  /**
+   * Link newly detected library models into the active version automatically
+   */
+  set_auto_sync(
+    enabled: boolean,
+    conflictResolution?: ConflictResolutionAction
+  ): Promise<AutoSyncResponse>;
+
+  /**
+   * Get the current watch-and-sync target (null when disabled)
+   */
+  get_auto_sync(): Promise<AutoSyncResponse>;
+
+  /**
+   * Get the link type mapping tries first
+   */
+  get_default_link_type(): Promise<DefaultLinkTypeResponse>;
+
+  /**
+   * Persist the link type mapping tries first, checked against the active version
+   */
+  set_default_link_type(linkType: LinkType): Promise<DefaultLinkTypeResponse>;
+
   /**
    * Check if library and app version are on different filesystems
    */
@@ -1,5 +1,7 @@
 import type { BaseResponse } from './api-common';
 import type {
+  BandwidthLimitResponse,
+  CancelRebuildModelIndexResponse,
   CheckFilesWritableResponse,
   DetectShardedSetsResponse,
   EmbeddedMetadataResponse,
@@ -11,13 +13,25 @@ import type {
   HFMetadataLookupResponse,
   ImportBatchResponse,
   ImportPathClassification,
+  ImportProgressResponse,
+  ImportSpecValidationResponse,
   LibraryModelMetadataResponse,
   ModelExecutionDescriptor,
+  ModelFileLinkCountsResponse,
   ModelImportResult,
   ModelImportSpec,
+  ModelPathPreviewResponse,
   NetworkStatusResponse,
+  RebuildProgressResponse,
+  SearchModelsOptions,
+  SecurityAuditResponse,
+  StartImportResponse,
+  StartRebuildModelIndexResponse,
+  TrackedImportParams,
+  VerifyLocalModelResponse,
 } from './api-import';
 import type {
+  DownloadPriority,
   GetHFDownloadDetailsResponse,
   HfAuthStatusResponse,
   InferenceParamSchema,
@@ -34,6 +48,7 @@ import type {
   SearchHFModelsResponse,
   UpdateInferenceSettingsResponse,
   UpdateModelNotesResponse,
+  UpdateModelTagsResponse,
 } from './api-models';
 import type {
   ModelLibraryUpdateFeed,
@@ -47,8 +62,12 @@ export interface DesktopBridgeModelAPI {
   // ========================================
   // Model Management
   // ========================================
-  get_models(): Promise<ModelsResponse>;
-  scan_shared_storage(): Promise<ScanSharedStorageResponse>;
+  /**
+   * List library models keyed by ID. Models that are still downloading,
+   * importing, or failed are left out unless `includeIncomplete` is set.
+   */
+  get_models(includeIncomplete?: boolean): Promise<ModelsResponse>;
+  scan_shared_storage(paths?: string[]): Promise<ScanSharedStorageResponse>;
   search_hf_models(
     query: string,
     kind?: string | null,
@@ -86,6 +105,7 @@ export interface DesktopBridgeModelAPI {
   cancel_model_download(downloadId: string): Promise<BaseResponse>;
   pause_model_download(downloadId: string): Promise<BaseResponse>;
   resume_model_download(downloadId: string): Promise<BaseResponse>;
+  reorder_model_download(downloadId: string, priority: DownloadPriority): Promise<BaseResponse>;
   list_model_downloads(): Promise<ListModelDownloadsResponse>;
   list_interrupted_downloads(): Promise<ListInterruptedDownloadsResponse>;
   recover_download(repoId: string, destDir: string): Promise<RecoverDownloadResponse>;
@@ -120,6 +140,15 @@ export interface DesktopBridgeModelAPI {
     notes?: string | null
   ): Promise<UpdateModelNotesResponse>;
 
+  /**
+   * Add and remove model tags (case-insensitive); resolves to the resulting tags.
+   */
+  update_model_tags(
+    modelId: string,
+    add?: string[],
+    remove?: string[]
+  ): Promise<UpdateModelTagsResponse>;
+
   /**
    * Get metadata for a library model (both stored and embedded)
    */
@@ -175,14 +204,17 @@ export interface DesktopBridgeModelAPI {
   // Model Library Import (Phase 1A - Part 6)
   // ========================================
   /**
-   * Search local model library using FTS5 full-text search
+   * Search local model library using FTS5 full-text search. Models that are
+   * not ready are left out unless `includeIncomplete` is set.
    */
   search_models_fts(
     query: string,
     limit?: number,
     offset?: number,
     modelType?: string | null,
-    tags?: string[] | null
+    tags?: string[] | null,
+    includeIncomplete?: boolean,
+    options?: SearchModelsOptions
   ): Promise<FTSSearchResponse>;
 
   /**
@@ -190,6 +222,46 @@ export interface DesktopBridgeModelAPI {
    */
   import_batch(importSpecs: ModelImportSpec[]): Promise<ImportBatchResponse>;
 
+  /**
+   * Dry-run an import: destination, detected type, conflicts, and blocking errors, without writing
+   */
+  validate_import_spec(spec: TrackedImportParams): Promise<ImportSpecValidationResponse>;
+
+  /**
+   * Show where a download or import would save the model and whether that path is taken
+   */
+  preview_model_path(
+    modelType: string,
+    family: string,
+    officialName: string
+  ): Promise<ModelPathPreviewResponse>;
+
+  /**
+   * Start importing one model in the background; poll get_import_progress with the returned ID
+   */
+  start_import(spec: TrackedImportParams): Promise<StartImportResponse>;
+
+  /**
+   * Get stage and copied-byte progress for an import started with start_import
+   */
+  get_import_progress(importId: string): Promise<ImportProgressResponse>;
+
+  /**
+   * Start a metadata-only index rebuild in the background; poll get_rebuild_progress with the
+   * returned ID
+   */
+  start_rebuild_model_index(): Promise<StartRebuildModelIndexResponse>;
+
+  /**
+   * Get walked/total model counts for a rebuild started with start_rebuild_model_index
+   */
+  get_rebuild_progress(rebuildId: string): Promise<RebuildProgressResponse>;
+
+  /**
+   * Cancel a running rebuild; the index is left unchanged unless writes had already begun
+   */
+  cancel_rebuild_model_index(rebuildId: string): Promise<CancelRebuildModelIndexResponse>;
+
   /**
    * Register an external diffusers directory without copying its contents.
    */
@@ -207,6 +279,11 @@ export interface DesktopBridgeModelAPI {
    */
   get_network_status(): Promise<NetworkStatusResponse>;
 
+  /**
+   * Cap combined download throughput (bytes/sec); null or 0 removes the cap
+   */
+  set_global_bandwidth_limit(bytesPerSec: number | null): Promise<BandwidthLimitResponse>;
+
   // ========================================
   // Model Import (Phase 2)
   // ========================================
@@ -235,6 +312,16 @@ export interface DesktopBridgeModelAPI {
    */
   validate_file_type(filePath: string): Promise<FileTypeValidationResponse>;
 
+  /**
+   * Report each model's security tier, flagging pickle and unidentified formats
+   */
+  security_audit(): Promise<SecurityAuditResponse>;
+
+  /**
+   * Re-hash one model's files against its metadata
+   */
+  verify_local_model(modelId: string): Promise<VerifyLocalModelResponse>;
+
   /**
    * Get embedded metadata from a model file (GGUF or safetensors)
    */
@@ -249,9 +336,13 @@ export interface DesktopBridgeModelAPI {
    * Get number of hard links for a file (NTFS detection)
    */
   get_file_link_count(filePath: string): Promise<FileLinkCountResponse>;
+  get_model_file_link_counts(modelId: string): Promise<ModelFileLinkCountsResponse>;
 
   /**
-   * Check if files can be safely deleted
+   * Check write access for files, plus the version's models directory when given
    */
-  check_files_writable(filePaths: string[]): Promise<CheckFilesWritableResponse>;
+  check_files_writable(
+    filePaths: string[],
+    versionTag?: string
+  ): Promise<CheckFilesWritableResponse>;
 }
@@ -3,6 +3,7 @@ import type {
   GetAllShortcutStatesResponse,
   GetVersionShortcutsResponse,
   LaunchResponse,
+  NativeProcessTitleResponse,
   OllamaCreateModelResponse,
   OllamaListModelsResponse,
   OllamaListRunningResponse,
@@ -21,7 +22,13 @@ import type {
   TorchUnloadModelResponse,
 } from './api-processes';
 import type {
+  CapabilitiesResponse,
+  DatabaseMaintenanceResponse,
+  DiagnosticBundleResponse,
   DiskSpaceResponse,
+  EnvironmentRepairResponse,
+  HealthSummaryResponse,
+  ReadinessResponse,
   StatusResponse,
   StatusTelemetrySnapshot,
   SystemResourcesResponse,
@@ -52,8 +59,10 @@ import type {
   GetInstalledVersionsResponse,
   GetVersionInfoResponse,
   InstallationProgressResponse,
+  ReclaimableSizeResponse,
   ResetBackgroundFetchFlagResponse,
   SetDefaultVersionResponse,
+  UninstallAppResponse,
   ValidateInstallationsResponse,
   VersionActionResponse,
   VersionStatusResponse,
@@ -67,6 +76,13 @@ export interface DesktopBridgeRuntimeAPI {
   get_disk_space(): Promise<DiskSpaceResponse>;
   get_system_resources(): Promise<SystemResourcesResponse>;
   get_status_telemetry_snapshot(): Promise<StatusTelemetrySnapshot>;
+  get_capabilities(): Promise<CapabilitiesResponse>;
+  get_readiness(): Promise<ReadinessResponse>;
+  diagnose_and_repair(): Promise<EnvironmentRepairResponse>;
+  run_maintenance(): Promise<DatabaseMaintenanceResponse>;
+  /** Write a redacted diagnostics zip to `dest` (a file path or existing directory). */
+  generate_diagnostic_bundle(dest: string): Promise<DiagnosticBundleResponse>;
+  get_health_summary(): Promise<HealthSummaryResponse>;
 
   // ========================================
   // Dependencies
@@ -95,6 +111,8 @@ export interface DesktopBridgeRuntimeAPI {
   get_active_version(appId?: string): Promise<GetActiveVersionResponse>;
   install_version(tag: string, appId?: string): Promise<VersionActionResponse>;
   remove_version(tag: string, appId?: string): Promise<VersionActionResponse>;
+  uninstall_app(appId: string, keepModels?: boolean): Promise<UninstallAppResponse>;
+  get_reclaimable_size(tag?: string, appId?: string): Promise<ReclaimableSizeResponse>;
   switch_version(tag: string, appId?: string): Promise<VersionActionResponse>;
   validate_installations(appId?: string): Promise<ValidateInstallationsResponse>;
   get_version_info(tag: string, appId?: string): Promise<GetVersionInfoResponse>;
@@ -123,6 +141,7 @@ export interface DesktopBridgeRuntimeAPI {
   // ========================================
   launch_comfyui(): Promise<LaunchResponse>;
   stop_comfyui(): Promise<StopComfyUIResponse>;
+  set_native_process_title(enabled: boolean): Promise<NativeProcessTitleResponse>;
   launch_ollama(): Promise<LaunchResponse>;
   stop_ollama(): Promise<StopOllamaResponse>;
 
@@ -11,6 +11,7 @@ import type {
   CheckLauncherUpdatesResponse,
   LaunchResponse,
   LauncherVersionResponse,
+  PreviewLauncherUpdateResponse,
   RestartLauncherResponse,
 } from './api-processes';
 import type {
@@ -61,7 +62,10 @@ export interface DesktopBridgeUtilityAPI {
   // ========================================
   get_launcher_version(): Promise<LauncherVersionResponse>;
   check_launcher_updates(forceRefresh?: boolean): Promise<CheckLauncherUpdatesResponse>;
+  preview_launcher_update(): Promise<PreviewLauncherUpdateResponse>;
   apply_launcher_update(): Promise<ApplyLauncherUpdateResponse>;
+  stage_launcher_update(): Promise<ApplyLauncherUpdateResponse>;
+  build_launcher_update(): Promise<ApplyLauncherUpdateResponse>;
   restart_launcher(): Promise<RestartLauncherResponse>;
 
   // ========================================
@@ -104,5 +108,5 @@ export interface DesktopBridgeUtilityAPI {
   stop_app(appId: string): Promise<BaseResponse>;
 
   /** Get the status of an app */
-  get_app_status(appId: string): Promise<AppStatusResponse>;
+  get_app_status(appId: string, includeStats?: boolean): Promise<AppStatusResponse>;
 }
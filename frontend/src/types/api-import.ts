@@ -62,6 +62,10 @@ export interface ModelImportSpec {
   subtype?: string;
   tags?: string[];
   security_acknowledged?: boolean;
+  /** Link the file into the library instead of copying it. */
+  by_reference?: boolean;
+  /** How to store a primary file whose content is already in the library. */
+  dedup?: DedupStrategy;
 }
 
 export interface ExternalDiffusersImportSpec {
@@ -123,6 +127,8 @@ export interface ImportPathClassification {
 
 export type StorageKind = 'library_owned' | 'external_reference';
 
+export type DedupStrategy = 'copy' | 'hardlink' | 'reflink' | 'auto';
+
 export type BundleFormat = 'diffusers_directory';
 
 export type ImportState = 'pending' | 'ready' | 'failed';
@@ -177,6 +183,133 @@ export interface ModelExecutionDescriptor {
   dependency_resolution?: Record<string, unknown> | null;
 }
 
+export type ImportStage =
+  | 'copying'
+  | 'hashing'
+  | 'writingmetadata'
+  | 'indexing'
+  | 'syncing'
+  | 'complete';
+
+/**
+ * Destination a download or import would use (nothing is created)
+ */
+export interface ModelPathPreview {
+  model_id: string;
+  model_path: string;
+  /** True when a directory already exists at the destination */
+  exists: boolean;
+}
+
+export interface ModelPathPreviewResponse extends BaseResponse {
+  preview: ModelPathPreview;
+}
+
+/**
+ * Parameters for start_import (same as import_model)
+ */
+export interface TrackedImportParams {
+  local_path: string;
+  family: string;
+  official_name: string;
+  repo_id?: string;
+  model_type?: string;
+  subtype?: string;
+  security_acknowledged?: boolean;
+  by_reference?: boolean;
+  dedup?: DedupStrategy;
+}
+
+/**
+ * What import_model would do with a spec, from a dry run that writes nothing
+ */
+export interface ImportSpecValidation {
+  path: string;
+  /** True when nothing blocks the import */
+  valid: boolean;
+  destination?: string | null;
+  cleaned_name?: string | null;
+  model_type?: string | null;
+  family?: string | null;
+  file_format?: string | null;
+  security_tier?: string | null;
+  /** True when the destination already holds this model */
+  already_exists?: boolean;
+  /** Existing library directories already using the normalized name */
+  conflicts: string[];
+  /** Problems that would make the import fail */
+  errors: string[];
+}
+
+export interface ImportSpecValidationResponse extends BaseResponse {
+  report: ImportSpecValidation;
+}
+
+/**
+ * Final result of a tracked import
+ */
+export interface ImportJobResult {
+  path: string;
+  success: boolean;
+  model_id?: string | null;
+  model_path?: string | null;
+  error?: string | null;
+  security_tier?: string | null;
+}
+
+/**
+ * Progress of an import started with start_import
+ */
+export interface ImportJobProgress {
+  import_id: string;
+  source_path: string;
+  stage: ImportStage;
+  bytes_done: number;
+  bytes_total: number;
+  model_path: string | null;
+  /** Final result, null while the import is running */
+  result: ImportJobResult | null;
+}
+
+export interface StartImportResponse extends BaseResponse {
+  import_id: string;
+}
+
+export interface ImportProgressResponse extends BaseResponse {
+  /** Null when the import ID is unknown */
+  progress: ImportJobProgress | null;
+}
+
+/**
+ * Progress of an index rebuild started with start_rebuild_model_index
+ */
+export interface IndexRebuildJob {
+  rebuild_id: string;
+  state: 'running' | 'completed' | 'cancelled' | 'failed';
+  progress: {
+    current: number;
+    total: number;
+    current_model_id: string | null;
+  };
+  /** Indexed model count once the rebuild has completed */
+  model_count: number | null;
+  error: string | null;
+}
+
+export interface StartRebuildModelIndexResponse extends BaseResponse {
+  rebuild_id: string;
+}
+
+export interface RebuildProgressResponse extends BaseResponse {
+  /** Null when the rebuild ID is unknown */
+  progress: IndexRebuildJob | null;
+}
+
+export interface CancelRebuildModelIndexResponse extends BaseResponse {
+  /** False when the rebuild is unknown or already finished */
+  cancelled: boolean;
+}
+
 /**
  * Batch import response
  */
@@ -202,6 +335,26 @@ export interface NetworkStatusResponse extends BaseResponse {
   is_offline: boolean;
 }
 
+/**
+ * Global download bandwidth cap after an update
+ */
+export interface BandwidthLimitResponse extends BaseResponse {
+  /** Bytes per second shared by all downloads; null when unlimited */
+  bytes_per_sec: number | null;
+}
+
+/**
+ * Extra filters and ordering for local model search. The query text may also
+ * carry `type:`, `family:`, `tag:`, and `size>4gb`-style field filters.
+ */
+export interface SearchModelsOptions {
+  model_types?: string[];
+  family?: string | null;
+  tags?: string[];
+  sort_by?: 'relevance' | 'name' | 'updated_at' | 'size' | null;
+  sort_dir?: 'asc' | 'desc' | null;
+}
+
 /**
  * FTS5 search response for local model library
  */
@@ -228,6 +381,25 @@ export interface FileLinkCountResponse extends BaseResponse {
   is_hard_linked: boolean;
 }
 
+/**
+ * Hardlink count for one file in a model directory
+ */
+export interface ModelFileLinkCount {
+  path: string;
+  size: number;
+  link_count: number;
+}
+
+/**
+ * Per-file hardlink counts for a model.
+ * `shared_bytes` stays on disk through other hardlinks after the model is deleted.
+ */
+export interface ModelFileLinkCountsResponse extends BaseResponse {
+  model_id: string;
+  files: ModelFileLinkCount[];
+  shared_bytes: number;
+}
+
 /**
  * HuggingFace metadata lookup result (Phase 2 - Model Import)
  */
@@ -288,6 +460,58 @@ export interface FileTypeValidationResponse extends BaseResponse {
   error?: string;
 }
 
+/**
+ * How a security audit entry's tier was determined
+ */
+export type SecurityTierSource = 'metadata' | 'sniffed' | 'no_model_file';
+
+export interface SecurityAuditEntry {
+  model_id: string;
+  security_tier: SecurityTier;
+  tier_source: SecurityTierSource;
+  primary_file: string | null;
+  security_acknowledged: boolean;
+  flagged: boolean;
+}
+
+export interface SecurityAuditReport {
+  generated_at: string;
+  total_models_scanned: number;
+  safe_count: number;
+  pickle_count: number;
+  unknown_count: number;
+  flagged_count: number;
+  entries: SecurityAuditEntry[];
+}
+
+export interface SecurityAuditResponse extends BaseResponse {
+  report: SecurityAuditReport;
+}
+
+/**
+ * Outcome of re-hashing one model file against its metadata
+ */
+export type FileVerifyStatus = 'ok' | 'mismatch' | 'missing' | 'extra' | 'unverified';
+
+export interface FileVerifyEntry {
+  name: string;
+  status: FileVerifyStatus;
+  size: number | null;
+  sha256: string | null;
+  blake3: string | null;
+  message: string | null;
+}
+
+export interface ModelVerifyResult {
+  model_id: string;
+  verified: boolean;
+  files: FileVerifyEntry[];
+}
+
+export interface VerifyLocalModelResponse extends BaseResponse {
+  result: ModelVerifyResult;
+}
+
 /**
  * Embedded metadata response (GGUF or safetensors)
  */
@@ -319,6 +543,8 @@ export interface CheckFilesWritableResponse extends BaseResponse {
   details: Array<{
     path: string;
     writable: boolean;
-    reason?: string;
+    reason?: 'permissions' | 'readonly_file' | 'readonly_mount' | 'not_found' | 'other';
+    /** Underlying error message when not writable */
+    message?: string;
   }>;
 }
@@ -1,4 +1,5 @@
 import type { BaseResponse } from './api-common';
+import type { LinkType } from './api-links';
 
 // ============================================================================
 
@@ -41,9 +42,30 @@ export interface MappingPreviewResponse extends BaseResponse {
  * Incremental sync response
  */
 export interface IncrementalSyncResponse extends BaseResponse {
-  links_created: number;
-  links_updated: number;
-  links_skipped: number;
+  /** Links created (same as links_added) */
+  synced: number;
+  links_added: number;
+  /** Registered links deleted because the mapping no longer wants them */
+  links_removed: number;
+  links_unchanged: number;
+  errors: string[];
+}
+
+/**
+ * Problem found in a mapping config file
+ */
+export interface MappingConfigIssue {
+  file: string;
+  /** Offending field, e.g. "mappings[1].target_dir" */
+  field: string | null;
+  message: string;
+}
+
+export interface MappingConfigValidationResponse extends BaseResponse {
+  report: {
+    files_checked: number;
+    issues: MappingConfigIssue[];
+  };
 }
 
 /**
@@ -51,6 +73,35 @@ export interface IncrementalSyncResponse extends BaseResponse {
  */
 export type ConflictResolutionAction = 'skip' | 'overwrite' | 'rename';
 
+/**
+ * Watch-and-sync target: new library models are linked into this version
+ */
+export interface AutoSyncTarget {
+  version_tag: string;
+  models_path: string;
+  conflict_resolution: ConflictResolutionAction;
+}
+
+export interface AutoSyncResponse extends BaseResponse {
+  /** null when watch-and-sync is disabled */
+  auto_sync: AutoSyncTarget | null;
+}
+
+/**
+ * Whether a link type can be used for a version's models directory
+ */
+export interface LinkTypeCheck {
+  link_type: LinkType;
+  feasible: boolean;
+  reason?: string;
+}
+
+export interface DefaultLinkTypeResponse extends BaseResponse {
+  link_type: LinkType;
+  /** Check against the active version; absent from get, null without an active version */
+  check?: LinkTypeCheck | null;
+}
+
 /**
  * Conflict resolutions map
  */
@@ -106,6 +157,10 @@ export interface CrossFilesystemWarningResponse extends BaseResponse {
   app_path?: string;
   warning?: string;
   recommendation?: string;
+  /** Link type mapping tries first */
+  default_link_type?: LinkType;
+  /** Why the default link type cannot be used for this version, if it cannot */
+  link_type_warning?: string;
 }
 
 /**
@@ -4,6 +4,9 @@ import type { BaseResponse } from './api-common';
 // Model Types
 // ============================================================================
 
+/** Download/import pipeline state projected into indexed metadata. */
+export type ModelReadiness = 'downloading' | 'importing' | 'ready' | 'error';
+
 export interface ModelRecordMetadata {
   family?: string;
   added_date?: string;
@@ -18,6 +21,7 @@ export interface ModelRecordMetadata {
     blake3?: string | null;
   }>;
   repo_id?: string;
+  readiness?: ModelReadiness;
   download_incomplete?: boolean;
   download_has_part_files?: boolean;
   download_missing_expected_files?: number;
@@ -128,6 +132,11 @@ export interface UpdateModelNotesResponse extends BaseResponse {
   notes?: string | null;
 }
 
+export interface UpdateModelTagsResponse extends BaseResponse {
+  model_id: string;
+  tags: string[];
+}
+
 export interface SearchHFModelsResponse extends BaseResponse {
   models: HuggingFaceModel[];
 }
@@ -154,6 +163,9 @@ export interface ModelDownloadResponse extends BaseResponse {
   model_path?: string;
 }
 
+/** Queue priority of a HuggingFace download. */
+export type DownloadPriority = 'high' | 'normal' | 'low';
+
 export interface ModelDownloadStatusResponse extends BaseResponse {
   downloadId?: string;
   repoId?: string;
@@ -171,6 +183,12 @@ export interface ModelDownloadStatusResponse extends BaseResponse {
   retryLimit?: number;
   retrying?: boolean;
   nextRetryDelaySeconds?: number;
+  /** 1-based place in the download queue while queued behind other downloads. */
+  queuePosition?: number | null;
+  /** True when the download stopped because no bytes arrived within the stall timeout. */
+  stalled?: boolean;
+  /** Repo files left out by the request's include/exclude patterns. */
+  skippedFiles?: string[];
   error?: string;
 }
 
@@ -219,9 +237,24 @@ export interface ResumePartialDownloadResponse extends BaseResponse {
   reason_code?: string;
 }
 
+export interface SharedStorageCandidate {
+  path: string;
+  size_bytes: number;
+  model_type: string;
+  family?: string | null;
+  security_tier: 'safe' | 'unknown' | 'pickle';
+  shard_files: string[];
+}
+
 export interface ScanSharedStorageResponse extends BaseResponse {
   result: {
     modelsFound?: number;
+    /** Present when external paths were scanned. */
+    scanned_paths?: string[];
+    files_scanned?: number;
+    already_in_library?: number;
+    candidates?: SharedStorageCandidate[];
+    errors?: string[];
     [key: string]: unknown;
   };
 }
@@ -60,4 +60,6 @@ export interface AppStatusResponse extends BaseResponse {
   pid?: number;
   port?: number;
   uptime_secs?: number;
+  /** Live stats from the plugin's `stats` endpoint; null when unavailable */
+  stats?: unknown;
 }
@@ -5,12 +5,20 @@ import type { BaseResponse } from './api-common';
 export interface LaunchResponse extends BaseResponse {
   log_path?: string;
   ready?: boolean;
+  preloaded?: TorchPreloadResult[];
 }
 
 export interface StopComfyUIResponse extends BaseResponse {
   // Empty body on success
 }
 
+export interface NativeProcessTitleResponse extends BaseResponse {
+  /** Whether ComfyUI launches will be titled without the main.py patch. */
+  enabled: boolean;
+  /** Whether this platform can title processes at spawn. */
+  supported: boolean;
+}
+
 export interface StopOllamaResponse extends BaseResponse {
   // Empty body on success
 }
@@ -59,6 +67,7 @@ export interface TorchModelSlot {
   gpu_memory_bytes?: number;
   ram_memory_bytes?: number;
   model_type?: string;
+  last_used_at?: number;
 }
 
 export interface TorchDeviceInfo {
@@ -74,6 +83,15 @@ export interface TorchServerConfig {
   host: string;
   max_loaded_models: number;
   lan_access: boolean;
+  auto_evict: boolean;
+  preload_models: string[];
+}
+
+export interface TorchPreloadResult {
+  model_path: string;
+  success: boolean;
+  slot?: TorchModelSlot | null;
+  error?: string | null;
 }
 
 export interface TorchListSlotsResponse extends BaseResponse {
@@ -82,6 +100,7 @@ export interface TorchListSlotsResponse extends BaseResponse {
 
 export interface TorchLoadModelResponse extends BaseResponse {
   slot?: TorchModelSlot;
+  evicted?: TorchModelSlot | null;
 }
 
 export interface TorchUnloadModelResponse extends BaseResponse {}
@@ -158,11 +177,45 @@ export interface CheckLauncherUpdatesResponse extends BaseResponse {
     author: string;
     date: string;
   }>;
+  preview?: LauncherUpdatePreview;
+}
+
+export interface LauncherChangedFile {
+  path: string;
+  status: string;
+}
+
+export interface LauncherUpdatePreview {
+  currentCommit: string;
+  targetCommit: string;
+  branch: string;
+  commits: Array<{
+    hash: string;
+    message: string;
+    author: string;
+    date: string;
+  }>;
+  changedFiles: LauncherChangedFile[];
+}
+
+export interface PreviewLauncherUpdateResponse
+  extends BaseResponse,
+    Partial<LauncherUpdatePreview> {}
+
+export type LauncherUpdatePhase = 'stage' | 'build';
+
+export interface LauncherUpdatePhaseResult {
+  phase: LauncherUpdatePhase;
+  success: boolean;
+  message?: string;
+  error?: string;
 }
 
 export interface ApplyLauncherUpdateResponse extends BaseResponse {
   message: string;
   newCommit?: string;
+  previousCommit?: string;
+  phases: LauncherUpdatePhaseResult[];
 }
 
 export interface RestartLauncherResponse extends BaseResponse {
@@ -12,6 +12,87 @@ export interface DiskSpaceResponse extends BaseResponse {
   percent: number;
 }
 
+export interface CapabilitiesResponse extends BaseResponse {
+  version: string;
+  methods: string[];
+  version_managers: string[];
+  /** App ids whose version manager failed to initialize, mapped to the error. */
+  unavailable_version_managers: Record<string, string | null>;
+  features: {
+    hf_client: boolean;
+    conversion_environment_ready: boolean;
+    /** False when the library filesystem rejects symlinks; mapping then uses hardlinks or copies. */
+    symlinks: boolean;
+  };
+}
+
+export interface StartupSubsystemStatus {
+  name: string;
+  ok: boolean;
+  error?: string;
+}
+
+export interface ReadinessResponse extends BaseResponse {
+  ready: boolean;
+  subsystems: StartupSubsystemStatus[];
+  failed: string[];
+}
+
+export interface EnvironmentIssue {
+  check: string;
+  message: string;
+  path?: string;
+}
+
+export interface EnvironmentRepairResponse extends BaseResponse {
+  /** Issues repaired automatically. */
+  fixed: EnvironmentIssue[];
+  /** Issues that need manual action. */
+  needs_action: EnvironmentIssue[];
+}
+
+export interface DatabaseMaintenanceResult {
+  name: string;
+  path: string;
+  size_before: number;
+  size_after: number;
+  reclaimed_bytes: number;
+  error?: string;
+}
+
+export interface DatabaseMaintenanceResponse extends BaseResponse {
+  databases: DatabaseMaintenanceResult[];
+  total_reclaimed_bytes: number;
+}
+
+export interface HealthCheckFailure {
+  check: string;
+  error: string;
+}
+
+export interface HealthSummaryResponse extends BaseResponse {
+  network: NetworkStatusResponse;
+  hf_authenticated: boolean;
+  hf_username?: string;
+  conversion_environment_ready: boolean;
+  disk_space?: DiskSpaceResponse;
+  /** Built-in apps with a running process. */
+  running_apps: string[];
+  /** False while filesystem changes await reconciliation into the index. */
+  index_consistent: boolean;
+  pending_downloads: number;
+  /** Checks that could not run; their fields hold defaults. */
+  failed_checks: HealthCheckFailure[];
+}
+
+export interface DiagnosticBundleResponse extends BaseResponse {
+  /** Zip file that was written. */
+  path: string;
+  entries: string[];
+  /** Sections left out because they could not be collected. */
+  failed_sections: HealthCheckFailure[];
+}
+
 export interface StatusResponse extends BaseResponse {
   version: string;
   deps_ready: boolean;
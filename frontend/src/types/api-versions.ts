@@ -44,6 +44,31 @@ export interface VersionActionResponse extends BaseResponse {
   // Used for install, remove, switch operations
 }
 
+export interface UninstallAppResponse extends BaseResponse {
+  app_id?: string;
+  removed_versions?: string[];
+  /** Versions whose desktop or menu shortcuts were removed */
+  shortcuts_removed?: number;
+  links_removed?: number;
+  /** Models deleted because no other app linked to them (only when keep_models is false) */
+  deleted_models?: string[];
+  pid_file_removed?: boolean;
+  errors?: string[];
+}
+
+export interface ReclaimableSizeResponse extends BaseResponse {
+  app_id?: string;
+  tag?: string | null;
+  /** Versions included in the estimate */
+  versions?: string[];
+  total_bytes?: number;
+  /** Bytes in files hardlinked from elsewhere, which removal does not free */
+  shared_bytes?: number;
+  reclaimable_bytes?: number;
+  reclaimable_formatted?: string;
+  file_count?: number;
+}
+
 export interface ValidateInstallationsResponse extends BaseResponse {
   result: {
     had_invalid: boolean;
@@ -76,6 +101,9 @@ export interface VersionStatusResponse extends BaseResponse {
     installedCount: number;
     activeVersion: string | null;
     defaultVersion?: string | null;
+    /** True when a release newer than every installed version exists */
+    updateAvailable?: boolean;
+    latestVersion?: string | null;
     versions: {
       [tag: string]: {
         isActive: boolean;
@@ -34,6 +34,8 @@ export interface ModelInfo {
   linkedApps?: string[]; // App IDs this model is linked to
   relatedAvailable?: boolean;
   isPartialDownload?: boolean;
+  /** Pipeline readiness; anything other than `ready` is not usable yet. */
+  readiness?: 'downloading' | 'importing' | 'ready' | 'error';
   isDownloading?: boolean;
   downloadProgress?: number;
   downloadStatus?: 'queued' | 'downloading' | 'pausing' | 'paused' | 'cancelling' | 'error';
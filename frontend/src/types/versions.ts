@@ -22,6 +22,9 @@ export interface VersionStatus {
   installedCount: number;
   activeVersion: string | null;
   defaultVersion?: string | null;
+  /** True when a release newer than every installed version exists */
+  updateAvailable?: boolean;
+  latestVersion?: string | null;
   versions: {
     [tag: string]: {
       isActive: boolean;
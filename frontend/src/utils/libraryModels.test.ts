@@ -47,6 +47,15 @@ describe('mapModelRecordToInfo', () => {
     expect(info.primaryFormat).toBe('gguf');
   });
 
+  it('maps projected readiness and ignores unknown values', () => {
+    expect(
+      mapModelRecordToInfo(makeModelRecord({ metadata: { readiness: 'importing' } })).readiness
+    ).toBe('importing');
+    expect(
+      mapModelRecordToInfo(makeModelRecord({ metadata: { readiness: 'bogus' as never } })).readiness
+    ).toBeUndefined();
+  });
+
   it('keeps non-convertible formats out of the convert action field', () => {
     const info = mapModelRecordToInfo(
       makeModelRecord({
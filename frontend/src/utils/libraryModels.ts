@@ -31,6 +31,13 @@ function getIntegrityIssueMessage(metadata: ModelRecordMetadata): string | undef
   return `Duplicate repo entries detected (${count} paths). Run library reconciliation.`;
 }
 
+function getReadiness(value: unknown): ModelInfo['readiness'] {
+  if (value === 'downloading' || value === 'importing' || value === 'ready' || value === 'error') {
+    return value;
+  }
+  return undefined;
+}
+
 function getConvertibleFormat(format?: string): ModelInfo['primaryFormat'] {
   if (format === 'gguf' || format === 'safetensors') {
     return format;
@@ -57,6 +64,7 @@ export function mapModelRecordToInfo(model: ModelRecord): ModelInfo {
     date: asString(metadata.added_date),
     relatedAvailable: asBoolean(metadata.related_available) ?? false,
     isPartialDownload: asBoolean(metadata.download_incomplete) ?? false,
+    readiness: getReadiness(metadata.readiness),
     repoId: asString(metadata.repo_id),
     selectedArtifactId: asString(metadata.selected_artifact_id),
     selectedArtifactFiles: asStringArray(metadata.selected_artifact_files),
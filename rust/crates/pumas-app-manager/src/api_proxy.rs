@@ -3,15 +3,35 @@
 //! Enables config-driven API calls to app backends without app-specific code.
 //! Reads endpoint definitions from plugin configs and forwards requests.
 
+use pumas_library::network::CircuitBreaker;
 use pumas_library::plugins::{ApiEndpoint, PluginConfig, PluginLoader};
 use pumas_library::{PumasError, Result};
 use reqwest::Client;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 use tracing::debug;
 
+/// Name of the plugin endpoint polled for live stats.
+const STATS_ENDPOINT: &str = "stats";
+
+/// Circuit breakers for stats polling, keyed by app ID.
+///
+/// Proxies are created per request, so breakers live for the whole process.
+static STATS_BREAKERS: OnceLock<Mutex<HashMap<String, Arc<CircuitBreaker>>>> = OnceLock::new();
+
+fn stats_breaker(app_id: &str) -> Arc<CircuitBreaker> {
+    let breakers = STATS_BREAKERS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut breakers = breakers
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    breakers
+        .entry(app_id.to_string())
+        .or_insert_with(|| Arc::new(CircuitBreaker::new(format!("plugin-stats:{}", app_id))))
+        .clone()
+}
+
 /// Generic API proxy for calling plugin-defined endpoints.
 ///
 /// Reads endpoint configurations from plugins and makes HTTP requests
@@ -165,7 +185,37 @@ impl PluginApiProxy {
 
     /// Get stats from an app using its stats endpoint config.
     pub async fn get_stats(&self, app_id: &str) -> Result<Value> {
-        self.call_endpoint(app_id, "stats", HashMap::new()).await
+        self.call_endpoint(app_id, STATS_ENDPOINT, HashMap::new())
+            .await
+    }
+
+    /// Poll stats for a running app, for inclusion in status responses.
+    ///
+    /// Returns `None` when the plugin defines no stats endpoint or connection,
+    /// the request fails, or the app's circuit breaker is open.
+    pub async fn poll_stats(&self, app_id: &str) -> Option<Value> {
+        let plugin = self.plugin_loader.get(app_id)?;
+        if plugin.connection.is_none() || !plugin.api.contains_key(STATS_ENDPOINT) {
+            return None;
+        }
+
+        let breaker = stats_breaker(app_id);
+        if !breaker.allow_request() {
+            debug!("Skipping stats poll for {}: circuit open", app_id);
+            return None;
+        }
+
+        match self.get_stats(app_id).await {
+            Ok(stats) => {
+                breaker.record_success();
+                Some(stats)
+            }
+            Err(e) => {
+                breaker.record_failure();
+                debug!("Stats poll for {} failed: {}", app_id, e);
+                None
+            }
+        }
     }
 
     /// List models from an app.
@@ -342,6 +392,44 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[tokio::test]
+    async fn poll_stats_skips_unreachable_and_undeclared_apps() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("unreachable.json"),
+            r#"{
+              "id": "unreachable",
+              "displayName": "Unreachable",
+              "installationType": "binary",
+              "connection": {"defaultPort": 9},
+              "api": {"stats": {"endpoint": "/stats"}}
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("no-stats.json"),
+            r#"{
+              "id": "no-stats",
+              "displayName": "No Stats",
+              "installationType": "binary",
+              "connection": {"defaultPort": 9}
+            }"#,
+        )
+        .unwrap();
+
+        let loader = Arc::new(PluginLoader::new(temp.path()).unwrap());
+        let proxy = PluginApiProxy::new(loader)
+            .unwrap()
+            .with_timeout(Duration::from_millis(200));
+
+        assert!(proxy.poll_stats("unreachable").await.is_none());
+        assert_eq!(stats_breaker("unreachable").stats().total_failures, 1);
+
+        assert!(proxy.poll_stats("no-stats").await.is_none());
+        assert_eq!(stats_breaker("no-stats").stats().total_failures, 0);
+        assert!(proxy.poll_stats("missing").await.is_none());
+    }
+
     #[test]
     fn test_interpolate_body() {
         let plugin_loader = Arc::new(PluginLoader::new("/tmp/test-plugins").unwrap());
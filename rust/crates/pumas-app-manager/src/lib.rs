@@ -25,10 +25,13 @@ pub use ollama_client::{
 };
 pub use process::{AppProcessManager, ProcessHandle, ProcessManagerFactory, ProcessStatus};
 pub use torch_client::{
-    ComputeDevice, DeviceInfo, ModelSlot, SlotState, TorchClient, TorchServerConfig,
-    TorchServerStatus,
+    ComputeDevice, DeviceInfo, ModelSlot, SlotState, TorchClient, TorchLoadResult,
+    TorchPreloadResult, TorchServerConfig, TorchServerStatus,
+};
+pub use version_manager::{
+    InstallActivateResult, NewerVersionCheck, ReclaimEstimate, ReleaseSize, SizeBreakdown,
+    SizeCalculator, VersionManager,
 };
-pub use version_manager::{ReleaseSize, SizeBreakdown, SizeCalculator, VersionManager};
 
 // Re-export pumas-core types that are commonly needed with app manager
 pub use pumas_library::config::AppId;
@@ -105,22 +105,27 @@ pub struct OllamaHttpClients {
 
 impl OllamaHttpClients {
     pub fn new() -> Result<Self> {
-        let client = reqwest::Client::builder()
+        Self::with_http_settings(&pumas_library::network::HttpSettings::default())
+    }
+
+    /// Build the clients with a `PumasApi` instance's proxy, CA, and user-agent settings.
+    pub fn with_http_settings(http: &pumas_library::network::HttpSettings) -> Result<Self> {
+        let client = http
+            .client_builder()
             .timeout(API_TIMEOUT)
-            .user_agent("pumas-library")
             .build()
             .map_err(|err| net_err(format!("failed to build Ollama API client: {err}")))?;
 
-        let upload_client = reqwest::Client::builder()
+        let upload_client = http
+            .client_builder()
             .connect_timeout(Duration::from_secs(10))
             // No total timeout -- large blob uploads can take a while.
-            .user_agent("pumas-library")
             .build()
             .map_err(|err| net_err(format!("failed to build Ollama upload client: {err}")))?;
 
-        let create_client = reqwest::Client::builder()
+        let create_client = http
+            .client_builder()
             .timeout(CREATE_TIMEOUT)
-            .user_agent("pumas-library")
             .build()
             .map_err(|err| net_err(format!("failed to build Ollama create client: {err}")))?;
 
@@ -1,8 +1,9 @@
 //! Process manager factory for creating app-specific managers.
 
+use super::plugin::PluginProcessManager;
 use super::traits::AppProcessManager;
 use pumas_library::plugins::{InstallationType, PluginConfig, PluginLoader};
-use pumas_library::{PumasError, Result};
+use pumas_library::{AppId, PumasError, Result};
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
@@ -47,22 +48,30 @@ impl ProcessManagerFactory {
 
         // Get plugin config
         let plugin = self.plugin_loader.get(app_id)?;
+        let app = self.plugin_loader.app_id(app_id)?;
 
-        // Create manager based on installation type
+        // A plugin launch command takes precedence over the installation-type default
         let manager: Arc<dyn AppProcessManager> = match plugin.installation_type {
+            InstallationType::InProcess => return None,
+            _ if plugin.launch.is_some() => Arc::new(PluginProcessManager::new(
+                self.launcher_root.clone(),
+                plugin.clone(),
+                app,
+            )),
             InstallationType::Binary => Arc::new(BinaryProcessManager::new(
                 self.launcher_root.clone(),
                 plugin.clone(),
+                app,
             )),
             InstallationType::PythonVenv => Arc::new(PythonProcessManager::new(
                 self.launcher_root.clone(),
                 plugin.clone(),
+                app,
             )),
             InstallationType::Docker => {
                 // Docker support can be added later
                 return None;
             }
-            InstallationType::InProcess => return None,
         };
 
         // Cache the manager
@@ -88,19 +97,20 @@ impl ProcessManagerFactory {
 struct BinaryProcessManager {
     launcher_root: PathBuf,
     plugin: PluginConfig,
+    app: AppId,
 }
 
 impl BinaryProcessManager {
-    fn new(launcher_root: PathBuf, plugin: PluginConfig) -> Self {
+    fn new(launcher_root: PathBuf, plugin: PluginConfig, app: AppId) -> Self {
         Self {
             launcher_root,
             plugin,
+            app,
         }
     }
 
     fn versions_dir(&self) -> PathBuf {
-        self.launcher_root
-            .join(format!("{}-versions", self.plugin.id))
+        self.launcher_root.join(self.app.versions_dir_name())
     }
 
     fn binary_name(&self) -> String {
@@ -266,19 +276,20 @@ impl AppProcessManager for BinaryProcessManager {
 struct PythonProcessManager {
     launcher_root: PathBuf,
     plugin: PluginConfig,
+    app: AppId,
 }
 
 impl PythonProcessManager {
-    fn new(launcher_root: PathBuf, plugin: PluginConfig) -> Self {
+    fn new(launcher_root: PathBuf, plugin: PluginConfig, app: AppId) -> Self {
         Self {
             launcher_root,
             plugin,
+            app,
         }
     }
 
     fn versions_dir(&self) -> PathBuf {
-        self.launcher_root
-            .join(format!("{}-versions", self.plugin.id))
+        self.launcher_root.join(self.app.versions_dir_name())
     }
 }
 
@@ -439,7 +450,7 @@ impl AppProcessManager for PythonProcessManager {
     }
 }
 
-async fn path_exists(path: &Path) -> Result<bool> {
+pub(super) async fn path_exists(path: &Path) -> Result<bool> {
     fs::try_exists(path).await.map_err(|e| PumasError::Io {
         message: format!("Failed to check path existence: {}", e),
         path: Some(path.to_path_buf()),
@@ -480,13 +491,15 @@ mod tests {
             version_filter: None,
             model_compatibility: None,
             python_config: None,
+            launch: None,
             api: Default::default(),
             panel_layout: vec![],
             sidebar_priority: 100,
             enabled_by_default: true,
         };
 
-        let manager = BinaryProcessManager::new(temp.path().to_path_buf(), plugin);
+        let app = plugin.app_id().unwrap();
+        let manager = BinaryProcessManager::new(temp.path().to_path_buf(), plugin, app);
         let path = manager.version_path("v1.0.0");
         assert!(path.ends_with("test-app-versions/v1.0.0"));
     }
@@ -512,4 +525,27 @@ mod tests {
         assert!(factory.get_manager("onnx-runtime").is_none());
         assert!(!factory.has_manager("onnx-runtime"));
     }
+
+    #[test]
+    fn docker_plugins_with_launch_command_get_a_manager() {
+        let temp = TempDir::new().unwrap();
+        let plugins_dir = temp.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+        std::fs::write(
+            plugins_dir.join("text-gen.json"),
+            r#"{
+              "id": "text-gen",
+              "displayName": "Text Gen",
+              "installationType": "docker",
+              "launch": {"command": "run.sh"}
+            }"#,
+        )
+        .unwrap();
+
+        let loader = Arc::new(PluginLoader::new(&plugins_dir).unwrap());
+        let factory = ProcessManagerFactory::new(temp.path().to_path_buf(), loader);
+
+        let manager = factory.get_manager("text-gen").unwrap();
+        assert_eq!(manager.app_id(), "text-gen");
+    }
 }
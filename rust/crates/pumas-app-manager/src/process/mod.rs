@@ -5,6 +5,7 @@
 //! through a common interface.
 
 mod factory;
+mod plugin;
 mod traits;
 
 pub use factory::ProcessManagerFactory;
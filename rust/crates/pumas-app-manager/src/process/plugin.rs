@@ -0,0 +1,273 @@
+//! Process manager for apps launched from their plugin's `launch` command.
+
+use super::factory::path_exists;
+use super::traits::{AppProcessManager, ProcessHandle, ProcessStatus};
+use pumas_library::plugins::{PluginConfig, PluginLaunchConfig};
+use pumas_library::process::{BinaryLaunchConfig, ProcessLauncher};
+use pumas_library::{platform, AppId, PumasError, Result};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::fs;
+
+/// Grace period before a stopped process is force-killed.
+const STOP_TIMEOUT_MS: u64 = 5000;
+
+/// Process manager driven entirely by plugin configuration.
+pub(super) struct PluginProcessManager {
+    launcher_root: PathBuf,
+    plugin: PluginConfig,
+    app: AppId,
+}
+
+impl PluginProcessManager {
+    pub(super) fn new(launcher_root: PathBuf, plugin: PluginConfig, app: AppId) -> Self {
+        Self {
+            launcher_root,
+            plugin,
+            app,
+        }
+    }
+
+    fn versions_dir(&self) -> PathBuf {
+        self.launcher_root.join(self.app.versions_dir_name())
+    }
+
+    fn pid_file(&self) -> PathBuf {
+        self.launcher_root.join(format!("{}.pid", self.plugin.id))
+    }
+
+    fn log_file(&self) -> PathBuf {
+        self.launcher_root
+            .join("launcher-data")
+            .join("logs")
+            .join(format!("{}.log", self.plugin.id))
+    }
+
+    fn port(&self) -> Option<u16> {
+        self.plugin.connection.as_ref().map(|c| c.default_port)
+    }
+
+    fn health_check_url(&self) -> Option<String> {
+        let conn = self.plugin.connection.as_ref()?;
+        let health = conn.health_endpoint.as_deref()?;
+        Some(format!(
+            "{}://127.0.0.1:{}{}",
+            conn.protocol, conn.default_port, health
+        ))
+    }
+
+    /// Build the launch config for an installed version.
+    fn launch_config(&self, launch: &PluginLaunchConfig, version_tag: &str) -> BinaryLaunchConfig {
+        let version_dir = self.version_path(version_tag);
+        let port = self.port();
+
+        BinaryLaunchConfig {
+            tag: version_tag.to_string(),
+            binary_path: version_dir.join(&launch.command),
+            command: None,
+            extra_args: launch
+                .args
+                .iter()
+                .map(|arg| launch.expand(arg, &version_dir, port))
+                .collect(),
+            env_vars: launch
+                .env
+                .iter()
+                .map(|(key, value)| (key.clone(), launch.expand(value, &version_dir, port)))
+                .collect(),
+            pid_file: self.pid_file(),
+            log_file: Some(self.log_file()),
+            ready_timeout: Duration::from_secs(launch.ready_timeout_secs),
+            health_check_url: self.health_check_url(),
+            version_dir,
+        }
+    }
+
+    async fn read_pid(&self) -> Option<u32> {
+        let pid_str = fs::read_to_string(self.pid_file()).await.ok()?;
+        pid_str.trim().parse().ok()
+    }
+
+    async fn is_healthy(&self) -> bool {
+        let Some(url) = self.health_check_url() else {
+            return true;
+        };
+        match reqwest::Client::new()
+            .get(&url)
+            .timeout(Duration::from_secs(2))
+            .send()
+            .await
+        {
+            Ok(resp) => resp.status().is_success(),
+            Err(_) => false,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AppProcessManager for PluginProcessManager {
+    fn app_id(&self) -> &str {
+        &self.plugin.id
+    }
+
+    async fn launch(&self, version_tag: &str) -> Result<ProcessHandle> {
+        let Some(launch) = self.plugin.launch.as_ref() else {
+            return Ok(ProcessHandle {
+                success: false,
+                log_file: None,
+                error: Some(format!("Plugin {} has no launch command", self.plugin.id)),
+                ready: false,
+            });
+        };
+
+        if !self.is_version_installed(version_tag).await {
+            return Ok(ProcessHandle {
+                success: false,
+                log_file: None,
+                error: Some(format!("Version not installed: {}", version_tag)),
+                ready: false,
+            });
+        }
+
+        let config = self.launch_config(launch, version_tag);
+        let result = tokio::task::spawn_blocking(move || ProcessLauncher::launch_binary(&config))
+            .await
+            .map_err(|e| PumasError::LaunchFailed {
+                app: self.plugin.id.clone(),
+                message: format!("Task join error: {}", e),
+            })??;
+
+        // The process is detached; it is tracked through the PID file from here on.
+        Ok(ProcessHandle {
+            success: result.success,
+            log_file: result.log_path,
+            error: result.error,
+            ready: result.ready,
+        })
+    }
+
+    async fn stop(&self) -> Result<bool> {
+        let Some(pid) = self.read_pid().await else {
+            return Ok(false);
+        };
+
+        let stopped = tokio::task::spawn_blocking(move || {
+            ProcessLauncher::stop_process(pid, STOP_TIMEOUT_MS)
+        })
+        .await
+        .map_err(|e| PumasError::Other(format!("Task join error: {}", e)))??;
+        ProcessLauncher::remove_pid_file(&self.pid_file())?;
+        Ok(stopped)
+    }
+
+    async fn is_running(&self) -> bool {
+        self.read_pid()
+            .await
+            .is_some_and(platform::is_process_alive)
+    }
+
+    async fn get_status(&self) -> Option<ProcessStatus> {
+        let pid = self
+            .read_pid()
+            .await
+            .filter(|pid| platform::is_process_alive(*pid))?;
+
+        Some(ProcessStatus {
+            pid: Some(pid),
+            port: self.port(),
+            healthy: self.is_healthy().await,
+            ..Default::default()
+        })
+    }
+
+    async fn get_logs(&self, lines: usize) -> Vec<String> {
+        if let Ok(content) = fs::read_to_string(self.log_file()).await {
+            content
+                .lines()
+                .rev()
+                .take(lines)
+                .map(String::from)
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    fn version_path(&self, version_tag: &str) -> PathBuf {
+        self.versions_dir().join(version_tag)
+    }
+
+    async fn is_version_installed(&self, version_tag: &str) -> bool {
+        let Some(launch) = self.plugin.launch.as_ref() else {
+            return false;
+        };
+        let command_path = self.version_path(version_tag).join(&launch.command);
+        path_exists(&command_path).await.unwrap_or(false)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use pumas_library::plugins::PluginLoader;
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn launches_plugin_command_with_log_and_pid_file() {
+        let temp = TempDir::new().unwrap();
+        let plugins_dir = temp.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+        std::fs::write(
+            plugins_dir.join("echo-server.json"),
+            r#"{
+              "id": "echo-server",
+              "displayName": "Echo Server",
+              "installationType": "binary",
+              "launch": {
+                "command": "run.sh",
+                "args": ["{version_dir}"]
+              }
+            }"#,
+        )
+        .unwrap();
+
+        let version_dir = temp.path().join("echo-server-versions").join("v1.0.0");
+        std::fs::create_dir_all(&version_dir).unwrap();
+        let script = version_dir.join("run.sh");
+        std::fs::write(&script, "#!/bin/sh\necho \"serving $1\"\nexec sleep 30\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let loader = Arc::new(PluginLoader::new(&plugins_dir).unwrap());
+        let factory = super::super::ProcessManagerFactory::new(temp.path().to_path_buf(), loader);
+        let manager = factory.get_manager("echo-server").unwrap();
+
+        assert!(manager.is_version_installed("v1.0.0").await);
+        assert!(!manager.is_version_installed("v2.0.0").await);
+
+        let handle = manager.launch("v1.0.0").await.unwrap();
+        assert!(handle.success, "{:?}", handle.error);
+        assert!(handle.ready);
+        assert!(manager.is_running().await);
+        assert!(temp.path().join("echo-server.pid").exists());
+
+        let status = manager.get_status().await.unwrap();
+        assert!(status.pid.is_some());
+
+        let expected = format!("serving {}", version_dir.display());
+        let mut logged = false;
+        for _ in 0..50 {
+            if manager.get_logs(10).await.contains(&expected) {
+                logged = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert!(logged);
+
+        assert!(manager.stop().await.unwrap());
+        assert!(!manager.is_running().await);
+        assert!(!temp.path().join("echo-server.pid").exists());
+    }
+}
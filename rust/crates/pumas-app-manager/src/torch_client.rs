@@ -13,13 +13,19 @@
 //! - `/api/status`  — Server status with resource usage
 //! - `/api/devices` — Available compute devices
 //! - `/api/configure` — Update server configuration
+//!
+//! Configuration applied through [`TorchClient::configure`] is persisted under
+//! `launcher-data` when the client is given a config directory, so device and
+//! slot settings survive server restarts.
 
 use pumas_library::config::AppId;
+use pumas_library::metadata::{atomic_read_json, atomic_write_json};
 use pumas_library::{PumasError, Result};
 use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Default Torch server API base URL — delegates to [`AppId::Torch`].
 fn default_base_url() -> &'static str {
@@ -32,6 +38,9 @@ const API_TIMEOUT: Duration = Duration::from_secs(10);
 /// Timeout for model loading (can take minutes for large models).
 const LOAD_TIMEOUT: Duration = Duration::from_secs(600);
 
+/// Filename of the persisted Torch server configuration inside `launcher-data`.
+pub const TORCH_CONFIG_FILENAME: &str = "torch-server-config.json";
+
 /// Helper to create a network error.
 fn net_err(msg: String) -> PumasError {
     PumasError::Network {
@@ -122,6 +131,19 @@ pub struct ModelSlot {
     /// Model type (e.g., "text-generation", "dllm", "sherry").
     #[serde(default)]
     pub model_type: Option<String>,
+    /// Unix timestamp (seconds) of the last load or inference request.
+    #[serde(default)]
+    pub last_used_at: Option<f64>,
+}
+
+/// Result of loading a model, including any slot evicted to make room.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorchLoadResult {
+    /// The newly loaded slot.
+    pub slot: ModelSlot,
+    /// Least-recently-used slot unloaded because every slot was in use.
+    #[serde(default)]
+    pub evicted: Option<ModelSlot>,
 }
 
 /// Information about an available compute device.
@@ -144,7 +166,8 @@ pub struct DeviceInfo {
 /// Local-only loopback binding is the default. Non-loopback binding is supported only when
 /// `lan_access` is explicitly enabled here, and the Python sidecar separately requires both
 /// `PUMAS_TORCH_ALLOW_LAN=1` and `PUMAS_TORCH_API_TOKEN` before accepting LAN traffic.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TorchServerConfig {
     /// Port for the OpenAI-compatible API.
     pub api_port: u16,
@@ -154,6 +177,10 @@ pub struct TorchServerConfig {
     pub max_loaded_models: usize,
     /// Whether LAN access is enabled (user opt-in).
     pub lan_access: bool,
+    /// Evict the least-recently-used slot instead of rejecting loads when all slots are full.
+    pub auto_evict: bool,
+    /// Model paths loaded automatically once the server reports ready.
+    pub preload_models: Vec<String>,
 }
 
 impl Default for TorchServerConfig {
@@ -163,6 +190,8 @@ impl Default for TorchServerConfig {
             host: "127.0.0.1".to_string(),
             max_loaded_models: 4,
             lan_access: false,
+            auto_evict: true,
+            preload_models: Vec::new(),
         }
     }
 }
@@ -186,8 +215,80 @@ impl TorchServerConfig {
             });
         }
 
+        if self
+            .preload_models
+            .iter()
+            .any(|path| path.trim().is_empty())
+        {
+            return Err(PumasError::InvalidParams {
+                message: "Torch preload model paths must not be empty".to_string(),
+            });
+        }
+
+        if self.preload_models.len() > self.max_loaded_models {
+            return Err(PumasError::InvalidParams {
+                message: format!(
+                    "Torch preload list has {} models but max_loaded_models is {}",
+                    self.preload_models.len(),
+                    self.max_loaded_models
+                ),
+            });
+        }
+
         Ok(())
     }
+
+    /// Base URL for reaching a server bound with this configuration locally.
+    ///
+    /// An unspecified bind address (`0.0.0.0`, `::`) is reached over loopback.
+    pub fn base_url(&self) -> String {
+        let host = match self.host.parse::<IpAddr>() {
+            Ok(ip) if ip.is_unspecified() => "127.0.0.1".to_string(),
+            Ok(IpAddr::V6(ip)) => format!("[{}]", ip),
+            _ => self.host.clone(),
+        };
+        format!("http://{}:{}", host, self.api_port)
+    }
+
+    /// Path of the persisted config file inside `launcher_data_dir`.
+    pub fn config_path(launcher_data_dir: &Path) -> PathBuf {
+        launcher_data_dir.join(TORCH_CONFIG_FILENAME)
+    }
+
+    /// Load the persisted configuration from `launcher_data_dir`.
+    ///
+    /// Returns `None` if no configuration has been saved yet.
+    pub fn load(launcher_data_dir: &Path) -> Result<Option<Self>> {
+        atomic_read_json(&Self::config_path(launcher_data_dir))
+    }
+
+    /// Persist this configuration into `launcher_data_dir`.
+    pub fn save(&self, launcher_data_dir: &Path) -> Result<()> {
+        atomic_write_json(&Self::config_path(launcher_data_dir), self, false)
+    }
+
+    /// Settings the running server can apply without a restart.
+    fn runtime_settings(&self) -> serde_json::Value {
+        serde_json::json!({
+            "max_loaded_models": self.max_loaded_models,
+            "auto_evict": self.auto_evict,
+        })
+    }
+}
+
+/// Outcome of preloading one configured model after server startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorchPreloadResult {
+    /// Model path from [`TorchServerConfig::preload_models`].
+    pub model_path: String,
+    /// Whether the model was loaded.
+    pub success: bool,
+    /// The loaded slot, on success.
+    #[serde(default)]
+    pub slot: Option<ModelSlot>,
+    /// Failure reason, on error.
+    #[serde(default)]
+    pub error: Option<String>,
 }
 
 /// Server status including all loaded slots and device information.
@@ -218,11 +319,6 @@ struct DevicesResponse {
     devices: Vec<DeviceInfo>,
 }
 
-#[derive(Debug, Deserialize)]
-struct LoadResponse {
-    slot: ModelSlot,
-}
-
 #[derive(Debug, Deserialize)]
 struct HealthResponse {
     status: String,
@@ -238,6 +334,8 @@ pub struct TorchClient {
     client: reqwest::Client,
     /// Client with extended timeout for model loading operations.
     load_client: reqwest::Client,
+    /// Directory holding the persisted server config, if persistence is enabled.
+    config_dir: Option<PathBuf>,
 }
 
 impl TorchClient {
@@ -266,7 +364,86 @@ impl TorchClient {
             base_url,
             client,
             load_client,
+            config_dir: None,
+        }
+    }
+
+    /// Persist configuration applied through this client into `launcher_data_dir`.
+    pub fn with_config_dir(mut self, launcher_data_dir: impl Into<PathBuf>) -> Self {
+        self.config_dir = Some(launcher_data_dir.into());
+        self
+    }
+
+    /// Read the persisted server configuration.
+    ///
+    /// Returns `None` if persistence is disabled for this client or nothing
+    /// has been saved yet.
+    pub async fn saved_config(&self) -> Result<Option<TorchServerConfig>> {
+        let Some(config_dir) = self.config_dir.clone() else {
+            return Ok(None);
+        };
+
+        tokio::task::spawn_blocking(move || TorchServerConfig::load(&config_dir))
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join Torch config load task: {}", e))
+            })?
+    }
+
+    /// Re-apply the persisted configuration to a freshly started server.
+    ///
+    /// Only settings that take effect without a restart are sent; `host` and
+    /// `api_port` are fixed by the launch itself. Returns the configuration
+    /// that was restored, if any.
+    pub async fn restore_saved_config(&self) -> Result<Option<TorchServerConfig>> {
+        let Some(config) = self.saved_config().await? else {
+            return Ok(None);
+        };
+        self.restore_config(&config).await?;
+        Ok(Some(config))
+    }
+
+    /// Re-apply a previously saved configuration to a freshly started server.
+    ///
+    /// Like [`Self::restore_saved_config`], only settings that take effect
+    /// without a restart are sent.
+    pub async fn restore_config(&self, config: &TorchServerConfig) -> Result<()> {
+        config.validate()?;
+
+        info!("Restoring saved Torch server configuration");
+        self.post_configure(&config.runtime_settings()).await
+    }
+
+    /// Load each model path into its own slot on the auto-selected device.
+    ///
+    /// Failures are reported per model and do not stop the remaining loads.
+    pub async fn preload_models(&self, model_paths: &[String]) -> Vec<TorchPreloadResult> {
+        let mut results = Vec::with_capacity(model_paths.len());
+        for model_path in model_paths {
+            let model_name = preload_model_name(model_path);
+            let result = match self
+                .load_model(model_path, &model_name, &ComputeDevice::Auto, None)
+                .await
+            {
+                Ok(loaded) => TorchPreloadResult {
+                    model_path: model_path.clone(),
+                    success: true,
+                    slot: Some(loaded.slot),
+                    error: None,
+                },
+                Err(err) => {
+                    warn!("Failed to preload Torch model '{}': {}", model_path, err);
+                    TorchPreloadResult {
+                        model_path: model_path.clone(),
+                        success: false,
+                        slot: None,
+                        error: Some(err.to_string()),
+                    }
+                }
+            };
+            results.push(result);
         }
+        results
     }
 
     /// Check if the server is responding.
@@ -320,13 +497,16 @@ impl TorchClient {
     }
 
     /// Load a model into a slot on a specific compute device.
+    ///
+    /// When the server is full and auto-eviction is enabled, the evicted slot is
+    /// reported in [`TorchLoadResult::evicted`].
     pub async fn load_model(
         &self,
         model_path: &str,
         model_name: &str,
         device: &ComputeDevice,
         model_type: Option<&str>,
-    ) -> Result<ModelSlot> {
+    ) -> Result<TorchLoadResult> {
         let url = format!("{}/api/load", self.base_url);
         info!(
             "Loading model '{}' on {} from {}",
@@ -366,16 +546,22 @@ impl TorchClient {
             });
         }
 
-        let load_response: LoadResponse = response
+        let load_result: TorchLoadResult = response
             .json()
             .await
             .map_err(|e| net_err(format!("Failed to parse Torch load response: {}", e)))?;
 
+        if let Some(evicted) = &load_result.evicted {
+            info!(
+                "Evicted model '{}' from slot '{}' to make room",
+                evicted.model_name, evicted.slot_id
+            );
+        }
         info!(
             "Model '{}' loaded into slot '{}' on {}",
-            model_name, load_response.slot.slot_id, load_response.slot.device
+            model_name, load_result.slot.slot_id, load_result.slot.device
         );
-        Ok(load_response.slot)
+        Ok(load_result)
     }
 
     /// Unload a model from a slot.
@@ -475,16 +661,34 @@ impl TorchClient {
     /// Non-loopback binding is intentionally gated twice: this client rejects it unless
     /// `lan_access` is enabled, and the Python sidecar still requires explicit LAN env opt-in
     /// plus an API token before serving non-loopback traffic.
+    ///
+    /// When a config directory is set, the applied configuration is persisted
+    /// so it can be restored on the next launch.
     pub async fn configure(&self, config: &TorchServerConfig) -> Result<()> {
         config.validate()?;
 
-        let url = format!("{}/api/configure", self.base_url);
         info!("Configuring Torch server: {:?}", config);
+        self.post_configure(config).await?;
+        info!("Torch server configuration updated");
+
+        if let Some(config_dir) = self.config_dir.clone() {
+            let config = config.clone();
+            tokio::task::spawn_blocking(move || config.save(&config_dir))
+                .await
+                .map_err(|e| {
+                    PumasError::Other(format!("Failed to join Torch config save task: {}", e))
+                })??;
+        }
+
+        Ok(())
+    }
 
+    async fn post_configure<T: Serialize + ?Sized>(&self, body: &T) -> Result<()> {
+        let url = format!("{}/api/configure", self.base_url);
         let response = self
             .client
             .post(&url)
-            .json(config)
+            .json(body)
             .send()
             .await
             .map_err(|e| {
@@ -503,11 +707,18 @@ impl TorchClient {
             )));
         }
 
-        info!("Torch server configuration updated");
         Ok(())
     }
 }
 
+/// Display name for a preloaded model: the final path component.
+fn preload_model_name(model_path: &str) -> String {
+    Path::new(model_path.trim_end_matches(['/', '\\']))
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| model_path.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -541,6 +752,7 @@ mod tests {
         assert_eq!(config.host, "127.0.0.1");
         assert_eq!(config.max_loaded_models, 4);
         assert!(!config.lan_access);
+        assert!(config.auto_evict);
     }
 
     #[test]
@@ -580,6 +792,89 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_torch_server_config_rejects_invalid_preload_list() {
+        let config = TorchServerConfig {
+            preload_models: vec![" ".to_string()],
+            ..TorchServerConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = TorchServerConfig {
+            max_loaded_models: 1,
+            preload_models: vec!["/models/a".to_string(), "/models/b".to_string()],
+            ..TorchServerConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_preload_model_name_uses_final_path_component() {
+        assert_eq!(preload_model_name("/models/llama3-8b"), "llama3-8b");
+        assert_eq!(preload_model_name("/models/llama3-8b/"), "llama3-8b");
+        assert_eq!(preload_model_name("model.safetensors"), "model.safetensors");
+    }
+
+    #[test]
+    fn test_torch_server_config_persistence_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(TorchServerConfig::load(temp_dir.path()).unwrap().is_none());
+
+        let config = TorchServerConfig {
+            max_loaded_models: 2,
+            ..TorchServerConfig::default()
+        };
+        config.save(temp_dir.path()).unwrap();
+
+        let loaded = TorchServerConfig::load(temp_dir.path()).unwrap();
+        assert_eq!(loaded, Some(config));
+    }
+
+    #[test]
+    fn test_torch_server_config_fills_missing_fields_with_defaults() {
+        let config: TorchServerConfig =
+            serde_json::from_str(r#"{"max_loaded_models": 1}"#).unwrap();
+        assert_eq!(config.max_loaded_models, 1);
+        assert_eq!(config.api_port, 8400);
+        assert_eq!(config.host, "127.0.0.1");
+    }
+
+    #[test]
+    fn test_torch_server_config_base_url_uses_saved_port() {
+        let config = TorchServerConfig {
+            api_port: 8512,
+            ..TorchServerConfig::default()
+        };
+        assert_eq!(config.base_url(), "http://127.0.0.1:8512");
+
+        let lan = TorchServerConfig {
+            host: "0.0.0.0".to_string(),
+            lan_access: true,
+            ..config.clone()
+        };
+        assert_eq!(lan.base_url(), "http://127.0.0.1:8512");
+
+        let ipv6 = TorchServerConfig {
+            host: "::1".to_string(),
+            ..config
+        };
+        assert_eq!(ipv6.base_url(), "http://[::1]:8512");
+    }
+
+    #[tokio::test]
+    async fn test_saved_config_requires_config_dir() {
+        let client = TorchClient::new(None);
+        assert!(client.saved_config().await.unwrap().is_none());
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        TorchServerConfig::default().save(temp_dir.path()).unwrap();
+        let client = TorchClient::new(None).with_config_dir(temp_dir.path());
+        assert_eq!(
+            client.saved_config().await.unwrap(),
+            Some(TorchServerConfig::default())
+        );
+    }
+
     #[test]
     fn test_slot_state_serialization() {
         let state = SlotState::Ready;
@@ -609,6 +904,41 @@ mod tests {
         assert_eq!(slot.state, SlotState::Ready);
         assert_eq!(slot.gpu_memory_bytes, Some(8589934592));
         assert_eq!(slot.model_type.as_deref(), Some("text-generation"));
+        assert_eq!(slot.last_used_at, None);
+    }
+
+    #[test]
+    fn test_load_result_deserialization_with_eviction() {
+        let json = r#"{
+            "success": true,
+            "slot": {
+                "slot_id": "slot-2",
+                "model_name": "new",
+                "model_path": "/models/new",
+                "device": "cpu",
+                "state": "ready",
+                "last_used_at": 1700000100.5
+            },
+            "evicted": {
+                "slot_id": "slot-1",
+                "model_name": "old",
+                "model_path": "/models/old",
+                "device": "cpu",
+                "state": "ready",
+                "last_used_at": 1700000000.0
+            }
+        }"#;
+
+        let result: TorchLoadResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.slot.slot_id, "slot-2");
+        assert_eq!(result.slot.last_used_at, Some(1700000100.5));
+        assert_eq!(result.evicted.unwrap().slot_id, "slot-1");
+
+        let result: TorchLoadResult = serde_json::from_str(
+            r#"{"slot": {"slot_id": "s", "model_name": "m", "model_path": "/m", "device": "cpu", "state": "ready"}, "evicted": null}"#,
+        )
+        .unwrap();
+        assert!(result.evicted.is_none());
     }
 
     #[test]
@@ -4,6 +4,7 @@
 //! for reproducible installations.
 
 use chrono::{DateTime, Utc};
+use pumas_library::network::HttpSettings;
 use pumas_library::{PumasError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -20,6 +21,8 @@ pub struct ConstraintsManager {
     pypi_cache: Mutex<HashMap<String, HashMap<String, DateTime<Utc>>>>,
     /// Cache of built constraints (tag -> package -> pinned version).
     constraints_cache: Mutex<HashMap<String, HashMap<String, String>>>,
+    /// Proxy, CA, and user-agent settings for PyPI requests.
+    http: HttpSettings,
 }
 
 /// Cached constraints file data.
@@ -37,9 +40,16 @@ impl ConstraintsManager {
             constraints_dir,
             pypi_cache: Mutex::new(HashMap::new()),
             constraints_cache: Mutex::new(HashMap::new()),
+            http: HttpSettings::default(),
         }
     }
 
+    /// Use the given outbound HTTP settings for PyPI requests.
+    pub fn with_http_settings(mut self, http: HttpSettings) -> Self {
+        self.http = http;
+        self
+    }
+
     /// Create a new constraints manager and load cached constraints from disk.
     pub async fn new_with_cache(constraints_dir: PathBuf) -> Self {
         let manager = Self::new(constraints_dir);
@@ -298,7 +308,14 @@ impl ConstraintsManager {
 
         // Fetch from PyPI
         let url = format!("https://pypi.org/pypi/{}/json", package);
-        let client = reqwest::Client::new();
+        let client = self
+            .http
+            .client_builder()
+            .build()
+            .map_err(|e| PumasError::Network {
+                message: format!("Failed to create HTTP client: {}", e),
+                cause: Some(e.to_string()),
+            })?;
 
         let response = client
             .get(&url)
@@ -7,7 +7,7 @@ use chrono::Utc;
 use pumas_library::config::{AppId, InstallationConfig, PathsConfig};
 use pumas_library::metadata::{InstalledVersionMetadata, MetadataManager};
 use pumas_library::models::InstallationStage;
-use pumas_library::network::{GitHubAsset, GitHubRelease};
+use pumas_library::network::{GitHubAsset, GitHubRelease, HttpSettings};
 use pumas_library::{PumasError, Result};
 use std::fs::File;
 use std::io::BufReader;
@@ -39,6 +39,8 @@ pub struct VersionInstaller {
     progress_tracker: Arc<RwLock<InstallationProgressTracker>>,
     /// Cancellation flag.
     cancel_flag: Arc<AtomicBool>,
+    /// Proxy, CA, and user-agent settings for archive downloads.
+    http: HttpSettings,
 }
 
 impl VersionInstaller {
@@ -56,9 +58,16 @@ impl VersionInstaller {
             metadata_manager,
             progress_tracker,
             cancel_flag,
+            http: HttpSettings::default(),
         }
     }
 
+    /// Use the given outbound HTTP settings for archive downloads.
+    pub fn with_http_settings(mut self, http: HttpSettings) -> Self {
+        self.http = http;
+        self
+    }
+
     /// Install a version from a GitHub release.
     /// Dispatches to app-specific installation method based on app_id.
     pub async fn install_version(
@@ -217,11 +226,7 @@ impl VersionInstaller {
         }
 
         // Use download cache directory to avoid re-downloading on reinstalls
-        let cache_downloads = self
-            .launcher_root
-            .join("launcher-data")
-            .join("cache")
-            .join("downloads");
+        let cache_downloads = PathsConfig::cache_dir(&self.launcher_root).join("downloads");
         fs::create_dir_all(&cache_downloads)
             .await
             .map_err(|e| PumasError::Io {
@@ -328,11 +333,7 @@ impl VersionInstaller {
             );
         }
 
-        let cache_downloads = self
-            .launcher_root
-            .join("launcher-data")
-            .join("cache")
-            .join("downloads");
+        let cache_downloads = PathsConfig::cache_dir(&self.launcher_root).join("downloads");
         fs::create_dir_all(&cache_downloads)
             .await
             .map_err(|e| PumasError::Io {
@@ -882,7 +883,7 @@ impl VersionInstaller {
                 final_path.display(),
                 source.display()
             );
-            return Ok(final_path);
+            Ok(final_path)
         }
 
         #[cfg(not(unix))]
@@ -1138,7 +1139,7 @@ impl VersionInstaller {
 
         // Save metadata
         self.metadata_manager
-            .update_installed_version(tag, metadata, Some(self.app_id))?;
+            .update_installed_version(tag, metadata, Some(self.app_id.clone()))?;
 
         // Update progress
         {
@@ -1204,7 +1205,7 @@ impl VersionInstaller {
         };
 
         self.metadata_manager
-            .update_installed_version(tag, metadata, Some(self.app_id))?;
+            .update_installed_version(tag, metadata, Some(self.app_id.clone()))?;
 
         {
             let mut tracker = self.progress_tracker.write().await;
@@ -1307,9 +1308,10 @@ impl VersionInstaller {
         // Create HTTP client with appropriate timeouts for large downloads
         // - connect_timeout: time to establish connection (15s is fine)
         // - NO overall timeout: downloads can take a long time for large files (1.6 GB+)
-        let client = reqwest::Client::builder()
+        let client = self
+            .http
+            .client_builder()
             .connect_timeout(InstallationConfig::URL_FETCH_TIMEOUT)
-            .user_agent("pumas-library")
             .build()
             .map_err(|e| PumasError::Network {
                 message: format!("Failed to create HTTP client: {}", e),
@@ -1913,7 +1915,7 @@ impl VersionInstaller {
 
         // Save metadata
         self.metadata_manager
-            .update_installed_version(tag, metadata, Some(self.app_id))?;
+            .update_installed_version(tag, metadata, Some(self.app_id.clone()))?;
 
         // Update progress
         {
@@ -1955,10 +1957,7 @@ impl VersionInstaller {
     }
 
     fn pip_cache_dir(&self) -> PathBuf {
-        self.launcher_root
-            .join("launcher-data")
-            .join(PathsConfig::CACHE_DIR_NAME)
-            .join(PathsConfig::PIP_CACHE_DIR_NAME)
+        PathsConfig::cache_dir(&self.launcher_root).join(PathsConfig::PIP_CACHE_DIR_NAME)
     }
 
     fn slugify_tag(&self, tag: &str) -> String {
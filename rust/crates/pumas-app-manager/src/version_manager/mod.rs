@@ -18,6 +18,7 @@
 //! - `progress`: Installation progress tracking
 //! - `constraints`: PyPI constraint resolution
 //! - `ollama`: Ollama-specific binary installation
+//! - `version_order`: Semantic ordering of release tags
 //!
 //! # Example
 //!
@@ -50,6 +51,7 @@ pub mod ollama;
 mod progress;
 pub mod size_calculator;
 mod state;
+pub mod version_order;
 
 pub use constraints::ConstraintsManager;
 pub use dependencies::DependencyManager;
@@ -57,13 +59,13 @@ pub use installer::VersionInstaller;
 pub use launcher::VersionLauncher;
 pub use ollama::OllamaVersionManager;
 pub use progress::{InstallationProgressTracker, PackageWeights, ProgressUpdate};
-pub use size_calculator::{ReleaseSize, SizeBreakdown, SizeCalculator};
+pub use size_calculator::{ReclaimEstimate, ReleaseSize, SizeBreakdown, SizeCalculator};
 pub use state::VersionState;
 
 use pumas_library::config::{AppId, PathsConfig};
 use pumas_library::metadata::MetadataManager;
 use pumas_library::models::InstallationProgress;
-use pumas_library::network::GitHubClient;
+use pumas_library::network::{GitHubClient, HttpSettings};
 use pumas_library::{PumasError, Result};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -90,6 +92,8 @@ pub struct VersionManager {
     metadata_manager: Arc<MetadataManager>,
     /// GitHub client for fetching releases.
     github_client: Arc<GitHubClient>,
+    /// Proxy, CA, and user-agent settings for downloads.
+    http: HttpSettings,
     /// Version state tracker.
     state: Arc<RwLock<VersionState>>,
     /// Installation progress tracker.
@@ -109,7 +113,7 @@ impl VersionManager {
     ) -> Result<Option<pumas_library::metadata::InstalledVersionMetadata>> {
         let metadata_manager = self.metadata_manager.clone();
         let tag = tag.to_string();
-        let app_id = self.app_id;
+        let app_id = self.app_id.clone();
         tokio::task::spawn_blocking(move || {
             metadata_manager.get_installed_version(&tag, Some(app_id))
         })
@@ -129,6 +133,16 @@ impl VersionManager {
     /// * `launcher_root` - Path to the launcher root directory
     /// * `app_id` - The application to manage versions for
     pub async fn new(launcher_root: impl Into<PathBuf>, app_id: AppId) -> Result<Self> {
+        Self::with_http_settings(launcher_root, app_id, HttpSettings::default()).await
+    }
+
+    /// Create a version manager whose GitHub and download requests use the
+    /// given proxy, CA, and user-agent settings (see `PumasApi::http_settings`).
+    pub async fn with_http_settings(
+        launcher_root: impl Into<PathBuf>,
+        app_id: AppId,
+        http: HttpSettings,
+    ) -> Result<Self> {
         let launcher_root = launcher_root.into();
 
         if !app_id.has_version_manager() {
@@ -146,15 +160,17 @@ impl VersionManager {
             });
         }
 
-        let cache_dir = launcher_root
-            .join("launcher-data")
-            .join(PathsConfig::CACHE_DIR_NAME);
+        let cache_dir = PathsConfig::cache_dir(&launcher_root);
         let launcher_root_for_setup = launcher_root.clone();
         let cache_dir_for_setup = cache_dir.clone();
+        let http_for_setup = http.clone();
         let (metadata_manager, github_client) = tokio::task::spawn_blocking(move || {
             let metadata_manager = Arc::new(MetadataManager::new(&launcher_root_for_setup));
             metadata_manager.ensure_directories()?;
-            let github_client = Arc::new(GitHubClient::new(cache_dir_for_setup)?);
+            let github_client = Arc::new(GitHubClient::with_http_settings(
+                cache_dir_for_setup,
+                &http_for_setup,
+            )?);
             Ok::<_, PumasError>((metadata_manager, github_client))
         })
         .await
@@ -171,7 +187,7 @@ impl VersionManager {
 
         // Initialize state
         let state = Arc::new(RwLock::new(
-            VersionState::new(&launcher_root, app_id, metadata_manager.clone()).await?,
+            VersionState::new(&launcher_root, app_id.clone(), metadata_manager.clone()).await?,
         ));
 
         // Validate installed versions exist on disk (removes stale entries)
@@ -198,6 +214,7 @@ impl VersionManager {
             app_id,
             metadata_manager,
             github_client,
+            http,
             state,
             progress_tracker,
             cancel_flag: Arc::new(AtomicBool::new(false)),
@@ -224,9 +241,7 @@ impl VersionManager {
 
     /// Get the cache directory.
     pub fn cache_dir(&self) -> PathBuf {
-        self.launcher_root
-            .join("launcher-data")
-            .join(PathsConfig::CACHE_DIR_NAME)
+        PathsConfig::cache_dir(&self.launcher_root)
     }
 
     /// Get the pip cache directory.
@@ -325,6 +340,28 @@ impl VersionManager {
         })
     }
 
+    /// Check whether a release newer than every installed version exists.
+    ///
+    /// Pre-releases are ignored. Uses cached release data unless
+    /// `force_refresh` is set.
+    pub async fn check_for_newer(&self, force_refresh: bool) -> Result<NewerVersionCheck> {
+        let installed = self.get_installed_versions().await?;
+        let releases = self.get_available_releases(force_refresh).await?;
+        let latest = version_order::newer_than_installed(
+            &installed,
+            releases
+                .iter()
+                .filter(|release| !release.prerelease)
+                .map(|release| release.tag_name.as_str()),
+        );
+
+        Ok(NewerVersionCheck {
+            update_available: latest.is_some(),
+            latest_version: latest.map(str::to_string),
+            newest_installed: installed.into_iter().next(),
+        })
+    }
+
     /// Validate all installations and remove incomplete ones.
     pub async fn validate_installations(&self) -> Result<ValidationResult> {
         let mut state = self.state.write().await;
@@ -335,14 +372,17 @@ impl VersionManager {
     // GitHub releases
     // ========================================
 
-    /// Get available releases from GitHub.
+    /// Get available releases from GitHub, newest first by semver.
     pub async fn get_available_releases(
         &self,
         force_refresh: bool,
     ) -> Result<Vec<pumas_library::network::GitHubRelease>> {
-        self.github_client
-            .get_releases_for_app(self.app_id, force_refresh)
-            .await
+        let mut releases = self
+            .github_client
+            .get_releases_for_app(self.app_id.clone(), force_refresh)
+            .await?;
+        releases.sort_by(|a, b| version_order::compare_version_tags(&b.tag_name, &a.tag_name));
+        Ok(releases)
     }
 
     /// Get a specific release by tag.
@@ -435,11 +475,12 @@ impl VersionManager {
         // Create installer
         let installer = VersionInstaller::new(
             self.launcher_root.clone(),
-            self.app_id,
+            self.app_id.clone(),
             self.metadata_manager.clone(),
             self.progress_tracker.clone(),
             self.cancel_flag.clone(),
-        );
+        )
+        .with_http_settings(self.http.clone());
 
         // Spawn installation task
         let tag = tag.to_string();
@@ -485,6 +526,64 @@ impl VersionManager {
         Ok(rx)
     }
 
+    /// Install a version and make it active once the install succeeds.
+    ///
+    /// Waits for the installation to finish. If it fails partway, any partial
+    /// version directory is removed and the active/default versions are left
+    /// unchanged.
+    pub async fn install_and_activate(
+        &self,
+        tag: &str,
+        set_default: bool,
+    ) -> Result<InstallActivateResult> {
+        let rx = self.install_version(tag).await?;
+
+        if let Err(e) = wait_for_install(rx).await {
+            warn!("Installation of {} failed, cleaning up: {}", tag, e);
+            self.cleanup_failed_install(tag).await;
+            return Err(e);
+        }
+
+        self.set_active_version(tag).await?;
+        if set_default {
+            self.set_default_version(Some(tag)).await?;
+        }
+
+        info!("Installed and activated version: {}", tag);
+        Ok(InstallActivateResult {
+            tag: tag.to_string(),
+            activated: true,
+            default_set: set_default,
+        })
+    }
+
+    /// Remove leftovers of an installation that did not complete.
+    async fn cleanup_failed_install(&self, tag: &str) {
+        let mut state = self.state.write().await;
+        if state.is_installed(tag) {
+            return;
+        }
+
+        let version_path = self.version_path(tag);
+        match path_exists(&version_path).await {
+            Ok(true) => {
+                if let Err(e) = fs::remove_dir_all(&version_path).await {
+                    warn!(
+                        "Failed to remove partial install {}: {}",
+                        version_path.display(),
+                        e
+                    );
+                }
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to check partial install {}: {}", tag, e),
+        }
+
+        if let Err(e) = state.refresh().await {
+            warn!("Failed to refresh state after failed installation: {}", e);
+        }
+    }
+
     async fn resolve_installable_release(
         &self,
         tag: &str,
@@ -544,7 +643,7 @@ impl VersionManager {
 
         // Remove from metadata
         self.metadata_manager
-            .remove_installed_version(tag, Some(self.app_id))?;
+            .remove_installed_version(tag, Some(self.app_id.clone()))?;
 
         // Refresh state
         {
@@ -567,7 +666,7 @@ impl VersionManager {
     ) -> Result<pumas_library::models::DependencyStatus> {
         let dep_manager = DependencyManager::new(
             self.launcher_root.clone(),
-            self.app_id,
+            self.app_id.clone(),
             self.pip_cache_dir(),
         );
         dep_manager.check_dependencies(tag).await
@@ -581,11 +680,13 @@ impl VersionManager {
     ) -> Result<bool> {
         let dep_manager = DependencyManager::new(
             self.launcher_root.clone(),
-            self.app_id,
+            self.app_id.clone(),
             self.pip_cache_dir(),
         );
 
-        let constraints_manager = ConstraintsManager::new_with_cache(self.constraints_dir()).await;
+        let constraints_manager = ConstraintsManager::new_with_cache(self.constraints_dir())
+            .await
+            .with_http_settings(self.http.clone());
 
         dep_manager
             .install_dependencies(tag, &constraints_manager, progress_tx)
@@ -614,8 +715,11 @@ impl VersionManager {
         }
 
         // Create launcher
-        let launcher =
-            VersionLauncher::new(self.launcher_root.clone(), self.app_id, self.logs_dir());
+        let launcher = VersionLauncher::new(
+            self.launcher_root.clone(),
+            self.app_id.clone(),
+            self.logs_dir(),
+        );
 
         launcher.launch_version(tag, extra_args).await
     }
@@ -640,6 +744,45 @@ pub struct VersionStatusEntry {
     pub dependencies_installed: Option<bool>,
 }
 
+/// Result of [`VersionManager::install_and_activate`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InstallActivateResult {
+    pub tag: String,
+    pub activated: bool,
+    pub default_set: bool,
+}
+
+/// Drain installation progress until the final status arrives.
+async fn wait_for_install(mut rx: mpsc::Receiver<ProgressUpdate>) -> Result<()> {
+    while let Some(update) = rx.recv().await {
+        match update {
+            ProgressUpdate::Completed { success: true } => return Ok(()),
+            ProgressUpdate::Completed { success: false } => {
+                return Err(PumasError::InstallationFailed {
+                    message: "Installation did not complete".to_string(),
+                })
+            }
+            ProgressUpdate::Error { message } => {
+                return Err(PumasError::InstallationFailed { message })
+            }
+            _ => {}
+        }
+    }
+
+    Err(PumasError::InstallationFailed {
+        message: "Installation ended without a final status".to_string(),
+    })
+}
+
+/// Whether a newer release than the installed versions exists.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NewerVersionCheck {
+    pub update_available: bool,
+    /// Newest release tag, when it is newer than everything installed.
+    pub latest_version: Option<String>,
+    pub newest_installed: Option<String>,
+}
+
 /// Result of version validation.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ValidationResult {
@@ -676,6 +819,45 @@ mod tests {
         (manager, temp_dir)
     }
 
+    #[tokio::test]
+    async fn wait_for_install_reports_final_status() {
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(ProgressUpdate::Setup {
+            message: "Preparing".to_string(),
+        })
+        .await
+        .unwrap();
+        tx.send(ProgressUpdate::Completed { success: true })
+            .await
+            .unwrap();
+        assert!(wait_for_install(rx).await.is_ok());
+
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(ProgressUpdate::Error {
+            message: "pip failed".to_string(),
+        })
+        .await
+        .unwrap();
+        let err = wait_for_install(rx).await.unwrap_err();
+        assert!(err.to_string().contains("pip failed"));
+
+        let (tx, rx) = mpsc::channel::<ProgressUpdate>(4);
+        drop(tx);
+        assert!(wait_for_install(rx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_install_cleanup_removes_partial_directory() {
+        let (manager, _temp) = create_test_manager().await;
+        let partial = manager.version_path("v9.9.9");
+        std::fs::create_dir_all(partial.join("comfy")).unwrap();
+
+        manager.cleanup_failed_install("v9.9.9").await;
+
+        assert!(!partial.exists());
+        assert!(manager.get_active_version().await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_manager_creation() {
         let (manager, _temp) = create_test_manager().await;
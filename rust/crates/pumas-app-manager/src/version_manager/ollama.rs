@@ -8,7 +8,7 @@ use crate::version_manager::state::VersionState;
 use pumas_library::config::{AppId, InstallationConfig};
 use pumas_library::metadata::{InstalledVersionMetadata, MetadataManager};
 use pumas_library::models::InstallationStage;
-use pumas_library::network::{GitHubAsset, GitHubClient, GitHubRelease};
+use pumas_library::network::{GitHubAsset, GitHubClient, GitHubRelease, HttpSettings};
 use pumas_library::{PumasError, Result};
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -44,6 +44,8 @@ pub struct OllamaVersionManager {
     install_lock: Arc<Mutex<()>>,
     /// Currently installing tag.
     installing_tag: Arc<RwLock<Option<String>>>,
+    /// Proxy, CA, and user-agent settings for binary downloads.
+    http: HttpSettings,
 }
 
 impl OllamaVersionManager {
@@ -54,7 +56,8 @@ impl OllamaVersionManager {
         github_client: Arc<GitHubClient>,
     ) -> Result<Self> {
         let app_id = AppId::Ollama;
-        let state = VersionState::new(&launcher_root, app_id, metadata_manager.clone()).await?;
+        let state =
+            VersionState::new(&launcher_root, app_id.clone(), metadata_manager.clone()).await?;
 
         Ok(Self {
             launcher_root,
@@ -65,9 +68,16 @@ impl OllamaVersionManager {
             cancel_flag: Arc::new(AtomicBool::new(false)),
             install_lock: Arc::new(Mutex::new(())),
             installing_tag: Arc::new(RwLock::new(None)),
+            http: HttpSettings::default(),
         })
     }
 
+    /// Use the given outbound HTTP settings for binary downloads.
+    pub fn with_http_settings(mut self, http: HttpSettings) -> Self {
+        self.http = http;
+        self
+    }
+
     /// Get the versions directory.
     fn versions_dir(&self) -> PathBuf {
         self.launcher_root.join(self.app_id.versions_dir_name())
@@ -336,9 +346,10 @@ impl OllamaVersionManager {
         dest: &Path,
         progress_tx: Option<mpsc::Sender<ProgressUpdate>>,
     ) -> Result<()> {
-        let client = reqwest::Client::builder()
+        let client = self
+            .http
+            .client_builder()
             .timeout(InstallationConfig::URL_FETCH_TIMEOUT)
-            .user_agent("pumas-library")
             .build()
             .map_err(|e| PumasError::Network {
                 message: format!("Failed to create HTTP client: {}", e),
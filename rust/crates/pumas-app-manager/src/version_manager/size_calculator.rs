@@ -9,6 +9,9 @@
 //!
 //! This approach is more reliable than Python's pip-based method which
 //! often fails or returns inconsistent results.
+//!
+//! It also measures installed versions to estimate how much disk space
+//! removing them would free, accounting for hardlinks shared elsewhere.
 
 use pumas_library::{PumasError, Result};
 use serde::{Deserialize, Serialize};
@@ -51,6 +54,20 @@ pub struct SizeBreakdown {
     pub dependency_count: usize,
 }
 
+/// Disk space that removing a set of installed directories would free.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReclaimEstimate {
+    /// Apparent size of every regular file under the directories
+    pub total_bytes: u64,
+    /// Bytes counted in `total_bytes` whose data removal does not free
+    /// (hardlinked from outside, or the same file reached twice)
+    pub shared_bytes: u64,
+    /// Bytes actually freed by removal (`total_bytes - shared_bytes`)
+    pub reclaimable_bytes: u64,
+    /// Number of regular files counted
+    pub file_count: u64,
+}
+
 /// Calculator for release sizes with caching support.
 pub struct SizeCalculator {
     /// Path to the cache file
@@ -461,6 +478,64 @@ impl SizeCalculator {
         }
     }
 
+    /// Estimate the space freed by removing `dirs` (e.g. a version or a whole app).
+    ///
+    /// Symlinks are not followed and free nothing measurable. A hardlinked
+    /// file only counts as reclaimable when every one of its links lies
+    /// inside `dirs`; otherwise its data stays on disk and it is reported as
+    /// shared. Missing directories contribute nothing.
+    pub fn estimate_reclaimable_size(dirs: &[PathBuf]) -> ReclaimEstimate {
+        let mut estimate = ReclaimEstimate::default();
+        // (device, inode) -> (size, total link count, links seen under `dirs`)
+        let mut hardlinks: HashMap<(u64, u64), (u64, u64, u64)> = HashMap::new();
+
+        for dir in dirs {
+            for entry in walkdir::WalkDir::new(dir)
+                .follow_links(false)
+                .into_iter()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_file())
+            {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                estimate.file_count += 1;
+                estimate.total_bytes = estimate.total_bytes.saturating_add(metadata.len());
+                if let Some((key, nlink)) = Self::hardlink_identity(&metadata) {
+                    let seen = hardlinks.entry(key).or_insert((metadata.len(), nlink, 0));
+                    seen.2 += 1;
+                }
+            }
+        }
+
+        for (size, nlink, seen) in hardlinks.into_values() {
+            // Links seen more than once inside `dirs` are all counted in `total_bytes`;
+            // only one copy of the data exists, so the duplicates are never freed either.
+            let duplicate_bytes = size.saturating_mul(seen.saturating_sub(1));
+            let shared = if seen < nlink {
+                size.saturating_add(duplicate_bytes)
+            } else {
+                duplicate_bytes
+            };
+            estimate.shared_bytes = estimate.shared_bytes.saturating_add(shared);
+        }
+
+        estimate.reclaimable_bytes = estimate.total_bytes.saturating_sub(estimate.shared_bytes);
+        estimate
+    }
+
+    /// Inode identity and link count for files with more than one hardlink.
+    #[cfg(unix)]
+    fn hardlink_identity(metadata: &std::fs::Metadata) -> Option<((u64, u64), u64)> {
+        use std::os::unix::fs::MetadataExt;
+        (metadata.nlink() > 1).then(|| ((metadata.dev(), metadata.ino()), metadata.nlink()))
+    }
+
+    #[cfg(not(unix))]
+    fn hardlink_identity(_metadata: &std::fs::Metadata) -> Option<((u64, u64), u64)> {
+        None
+    }
+
     /// Clear all cached sizes.
     pub fn clear_cache(&mut self) {
         self.cache.clear();
@@ -484,6 +559,45 @@ mod tests {
         (calculator, temp_dir)
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_estimate_reclaimable_size_excludes_shared_hardlinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let version = temp_dir.path().join("v1");
+        let library = temp_dir.path().join("library");
+        std::fs::create_dir_all(&version).unwrap();
+        std::fs::create_dir_all(&library).unwrap();
+
+        std::fs::write(version.join("main.py"), vec![0u8; 100]).unwrap();
+        // Model hardlinked into the version from the library: not freed.
+        std::fs::write(library.join("model.bin"), vec![0u8; 1000]).unwrap();
+        std::fs::hard_link(library.join("model.bin"), version.join("model.bin")).unwrap();
+        // Hardlink pair entirely inside the version: freed once.
+        std::fs::write(version.join("a.bin"), vec![0u8; 10]).unwrap();
+        std::fs::hard_link(version.join("a.bin"), version.join("b.bin")).unwrap();
+        // Symlinks are not followed.
+        std::os::unix::fs::symlink(library.join("model.bin"), version.join("link.bin")).unwrap();
+
+        let estimate = SizeCalculator::estimate_reclaimable_size(std::slice::from_ref(&version));
+
+        assert_eq!(estimate.file_count, 4);
+        assert_eq!(estimate.total_bytes, 1120);
+        assert_eq!(estimate.shared_bytes, 1010);
+        assert_eq!(estimate.reclaimable_bytes, 110);
+
+        // Removing the library too frees the hardlinked model.
+        let estimate = SizeCalculator::estimate_reclaimable_size(&[version, library]);
+        assert_eq!(estimate.reclaimable_bytes, 1110);
+    }
+
+    #[test]
+    fn test_estimate_reclaimable_size_missing_dir_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let estimate =
+            SizeCalculator::estimate_reclaimable_size(&[temp_dir.path().join("missing")]);
+        assert_eq!(estimate, ReclaimEstimate::default());
+    }
+
     #[test]
     fn test_format_size_bytes() {
         assert_eq!(SizeCalculator::format_size(0), "0 bytes");
@@ -3,6 +3,7 @@
 //! Manages the state of installed, active, and default versions.
 //! Handles state persistence and validation.
 
+use crate::version_manager::version_order::sort_tags_newest_first;
 use crate::version_manager::ValidationResult;
 use pumas_library::config::AppId;
 use pumas_library::metadata::{InstalledVersionMetadata, MetadataManager};
@@ -54,7 +55,7 @@ pub struct VersionState {
 impl VersionState {
     async fn load_versions_metadata(&self) -> Result<pumas_library::metadata::VersionsMetadata> {
         let metadata_manager = self.metadata_manager.clone();
-        let app_id = self.app_id;
+        let app_id = self.app_id.clone();
         tokio::task::spawn_blocking(move || metadata_manager.load_versions(Some(app_id)))
             .await
             .map_err(|err| {
@@ -67,7 +68,7 @@ impl VersionState {
 
     async fn set_last_selected_version_metadata(&self, tag: Option<String>) -> Result<()> {
         let metadata_manager = self.metadata_manager.clone();
-        let app_id = self.app_id;
+        let app_id = self.app_id.clone();
         tokio::task::spawn_blocking(move || {
             metadata_manager.set_last_selected_version(tag.as_deref(), Some(app_id))
         })
@@ -82,7 +83,7 @@ impl VersionState {
 
     async fn set_default_version_metadata(&self, tag: Option<String>) -> Result<()> {
         let metadata_manager = self.metadata_manager.clone();
-        let app_id = self.app_id;
+        let app_id = self.app_id.clone();
         tokio::task::spawn_blocking(move || {
             metadata_manager.set_default_version(tag.as_deref(), Some(app_id))
         })
@@ -184,12 +185,9 @@ impl VersionState {
             }
         }
 
-        // 4. Newest installed version (lexicographically, which works for semver with v prefix)
+        // 4. Newest installed version
         if !self.installed_tags.is_empty() {
-            let mut sorted: Vec<_> = self.installed_tags.iter().cloned().collect();
-            sorted.sort();
-            sorted.reverse(); // Newest first
-            let newest = sorted.into_iter().next();
+            let newest = self.get_installed_tags().into_iter().next();
             debug!("Active version from newest: {:?}", newest);
             return Ok(newest);
         }
@@ -340,11 +338,10 @@ impl VersionState {
     // Getters
     // ========================================
 
-    /// Get list of installed version tags.
+    /// Get list of installed version tags, newest first.
     pub fn get_installed_tags(&self) -> Vec<String> {
         let mut tags: Vec<_> = self.installed_tags.iter().cloned().collect();
-        tags.sort();
-        tags.reverse(); // Newest first
+        sort_tags_newest_first(&mut tags);
         tags
     }
 
@@ -435,7 +432,7 @@ impl VersionState {
         metadata: InstalledVersionMetadata,
     ) -> Result<()> {
         self.metadata_manager
-            .update_installed_version(tag, metadata, Some(self.app_id))?;
+            .update_installed_version(tag, metadata, Some(self.app_id.clone()))?;
         self.installed_tags.insert(tag.to_string());
         debug!("Added installed version: {}", tag);
         Ok(())
@@ -444,7 +441,7 @@ impl VersionState {
     /// Remove an installed version.
     pub async fn remove_installed_version(&mut self, tag: &str) -> Result<()> {
         self.metadata_manager
-            .remove_installed_version(tag, Some(self.app_id))?;
+            .remove_installed_version(tag, Some(self.app_id.clone()))?;
         self.installed_tags.remove(tag);
 
         // Clear active if it was this version
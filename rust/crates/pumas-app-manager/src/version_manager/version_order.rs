@@ -0,0 +1,95 @@
+//! Semantic ordering of release tags.
+//!
+//! Tags such as `v0.2.0` and `v0.10.0` sort wrongly as plain strings. These
+//! helpers order tags by semver where they parse and fall back to string order
+//! for tags that don't (e.g. llama.cpp `b4567` builds).
+
+use semver::Version;
+use std::cmp::Ordering;
+
+/// Parse a release tag as semver.
+///
+/// Accepts a leading `v` and two-part `major.minor` tags.
+pub fn parse_version_tag(tag: &str) -> Option<Version> {
+    let tag = tag.trim();
+    let version = tag.strip_prefix(['v', 'V']).unwrap_or(tag);
+    Version::parse(version).ok().or_else(|| {
+        if version.split('.').count() == 2 {
+            Version::parse(&format!("{}.0", version)).ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// Compare two tags, oldest first.
+///
+/// Semver tags order semantically and rank above non-semver tags, which
+/// compare as strings.
+pub fn compare_version_tags(a: &str, b: &str) -> Ordering {
+    match (parse_version_tag(a), parse_version_tag(b)) {
+        (Some(a_version), Some(b_version)) => a_version.cmp(&b_version).then_with(|| a.cmp(b)),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => a.cmp(b),
+    }
+}
+
+/// Sort tags newest first.
+pub fn sort_tags_newest_first(tags: &mut [String]) {
+    tags.sort_by(|a, b| compare_version_tags(b, a));
+}
+
+/// Newest available tag that is newer than every installed tag.
+///
+/// Returns `None` when nothing is installed or the newest release is
+/// already installed.
+pub fn newer_than_installed<'a>(
+    installed: &[String],
+    available: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let newest_installed = installed.iter().max_by(|a, b| compare_version_tags(a, b))?;
+    available
+        .into_iter()
+        .max_by(|a, b| compare_version_tags(a, b))
+        .filter(|latest| compare_version_tags(latest, newest_installed) == Ordering::Greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_orders_semver_tags_numerically() {
+        let mut tags = vec![
+            "v0.2.0".to_string(),
+            "nightly".to_string(),
+            "v0.10.0".to_string(),
+            "v0.3".to_string(),
+            "v0.10.0-rc1".to_string(),
+        ];
+        sort_tags_newest_first(&mut tags);
+        assert_eq!(
+            tags,
+            vec!["v0.10.0", "v0.10.0-rc1", "v0.3", "v0.2.0", "nightly"]
+        );
+    }
+
+    #[test]
+    fn test_newer_than_installed_compares_against_newest_installed() {
+        let installed = vec!["v0.2.0".to_string(), "v0.9.0".to_string()];
+        assert_eq!(
+            newer_than_installed(&installed, ["v0.3.0", "v0.10.0"]),
+            Some("v0.10.0")
+        );
+        assert_eq!(newer_than_installed(&installed, ["v0.3.0", "v0.9.0"]), None);
+        assert_eq!(newer_than_installed(&[], ["v0.10.0"]), None);
+    }
+
+    #[test]
+    fn test_non_semver_tags_fall_back_to_string_order() {
+        assert_eq!(parse_version_tag("b4567"), None);
+        assert_eq!(compare_version_tags("b4567", "b4600"), Ordering::Less);
+        assert_eq!(parse_version_tag("V1.2.3"), Some(Version::new(1, 2, 3)));
+    }
+}
@@ -17,7 +17,7 @@ async fn main() -> Result<()> {
         .await?;
 
     println!("Listing models...");
-    let models = api.list_models().await?;
+    let models = api.list_models(false).await?;
 
     if models.is_empty() {
         println!("No models found in library.");
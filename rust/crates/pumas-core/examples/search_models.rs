@@ -20,7 +20,7 @@ async fn main() -> Result<()> {
         .await?;
 
     println!("Searching for '{}'...", query);
-    let results = api.search_models(query, 10, 0).await?;
+    let results = api.search_models(query, 10, 0, false).await?;
 
     println!("Found {} matches (showing first 10):", results.total_count);
     for model in results.models {
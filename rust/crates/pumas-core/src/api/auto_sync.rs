@@ -0,0 +1,158 @@
+//! Opt-in watch-and-sync of new models into an application version.
+//!
+//! While a target is set, model-library watcher changes schedule an
+//! incremental mapping sync into the target's models directory, so models
+//! that match the mapping rules show up without a manual sync. Syncs are
+//! debounced so a burst of watcher events (a download finishing, a directory
+//! import) results in a single sync.
+
+use crate::api::{reconcile_on_demand, PrimaryState, ReconcileScope};
+use crate::error::{PumasError, Result};
+use crate::model_library::{ConflictResolution, IncrementalSyncResult};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::fs;
+
+/// Quiet period after the last watcher change before an auto-sync runs.
+pub(crate) const AUTO_SYNC_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Where watch-and-sync links new models.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AutoSyncTarget {
+    /// ComfyUI version whose mapping config applies
+    pub version_tag: String,
+    /// The version's models directory
+    pub models_path: PathBuf,
+    /// How targets occupied by unrelated files are handled
+    pub conflict_resolution: ConflictResolution,
+}
+
+/// Primary-local watch-and-sync state. Disabled until a target is set.
+#[derive(Debug, Default)]
+pub(crate) struct AutoSync {
+    target: Mutex<Option<AutoSyncTarget>>,
+    /// Bumped per scheduled sync; only the latest scheduled sync runs.
+    generation: AtomicU64,
+    /// Serializes syncs so two never race on the same targets.
+    running: tokio::sync::Mutex<()>,
+}
+
+impl AutoSync {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn target(&self) -> Option<AutoSyncTarget> {
+        self.target.lock().expect("auto-sync lock poisoned").clone()
+    }
+
+    /// Replace the target; `None` disables auto-sync and cancels pending syncs.
+    pub(crate) fn set_target(&self, target: Option<AutoSyncTarget>) {
+        *self.target.lock().expect("auto-sync lock poisoned") = target;
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn next_generation(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn is_latest(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::Relaxed) == generation
+    }
+}
+
+/// Schedule a debounced auto-sync if a target is set.
+pub(crate) fn schedule_auto_sync(primary: Arc<PrimaryState>) {
+    if primary.auto_sync.target().is_none() {
+        return;
+    }
+
+    let generation = primary.auto_sync.next_generation();
+    let runtime_tasks = primary.runtime_tasks.clone();
+    runtime_tasks.spawn(async move {
+        tokio::time::sleep(AUTO_SYNC_DEBOUNCE).await;
+        if primary.runtime_tasks.shutdown_token().is_cancelled()
+            || !primary.auto_sync.is_latest(generation)
+        {
+            return;
+        }
+        let Some(target) = primary.auto_sync.target() else {
+            return;
+        };
+
+        match run_auto_sync(&primary, &target).await {
+            Ok(result) => {
+                if result.added > 0 || result.removed > 0 {
+                    tracing::info!(
+                        "Auto-sync into {}: {} added, {} removed, {} unchanged",
+                        target.models_path.display(),
+                        result.added,
+                        result.removed,
+                        result.unchanged
+                    );
+                }
+                for (path, error) in &result.errors {
+                    tracing::warn!("Auto-sync failed for {}: {}", path.display(), error);
+                }
+            }
+            Err(err) => tracing::warn!("Auto-sync failed: {}", err),
+        }
+    });
+}
+
+async fn run_auto_sync(
+    primary: &PrimaryState,
+    target: &AutoSyncTarget,
+) -> Result<IncrementalSyncResult> {
+    let _running = primary.auto_sync.running.lock().await;
+
+    // Index the models the watcher just reported before mapping them.
+    reconcile_on_demand(primary, ReconcileScope::AllModels, "auto-sync").await?;
+
+    let models_path = target.models_path.as_path();
+    fs::create_dir_all(models_path)
+        .await
+        .map_err(|err| PumasError::io_with_path(err, models_path))?;
+    primary
+        .model_mapper
+        .create_default_comfyui_config_async("*", models_path)
+        .await?;
+
+    primary
+        .model_mapper
+        .sync_incremental(
+            "comfyui",
+            Some(&target.version_tag),
+            models_path,
+            target.conflict_resolution,
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_latest_scheduled_sync_is_current() {
+        let auto_sync = AutoSync::new();
+        assert!(auto_sync.target().is_none());
+
+        let first = auto_sync.next_generation();
+        let second = auto_sync.next_generation();
+        assert!(!auto_sync.is_latest(first));
+        assert!(auto_sync.is_latest(second));
+
+        auto_sync.set_target(Some(AutoSyncTarget {
+            version_tag: "v0.3.0".to_string(),
+            models_path: PathBuf::from("/versions/v0.3.0/models"),
+            conflict_resolution: ConflictResolution::Skip,
+        }));
+        assert!(!auto_sync.is_latest(second));
+        assert_eq!(auto_sync.target().unwrap().version_tag, "v0.3.0");
+    }
+}
@@ -11,9 +11,10 @@ use crate::api::state::{ApiState, PrimaryState};
 use crate::api::status_telemetry::StatusTelemetryService;
 use crate::api::RuntimeTasks;
 use crate::error::{PumasError, Result};
-use crate::{config, conversion, model_library, network, process, registry, system};
+use crate::{config, conversion, model_library, network, platform, process, registry, system};
 use crate::{ApiInner, PumasApi};
 
+use super::maintenance;
 use super::{
     start_model_library_watcher, ReconciliationCoordinator, WatcherWriteSuppressor,
     WATCHER_WRITE_SUPPRESSION_TTL,
@@ -39,6 +40,16 @@ pub struct PumasApiBuilder {
     auto_create_dirs: bool,
     enable_hf_client: bool,
     enable_process_manager: bool,
+    proxy: Option<network::ProxyConfig>,
+    ca_bundle: Option<PathBuf>,
+    user_agent: Option<String>,
+    hf_auth_token: Option<String>,
+    max_active_downloads: Option<usize>,
+    model_library_dir: Option<PathBuf>,
+    rebuild_corrupt_model_index: bool,
+    orphan_scan: model_library::OrphanScanConfig,
+    scan_ignore_patterns: Vec<String>,
+    model_path_template: model_library::ModelPathTemplate,
 }
 
 struct InstanceClaimGuard {
@@ -102,7 +113,8 @@ fn start_primary_background_work(
 
     {
         let ps = primary_state.clone();
-        runtime_tasks.spawn(async move {
+        let shutdown = runtime_tasks.shutdown_token();
+        runtime_tasks.spawn_startup(async move {
             let recoveries = ps.model_importer.recover_incomplete_shards_async().await;
             if recoveries.is_empty() {
                 return;
@@ -116,6 +128,9 @@ fn start_primary_background_work(
                 return;
             };
             for recovery in recoveries {
+                if shutdown.is_cancelled() {
+                    break;
+                }
                 let request = model_library::DownloadRequest {
                     repo_id: recovery.repo_id.clone(),
                     family: recovery.family,
@@ -131,6 +146,12 @@ fn start_primary_background_work(
                     download_url: None,
                     model_card_json: None,
                     license_status: None,
+                    skip_hash_verification: false,
+                    max_concurrent_files: None,
+                    rate_limit: None,
+                    priority: Default::default(),
+                    include_patterns: None,
+                    exclude_patterns: None,
                 };
                 match client
                     .start_download(&request, &recovery.model_dir, None)
@@ -157,7 +178,8 @@ fn start_primary_background_work(
 
     {
         let ps = primary_state;
-        runtime_tasks.spawn(async move {
+        let shutdown = runtime_tasks.shutdown_token();
+        runtime_tasks.spawn_startup(async move {
             let interrupted = ps
                 .model_importer
                 .find_interrupted_downloads_async(known_download_dirs)
@@ -174,6 +196,9 @@ fn start_primary_background_work(
                 return;
             };
             for item in interrupted {
+                if shutdown.is_cancelled() {
+                    break;
+                }
                 let repo_id = item.repo_id.unwrap_or_else(|| {
                     let dir_name = item
                         .model_dir
@@ -197,6 +222,12 @@ fn start_primary_background_work(
                     download_url: None,
                     model_card_json: None,
                     license_status: None,
+                    skip_hash_verification: false,
+                    max_concurrent_files: None,
+                    rate_limit: None,
+                    priority: Default::default(),
+                    include_patterns: None,
+                    exclude_patterns: None,
                 };
                 match client.start_download(&request, &item.model_dir, None).await {
                     Ok(id) => {
@@ -229,6 +260,16 @@ impl PumasApiBuilder {
             auto_create_dirs: false,
             enable_hf_client: true,
             enable_process_manager: true,
+            proxy: None,
+            ca_bundle: None,
+            user_agent: None,
+            hf_auth_token: None,
+            max_active_downloads: None,
+            model_library_dir: None,
+            rebuild_corrupt_model_index: true,
+            orphan_scan: model_library::OrphanScanConfig::default(),
+            scan_ignore_patterns: Vec::new(),
+            model_path_template: model_library::ModelPathTemplate::default(),
         }
     }
 
@@ -241,6 +282,8 @@ impl PumasApiBuilder {
     /// - `launcher-data/mapping-configs/`
     /// - `shared-resources/models/`
     ///
+    /// A directory set with [`Self::with_model_library_dir`] is created too.
+    ///
     /// Default: `false` (directories must exist)
     pub fn auto_create_dirs(mut self, enable: bool) -> Self {
         self.auto_create_dirs = enable;
@@ -267,20 +310,217 @@ impl PumasApiBuilder {
         self
     }
 
-    /// Create the required directory structure.
-    async fn create_directory_structure(launcher_root: &Path) -> Result<()> {
-        let dirs = [
+    /// Route outbound HTTP traffic through an explicit proxy.
+    ///
+    /// Applies to the HuggingFace, GitHub, download, and connectivity clients
+    /// of the built instance, overriding `HTTPS_PROXY` / `HTTP_PROXY`. Other
+    /// instances in the process are unaffected; host apps pass
+    /// `PumasApi::http_settings` to clients they create themselves. Loopback
+    /// hosts always bypass the proxy.
+    ///
+    /// Default: proxy from the environment, if any
+    pub fn with_proxy(mut self, proxy: network::ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Trust the root certificates in a PEM bundle for outbound HTTPS.
+    ///
+    /// Needed behind TLS-inspecting proxies or with internal CAs. The bundle is
+    /// trusted in addition to the system store by the HuggingFace, GitHub,
+    /// download, and connectivity clients of the built instance. A missing
+    /// or unparsable bundle fails `build()`.
+    ///
+    /// Default: system trust store only
+    pub fn with_ca_bundle(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ca_bundle = Some(path.into());
+        self
+    }
+
+    /// Identify the host app in outbound requests.
+    ///
+    /// The value (e.g. `MyApp/2.3`) is appended to the base
+    /// `pumas-library/1.0` user-agent for the HuggingFace, GitHub, and
+    /// download clients of the built instance.
+    ///
+    /// Default: `pumas-library/1.0`
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Authenticate HuggingFace requests with this token.
+    ///
+    /// The token is used for this instance only and is not written to disk,
+    /// so it does not replace a token saved with `PumasApi::set_hf_token`.
+    ///
+    /// Default: token from the Pumas config, `HF_TOKEN`, or the HuggingFace cache
+    pub fn with_hf_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.hf_auth_token = Some(token.into());
+        self
+    }
+
+    /// Set how many HuggingFace downloads transfer at once.
+    ///
+    /// Further downloads stay queued until a slot frees up. Values below 1
+    /// are raised to 1.
+    ///
+    /// Default: `NetworkConfig::HF_MAX_ACTIVE_DOWNLOADS` (3)
+    pub fn with_max_active_downloads(mut self, max_active: usize) -> Self {
+        self.max_active_downloads = Some(max_active);
+        self
+    }
+
+    /// Keep the model library outside the launcher root.
+    ///
+    /// Lets large model files live on a separate drive while `launcher-data/`
+    /// stays put. Downloads, imports, and app model mappings all use this
+    /// directory. The path must be absolute and must exist unless
+    /// `auto_create_dirs` is enabled.
+    ///
+    /// Default: `PUMAS_MODELS_DIR` if set, else `shared-resources/models/`
+    /// under the launcher root
+    pub fn with_model_library_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.model_library_dir = Some(path.into());
+        self
+    }
+
+    /// Rebuild the model index from `metadata.json` files if it is corrupt.
+    ///
+    /// When enabled, `ModelIndex::new` moves a corrupt `models.db` aside as
+    /// `models.db.corrupt-<timestamp>` and the library rebuilds a fresh index
+    /// from the per-model metadata. When disabled, the model index is
+    /// integrity-checked with the other databases and `build()` fails with
+    /// the file path instead.
+    ///
+    /// Default: `true`
+    pub fn rebuild_corrupt_model_index(mut self, enable: bool) -> Self {
+        self.rebuild_corrupt_model_index = enable;
+        self
+    }
+
+    /// Configure automatic orphan adoption.
+    ///
+    /// Controls the startup scan and the orphan pass of full-library
+    /// reconciles. `PumasApi::scan_orphans` runs regardless of this setting.
+    ///
+    /// Default: automatic, with no library-size limit
+    pub fn with_orphan_scan(mut self, config: model_library::OrphanScanConfig) -> Self {
+        self.orphan_scan = config;
+        self
+    }
+
+    /// Skip extra directories in library scans, orphan adoption, and the watcher.
+    ///
+    /// Patterns are added to the defaults (`.trash`, `.staging`, and hidden
+    /// directories) and to any `.pumasignore` file in the model library root.
+    ///
+    /// Default: no extra patterns
+    pub fn with_scan_ignore_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scan_ignore_patterns = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the directory layout for new models, such as `{family}/{name}`.
+    ///
+    /// Changing the layout does not move models already in the library; the
+    /// model metadata migration moves them to the current layout.
+    ///
+    /// Default: `{type}/{family}/{name}`
+    pub fn with_model_path_template(mut self, template: model_library::ModelPathTemplate) -> Self {
+        self.model_path_template = template;
+        self
+    }
+
+    /// Integrity-check existing databases before any subsystem opens them.
+    ///
+    /// The model index is only checked when automatic rebuild is disabled;
+    /// otherwise `ModelIndex::new` recovers a corrupt index on its own.
+    fn verify_databases(&self, model_library_dir: &Path) -> Result<()> {
+        if let Ok(registry_db) = platform::registry_db_path() {
+            maintenance::verify_database_integrity(
+                "library registry",
+                &registry_db,
+                "Restore it from a backup, or delete it so libraries are re-registered on the next start.",
+            )?;
+        }
+        if self.enable_hf_client {
+            maintenance::verify_database_integrity(
+                "search cache",
+                &Self::search_cache_db_path(&self.launcher_root),
+                "It only holds cached HuggingFace search results; delete it to start fresh.",
+            )?;
+        }
+        if !self.rebuild_corrupt_model_index {
+            maintenance::verify_database_integrity(
+                "model index",
+                &model_library_dir.join(model_library::MODEL_INDEX_DB_FILENAME),
+                "Restore it from a backup, or rebuild it from metadata.json files with \
+                 PumasApiBuilder::rebuild_corrupt_model_index(true).",
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Location of the HuggingFace search cache database.
+    pub(crate) fn search_cache_db_path(launcher_root: &Path) -> PathBuf {
+        launcher_root
+            .join(config::PathsConfig::SHARED_RESOURCES_DIR_NAME)
+            .join(config::PathsConfig::CACHE_DIR_NAME)
+            .join("search.sqlite")
+    }
+
+    /// Directories the launcher expects under `launcher_root`.
+    pub(crate) fn required_directories(launcher_root: &Path) -> Vec<PathBuf> {
+        vec![
             launcher_root.join("launcher-data"),
             launcher_root.join("launcher-data").join("metadata"),
-            launcher_root.join("launcher-data").join("cache"),
-            launcher_root.join("launcher-data").join("cache").join("hf"),
+            config::PathsConfig::cache_dir(launcher_root),
+            config::PathsConfig::cache_dir(launcher_root).join("hf"),
             launcher_root.join("launcher-data").join("mapping-configs"),
             launcher_root.join("launcher-data").join("logs"),
             launcher_root.join("shared-resources"),
-            launcher_root.join("shared-resources").join("models"),
-        ];
+            config::PathsConfig::default_model_library_dir(launcher_root),
+        ]
+    }
 
-        for dir in &dirs {
+    /// Validate a configured model library directory, creating it if allowed.
+    async fn prepare_model_library_dir(path: &Path, create: bool) -> Result<()> {
+        if !path.is_absolute() {
+            return Err(PumasError::Config {
+                message: format!(
+                    "Model library directory must be an absolute path: {}",
+                    path.display()
+                ),
+            });
+        }
+
+        match fs::metadata(path).await {
+            Ok(metadata) if metadata.is_dir() => Ok(()),
+            Ok(_) => Err(PumasError::Config {
+                message: format!("Model library path is not a directory: {}", path.display()),
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && create => {
+                fs::create_dir_all(path).await.map_err(|e| PumasError::Io {
+                    message: format!("Failed to create directory: {}", path.display()),
+                    path: Some(path.to_path_buf()),
+                    source: Some(e),
+                })
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(PumasError::Config {
+                message: format!("Model library directory does not exist: {}", path.display()),
+            }),
+            Err(e) => Err(PumasError::io_with_path(e, path)),
+        }
+    }
+
+    /// Create the required directory structure.
+    async fn create_directory_structure(launcher_root: &Path) -> Result<()> {
+        for dir in &Self::required_directories(launcher_root) {
             if !fs::try_exists(dir)
                 .await
                 .map_err(|e| PumasError::io_with_path(e, dir))?
@@ -298,6 +538,23 @@ impl PumasApiBuilder {
 
     /// Build the PumasApi instance.
     pub async fn build(self) -> Result<PumasApi> {
+        // Resolve and validate every network input before anything is built,
+        // so a bad value fails `build()` with nothing applied.
+        let mut http_settings = network::HttpSettings::default();
+        if let Some(proxy) = &self.proxy {
+            http_settings = http_settings.with_proxy(proxy.clone())?;
+        }
+        if let Some(user_agent) = &self.user_agent {
+            http_settings = http_settings.with_user_agent_suffix(user_agent)?;
+        }
+        if let Some(path) = &self.ca_bundle {
+            let pem = fs::read(path)
+                .await
+                .map_err(|e| PumasError::io_with_path(e, path))?;
+            http_settings =
+                http_settings.with_root_certificates(network::parse_ca_bundle(&pem, path)?);
+        }
+
         // Auto-create directories if requested
         if self.auto_create_dirs {
             // Create launcher_root if it doesn't exist
@@ -332,6 +589,22 @@ impl PumasApiBuilder {
             }
         }
 
+        let model_library_dir = match self
+            .model_library_dir
+            .clone()
+            .or_else(config::PathsConfig::model_library_dir_override)
+        {
+            Some(dir) => {
+                Self::prepare_model_library_dir(&dir, self.auto_create_dirs).await?;
+                dir
+            }
+            None => config::PathsConfig::default_model_library_dir(&self.launcher_root),
+        };
+
+        // Surface corrupt databases here, with the file path, instead of as a
+        // confusing failure deep inside a subsystem.
+        self.verify_databases(&model_library_dir)?;
+
         let registry = registry::LibraryRegistry::open()?;
         let library_name = self
             .launcher_root
@@ -359,12 +632,15 @@ impl PumasApiBuilder {
         let runtime_tasks = RuntimeTasks::default();
 
         // Initialize network manager for connectivity checking
-        let network_manager =
-            Arc::new(
-                network::NetworkManager::new().map_err(|e| PumasError::Config {
-                    message: format!("Failed to initialize network manager: {}", e),
-                })?,
-            );
+        let network_manager = Arc::new(
+            network::NetworkManager::with_http_settings(
+                network::ConnectivityConfig::default(),
+                &http_settings,
+            )
+            .map_err(|e| PumasError::Config {
+                message: format!("Failed to initialize network manager: {}", e),
+            })?,
+        );
 
         // Check initial connectivity (non-blocking, will update state)
         let nm_clone = network_manager.clone();
@@ -392,23 +668,20 @@ impl PumasApiBuilder {
         let system_utils = Arc::new(system::SystemUtils::new(&self.launcher_root));
 
         // Initialize model library for AI model management
-        let model_library_dir = self.launcher_root.join("shared-resources").join("models");
         let mapping_config_dir = self
             .launcher_root
             .join("launcher-data")
             .join("mapping-configs");
 
+        let bandwidth_limiter = std::sync::Arc::new(network::BandwidthLimiter::new(None));
+
         // Initialize HuggingFace client (if enabled)
         let mut hf_client = if self.enable_hf_client {
-            let cache_dir = self
-                .launcher_root
-                .join("launcher-data")
-                .join(config::PathsConfig::CACHE_DIR_NAME);
+            let cache_dir = config::PathsConfig::cache_dir(&self.launcher_root);
             let hf_cache_dir = cache_dir.join("hf");
 
             // Initialize SQLite search cache at shared-resources/cache/search.sqlite
-            let search_cache_dir = self.launcher_root.join("shared-resources").join("cache");
-            let search_cache_db = search_cache_dir.join("search.sqlite");
+            let search_cache_db = Self::search_cache_db_path(&self.launcher_root);
             let search_cache_db_for_task = search_cache_db.clone();
             let search_cache = match tokio::task::spawn_blocking(move || {
                 model_library::HfSearchCache::new(&search_cache_db_for_task)
@@ -436,8 +709,12 @@ impl PumasApiBuilder {
                 std::sync::Arc::new(model_library::DownloadPersistence::new(&data_dir));
 
             let hf_cache_dir_for_task = hf_cache_dir.clone();
+            let http_settings_for_task = http_settings.clone();
             match tokio::task::spawn_blocking(move || {
-                model_library::HuggingFaceClient::new(&hf_cache_dir_for_task)
+                model_library::HuggingFaceClient::with_http_settings(
+                    &hf_cache_dir_for_task,
+                    &http_settings_for_task,
+                )
             })
             .await
             {
@@ -448,6 +725,13 @@ impl PumasApiBuilder {
                     }
                     // Attach download persistence
                     client.set_persistence(download_persistence);
+                    client.set_shared_rate_limiter(bandwidth_limiter.clone());
+                    if let Some(token) = &self.hf_auth_token {
+                        client.set_session_auth_token(token).await;
+                    }
+                    if let Some(max_active) = self.max_active_downloads {
+                        client.set_max_active_downloads(max_active);
+                    }
                     // Restore persisted downloads from previous session
                     client.restore_persisted_downloads().await;
                     Some(client)
@@ -469,11 +753,15 @@ impl PumasApiBuilder {
         };
 
         // Initialize model library (required - core functionality)
-        let model_library = model_library::ModelLibrary::new(&model_library_dir)
-            .await
-            .map_err(|e| PumasError::Config {
-                message: format!("Model library initialization failed: {}", e),
-            })?;
+        let model_library = model_library::ModelLibrary::new_with_ignore_patterns(
+            &model_library_dir,
+            self.scan_ignore_patterns.clone(),
+        )
+        .await
+        .map_err(|e| PumasError::Config {
+            message: format!("Model library initialization failed: {}", e),
+        })?
+        .with_path_template(self.model_path_template.clone());
         let model_library = Arc::new(model_library);
         let watcher_write_suppressor =
             Arc::new(WatcherWriteSuppressor::new(WATCHER_WRITE_SUPPRESSION_TTL));
@@ -483,9 +771,40 @@ impl PumasApiBuilder {
         })));
         let model_mapper =
             model_library::ModelMapper::new(model_library.clone(), &mapping_config_dir);
-        let model_importer = model_library::ModelImporter::new(model_library.clone());
+        match model_mapper.validate_configs_async().await {
+            Ok(report) => {
+                for issue in &report.issues {
+                    tracing::warn!(
+                        "Mapping config {}{}: {}",
+                        issue.file,
+                        issue
+                            .field
+                            .as_deref()
+                            .map(|field| format!(" ({})", field))
+                            .unwrap_or_default(),
+                        issue.message
+                    );
+                }
+            }
+            Err(e) => tracing::warn!("Failed to validate mapping configs: {}", e),
+        }
+        let download_persistence = hf_client
+            .as_ref()
+            .and_then(|client| client.persistence().cloned());
+        let new_scan_importer = |library: Arc<model_library::ModelLibrary>| {
+            let importer = model_library::ModelImporter::new(library)
+                .with_shutdown_token(runtime_tasks.shutdown_token());
+            match download_persistence.clone() {
+                Some(persistence) => importer.with_download_persistence(persistence),
+                None => importer,
+            }
+        };
+        let model_importer = new_scan_importer(model_library.clone());
+        let download_hooks = super::DownloadCompleteHooks::default();
+        let library_events = super::LibraryEventBus::default();
 
         // Wire download completion -> in-place import (metadata + indexing)
+        // -> host callbacks
         if let Some(ref mut client) = hf_client {
             let lib = model_library.clone();
             let tasks = runtime_tasks.clone();
@@ -507,14 +826,32 @@ impl PumasApiBuilder {
 
             let lib = model_library.clone();
             let tasks = runtime_tasks.clone();
+            let hooks = download_hooks.clone();
+            let events = library_events.clone();
             client.set_completion_callback(std::sync::Arc::new(
                 move |info: model_library::DownloadCompletionInfo| {
                     let lib = lib.clone();
+                    let hooks = hooks.clone();
+                    let events = events.clone();
                     tasks.spawn(async move {
                         let importer = model_library::ModelImporter::new(lib);
                         match importer.finalize_downloaded_directory(&info).await {
                             Ok(r) if r.success => {
                                 tracing::info!("Post-download import succeeded: {:?}", r.model_id);
+                                events.publish_import(&r);
+                                if let Some(model_id) = r.model_id {
+                                    events.publish(super::LibraryEvent::DownloadCompleted {
+                                        download_id: info.download_id.clone(),
+                                        repo_id: info.download_request.repo_id.clone(),
+                                        model_id: model_id.clone(),
+                                    });
+                                    hooks.notify(&super::DownloadCompleteEvent {
+                                        download_id: info.download_id,
+                                        repo_id: info.download_request.repo_id,
+                                        model_id,
+                                        model_path: info.dest_dir,
+                                    });
+                                }
                             }
                             Ok(r) => {
                                 tracing::warn!("Post-download import failed: {:?}", r.error);
@@ -536,12 +873,14 @@ impl PumasApiBuilder {
         ));
 
         // Spawn non-blocking orphan scan to adopt models missing metadata
-        {
-            let lib_clone = model_library.clone();
-            let importer = model_library::ModelImporter::new(lib_clone);
+        let orphan_scan_status = super::models::OrphanScanStatus::default();
+        let indexed_models = model_library.index().count().unwrap_or(0);
+        if self.orphan_scan.should_scan_automatically(indexed_models) {
+            let importer = new_scan_importer(model_library.clone());
             if importer.has_orphan_candidates_async().await {
-                runtime_tasks.spawn(async move {
-                    let result = importer.adopt_orphans(false).await;
+                let status = orphan_scan_status.clone();
+                runtime_tasks.spawn_startup(async move {
+                    let result = status.adopt(&importer).await;
                     if result.orphans_found > 0 {
                         tracing::info!(
                             "Startup orphan scan: found={}, adopted={}, errors={}",
@@ -552,22 +891,24 @@ impl PumasApiBuilder {
                     }
                 });
             }
+        } else {
+            tracing::debug!(
+                "Skipping startup orphan scan (indexed models: {})",
+                indexed_models
+            );
         }
 
         // Collect known dest_dirs for interrupted download detection
         // (must happen before hf_client is moved into PrimaryState)
-        let known_download_dirs = load_known_download_dirs(
-            hf_client
-                .as_ref()
-                .and_then(|client| client.persistence().cloned()),
-        )
-        .await;
+        let known_download_dirs = load_known_download_dirs(download_persistence.clone()).await;
 
         let provider_registry = crate::providers::ProviderRegistry::builtin();
         let runtime_provider_adapters = crate::runtime_profiles::RuntimeProviderAdapters::builtin();
         let primary_state = Arc::new(PrimaryState {
             _state: state,
             network_manager,
+            http_settings,
+            bandwidth_limiter,
             process_manager,
             resource_tracker,
             status_telemetry,
@@ -576,13 +917,22 @@ impl PumasApiBuilder {
             model_mapper,
             hf_client,
             model_importer,
+            orphan_scan: self.orphan_scan,
+            orphan_scan_status,
+            import_progress: Arc::new(model_library::ImportProgressTracker::new()),
+            batch_imports: Arc::new(model_library::BatchImportTracker::new()),
+            index_rebuilds: Arc::new(model_library::IndexRebuildTracker::new()),
+            auto_sync: super::AutoSync::new(),
+            download_hooks,
+            library_events,
             conversion_manager,
             runtime_profile_service: Arc::new(
                 crate::runtime_profiles::RuntimeProfileService::with_provider_registry_and_adapters(
                     &self.launcher_root,
                     provider_registry.clone(),
                     runtime_provider_adapters,
-                ),
+                )
+                .with_models_dir(&model_library_dir),
             ),
             serving_service: Arc::new(crate::serving::ServingService::with_provider_registry(
                 provider_registry,
@@ -0,0 +1,499 @@
+//! Diagnostic bundle export for bug reports.
+//!
+//! The bundle is a zip of JSON reports plus redacted config and log tails.
+//! Everything written passes through [`Redactor`], which strips credentials
+//! and replaces the launcher root, model library, and home directory with
+//! placeholders.
+
+use crate::config::{AppId, PathsConfig};
+use crate::error::{PumasError, Result};
+use crate::index::integrity;
+use crate::models;
+use crate::PumasApi;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
+use zip::write::SimpleFileOptions;
+
+/// Apps whose installed versions are summarized.
+const VERSIONED_APPS: &[AppId] = &[
+    AppId::ComfyUI,
+    AppId::Ollama,
+    AppId::OpenWebUI,
+    AppId::InvokeAI,
+    AppId::KritaDiffusion,
+    AppId::LlamaCpp,
+    AppId::Torch,
+];
+/// Most recently modified log files included.
+const MAX_LOG_FILES: usize = 10;
+/// Bytes kept from the end of each log file.
+const MAX_LOG_TAIL_BYTES: u64 = 1024 * 1024;
+/// Config files larger than this are skipped.
+const MAX_CONFIG_FILE_BYTES: u64 = 256 * 1024;
+
+/// Credential assignments such as `"api_key": "..."` or `HF_TOKEN=...`.
+const SECRET_ASSIGNMENT_PATTERN: &str =
+    r#"(?i)("?[a-z_\-]*(?:token|api[_\-]?key|secret|password)"?\s*[:=]\s*"?)[^"\s,}]+"#;
+
+static SECRET_PATTERNS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (r"\bhf_[A-Za-z0-9]{16,}", "hf_<redacted>"),
+        (r"\bsk-[A-Za-z0-9_\-]{16,}", "sk-<redacted>"),
+        (r"(?i)(bearer\s+)[A-Za-z0-9._~+/=\-]+", "${1}<redacted>"),
+        (SECRET_ASSIGNMENT_PATTERN, "${1}<redacted>"),
+    ]
+    .into_iter()
+    .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement))
+    .collect()
+});
+
+/// Strips credentials and user-specific absolute paths from bundle text.
+struct Redactor {
+    /// Path prefixes and their placeholders, longest prefix first. Each
+    /// prefix is also listed in its JSON-escaped form, so Windows paths are
+    /// caught inside serialized reports and JSON config files.
+    paths: Vec<(String, &'static str)>,
+}
+
+impl Redactor {
+    fn new(launcher_root: &Path, models_dir: &Path, home: Option<PathBuf>) -> Self {
+        let mut paths: Vec<(String, &'static str)> = [
+            (Some(models_dir.to_path_buf()), "<models_dir>"),
+            (Some(launcher_root.to_path_buf()), "<launcher_root>"),
+            (home, "~"),
+        ]
+        .into_iter()
+        .filter_map(|(path, placeholder)| Some((path?.display().to_string(), placeholder)))
+        .filter(|(path, _)| path.len() > 1)
+        .collect();
+        let escaped: Vec<(String, &'static str)> = paths
+            .iter()
+            .filter_map(|(path, placeholder)| {
+                let quoted = serde_json::to_string(path).ok()?;
+                let escaped = &quoted[1..quoted.len() - 1];
+                (escaped != path).then(|| (escaped.to_string(), *placeholder))
+            })
+            .collect();
+        paths.extend(escaped);
+        paths.sort_by_key(|(path, _)| std::cmp::Reverse(path.len()));
+        Self { paths }
+    }
+
+    fn redact(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for (pattern, replacement) in SECRET_PATTERNS.iter() {
+            text = pattern.replace_all(&text, *replacement).into_owned();
+        }
+        for (path, placeholder) in &self.paths {
+            text = text.replace(path.as_str(), placeholder);
+        }
+        text
+    }
+
+    fn redact_json(&self, value: &Value) -> Result<Vec<u8>> {
+        Ok(self
+            .redact(&serde_json::to_string_pretty(value)?)
+            .into_bytes())
+    }
+}
+
+/// Files for the zip, collected before anything is written.
+#[derive(Default)]
+struct BundleContents {
+    entries: Vec<(String, Vec<u8>)>,
+    failed_sections: Vec<models::HealthCheckFailure>,
+}
+
+impl BundleContents {
+    fn add_json(&mut self, redactor: &Redactor, name: &str, value: Result<Value>) {
+        match value.and_then(|value| redactor.redact_json(&value)) {
+            Ok(bytes) => self.entries.push((name.to_string(), bytes)),
+            Err(err) => self.fail(name, err),
+        }
+    }
+
+    fn fail(&mut self, section: &str, err: impl std::fmt::Display) {
+        self.failed_sections.push(models::HealthCheckFailure {
+            check: section.to_string(),
+            error: err.to_string(),
+        });
+    }
+}
+
+impl PumasApi {
+    /// Write a zip of redacted diagnostics to `dest` for attaching to bug reports.
+    ///
+    /// The bundle holds a system report (health summary, resources, tool
+    /// checks), SQLite integrity results, library and installed-version
+    /// summaries, launcher config files, and the tails of recent logs. Tokens
+    /// and the launcher root, model library, and home paths are redacted.
+    ///
+    /// When `dest` is an existing directory, a timestamped file is created in
+    /// it. Sections that cannot be collected are listed in `failed_sections`
+    /// instead of failing the export.
+    pub async fn generate_diagnostic_bundle(
+        &self,
+        dest: &Path,
+    ) -> Result<models::DiagnosticBundleResponse> {
+        let dest = if tokio::fs::metadata(dest)
+            .await
+            .is_ok_and(|meta| meta.is_dir())
+        {
+            let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+            dest.join(format!("pumas-diagnostics-{}.zip", stamp))
+        } else {
+            dest.to_path_buf()
+        };
+
+        let redactor = Redactor::new(
+            &self.launcher_root,
+            &self.model_library_dir(),
+            dirs::home_dir(),
+        );
+        let mut contents = BundleContents::default();
+
+        contents.add_json(
+            &redactor,
+            "manifest.json",
+            Ok(json!({
+                "generated_at": chrono::Utc::now().to_rfc3339(),
+                "pumas_version": env!("CARGO_PKG_VERSION"),
+                "os": std::env::consts::OS,
+                "arch": std::env::consts::ARCH,
+            })),
+        );
+        let system_report = self.diagnostic_system_report().await;
+        contents.add_json(&redactor, "system.json", system_report);
+        contents.add_json(
+            &redactor,
+            "databases.json",
+            self.diagnostic_database_report().await,
+        );
+        let library_report = self.diagnostic_library_report().await;
+        contents.add_json(&redactor, "library.json", library_report);
+        contents.add_json(
+            &redactor,
+            "versions.json",
+            Ok(self.diagnostic_version_report()),
+        );
+
+        let launcher_data = self.launcher_data_dir();
+        let collected = tokio::task::spawn_blocking(move || {
+            let mut contents = contents;
+            collect_config_files(&launcher_data, &redactor, &mut contents);
+            collect_log_tails(
+                &launcher_data.join(PathsConfig::LOGS_DIR_NAME),
+                &redactor,
+                &mut contents,
+            );
+            write_bundle(&dest, &contents.entries).map(|()| (dest, contents))
+        })
+        .await
+        .map_err(|err| PumasError::Other(format!("Diagnostic bundle task failed: {}", err)))?;
+        let (dest, contents) = collected?;
+
+        tracing::info!("Wrote diagnostic bundle to {}", dest.display());
+        Ok(models::DiagnosticBundleResponse {
+            success: true,
+            error: None,
+            path: dest.display().to_string(),
+            entries: contents.entries.into_iter().map(|(name, _)| name).collect(),
+            failed_sections: contents.failed_sections,
+        })
+    }
+
+    async fn diagnostic_system_report(&self) -> Result<Value> {
+        let (health, resources, git, uv, brave, setproctitle) = tokio::join!(
+            self.health_summary(),
+            self.get_system_resources(),
+            self.check_git(),
+            self.check_uv(),
+            self.check_brave(),
+            self.check_setproctitle(),
+        );
+        Ok(json!({
+            "health": health,
+            "resources": match resources {
+                Ok(resources) => json!(resources),
+                Err(err) => json!({ "error": err.to_string() }),
+            },
+            "tools": { "git": git, "uv": uv, "brave": brave, "setproctitle": setproctitle },
+        }))
+    }
+
+    async fn diagnostic_database_report(&self) -> Result<Value> {
+        let databases = self.maintenance_databases();
+        tokio::task::spawn_blocking(move || {
+            databases
+                .iter()
+                .map(|(name, path)| {
+                    let size = std::fs::metadata(path).map(|meta| meta.len()).ok();
+                    match integrity::integrity_problems(path) {
+                        Ok(problems) => json!({
+                            "name": name,
+                            "path": path.display().to_string(),
+                            "size_bytes": size,
+                            "ok": problems.is_empty(),
+                            "problems": problems,
+                        }),
+                        Err(err) => json!({
+                            "name": name,
+                            "path": path.display().to_string(),
+                            "size_bytes": size,
+                            "ok": false,
+                            "error": err.to_string(),
+                        }),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map(Value::from)
+        .map_err(|err| PumasError::Other(format!("Database integrity task failed: {}", err)))
+    }
+
+    async fn diagnostic_library_report(&self) -> Result<Value> {
+        let library = &self.primary().model_library;
+        Ok(json!({
+            "library_root": library.library_root().display().to_string(),
+            "indexed_models": library.model_count()?,
+            "stats": library.get_stats().await?,
+        }))
+    }
+
+    /// Installed version directories per app, read straight from disk.
+    fn diagnostic_version_report(&self) -> Value {
+        VERSIONED_APPS
+            .iter()
+            .map(|app| {
+                let dir = self.launcher_root.join(app.versions_dir_name());
+                let mut versions: Vec<String> = std::fs::read_dir(&dir)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                    .collect();
+                versions.sort();
+                (app.as_str().to_string(), json!(versions))
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+}
+
+/// Add launcher JSON/TOML config files: `launcher-data/*` and `launcher-data/config/**`.
+fn collect_config_files(launcher_data: &Path, redactor: &Redactor, contents: &mut BundleContents) {
+    let top_level = walkdir::WalkDir::new(launcher_data)
+        .min_depth(1)
+        .max_depth(1);
+    let config_tree = walkdir::WalkDir::new(launcher_data.join("config")).min_depth(1);
+    for entry in top_level
+        .into_iter()
+        .chain(config_tree)
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let path = entry.path();
+        let is_config = matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("json" | "toml")
+        );
+        let small = entry
+            .metadata()
+            .is_ok_and(|meta| meta.len() <= MAX_CONFIG_FILE_BYTES);
+        if !is_config || !small {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(launcher_data) else {
+            continue;
+        };
+        let name = format!("config/{}", zip_path(relative));
+        match std::fs::read_to_string(path) {
+            Ok(text) => contents
+                .entries
+                .push((name, redactor.redact(&text).into_bytes())),
+            Err(err) => contents.fail(&name, err),
+        }
+    }
+}
+
+/// Add the tails of the most recently modified files in the logs directory.
+fn collect_log_tails(logs_dir: &Path, redactor: &Redactor, contents: &mut BundleContents) {
+    let Ok(entries) = std::fs::read_dir(logs_dir) else {
+        return;
+    };
+    let mut logs: Vec<(SystemTime, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| {
+                (
+                    meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                    entry.path(),
+                )
+            })
+        })
+        .collect();
+    logs.sort_by(|a, b| b.0.cmp(&a.0));
+
+    for (_, path) in logs.into_iter().take(MAX_LOG_FILES) {
+        let name = format!(
+            "logs/{}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        );
+        match read_tail(&path, MAX_LOG_TAIL_BYTES) {
+            Ok(text) => contents
+                .entries
+                .push((name, redactor.redact(&text).into_bytes())),
+            Err(err) => contents.fail(&name, err),
+        }
+    }
+}
+
+/// Read at most the last `max_bytes` of a file as lossy UTF-8.
+fn read_tail(path: &Path, max_bytes: u64) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(max_bytes)))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn zip_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Write entries to a sibling temp file, then rename it over `dest`.
+fn write_bundle(dest: &Path, entries: &[(String, Vec<u8>)]) -> Result<()> {
+    let mut partial = dest.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+
+    let write = || -> Result<()> {
+        let file = std::fs::File::create(&partial)
+            .map_err(|err| PumasError::io_with_path(err, &partial))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, bytes) in entries {
+            zip.start_file(name.as_str(), options)
+                .map_err(|err| PumasError::Other(format!("Failed to add {}: {}", name, err)))?;
+            zip.write_all(bytes)?;
+        }
+        zip.finish()
+            .map_err(|err| PumasError::Other(format!("Failed to finish zip: {}", err)))?;
+        std::fs::rename(&partial, dest).map_err(|err| PumasError::io_with_path(err, dest))
+    };
+    write().inspect_err(|_| {
+        let _ = std::fs::remove_file(&partial);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn redactor_strips_tokens_and_user_paths() {
+        let redactor = Redactor::new(
+            Path::new("/home/alice/pumas"),
+            Path::new("/mnt/models"),
+            Some(PathBuf::from("/home/alice")),
+        );
+
+        let text = redactor.redact(
+            "token=hf_abcdefghijklmnopqrstuv at /home/alice/pumas/launcher-data \
+             models /mnt/models/llm cache /home/alice/.cache \
+             {\"api_key\": \"secret-value\"} Authorization: Bearer abc.def",
+        );
+
+        assert!(!text.contains("hf_abcdefghijklmnopqrstuv"), "{text}");
+        assert!(!text.contains("secret-value"), "{text}");
+        assert!(!text.contains("abc.def"), "{text}");
+        assert!(!text.contains("alice"), "{text}");
+        assert!(text.contains("<launcher_root>/launcher-data"), "{text}");
+        assert!(text.contains("<models_dir>/llm"), "{text}");
+        assert!(text.contains("~/.cache"), "{text}");
+    }
+
+    #[test]
+    fn redactor_strips_escaped_windows_paths_from_json() {
+        let redactor = Redactor::new(
+            Path::new(r"C:\Users\alice\pumas"),
+            Path::new(r"D:\models"),
+            Some(PathBuf::from(r"C:\Users\alice")),
+        );
+        let value = json!({
+            "launcher_root": r"C:\Users\alice\pumas\launcher-data",
+            "models": r"D:\models\llm",
+            "cache": r"C:\Users\alice\.cache",
+        });
+
+        let text = String::from_utf8(redactor.redact_json(&value).unwrap()).unwrap();
+
+        assert!(!text.contains("alice"), "{text}");
+        assert!(text.contains(r"<launcher_root>\\launcher-data"), "{text}");
+        assert!(text.contains(r"<models_dir>\\llm"), "{text}");
+        assert!(text.contains(r"~\\.cache"), "{text}");
+    }
+
+    #[tokio::test]
+    async fn generate_diagnostic_bundle_writes_redacted_zip() {
+        let temp_dir = TempDir::new().unwrap();
+        let api = PumasApi::builder(temp_dir.path())
+            .auto_create_dirs(true)
+            .with_hf_client(false)
+            .with_process_manager(false)
+            .build()
+            .await
+            .unwrap();
+        let logs_dir = api.launcher_data_dir().join(PathsConfig::LOGS_DIR_NAME);
+        std::fs::write(
+            logs_dir.join("pumas-rpc.log"),
+            format!(
+                "loaded {} with hf_abcdefghijklmnopqrstuv\n",
+                temp_dir.path().display()
+            ),
+        )
+        .unwrap();
+
+        let response = api
+            .generate_diagnostic_bundle(temp_dir.path())
+            .await
+            .unwrap();
+
+        assert!(response.success);
+        for entry in [
+            "manifest.json",
+            "system.json",
+            "databases.json",
+            "logs/pumas-rpc.log",
+        ] {
+            assert!(
+                response.entries.iter().any(|name| name == entry),
+                "{entry} missing from {:?}",
+                response.entries
+            );
+        }
+        let mut archive =
+            zip::ZipArchive::new(std::fs::File::open(&response.path).unwrap()).unwrap();
+        let mut log = String::new();
+        archive
+            .by_name("logs/pumas-rpc.log")
+            .unwrap()
+            .read_to_string(&mut log)
+            .unwrap();
+        assert_eq!(log, "loaded <launcher_root> with hf_<redacted>\n");
+    }
+}
@@ -0,0 +1,100 @@
+//! Host callbacks for HuggingFace downloads that finished importing.
+//!
+//! The HF client reports a completed transfer to the primary instance, which
+//! imports the download in place. Once that import succeeds, every callback
+//! registered with [`crate::PumasApi::on_download_complete`] receives a
+//! [`DownloadCompleteEvent`], in registration order.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// A download that was imported into the library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadCompleteEvent {
+    /// ID of the finished download
+    pub download_id: String,
+    /// HuggingFace repository the files came from
+    pub repo_id: String,
+    /// Library model ID assigned by the import
+    pub model_id: String,
+    /// Model directory inside the library
+    pub model_path: PathBuf,
+}
+
+/// Callback registered with [`crate::PumasApi::on_download_complete`].
+pub type DownloadCompleteCallback = Arc<dyn Fn(&DownloadCompleteEvent) + Send + Sync + 'static>;
+
+/// Primary-local list of download-complete callbacks.
+#[derive(Clone, Default)]
+pub(crate) struct DownloadCompleteHooks {
+    callbacks: Arc<Mutex<Vec<DownloadCompleteCallback>>>,
+}
+
+impl DownloadCompleteHooks {
+    pub(crate) fn register(&self, callback: DownloadCompleteCallback) {
+        self.callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(callback);
+    }
+
+    /// Call every registered callback with `event`.
+    pub(crate) fn notify(&self, event: &DownloadCompleteEvent) {
+        // Snapshot so a callback may register another without deadlocking.
+        let callbacks = self
+            .callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        for callback in callbacks {
+            callback(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> DownloadCompleteEvent {
+        DownloadCompleteEvent {
+            download_id: "download-1".to_string(),
+            repo_id: "owner/model".to_string(),
+            model_id: "llm/owner/model".to_string(),
+            model_path: PathBuf::from("/library/llm/owner/model"),
+        }
+    }
+
+    #[test]
+    fn test_notify_calls_every_callback_in_registration_order() {
+        let hooks = DownloadCompleteHooks::default();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        for name in ["first", "second"] {
+            let calls = calls.clone();
+            hooks.register(Arc::new(move |event: &DownloadCompleteEvent| {
+                calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{name}:{}", event.model_id));
+            }));
+        }
+
+        hooks.notify(&event());
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["first:llm/owner/model", "second:llm/owner/model"]
+        );
+    }
+
+    #[test]
+    fn test_callback_may_register_another_callback() {
+        let hooks = DownloadCompleteHooks::default();
+        let inner = hooks.clone();
+        hooks.register(Arc::new(move |_: &DownloadCompleteEvent| {
+            inner.register(Arc::new(|_: &DownloadCompleteEvent| {}));
+        }));
+
+        hooks.notify(&event());
+        assert_eq!(hooks.callbacks.lock().unwrap().len(), 2);
+    }
+}
@@ -98,6 +98,11 @@ impl PumasApi {
     // HuggingFace Methods
     // ========================================
 
+    /// Returns true if the HuggingFace client was enabled at build time.
+    pub fn is_hf_client_enabled(&self) -> bool {
+        self.primary().hf_client.is_some()
+    }
+
     /// Search for models on HuggingFace.
     ///
     /// Uses intelligent caching to minimize API calls:
@@ -283,11 +288,12 @@ impl PumasApi {
             resolved_request.model_type = Some(model_type.clone());
             let dest_dir = primary
                 .model_library
-                .prepare_artifact_download_destination(
+                .prepare_artifact_download_destination_async(
                     &model_type,
                     &architecture_family,
                     &selected_artifact.artifact_id,
-                )?;
+                )
+                .await?;
             if model_type == "unknown" {
                 warn!(
                     "Download {} is starting with unknown model_type after HF metadata lookup; destination={}",
@@ -300,9 +306,16 @@ impl PumasApi {
                 evidence.requested_pipeline_tag = request.pipeline_tag.clone();
                 evidence.requested_quant = request.quant.clone();
             }
-            client
+            let download_id = client
                 .start_download(&resolved_request, &dest_dir, huggingface_evidence)
-                .await
+                .await?;
+            primary
+                .library_events
+                .publish(super::LibraryEvent::DownloadStarted {
+                    download_id: download_id.clone(),
+                    repo_id: request.repo_id.clone(),
+                });
+            Ok(download_id)
         } else {
             Err(PumasError::Config {
                 message: "HuggingFace client not initialized".to_string(),
@@ -349,6 +362,34 @@ impl PumasApi {
         }
     }
 
+    /// Change the queue priority of a HuggingFace download.
+    pub async fn reorder_hf_download(
+        &self,
+        download_id: &str,
+        priority: model_library::DownloadPriority,
+    ) -> Result<bool> {
+        if let Some(ref client) = self.primary().hf_client {
+            client.reorder_download(download_id, priority).await
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Register a callback fired after a HuggingFace download imports
+    /// successfully, with the download ID, repo ID, model ID, and model
+    /// directory.
+    ///
+    /// Callbacks compose: each registration adds one, and all run in
+    /// registration order on the import task, so long-running work should be
+    /// handed off. Downloads that fail or whose import fails never fire, and
+    /// callbacks cannot be removed once registered.
+    pub fn on_download_complete<F>(&self, callback: F)
+    where
+        F: Fn(&crate::DownloadCompleteEvent) + Send + Sync + 'static,
+    {
+        self.primary().download_hooks.register(Arc::new(callback));
+    }
+
     /// List all HuggingFace downloads (active, paused, completed, etc.).
     pub async fn list_hf_downloads(&self) -> Vec<models::ModelDownloadProgress> {
         if let Some(ref client) = self.primary().hf_client {
@@ -510,6 +551,12 @@ impl PumasApi {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         client.start_download(&request, &dest, None).await
@@ -0,0 +1,161 @@
+//! Typed fan-out of library changes.
+//!
+//! The primary instance publishes a [`LibraryEvent`] whenever a model is
+//! imported or deleted, a HuggingFace download starts or finishes importing,
+//! or mapping links an app version. Subscribers get their own
+//! `tokio::sync::broadcast` receiver from
+//! [`crate::PumasApi::subscribe_library_events`]; a receiver that falls more
+//! than `LIBRARY_EVENT_CHANNEL_CAPACITY` events behind sees `Lagged` and should
+//! re-read whatever state it mirrors.
+
+use crate::models;
+use crate::PumasApi;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::sync::broadcast;
+
+const LIBRARY_EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// A change to the library or to what is linked from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LibraryEvent {
+    /// A model was imported and indexed.
+    ModelImported { model_id: String },
+    /// A model and its links were deleted.
+    ModelDeleted { model_id: String },
+    /// A HuggingFace download was queued.
+    DownloadStarted {
+        download_id: String,
+        repo_id: String,
+    },
+    /// A HuggingFace download finished and was imported as `model_id`.
+    DownloadCompleted {
+        download_id: String,
+        repo_id: String,
+        model_id: String,
+    },
+    /// An app version finished installing.
+    VersionInstalled { app_id: String, tag: String },
+    /// Mapping created links for an app version.
+    MappingApplied {
+        version_tag: String,
+        models_path: PathBuf,
+        links_created: usize,
+    },
+}
+
+/// Primary-local sender for [`LibraryEvent`]s.
+#[derive(Clone)]
+pub(crate) struct LibraryEventBus {
+    sender: broadcast::Sender<LibraryEvent>,
+}
+
+impl Default for LibraryEventBus {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(LIBRARY_EVENT_CHANNEL_CAPACITY).0,
+        }
+    }
+}
+
+impl LibraryEventBus {
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<LibraryEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Send `event` to current subscribers; with none, it is dropped.
+    pub(crate) fn publish(&self, event: LibraryEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Publish [`LibraryEvent::ModelImported`] for a successful import.
+    pub(crate) fn publish_import(&self, result: &models::ModelImportResult) {
+        if let (true, Some(model_id)) = (result.success, &result.model_id) {
+            self.publish(LibraryEvent::ModelImported {
+                model_id: model_id.clone(),
+            });
+        }
+    }
+
+    /// Publish [`LibraryEvent::MappingApplied`] for a finished mapping pass.
+    pub(crate) fn publish_mapping(
+        &self,
+        version_tag: &str,
+        models_path: &Path,
+        links_created: usize,
+    ) {
+        self.publish(LibraryEvent::MappingApplied {
+            version_tag: version_tag.to_string(),
+            models_path: models_path.to_path_buf(),
+            links_created,
+        });
+    }
+}
+
+impl PumasApi {
+    /// Subscribe to typed library change events.
+    ///
+    /// Only events published after this call are received.
+    pub fn subscribe_library_events(&self) -> broadcast::Receiver<LibraryEvent> {
+        self.primary().library_events.subscribe()
+    }
+
+    /// Sender for events the library cannot observe itself, such as
+    /// [`LibraryEvent::VersionInstalled`] from the app manager.
+    ///
+    /// The sender can be moved into the host task that sees the change.
+    pub fn library_event_sender(&self) -> broadcast::Sender<LibraryEvent> {
+        self.primary().library_events.sender.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import_result(success: bool, model_id: Option<&str>) -> models::ModelImportResult {
+        models::ModelImportResult {
+            path: "/imports/model.safetensors".to_string(),
+            success,
+            model_id: model_id.map(ToOwned::to_owned),
+            model_path: None,
+            error: None,
+            security_tier: None,
+        }
+    }
+
+    #[test]
+    fn test_publish_import_only_reports_successful_imports() {
+        let bus = LibraryEventBus::default();
+        let mut events = bus.subscribe();
+
+        bus.publish_import(&import_result(false, None));
+        bus.publish_import(&import_result(true, Some("llm/owner/model")));
+
+        assert_eq!(
+            events.try_recv().unwrap(),
+            LibraryEvent::ModelImported {
+                model_id: "llm/owner/model".to_string()
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_library_event_serializes_with_type_tag() {
+        let event = LibraryEvent::DownloadStarted {
+            download_id: "download-1".to_string(),
+            repo_id: "owner/model".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": "download_started",
+                "download_id": "download-1",
+                "repo_id": "owner/model",
+            })
+        );
+    }
+}
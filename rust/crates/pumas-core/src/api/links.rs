@@ -1,6 +1,7 @@
 //! Link-registry and link-health methods on `PumasApi`.
 
 use crate::error::Result;
+use crate::model_library;
 use crate::models;
 use crate::PumasApi;
 use std::io::ErrorKind;
@@ -85,6 +86,61 @@ impl PumasApi {
         })
     }
 
+    /// Remove every model link created for an app.
+    ///
+    /// Deletes the link files and their registry entries. With `delete_models`,
+    /// models that were linked into this app and are not linked by any other
+    /// app are deleted from the library as well.
+    pub async fn remove_links_for_app(
+        &self,
+        app_id: &str,
+        delete_models: bool,
+    ) -> Result<models::RemoveAppLinksResponse> {
+        let model_library = &self.primary().model_library;
+        let registry = model_library.link_registry().write().await;
+        let links = registry.get_links_for_app(app_id).await;
+
+        let mut links_removed = 0;
+        let mut errors = Vec::new();
+        let mut linked_models = std::collections::BTreeSet::new();
+        for link in links {
+            if path_exists(&link.target).await? || path_is_symlink(&link.target).await? {
+                if let Err(err) = fs::remove_file(&link.target).await {
+                    errors.push(format!("{}: {}", link.target.display(), err));
+                    continue;
+                }
+            }
+            registry.unregister(&link.target).await?;
+            linked_models.insert(link.model_id);
+            links_removed += 1;
+        }
+
+        let mut models_to_delete = Vec::new();
+        if delete_models {
+            for model_id in linked_models {
+                if registry.get_links_for_model(&model_id).await.is_empty() {
+                    models_to_delete.push(model_id);
+                }
+            }
+        }
+        drop(registry);
+
+        let mut deleted_models = Vec::new();
+        for model_id in models_to_delete {
+            match model_library.delete_model(&model_id, true).await {
+                Ok(()) => deleted_models.push(model_id),
+                Err(err) => errors.push(format!("{}: {}", model_id, err)),
+            }
+        }
+
+        Ok(models::RemoveAppLinksResponse {
+            success: errors.is_empty(),
+            links_removed,
+            deleted_models,
+            errors,
+        })
+    }
+
     /// Get all links for a specific model.
     pub async fn get_links_for_model(
         &self,
@@ -116,16 +172,31 @@ impl PumasApi {
         &self,
         model_id: &str,
     ) -> Result<models::DeleteModelResponse> {
-        self.primary()
-            .model_library
-            .delete_model(model_id, true)
-            .await?;
+        let primary = self.primary();
+        primary.model_library.delete_model(model_id, true).await?;
+        primary
+            .library_events
+            .publish(super::LibraryEvent::ModelDeleted {
+                model_id: model_id.to_string(),
+            });
         Ok(models::DeleteModelResponse {
             success: true,
             error: None,
         })
     }
 
+    /// Report the hardlink count of each file in a model directory.
+    ///
+    /// Files with more than one link keep their data on disk after the model
+    /// is deleted, so the delete confirmation can say how much space stays
+    /// in use.
+    pub async fn file_link_count(
+        &self,
+        model_id: &str,
+    ) -> Result<Vec<model_library::ModelFileLinkCount>> {
+        self.primary().model_library.file_link_count(model_id).await
+    }
+
     /// Toggle whether a model is excluded from app linking.
     pub fn set_model_link_exclusion(
         &self,
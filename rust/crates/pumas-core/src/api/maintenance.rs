@@ -0,0 +1,193 @@
+//! SQLite database maintenance and startup integrity checks.
+
+use crate::error::{PumasError, Result};
+use crate::index::integrity;
+use crate::models;
+use crate::platform;
+use crate::PumasApi;
+use rusqlite::{Connection, OpenFlags};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long maintenance waits for another connection to release its lock.
+const MAINTENANCE_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Combined size of a database file and its WAL sidecar.
+fn database_size(path: &Path) -> u64 {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    [path.to_path_buf(), PathBuf::from(wal)]
+        .iter()
+        .filter_map(|file| std::fs::metadata(file).ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+fn checkpoint_wal(conn: &Connection) -> Result<()> {
+    // PRAGMA wal_checkpoint returns a row, so it must go through query_row.
+    let _: i64 = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))?;
+    Ok(())
+}
+
+/// Checkpoint, vacuum, and analyze one database through a dedicated connection.
+fn compact_database(path: &Path) -> Result<()> {
+    // Open without CREATE so a missing database is reported, not created.
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+    conn.busy_timeout(MAINTENANCE_BUSY_TIMEOUT)?;
+
+    checkpoint_wal(&conn)?;
+    conn.execute_batch("VACUUM; ANALYZE;")?;
+    // VACUUM rewrites pages through the WAL; truncate it again afterwards.
+    checkpoint_wal(&conn)
+}
+
+/// Check that a database opens cleanly and passes `PRAGMA integrity_check`.
+///
+/// Missing files pass: the owning subsystem creates them on first open. On
+/// failure the error names the file and the recovery the caller suggested.
+pub(super) fn verify_database_integrity(name: &str, path: &Path, recovery: &str) -> Result<()> {
+    if !path.is_file() {
+        return Ok(());
+    }
+    let Some(problem) = integrity::corruption(path)? else {
+        return Ok(());
+    };
+    Err(PumasError::Database {
+        message: format!(
+            "The {} database at {} is corrupted ({}). {}",
+            name,
+            path.display(),
+            problem,
+            recovery
+        ),
+        source: None,
+    })
+}
+
+fn maintain_database(name: &str, path: &Path) -> models::DatabaseMaintenanceResult {
+    let size_before = database_size(path);
+    let error = compact_database(path).err().map(|err| err.to_string());
+    let size_after = database_size(path);
+
+    models::DatabaseMaintenanceResult {
+        name: name.to_string(),
+        path: path.display().to_string(),
+        size_before,
+        size_after,
+        reclaimed_bytes: size_before.saturating_sub(size_after),
+        error,
+    }
+}
+
+impl PumasApi {
+    /// SQLite databases owned by this library, keyed by a short name.
+    ///
+    /// Only databases that exist on disk are listed.
+    pub(super) fn maintenance_databases(&self) -> Vec<(&'static str, PathBuf)> {
+        let mut databases = vec![
+            ("models", self.primary().model_library.db_path()),
+            (
+                "search_cache",
+                super::PumasApiBuilder::search_cache_db_path(&self.launcher_root),
+            ),
+        ];
+        if let Ok(path) = platform::registry_db_path() {
+            databases.push(("registry", path));
+        }
+        databases.retain(|(_, path)| path.is_file());
+        databases
+    }
+
+    /// Compact every SQLite database and refresh its query-planner statistics.
+    ///
+    /// Runs a WAL checkpoint, `VACUUM`, and `ANALYZE` on each database and
+    /// reports the bytes reclaimed. Intended for idle periods: a database that
+    /// stays locked by an active writer is reported with an error and skipped.
+    pub async fn run_maintenance(&self) -> Result<models::DatabaseMaintenanceReport> {
+        let databases = self.maintenance_databases();
+        let results = tokio::task::spawn_blocking(move || {
+            databases
+                .iter()
+                .map(|(name, path)| maintain_database(name, path))
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|err| PumasError::Other(format!("Database maintenance task failed: {}", err)))?;
+
+        for result in &results {
+            match &result.error {
+                Some(err) => tracing::warn!("Maintenance failed for {}: {}", result.path, err),
+                None => tracing::info!(
+                    "Compacted {} database, reclaimed {} bytes",
+                    result.name,
+                    result.reclaimed_bytes
+                ),
+            }
+        }
+
+        let failed = results
+            .iter()
+            .filter(|result| result.error.is_some())
+            .count();
+        Ok(models::DatabaseMaintenanceReport {
+            success: failed == 0,
+            error: (failed > 0).then(|| format!("{} database(s) could not be compacted", failed)),
+            total_reclaimed_bytes: results.iter().map(|result| result.reclaimed_bytes).sum(),
+            databases: results,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn maintain_database_reclaims_space_from_deleted_rows() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("churn.sqlite");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "PRAGMA journal_mode=WAL;
+                 CREATE TABLE blobs (id INTEGER PRIMARY KEY, data BLOB);
+                 WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 200)
+                 INSERT INTO blobs (data) SELECT zeroblob(8192) FROM n;
+                 DELETE FROM blobs;",
+            )
+            .unwrap();
+        }
+
+        let result = maintain_database("churn", &path);
+
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert!(result.reclaimed_bytes > 0);
+        assert_eq!(result.size_after, database_size(&path));
+    }
+
+    #[test]
+    fn verify_database_integrity_names_the_corrupt_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("broken.sqlite");
+        assert!(verify_database_integrity("broken", &path, "Delete it.").is_ok());
+
+        std::fs::write(&path, vec![0xAB; 4096]).unwrap();
+        let err = verify_database_integrity("broken", &path, "Delete it.").unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains(&path.display().to_string()));
+        assert!(message.ends_with("Delete it."));
+    }
+
+    #[test]
+    fn maintain_database_does_not_create_missing_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("missing.sqlite");
+
+        let result = maintain_database("missing", &path);
+
+        assert!(result.error.is_some());
+        assert!(!path.exists());
+    }
+}
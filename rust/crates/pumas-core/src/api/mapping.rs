@@ -183,6 +183,9 @@ impl PumasApi {
             .model_mapper
             .apply_mapping("comfyui", Some(version_tag), models_path)
             .await?;
+        primary
+            .library_events
+            .publish_mapping(version_tag, models_path, result.created);
 
         Ok(models::MappingApplyResponse {
             success: true,
@@ -193,24 +196,78 @@ impl PumasApi {
         })
     }
 
+    /// Validate every mapping config file, reporting problems with file and field.
+    pub async fn validate_mapping_configs(
+        &self,
+    ) -> Result<model_library::MappingConfigValidationReport> {
+        self.primary().model_mapper.validate_configs_async().await
+    }
+
     /// Perform incremental sync of models for a version.
     ///
+    /// Only links that differ from the link registry are created or removed.
     /// The caller (RPC layer) is responsible for providing the models_path.
     pub async fn sync_models_incremental(
         &self,
         version_tag: &str,
         models_path: &Path,
     ) -> Result<models::SyncModelsResponse> {
-        let result = self.apply_model_mapping(version_tag, models_path).await?;
+        if !path_exists(models_path).await? {
+            fs::create_dir_all(models_path)
+                .await
+                .map_err(|err| crate::error::PumasError::io_with_path(err, models_path))?;
+        }
+
+        let primary = self.primary();
+        primary
+            .model_mapper
+            .create_default_comfyui_config_async("*", models_path)
+            .await?;
+
+        let result = primary
+            .model_mapper
+            .sync_incremental(
+                "comfyui",
+                Some(version_tag),
+                models_path,
+                model_library::ConflictResolution::Skip,
+            )
+            .await?;
+        primary
+            .library_events
+            .publish_mapping(version_tag, models_path, result.added);
 
         Ok(models::SyncModelsResponse {
-            success: result.success,
-            error: result.error,
-            synced: result.links_created,
-            errors: vec![],
+            success: result.errors.is_empty(),
+            error: None,
+            synced: result.added,
+            links_added: result.added,
+            links_removed: result.removed,
+            links_unchanged: result.unchanged,
+            errors: result
+                .errors
+                .iter()
+                .map(|(path, error)| format!("{}: {}", path.display(), error))
+                .collect(),
         })
     }
 
+    /// Enable (or retarget) watch-and-sync, or disable it with `None`.
+    ///
+    /// While enabled, models the library watcher detects are linked into the
+    /// target's models directory by a debounced incremental sync. Enabling
+    /// also schedules a sync so existing models are linked.
+    pub fn set_auto_sync(&self, target: Option<crate::AutoSyncTarget>) {
+        let primary = self.primary();
+        primary.auto_sync.set_target(target);
+        super::schedule_auto_sync(primary.clone());
+    }
+
+    /// Current watch-and-sync target, if enabled.
+    pub fn auto_sync_target(&self) -> Option<crate::AutoSyncTarget> {
+        self.primary().auto_sync.target()
+    }
+
     /// Apply model mapping with per-path conflict resolutions.
     pub async fn sync_with_resolutions(
         &self,
@@ -248,6 +305,9 @@ impl PumasApi {
                 &typed_resolutions,
             )
             .await?;
+        primary
+            .library_events
+            .publish_mapping(version_tag, models_path, result.created);
 
         let errors: Vec<String> = result
             .errors
@@ -272,7 +332,49 @@ impl PumasApi {
         })
     }
 
+    /// Return whether symlinks can be created on the library's filesystem.
+    ///
+    /// When false (for example Windows without Developer Mode, or a FAT/exFAT
+    /// drive), mapping falls back to hardlinks or copies.
+    pub fn can_create_symlinks(&self) -> bool {
+        self.primary().model_library.can_create_symlinks()
+    }
+
+    /// Link type tried first when mapping models into app directories.
+    pub async fn default_link_type(&self) -> Result<model_library::LinkType> {
+        self.primary().model_mapper.default_link_type_async().await
+    }
+
+    /// Persist the link type tried first when mapping models into app directories.
+    ///
+    /// Other link types remain fallbacks; use [`Self::check_link_type`] or
+    /// [`Self::get_cross_filesystem_warning`] to report an infeasible choice.
+    pub async fn set_default_link_type(&self, link_type: model_library::LinkType) -> Result<()> {
+        self.primary()
+            .model_mapper
+            .set_default_link_type_async(link_type)
+            .await
+    }
+
+    /// Check whether links of `link_type` can be created in `app_models_path`.
+    pub async fn check_link_type(
+        &self,
+        link_type: model_library::LinkType,
+        app_models_path: &Path,
+    ) -> Result<model_library::LinkTypeCheck> {
+        let model_mapper = self.primary().model_mapper.clone();
+        let app_models_path = app_models_path.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            model_mapper.check_link_type(link_type, &app_models_path)
+        })
+        .await
+        .map_err(|err| PumasError::Other(format!("Failed to join check_link_type task: {}", err)))?
+    }
+
     /// Return whether library and app version paths are on different filesystems.
+    ///
+    /// Also reports whether the default link type works for the app path,
+    /// since hardlinks cannot cross filesystems.
     pub async fn get_cross_filesystem_warning(
         &self,
         app_models_path: &Path,
@@ -283,59 +385,59 @@ impl PumasApi {
         let model_mapper = primary.model_mapper.clone();
         let app_models_path = app_models_path.to_path_buf();
 
+        let mut response = models::CrossFilesystemWarningResponse {
+            success: true,
+            error: None,
+            cross_filesystem: false,
+            library_path: Some(library_root),
+            app_path: Some(app_path),
+            warning: None,
+            recommendation: None,
+            default_link_type: None,
+            link_type_warning: None,
+        };
+
         match tokio::task::spawn_blocking(move || {
-            model_mapper.check_cross_filesystem(&app_models_path)
+            let cross_filesystem = model_mapper.check_cross_filesystem(&app_models_path)?;
+            let link_type_check = model_mapper
+                .check_link_type(model_mapper.default_link_type()?, &app_models_path)?;
+            Ok::<_, PumasError>((cross_filesystem, link_type_check))
         })
         .await
         {
-            Ok(Ok(cross_filesystem)) if cross_filesystem => {
-                models::CrossFilesystemWarningResponse {
-                    success: true,
-                    error: None,
-                    cross_filesystem: true,
-                    library_path: Some(library_root),
-                    app_path: Some(app_path),
-                    warning: Some(
+            Ok(Ok((cross_filesystem, link_type_check))) => {
+                response.cross_filesystem = cross_filesystem;
+                if cross_filesystem {
+                    response.warning = Some(
                         "Model library and app version directory are on different filesystems."
                             .to_string(),
-                    ),
-                    recommendation: Some(
+                    );
+                    response.recommendation = Some(
                         "Prefer keeping both directories on the same filesystem for best link behavior."
                             .to_string(),
-                    ),
+                    );
                 }
+                response.default_link_type = Some(match link_type_check.link_type {
+                    model_library::LinkType::Symlink => models::LinkType::Symlink,
+                    model_library::LinkType::Hardlink => models::LinkType::Hardlink,
+                    model_library::LinkType::Copy => models::LinkType::Copy,
+                });
+                response.link_type_warning = link_type_check.reason;
             }
-            Ok(Ok(_)) => models::CrossFilesystemWarningResponse {
-                success: true,
-                error: None,
-                cross_filesystem: false,
-                library_path: Some(library_root),
-                app_path: Some(app_path),
-                warning: None,
-                recommendation: None,
-            },
-            Ok(Err(err)) => models::CrossFilesystemWarningResponse {
-                success: false,
-                error: Some(err.to_string()),
-                cross_filesystem: false,
-                library_path: Some(library_root),
-                app_path: Some(app_path),
-                warning: None,
-                recommendation: None,
-            },
-            Err(err) => models::CrossFilesystemWarningResponse {
-                success: false,
-                error: Some(format!(
+            Ok(Err(err)) => {
+                response.success = false;
+                response.error = Some(err.to_string());
+            }
+            Err(err) => {
+                response.success = false;
+                response.error = Some(format!(
                     "Failed to join get_cross_filesystem_warning task: {}",
                     err
-                )),
-                cross_filesystem: false,
-                library_path: Some(library_root),
-                app_path: Some(app_path),
-                warning: None,
-                recommendation: None,
-            },
+                ));
+            }
         }
+
+        response
     }
 }
 
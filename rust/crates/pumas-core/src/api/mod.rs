@@ -3,10 +3,15 @@
 //! Each submodule contains `impl PumasApi` blocks that extend the public API
 //! with domain-specific methods. The struct definitions remain in `lib.rs`.
 
+mod auto_sync;
 mod builder;
 mod conversion;
+mod diagnostics;
+mod download_hooks;
 mod hf;
+mod library_events;
 mod links;
+mod maintenance;
 mod mapping;
 mod migration;
 mod models;
@@ -25,7 +30,13 @@ mod state_runtime_profiles;
 mod status_telemetry;
 mod system;
 
+pub use auto_sync::AutoSyncTarget;
+pub(crate) use auto_sync::{schedule_auto_sync, AutoSync};
 pub use builder::PumasApiBuilder;
+pub(crate) use download_hooks::DownloadCompleteHooks;
+pub use download_hooks::{DownloadCompleteCallback, DownloadCompleteEvent};
+pub use library_events::LibraryEvent;
+pub(crate) use library_events::LibraryEventBus;
 pub(crate) use reconciliation::{
     reconcile_on_demand, start_model_library_watcher, ReconcileScope, ReconciliationCoordinator,
     WatcherWriteSuppressor, WATCHER_WRITE_SUPPRESSION_TTL,
@@ -1,8 +1,9 @@
 //! Model library methods on PumasApi.
 
 use super::{reconcile_on_demand, ReconcileScope};
+use crate::cancel::CancellationToken;
 use crate::error::{PumasError, Result};
-use crate::index::{ModelRecord, SearchResult};
+use crate::index::{ModelRecord, SearchOptions, SearchResult};
 use crate::model_library;
 use crate::models;
 use crate::PumasApi;
@@ -13,6 +14,120 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
 
+/// Progress of in-flight orphan adoption, surfaced through library status.
+#[derive(Clone, Default)]
+pub(crate) struct OrphanScanStatus(Arc<std::sync::Mutex<Option<models::DeepScanProgress>>>);
+
+impl OrphanScanStatus {
+    /// Stage label reported while orphans are being adopted.
+    const STAGE: &'static str = "Adopting orphans";
+
+    /// Current progress, or `None` when no adoption is running.
+    pub(crate) fn snapshot(&self) -> Option<models::DeepScanProgress> {
+        self.0.lock().ok().and_then(|progress| progress.clone())
+    }
+
+    fn publish(&self, progress: Option<models::DeepScanProgress>) {
+        if let Ok(mut slot) = self.0.lock() {
+            *slot = progress;
+        }
+    }
+
+    /// Adopt orphans, publishing progress until the scan finishes.
+    pub(crate) async fn adopt(
+        &self,
+        importer: &model_library::ModelImporter,
+    ) -> model_library::OrphanScanResult {
+        let result = importer
+            .adopt_orphans_with_progress(
+                false,
+                false,
+                Some(|progress: model_library::OrphanScanProgress| {
+                    self.publish(Some(models::DeepScanProgress {
+                        current: progress.current as u32,
+                        total: progress.total as u32,
+                        stage: Self::STAGE.to_string(),
+                    }))
+                }),
+            )
+            .await;
+        self.publish(None);
+        result
+    }
+}
+
+/// Spawn a tracked import on the primary's runtime and return its import ID.
+pub(crate) fn start_tracked_import(
+    primary: &super::PrimaryState,
+    spec: model_library::ModelImportSpec,
+) -> String {
+    let reporter = primary.import_progress.start(&spec.path);
+    let import_id = reporter.import_id().to_string();
+    let importer = primary.model_importer.clone();
+    let events = primary.library_events.clone();
+    primary.runtime_tasks.spawn(async move {
+        let result = importer
+            .import_tracked(&spec, &reporter)
+            .await
+            .unwrap_or_else(|err| model_library::ModelImportResult {
+                path: spec.path.clone(),
+                success: false,
+                model_id: None,
+                model_path: None,
+                error: Some(err.to_string()),
+                security_tier: None,
+            });
+        events.publish_import(&result);
+        reporter.finish(result);
+    });
+    import_id
+}
+
+/// Spawn a batch import on the primary's runtime and return its batch ID.
+pub(crate) fn start_tracked_batch_import(
+    primary: &super::PrimaryState,
+    specs: Vec<model_library::ModelImportSpec>,
+) -> String {
+    let tracker = primary.batch_imports.clone();
+    let batch_id = tracker.start(specs.len());
+    let importer = primary.model_importer.clone();
+    let events = primary.library_events.clone();
+    let id = batch_id.clone();
+    primary.runtime_tasks.spawn(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+        let forward = async {
+            while let Some(progress) = rx.recv().await {
+                tracker.update(&id, progress);
+            }
+        };
+        let cancel = CancellationToken::new();
+        let (results, ()) = tokio::join!(importer.batch_import(specs, Some(tx), &cancel), forward);
+        results
+            .iter()
+            .for_each(|result| events.publish_import(result));
+    });
+    batch_id
+}
+
+/// Spawn an index rebuild on the primary's runtime and return its rebuild ID.
+pub(crate) fn start_tracked_rebuild(primary: &super::PrimaryState) -> String {
+    let tracker = primary.index_rebuilds.clone();
+    let (rebuild_id, cancel) = tracker.start();
+    let library = primary.model_library.clone();
+    let id = rebuild_id.clone();
+    primary.runtime_tasks.spawn(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+        let forward = async {
+            while let Some(progress) = rx.recv().await {
+                tracker.update(&id, progress);
+            }
+        };
+        let (result, ()) = tokio::join!(library.rebuild_index_with_progress(tx, &cancel), forward);
+        tracker.finish(&id, &result);
+    });
+    rebuild_id
+}
+
 async fn path_exists(path: &Path) -> Result<bool> {
     fs::try_exists(path)
         .await
@@ -168,8 +283,11 @@ impl PumasApi {
     // Model Library Methods
     // ========================================
 
-    /// List all models in the library.
-    pub async fn list_models(&self) -> Result<Vec<ModelRecord>> {
+    /// List models in the library.
+    ///
+    /// Models that are still downloading, importing, or failed are left out
+    /// unless `include_incomplete` is set.
+    pub async fn list_models(&self, include_incomplete: bool) -> Result<Vec<ModelRecord>> {
         let primary = self.primary();
         let _ = reconcile_on_demand(
             primary.as_ref(),
@@ -177,15 +295,61 @@ impl PumasApi {
             "api-list-models",
         )
         .await?;
-        primary.model_library.list_models().await
+        let mut models = primary.model_library.list_models().await?;
+        if !include_incomplete {
+            models.retain(ModelRecord::is_ready);
+        }
+        Ok(models)
     }
 
     /// Search models using full-text search.
+    ///
+    /// Models that are not ready are left out unless `include_incomplete` is
+    /// set.
     pub async fn search_models(
         &self,
         query: &str,
         limit: usize,
         offset: usize,
+        include_incomplete: bool,
+    ) -> Result<SearchResult> {
+        self.search_models_with_options(
+            query,
+            limit,
+            offset,
+            include_incomplete,
+            SearchOptions::default(),
+        )
+        .await
+    }
+
+    /// Search models with field filters and ordering.
+    ///
+    /// `query` accepts field filters such as `type:llm`, `family:llama`,
+    /// `tag:chat`, and `size>4gb` alongside free text.
+    pub async fn search_models_with_options(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+        include_incomplete: bool,
+        options: SearchOptions,
+    ) -> Result<SearchResult> {
+        let mut result = self
+            .search_all_models(query, limit, offset, &options)
+            .await?;
+        if !include_incomplete {
+            result.retain_ready();
+        }
+        Ok(result)
+    }
+
+    async fn search_all_models(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+        options: &SearchOptions,
     ) -> Result<SearchResult> {
         let primary = self.primary();
 
@@ -198,13 +362,13 @@ impl PumasApi {
             .await?;
             return primary
                 .model_library
-                .search_models(query, limit, offset)
+                .search_models_with_options(query, limit, offset, options.clone())
                 .await;
         }
 
         let mut result = primary
             .model_library
-            .search_models(query, limit, offset)
+            .search_models_with_options(query, limit, offset, options.clone())
             .await?;
         let mut model_ids = HashSet::new();
         for model in &result.models {
@@ -227,7 +391,7 @@ impl PumasApi {
         if reconciled_any {
             result = primary
                 .model_library
-                .search_models(query, limit, offset)
+                .search_models_with_options(query, limit, offset, options.clone())
                 .await?;
         }
 
@@ -251,6 +415,36 @@ impl PumasApi {
         load_model_count(primary.model_library.clone()).await
     }
 
+    /// Start rebuilding the model index from metadata files in the background.
+    ///
+    /// Returns a rebuild ID to poll with
+    /// [`get_rebuild_progress`](Self::get_rebuild_progress). Unlike
+    /// [`rebuild_model_index`](Self::rebuild_model_index) this skips the
+    /// reconciliation pass and only re-reads `metadata.json` files.
+    pub fn start_rebuild_model_index(&self) -> String {
+        start_tracked_rebuild(self.primary())
+    }
+
+    /// Get progress for a rebuild started with
+    /// [`start_rebuild_model_index`](Self::start_rebuild_model_index).
+    pub fn get_rebuild_progress(&self, rebuild_id: &str) -> Option<model_library::IndexRebuildJob> {
+        self.primary().index_rebuilds.get(rebuild_id)
+    }
+
+    /// Cancel a running rebuild. The index is left as it was unless index
+    /// writes had already begun, in which case the rebuild completes.
+    ///
+    /// Returns `false` for unknown or finished rebuilds.
+    pub fn cancel_rebuild_model_index(&self, rebuild_id: &str) -> bool {
+        self.primary().index_rebuilds.cancel(rebuild_id)
+    }
+
+    /// Reload one model's metadata and re-upsert its index row without a
+    /// full rebuild.
+    pub async fn refresh_model(&self, model_id: &str) -> Result<()> {
+        self.primary().model_library.refresh_model(model_id).await
+    }
+
     /// Get model-library status information for GUI polling.
     pub async fn get_library_status(&self) -> Result<models::LibraryStatusResponse> {
         let primary = self.primary();
@@ -263,15 +457,16 @@ impl PumasApi {
 
         let model_count = load_model_count(primary.model_library.clone()).await? as u32;
         let pending_lookups = primary.model_library.get_pending_lookups().await?.len() as u32;
+        let orphan_progress = primary.orphan_scan_status.snapshot();
 
         Ok(models::LibraryStatusResponse {
             success: true,
             error: None,
             indexing: false,
-            deep_scan_in_progress: false,
+            deep_scan_in_progress: orphan_progress.is_some(),
             model_count,
             pending_lookups: Some(pending_lookups),
-            deep_scan_progress: None,
+            deep_scan_progress: orphan_progress,
         })
     }
 
@@ -445,6 +640,22 @@ impl PumasApi {
         })
     }
 
+    /// Add and remove tags on a model and return its resulting tags.
+    ///
+    /// See [`model_library::ModelLibrary::update_model_tags`] for matching
+    /// and validation rules. Search reflects the change immediately.
+    pub async fn update_model_tags(
+        &self,
+        model_id: &str,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> Result<Vec<String>> {
+        self.primary()
+            .model_library
+            .update_model_tags(model_id, add, remove)
+            .await
+    }
+
     /// Resolve deterministic dependency requirements for a model in a specific runtime context.
     pub async fn resolve_model_dependency_requirements(
         &self,
@@ -602,6 +813,30 @@ impl PumasApi {
             .await
     }
 
+    /// Report the security tier of every model, flagging pickle and unidentified formats.
+    pub async fn security_audit(&self) -> Result<model_library::SecurityAuditReport> {
+        self.primary().model_library.security_audit().await
+    }
+
+    /// Re-hash one model's files against its metadata without a library scan.
+    pub async fn verify_local_model(
+        &self,
+        model_id: &str,
+    ) -> Result<model_library::ModelVerifyResult> {
+        self.primary().model_library.verify_model(model_id).await
+    }
+
+    /// Scan external directories for model files that are not in the library yet.
+    pub async fn scan_shared_storage(
+        &self,
+        extra_paths: Vec<std::path::PathBuf>,
+    ) -> Result<model_library::SharedStorageScanReport> {
+        self.primary()
+            .model_library
+            .scan_shared_storage(extra_paths)
+            .await
+    }
+
     /// List models that currently require metadata review.
     pub async fn list_models_needing_review(
         &self,
@@ -660,29 +895,120 @@ impl PumasApi {
         &self,
         spec: &model_library::ModelImportSpec,
     ) -> Result<model_library::ModelImportResult> {
-        self.primary().model_importer.import(spec).await
+        let primary = self.primary();
+        let result = primary.model_importer.import(spec).await?;
+        primary.library_events.publish_import(&result);
+        Ok(result)
+    }
+
+    /// Check what importing `spec` would do without writing anything.
+    pub async fn validate_import_spec(
+        &self,
+        spec: &model_library::ModelImportSpec,
+    ) -> Result<model_library::ImportSpecValidation> {
+        self.primary().model_importer.validate_spec(spec).await
+    }
+
+    /// Show where an import or download of this model would be stored.
+    ///
+    /// Nothing is created; `exists` flags a conflicting directory.
+    pub fn preview_model_path(
+        &self,
+        model_type: &str,
+        family: &str,
+        official_name: &str,
+    ) -> model_library::ModelPathPreview {
+        self.primary()
+            .model_library
+            .preview_model_path(model_type, family, official_name)
+    }
+
+    /// Start importing a model in the background.
+    ///
+    /// Returns an import ID to poll with [`get_import_progress`](Self::get_import_progress).
+    pub fn start_import(&self, spec: model_library::ModelImportSpec) -> String {
+        start_tracked_import(self.primary(), spec)
+    }
+
+    /// Get stage and byte progress for an import started with [`start_import`](Self::start_import).
+    pub fn get_import_progress(&self, import_id: &str) -> Option<model_library::ImportJobProgress> {
+        self.primary().import_progress.get(import_id)
     }
 
     /// Import multiple models in batch.
+    ///
+    /// Cancelling `cancel` aborts the in-flight import and marks the remaining
+    /// items cancelled; completed items keep their results.
     pub async fn import_models_batch(
         &self,
         specs: Vec<model_library::ModelImportSpec>,
+        cancel: &CancellationToken,
     ) -> Vec<model_library::ModelImportResult> {
-        self.primary()
-            .model_importer
-            .batch_import(specs, None)
+        self.run_import_batch(specs, None, cancel).await
+    }
+
+    /// Import multiple models in batch, sending aggregate progress on `progress_tx`.
+    ///
+    /// Each item produces an update when it starts (with its spec index in
+    /// `current_index`) and when it finishes (with running success/failure
+    /// counts), followed by a final update once the batch is done.
+    pub async fn import_models_batch_with_progress(
+        &self,
+        specs: Vec<model_library::ModelImportSpec>,
+        progress_tx: tokio::sync::mpsc::Sender<model_library::BatchImportProgress>,
+        cancel: &CancellationToken,
+    ) -> Vec<model_library::ModelImportResult> {
+        self.run_import_batch(specs, Some(progress_tx), cancel)
             .await
     }
 
+    /// Start a batch import in the background.
+    ///
+    /// Returns a batch ID to poll with
+    /// [`get_batch_import_progress`](Self::get_batch_import_progress), for
+    /// callers that cannot hold a channel.
+    pub fn start_import_models_batch(&self, specs: Vec<model_library::ModelImportSpec>) -> String {
+        start_tracked_batch_import(self.primary(), specs)
+    }
+
+    /// Get aggregate progress for a batch started with
+    /// [`start_import_models_batch`](Self::start_import_models_batch).
+    pub fn get_batch_import_progress(
+        &self,
+        batch_id: &str,
+    ) -> Option<model_library::BatchImportProgress> {
+        self.primary().batch_imports.get(batch_id)
+    }
+
+    async fn run_import_batch(
+        &self,
+        specs: Vec<model_library::ModelImportSpec>,
+        progress_tx: Option<tokio::sync::mpsc::Sender<model_library::BatchImportProgress>>,
+        cancel: &CancellationToken,
+    ) -> Vec<model_library::ModelImportResult> {
+        let primary = self.primary();
+        let results = primary
+            .model_importer
+            .batch_import(specs, progress_tx, cancel)
+            .await;
+        results
+            .iter()
+            .for_each(|result| primary.library_events.publish_import(result));
+        results
+    }
+
     /// Register an external diffusers directory without copying its contents.
     pub async fn import_external_diffusers_directory(
         &self,
         spec: &model_library::ExternalDiffusersImportSpec,
     ) -> Result<model_library::ModelImportResult> {
-        self.primary()
+        let primary = self.primary();
+        let result = primary
             .model_importer
             .import_external_diffusers_directory(spec)
-            .await
+            .await?;
+        primary.library_events.publish_import(&result);
+        Ok(result)
     }
 
     /// Classify import paths without creating any library state.
@@ -718,19 +1044,47 @@ impl PumasApi {
             validate_existing_local_directory_path(spec.model_dir.to_string_lossy().as_ref())
                 .await?;
 
-        self.primary()
+        let primary = self.primary();
+        let result = primary
             .model_importer
             .import_in_place(&validated_spec)
-            .await
+            .await?;
+        primary.library_events.publish_import(&result);
+        Ok(result)
     }
 
     /// Scan for and adopt orphan model directories.
     ///
     /// Finds directories in the library with model files but no `metadata.json`,
     /// creates metadata from directory structure and file type detection, and
-    /// indexes the models.
-    pub async fn adopt_orphan_models(&self) -> Result<model_library::OrphanScanResult> {
-        Ok(self.primary().model_importer.adopt_orphans(false).await)
+    /// indexes the models. With `dry_run` nothing is written; the result lists
+    /// each orphan's detected type, proposed model ID, and ambiguities instead.
+    pub async fn adopt_orphan_models(
+        &self,
+        dry_run: bool,
+    ) -> Result<model_library::OrphanScanResult> {
+        if dry_run {
+            return Ok(self
+                .primary()
+                .model_importer
+                .adopt_orphans(false, true)
+                .await);
+        }
+        self.scan_orphans().await
+    }
+
+    /// Run an orphan scan on demand.
+    ///
+    /// Runs even when automatic scans are disabled with
+    /// [`crate::PumasApiBuilder::with_orphan_scan`], for libraries that only
+    /// want adoption after an external import. Progress is reported through
+    /// `deep_scan_progress` in [`Self::get_library_status`] while it runs.
+    pub async fn scan_orphans(&self) -> Result<model_library::OrphanScanResult> {
+        let primary = self.primary();
+        Ok(primary
+            .orphan_scan_status
+            .adopt(&primary.model_importer)
+            .await)
     }
 
     /// Reclassify a single model (re-detect type and relocate directory if needed).
@@ -75,6 +75,37 @@ impl PumasApi {
         }
     }
 
+    /// Cap combined throughput of all active downloads, in bytes per second.
+    ///
+    /// The budget is shared across every download stream of this instance, so
+    /// concurrent downloads split it. `None` or `Some(0)` removes the cap.
+    /// Streams already waiting on the old cap pick up the new one immediately.
+    pub fn set_global_bandwidth_limit(&self, bytes_per_sec: Option<u64>) {
+        self.primary().bandwidth_limiter.set_limit(bytes_per_sec);
+    }
+
+    /// Current global download bandwidth cap, if any.
+    pub fn global_bandwidth_limit(&self) -> Option<u64> {
+        self.primary().bandwidth_limiter.limit()
+    }
+
+    /// Bandwidth budget this instance's downloads draw from.
+    ///
+    /// Host apps pass it to `DownloadManager::with_bandwidth_limiter` so their
+    /// downloads count against [`Self::set_global_bandwidth_limit`].
+    pub fn bandwidth_limiter(&self) -> Arc<network::BandwidthLimiter> {
+        self.primary().bandwidth_limiter.clone()
+    }
+
+    /// Proxy, CA, and user-agent settings this instance's HTTP clients use.
+    ///
+    /// Host apps pass these to clients they create alongside the API (for
+    /// example app version managers) so every request follows the builder's
+    /// network configuration.
+    pub fn http_settings(&self) -> network::HttpSettings {
+        self.primary().http_settings.clone()
+    }
+
     /// Get the network manager for advanced operations.
     pub fn network_manager(&self) -> &Arc<network::NetworkManager> {
         &self.primary().network_manager
@@ -210,6 +210,25 @@ impl PumasApi {
         &self,
         tag: &str,
         version_dir: &std::path::Path,
+    ) -> Result<models::LaunchResponse> {
+        self.launch_torch_at(
+            tag,
+            version_dir,
+            "127.0.0.1",
+            crate::config::AppId::Torch.default_port(),
+        )
+        .await
+    }
+
+    /// Launch the Torch inference server bound to `host:port`.
+    ///
+    /// Used to start the server on the address saved in its configuration.
+    pub async fn launch_torch_at(
+        &self,
+        tag: &str,
+        version_dir: &std::path::Path,
+        host: &str,
+        port: u16,
     ) -> Result<models::LaunchResponse> {
         if !path_exists(version_dir).await? {
             return Ok(models::LaunchResponse {
@@ -232,9 +251,10 @@ impl PumasApi {
             let log_dir = self.launcher_data_dir().join("logs");
             let tag = tag.to_string();
             let version_dir = version_dir.to_path_buf();
+            let host = host.to_string();
 
             let result = tokio::task::spawn_blocking(move || {
-                pm.launch_torch(&tag, &version_dir, Some(&log_dir))
+                pm.launch_torch_at(&tag, &version_dir, &host, port, Some(&log_dir))
             })
             .await
             .map_err(|e| PumasError::Other(format!("Failed to join launch_torch task: {}", e)))?;
@@ -328,4 +348,18 @@ impl PumasApi {
             None
         }
     }
+
+    /// Enable or disable titling ComfyUI processes at launch.
+    ///
+    /// Returns whether launches will be titled; this stays `false` on
+    /// platforms that still need the setproctitle patch.
+    pub async fn set_native_process_title(&self, enabled: bool) -> bool {
+        let mgr_lock = self.primary().process_manager.read().await;
+        if let Some(ref mgr) = *mgr_lock {
+            mgr.set_native_process_title(enabled);
+            mgr.native_process_title()
+        } else {
+            false
+        }
+    }
 }
@@ -127,6 +127,14 @@ impl ReconciliationCoordinator {
         model_state.last_dirty_instant = Some(Instant::now());
     }
 
+    /// True when no scope has unreconciled changes or a reconcile in flight.
+    pub(crate) async fn is_settled(&self) -> bool {
+        let state = self.state.lock().await;
+        std::iter::once(&state.all)
+            .chain(state.models.values())
+            .all(|scope| !scope.in_flight && !has_unreconciled_dirty(scope))
+    }
+
     pub(crate) async fn try_start(&self, scope: &ReconcileScope, force: bool) -> bool {
         let mut state = self.state.lock().await;
 
@@ -278,9 +286,12 @@ pub(crate) fn start_model_library_watcher(
     let runtime_tasks = primary.runtime_tasks.clone();
     let library_root = primary.model_library.library_root().to_path_buf();
 
+    let scan_ignore = primary.model_library.scan_ignore().clone();
+
     ModelLibraryWatcher::new(
         library_root,
         NetworkConfig::FILE_WATCHER_DEBOUNCE,
+        scan_ignore,
         Box::new(move |paths| {
             let primary = primary_for_watcher.clone();
             let runtime_tasks = runtime_tasks.clone();
@@ -291,7 +302,13 @@ pub(crate) fn start_model_library_watcher(
     )
 }
 
-fn model_id_from_path(library_root: &Path, path: &Path) -> Option<String> {
+/// Directory depth of `{type}/{family}/{name}` model IDs.
+///
+/// Download recovery splits model IDs back into type, family, and name, so it
+/// always reads download destinations in the default layout.
+const TYPE_FAMILY_NAME_DEPTH: usize = 3;
+
+fn model_id_from_path(library_root: &Path, model_depth: usize, path: &Path) -> Option<String> {
     let rel = path.strip_prefix(library_root).ok()?;
     let components: Vec<String> = rel
         .components()
@@ -301,14 +318,11 @@ fn model_id_from_path(library_root: &Path, path: &Path) -> Option<String> {
         })
         .collect();
 
-    if components.len() < 3 {
+    if components.len() < model_depth {
         return None;
     }
 
-    Some(format!(
-        "{}/{}/{}",
-        components[0], components[1], components[2]
-    ))
+    Some(components[..model_depth].join("/"))
 }
 
 fn is_internal_library_artifact_path(library_root: &Path, path: &Path) -> bool {
@@ -360,7 +374,12 @@ pub(crate) async fn notify_filesystem_changes(primary: Arc<PrimaryState>, paths:
     let WatcherChangeSummary {
         model_ids,
         requires_full_scope,
-    } = classify_watcher_changes(&library_root, &primary.watcher_write_suppressor, paths);
+    } = classify_watcher_changes(
+        &library_root,
+        primary.model_library.path_template().depth(),
+        &primary.watcher_write_suppressor,
+        paths,
+    );
 
     if !requires_full_scope && model_ids.is_empty() {
         return;
@@ -385,10 +404,13 @@ pub(crate) async fn notify_filesystem_changes(primary: Arc<PrimaryState>, paths:
         )
         .await;
     }
+
+    super::schedule_auto_sync(primary);
 }
 
 fn classify_watcher_changes(
     library_root: &Path,
+    model_depth: usize,
     suppressor: &WatcherWriteSuppressor,
     paths: Vec<PathBuf>,
 ) -> WatcherChangeSummary {
@@ -404,11 +426,11 @@ fn classify_watcher_changes(
             summary.requires_full_scope = true;
             continue;
         };
-        if depth < 3 {
+        if depth < model_depth {
             continue;
         }
 
-        if let Some(model_id) = model_id_from_path(library_root, &path) {
+        if let Some(model_id) = model_id_from_path(library_root, model_depth, &path) {
             summary.model_ids.insert(model_id);
         } else {
             summary.requires_full_scope = true;
@@ -715,7 +737,7 @@ fn candidate_from_persisted(
     if !persisted.dest_dir.starts_with(library_root) {
         return None;
     }
-    let model_id = model_id_from_path(library_root, &persisted.dest_dir)?;
+    let model_id = model_id_from_path(library_root, TYPE_FAMILY_NAME_DEPTH, &persisted.dest_dir)?;
     let (_path_model_type, path_family, _cleaned_name) = split_model_id(&model_id);
     let request = &persisted.download_request;
     let mut expected_files = if !persisted.filenames.is_empty() {
@@ -755,7 +777,8 @@ fn candidate_from_interrupted(
     if !interrupted.model_dir.starts_with(library_root) {
         return None;
     }
-    let model_id = model_id_from_path(library_root, &interrupted.model_dir)?;
+    let model_id =
+        model_id_from_path(library_root, TYPE_FAMILY_NAME_DEPTH, &interrupted.model_dir)?;
     let (_path_model_type, _path_family, cleaned_name) = split_model_id(&model_id);
     let inferred_repo = interrupted.repo_id.or_else(|| {
         cleaned_name
@@ -1198,12 +1221,21 @@ async fn reconcile_model_scope(primary: &PrimaryState, model_id: &str) -> Result
 async fn run_scope(primary: &PrimaryState, scope: &ReconcileScope) -> Result<()> {
     match scope {
         ReconcileScope::AllModels => {
-            let orphan_result = primary.model_importer.adopt_orphans(false).await;
-            if !orphan_result.errors.is_empty() {
-                tracing::warn!(
-                    "Reconcile(all): orphan adoption had {} errors",
-                    orphan_result.errors.len()
-                );
+            let indexed_models = primary.model_library.index().count().unwrap_or(0);
+            if primary
+                .orphan_scan
+                .should_scan_automatically(indexed_models)
+            {
+                let orphan_result = primary
+                    .orphan_scan_status
+                    .adopt(&primary.model_importer)
+                    .await;
+                if !orphan_result.errors.is_empty() {
+                    tracing::warn!(
+                        "Reconcile(all): orphan adoption had {} errors",
+                        orphan_result.errors.len()
+                    );
+                }
             }
 
             let pre_cleanup = tokio::task::spawn_blocking({
@@ -1334,6 +1366,24 @@ mod tests {
         assert!(coordinator.try_start(&scope, false).await);
     }
 
+    #[tokio::test]
+    async fn test_is_settled_until_model_marked_dirty() {
+        let coordinator =
+            ReconciliationCoordinator::new(Duration::from_secs(5), Duration::from_secs(5));
+        let scope = ReconcileScope::Model("llm/test/model".to_string());
+        assert!(coordinator.is_settled().await);
+
+        coordinator.mark_dirty_model("llm/test/model").await;
+        assert!(!coordinator.is_settled().await);
+
+        assert!(coordinator.try_start(&scope, false).await);
+        assert!(!coordinator.is_settled().await);
+        coordinator
+            .complete(&scope, "2026-03-11T00:00:00Z".to_string())
+            .await;
+        assert!(coordinator.is_settled().await);
+    }
+
     #[test]
     fn test_partial_download_dir_is_not_importable() {
         let temp = TempDir::new().unwrap();
@@ -1400,6 +1450,7 @@ mod tests {
         suppressor.record(metadata_path);
         let summary = classify_watcher_changes(
             root,
+            3,
             &suppressor,
             vec![model_dir, sibling_config, sibling_weights, external_path],
         );
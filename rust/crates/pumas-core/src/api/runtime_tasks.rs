@@ -1,14 +1,29 @@
 //! Runtime task ownership for primary API background work.
 
+use crate::cancel::CancellationToken;
 use std::future::Future;
 use std::sync::{Arc, Mutex};
 use tokio::runtime::Handle;
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
 #[derive(Clone)]
 pub(crate) struct RuntimeTasks {
     handle: Handle,
     inner: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    pending_startup: Arc<watch::Sender<usize>>,
+    /// Root shutdown token; cancelled before tracked tasks are aborted.
+    shutdown: CancellationToken,
+}
+
+/// Decrements the pending startup count when a startup task finishes or is aborted.
+struct StartupTaskGuard(Arc<watch::Sender<usize>>);
+
+impl Drop for StartupTaskGuard {
+    fn drop(&mut self) {
+        self.0
+            .send_modify(|pending| *pending = pending.saturating_sub(1));
+    }
 }
 
 impl RuntimeTasks {
@@ -16,9 +31,19 @@ impl RuntimeTasks {
         Self {
             handle: Handle::current(),
             inner: Arc::new(Mutex::new(Vec::new())),
+            pending_startup: Arc::new(watch::Sender::new(0)),
+            shutdown: CancellationToken::new(),
         }
     }
 
+    /// Token cancelled when the primary shuts down.
+    ///
+    /// Background loops check it between units of work so they stop at a
+    /// clean boundary instead of being aborted in the middle of a write.
+    pub(crate) fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown.clone()
+    }
+
     fn prune_finished(handles: &mut Vec<JoinHandle<()>>) {
         handles.retain(|handle| !handle.is_finished());
     }
@@ -27,13 +52,37 @@ impl RuntimeTasks {
     where
         F: Future<Output = ()> + Send + 'static,
     {
+        if self.shutdown.is_cancelled() {
+            tracing::debug!("Skipping background task spawned during shutdown");
+            return;
+        }
         let handle = self.handle.spawn(task);
         let mut handles = self.inner.lock().expect("runtime task owner poisoned");
         Self::prune_finished(&mut handles);
         handles.push(handle);
     }
 
+    /// Spawn a tracked task that counts toward startup completion.
+    pub(crate) fn spawn_startup<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.pending_startup.send_modify(|pending| *pending += 1);
+        let guard = StartupTaskGuard(self.pending_startup.clone());
+        self.spawn(async move {
+            let _guard = guard;
+            task.await;
+        });
+    }
+
+    /// Wait until every task spawned with [`spawn_startup`](Self::spawn_startup) has finished.
+    pub(crate) async fn wait_for_startup(&self) {
+        let mut receiver = self.pending_startup.subscribe();
+        let _ = receiver.wait_for(|pending| *pending == 0).await;
+    }
+
     pub(crate) fn shutdown(&self) {
+        self.shutdown.cancel();
         let mut handles = self.inner.lock().expect("runtime task owner poisoned");
         Self::prune_finished(&mut handles);
         for handle in handles.drain(..) {
@@ -90,6 +139,44 @@ mod tests {
         assert_eq!(tasks.tracked_count(), 0);
     }
 
+    #[tokio::test]
+    async fn shutdown_cancels_token_and_skips_new_tasks() {
+        let tasks = RuntimeTasks::default();
+        let token = tasks.shutdown_token();
+        assert!(!token.is_cancelled());
+
+        tasks.shutdown();
+        assert!(token.is_cancelled());
+
+        let (ran_tx, ran_rx) = oneshot::channel::<()>();
+        tasks.spawn(async move {
+            let _ = ran_tx.send(());
+        });
+        assert_eq!(tasks.tracked_count(), 0);
+        assert!(
+            ran_rx.await.is_err(),
+            "task spawned after shutdown must not run"
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_startup_resolves_after_startup_tasks_finish() {
+        let tasks = RuntimeTasks::default();
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+
+        tasks.spawn_startup(async move {
+            let _ = release_rx.await;
+        });
+        assert!(timeout(Duration::from_millis(50), tasks.wait_for_startup())
+            .await
+            .is_err());
+
+        release_tx.send(()).unwrap();
+        timeout(Duration::from_secs(1), tasks.wait_for_startup())
+            .await
+            .expect("startup wait should resolve once tasks finish");
+    }
+
     #[tokio::test]
     async fn spawn_prunes_finished_handles_before_tracking_new_tasks() {
         let tasks = RuntimeTasks::default();
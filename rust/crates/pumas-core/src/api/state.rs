@@ -8,8 +8,9 @@ use super::state_hf::{
     cancel_hf_download, clear_hf_token, get_hf_auth_status, get_hf_download_details,
     get_hf_download_progress, get_hf_repo_files, list_hf_downloads, list_interrupted_downloads,
     lookup_hf_metadata_for_bundle_directory, lookup_hf_metadata_for_file, pause_hf_download,
-    recover_download, refetch_metadata_from_hf, resume_hf_download, resume_partial_download,
-    search_hf_models, search_hf_models_with_hydration, set_hf_token, start_hf_download,
+    recover_download, refetch_metadata_from_hf, reorder_hf_download, resume_hf_download,
+    resume_partial_download, search_hf_models, search_hf_models_with_hydration, set_hf_token,
+    start_hf_download,
 };
 use super::state_process::{
     get_last_launch_error, get_last_launch_log, get_running_processes, is_comfyui_running,
@@ -21,6 +22,7 @@ use super::state_runtime::{
 };
 use super::state_runtime_profiles::{launch_runtime_profile, stop_runtime_profile};
 use super::{reconcile_on_demand, ReconcileScope, ReconciliationCoordinator, RuntimeTasks};
+use crate::cancel::CancellationToken;
 use crate::conversion;
 use crate::error::PumasError;
 use crate::ipc;
@@ -235,6 +237,10 @@ async fn load_model_count(
 pub(crate) struct PrimaryState {
     pub(crate) _state: Arc<RwLock<ApiState>>,
     pub(crate) network_manager: Arc<network::NetworkManager>,
+    /// Proxy, CA, and user-agent settings for this instance's HTTP clients.
+    pub(crate) http_settings: network::HttpSettings,
+    /// Download bandwidth budget shared by this instance's download clients.
+    pub(crate) bandwidth_limiter: Arc<network::BandwidthLimiter>,
     pub(crate) process_manager: Arc<RwLock<Option<process::ProcessManager>>>,
     pub(crate) resource_tracker: Arc<system::ResourceTracker>,
     pub(crate) status_telemetry: Arc<super::status_telemetry::StatusTelemetryService>,
@@ -243,10 +249,27 @@ pub(crate) struct PrimaryState {
     pub(crate) model_mapper: model_library::ModelMapper,
     pub(crate) hf_client: Option<model_library::HuggingFaceClient>,
     pub(crate) model_importer: model_library::ModelImporter,
+    /// When orphan adoption runs without an explicit request.
+    pub(crate) orphan_scan: model_library::OrphanScanConfig,
+    /// Progress of in-flight orphan adoption.
+    pub(crate) orphan_scan_status: super::models::OrphanScanStatus,
+    /// Progress of imports started with `start_import`.
+    pub(crate) import_progress: Arc<model_library::ImportProgressTracker>,
+    /// Progress of batches started with `start_import_models_batch`.
+    pub(crate) batch_imports: Arc<model_library::BatchImportTracker>,
+    /// Progress of index rebuilds started with `start_rebuild_model_index`.
+    pub(crate) index_rebuilds: Arc<model_library::IndexRebuildTracker>,
+    /// Opt-in watch-and-sync of new models into an app version.
+    pub(crate) auto_sync: super::AutoSync,
+    /// Host callbacks fired after a download imports successfully.
+    pub(crate) download_hooks: super::DownloadCompleteHooks,
+    /// Typed library change events for `subscribe_library_events`.
+    pub(crate) library_events: super::LibraryEventBus,
     pub(crate) conversion_manager: Arc<conversion::ConversionManager>,
     pub(crate) runtime_profile_service: Arc<runtime_profiles::RuntimeProfileService>,
     pub(crate) serving_service: Arc<serving::ServingService>,
-    /// Shared owner for primary runtime task handles.
+    /// Shared owner for primary runtime task handles and the root shutdown
+    /// token those tasks observe.
     pub(crate) runtime_tasks: RuntimeTasks,
     /// Internal scheduler for event-driven reconciliation.
     pub(crate) reconciliation: Arc<ReconciliationCoordinator>,
@@ -275,15 +298,26 @@ impl ipc::server::IpcDispatch for PrimaryState {
             "list_models" => {
                 let _ =
                     reconcile_on_demand(self, ReconcileScope::AllModels, "ipc-list-models").await?;
-                let models = self.model_library.list_models().await?;
+                let mut models = self.model_library.list_models().await?;
+                if !params["include_incomplete"].as_bool().unwrap_or(false) {
+                    models.retain(crate::index::ModelRecord::is_ready);
+                }
                 Ok(serde_json::to_value(models)?)
             }
             "search_models" => {
                 let query = params["query"].as_str().unwrap_or("");
                 let limit = params["limit"].as_u64().unwrap_or(50) as usize;
                 let offset = params["offset"].as_u64().unwrap_or(0) as usize;
+                let include_incomplete = params["include_incomplete"].as_bool().unwrap_or(false);
+                let options: crate::index::SearchOptions = match params.get("options") {
+                    Some(options) if !options.is_null() => serde_json::from_value(options.clone())
+                        .map_err(|err| PumasError::InvalidParams {
+                            message: format!("invalid search options: {}", err),
+                        })?,
+                    _ => Default::default(),
+                };
 
-                let result = if query.trim().is_empty() {
+                let mut result = if query.trim().is_empty() {
                     let _ = reconcile_on_demand(
                         self,
                         ReconcileScope::AllModels,
@@ -291,12 +325,12 @@ impl ipc::server::IpcDispatch for PrimaryState {
                     )
                     .await?;
                     self.model_library
-                        .search_models(query, limit, offset)
+                        .search_models_with_options(query, limit, offset, options.clone())
                         .await?
                 } else {
                     let mut result = self
                         .model_library
-                        .search_models(query, limit, offset)
+                        .search_models_with_options(query, limit, offset, options.clone())
                         .await?;
                     let mut model_ids = HashSet::new();
                     for model in &result.models {
@@ -319,12 +353,15 @@ impl ipc::server::IpcDispatch for PrimaryState {
                     if reconciled_any {
                         result = self
                             .model_library
-                            .search_models(query, limit, offset)
+                            .search_models_with_options(query, limit, offset, options.clone())
                             .await?;
                     }
 
                     result
                 };
+                if !include_incomplete {
+                    result.retain_ready();
+                }
                 Ok(serde_json::to_value(result)?)
             }
             "get_model" => {
@@ -351,6 +388,10 @@ impl ipc::server::IpcDispatch for PrimaryState {
                             message: "model_id is required".to_string(),
                         })?;
                 self.model_library.delete_model(model_id, true).await?;
+                self.library_events
+                    .publish(super::LibraryEvent::ModelDeleted {
+                        model_id: model_id.to_string(),
+                    });
                 Ok(serde_json::to_value(models::DeleteModelResponse {
                     success: true,
                     error: None,
@@ -364,8 +405,52 @@ impl ipc::server::IpcDispatch for PrimaryState {
                         }
                     })?;
                 let result = self.model_importer.import(&spec).await?;
+                self.library_events.publish_import(&result);
                 Ok(serde_json::to_value(result)?)
             }
+            "validate_import_spec" => {
+                let spec: model_library::ModelImportSpec =
+                    serde_json::from_value(params["spec"].clone()).map_err(|e| {
+                        PumasError::InvalidParams {
+                            message: format!("Invalid import spec: {e}"),
+                        }
+                    })?;
+                let report = self.model_importer.validate_spec(&spec).await?;
+                Ok(serde_json::to_value(report)?)
+            }
+            "start_import" => {
+                let spec: model_library::ModelImportSpec =
+                    serde_json::from_value(params["spec"].clone()).map_err(|e| {
+                        PumasError::InvalidParams {
+                            message: format!("Invalid import spec: {e}"),
+                        }
+                    })?;
+                let id = super::models::start_tracked_import(self, spec);
+                Ok(serde_json::json!({ "import_id": id }))
+            }
+            "preview_model_path" => {
+                let field = |name: &str| {
+                    params[name]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: format!("{name} is required"),
+                        })
+                };
+                let preview = self.model_library.preview_model_path(
+                    field("model_type")?,
+                    field("family")?,
+                    field("official_name")?,
+                );
+                Ok(serde_json::to_value(preview)?)
+            }
+            "get_import_progress" => {
+                let id = params["import_id"]
+                    .as_str()
+                    .ok_or_else(|| PumasError::InvalidParams {
+                        message: "import_id is required".to_string(),
+                    })?;
+                Ok(serde_json::to_value(self.import_progress.get(id))?)
+            }
             "import_models_batch" => {
                 let specs: Vec<model_library::ModelImportSpec> =
                     serde_json::from_value(params["specs"].clone()).map_err(|e| {
@@ -373,9 +458,33 @@ impl ipc::server::IpcDispatch for PrimaryState {
                             message: format!("Invalid import specs: {e}"),
                         }
                     })?;
-                let result = self.model_importer.batch_import(specs, None).await;
+                let result = self
+                    .model_importer
+                    .batch_import(specs, None, &CancellationToken::new())
+                    .await;
+                result
+                    .iter()
+                    .for_each(|item| self.library_events.publish_import(item));
                 Ok(serde_json::to_value(result)?)
             }
+            "start_import_models_batch" => {
+                let specs: Vec<model_library::ModelImportSpec> =
+                    serde_json::from_value(params["specs"].clone()).map_err(|e| {
+                        PumasError::InvalidParams {
+                            message: format!("Invalid import specs: {e}"),
+                        }
+                    })?;
+                let id = super::models::start_tracked_batch_import(self, specs);
+                Ok(serde_json::json!({ "batch_id": id }))
+            }
+            "get_batch_import_progress" => {
+                let id = params["batch_id"]
+                    .as_str()
+                    .ok_or_else(|| PumasError::InvalidParams {
+                        message: "batch_id is required".to_string(),
+                    })?;
+                Ok(serde_json::to_value(self.batch_imports.get(id))?)
+            }
             "rebuild_model_index" => {
                 self.reconciliation.mark_dirty_all().await;
                 let _ = reconcile_on_demand(self, ReconcileScope::AllModels, "ipc-rebuild-index")
@@ -383,6 +492,33 @@ impl ipc::server::IpcDispatch for PrimaryState {
                 let model_count = load_model_count(self.model_library.clone()).await?;
                 Ok(serde_json::to_value(model_count)?)
             }
+            "start_rebuild_model_index" => {
+                let id = super::models::start_tracked_rebuild(self);
+                Ok(serde_json::json!({ "rebuild_id": id }))
+            }
+            "get_rebuild_progress" | "cancel_rebuild_model_index" => {
+                let id =
+                    params["rebuild_id"]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: "rebuild_id is required".to_string(),
+                        })?;
+                if method == "cancel_rebuild_model_index" {
+                    Ok(serde_json::to_value(self.index_rebuilds.cancel(id))?)
+                } else {
+                    Ok(serde_json::to_value(self.index_rebuilds.get(id))?)
+                }
+            }
+            "refresh_model" => {
+                let model_id =
+                    params["model_id"]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: "model_id is required".to_string(),
+                        })?;
+                self.model_library.refresh_model(model_id).await?;
+                Ok(serde_json::Value::Null)
+            }
             "reclassify_model" => {
                 let model_id =
                     params["model_id"]
@@ -459,20 +595,44 @@ impl ipc::server::IpcDispatch for PrimaryState {
                 let response = store_model_notes(self, model_id, notes).await?;
                 Ok(serde_json::to_value(response)?)
             }
+            "update_model_tags" => {
+                let model_id =
+                    params["model_id"]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: "model_id is required".to_string(),
+                        })?;
+                let tag_list = |key: &str| -> std::result::Result<Vec<String>, PumasError> {
+                    match params.get(key) {
+                        None | Some(serde_json::Value::Null) => Ok(Vec::new()),
+                        Some(value) => serde_json::from_value(value.clone()).map_err(|e| {
+                            PumasError::InvalidParams {
+                                message: format!("Invalid {key}: {e}"),
+                            }
+                        }),
+                    }
+                };
+                let tags = self
+                    .model_library
+                    .update_model_tags(model_id, tag_list("add")?, tag_list("remove")?)
+                    .await?;
+                Ok(serde_json::to_value(tags)?)
+            }
             "get_library_status" => {
                 let _ =
                     reconcile_on_demand(self, ReconcileScope::AllModels, "ipc-get-library-status")
                         .await?;
                 let model_count = load_model_count(self.model_library.clone()).await? as u32;
                 let pending_lookups = self.model_library.get_pending_lookups().await?.len() as u32;
+                let orphan_progress = self.orphan_scan_status.snapshot();
                 Ok(serde_json::to_value(models::LibraryStatusResponse {
                     success: true,
                     error: None,
                     indexing: false,
-                    deep_scan_in_progress: false,
+                    deep_scan_in_progress: orphan_progress.is_some(),
                     model_count,
                     pending_lookups: Some(pending_lookups),
-                    deep_scan_progress: None,
+                    deep_scan_progress: orphan_progress,
                 })?)
             }
             "resolve_model_dependency_requirements" => {
@@ -821,6 +981,30 @@ impl ipc::server::IpcDispatch for PrimaryState {
                 let report = self.model_library.audit_dependency_pin_compliance().await?;
                 Ok(serde_json::to_value(report)?)
             }
+            "security_audit" => {
+                let report = self.model_library.security_audit().await?;
+                Ok(serde_json::to_value(report)?)
+            }
+            "verify_local_model" => {
+                let model_id =
+                    params["model_id"]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: "model_id is required".to_string(),
+                        })?;
+                let result = self.model_library.verify_model(model_id).await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "scan_shared_storage" => {
+                let paths: Vec<std::path::PathBuf> =
+                    serde_json::from_value(params["paths"].clone()).map_err(|e| {
+                        PumasError::InvalidParams {
+                            message: format!("Invalid paths: {}", e),
+                        }
+                    })?;
+                let report = self.model_library.scan_shared_storage(paths).await?;
+                Ok(serde_json::to_value(report)?)
+            }
             "list_models_needing_review" => {
                 let filter: Option<model_library::ModelReviewFilter> =
                     serde_json::from_value(params["filter"].clone()).map_err(|e| {
@@ -904,6 +1088,7 @@ impl ipc::server::IpcDispatch for PrimaryState {
                     .model_importer
                     .import_external_diffusers_directory(&spec)
                     .await?;
+                self.library_events.publish_import(&result);
                 Ok(serde_json::to_value(result)?)
             }
             "import_model_in_place" => {
@@ -918,10 +1103,15 @@ impl ipc::server::IpcDispatch for PrimaryState {
                 )
                 .await?;
                 let result = self.model_importer.import_in_place(&spec).await?;
+                self.library_events.publish_import(&result);
                 Ok(serde_json::to_value(result)?)
             }
             "adopt_orphan_models" => {
-                let result = self.model_importer.adopt_orphans(false).await;
+                let result = if params["dry_run"].as_bool().unwrap_or(false) {
+                    self.model_importer.adopt_orphans(false, true).await
+                } else {
+                    self.orphan_scan_status.adopt(&self.model_importer).await
+                };
                 Ok(serde_json::to_value(result)?)
             }
             "get_link_health" => {
@@ -1076,6 +1266,10 @@ impl ipc::server::IpcDispatch for PrimaryState {
                     apply_model_mapping_response(self, version_tag, models_path.as_path()).await?;
                 Ok(serde_json::to_value(response)?)
             }
+            "validate_mapping_configs" => {
+                let report = self.model_mapper.validate_configs_async().await?;
+                Ok(serde_json::to_value(report)?)
+            }
             "sync_models_incremental" => {
                 let version_tag =
                     params["version_tag"]
@@ -1091,14 +1285,9 @@ impl ipc::server::IpcDispatch for PrimaryState {
                         })?;
                 let models_path =
                     validate_local_directory_target_path(models_path, "models_path").await?;
-                let apply =
-                    apply_model_mapping_response(self, version_tag, models_path.as_path()).await?;
-                let response = models::SyncModelsResponse {
-                    success: apply.success,
-                    error: apply.error,
-                    synced: apply.links_created,
-                    errors: vec![],
-                };
+                let response =
+                    sync_models_incremental_response(self, version_tag, models_path.as_path())
+                        .await?;
                 Ok(serde_json::to_value(response)?)
             }
             "sync_with_resolutions" => {
@@ -1278,6 +1467,22 @@ impl ipc::server::IpcDispatch for PrimaryState {
                 let resumed = resume_hf_download(self, download_id).await?;
                 Ok(serde_json::to_value(resumed)?)
             }
+            "reorder_hf_download" => {
+                let download_id =
+                    params["download_id"]
+                        .as_str()
+                        .ok_or_else(|| PumasError::InvalidParams {
+                            message: "download_id is required".to_string(),
+                        })?;
+                let priority: model_library::DownloadPriority =
+                    serde_json::from_value(params["priority"].clone()).map_err(|e| {
+                        PumasError::InvalidParams {
+                            message: format!("priority must be high, normal, or low: {}", e),
+                        }
+                    })?;
+                let reordered = reorder_hf_download(self, download_id, priority).await?;
+                Ok(serde_json::to_value(reordered)?)
+            }
             "list_hf_downloads" => {
                 let downloads = list_hf_downloads(self).await;
                 Ok(serde_json::to_value(downloads)?)
@@ -1563,16 +1768,35 @@ impl ipc::server::IpcDispatch for PrimaryState {
             "check_launcher_updates" => {
                 let force_refresh = params["force_refresh"].as_bool().unwrap_or(false);
                 let updater =
-                    crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self));
+                    crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self))
+                        .with_http_settings(self.http_settings.clone());
                 let result = updater.check_for_updates(force_refresh).await;
                 Ok(serde_json::to_value(result)?)
             }
+            "preview_launcher_update" => {
+                let updater =
+                    crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self));
+                let preview = updater.preview_update().await?;
+                Ok(serde_json::to_value(preview)?)
+            }
             "apply_launcher_update" => {
                 let updater =
                     crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self));
                 let result = updater.apply_update().await;
                 Ok(serde_json::to_value(result)?)
             }
+            "stage_launcher_update" => {
+                let updater =
+                    crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self));
+                let result = updater.stage_update().await;
+                Ok(serde_json::to_value(result)?)
+            }
+            "build_launcher_update" => {
+                let updater =
+                    crate::launcher::LauncherUpdater::new(launcher_root_from_primary(self));
+                let result = updater.build_update().await;
+                Ok(serde_json::to_value(result)?)
+            }
             "ping" => Ok(serde_json::json!("pong")),
             // Conversion methods
             "start_conversion" => {
@@ -1900,6 +2124,9 @@ async fn apply_model_mapping_response(
         .model_mapper
         .apply_mapping("comfyui", Some(version_tag), models_path)
         .await?;
+    primary
+        .library_events
+        .publish_mapping(version_tag, models_path, result.created);
 
     Ok(models::MappingApplyResponse {
         success: true,
@@ -1910,6 +2137,50 @@ async fn apply_model_mapping_response(
     })
 }
 
+async fn sync_models_incremental_response(
+    primary: &PrimaryState,
+    version_tag: &str,
+    models_path: &Path,
+) -> std::result::Result<models::SyncModelsResponse, PumasError> {
+    if !path_exists(models_path).await? {
+        fs::create_dir_all(models_path)
+            .await
+            .map_err(|err| PumasError::io_with_path(err, models_path))?;
+    }
+
+    primary
+        .model_mapper
+        .create_default_comfyui_config_async("*", models_path)
+        .await?;
+
+    let result = primary
+        .model_mapper
+        .sync_incremental(
+            "comfyui",
+            Some(version_tag),
+            models_path,
+            model_library::ConflictResolution::Skip,
+        )
+        .await?;
+    primary
+        .library_events
+        .publish_mapping(version_tag, models_path, result.added);
+
+    Ok(models::SyncModelsResponse {
+        success: result.errors.is_empty(),
+        error: None,
+        synced: result.added,
+        links_added: result.added,
+        links_removed: result.removed,
+        links_unchanged: result.unchanged,
+        errors: result
+            .errors
+            .iter()
+            .map(|(path, error)| format!("{}: {}", path.display(), error))
+            .collect(),
+    })
+}
+
 async fn sync_with_resolutions_response(
     primary: &PrimaryState,
     version_tag: &str,
@@ -1946,6 +2217,9 @@ async fn sync_with_resolutions_response(
             &typed_resolutions,
         )
         .await?;
+    primary
+        .library_events
+        .publish_mapping(version_tag, models_path, result.created);
 
     let errors: Vec<String> = result
         .errors
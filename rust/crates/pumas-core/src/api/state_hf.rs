@@ -206,11 +206,12 @@ pub(super) async fn start_hf_download(
     resolved_request.model_type = Some(model_type.clone());
     let dest_dir = primary
         .model_library
-        .prepare_artifact_download_destination(
+        .prepare_artifact_download_destination_async(
             &model_type,
             &architecture_family,
             &selected_artifact.artifact_id,
-        )?;
+        )
+        .await?;
     if model_type == "unknown" {
         warn!(
             "Download {} is starting with unknown model_type after HF metadata lookup; destination={}",
@@ -223,9 +224,16 @@ pub(super) async fn start_hf_download(
         evidence.requested_pipeline_tag = request.pipeline_tag.clone();
         evidence.requested_quant = request.quant.clone();
     }
-    client
+    let download_id = client
         .start_download(&resolved_request, &dest_dir, huggingface_evidence)
-        .await
+        .await?;
+    primary
+        .library_events
+        .publish(super::LibraryEvent::DownloadStarted {
+            download_id: download_id.clone(),
+            repo_id: request.repo_id.clone(),
+        });
+    Ok(download_id)
 }
 
 pub(super) async fn get_hf_download_progress(
@@ -272,6 +280,18 @@ pub(super) async fn resume_hf_download(
     }
 }
 
+pub(super) async fn reorder_hf_download(
+    primary: &PrimaryState,
+    download_id: &str,
+    priority: model_library::DownloadPriority,
+) -> std::result::Result<bool, PumasError> {
+    if let Some(ref client) = primary.hf_client {
+        client.reorder_download(download_id, priority).await
+    } else {
+        Ok(false)
+    }
+}
+
 pub(super) async fn list_hf_downloads(
     primary: &PrimaryState,
 ) -> Vec<models::ModelDownloadProgress> {
@@ -375,6 +395,12 @@ pub(super) async fn recover_download(
         download_url: None,
         model_card_json: None,
         license_status: None,
+        skip_hash_verification: false,
+        max_concurrent_files: None,
+        rate_limit: None,
+        priority: Default::default(),
+        include_patterns: None,
+        exclude_patterns: None,
     };
 
     client.start_download(&request, &dest, None).await
@@ -135,15 +135,16 @@ async fn library_status_response(primary: &PrimaryState) -> Result<models::Libra
         .await
         .map_err(|err| PumasError::Other(format!("Failed to join model count task: {}", err)))??;
     let pending_lookups = primary.model_library.get_pending_lookups().await?.len() as u32;
+    let orphan_progress = primary.orphan_scan_status.snapshot();
 
     Ok(models::LibraryStatusResponse {
         success: true,
         error: None,
         indexing: false,
-        deep_scan_in_progress: false,
+        deep_scan_in_progress: orphan_progress.is_some(),
         model_count: model_count as u32,
         pending_lookups: Some(pending_lookups),
-        deep_scan_progress: None,
+        deep_scan_progress: orphan_progress,
     })
 }
 
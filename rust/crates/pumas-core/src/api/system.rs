@@ -16,6 +16,18 @@ async fn path_exists(path: &Path) -> Result<bool> {
         .map_err(|err| crate::error::PumasError::io_with_path(err, path))
 }
 
+fn environment_issue(
+    check: &str,
+    message: impl Into<String>,
+    path: Option<&Path>,
+) -> models::EnvironmentIssue {
+    models::EnvironmentIssue {
+        check: check.to_string(),
+        message: message.into(),
+        path: path.map(|path| path.display().to_string()),
+    }
+}
+
 async fn validate_existing_local_open_path(path: &str) -> Result<std::path::PathBuf> {
     let trimmed = path.trim();
     if trimmed.is_empty() {
@@ -194,6 +206,81 @@ impl PumasApi {
         .map_err(|e| PumasError::Other(format!("Failed to join get_disk_space task: {}", e)))?
     }
 
+    /// Summarize the health of every subsystem in one call.
+    ///
+    /// Composes network status, HuggingFace auth, conversion environment
+    /// readiness, disk space, running apps, index consistency, and the
+    /// pending download count. The checks run concurrently; one that fails is
+    /// reported in `failed_checks` instead of failing the summary.
+    pub async fn health_summary(&self) -> models::HealthSummaryResponse {
+        let (network, hf_auth, conversion_ready, disk_space, running, downloads, index_consistent) = tokio::join!(
+            self.get_network_status_response(),
+            self.get_hf_auth_status(),
+            self.is_conversion_environment_ready(),
+            self.get_disk_space(),
+            async {
+                tokio::join!(
+                    self.is_comfyui_running(),
+                    self.is_ollama_running(),
+                    self.is_torch_running()
+                )
+            },
+            self.list_hf_downloads(),
+            self.primary().reconciliation.is_settled(),
+        );
+
+        let mut failed_checks = Vec::new();
+        let mut check = |name: &str, error: PumasError| {
+            failed_checks.push(models::HealthCheckFailure {
+                check: name.to_string(),
+                error: error.to_string(),
+            });
+        };
+        let hf_auth = hf_auth.map_err(|err| check("hf_auth", err)).ok();
+        let conversion_ready = conversion_ready
+            .map_err(|err| check("conversion_environment", err))
+            .unwrap_or(false);
+        let disk_space = disk_space.map_err(|err| check("disk_space", err)).ok();
+
+        let (comfyui, ollama, torch) = running;
+        let running_apps = [
+            (AppId::ComfyUI, comfyui),
+            (AppId::Ollama, ollama),
+            (AppId::Torch, torch),
+        ]
+        .into_iter()
+        .filter(|(_, running)| *running)
+        .map(|(app, _)| app.as_str().to_string())
+        .collect();
+        let pending_downloads = downloads
+            .iter()
+            .filter(|download| {
+                matches!(
+                    download.status,
+                    models::DownloadStatus::Queued
+                        | models::DownloadStatus::Downloading
+                        | models::DownloadStatus::Pausing
+                        | models::DownloadStatus::Paused
+                )
+            })
+            .count() as u32;
+
+        models::HealthSummaryResponse {
+            success: failed_checks.is_empty(),
+            error: (!failed_checks.is_empty())
+                .then(|| format!("{} health check(s) failed", failed_checks.len())),
+            network,
+            hf_authenticated: hf_auth.as_ref().is_some_and(|auth| auth.authenticated),
+            hf_username: hf_auth.and_then(|auth| auth.username),
+            conversion_environment_ready: conversion_ready,
+            disk_space,
+            running_apps,
+            index_consistent,
+            pending_downloads,
+            failed_checks,
+        }
+    }
+
     /// Get system resources (CPU, GPU, RAM, disk).
     pub async fn get_system_resources(&self) -> Result<models::SystemResourcesResponse> {
         let tracker = self.primary().resource_tracker.clone();
@@ -247,6 +334,25 @@ impl PumasApi {
             .map_err(|e| PumasError::Other(format!("Failed to join open_directory task: {}", e)))?
     }
 
+    /// Check write access for files and directories before modifying them.
+    ///
+    /// Pass model files before conversions or edits and the target mapping
+    /// directory before mapping, so read-only files, missing permissions, or
+    /// read-only mounts surface as warnings instead of mid-operation failures.
+    pub async fn check_files_writable(
+        &self,
+        paths: Vec<std::path::PathBuf>,
+    ) -> Result<Vec<system::PathWritability>> {
+        tokio::task::spawn_blocking(move || {
+            paths
+                .iter()
+                .map(|path| system::check_path_writable(path))
+                .collect()
+        })
+        .await
+        .map_err(|e| PumasError::Other(format!("Failed to join check_files_writable task: {}", e)))
+    }
+
     // ========================================
     // Background fetch tracking
     // ========================================
@@ -292,16 +398,35 @@ impl PumasApi {
 
     /// Check for launcher updates via GitHub.
     pub async fn check_launcher_updates(&self, force_refresh: bool) -> launcher::UpdateCheckResult {
-        let updater = launcher::LauncherUpdater::new(&self.launcher_root);
+        let updater = launcher::LauncherUpdater::new(&self.launcher_root)
+            .with_http_settings(self.primary().http_settings.clone());
         updater.check_for_updates(force_refresh).await
     }
 
+    /// Preview the commits and files a launcher update would pull.
+    pub async fn preview_launcher_update(&self) -> Result<launcher::UpdatePreview> {
+        let updater = launcher::LauncherUpdater::new(&self.launcher_root);
+        updater.preview_update().await
+    }
+
     /// Apply launcher update by pulling latest changes and rebuilding.
     pub async fn apply_launcher_update(&self) -> launcher::UpdateApplyResult {
         let updater = launcher::LauncherUpdater::new(&self.launcher_root);
         updater.apply_update().await
     }
 
+    /// Pull the latest launcher changes without rebuilding.
+    pub async fn stage_launcher_update(&self) -> launcher::UpdateApplyResult {
+        let updater = launcher::LauncherUpdater::new(&self.launcher_root);
+        updater.stage_update().await
+    }
+
+    /// Rebuild a staged launcher update, rolling back on failure.
+    pub async fn build_launcher_update(&self) -> launcher::UpdateApplyResult {
+        let updater = launcher::LauncherUpdater::new(&self.launcher_root);
+        updater.build_update().await
+    }
+
     /// Restart the launcher by spawning a new process.
     pub async fn restart_launcher(&self) -> Result<bool> {
         let launcher_root = self.launcher_root.clone();
@@ -317,36 +442,69 @@ impl PumasApi {
     // Patch Manager Methods
     // ========================================
 
-    /// Check if ComfyUI main.py is patched with setproctitle.
-    pub async fn is_patched(&self, tag: Option<&str>) -> bool {
+    fn comfyui_patch_manager(&self) -> launcher::PatchManager {
         let comfyui_dir = self.launcher_root.join("ComfyUI");
         let main_py = comfyui_dir.join("main.py");
         let versions_dir = Some(self.versions_dir(AppId::ComfyUI));
+        launcher::PatchManager::new(&comfyui_dir, &main_py, versions_dir)
+    }
+
+    /// Check if ComfyUI main.py is cleanly patched with setproctitle.
+    pub async fn is_patched(&self, tag: Option<&str>) -> bool {
+        let patch_mgr = self.comfyui_patch_manager();
         let tag = tag.map(str::to_owned);
 
-        tokio::task::spawn_blocking(move || {
-            let patch_mgr = launcher::PatchManager::new(&comfyui_dir, &main_py, versions_dir);
-            patch_mgr.is_patched(tag.as_deref())
-        })
-        .await
-        .unwrap_or(false)
+        tokio::task::spawn_blocking(move || patch_mgr.is_patched(tag.as_deref()))
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Get the patch state of a ComfyUI version's main.py.
+    pub async fn patch_state(&self, tag: Option<&str>) -> Result<launcher::PatchState> {
+        let patch_mgr = self.comfyui_patch_manager();
+        let tag = tag.map(str::to_owned);
+
+        tokio::task::spawn_blocking(move || patch_mgr.patch_state(tag.as_deref()))
+            .await
+            .map_err(|e| PumasError::Other(format!("Failed to join patch_state task: {}", e)))
     }
 
     /// Toggle the setproctitle patch for a ComfyUI version.
     ///
     /// Returns `true` if now patched, `false` if now unpatched.
     pub async fn toggle_patch(&self, tag: Option<&str>) -> Result<bool> {
-        let comfyui_dir = self.launcher_root.join("ComfyUI");
-        let main_py = comfyui_dir.join("main.py");
-        let versions_dir = Some(self.versions_dir(AppId::ComfyUI));
+        let patch_mgr = self.comfyui_patch_manager();
         let tag = tag.map(str::to_owned);
 
+        tokio::task::spawn_blocking(move || patch_mgr.toggle_patch(tag.as_deref()))
+            .await
+            .map_err(|e| PumasError::Other(format!("Failed to join toggle_patch task: {}", e)))?
+    }
+
+    /// Apply or remove the setproctitle patch for every installed ComfyUI version.
+    pub async fn apply_patch_to_all(
+        &self,
+        enable: bool,
+    ) -> Result<Vec<launcher::VersionPatchResult>> {
+        let versions_dir = self.versions_dir(AppId::ComfyUI);
+
         tokio::task::spawn_blocking(move || {
-            let patch_mgr = launcher::PatchManager::new(&comfyui_dir, &main_py, versions_dir);
-            patch_mgr.toggle_patch(tag.as_deref())
+            launcher::PatchManager::apply_to_all(&versions_dir, enable)
         })
         .await
-        .map_err(|e| PumasError::Other(format!("Failed to join toggle_patch task: {}", e)))?
+        .map_err(|e| PumasError::Other(format!("Failed to join apply_patch_to_all task: {}", e)))?
+    }
+
+    /// Restore a ComfyUI version's main.py from its pre-patch backup.
+    pub async fn restore_patch_backup(&self, tag: Option<&str>) -> Result<()> {
+        let patch_mgr = self.comfyui_patch_manager();
+        let tag = tag.map(str::to_owned);
+
+        tokio::task::spawn_blocking(move || patch_mgr.restore_backup(tag.as_deref()))
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join restore_patch_backup task: {}", e))
+            })?
     }
 
     // ========================================
@@ -375,6 +533,17 @@ impl PumasApi {
             })
     }
 
+    /// Check if the uv Python package manager is available.
+    pub async fn check_uv(&self) -> system::SystemCheckResult {
+        tokio::task::spawn_blocking(system::check_uv)
+            .await
+            .unwrap_or_else(|_| system::SystemCheckResult {
+                available: false,
+                path: None,
+                info: Some("Failed to join check_uv task".to_string()),
+            })
+    }
+
     /// Check if setproctitle Python package is available.
     pub async fn check_setproctitle(&self) -> system::SystemCheckResult {
         tokio::task::spawn_blocking(system::check_setproctitle)
@@ -385,13 +554,145 @@ impl PumasApi {
                 info: Some("Failed to join check_setproctitle task".to_string()),
             })
     }
+
+    /// Diagnose common setup problems and repair the ones that are safe to fix.
+    ///
+    /// Missing launcher directories are recreated. Missing tools (git, uv,
+    /// setproctitle) and directories that reject writes are reported under
+    /// `needs_action`, since fixing them requires the user's system tools.
+    pub async fn diagnose_and_repair(&self) -> models::EnvironmentRepairReport {
+        let mut fixed = Vec::new();
+        let mut needs_action = Vec::new();
+
+        let tool_checks = [
+            (
+                "git",
+                self.check_git().await,
+                "git is not installed; install git to manage app versions",
+            ),
+            (
+                "uv",
+                self.check_uv().await,
+                "uv is not installed; install uv to create Python environments",
+            ),
+            (
+                "setproctitle",
+                self.check_setproctitle().await,
+                "setproctitle is not installed; run `pip install setproctitle` for named processes",
+            ),
+        ];
+        for (check, result, message) in tool_checks {
+            if !result.available {
+                needs_action.push(environment_issue(check, message, None));
+            }
+        }
+
+        for dir in super::PumasApiBuilder::required_directories(&self.launcher_root) {
+            match fs::try_exists(&dir).await {
+                Ok(true) => {}
+                Ok(false) => match fs::create_dir_all(&dir).await {
+                    Ok(()) => fixed.push(environment_issue(
+                        "directories",
+                        "Recreated missing directory",
+                        Some(&dir),
+                    )),
+                    Err(err) => needs_action.push(environment_issue(
+                        "directories",
+                        format!("Failed to create missing directory: {}", err),
+                        Some(&dir),
+                    )),
+                },
+                Err(err) => needs_action.push(environment_issue(
+                    "directories",
+                    format!("Failed to inspect directory: {}", err),
+                    Some(&dir),
+                )),
+            }
+        }
+
+        let writable_dirs = vec![self.launcher_data_dir(), self.model_library_dir()];
+        match self.check_files_writable(writable_dirs).await {
+            Ok(results) => {
+                for result in results.into_iter().filter(|result| !result.writable) {
+                    needs_action.push(environment_issue(
+                        "write_access",
+                        format!(
+                            "Directory is not writable: {}",
+                            result.message.as_deref().unwrap_or("unknown error")
+                        ),
+                        Some(&result.path),
+                    ));
+                }
+            }
+            Err(err) => needs_action.push(environment_issue(
+                "write_access",
+                format!("Failed to check write access: {}", err),
+                None,
+            )),
+        }
+
+        models::EnvironmentRepairReport {
+            success: true,
+            error: None,
+            fixed,
+            needs_action,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::validate_existing_local_open_path;
+    use crate::PumasApi;
     use tempfile::TempDir;
 
+    #[tokio::test]
+    async fn diagnose_and_repair_recreates_missing_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let api = PumasApi::builder(temp_dir.path()).build().await.unwrap();
+        api.diagnose_and_repair().await;
+        let logs_dir = api.launcher_data_dir().join("logs");
+        std::fs::remove_dir_all(&logs_dir).unwrap();
+
+        let report = api.diagnose_and_repair().await;
+
+        assert!(report.success);
+        assert!(logs_dir.is_dir());
+        assert!(report
+            .fixed
+            .iter()
+            .any(|issue| issue.path.as_deref() == Some(logs_dir.to_string_lossy().as_ref())));
+        assert!(report
+            .needs_action
+            .iter()
+            .all(|issue| issue.check != "directories" && issue.check != "write_access"));
+
+        let second = api.diagnose_and_repair().await;
+        assert!(second.fixed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn health_summary_reports_idle_library() {
+        let temp_dir = TempDir::new().unwrap();
+        let api = PumasApi::builder(temp_dir.path())
+            .with_hf_client(false)
+            .with_process_manager(false)
+            .build()
+            .await
+            .unwrap();
+
+        let summary = api.health_summary().await;
+
+        assert!(!summary.hf_authenticated);
+        assert!(summary.running_apps.is_empty());
+        assert_eq!(summary.pending_downloads, 0);
+        assert!(summary
+            .failed_checks
+            .iter()
+            .all(|failure| failure.check != "hf_auth"));
+        assert_eq!(summary.success, summary.failed_checks.is_empty());
+    }
+
     #[tokio::test]
     async fn validate_existing_local_open_path_canonicalizes_existing_directory() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,6 +1,7 @@
 //! SQLite-based unified cache implementation.
 
 use super::traits::{CacheBackend, CacheConfig, CacheEntry, CacheMeta, CacheStats};
+use crate::clock::{system_clock, SharedClock};
 use crate::error::{PumasError, Result};
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
@@ -18,6 +19,8 @@ pub struct SqliteCache {
     conn: Arc<Mutex<Connection>>,
     /// Cache configuration.
     config: CacheConfig,
+    /// Time source for expiry checks.
+    clock: SharedClock,
 }
 
 impl SqliteCache {
@@ -56,6 +59,7 @@ impl SqliteCache {
         let cache = Self {
             conn: Arc::new(Mutex::new(conn)),
             config,
+            clock: system_clock(),
         };
 
         cache.init_schema()?;
@@ -63,6 +67,12 @@ impl SqliteCache {
         Ok(cache)
     }
 
+    /// Use `clock` instead of system time for expiry and access timestamps.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Initialize database schema.
     fn init_schema(&self) -> Result<()> {
         let conn = self.conn.lock().map_err(|e| PumasError::Database {
@@ -210,7 +220,7 @@ impl SqliteCache {
 
     /// Update namespace metadata after modifications.
     fn update_namespace_meta(&self, conn: &Connection, namespace: &str) -> Result<()> {
-        let now = Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         // Calculate entry count and total size
         let (count, size): (i64, i64) = conn
@@ -275,7 +285,7 @@ impl CacheBackend for SqliteCache {
             source: None,
         })?;
 
-        let now = Utc::now();
+        let now = self.clock.now();
         let now_str = now.to_rfc3339();
 
         let row: Option<(Vec<u8>, String, String, i64, String)> = conn
@@ -333,7 +343,7 @@ impl CacheBackend for SqliteCache {
     }
 
     fn set(&self, namespace: &str, key: &str, value: &[u8], ttl: Duration) -> Result<()> {
-        let expires_at = Utc::now() + chrono::Duration::from_std(ttl).unwrap_or_default();
+        let expires_at = self.clock.now() + chrono::Duration::from_std(ttl).unwrap_or_default();
         self.set_with_expiry(namespace, key, value, expires_at)
     }
 
@@ -349,7 +359,7 @@ impl CacheBackend for SqliteCache {
             source: None,
         })?;
 
-        let now = Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
         let expires_str = expires_at.to_rfc3339();
         let size_bytes = value.len() as i64;
 
@@ -435,7 +445,7 @@ impl CacheBackend for SqliteCache {
             source: None,
         })?;
 
-        let now = Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         let exists: bool = conn
             .query_row(
@@ -575,7 +585,7 @@ impl CacheBackend for SqliteCache {
             source: None,
         })?;
 
-        let now = Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         // Get affected namespaces before deletion
         let mut stmt = conn
@@ -784,6 +794,22 @@ mod tests {
         assert!(value.is_none());
     }
 
+    #[test]
+    fn test_ttl_expires_with_clock() {
+        let clock = crate::clock::ManualClock::default();
+        let (_temp, cache) = create_test_cache();
+        let cache = cache.with_clock(Arc::new(clock.clone()));
+
+        cache
+            .set("test_ns", "key1", b"data", Duration::from_secs(60))
+            .unwrap();
+        clock.advance(Duration::from_secs(59));
+        assert!(cache.get("test_ns", "key1").unwrap().is_some());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(cache.get("test_ns", "key1").unwrap().is_none());
+    }
+
     #[test]
     fn test_invalidate() {
         let (_temp, cache) = create_test_cache();
@@ -0,0 +1,113 @@
+//! Injectable wall-clock time for cache TTLs.
+//!
+//! Caches compare stored timestamps against "now" to decide freshness. Reading
+//! the time through a [`Clock`] lets tests advance time deterministically
+//! instead of sleeping past a TTL.
+
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Source of the current wall-clock time.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Current UTC time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Shared clock handle held by caches.
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The real system clock. This is the default for every cache.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that only moves when told to, for testing expiry and staleness.
+///
+/// Clones share the same time, so a test can keep one handle and advance the
+/// clock a cache was built with.
+///
+/// # Example
+///
+/// ```
+/// use pumas_library::clock::{Clock, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::default();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(60));
+/// assert_eq!((clock.now() - start).num_seconds(), 60);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl ManualClock {
+    /// Create a clock fixed at `start`.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let step = chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now = now.checked_add_signed(step).unwrap_or(*now);
+    }
+
+    /// Set the clock to `time`.
+    pub fn set(&self, time: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = time;
+    }
+}
+
+impl Default for ManualClock {
+    /// A manual clock starting at the current system time.
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The default shared clock: [`SystemClock`].
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_advances_only_when_told() {
+        let clock = ManualClock::default();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!((clock.now() - start).num_seconds(), 90);
+    }
+
+    #[test]
+    fn test_manual_clock_clones_share_time() {
+        let clock = ManualClock::default();
+        let shared: SharedClock = Arc::new(clock.clone());
+        let start = shared.now();
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!((shared.now() - start).num_seconds(), 5);
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides configuration constants for installation, network operations,
 //! UI dimensions, and other system parameters.
 
+use crate::error::{PumasError, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Application-level configuration.
@@ -62,6 +65,12 @@ impl NetworkConfig {
     pub const DOWNLOAD_CHUNK_SIZE: usize = 8192;
     pub const DOWNLOAD_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
     pub const DOWNLOAD_TEMP_SUFFIX: &'static str = ".part";
+    /// Times a dropped download stream is resumed with an HTTP Range request
+    /// before the download fails.
+    pub const DOWNLOAD_MAX_RESUME_ATTEMPTS: u32 = 5;
+    pub const DOWNLOAD_RESUME_DELAY: Duration = Duration::from_secs(1);
+    /// Smallest file split across parallel range connections.
+    pub const DOWNLOAD_PARALLEL_MIN_SIZE: u64 = 64 * 1024 * 1024;
     pub const GITHUB_API_BASE: &'static str = "https://api.github.com";
     pub const GITHUB_RELEASES_PER_PAGE: u32 = 100;
     /// Maximum GitHub release pages fetched for interactive version selection.
@@ -82,6 +91,17 @@ impl NetworkConfig {
     ///
     /// `0` disables elapsed-time capping.
     pub const HF_DOWNLOAD_MAX_RETRY_ELAPSED: Duration = Duration::from_secs(12 * 60 * 60);
+    /// Upper bound on `DownloadRequest::max_concurrent_files`.
+    pub const HF_DOWNLOAD_MAX_CONCURRENT_FILES: usize = 8;
+    /// Window of recent transfer samples that download speed and ETA are
+    /// computed from.
+    pub const HF_DOWNLOAD_SPEED_WINDOW: Duration = Duration::from_secs(10);
+    /// Default time without receiving any bytes after which a download is
+    /// considered stalled and stopped with an error so it can be resumed.
+    pub const HF_DOWNLOAD_STALL_TIMEOUT: Duration = Duration::from_secs(60);
+    /// Default number of HuggingFace downloads that transfer at once. Further
+    /// downloads wait in the client's queue.
+    pub const HF_MAX_ACTIVE_DOWNLOADS: usize = 3;
     /// Timeout for HuggingFace API metadata requests.
     pub const HF_API_TIMEOUT: Duration = Duration::from_secs(30);
     /// Connect timeout for HuggingFace download client.
@@ -120,18 +140,70 @@ impl NetworkConfig {
 }
 
 /// Shared directory and path configurations.
+///
+/// Key paths can be overridden from the environment for container deployments.
+/// Precedence, highest first:
+/// 1. Explicit builder calls or CLI flags (e.g. `PumasApiBuilder::with_model_library_dir`)
+/// 2. Environment variables (`PUMAS_LIBRARY_ROOT`, `PUMAS_MODELS_DIR`, `PUMAS_CACHE_DIR`)
+/// 3. Built-in defaults relative to the launcher root
+///
+/// Empty environment values are ignored.
 pub struct PathsConfig;
 
 impl PathsConfig {
     pub const CACHE_DIR_NAME: &'static str = "cache";
     pub const PIP_CACHE_DIR_NAME: &'static str = "pip";
     pub const SHARED_RESOURCES_DIR_NAME: &'static str = "shared-resources";
+    pub const MODELS_DIR_NAME: &'static str = "models";
     pub const VERSIONS_DIR_NAME: &'static str = "versions";
     pub const ICONS_DIR_NAME: &'static str = "icons";
     pub const CONSTRAINTS_DIR_NAME: &'static str = "constraints";
     pub const CONSTRAINTS_CACHE_FILENAME: &'static str = "constraints-cache.json";
     pub const METADATA_DIR_NAME: &'static str = "metadata";
     pub const LOGS_DIR_NAME: &'static str = "logs";
+
+    /// Environment variable overriding the launcher root.
+    pub const LIBRARY_ROOT_ENV_VAR: &'static str = "PUMAS_LIBRARY_ROOT";
+    /// Environment variable overriding the model library directory.
+    pub const MODELS_DIR_ENV_VAR: &'static str = "PUMAS_MODELS_DIR";
+    /// Environment variable overriding the launcher cache directory.
+    pub const CACHE_DIR_ENV_VAR: &'static str = "PUMAS_CACHE_DIR";
+
+    /// Model library location used when none is configured.
+    pub fn default_model_library_dir(launcher_root: &Path) -> PathBuf {
+        launcher_root
+            .join(Self::SHARED_RESOURCES_DIR_NAME)
+            .join(Self::MODELS_DIR_NAME)
+    }
+
+    /// Launcher root from `PUMAS_LIBRARY_ROOT`, if set.
+    ///
+    /// Callers that receive an explicit root should ignore this.
+    pub fn library_root_override() -> Option<PathBuf> {
+        env_path(Self::LIBRARY_ROOT_ENV_VAR)
+    }
+
+    /// Model library directory from `PUMAS_MODELS_DIR`, if set.
+    pub fn model_library_dir_override() -> Option<PathBuf> {
+        env_path(Self::MODELS_DIR_ENV_VAR)
+    }
+
+    /// Cache directory: `PUMAS_CACHE_DIR` if set, else `launcher-data/cache`.
+    pub fn cache_dir(launcher_root: &Path) -> PathBuf {
+        env_path(Self::CACHE_DIR_ENV_VAR).unwrap_or_else(|| {
+            launcher_root
+                .join("launcher-data")
+                .join(Self::CACHE_DIR_NAME)
+        })
+    }
+}
+
+fn env_path(name: &str) -> Option<PathBuf> {
+    non_empty_path(std::env::var_os(name))
+}
+
+fn non_empty_path(value: Option<std::ffi::OsString>) -> Option<PathBuf> {
+    value.filter(|value| !value.is_empty()).map(PathBuf::from)
 }
 
 /// Configuration for the global library registry and IPC.
@@ -157,7 +229,7 @@ impl RegistryConfig {
 }
 
 /// App-specific configurations for multi-app support.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum AppId {
     #[default]
     ComfyUI,
@@ -168,11 +240,46 @@ pub enum AppId {
     LlamaCpp,
     Torch,
     OnnxRuntime,
+    /// App defined only by a plugin config; created via [`AppId::plugin`].
+    Plugin(Arc<str>),
 }
 
+/// Maximum length of a plugin app ID.
+const MAX_PLUGIN_ID_LEN: usize = 64;
+
 impl AppId {
+    /// Resolve a plugin config ID to an `AppId`.
+    ///
+    /// Built-in IDs return their existing variant. Other IDs must be lowercase
+    /// ASCII letters, digits, `-` or `_`, starting with a letter or digit, since
+    /// they name directories under the launcher root.
+    pub fn plugin(id: &str) -> Result<Self> {
+        if let Some(builtin) = Self::builtin_from_str(id) {
+            return Ok(builtin);
+        }
+
+        let valid = id.len() <= MAX_PLUGIN_ID_LEN
+            && id
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid {
+            return Err(PumasError::Config {
+                message: format!(
+                    "Invalid plugin app id '{}': use up to {} lowercase letters, digits, '-' or '_'",
+                    id, MAX_PLUGIN_ID_LEN
+                ),
+            });
+        }
+
+        Ok(AppId::Plugin(Arc::from(id)))
+    }
+
     /// Return the lowercase string identifier for this app (e.g. `"comfyui"`, `"ollama"`).
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             AppId::ComfyUI => "comfyui",
             AppId::Ollama => "ollama",
@@ -182,18 +289,22 @@ impl AppId {
             AppId::LlamaCpp => "llama-cpp",
             AppId::Torch => "torch",
             AppId::OnnxRuntime => "onnx-runtime",
+            AppId::Plugin(id) => id,
         }
     }
 
     /// Return whether this app is managed by the GitHub-backed version manager.
     ///
     /// ONNX Runtime is provided by the Rust backend in-process and is configured
-    /// through runtime profiles, not a separately installed app version.
+    /// through runtime profiles, not a separately installed app version. Plugin
+    /// apps declare version management in their `PluginConfig` capabilities.
     pub fn has_version_manager(&self) -> bool {
-        !matches!(self, AppId::OnnxRuntime)
+        !matches!(self, AppId::OnnxRuntime | AppId::Plugin(_))
     }
 
     /// Return the GitHub `owner/repo` path for this app's upstream repository.
+    ///
+    /// Empty for plugin apps, whose repository is `PluginConfig::github_repo`.
     pub fn github_repo(&self) -> &'static str {
         match self {
             AppId::ComfyUI => "comfyanonymous/ComfyUI",
@@ -204,24 +315,28 @@ impl AppId {
             AppId::LlamaCpp => "ggml-org/llama.cpp",
             AppId::Torch => "pytorch/pytorch",
             AppId::OnnxRuntime => "",
+            AppId::Plugin(_) => "",
         }
     }
 
     /// Return the directory name used to store installed versions of this app.
-    pub fn versions_dir_name(&self) -> &'static str {
+    pub fn versions_dir_name(&self) -> String {
         match self {
-            AppId::ComfyUI => "comfyui-versions",
-            AppId::Ollama => "ollama-versions",
-            AppId::OpenWebUI => "openwebui-versions",
-            AppId::InvokeAI => "invokeai-versions",
-            AppId::KritaDiffusion => "kritadiffusion-versions",
-            AppId::LlamaCpp => "llama-cpp-versions",
-            AppId::Torch => "torch-versions",
-            AppId::OnnxRuntime => "onnx-runtime",
+            AppId::ComfyUI => "comfyui-versions".to_string(),
+            AppId::Ollama => "ollama-versions".to_string(),
+            AppId::OpenWebUI => "openwebui-versions".to_string(),
+            AppId::InvokeAI => "invokeai-versions".to_string(),
+            AppId::KritaDiffusion => "kritadiffusion-versions".to_string(),
+            AppId::LlamaCpp => "llama-cpp-versions".to_string(),
+            AppId::Torch => "torch-versions".to_string(),
+            AppId::OnnxRuntime => "onnx-runtime".to_string(),
+            AppId::Plugin(id) => format!("{}-versions", id),
         }
     }
 
     /// Default network port for this app's local server, or 0 if none.
+    ///
+    /// Plugin apps report 0; their port is in `PluginConfig::connection`.
     pub fn default_port(&self) -> u16 {
         match self {
             AppId::ComfyUI => 8188,
@@ -245,9 +360,15 @@ impl AppId {
         }
     }
 
-    /// Parse a case-insensitive string into an `AppId`, returning `None` if unrecognized.
+    /// Parse a case-insensitive string into a built-in `AppId`, returning `None` if unrecognized.
+    ///
+    /// Plugin-defined apps resolve through `PluginLoader::app_id`.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
+        Self::builtin_from_str(s)
+    }
+
+    fn builtin_from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "comfyui" => Some(AppId::ComfyUI),
             "ollama" => Some(AppId::Ollama),
@@ -290,6 +411,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn path_overrides_ignore_empty_values() {
+        assert_eq!(non_empty_path(None), None);
+        assert_eq!(non_empty_path(Some("".into())), None);
+        assert_eq!(
+            non_empty_path(Some("/data/models".into())),
+            Some(PathBuf::from("/data/models"))
+        );
+    }
+
     #[test]
     fn onnx_runtime_is_in_process_without_version_manager() {
         assert!(!AppId::OnnxRuntime.has_version_manager());
@@ -298,6 +429,45 @@ mod tests {
         assert_eq!(AppId::OnnxRuntime.default_base_url(), "");
     }
 
+    #[test]
+    fn plugin_apps_resolve_paths_from_their_id() {
+        let app_id = AppId::plugin("test-plugin_app2").unwrap();
+
+        assert_eq!(app_id, AppId::Plugin(Arc::from("test-plugin_app2")));
+        assert_eq!(app_id.as_str(), "test-plugin_app2");
+        assert_eq!(app_id.versions_dir_name(), "test-plugin_app2-versions");
+        assert_eq!(app_id.github_repo(), "");
+        assert_eq!(app_id.default_port(), 0);
+        assert!(!app_id.has_version_manager());
+        assert_eq!(AppId::from_str("test-plugin_app2"), None);
+    }
+
+    #[test]
+    fn plugin_ids_resolve_builtins_case_insensitively() {
+        assert_eq!(AppId::plugin("ollama").unwrap(), AppId::Ollama);
+        assert_eq!(AppId::plugin("ComfyUI").unwrap(), AppId::ComfyUI);
+    }
+
+    #[test]
+    fn plugin_ids_that_are_not_directory_names_are_rejected() {
+        for id in [
+            "",
+            "../escape",
+            "nested/app",
+            "back\\slash",
+            "MyApp",
+            "-leading-dash",
+            ".hidden",
+            "with space",
+            &"a".repeat(MAX_PLUGIN_ID_LEN + 1),
+        ] {
+            assert!(
+                matches!(AppId::plugin(id), Err(PumasError::Config { .. })),
+                "{id:?} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn test_timeouts_are_reasonable() {
         assert!(InstallationConfig::UV_INSTALL_TIMEOUT > Duration::from_secs(60));
@@ -102,6 +102,12 @@ pub enum PumasError {
     #[error("Model import failed: {message}")]
     ImportFailed { message: String },
 
+    #[error("Model import cancelled")]
+    ImportCancelled,
+
+    #[error("Model index rebuild cancelled")]
+    IndexRebuildCancelled,
+
     #[error("Download failed for {url}: {message}")]
     DownloadFailed { url: String, message: String },
 
@@ -295,6 +301,8 @@ impl PumasError {
             PumasError::InstallationCancelled
             | PumasError::DownloadCancelled
             | PumasError::DownloadPaused
+            | PumasError::ImportCancelled
+            | PumasError::IndexRebuildCancelled
             | PumasError::ConversionCancelled => -32004,
 
             PumasError::Validation { .. }
@@ -4,24 +4,120 @@ use crate::Result;
 use rusqlite::{Connection, OptionalExtension};
 use tracing::{debug, info};
 
+/// Tokenizer used by the FTS5 table.
+///
+/// The choice trades recall against index size:
+///
+/// - `Unicode61` splits on whitespace and punctuation and folds diacritics, so
+///   "llama-3.1" is indexed as `llama`, `3`, `1`. Queries match whole words
+///   or word prefixes, and the index stays small. Scripts written without
+///   spaces (Chinese, Japanese) become one long token, so only the leading
+///   characters match: "日本語" finds "日本語モデル" but "モデル" does not.
+/// - `Trigram` indexes every three-character window, so any substring of three
+///   or more characters matches, including punctuation and CJK text. Terms
+///   shorter than three characters match nothing, and the index is several
+///   times larger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FTS5Tokenizer {
+    /// Word tokenizer with optional extra separator or token characters.
+    Unicode61 {
+        /// Strip diacritics so "cafe" matches "café".
+        remove_diacritics: bool,
+        /// Extra characters treated as separators.
+        separators: String,
+        /// Extra characters kept inside tokens.
+        token_chars: String,
+    },
+    /// Substring tokenizer for punctuation-heavy and non-Latin names.
+    Trigram {
+        /// Match case exactly instead of folding ASCII case.
+        case_sensitive: bool,
+    },
+}
+
+impl Default for FTS5Tokenizer {
+    fn default() -> Self {
+        Self::Unicode61 {
+            remove_diacritics: true,
+            separators: String::new(),
+            token_chars: String::new(),
+        }
+    }
+}
+
+impl FTS5Tokenizer {
+    /// Trigram tokenizer with case folding.
+    pub fn trigram() -> Self {
+        Self::Trigram {
+            case_sensitive: false,
+        }
+    }
+
+    /// Render the tokenizer as the value of the FTS5 `tokenize` option.
+    pub fn to_sql(&self) -> String {
+        match self {
+            Self::Unicode61 {
+                remove_diacritics,
+                separators,
+                token_chars,
+            } => {
+                let mut sql = format!(
+                    "unicode61 remove_diacritics {}",
+                    u8::from(*remove_diacritics)
+                );
+                if !separators.is_empty() {
+                    sql.push_str(&format!(" separators {}", quote_argument(separators)));
+                }
+                if !token_chars.is_empty() {
+                    sql.push_str(&format!(" tokenchars {}", quote_argument(token_chars)));
+                }
+                sql
+            }
+            Self::Trigram { case_sensitive } => {
+                format!("trigram case_sensitive {}", u8::from(*case_sensitive))
+            }
+        }
+    }
+}
+
+/// Quote a tokenizer argument as an FTS5 string literal.
+fn quote_argument(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
 /// Configuration for FTS5 table.
 #[derive(Debug, Clone)]
 pub struct FTS5Config {
     /// Name of the FTS5 virtual table.
     pub table_name: String,
     /// Tokenizer configuration.
-    pub tokenizer: String,
+    pub tokenizer: FTS5Tokenizer,
 }
 
 impl Default for FTS5Config {
     fn default() -> Self {
         Self {
             table_name: "model_search".to_string(),
-            tokenizer: "unicode61 remove_diacritics 1".to_string(),
+            tokenizer: FTS5Tokenizer::default(),
         }
     }
 }
 
+impl FTS5Config {
+    /// Default table with the given tokenizer.
+    pub fn with_tokenizer(tokenizer: FTS5Tokenizer) -> Self {
+        Self {
+            tokenizer,
+            ..Self::default()
+        }
+    }
+
+    /// The `tokenize='...'` clause written into the table definition.
+    fn tokenize_clause(&self) -> String {
+        format!("tokenize='{}'", self.tokenizer.to_sql().replace('\'', "''"))
+    }
+}
+
 /// Manager for FTS5 setup and maintenance.
 pub struct FTS5Manager<'a> {
     config: &'a FTS5Config,
@@ -83,10 +179,31 @@ impl<'a> FTS5Manager<'a> {
         Ok(true)
     }
 
+    /// Check whether the existing table was created with the configured tokenizer.
+    pub fn tokenizer_current(&self, conn: &Connection) -> Result<bool> {
+        let sql: Option<String> = conn
+            .query_row(
+                "SELECT sql FROM sqlite_master WHERE type='table' AND name=?1",
+                [&self.config.table_name],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(sql.is_some_and(|sql| sql.contains(&self.config.tokenize_clause())))
+    }
+
     /// Ensure FTS5 is fully set up.
+    ///
+    /// A table created with a different tokenizer is rebuilt from `models`.
     pub fn ensure_setup(&self, conn: &Connection) -> Result<()> {
         if !self.table_exists(conn)? {
             self.create_table(conn)?;
+        } else if !self.tokenizer_current(conn)? {
+            info!(
+                "FTS5 tokenizer changed to '{}', rebuilding {}",
+                self.config.tokenizer.to_sql(),
+                self.config.table_name
+            );
+            return self.rebuild(conn);
         }
 
         if !self.triggers_current(conn)? {
@@ -111,9 +228,10 @@ impl<'a> FTS5Manager<'a> {
                 tags,
                 family,
                 description,
-                tokenize='{}'
+                {}
             )",
-            self.config.table_name, self.config.tokenizer
+            self.config.table_name,
+            self.config.tokenize_clause()
         );
 
         conn.execute(&sql, [])?;
@@ -271,7 +389,7 @@ impl<'a> FTS5Manager<'a> {
         Ok(FTS5Stats {
             table_name: self.config.table_name.clone(),
             row_count,
-            tokenizer: self.config.tokenizer.clone(),
+            tokenizer: self.config.tokenizer.to_sql(),
         })
     }
 }
@@ -331,6 +449,71 @@ mod tests {
         assert!(manager.triggers_exist(&conn).unwrap());
     }
 
+    #[test]
+    fn test_fts5_tokenizer_sql() {
+        assert_eq!(
+            FTS5Tokenizer::default().to_sql(),
+            "unicode61 remove_diacritics 1"
+        );
+        let tokenizer = FTS5Tokenizer::Unicode61 {
+            remove_diacritics: false,
+            separators: ".'".to_string(),
+            token_chars: "_".to_string(),
+        };
+        assert_eq!(
+            tokenizer.to_sql(),
+            "unicode61 remove_diacritics 0 separators '.''' tokenchars '_'"
+        );
+        assert_eq!(
+            FTS5Tokenizer::trigram().to_sql(),
+            "trigram case_sensitive 0"
+        );
+
+        let (conn, _temp) = create_test_db();
+        let config = FTS5Config::with_tokenizer(tokenizer);
+        let manager = FTS5Manager::new(&config);
+        manager.ensure_setup(&conn).unwrap();
+        assert!(manager.tokenizer_current(&conn).unwrap());
+    }
+
+    #[test]
+    fn test_fts5_rebuilds_when_tokenizer_changes() {
+        let (conn, _temp) = create_test_db();
+        let config = FTS5Config::default();
+        FTS5Manager::new(&config).ensure_setup(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO models VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            [
+                "test-id",
+                "path/to/model",
+                "qwen_chat",
+                "通义千问聊天",
+                "llm",
+                "[]",
+                "{}",
+                "{}",
+                "2024-01-01T00:00:00Z",
+            ],
+        )
+        .unwrap();
+
+        let trigram = FTS5Config::with_tokenizer(FTS5Tokenizer::trigram());
+        let manager = FTS5Manager::new(&trigram);
+        assert!(!manager.tokenizer_current(&conn).unwrap());
+        manager.ensure_setup(&conn).unwrap();
+        assert!(manager.tokenizer_current(&conn).unwrap());
+        assert!(manager.triggers_current(&conn).unwrap());
+
+        let count: usize = conn
+            .query_row(
+                "SELECT COUNT(*) FROM model_search WHERE model_search MATCH '\"千问聊\"'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn test_fts5_triggers() {
         let (conn, _temp) = create_test_db();
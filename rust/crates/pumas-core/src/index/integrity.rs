@@ -0,0 +1,113 @@
+//! SQLite integrity checks and corrupt-database quarantine.
+
+use crate::{PumasError, Result};
+use rusqlite::{Connection, ErrorCode, OpenFlags};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long an integrity check waits for another connection's lock.
+const INTEGRITY_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Suffix for corrupt databases moved aside during recovery.
+const CORRUPT_SUFFIX: &str = "corrupt";
+
+/// Run `PRAGMA integrity_check` and return the problems it reports.
+///
+/// A file SQLite cannot open at all is reported as an error.
+pub(crate) fn integrity_problems(path: &Path) -> Result<Vec<String>> {
+    Ok(run_integrity_check(path)?)
+}
+
+fn run_integrity_check(path: &Path) -> rusqlite::Result<Vec<String>> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    conn.busy_timeout(INTEGRITY_BUSY_TIMEOUT)?;
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows.into_iter().filter(|row| row != "ok").collect())
+}
+
+/// Whether SQLite rejected the file itself rather than failing to reach it.
+fn is_corruption_error(err: &rusqlite::Error) -> bool {
+    matches!(
+        err.sqlite_error_code(),
+        Some(ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase)
+    )
+}
+
+/// Describe why an existing database is corrupt, or `None` if it is healthy.
+///
+/// Only `SQLITE_CORRUPT`, `SQLITE_NOTADB`, and failed `integrity_check` rows
+/// count as corruption. Any other failure (a lock held past the busy timeout,
+/// permission denied, ...) is returned as an error, since the file itself may
+/// be fine and must not be moved aside.
+pub(crate) fn corruption(path: &Path) -> Result<Option<String>> {
+    match run_integrity_check(path) {
+        Ok(problems) if problems.is_empty() => Ok(None),
+        Ok(problems) => Ok(Some(problems.join("; "))),
+        Err(err) if is_corruption_error(&err) => Ok(Some(err.to_string())),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Move a corrupt database and its WAL/SHM sidecars aside so it can be recreated.
+///
+/// Returns the path the main database file was moved to.
+pub(crate) fn quarantine_database(path: &Path) -> Result<PathBuf> {
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut moved = None;
+    for suffix in ["", "-wal", "-shm"] {
+        let mut from = path.as_os_str().to_owned();
+        from.push(suffix);
+        let from = PathBuf::from(from);
+        if !from.exists() {
+            continue;
+        }
+        let mut to = path.as_os_str().to_owned();
+        to.push(format!(".{}-{}{}", CORRUPT_SUFFIX, stamp, suffix));
+        let to = PathBuf::from(to);
+        std::fs::rename(&from, &to).map_err(|err| PumasError::io_with_path(err, &from))?;
+        if suffix.is_empty() {
+            moved = Some(to);
+        }
+    }
+    moved.ok_or_else(|| PumasError::NotFound {
+        resource: path.display().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn corrupt_database_is_detected_and_moved_aside() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("broken.sqlite");
+        std::fs::write(&path, vec![0xAB; 4096]).unwrap();
+        std::fs::write(temp_dir.path().join("broken.sqlite-wal"), b"wal").unwrap();
+
+        assert!(corruption(&path).unwrap().is_some());
+        let moved = quarantine_database(&path).unwrap();
+
+        assert!(!path.exists());
+        assert!(moved.is_file());
+        let mut moved_wal = moved.into_os_string();
+        moved_wal.push("-wal");
+        assert!(PathBuf::from(moved_wal).is_file());
+    }
+
+    #[test]
+    fn healthy_database_reports_no_corruption() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("healthy.sqlite");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER PRIMARY KEY);")
+            .unwrap();
+
+        assert_eq!(corruption(&path).unwrap(), None);
+    }
+}
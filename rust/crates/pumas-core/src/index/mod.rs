@@ -6,15 +6,20 @@
 //! - Query building and search execution
 
 mod fts5;
+pub(crate) mod integrity;
 mod model_index;
 mod query;
 
-pub use fts5::{FTS5Config, FTS5Manager};
+pub use fts5::{FTS5Config, FTS5Manager, FTS5Tokenizer};
 pub(crate) use model_index::classify_package_facts_cache_record;
 pub use model_index::{
     DependencyBindingHistoryRecord, DependencyProfileRecord, ForeignKeyViolation,
     ModelDependencyBindingRecord, ModelIndex, ModelPackageFactsCacheRecord,
     ModelPackageFactsCacheRowState, ModelPackageFactsCacheScope, ModelRecord, ModelTypeArchRule,
-    ModelTypeConfigRule, SearchResult, TaskSignatureMapping,
+    ModelTypeConfigRule, SearchResult, SearchSuggestion, TaskSignatureMapping,
+};
+pub use query::{
+    build_fts5_prefix_query, build_fts5_query, escape_fts5_term, parse_search_query,
+    quote_fts5_term, Comparison, FieldFilter, ParsedSearchQuery, SearchOptions, SearchSortBy,
+    SortDirection,
 };
-pub use query::{build_fts5_query, escape_fts5_term};
@@ -11,11 +11,11 @@ pub(crate) use package_facts_cache::classify_package_facts_cache_record;
 
 use crate::models::{
     ModelFactFamily, ModelLibraryChangeKind, ModelLibraryRefreshScope, ModelLibraryUpdateEvent,
+    ModelReadiness,
 };
 use crate::{PumasError, Result};
 use rusqlite::{params, Connection, OpenFlags, OptionalExtension, Row};
 use serde::{Deserialize, Serialize};
-#[cfg(test)]
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -25,7 +25,11 @@ use tokio::sync::broadcast;
 use tracing::{debug, error, warn};
 
 use super::fts5::{FTS5Config, FTS5Manager};
-use super::query::build_fts5_query;
+use super::integrity;
+use super::query::{
+    build_fts5_prefix_query, parse_search_query, FieldFilter, ParsedSearchQuery, SearchOptions,
+    SearchSortBy, SortDirection,
+};
 
 /// A record in the model index.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +46,40 @@ pub struct ModelRecord {
     pub updated_at: String,
 }
 
+impl ModelRecord {
+    /// Pipeline readiness projected into the row's metadata.
+    ///
+    /// Rows indexed before readiness was projected fall back to the download
+    /// completeness fields: partial downloads are `Downloading`, everything
+    /// else is `Ready`.
+    pub fn readiness(&self) -> ModelReadiness {
+        if let Some(readiness) = self
+            .metadata
+            .get("readiness")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+        {
+            return readiness;
+        }
+        let download_incomplete = self
+            .metadata
+            .get("download_incomplete")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+            || self.metadata.get("match_source").and_then(Value::as_str)
+                == Some("download_partial");
+        if download_incomplete {
+            ModelReadiness::Downloading
+        } else {
+            ModelReadiness::Ready
+        }
+    }
+
+    /// Whether the model is complete and usable.
+    pub fn is_ready(&self) -> bool {
+        self.readiness() == ModelReadiness::Ready
+    }
+}
+
 /// Package-facts cache scope.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -110,6 +148,22 @@ pub struct SearchResult {
     pub query: String,
 }
 
+/// Lightweight autocomplete match from [`ModelIndex::autocomplete`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSuggestion {
+    pub id: String,
+    pub display_name: String,
+}
+
+impl SearchResult {
+    /// Drop models that are not [`ModelReadiness::Ready`] and recount.
+    pub fn retain_ready(&mut self) {
+        self.models.retain(ModelRecord::is_ready);
+        self.total_count = self.models.len();
+    }
+}
+
 /// Active task-signature mapping row used by metadata v2 classification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -247,6 +301,8 @@ pub struct ModelIdRemapSummary {
 #[derive(Clone)]
 pub struct ModelIndex {
     db_path: PathBuf,
+    /// Backup of a corrupt database replaced when this index was opened.
+    recovered_from: Option<PathBuf>,
     conn: Arc<Mutex<Connection>>,
     fts5_config: FTS5Config,
     update_tx: broadcast::Sender<ModelLibraryUpdateEvent>,
@@ -254,7 +310,21 @@ pub struct ModelIndex {
 
 impl ModelIndex {
     /// Create or open a model index at the given path.
+    ///
+    /// An existing database that fails `PRAGMA integrity_check` (or cannot be
+    /// opened at all) is moved aside and replaced with an empty index; see
+    /// [`Self::recovered_from`]. The index only mirrors per-model
+    /// `metadata.json` files, so callers repopulate it with a rebuild.
     pub fn new(db_path: impl Into<PathBuf>) -> Result<Self> {
+        Self::with_fts5_config(db_path, FTS5Config::default())
+    }
+
+    /// Create or open a model index that searches with the given FTS5 config.
+    ///
+    /// Opening an existing index whose search table used a different
+    /// tokenizer rebuilds that table from the stored models; see
+    /// [`FTS5Tokenizer`](super::FTS5Tokenizer) for the tradeoffs.
+    pub fn with_fts5_config(db_path: impl Into<PathBuf>, fts5_config: FTS5Config) -> Result<Self> {
         let db_path = db_path.into();
 
         // Ensure parent directory exists
@@ -268,6 +338,7 @@ impl ModelIndex {
             }
         }
 
+        let recovered_from = Self::quarantine_if_corrupt(&db_path)?;
         let conn = Connection::open(&db_path)?;
         let (update_tx, _) = broadcast::channel(256);
 
@@ -279,8 +350,9 @@ impl ModelIndex {
 
         let index = Self {
             db_path,
+            recovered_from,
             conn: Arc::new(Mutex::new(conn)),
-            fts5_config: FTS5Config::default(),
+            fts5_config,
             update_tx,
         };
 
@@ -299,12 +371,35 @@ impl ModelIndex {
 
         Ok(Self {
             db_path,
+            recovered_from: None,
             conn: Arc::new(Mutex::new(conn)),
             fts5_config: FTS5Config::default(),
             update_tx,
         })
     }
 
+    /// Move a corrupt database aside, returning where it was backed up to.
+    ///
+    /// Errors that do not show corruption (such as another process holding
+    /// the database locked) are returned unchanged and the file is left alone.
+    fn quarantine_if_corrupt(db_path: &Path) -> Result<Option<PathBuf>> {
+        if !db_path.is_file() {
+            return Ok(None);
+        }
+        let Some(problem) = integrity::corruption(db_path)? else {
+            return Ok(None);
+        };
+
+        let backup = integrity::quarantine_database(db_path)?;
+        error!(
+            "Model index {} is corrupt ({}); backed it up to {} and recreating it",
+            db_path.display(),
+            problem,
+            backup.display()
+        );
+        Ok(Some(backup))
+    }
+
     /// Configure connection with optimal settings.
     fn configure_connection(conn: &Connection) -> Result<()> {
         conn.execute_batch(
@@ -394,6 +489,13 @@ impl ModelIndex {
         &self.db_path
     }
 
+    /// Backup path of a corrupt database that was replaced on open, if any.
+    ///
+    /// When set, the index started empty and needs a full rebuild.
+    pub fn recovered_from(&self) -> Option<&Path> {
+        self.recovered_from.as_deref()
+    }
+
     /// Insert or update a model record.
     ///
     /// Returns `true` when SQLite inserted or updated a row and `false` when the
@@ -689,6 +791,48 @@ impl ModelIndex {
         Ok(rows_affected > 0)
     }
 
+    /// Suggest models whose names start with the typed words.
+    ///
+    /// Meant for search-as-you-type: only `official_name` and `cleaned_name`
+    /// are matched, every word must prefix-match, and only the ID and display
+    /// name are read. Field filters such as `type:llm` are treated as text.
+    pub fn autocomplete(&self, prefix: &str, limit: usize) -> Result<Vec<SearchSuggestion>> {
+        let query = build_fts5_prefix_query(prefix);
+        if query.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.conn.lock().map_err(|_| PumasError::Database {
+            message: "Failed to acquire connection lock".to_string(),
+            source: None,
+        })?;
+        let table_name = &self.fts5_config.table_name;
+        let sql = format!(
+            "SELECT m.id, m.official_name FROM {table} ms JOIN models m ON ms.id = m.id
+             WHERE {table} MATCH ?1 ORDER BY ms.rank, m.official_name LIMIT ?2",
+            table = table_name
+        );
+        let mut stmt = conn.prepare_cached(&sql)?;
+        let rows = stmt.query_map(
+            params![
+                format!("{{official_name cleaned_name}} : ({})", query),
+                i64::try_from(limit).unwrap_or(i64::MAX)
+            ],
+            |row| {
+                Ok(SearchSuggestion {
+                    id: row.get(0)?,
+                    display_name: row.get(1)?,
+                })
+            },
+        )?;
+
+        let mut suggestions = Vec::new();
+        for row in rows {
+            suggestions.push(row?);
+        }
+        Ok(suggestions)
+    }
+
     /// Search models using FTS5 full-text search.
     pub fn search(
         &self,
@@ -697,6 +841,25 @@ impl ModelIndex {
         tags: Option<&[String]>,
         limit: usize,
         offset: usize,
+    ) -> Result<SearchResult> {
+        let options = SearchOptions {
+            model_types: model_types.map(<[String]>::to_vec).unwrap_or_default(),
+            tags: tags.map(<[String]>::to_vec).unwrap_or_default(),
+            ..SearchOptions::default()
+        };
+        self.search_with_options(query, &options, limit, offset)
+    }
+
+    /// Search models with field filters and ordering.
+    ///
+    /// Field filters in `query` (see [`parse_search_query`]) are combined with
+    /// `options`; remaining free text is matched through FTS5.
+    pub fn search_with_options(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+        limit: usize,
+        offset: usize,
     ) -> Result<SearchResult> {
         let start = Instant::now();
 
@@ -705,19 +868,8 @@ impl ModelIndex {
             source: None,
         })?;
 
-        let fts5_query = if query.trim().is_empty() {
-            String::new()
-        } else {
-            build_fts5_query(query)
-        };
-
-        let (models, total_count) = if fts5_query.is_empty() {
-            // Empty query - return all models
-            self.search_all(&conn, model_types, tags, limit, offset)?
-        } else {
-            // FTS5 search
-            self.search_fts5(&conn, &fts5_query, model_types, tags, limit, offset)?
-        };
+        let parsed = parse_search_query(query);
+        let (models, total_count) = self.search_filtered(&conn, &parsed, options, limit, offset)?;
 
         let query_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
@@ -725,128 +877,110 @@ impl ModelIndex {
             models,
             total_count,
             query_time_ms,
-            query: fts5_query,
+            query: parsed.fts5_query,
         })
     }
 
-    /// Search all models without FTS5.
-    fn search_all(
+    /// Run a search, using FTS5 only when there is free text.
+    fn search_filtered(
         &self,
         conn: &Connection,
-        model_types: Option<&[String]>,
-        tags: Option<&[String]>,
+        parsed: &ParsedSearchQuery,
+        options: &SearchOptions,
         limit: usize,
         offset: usize,
     ) -> Result<(Vec<ModelRecord>, usize)> {
-        // Build WHERE clause
-        let mut where_clause = String::from("WHERE 1=1");
+        let table_name = &self.fts5_config.table_name;
+        let use_fts5 = !parsed.fts5_query.is_empty();
+
+        let mut where_parts = Vec::new();
         let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
-        // Add model type filter
-        if let Some(types) = model_types {
-            if !types.is_empty() {
-                let placeholders: Vec<_> = types.iter().map(|_| "?").collect();
-                where_clause.push_str(&format!(" AND model_type IN ({})", placeholders.join(",")));
-                for t in types {
-                    params_vec.push(Box::new(t.clone()));
-                }
-            }
+        if use_fts5 {
+            where_parts.push(format!("{} MATCH ?", table_name));
+            params_vec.push(Box::new(parsed.fts5_query.clone()));
         }
 
-        // Get total count first
-        let count_sql = format!("SELECT COUNT(*) FROM models {}", where_clause);
-        let total_count: usize = {
-            let mut stmt = conn.prepare(&count_sql)?;
-            let params_refs: Vec<&dyn rusqlite::ToSql> =
-                params_vec.iter().map(|p| p.as_ref()).collect();
-            stmt.query_row(params_refs.as_slice(), |row| row.get(0))?
-        };
-
-        // Build full query with pagination
-        let sql = format!(
-            "SELECT id, path, cleaned_name, official_name, model_type, \
-             tags_json, hashes_json, metadata_json, updated_at \
-             FROM models {} ORDER BY updated_at DESC LIMIT {} OFFSET {}",
-            where_clause, limit, offset
-        );
-
-        let mut stmt = conn.prepare(&sql)?;
-        let params_refs: Vec<&dyn rusqlite::ToSql> =
-            params_vec.iter().map(|p| p.as_ref()).collect();
-
-        let rows = stmt.query_map(params_refs.as_slice(), Self::row_to_record)?;
-
-        let mut models = Vec::new();
-        for row in rows {
-            match row {
-                Ok(record) => {
-                    // Apply tag filter in post-processing
-                    if let Some(required_tags) = tags {
-                        if required_tags
-                            .iter()
-                            .all(|t| record.tags.iter().any(|rt| rt.eq_ignore_ascii_case(t)))
-                        {
-                            models.push(record);
-                        }
-                    } else {
-                        models.push(record);
-                    }
-                }
-                Err(e) => {
-                    warn!("Error reading model row: {}", e);
-                }
+        if !options.model_types.is_empty() {
+            let placeholders: Vec<_> = options.model_types.iter().map(|_| "?").collect();
+            where_parts.push(format!("m.model_type IN ({})", placeholders.join(",")));
+            for t in &options.model_types {
+                params_vec.push(Box::new(t.clone()));
             }
         }
 
-        Ok((models, total_count))
-    }
+        let families = options
+            .family
+            .iter()
+            .cloned()
+            .chain(parsed.filters.iter().filter_map(|filter| match filter {
+                FieldFilter::Family(family) => Some(family.clone()),
+                _ => None,
+            }));
+        for family in families {
+            where_parts.push("lower(json_extract(m.metadata_json, '$.family')) = lower(?)".into());
+            params_vec.push(Box::new(family));
+        }
 
-    /// Search using FTS5.
-    fn search_fts5(
-        &self,
-        conn: &Connection,
-        fts5_query: &str,
-        model_types: Option<&[String]>,
-        tags: Option<&[String]>,
-        limit: usize,
-        offset: usize,
-    ) -> Result<(Vec<ModelRecord>, usize)> {
-        let table_name = &self.fts5_config.table_name;
+        let tags = options
+            .tags
+            .iter()
+            .cloned()
+            .chain(parsed.filters.iter().filter_map(|filter| match filter {
+                FieldFilter::Tag(tag) => Some(tag.clone()),
+                _ => None,
+            }));
+        for tag in tags {
+            where_parts.push(
+                "EXISTS (SELECT 1 FROM json_each(m.tags_json) \
+                 WHERE lower(json_each.value) = lower(?))"
+                    .into(),
+            );
+            params_vec.push(Box::new(tag));
+        }
 
-        // Build WHERE clause
-        let mut where_parts = vec![format!("{} MATCH ?", table_name)];
-        let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(fts5_query.to_string())];
-
-        // Add model type filter
-        if let Some(types) = model_types {
-            if !types.is_empty() {
-                let placeholders: Vec<_> = types.iter().map(|_| "?").collect();
-                where_parts.push(format!("m.model_type IN ({})", placeholders.join(",")));
-                for t in types {
-                    params_vec.push(Box::new(t.clone()));
+        for filter in &parsed.filters {
+            match filter {
+                FieldFilter::ModelType(model_type) => {
+                    where_parts.push("lower(m.model_type) = lower(?)".into());
+                    params_vec.push(Box::new(model_type.clone()));
+                }
+                FieldFilter::SizeBytes(comparison, bytes) => {
+                    where_parts.push(format!(
+                        "CAST(json_extract(m.metadata_json, '$.size_bytes') AS INTEGER) {} ?",
+                        comparison.as_sql()
+                    ));
+                    params_vec.push(Box::new(i64::try_from(*bytes).unwrap_or(i64::MAX)));
                 }
+                FieldFilter::Family(_) | FieldFilter::Tag(_) => {}
             }
         }
 
-        let where_clause = where_parts.join(" AND ");
+        let from_clause = if use_fts5 {
+            format!("{} ms JOIN models m ON ms.id = m.id", table_name)
+        } else {
+            "models m".to_string()
+        };
+        let where_clause = if where_parts.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_parts.join(" AND "))
+        };
 
         // Get total count first
-        let count_sql = format!(
-            "SELECT COUNT(*) FROM {} ms JOIN models m ON ms.id = m.id WHERE {}",
-            table_name, where_clause
-        );
-
+        let count_sql = format!("SELECT COUNT(*) FROM {} {}", from_clause, where_clause);
         let total_count: usize = {
             let mut stmt = conn.prepare(&count_sql)?;
             let params_refs: Vec<&dyn rusqlite::ToSql> =
                 params_vec.iter().map(|p| p.as_ref()).collect();
             match stmt.query_row(params_refs.as_slice(), |row| row.get(0)) {
                 Ok(count) => count,
-                Err(e) => {
+                Err(e) if use_fts5 => {
                     // FTS5 query may fail - return 0
                     error!("FTS5 count query failed: {}", e);
                     0
                 }
+                Err(e) => return Err(e.into()),
             }
         };
 
@@ -854,9 +988,12 @@ impl ModelIndex {
         let sql = format!(
             "SELECT m.id, m.path, m.cleaned_name, m.official_name, m.model_type, \
              m.tags_json, m.hashes_json, m.metadata_json, m.updated_at \
-             FROM {} ms JOIN models m ON ms.id = m.id \
-             WHERE {} ORDER BY rank LIMIT {} OFFSET {}",
-            table_name, where_clause, limit, offset
+             FROM {} {} ORDER BY {} LIMIT {} OFFSET {}",
+            from_clause,
+            where_clause,
+            Self::order_by_clause(options, use_fts5),
+            limit,
+            offset
         );
 
         let mut stmt = conn.prepare(&sql)?;
@@ -865,28 +1002,17 @@ impl ModelIndex {
 
         let rows = match stmt.query_map(params_refs.as_slice(), Self::row_to_record) {
             Ok(rows) => rows,
-            Err(e) => {
+            Err(e) if use_fts5 => {
                 error!("FTS5 search failed: {}", e);
                 return Ok((vec![], 0));
             }
+            Err(e) => return Err(e.into()),
         };
 
         let mut models = Vec::new();
         for row in rows {
             match row {
-                Ok(record) => {
-                    // Apply tag filter in post-processing
-                    if let Some(required_tags) = tags {
-                        if required_tags
-                            .iter()
-                            .all(|t| record.tags.iter().any(|rt| rt.eq_ignore_ascii_case(t)))
-                        {
-                            models.push(record);
-                        }
-                    } else {
-                        models.push(record);
-                    }
-                }
+                Ok(record) => models.push(record),
                 Err(e) => {
                     warn!("Error reading model row: {}", e);
                 }
@@ -896,6 +1022,26 @@ impl ModelIndex {
         Ok((models, total_count))
     }
 
+    /// ORDER BY clause for a search, with the model ID as a stable tiebreak.
+    fn order_by_clause(options: &SearchOptions, use_fts5: bool) -> String {
+        let sort_by = options.sort_by.unwrap_or_default();
+        let direction = match options.sort_dir.unwrap_or(sort_by.default_direction()) {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        };
+        let column = match sort_by {
+            // FTS5 rank is more negative for better matches.
+            SearchSortBy::Relevance if use_fts5 => {
+                let direction = if direction == "DESC" { "ASC" } else { "DESC" };
+                return format!("rank {}, m.id", direction);
+            }
+            SearchSortBy::Relevance | SearchSortBy::UpdatedAt => "m.updated_at",
+            SearchSortBy::Name => "m.official_name COLLATE NOCASE",
+            SearchSortBy::Size => "CAST(json_extract(m.metadata_json, '$.size_bytes') AS INTEGER)",
+        };
+        format!("{} {}, m.id", column, direction)
+    }
+
     /// Convert a row to a ModelRecord.
     fn row_to_record(row: &Row) -> rusqlite::Result<ModelRecord> {
         let tags_json: String = row.get(5)?;
@@ -1088,6 +1234,55 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn new_replaces_corrupt_database_with_empty_index() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("models.db");
+        std::fs::write(&db_path, vec![0xAB; 8192]).unwrap();
+
+        let index = ModelIndex::new(&db_path).unwrap();
+
+        let backup = index.recovered_from().unwrap().to_path_buf();
+        assert!(backup.is_file());
+        assert_eq!(std::fs::read(&backup).unwrap(), vec![0xAB; 8192]);
+        assert_eq!(index.count().unwrap(), 0);
+        drop(index);
+
+        let reopened = ModelIndex::new(&db_path).unwrap();
+        assert!(reopened.recovered_from().is_none());
+    }
+
+    #[test]
+    fn new_leaves_locked_database_in_place() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("models.db");
+        let holder = Connection::open(&db_path).unwrap();
+        holder
+            .execute_batch(
+                "CREATE TABLE held (id INTEGER PRIMARY KEY);
+                 INSERT INTO held VALUES (1);
+                 BEGIN EXCLUSIVE;",
+            )
+            .unwrap();
+
+        assert!(ModelIndex::new(&db_path).is_err());
+
+        let entries: Vec<_> = std::fs::read_dir(temp.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert!(
+            entries.iter().all(|name| !name.contains("corrupt")),
+            "{:?}",
+            entries
+        );
+        holder.execute_batch("COMMIT;").unwrap();
+        let held: i64 = holder
+            .query_row("SELECT COUNT(*) FROM held", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(held, 1);
+    }
+
     fn pinned_profile_spec(package: &str, version: &str) -> String {
         serde_json::json!({
             "python_packages": [
@@ -1627,6 +1822,79 @@ mod tests {
         assert_eq!(result.models[0].id, "stable-diffusion");
     }
 
+    #[test]
+    fn test_search_trigram_tokenizer_matches_substrings_after_reopen() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("models.db");
+        let index = ModelIndex::new(&db_path).unwrap();
+        index
+            .upsert(&create_test_record(
+                "llama-3.1-8b",
+                "Llama-3.1-8B-Instruct",
+                "llm",
+            ))
+            .unwrap();
+        index
+            .upsert(&create_test_record("jp-model", "日本語モデル", "llm"))
+            .unwrap();
+
+        let result = index.search("モデル", None, None, 10, 0).unwrap();
+        assert!(result.models.is_empty());
+        drop(index);
+
+        let config = FTS5Config::with_tokenizer(crate::index::FTS5Tokenizer::trigram());
+        let index = ModelIndex::with_fts5_config(&db_path, config).unwrap();
+
+        let result = index.search("モデル", None, None, 10, 0).unwrap();
+        assert_eq!(result.models.len(), 1);
+        assert_eq!(result.models[0].id, "jp-model");
+
+        let result = index.search("llama-3.1", None, None, 10, 0).unwrap();
+        assert_eq!(result.models.len(), 1);
+        assert_eq!(result.models[0].id, "llama-3.1-8b");
+    }
+
+    #[test]
+    fn test_autocomplete_prefix_matches_names() {
+        let (index, _temp) = create_test_index();
+        index
+            .upsert(&create_test_record("llama-7b", "Llama 7B", "llm"))
+            .unwrap();
+        index
+            .upsert(&create_test_record(
+                "llama-3.1-8b",
+                "Llama-3.1 8B Instruct",
+                "llm",
+            ))
+            .unwrap();
+        index
+            .upsert(&create_test_record(
+                "sd-15",
+                "Stable Diffusion v1.5",
+                "diffusion",
+            ))
+            .unwrap();
+
+        let suggestions = index.autocomplete("lla", 10).unwrap();
+        assert_eq!(suggestions.len(), 2);
+
+        let suggestions = index.autocomplete("llama-3.1 ins", 10).unwrap();
+        assert_eq!(
+            suggestions,
+            vec![SearchSuggestion {
+                id: "llama-3.1-8b".to_string(),
+                display_name: "Llama-3.1 8B Instruct".to_string(),
+            }]
+        );
+
+        assert_eq!(index.autocomplete("lla", 1).unwrap().len(), 1);
+        // Tags and descriptions are not name fields.
+        assert!(index.autocomplete("test", 10).unwrap().is_empty());
+        // FTS5 syntax in user input is matched literally.
+        assert!(index.autocomplete("(sta OR \"*", 10).unwrap().is_empty());
+        assert!(index.autocomplete("", 10).unwrap().is_empty());
+    }
+
     #[test]
     fn test_search_by_type() {
         let (index, _temp) = create_test_index();
@@ -1645,6 +1913,91 @@ mod tests {
         assert_eq!(result.models.len(), 2);
     }
 
+    #[test]
+    fn test_search_with_field_filters_and_sort() {
+        let (index, _temp) = create_test_index();
+
+        for (id, name, family, size, tags) in [
+            (
+                "llama-small",
+                "Llama Small",
+                "llama",
+                1_000u64,
+                vec!["chat"],
+            ),
+            (
+                "llama-large",
+                "Llama Large",
+                "llama",
+                5_000_000_000,
+                vec!["chat", "vision"],
+            ),
+            (
+                "mistral-chat",
+                "Mistral Chat",
+                "mistral",
+                2_000,
+                vec!["chat"],
+            ),
+        ] {
+            let mut record = create_test_record(id, name, "llm");
+            record.tags = tags.into_iter().map(String::from).collect();
+            record.metadata = serde_json::json!({"family": family, "size_bytes": size});
+            index.upsert(&record).unwrap();
+        }
+        index
+            .upsert(&create_test_record("llama-lora", "Llama Lora", "lora"))
+            .unwrap();
+
+        let by_size = SearchOptions {
+            sort_by: Some(SearchSortBy::Size),
+            ..SearchOptions::default()
+        };
+        let result = index
+            .search_with_options("family:llama type:llm", &by_size, 10, 0)
+            .unwrap();
+        let ids: Vec<_> = result.models.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["llama-large", "llama-small"]);
+        assert_eq!(result.total_count, 2);
+
+        let result = index
+            .search_with_options("chat size<1gb", &SearchOptions::default(), 10, 0)
+            .unwrap();
+        assert_eq!(result.query, "chat*");
+        assert_eq!(result.total_count, 2);
+
+        let by_name = SearchOptions {
+            tags: vec!["Chat".to_string()],
+            sort_by: Some(SearchSortBy::Name),
+            sort_dir: Some(SortDirection::Desc),
+            ..SearchOptions::default()
+        };
+        let result = index.search_with_options("", &by_name, 10, 0).unwrap();
+        let ids: Vec<_> = result.models.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["mistral-chat", "llama-small", "llama-large"]);
+    }
+
+    #[test]
+    fn test_search_treats_unknown_fields_as_text() {
+        let (index, _temp) = create_test_index();
+        let mut record = create_test_record("llama-gguf", "Llama GGUF", "llm");
+        record.metadata = serde_json::json!({"family": "llama"});
+        index.upsert(&record).unwrap();
+        index
+            .upsert(&create_test_record("author-me", "Author:me Notes", "llm"))
+            .unwrap();
+
+        let result = index.search("type:llm family:llama gguf", None, None, 10, 0);
+        let ids: Vec<_> = result.unwrap().models.into_iter().map(|m| m.id).collect();
+        assert_eq!(ids, ["llama-gguf"]);
+
+        for query in ["author:me", "downloads>1000", "type:", "(llama"] {
+            assert!(index.search(query, None, None, 10, 0).is_ok(), "{query}");
+        }
+        let result = index.search("author:me", None, None, 10, 0).unwrap();
+        assert_eq!(result.models[0].id, "author-me");
+    }
+
     #[test]
     fn test_count() {
         let (index, _temp) = create_test_index();
@@ -1,14 +1,115 @@
 //! FTS5 query building utilities.
+//!
+//! Search text may mix free text with field filters: `type:llm`,
+//! `family:llama`, `tag:chat`, and size comparisons such as `size>4gb`. Free
+//! text goes to the FTS5 index; filters become SQL conditions on the models
+//! table. Tokens that look like filters but name an unknown field or carry an
+//! unparsable value stay free text.
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
-/// Special characters that need escaping in FTS5 queries.
-static FTS5_SPECIAL_CHARS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[-._"]"#).unwrap());
+/// Characters that cannot appear in an FTS5 bareword (ASCII other than
+/// letters and digits). Underscore is allowed by FTS5 but quoted anyway so
+/// `vae_decoder` matches as a phrase.
+static FTS5_SPECIAL_CHARS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\x00-\x2F\x3A-\x40\x5B-\x60\x7B-\x7F]").unwrap());
+
+/// `field:value` or `field<op>number` tokens.
+static FIELD_FILTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([a-z_]+)(:|>=|<=|>|<|=)(.+)$").unwrap());
+
+/// Column a search can be sorted by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSortBy {
+    /// Best FTS5 match first; most recently updated first without free text.
+    #[default]
+    Relevance,
+    Name,
+    UpdatedAt,
+    Size,
+}
+
+/// Direction for [`SearchSortBy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SearchSortBy {
+    /// Direction used when none is requested: A-Z for names, largest or
+    /// newest first otherwise, best match first for relevance.
+    pub fn default_direction(self) -> SortDirection {
+        match self {
+            Self::Name => SortDirection::Asc,
+            Self::Relevance | Self::UpdatedAt | Self::Size => SortDirection::Desc,
+        }
+    }
+}
+
+/// Filters and ordering for a model search, on top of the query text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchOptions {
+    /// Keep models of any of these types; empty keeps all.
+    pub model_types: Vec<String>,
+    /// Keep models of this family (case-insensitive).
+    pub family: Option<String>,
+    /// Keep models carrying every one of these tags (case-insensitive).
+    pub tags: Vec<String>,
+    /// Result order. `None` keeps the library's default ordering.
+    pub sort_by: Option<SearchSortBy>,
+    /// Defaults to [`SearchSortBy::default_direction`].
+    pub sort_dir: Option<SortDirection>,
+}
+
+/// Comparison in a numeric field filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl Comparison {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+        }
+    }
+}
+
+/// A field filter parsed out of search text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldFilter {
+    ModelType(String),
+    Family(String),
+    Tag(String),
+    SizeBytes(Comparison, u64),
+}
+
+/// Search text split into an FTS5 query and field filters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedSearchQuery {
+    /// FTS5 query for the free text; empty when there is none.
+    pub fts5_query: String,
+    pub filters: Vec<FieldFilter>,
+}
 
 /// Escape a term for FTS5 queries.
 ///
-/// Terms containing special characters (hyphen, dot, underscore, quote) are wrapped in quotes.
+/// Terms containing punctuation (hyphen, dot, underscore, quote, colon, ...)
+/// are wrapped in quotes, so `author:me` is not read as a column filter.
 pub fn escape_fts5_term(term: &str) -> String {
     if FTS5_SPECIAL_CHARS.is_match(term) {
         // Double any existing quotes and wrap in quotes
@@ -19,23 +120,57 @@ pub fn escape_fts5_term(term: &str) -> String {
     }
 }
 
+/// Quote a term as an FTS5 string, whatever characters it contains.
+///
+/// Unlike [`escape_fts5_term`] this never leaves a bareword, so FTS5 syntax
+/// such as `(`, `*`, `:` or `NOT` in user input is matched literally.
+pub fn quote_fts5_term(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+/// Build an FTS5 prefix query for search-as-you-type input.
+///
+/// Every term is quoted and prefix-matched, and all terms must match:
+/// - "lla" → `"lla"*`
+/// - "llama 3.1" → `"llama"* "3.1"*`
+/// - "(inst" → `"(inst"*`
+///
+/// Field filters are not parsed; the input is matched as text.
+pub fn build_fts5_prefix_query(prefix: &str) -> String {
+    prefix
+        .to_lowercase()
+        .split_whitespace()
+        .map(|term| format!("{}*", quote_fts5_term(term)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Build an FTS5 query string from a search term.
 ///
 /// The query uses OR matching with prefix support:
 /// - "gpt-2 model" → `"gpt-2"* OR model*`
 /// - "stable.diffusion" → `"stable.diffusion"*`
 /// - "vae_decoder" → `"vae_decoder"*`
+///
+/// Field filters are left out; see [`parse_search_query`].
 pub fn build_fts5_query(search_term: &str) -> String {
-    let search_term = search_term.to_lowercase().trim().to_string();
-
-    if search_term.is_empty() {
-        return String::new();
-    }
+    parse_search_query(search_term).fts5_query
+}
 
-    let terms: Vec<&str> = search_term.split_whitespace().collect();
+/// Split search text into free text for FTS5 and field filters.
+///
+/// - "family:llama type:llm chat" → filters on family and type, FTS5 `chat*`
+/// - "tag:vision size<2gb" → tag filter plus `size_bytes < 2 GiB`
+pub fn parse_search_query(search_term: &str) -> ParsedSearchQuery {
+    let search_term = search_term.to_lowercase();
+    let mut parsed = ParsedSearchQuery::default();
     let mut query_parts = Vec::new();
 
-    for term in terms {
+    for term in search_term.split_whitespace() {
+        if let Some(filter) = parse_field_filter(term) {
+            parsed.filters.push(filter);
+            continue;
+        }
         let escaped = escape_fts5_term(term);
         if !escaped.is_empty() {
             // Add prefix matching with *
@@ -43,7 +178,46 @@ pub fn build_fts5_query(search_term: &str) -> String {
         }
     }
 
-    query_parts.join(" OR ")
+    parsed.fts5_query = query_parts.join(" OR ");
+    parsed
+}
+
+fn parse_field_filter(term: &str) -> Option<FieldFilter> {
+    let captures = FIELD_FILTER.captures(term)?;
+    let (field, op, value) = (&captures[1], &captures[2], &captures[3]);
+    let text = || (op == ":").then(|| value.to_string());
+    match field {
+        "type" | "model_type" => text().map(FieldFilter::ModelType),
+        "family" => text().map(FieldFilter::Family),
+        "tag" | "tags" => text().map(FieldFilter::Tag),
+        "size" => {
+            let comparison = match op {
+                ":" | "=" => Comparison::Eq,
+                ">" => Comparison::Gt,
+                ">=" => Comparison::Ge,
+                "<" => Comparison::Lt,
+                "<=" => Comparison::Le,
+                _ => return None,
+            };
+            Some(FieldFilter::SizeBytes(comparison, parse_size(value)?))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a byte count with an optional binary `kb`/`mb`/`gb`/`tb` suffix.
+fn parse_size(value: &str) -> Option<u64> {
+    let (number, multiplier) = [
+        ("tb", 1u64 << 40),
+        ("gb", 1 << 30),
+        ("mb", 1 << 20),
+        ("kb", 1 << 10),
+    ]
+    .into_iter()
+    .find_map(|(suffix, multiplier)| Some((value.strip_suffix(suffix)?, multiplier)))
+    .unwrap_or((value.strip_suffix('b').unwrap_or(value), 1));
+    let number: f64 = number.parse().ok()?;
+    (number.is_finite() && number >= 0.0).then_some((number * multiplier as f64) as u64)
 }
 
 #[cfg(test)]
@@ -99,9 +273,54 @@ mod tests {
         assert_eq!(build_fts5_query("   "), "");
     }
 
+    #[test]
+    fn test_parse_search_query_splits_field_filters_from_free_text() {
+        let parsed = parse_search_query("Family:Llama type:llm chat tag:vision size>=1.5gb");
+
+        assert_eq!(parsed.fts5_query, "chat*");
+        assert_eq!(
+            parsed.filters,
+            vec![
+                FieldFilter::Family("llama".to_string()),
+                FieldFilter::ModelType("llm".to_string()),
+                FieldFilter::Tag("vision".to_string()),
+                FieldFilter::SizeBytes(Comparison::Ge, 1_610_612_736),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_search_query_keeps_unknown_fields_as_free_text() {
+        let parsed = parse_search_query("downloads>1000 size>big author:me");
+
+        assert!(parsed.filters.is_empty());
+        assert_eq!(
+            parsed.fts5_query,
+            "\"downloads>1000\"* OR \"size>big\"* OR \"author:me\"*"
+        );
+
+        let parsed = parse_search_query("type: family:llama");
+        assert_eq!(
+            parsed.filters,
+            vec![FieldFilter::Family("llama".to_string())]
+        );
+        assert_eq!(parsed.fts5_query, "\"type:\"*");
+    }
+
     #[test]
     fn test_build_query_case_insensitive() {
         assert_eq!(build_fts5_query("LLAMA"), "llama*");
         assert_eq!(build_fts5_query("GPT-2"), "\"gpt-2\"*");
     }
+
+    #[test]
+    fn test_build_prefix_query_quotes_every_term() {
+        assert_eq!(build_fts5_prefix_query("Lla"), "\"lla\"*");
+        assert_eq!(build_fts5_prefix_query("llama 3.1"), "\"llama\"* \"3.1\"*");
+        assert_eq!(
+            build_fts5_prefix_query("(inst NOT \"x"),
+            "\"(inst\"* \"not\"* \"\"\"x\"*"
+        );
+        assert_eq!(build_fts5_prefix_query("  "), "");
+    }
 }
@@ -8,5 +8,8 @@
 mod patch;
 mod updater;
 
-pub use patch::PatchManager;
-pub use updater::{LauncherUpdater, UpdateApplyResult, UpdateCheckResult};
+pub use patch::{PatchManager, PatchState, VersionPatchResult};
+pub use updater::{
+    ChangedFile, LauncherUpdater, UpdateApplyResult, UpdateCheckResult, UpdatePhase,
+    UpdatePhaseResult, UpdatePreview,
+};
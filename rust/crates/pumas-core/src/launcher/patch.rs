@@ -1,11 +1,78 @@
 //! Patch manager for ComfyUI process naming.
 //!
 //! Manages patching of ComfyUI's main.py with setproctitle for process identification.
+//!
+//! main.py is backed up to `main.py.bak` before it is modified, and every write is
+//! re-read and verified; a patch that does not land cleanly is rolled back.
 
 use crate::error::{PumasError, Result};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use tracing::{debug, info};
+use std::sync::LazyLock;
+use tracing::{debug, info, warn};
+
+/// Matches any ComfyUI Server setproctitle call.
+static TITLE_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"setproctitle\.setproctitle\(["']ComfyUI Server[^"']*["']\)"#).unwrap()
+});
+
+/// Matches one complete inserted patch block.
+static PATCH_BLOCK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r#"try:[ \t]*\r?\n[ \t]*import setproctitle[ \t]*\r?\n"#,
+        r#"[ \t]*setproctitle\.setproctitle\(["']ComfyUI Server[^"']*["']\)[ \t]*\r?\n"#,
+        r#"[ \t]*except ImportError:[ \t]*\r?\n[ \t]*pass"#,
+    ))
+    .unwrap()
+});
+
+/// Patch state of a version's main.py.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatchState {
+    /// No main.py was found.
+    Missing,
+    /// main.py contains no patch code.
+    Unpatched,
+    /// main.py contains exactly one complete patch block.
+    Patched,
+    /// Patch fragments are present but do not form one complete block.
+    Corrupted,
+}
+
+impl PatchState {
+    /// Classify main.py contents.
+    fn of(content: &str) -> Self {
+        let calls = TITLE_CALL_RE.find_iter(content).count();
+        let imports = content
+            .lines()
+            .filter(|line| line.trim() == "import setproctitle")
+            .count();
+        let blocks = PATCH_BLOCK_RE.find_iter(content).count();
+
+        match (calls, imports, blocks) {
+            (0, 0, _) => Self::Unpatched,
+            (1, 1, 1) => Self::Patched,
+            _ => Self::Corrupted,
+        }
+    }
+}
+
+/// Outcome of patching one installed version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct VersionPatchResult {
+    /// Version tag (directory name under the versions directory).
+    pub tag: String,
+    /// Whether the version ended up in the requested state.
+    pub success: bool,
+    /// Patch state after the operation.
+    pub state: PatchState,
+    /// Error message if the operation failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
 
 /// Manages main.py patching for ComfyUI versions.
 pub struct PatchManager {
@@ -72,51 +139,97 @@ impl PatchManager {
         None
     }
 
-    /// Check if main.py is patched with setproctitle.
+    /// Check if main.py is cleanly patched with setproctitle.
+    ///
+    /// A partially applied patch counts as not patched; use
+    /// [`Self::patch_state`] to tell it apart.
     ///
     /// # Arguments
     ///
     /// * `tag` - Optional version tag to check
     pub fn is_patched(&self, tag: Option<&str>) -> bool {
-        let main_py = match self.get_target_main_py(tag) {
-            Some(p) => p,
-            None => return false,
-        };
-
-        self.check_patched(&main_py, None)
+        self.patch_state(tag) == PatchState::Patched
     }
 
-    /// Check if a specific main.py is patched.
+    /// Get the patch state of main.py.
     ///
     /// # Arguments
     ///
-    /// * `main_py` - Path to main.py
-    /// * `expected_title` - Optional exact title to look for
-    fn check_patched(&self, main_py: &Path, expected_title: Option<&str>) -> bool {
-        let content = match std::fs::read_to_string(main_py) {
-            Ok(c) => c,
+    /// * `tag` - Optional version tag to check
+    pub fn patch_state(&self, tag: Option<&str>) -> PatchState {
+        match self.get_target_main_py(tag) {
+            Some(main_py) => self.read_state(&main_py),
+            None => PatchState::Missing,
+        }
+    }
+
+    /// Read and classify a specific main.py.
+    fn read_state(&self, main_py: &Path) -> PatchState {
+        match std::fs::read_to_string(main_py) {
+            Ok(content) => PatchState::of(&content),
             Err(e) => {
                 debug!("Error reading {:?} to check patch state: {}", main_py, e);
-                return false;
+                PatchState::Missing
             }
-        };
+        }
+    }
+
+    /// Path of the pre-patch backup for a main.py.
+    fn backup_path(main_py: &Path) -> PathBuf {
+        main_py.with_extension("py.bak")
+    }
+
+    /// Restore main.py from the backup taken before patching.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Optional version tag to restore
+    pub fn restore_backup(&self, tag: Option<&str>) -> Result<()> {
+        let main_py = self
+            .get_target_main_py(tag)
+            .ok_or_else(|| PumasError::NotFound {
+                resource: format!("main.py for version {:?}", tag),
+            })?;
 
-        if let Some(title) = expected_title {
-            // Check for exact title
-            content.contains(&format!("setproctitle.setproctitle(\"{}\")", title))
-                || content.contains(&format!("setproctitle.setproctitle('{}')", title))
+        if self.restore_from_backup(&main_py)? {
+            Ok(())
         } else {
-            // Check for any ComfyUI Server setproctitle call
-            let pattern =
-                Regex::new(r#"setproctitle\.setproctitle\(["']ComfyUI Server[^"']*["']\)"#)
-                    .unwrap();
-            pattern.is_match(&content)
+            Err(PumasError::NotFound {
+                resource: format!("main.py backup for version {:?}", tag),
+            })
+        }
+    }
+
+    /// Copy the backup over main.py and remove it.
+    ///
+    /// Returns `false` if there is no usable backup.
+    fn restore_from_backup(&self, main_py: &Path) -> Result<bool> {
+        let backup = Self::backup_path(main_py);
+        if !backup.exists() {
+            return Ok(false);
         }
+        if self.read_state(&backup) != PatchState::Unpatched {
+            warn!(
+                "Ignoring backup {:?}: it is not an unpatched main.py",
+                backup
+            );
+            return Ok(false);
+        }
+
+        std::fs::copy(&backup, main_py).map_err(|e| PumasError::Io {
+            message: format!("Failed to restore from backup: {}", e),
+            path: Some(main_py.to_path_buf()),
+            source: Some(e),
+        })?;
+        std::fs::remove_file(&backup).ok();
+        info!("Restored {:?} from backup", main_py);
+        Ok(true)
     }
 
     /// Toggle the patch state for a version.
     ///
-    /// If patched, removes the patch. If not patched, applies it.
+    /// If patched (or partially patched), removes the patch. If not patched,
+    /// applies it.
     ///
     /// # Arguments
     ///
@@ -135,17 +248,68 @@ impl PatchManager {
             }
         };
 
-        if self.check_patched(&main_py, None) {
-            // Currently patched - revert
-            self.revert_patch(&main_py, tag)?;
-            Ok(false)
-        } else {
-            // Not patched - apply
-            self.apply_patch(&main_py, tag)?;
-            Ok(true)
+        match self.read_state(&main_py) {
+            PatchState::Patched | PatchState::Corrupted => {
+                self.revert_patch(&main_py, tag)?;
+                Ok(false)
+            }
+            PatchState::Unpatched | PatchState::Missing => {
+                self.apply_patch(&main_py, tag)?;
+                Ok(true)
+            }
         }
     }
 
+    /// Apply or remove the patch for every installed version.
+    ///
+    /// Versions already in the requested state are left untouched. One
+    /// version failing does not stop the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `versions_dir` - Directory containing one subdirectory per version tag
+    /// * `enable` - `true` to patch, `false` to unpatch
+    pub fn apply_to_all(versions_dir: &Path, enable: bool) -> Result<Vec<VersionPatchResult>> {
+        let entries = std::fs::read_dir(versions_dir).map_err(|e| PumasError::Io {
+            message: format!("Failed to read versions directory: {}", e),
+            path: Some(versions_dir.to_path_buf()),
+            source: Some(e),
+        })?;
+
+        let mut tags: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().join("main.py").is_file())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|tag| !tag.starts_with('.'))
+            .collect();
+        tags.sort();
+
+        let results = tags
+            .into_iter()
+            .map(|tag| {
+                let main_py = versions_dir.join(&tag).join("main.py");
+                let patch_mgr = Self::new(versions_dir, &main_py, Some(versions_dir.to_path_buf()));
+                let outcome = match (enable, patch_mgr.read_state(&main_py)) {
+                    (false, PatchState::Unpatched) => Ok(()),
+                    (false, _) => patch_mgr.revert_patch(&main_py, Some(&tag)),
+                    (true, _) => patch_mgr.apply_patch(&main_py, Some(&tag)),
+                };
+                let state = patch_mgr.read_state(&main_py);
+                if let Err(ref e) = outcome {
+                    warn!("Failed to update patch for {}: {}", tag, e);
+                }
+                VersionPatchResult {
+                    success: outcome.is_ok(),
+                    error: outcome.err().map(|e| e.to_string()),
+                    tag,
+                    state,
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
     /// Apply the setproctitle patch to main.py.
     ///
     /// # Arguments
@@ -162,16 +326,24 @@ impl PatchManager {
             source: Some(e),
         })?;
 
+        let state = PatchState::of(&content);
+        if state == PatchState::Corrupted {
+            return Err(PumasError::Other(format!(
+                "{} contains a partially applied patch; restore it before patching",
+                main_py.display()
+            )));
+        }
+
         // Already patched with correct title
-        if content.contains(&expected_line) {
+        if state == PatchState::Patched && content.contains(&expected_line) {
             debug!("main.py already patched with correct title");
             return Ok(());
         }
 
-        // Create backup
-        let backup = main_py.with_extension("py.bak");
-        if !backup.exists() {
-            std::fs::copy(main_py, &backup).map_err(|e| PumasError::Io {
+        // Back up the clean file; an existing backup may predate a ComfyUI update.
+        if state == PatchState::Unpatched {
+            let backup = Self::backup_path(main_py);
+            std::fs::write(&backup, &content).map_err(|e| PumasError::Io {
                 message: format!("Failed to create backup: {}", e),
                 path: Some(backup.clone()),
                 source: Some(e),
@@ -179,14 +351,10 @@ impl PatchManager {
             debug!("Created backup at {:?}", backup);
         }
 
-        // Check if an older patch exists and upgrade it
-        let pattern =
-            Regex::new(r#"setproctitle\.setproctitle\(["']ComfyUI Server[^"']*["']\)"#).unwrap();
-
-        let new_content = if pattern.is_match(&content) {
+        let new_content = if state == PatchState::Patched {
             // Upgrade existing patch
             info!("Upgrading existing patch to include version");
-            pattern
+            TITLE_CALL_RE
                 .replace(&content, expected_line.as_str())
                 .to_string()
         } else {
@@ -218,6 +386,24 @@ except ImportError:
             source: Some(e),
         })?;
 
+        // Verify the patch landed exactly once with the expected title.
+        let verified = std::fs::read_to_string(main_py)
+            .map(|written| {
+                PatchState::of(&written) == PatchState::Patched && written.contains(&expected_line)
+            })
+            .unwrap_or(false);
+        if !verified {
+            std::fs::write(main_py, &content).map_err(|e| PumasError::Io {
+                message: format!("Failed to restore main.py after bad patch: {}", e),
+                path: Some(main_py.to_path_buf()),
+                source: Some(e),
+            })?;
+            return Err(PumasError::Other(format!(
+                "Patch verification failed for {}; original main.py restored",
+                main_py.display()
+            )));
+        }
+
         info!("Applied setproctitle patch to {:?}", main_py);
         Ok(())
     }
@@ -230,15 +416,7 @@ except ImportError:
     /// * `tag` - Optional version tag (used for downloading original if needed)
     fn revert_patch(&self, main_py: &Path, tag: Option<&str>) -> Result<()> {
         // Try backup first
-        let backup = main_py.with_extension("py.bak");
-        if backup.exists() {
-            std::fs::copy(&backup, main_py).map_err(|e| PumasError::Io {
-                message: format!("Failed to restore from backup: {}", e),
-                path: Some(main_py.to_path_buf()),
-                source: Some(e),
-            })?;
-            std::fs::remove_file(&backup).ok();
-            info!("Reverted main.py from backup");
+        if self.restore_from_backup(main_py)? {
             return Ok(());
         }
 
@@ -397,4 +575,72 @@ if __name__ == "__main__":
         // Check backup was created
         assert!(main_py.with_extension("py.bak").exists());
     }
+
+    #[test]
+    fn test_patch_state_detects_partial_patch() {
+        assert_eq!(PatchState::of("import sys\n"), PatchState::Unpatched);
+        assert_eq!(
+            PatchState::of("try:\n    import setproctitle\nexcept ImportError:\n    pass\n"),
+            PatchState::Corrupted
+        );
+        assert_eq!(
+            PatchState::of("setproctitle.setproctitle(\"ComfyUI Server\")\n"),
+            PatchState::Corrupted
+        );
+    }
+
+    #[test]
+    fn test_apply_patch_refuses_corrupted_file_and_restore_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let main_py = temp_dir.path().join("main.py");
+        let original = "import sys\n\nif __name__ == \"__main__\":\n    main()\n";
+        std::fs::write(&main_py, original).unwrap();
+
+        let patch_mgr = PatchManager::new(temp_dir.path(), &main_py, None);
+        assert!(patch_mgr.toggle_patch(None).unwrap());
+        assert_eq!(patch_mgr.patch_state(None), PatchState::Patched);
+
+        // Simulate a botched edit that dropped the setproctitle call.
+        let patched = std::fs::read_to_string(&main_py).unwrap();
+        let broken = TITLE_CALL_RE.replace(&patched, "").to_string();
+        std::fs::write(&main_py, &broken).unwrap();
+        assert_eq!(patch_mgr.patch_state(None), PatchState::Corrupted);
+        assert!(!patch_mgr.is_patched(None));
+        assert!(patch_mgr.apply_patch(&main_py, None).is_err());
+        assert_eq!(std::fs::read_to_string(&main_py).unwrap(), broken);
+
+        patch_mgr.restore_backup(None).unwrap();
+        assert_eq!(std::fs::read_to_string(&main_py).unwrap(), original);
+        assert_eq!(patch_mgr.patch_state(None), PatchState::Unpatched);
+        assert!(patch_mgr.restore_backup(None).is_err());
+    }
+
+    #[test]
+    fn test_apply_to_all_patches_each_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let versions_dir = temp_dir.path();
+        for tag in ["v0.2.0", "v0.3.0"] {
+            std::fs::create_dir(versions_dir.join(tag)).unwrap();
+            std::fs::write(
+                versions_dir.join(tag).join("main.py"),
+                "if __name__ == \"__main__\":\n    main()\n",
+            )
+            .unwrap();
+        }
+        std::fs::create_dir(versions_dir.join("not-installed")).unwrap();
+
+        let results = PatchManager::apply_to_all(versions_dir, true).unwrap();
+        let tags: Vec<_> = results.iter().map(|r| r.tag.as_str()).collect();
+        assert_eq!(tags, vec!["v0.2.0", "v0.3.0"]);
+        assert!(results
+            .iter()
+            .all(|r| r.success && r.state == PatchState::Patched));
+        let patched = std::fs::read_to_string(versions_dir.join("v0.3.0").join("main.py")).unwrap();
+        assert!(patched.contains("ComfyUI Server - v0.3.0"));
+
+        let results = PatchManager::apply_to_all(versions_dir, false).unwrap();
+        assert!(results
+            .iter()
+            .all(|r| r.success && r.state == PatchState::Unpatched));
+    }
 }
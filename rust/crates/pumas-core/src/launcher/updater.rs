@@ -51,6 +51,35 @@ pub struct UpdateCheckResult {
     /// Error message if check failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Changes a git pull would bring in, for git checkouts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<UpdatePreview>,
+}
+
+/// Changes a launcher update would pull, computed without touching the checkout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePreview {
+    /// Current local commit SHA (short).
+    pub current_commit: String,
+    /// Fetched remote commit SHA (short).
+    pub target_commit: String,
+    /// Branch that was fetched.
+    pub branch: String,
+    /// Commits between HEAD and the target, newest first.
+    pub commits: Vec<CommitInfo>,
+    /// Files the update would change.
+    pub changed_files: Vec<ChangedFile>,
+}
+
+/// A file changed by a launcher update.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedFile {
+    /// Path relative to the launcher root (the new path for renames).
+    pub path: String,
+    /// Git status letter: `A`, `M`, `D`, `R`, ...
+    pub status: String,
 }
 
 /// Result of applying a launcher update.
@@ -71,6 +100,88 @@ pub struct UpdateApplyResult {
     /// Error message if update failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Outcome of each phase that ran, in order.
+    #[serde(default)]
+    pub phases: Vec<UpdatePhaseResult>,
+}
+
+impl UpdateApplyResult {
+    fn failed(
+        phase: UpdatePhase,
+        previous_commit: Option<String>,
+        error: impl Into<String>,
+    ) -> Self {
+        let error = error.into();
+        Self {
+            success: false,
+            message: None,
+            new_commit: None,
+            previous_commit,
+            error: Some(error.clone()),
+            phases: vec![UpdatePhaseResult {
+                phase,
+                success: false,
+                message: None,
+                error: Some(error),
+            }],
+        }
+    }
+
+    fn succeeded(
+        phase: UpdatePhase,
+        message: &str,
+        previous_commit: String,
+        new_commit: String,
+    ) -> Self {
+        Self {
+            success: true,
+            message: Some(message.to_string()),
+            new_commit: Some(new_commit),
+            previous_commit: Some(previous_commit),
+            error: None,
+            phases: vec![UpdatePhaseResult {
+                phase,
+                success: true,
+                message: Some(message.to_string()),
+                error: None,
+            }],
+        }
+    }
+}
+
+/// Phase of a launcher update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdatePhase {
+    /// Fetch and pull the new commits.
+    Stage,
+    /// Reinstall dependencies and rebuild artifacts.
+    Build,
+}
+
+/// Outcome of a single update phase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePhaseResult {
+    /// Which phase ran.
+    pub phase: UpdatePhase,
+    /// Whether the phase succeeded.
+    pub success: bool,
+    /// Status message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Error message if the phase failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Update pulled by `stage_update` and not yet built.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StagedUpdate {
+    /// Commit to roll back to if the build fails.
+    previous_commit: String,
+    /// Commit that was pulled.
+    staged_commit: String,
 }
 
 /// Cached update check result.
@@ -93,6 +204,8 @@ pub struct LauncherUpdater {
     repo_name: String,
     /// Path to cache file for update checks.
     cache_file: PathBuf,
+    /// Proxy, CA, and user-agent settings for GitHub requests.
+    http: crate::network::HttpSettings,
 }
 
 impl LauncherUpdater {
@@ -103,9 +216,7 @@ impl LauncherUpdater {
     /// * `launcher_root` - Path to the launcher root directory (git repo root)
     pub fn new(launcher_root: impl AsRef<Path>) -> Self {
         let launcher_root = launcher_root.as_ref().to_path_buf();
-        let cache_file = launcher_root
-            .join("launcher-data")
-            .join("cache")
+        let cache_file = crate::config::PathsConfig::cache_dir(&launcher_root)
             .join("launcher-update-check.json");
 
         Self {
@@ -113,9 +224,16 @@ impl LauncherUpdater {
             repo_owner: "MrScripty".to_string(),
             repo_name: "Pumas-Library".to_string(),
             cache_file,
+            http: crate::network::HttpSettings::default(),
         }
     }
 
+    /// Use the given outbound HTTP settings for GitHub requests.
+    pub fn with_http_settings(mut self, http: crate::network::HttpSettings) -> Self {
+        self.http = http;
+        self
+    }
+
     /// Check if the launcher is in a git repository.
     pub fn is_git_repo(&self) -> bool {
         self.launcher_root.join(".git").exists()
@@ -303,6 +421,7 @@ impl LauncherUpdater {
                             "Failed to join launcher update context task: {}",
                             error
                         )),
+                        preview: None,
                     };
                 }
             };
@@ -320,33 +439,37 @@ impl LauncherUpdater {
         }
 
         let cache_dir = self.cache_dir();
-        let github_client =
-            match tokio::task::spawn_blocking(move || GitHubClient::new(cache_dir)).await {
-                Ok(Ok(client)) => client,
-                Ok(Err(err)) => {
-                    return self
-                        .return_cached_or_error(
-                            current_commit,
-                            branch,
-                            current_version,
-                            format!("Failed to initialize GitHub client: {}", err),
-                        )
-                        .await;
-                }
-                Err(error) => {
-                    return self
-                        .return_cached_or_error(
-                            current_commit,
-                            branch,
-                            current_version,
-                            format!(
-                                "Failed to join GitHub client initialization task: {}",
-                                error
-                            ),
-                        )
-                        .await;
-                }
-            };
+        let http = self.http.clone();
+        let github_client = match tokio::task::spawn_blocking(move || {
+            GitHubClient::with_http_settings(cache_dir, &http)
+        })
+        .await
+        {
+            Ok(Ok(client)) => client,
+            Ok(Err(err)) => {
+                return self
+                    .return_cached_or_error(
+                        current_commit,
+                        branch,
+                        current_version,
+                        format!("Failed to initialize GitHub client: {}", err),
+                    )
+                    .await;
+            }
+            Err(error) => {
+                return self
+                    .return_cached_or_error(
+                        current_commit,
+                        branch,
+                        current_version,
+                        format!(
+                            "Failed to join GitHub client initialization task: {}",
+                            error
+                        ),
+                    )
+                    .await;
+            }
+        };
 
         let repo = format!("{}/{}", self.repo_owner, self.repo_name);
         let release = match github_client.get_latest_release(&repo, force_refresh).await {
@@ -376,18 +499,33 @@ impl LauncherUpdater {
 
         let latest_version = release.tag_name.clone();
         let has_update = is_newer_version(&current_version, &latest_version);
-        let latest_commit = if is_git_repo {
-            current_commit.clone()
+        let preview = if is_git_repo {
+            match self.preview_update().await {
+                Ok(preview) => Some(preview),
+                Err(err) => {
+                    debug!("Launcher update preview unavailable: {}", err);
+                    None
+                }
+            }
         } else {
-            String::new()
+            None
         };
+        let latest_commit = match (&preview, is_git_repo) {
+            (Some(preview), _) => preview.target_commit.clone(),
+            (None, true) => current_commit.clone(),
+            (None, false) => String::new(),
+        };
+        let (commits_behind, commits) = preview
+            .as_ref()
+            .map(|preview| (preview.commits.len() as i32, preview.commits.clone()))
+            .unwrap_or_default();
 
         let result = UpdateCheckResult {
             has_update,
             current_commit,
             latest_commit,
-            commits_behind: 0,
-            commits: vec![],
+            commits_behind,
+            commits,
             branch,
             current_version,
             latest_version: Some(latest_version),
@@ -396,6 +534,7 @@ impl LauncherUpdater {
             download_url: select_download_url(&release),
             published_at: Some(release.published_at.clone()),
             error: None,
+            preview,
         };
 
         // Cache the result
@@ -438,55 +577,139 @@ impl LauncherUpdater {
             download_url: None,
             published_at: None,
             error: Some(error_msg),
+            preview: None,
         }
     }
 
+    /// Run git in the launcher root and return its trimmed stdout.
+    fn git_output(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&self.launcher_root)
+            .output()
+            .map_err(|e| PumasError::Other(format!("Failed to run git {}: {}", args[0], e)))?;
+        if !output.status.success() {
+            return Err(PumasError::Other(format!(
+                "git {} failed: {}",
+                args[0],
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Fetch the remote branch and list what a pull would change.
+    ///
+    /// Only remote-tracking refs are updated; the working tree and HEAD are left
+    /// untouched.
+    fn preview_update_blocking(&self) -> Result<UpdatePreview> {
+        if !self.is_git_repo() {
+            return Err(PumasError::Other("Not a git repository".to_string()));
+        }
+
+        let branch = self.get_current_branch();
+        info!("Fetching origin/{} for update preview", branch);
+        self.git_output(&["fetch", "origin", &branch])?;
+
+        let target = self.git_output(&["rev-parse", "FETCH_HEAD"])?;
+        let current_commit = self.git_output(&["rev-parse", "--short=7", "HEAD"])?;
+        let target_commit = self.git_output(&["rev-parse", "--short=7", &target])?;
+
+        let log = self.git_output(&[
+            "log",
+            "--format=%h%x1f%an%x1f%aI%x1f%s",
+            &format!("HEAD..{}", target),
+        ])?;
+        let diff = self.git_output(&["diff", "--name-status", &format!("HEAD...{}", target)])?;
+
+        Ok(UpdatePreview {
+            current_commit,
+            target_commit,
+            branch,
+            commits: parse_commit_log(&log),
+            changed_files: parse_name_status(&diff),
+        })
+    }
+
+    /// Preview the commits and files a launcher update would pull.
+    pub async fn preview_update(&self) -> Result<UpdatePreview> {
+        let updater = self.clone();
+        tokio::task::spawn_blocking(move || updater.preview_update_blocking())
+            .await
+            .map_err(|e| PumasError::Other(format!("Failed to join preview_update task: {}", e)))?
+    }
+
     fn cache_dir(&self) -> PathBuf {
-        self.launcher_root.join("launcher-data").join("cache")
+        crate::config::PathsConfig::cache_dir(&self.launcher_root)
     }
 
-    /// Apply launcher update by pulling latest changes and rebuilding.
-    fn apply_update_blocking(&self) -> UpdateApplyResult {
+    fn staged_update_file(&self) -> PathBuf {
+        self.cache_dir().join("launcher-update-staged.json")
+    }
+
+    fn read_staged_update(&self) -> Option<StagedUpdate> {
+        let content = std::fs::read_to_string(self.staged_update_file()).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn write_staged_update(&self, staged: &StagedUpdate) -> std::result::Result<(), String> {
+        std::fs::create_dir_all(self.cache_dir()).map_err(|e| e.to_string())?;
+        let content = serde_json::to_string_pretty(staged).map_err(|e| e.to_string())?;
+        std::fs::write(self.staged_update_file(), content).map_err(|e| e.to_string())
+    }
+
+    fn clear_staged_update(&self) {
+        let path = self.staged_update_file();
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("Failed to clear staged update marker: {}", e);
+            }
+        }
+    }
+
+    /// Whether pulled changes are waiting for [`Self::build_update`].
+    pub fn has_staged_update(&self) -> bool {
+        self.read_staged_update().is_some()
+    }
+
+    /// Pull the latest changes without rebuilding.
+    ///
+    /// The pre-pull commit is remembered so a later build failure can roll
+    /// back to it. Staging again before building keeps the original target.
+    fn stage_update_blocking(&self) -> UpdateApplyResult {
+        const PHASE: UpdatePhase = UpdatePhase::Stage;
+
         // Safety checks
         if !self.is_git_repo() {
-            return UpdateApplyResult {
-                success: false,
-                message: None,
-                new_commit: None,
-                previous_commit: None,
-                error: Some("Not a git repository".to_string()),
-            };
+            return UpdateApplyResult::failed(PHASE, None, "Not a git repository");
         }
 
         if self.has_uncommitted_changes() {
-            return UpdateApplyResult {
-                success: false,
-                message: None,
-                new_commit: None,
-                previous_commit: None,
-                error: Some(
-                    "Uncommitted changes detected. Please commit or stash them first.".to_string(),
-                ),
-            };
+            return UpdateApplyResult::failed(
+                PHASE,
+                None,
+                "Uncommitted changes detected. Please commit or stash them first.",
+            );
         }
 
         let current_commit = match self.get_current_commit() {
             Some(c) => c,
             None => {
-                return UpdateApplyResult {
-                    success: false,
-                    message: None,
-                    new_commit: None,
-                    previous_commit: None,
-                    error: Some("Unable to determine current commit".to_string()),
-                };
+                return UpdateApplyResult::failed(
+                    PHASE,
+                    None,
+                    "Unable to determine current commit",
+                );
             }
         };
+        let previous_commit = self
+            .read_staged_update()
+            .map(|staged| staged.previous_commit)
+            .unwrap_or_else(|| current_commit.clone());
 
-        info!("Starting update from commit {}", current_commit);
+        info!("Staging update from commit {}", current_commit);
         let branch = self.get_current_branch();
 
-        // Step 1: Git pull
         info!("Running git pull");
         let pull_result = Command::new("git")
             .args(["pull", "origin", &branch])
@@ -497,44 +720,71 @@ impl LauncherUpdater {
             Ok(output) => output,
             Err(e) => {
                 error!("Git pull failed: {}", e);
-                return UpdateApplyResult {
-                    success: false,
-                    message: None,
-                    new_commit: None,
-                    previous_commit: Some(current_commit),
-                    error: Some(format!("Git pull failed: {}", e)),
-                };
+                return UpdateApplyResult::failed(
+                    PHASE,
+                    Some(previous_commit),
+                    format!("Git pull failed: {}", e),
+                );
             }
         };
 
         if !pull_output.status.success() {
             let stderr = String::from_utf8_lossy(&pull_output.stderr);
             error!("Git pull failed: {}", stderr);
-            return UpdateApplyResult {
-                success: false,
-                message: None,
-                new_commit: None,
-                previous_commit: Some(current_commit),
-                error: Some(format!("Git pull failed: {}", stderr)),
-            };
+            return UpdateApplyResult::failed(
+                PHASE,
+                Some(previous_commit),
+                format!("Git pull failed: {}", stderr),
+            );
         }
 
-        let stdout = String::from_utf8_lossy(&pull_output.stdout);
-        if stdout.contains("Already up to date") || stdout.contains("Already up-to-date") {
+        let new_commit = self.get_current_commit().unwrap_or_default();
+        if new_commit == previous_commit {
             info!("Already up to date");
-            return UpdateApplyResult {
-                success: true,
-                message: Some("Already up to date".to_string()),
-                new_commit: Some(current_commit.clone()),
-                previous_commit: Some(current_commit),
-                error: None,
-            };
+            self.clear_staged_update();
+            return UpdateApplyResult::succeeded(
+                PHASE,
+                "Already up to date",
+                previous_commit,
+                new_commit,
+            );
         }
 
-        let new_commit = self.get_current_commit().unwrap_or_default();
-        info!("Updated to commit {}", new_commit);
+        let staged = StagedUpdate {
+            previous_commit: previous_commit.clone(),
+            staged_commit: new_commit.clone(),
+        };
+        if let Err(e) = self.write_staged_update(&staged) {
+            error!("Failed to record staged update: {}", e);
+            self.rollback(&previous_commit);
+            return UpdateApplyResult::failed(
+                PHASE,
+                Some(previous_commit),
+                format!("Failed to record staged update: {}", e),
+            );
+        }
+
+        info!("Staged commit {}", new_commit);
+        UpdateApplyResult::succeeded(
+            PHASE,
+            "Update staged. Build it to finish updating.",
+            previous_commit,
+            new_commit,
+        )
+    }
 
-        // Step 2: Update Python dependencies
+    /// Rebuild dependencies and artifacts for a staged update.
+    ///
+    /// On failure the checkout is reset to the commit recorded when staging.
+    fn build_update_blocking(&self) -> UpdateApplyResult {
+        const PHASE: UpdatePhase = UpdatePhase::Build;
+
+        let Some(staged) = self.read_staged_update() else {
+            return UpdateApplyResult::failed(PHASE, None, "No staged update to build");
+        };
+        let previous_commit = staged.previous_commit;
+
+        // Step 1: Update Python dependencies
         info!("Updating Python dependencies");
         let pip_result = Command::new("pip")
             .args(["install", "-r", "requirements.txt", "--upgrade"])
@@ -546,86 +796,101 @@ impl LauncherUpdater {
             // Don't fail on pip warnings
         }
 
-        // Step 3: Refresh workspace dependencies and rebuilt Node artifacts.
+        // Step 2: Refresh workspace dependencies and rebuilt Node artifacts.
         if self.launcher_root.join("frontend").exists() {
-            info!("Running pnpm install");
-            if let Err(error_message) = self.run_pnpm_command(&["install", "--frozen-lockfile"]) {
-                error!("pnpm install failed: {}", error_message);
-                self.rollback(&current_commit);
-                return UpdateApplyResult {
-                    success: false,
-                    message: None,
-                    new_commit: None,
-                    previous_commit: Some(current_commit.clone()),
-                    error: Some(format!(
-                        "Workspace install failed. Rolled back to {}.",
-                        current_commit
-                    )),
-                };
-            }
-
-            info!("Building frontend");
-            if let Err(error_message) =
-                self.run_pnpm_command(&["--filter", "./frontend", "run", "build"])
-            {
-                error!("Frontend build failed: {}", error_message);
-                self.rollback(&current_commit);
-                return UpdateApplyResult {
-                    success: false,
-                    message: None,
-                    new_commit: None,
-                    previous_commit: Some(current_commit.clone()),
-                    error: Some(format!(
-                        "Frontend build failed. Rolled back to {}.",
-                        current_commit
-                    )),
-                };
-            }
-
-            info!("Building electron shell");
-            if let Err(error_message) =
-                self.run_pnpm_command(&["--filter", "./electron", "run", "build"])
-            {
-                error!("Electron build failed: {}", error_message);
-                self.rollback(&current_commit);
-                return UpdateApplyResult {
-                    success: false,
-                    message: None,
-                    new_commit: None,
-                    previous_commit: Some(current_commit.clone()),
-                    error: Some(format!(
-                        "Electron build failed. Rolled back to {}.",
-                        current_commit
-                    )),
-                };
+            let steps: [(&str, &[&str], &str); 3] = [
+                (
+                    "Running pnpm install",
+                    &["install", "--frozen-lockfile"],
+                    "Workspace install",
+                ),
+                (
+                    "Building frontend",
+                    &["--filter", "./frontend", "run", "build"],
+                    "Frontend build",
+                ),
+                (
+                    "Building electron shell",
+                    &["--filter", "./electron", "run", "build"],
+                    "Electron build",
+                ),
+            ];
+            for (progress, args, step) in steps {
+                info!("{}", progress);
+                if let Err(error_message) = self.run_pnpm_command(args) {
+                    error!("{} failed: {}", step, error_message);
+                    self.rollback(&previous_commit);
+                    self.clear_staged_update();
+                    return UpdateApplyResult::failed(
+                        PHASE,
+                        Some(previous_commit.clone()),
+                        format!("{} failed. Rolled back to {}.", step, previous_commit),
+                    );
+                }
             }
         }
 
+        self.clear_staged_update();
         info!("Update completed successfully");
-        UpdateApplyResult {
-            success: true,
-            message: Some("Update applied successfully. Please restart the launcher.".to_string()),
-            new_commit: Some(new_commit),
-            previous_commit: Some(current_commit),
-            error: None,
-        }
+        UpdateApplyResult::succeeded(
+            PHASE,
+            "Update applied successfully. Please restart the launcher.",
+            previous_commit,
+            staged.staged_commit,
+        )
     }
 
     /// Apply launcher update by pulling latest changes and rebuilding.
-    pub async fn apply_update(&self) -> UpdateApplyResult {
+    fn apply_update_blocking(&self) -> UpdateApplyResult {
+        let staged = self.stage_update_blocking();
+        if !staged.success || !self.has_staged_update() {
+            return staged;
+        }
+
+        let mut built = self.build_update_blocking();
+        let mut phases = staged.phases;
+        phases.append(&mut built.phases);
+        built.phases = phases;
+        built
+    }
+
+    async fn run_update_task(
+        &self,
+        name: &str,
+        task: fn(&LauncherUpdater) -> UpdateApplyResult,
+    ) -> UpdateApplyResult {
         let updater = self.clone();
-        match tokio::task::spawn_blocking(move || updater.apply_update_blocking()).await {
+        match tokio::task::spawn_blocking(move || task(&updater)).await {
             Ok(result) => result,
             Err(error) => UpdateApplyResult {
                 success: false,
                 message: None,
                 new_commit: None,
                 previous_commit: None,
-                error: Some(format!("Failed to join apply_update task: {}", error)),
+                error: Some(format!("Failed to join {} task: {}", name, error)),
+                phases: Vec::new(),
             },
         }
     }
 
+    /// Pull the latest changes without rebuilding.
+    pub async fn stage_update(&self) -> UpdateApplyResult {
+        self.run_update_task("stage_update", Self::stage_update_blocking)
+            .await
+    }
+
+    /// Rebuild a previously staged update, rolling back on failure.
+    pub async fn build_update(&self) -> UpdateApplyResult {
+        self.run_update_task("build_update", Self::build_update_blocking)
+            .await
+    }
+
+    /// Apply launcher update by pulling latest changes and rebuilding.
+    pub async fn apply_update(&self) -> UpdateApplyResult {
+        self.run_update_task("apply_update", Self::apply_update_blocking)
+            .await
+    }
+
     /// Rollback to a previous commit.
     fn rollback(&self, commit_sha: &str) {
         warn!("Rolling back to commit {}", commit_sha);
@@ -699,6 +964,35 @@ fn normalize_version(version: &str) -> String {
     version.trim().trim_start_matches(['v', 'V']).to_string()
 }
 
+/// Parse `git log --format=%h%x1f%an%x1f%aI%x1f%s` output.
+fn parse_commit_log(output: &str) -> Vec<CommitInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, '\x1f');
+            Some(CommitInfo {
+                hash: fields.next()?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                message: fields.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parse `git diff --name-status` output.
+fn parse_name_status(output: &str) -> Vec<ChangedFile> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (status, paths) = line.split_once('\t')?;
+            let status = status.chars().next()?.to_string();
+            let path = paths.rsplit('\t').next()?.to_string();
+            Some(ChangedFile { path, status })
+        })
+        .collect()
+}
+
 fn pnpm_args<'a>(args: &'a [&'a str]) -> Vec<&'a str> {
     let mut full_args = Vec::with_capacity(args.len() + 1);
     full_args.push("pnpm");
@@ -807,4 +1101,105 @@ mod tests {
             assert_eq!(updater.corepack_command(), "corepack");
         }
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn commit_file(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+        git(dir, &["add", "-A"]);
+        git(dir, &["commit", "-q", "-m", name]);
+    }
+
+    #[test]
+    fn test_stage_and_build_require_their_preconditions() {
+        let temp_dir = TempDir::new().unwrap();
+        let updater = LauncherUpdater::new(temp_dir.path());
+
+        let staged = updater.stage_update_blocking();
+        assert!(!staged.success);
+        assert_eq!(staged.phases.len(), 1);
+        assert_eq!(staged.phases[0].phase, UpdatePhase::Stage);
+
+        let built = updater.build_update_blocking();
+        assert!(!built.success);
+        assert_eq!(built.phases[0].phase, UpdatePhase::Build);
+        assert_eq!(built.error.as_deref(), Some("No staged update to build"));
+    }
+
+    #[test]
+    fn test_parse_git_preview_output() {
+        let commits = parse_commit_log(
+            "abc1234\x1fAda\x1f2026-01-02T03:04:05+00:00\x1fFix: a\x1fb\nmalformed\n",
+        );
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].hash, "abc1234");
+        assert_eq!(commits[0].author, "Ada");
+        assert_eq!(commits[0].message, "Fix: a\x1fb");
+
+        let files = parse_name_status("M\tsrc/main.rs\nR087\told.txt\tnew.txt\n");
+        assert_eq!(
+            files,
+            vec![
+                ChangedFile {
+                    path: "src/main.rs".to_string(),
+                    status: "M".to_string(),
+                },
+                ChangedFile {
+                    path: "new.txt".to_string(),
+                    status: "R".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stage_then_build_updates_checkout() {
+        let temp_dir = TempDir::new().unwrap();
+        let origin = temp_dir.path().join("origin");
+        let checkout = temp_dir.path().join("checkout");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-q"]);
+        commit_file(&origin, ".gitignore", "launcher-data/\n");
+        git(
+            temp_dir.path(),
+            &["clone", "-q", origin.to_str().unwrap(), "checkout"],
+        );
+        commit_file(&origin, "CHANGELOG.md", "new release\n");
+
+        let updater = LauncherUpdater::new(&checkout);
+        let before = updater.get_current_commit().unwrap();
+
+        let preview = updater.preview_update_blocking().unwrap();
+        assert_eq!(preview.current_commit, before);
+        assert_eq!(preview.commits.len(), 1);
+        assert_eq!(preview.commits[0].message, "CHANGELOG.md");
+        assert_eq!(preview.changed_files[0].path, "CHANGELOG.md");
+        assert_eq!(preview.changed_files[0].status, "A");
+        assert_eq!(updater.get_current_commit().unwrap(), before);
+
+        let staged = updater.stage_update_blocking();
+        assert!(staged.success, "{:?}", staged.error);
+        assert!(updater.has_staged_update());
+        assert_eq!(staged.previous_commit.as_deref(), Some(before.as_str()));
+        assert_ne!(staged.new_commit.as_deref(), Some(before.as_str()));
+
+        let built = updater.build_update_blocking();
+        assert!(built.success, "{:?}", built.error);
+        assert_eq!(built.previous_commit.as_deref(), Some(before.as_str()));
+        assert!(!updater.has_staged_update());
+
+        let applied = updater.apply_update_blocking();
+        assert!(applied.success);
+        assert_eq!(applied.message.as_deref(), Some("Already up to date"));
+        assert_eq!(applied.phases.len(), 1);
+    }
 }
@@ -16,11 +16,11 @@
 //!     let api = PumasApi::new("/path/to/pumas").await?;
 //!
 //!     // List models in the library
-//!     let models = api.list_models().await?;
+//!     let models = api.list_models(false).await?;
 //!     println!("Found {} models", models.len());
 //!
 //!     // Search for models
-//!     let search = api.search_models("llama", 10, 0).await?;
+//!     let search = api.search_models("llama", 10, 0, false).await?;
 //!     println!("Search found {} results", search.total_count);
 //!
 //!     Ok(())
@@ -33,6 +33,7 @@ uniffi::setup_scaffolding!();
 
 pub mod cache;
 pub mod cancel;
+pub mod clock;
 pub mod config;
 pub mod conversion;
 pub mod error;
@@ -60,14 +61,20 @@ pub use cache::{CacheBackend, CacheConfig, CacheEntry, CacheMeta, CacheStats, Sq
 pub use cancel::{CancellationToken, CancelledError};
 pub use config::AppId;
 pub use error::{PumasError, Result};
-pub use index::{ModelIndex, ModelRecord, SearchResult};
+pub use index::{
+    FTS5Config, FTS5Tokenizer, ModelIndex, ModelRecord, SearchOptions, SearchResult, SearchSortBy,
+    SearchSuggestion, SortDirection,
+};
 pub use ipc::PumasLocalClient;
-pub use launcher::{LauncherUpdater, PatchManager, UpdateApplyResult, UpdateCheckResult};
+pub use launcher::{
+    ChangedFile, LauncherUpdater, PatchManager, PatchState, UpdateApplyResult, UpdateCheckResult,
+    UpdatePhase, UpdatePhaseResult, UpdatePreview, VersionPatchResult,
+};
 pub use metadata::MetadataManager;
 pub use model_library::sharding::{self, ShardValidation};
 pub use model_library::{
-    BatchImportProgress, DownloadRequest, HfAuthStatus, HfSearchParams, HuggingFaceClient,
-    ModelImporter, ModelLibrary, ModelMapper, PumasReadOnlyLibrary,
+    BatchImportProgress, DownloadPriority, DownloadRequest, HfAuthStatus, HfSearchParams,
+    HuggingFaceClient, ModelImporter, ModelLibrary, ModelMapper, PumasReadOnlyLibrary,
 };
 pub use models::{
     BundleComponentManifestEntry, BundleComponentState, BundleFormat, CommitInfo,
@@ -96,12 +103,14 @@ pub use runtime_profiles::{
     RuntimeProviderCapabilities,
 };
 pub use system::{
-    check_brave, check_git, check_setproctitle, GpuInfo, GpuMonitor, ProcessResources,
+    check_brave, check_git, check_setproctitle, check_uv, GpuInfo, GpuMonitor, ProcessResources,
     ResourceTracker, SystemCheckResult, SystemResourceSnapshot, SystemUtils,
 };
 
 // Re-export builder from api module
-pub use api::PumasApiBuilder;
+pub use api::{
+    AutoSyncTarget, DownloadCompleteCallback, DownloadCompleteEvent, LibraryEvent, PumasApiBuilder,
+};
 
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -264,8 +273,7 @@ impl PumasApi {
 
     /// Get the cache directory path.
     pub fn cache_dir(&self) -> PathBuf {
-        self.launcher_data_dir()
-            .join(config::PathsConfig::CACHE_DIR_NAME)
+        config::PathsConfig::cache_dir(&self.launcher_root)
     }
 
     /// Get the shared resources directory path.
@@ -274,10 +282,23 @@ impl PumasApi {
             .join(config::PathsConfig::SHARED_RESOURCES_DIR_NAME)
     }
 
+    /// Get the model library directory path.
+    ///
+    /// This is `shared-resources/models` unless the builder was given another
+    /// location with [`PumasApiBuilder::with_model_library_dir`].
+    pub fn model_library_dir(&self) -> PathBuf {
+        self.primary().model_library.library_root().to_path_buf()
+    }
+
     /// Get the versions directory for a specific app.
     pub fn versions_dir(&self, app_id: AppId) -> PathBuf {
         self.launcher_root.join(app_id.versions_dir_name())
     }
+
+    /// Wait for startup background work (orphan adoption and download recovery) to finish.
+    pub async fn wait_for_startup_tasks(&self) {
+        self.runtime_tasks.wait_for_startup().await;
+    }
 }
 
 impl Drop for PumasApi {
@@ -8,6 +8,7 @@
 
 #![warn(unsafe_code)]
 
+use crate::platform::extended_length_path;
 use crate::{PumasError, Result};
 use serde::{de::DeserializeOwned, Serialize};
 use std::fs::{self, File, OpenOptions};
@@ -24,6 +25,7 @@ use std::os::unix::io::AsRawFd;
 ///
 /// Returns `None` if the file doesn't exist, or an error if parsing fails.
 pub fn atomic_read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    let path = &extended_length_path(path);
     if !path.exists() {
         return Ok(None);
     }
@@ -60,6 +62,8 @@ pub fn atomic_read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
 /// 5. Atomically renames temp file to target
 #[allow(unsafe_code)]
 pub fn atomic_write_json<T: Serialize>(path: &Path, data: &T, keep_backup: bool) -> Result<()> {
+    let path = &extended_length_path(path);
+
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
         if !parent.exists() {
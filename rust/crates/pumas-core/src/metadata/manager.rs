@@ -8,7 +8,7 @@
 //! - github-releases.json / github-releases-{repo}.json
 //! - Per-version config files
 
-use crate::config::AppId;
+use crate::config::{AppId, PathsConfig};
 use crate::metadata::atomic::{atomic_read_json, atomic_write_json};
 use crate::{PumasError, Result};
 use serde::{Deserialize, Serialize};
@@ -219,7 +219,7 @@ impl MetadataManager {
     }
 
     fn cache_dir(&self) -> PathBuf {
-        self.launcher_root.join("launcher-data").join("cache")
+        PathsConfig::cache_dir(&self.launcher_root)
     }
 
     fn config_dir(&self) -> PathBuf {
@@ -312,14 +312,14 @@ impl MetadataManager {
         metadata: InstalledVersionMetadata,
         app_id: Option<AppId>,
     ) -> Result<()> {
-        let mut versions = self.load_versions(app_id)?;
+        let mut versions = self.load_versions(app_id.clone())?;
         versions.installed.insert(tag.to_string(), metadata);
         self.save_versions(&versions, app_id)
     }
 
     /// Remove an installed version from metadata.
     pub fn remove_installed_version(&self, tag: &str, app_id: Option<AppId>) -> Result<()> {
-        let mut versions = self.load_versions(app_id)?;
+        let mut versions = self.load_versions(app_id.clone())?;
         versions.installed.remove(tag);
 
         // Clear selection if this version was selected
@@ -339,14 +339,14 @@ impl MetadataManager {
         tag: Option<&str>,
         app_id: Option<AppId>,
     ) -> Result<()> {
-        let mut versions = self.load_versions(app_id)?;
+        let mut versions = self.load_versions(app_id.clone())?;
         versions.last_selected_version = tag.map(String::from);
         self.save_versions(&versions, app_id)
     }
 
     /// Set the default version.
     pub fn set_default_version(&self, tag: Option<&str>, app_id: Option<AppId>) -> Result<()> {
-        let mut versions = self.load_versions(app_id)?;
+        let mut versions = self.load_versions(app_id.clone())?;
         versions.default_version = tag.map(String::from);
         self.save_versions(&versions, app_id)
     }
@@ -525,12 +525,12 @@ impl MetadataManager {
     ///
     /// Removes metadata for versions that no longer exist on disk.
     pub fn cleanup_stale_versions(&self, app_id: Option<AppId>) -> Result<Vec<String>> {
-        let mut versions = self.load_versions(app_id)?;
+        let mut versions = self.load_versions(app_id.clone())?;
         let mut removed = Vec::new();
 
         let versions_dir = self
             .launcher_root
-            .join(app_id.unwrap_or_default().versions_dir_name());
+            .join(app_id.clone().unwrap_or_default().versions_dir_name());
 
         // Check each installed version
         let tags_to_check: Vec<_> = versions.installed.keys().cloned().collect();
@@ -324,6 +324,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         }
     }
 
@@ -3,6 +3,10 @@
 //! Persists download state to a JSON file so that paused or errored downloads
 //! can be restored after the application restarts. Only non-terminal downloads
 //! (Paused, Error) are persisted; completed and cancelled downloads are removed.
+//!
+//! Finished downloads (completed, cancelled, or errored) are also appended to
+//! `download_history.json`, which keeps the most recent
+//! [`MAX_HISTORY_ENTRIES`] outcomes for browsing past downloads.
 
 use crate::error::Result;
 use crate::metadata::{atomic_read_json, atomic_write_json};
@@ -37,16 +41,52 @@ pub struct PersistedDownload {
     pub huggingface_evidence: Option<HuggingFaceEvidence>,
 }
 
+/// Oldest history entries are dropped once this many are stored.
+pub const MAX_HISTORY_ENTRIES: usize = 1000;
+
+/// Outcome of one finished download.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadHistoryEntry {
+    pub download_id: String,
+    pub repo_id: String,
+    /// Display name from the download request.
+    pub official_name: String,
+    #[serde(default)]
+    pub model_type: Option<String>,
+    pub filenames: Vec<String>,
+    pub dest_dir: PathBuf,
+    /// Completed, Cancelled, or Error.
+    pub status: DownloadStatus,
+    /// Bytes on disk when the download finished.
+    pub bytes_downloaded: u64,
+    pub total_bytes: Option<u64>,
+    /// When the download was first started (RFC 3339).
+    pub started_at: String,
+    /// When the download finished (RFC 3339).
+    pub finished_at: String,
+    /// Wall-clock seconds from start to finish, including time spent paused.
+    pub duration_secs: f64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 /// All persisted downloads (the JSON root object).
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct DownloadStoreData {
     downloads: Vec<PersistedDownload>,
 }
 
-/// Manages download persistence to `downloads.json`.
+/// Finished downloads, oldest first (the history JSON root object).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct DownloadHistoryData {
+    entries: Vec<DownloadHistoryEntry>,
+}
+
+/// Manages download persistence to `downloads.json` and `download_history.json`.
 #[derive(Clone)]
 pub struct DownloadPersistence {
     path: PathBuf,
+    history_path: PathBuf,
 }
 
 impl DownloadPersistence {
@@ -54,6 +94,101 @@ impl DownloadPersistence {
     pub fn new(data_dir: &Path) -> Self {
         Self {
             path: data_dir.join("downloads.json"),
+            history_path: data_dir.join("download_history.json"),
+        }
+    }
+
+    /// Record how a persisted download finished.
+    ///
+    /// Reads repo, file, and start-time details from the persisted entry, so
+    /// call this before [`Self::remove`]. A download already in the history
+    /// (for example an errored download that was later resumed) is replaced by
+    /// its latest outcome. Returns `false` when no persisted entry exists.
+    pub fn record_finished(
+        &self,
+        download_id: &str,
+        status: DownloadStatus,
+        bytes_downloaded: u64,
+        error: Option<String>,
+    ) -> Result<bool> {
+        let Some(download) = self
+            .load_all()
+            .into_iter()
+            .find(|d| d.download_id == download_id)
+        else {
+            return Ok(false);
+        };
+
+        let finished_at = chrono::Utc::now();
+        let duration_secs = chrono::DateTime::parse_from_rfc3339(&download.created_at)
+            .map(|started| {
+                (finished_at - started.with_timezone(&chrono::Utc))
+                    .num_milliseconds()
+                    .max(0) as f64
+                    / 1000.0
+            })
+            .unwrap_or(0.0);
+        let filenames = if download.filenames.is_empty() {
+            vec![download.filename.clone()]
+        } else {
+            download.filenames.clone()
+        };
+        let entry = DownloadHistoryEntry {
+            download_id: download.download_id,
+            repo_id: download.repo_id,
+            official_name: download.download_request.official_name,
+            model_type: download.download_request.model_type,
+            filenames,
+            dest_dir: download.dest_dir,
+            status,
+            bytes_downloaded,
+            total_bytes: download.total_bytes,
+            started_at: download.created_at,
+            finished_at: finished_at.to_rfc3339(),
+            duration_secs,
+            error,
+        };
+
+        let mut history = self.load_history_data();
+        history
+            .entries
+            .retain(|existing| existing.download_id != entry.download_id);
+        history.entries.push(entry);
+        let excess = history.entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        history.entries.drain(..excess);
+        atomic_write_json(&self.history_path, &history, false)?;
+        Ok(true)
+    }
+
+    /// Finished downloads, most recently finished first.
+    pub fn load_history(&self, limit: usize, offset: usize) -> Vec<DownloadHistoryEntry> {
+        self.load_history_data()
+            .entries
+            .into_iter()
+            .rev()
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+
+    /// Delete all download history. Active and resumable downloads are kept.
+    pub fn clear_history(&self) -> Result<()> {
+        atomic_write_json(&self.history_path, &DownloadHistoryData::default(), false)
+    }
+
+    /// Read history data, returning empty on any error.
+    fn load_history_data(&self) -> DownloadHistoryData {
+        match atomic_read_json::<DownloadHistoryData>(&self.history_path) {
+            Ok(Some(data)) => data,
+            Ok(None) => DownloadHistoryData::default(),
+            Err(e) => {
+                warn!(
+                    "Failed to read download history at {}: {}",
+                    self.history_path.display(),
+                    e
+                );
+                DownloadHistoryData::default()
+            }
         }
     }
 
@@ -136,6 +271,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         }
     }
 
@@ -225,4 +366,56 @@ mod tests {
         let store = DownloadPersistence::new(tmp.path());
         assert_eq!(store.load_all().len(), 0);
     }
+
+    #[test]
+    fn test_record_finished_keeps_latest_outcome_newest_first() {
+        let tmp = TempDir::new().unwrap();
+        let store = DownloadPersistence::new(tmp.path());
+
+        for id in ["dl-1", "dl-2"] {
+            store
+                .save(&PersistedDownload {
+                    download_id: id.to_string(),
+                    repo_id: "test/model".to_string(),
+                    filename: "model.gguf".to_string(),
+                    filenames: Vec::new(),
+                    dest_dir: tmp.path().to_path_buf(),
+                    total_bytes: Some(1000),
+                    status: DownloadStatus::Downloading,
+                    download_request: make_request(),
+                    created_at: "2025-01-01T00:00:00Z".to_string(),
+                    known_sha256: None,
+                    huggingface_evidence: None,
+                })
+                .unwrap();
+        }
+
+        assert!(store
+            .record_finished("dl-1", DownloadStatus::Error, 400, Some("boom".to_string()))
+            .unwrap());
+        assert!(store
+            .record_finished("dl-2", DownloadStatus::Cancelled, 10, None)
+            .unwrap());
+        assert!(store
+            .record_finished("dl-1", DownloadStatus::Completed, 1000, None)
+            .unwrap());
+        assert!(!store
+            .record_finished("dl-missing", DownloadStatus::Completed, 0, None)
+            .unwrap());
+
+        let history = store.load_history(10, 0);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].download_id, "dl-1");
+        assert_eq!(history[0].status, DownloadStatus::Completed);
+        assert_eq!(history[0].bytes_downloaded, 1000);
+        assert_eq!(history[0].filenames, vec!["model.gguf".to_string()]);
+        assert_eq!(history[0].official_name, "Test Model");
+        assert!(history[0].duration_secs > 0.0);
+        assert_eq!(store.load_history(10, 1)[0].download_id, "dl-2");
+        assert_eq!(store.load_history(1, 0).len(), 1);
+
+        store.clear_history().unwrap();
+        assert!(store.load_history(10, 0).is_empty());
+        assert_eq!(store.load_all().len(), 2);
+    }
 }
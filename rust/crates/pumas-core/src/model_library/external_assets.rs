@@ -9,7 +9,7 @@ use crate::model_library::naming::normalize_name;
 use crate::models::{
     resolve_inference_settings, AssetValidationError, AssetValidationState,
     BundleComponentManifestEntry, BundleComponentState, BundleFormat, ExternalDiffusersImportSpec,
-    ImportState, ModelMetadata, StorageKind,
+    ImportState, ModelMetadata, ModelReadiness, StorageKind,
 };
 use serde_json::Value;
 use std::path::{Component, Path, PathBuf};
@@ -198,6 +198,10 @@ pub(crate) fn build_diffusers_bundle_metadata(
             AssetValidationState::Valid => ImportState::Ready,
             _ => ImportState::Failed,
         }),
+        readiness: Some(match validation.validation_state {
+            AssetValidationState::Valid => ModelReadiness::Ready,
+            _ => ModelReadiness::Error,
+        }),
         validation_state: Some(validation.validation_state),
         validation_errors,
         expected_files: spec.expected_files.map(|files| files.to_vec()),
@@ -2,6 +2,7 @@
 //!
 //! Provides SHA256 and BLAKE3 hashing with:
 //! - Single-pass dual hash computation
+//! - Incremental dual hashing for bytes streamed elsewhere, such as a copy
 //! - Fast hash (first + last 8MB) for quick filtering
 //! - Progress reporting for large files
 
@@ -16,6 +17,40 @@ const CHUNK_SIZE: usize = 8 * 1024 * 1024;
 /// Size to read for fast hash (first + last 8MB).
 const FAST_HASH_SIZE: usize = 8 * 1024 * 1024;
 
+/// Directory used as a hashing gate by integration tests.
+///
+/// When set, each hash marks its start with a `hashing-<n>` file there and
+/// then waits for a `release` file, so a test can hold hashes in flight
+/// without real multi-gigabyte inputs.
+#[cfg(feature = "test-hash-gate")]
+const TEST_HASH_GATE_ENV: &str = "PUMAS_TEST_HASH_GATE";
+
+/// Longest a hash waits at the test gate before continuing anyway.
+#[cfg(feature = "test-hash-gate")]
+const TEST_HASH_GATE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[cfg(feature = "test-hash-gate")]
+fn wait_for_test_hash_gate() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static STARTED: AtomicUsize = AtomicUsize::new(0);
+
+    let Some(gate) = std::env::var_os(TEST_HASH_GATE_ENV) else {
+        return;
+    };
+    let gate = Path::new(&gate);
+    let index = STARTED.fetch_add(1, Ordering::SeqCst);
+    let _ = std::fs::write(gate.join(format!("hashing-{index}")), b"");
+    let deadline = std::time::Instant::now() + TEST_HASH_GATE_TIMEOUT;
+    while !gate.join("release").exists() {
+        if std::time::Instant::now() >= deadline {
+            tracing::warn!("Test hash gate {} was never released", gate.display());
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
 /// Dual hash result containing both SHA256 and BLAKE3.
 #[derive(Debug, Clone)]
 pub struct DualHash {
@@ -25,6 +60,43 @@ pub struct DualHash {
     pub blake3: String,
 }
 
+/// Incremental SHA256 and BLAKE3 hasher for data that is already being read,
+/// such as a file being copied.
+pub struct DualHasher {
+    sha256: Sha256,
+    blake3: Blake3Hasher,
+}
+
+impl DualHasher {
+    /// Create an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sha256: Sha256::new(),
+            blake3: Blake3Hasher::new(),
+        }
+    }
+
+    /// Feed the next chunk of data to both hashers.
+    pub fn update(&mut self, data: &[u8]) {
+        self.sha256.update(data);
+        self.blake3.update(data);
+    }
+
+    /// Finish both hashes.
+    pub fn finalize(self) -> DualHash {
+        DualHash {
+            sha256: hex::encode(self.sha256.finalize()),
+            blake3: self.blake3.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+impl Default for DualHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Compute both SHA256 and BLAKE3 hashes in a single pass.
 ///
 /// This is more efficient than computing them separately since
@@ -39,10 +111,11 @@ pub struct DualHash {
 /// DualHash containing both hash values as hex strings.
 pub fn compute_dual_hash(path: impl AsRef<Path>) -> Result<DualHash> {
     let path = path.as_ref();
+    #[cfg(feature = "test-hash-gate")]
+    wait_for_test_hash_gate();
     let mut file = std::fs::File::open(path).map_err(|e| PumasError::io_with_path(e, path))?;
 
-    let mut sha256_hasher = Sha256::new();
-    let mut blake3_hasher = Blake3Hasher::new();
+    let mut hasher = DualHasher::new();
 
     let mut buffer = vec![0u8; CHUNK_SIZE];
     loop {
@@ -53,14 +126,10 @@ pub fn compute_dual_hash(path: impl AsRef<Path>) -> Result<DualHash> {
             break;
         }
 
-        sha256_hasher.update(&buffer[..bytes_read]);
-        blake3_hasher.update(&buffer[..bytes_read]);
+        hasher.update(&buffer[..bytes_read]);
     }
 
-    let sha256 = hex::encode(sha256_hasher.finalize());
-    let blake3 = blake3_hasher.finalize().to_hex().to_string();
-
-    Ok(DualHash { sha256, blake3 })
+    Ok(hasher.finalize())
 }
 
 /// Compute a fast hash for quick candidate filtering.
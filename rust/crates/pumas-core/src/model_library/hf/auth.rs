@@ -27,7 +27,8 @@ pub struct HfAuthStatus {
     pub authenticated: bool,
     /// HuggingFace username, if authenticated.
     pub username: Option<String>,
-    /// Where the token was resolved from: "pumas_config", "env_var", or "hf_cache".
+    /// Where the token was resolved from: "pumas_config", "env_var", "hf_cache", or
+    /// "session" for a token that was not persisted.
     pub token_source: Option<String>,
 }
 
@@ -3,23 +3,32 @@
 //! Handles multi-file downloads with progress tracking, pause/resume,
 //! cancellation, retry with resume, and crash recovery via persistence.
 
+use super::queue::{DownloadSlot, QueueTicket};
 use super::types::{
     AuxFilesCompleteCallback, AuxFilesCompleteInfo, DownloadCompletionCallback,
-    DownloadCompletionInfo, DownloadState, FileToDownload, HF_HUB_BASE,
+    DownloadCompletionInfo, DownloadSessionCounters, DownloadState, FileToDownload, HF_HUB_BASE,
 };
 use super::HuggingFaceClient;
 use crate::error::{PumasError, Result};
-use crate::model_library::download_store::{DownloadPersistence, PersistedDownload};
+use crate::model_library::download_store::{
+    DownloadHistoryEntry, DownloadPersistence, PersistedDownload,
+};
+use crate::model_library::hashing::verify_sha256;
 use crate::model_library::sharding;
-use crate::model_library::types::{DownloadRequest, DownloadStatus, ModelDownloadProgress};
-use crate::model_library::SelectedArtifactIdentity;
+use crate::model_library::types::{
+    DownloadPriority, DownloadRequest, DownloadStatus, ModelDownloadProgress, RepoFileTree,
+};
+use crate::model_library::{ScanIgnore, SelectedArtifactIdentity};
+use crate::network::{parse_content_range, BandwidthLimiter, RetryConfig};
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::collections::VecDeque;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex as StdMutex};
 use std::time::{Duration, Instant};
 use tokio::io::AsyncWriteExt;
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, RwLock, Semaphore};
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
@@ -45,6 +54,8 @@ const AUXILIARY_FILE_PATTERNS: &[&str] = &[
 ];
 const DOWNLOAD_UPDATE_CURSOR_PREFIX: &str = "download:";
 const DOWNLOAD_PROGRESS_PUBLISH_INTERVAL: Duration = Duration::from_millis(500);
+/// Minimum spacing between samples kept for the rolling speed window.
+const DOWNLOAD_SPEED_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
 
 /// Select auxiliary config/tokenizer files from a repo's regular (non-LFS) file list.
 fn select_auxiliary_files(regular_files: &[String]) -> Vec<String> {
@@ -130,6 +141,157 @@ fn retry_limit(max_attempts: u32) -> Option<u32> {
     }
 }
 
+/// Number of weight files a download transfers at once, from its request.
+fn concurrent_file_limit(request: Option<&DownloadRequest>) -> usize {
+    let requested = request
+        .and_then(|req| req.max_concurrent_files)
+        .unwrap_or(1) as usize;
+    requested.clamp(
+        1,
+        crate::config::NetworkConfig::HF_DOWNLOAD_MAX_CONCURRENT_FILES,
+    )
+}
+
+/// Check that a `206 Partial Content` response continues a partial file of
+/// `resume_from_byte` bytes.
+///
+/// Returns the remote file size when the server reported one. Fails when the
+/// range does not start at the partial's end, or when the remote size no
+/// longer matches `expected_total`, which means the file changed since the
+/// partial was written.
+fn validate_resume_range(
+    content_range: Option<&str>,
+    content_length: Option<u64>,
+    resume_from_byte: u64,
+    expected_total: Option<u64>,
+) -> std::result::Result<Option<u64>, String> {
+    let content_range = content_range.ok_or("206 response without Content-Range")?;
+    let (start, end, total) = parse_content_range(content_range)
+        .ok_or_else(|| format!("unparseable Content-Range '{}'", content_range))?;
+    if start != resume_from_byte || end < start {
+        return Err(format!(
+            "server sent range {}-{} for resume from byte {}",
+            start, end, resume_from_byte
+        ));
+    }
+    if let (Some(total), Some(expected)) = (total, expected_total) {
+        if total != expected {
+            return Err(format!(
+                "remote size changed from {} to {} bytes",
+                expected, total
+            ));
+        }
+    }
+    if let (Some(total), Some(length)) = (total, content_length) {
+        if total.checked_sub(start) != Some(length) {
+            return Err(format!(
+                "Content-Length {} does not cover bytes {}-{}",
+                length,
+                start,
+                total.saturating_sub(1)
+            ));
+        }
+    }
+    Ok(total)
+}
+
+/// Whether a repository file path stays inside the directory it is joined to.
+fn is_contained_repo_path(path: &str) -> bool {
+    let path = Path::new(path);
+    path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, std::path::Component::Normal(_)))
+}
+
+/// Every file of a repository for a mirror download, keeping repo-relative
+/// paths. Regular files come first so they download one at a time before the
+/// LFS files, matching the auxiliary/weight split of model downloads. Paths
+/// that would escape the destination are dropped.
+fn repo_mirror_files(tree: &RepoFileTree) -> Vec<FileToDownload> {
+    let regular = tree.regular_files.iter().map(|filename| FileToDownload {
+        filename: filename.clone(),
+        size: None,
+        sha256: None,
+    });
+    let lfs = tree.lfs_files.iter().map(|file| FileToDownload {
+        filename: file.filename.clone(),
+        size: Some(file.size),
+        sha256: Some(file.sha256.clone()),
+    });
+    regular
+        .chain(lfs)
+        .filter(|file| {
+            let contained = is_contained_repo_path(&file.filename);
+            if !contained {
+                warn!(
+                    "Skipping repository file with unsafe path {:?} in {}",
+                    file.filename, tree.repo_id
+                );
+            }
+            contained
+        })
+        .collect()
+}
+
+/// Include/exclude patterns of a download request.
+///
+/// Patterns use the `.pumasignore` syntax and are matched against
+/// repo-relative file paths, so `*.md` matches a name at any depth and
+/// `images/*` matches files under `images/`.
+struct DownloadFileFilter {
+    include: Option<ScanIgnore>,
+    exclude: Option<ScanIgnore>,
+}
+
+impl DownloadFileFilter {
+    fn from_request(request: &DownloadRequest) -> Self {
+        let compile = |patterns: &Option<Vec<String>>| {
+            patterns
+                .as_deref()
+                .filter(|patterns| !patterns.is_empty())
+                .map(ScanIgnore::new)
+        };
+        Self {
+            include: compile(&request.include_patterns),
+            exclude: compile(&request.exclude_patterns),
+        }
+    }
+
+    fn is_excluded(&self, filename: &str) -> bool {
+        self.exclude
+            .as_ref()
+            .is_some_and(|exclude| exclude.is_ignored(Path::new(filename)))
+    }
+
+    /// Whether a weight file passes both the include and exclude patterns.
+    fn keeps_weight(&self, filename: &str) -> bool {
+        self.include
+            .as_ref()
+            .is_none_or(|include| include.is_ignored(Path::new(filename)))
+            && !self.is_excluded(filename)
+    }
+
+    /// Whether an auxiliary file passes the exclude patterns. Include
+    /// patterns select weights only, so configs and tokenizers still arrive.
+    fn keeps_auxiliary(&self, filename: &str) -> bool {
+        !self.is_excluded(filename)
+    }
+
+    /// Split `files` into kept files, appending the names of filtered files
+    /// to `skipped`.
+    fn retain(
+        files: Vec<FileToDownload>,
+        keep: impl Fn(&str) -> bool,
+        skipped: &mut Vec<String>,
+    ) -> Vec<FileToDownload> {
+        let (kept, dropped): (Vec<_>, Vec<_>) =
+            files.into_iter().partition(|file| keep(&file.filename));
+        skipped.extend(dropped.into_iter().map(|file| file.filename));
+        kept
+    }
+}
+
 fn retry_limit_display(limit: Option<u32>) -> String {
     match limit {
         Some(limit) => limit.to_string(),
@@ -226,6 +388,9 @@ fn progress_from_state(state: &DownloadState) -> ModelDownloadProgress {
         retry_limit: state.retry_limit,
         retrying: Some(state.retrying),
         next_retry_delay_seconds: state.next_retry_delay_seconds,
+        queue_position: state.queue_position,
+        stalled: state.stalled,
+        skipped_files: state.skipped_files.clone(),
         error: state.error.clone(),
     }
 }
@@ -264,182 +429,899 @@ async fn publish_download_snapshot_from_parts(
     let _ = updates.send(notification);
 }
 
-impl HuggingFaceClient {
-    fn store_download_task(&self, download_id: String, handle: JoinHandle<()>) {
-        let mut tasks = self
-            .download_tasks
-            .lock()
-            .expect("HF download task lock poisoned");
-        tasks.retain(|_, handle| !handle.is_finished());
-        if let Some(previous) = tasks.insert(download_id, handle) {
-            previous.abort();
-        }
+/// Bytes on disk for each file of one download, summed into overall progress.
+///
+/// Files transfer concurrently, so each file records its own byte count and
+/// the download's `downloaded_bytes` is their sum. Speed is measured over a
+/// rolling window of recent samples so the ETA follows the current rate
+/// instead of swinging with every chunk or lagging behind the run average.
+struct DownloadByteProgress {
+    file_bytes: Vec<AtomicU64>,
+    transferred: AtomicU64,
+    /// `(time, transferred)` samples covering the speed window, oldest first.
+    samples: StdMutex<VecDeque<(Instant, u64)>>,
+    window: Duration,
+}
+
+impl DownloadByteProgress {
+    fn new(file_count: usize) -> Self {
+        Self::with_window(
+            file_count,
+            crate::config::NetworkConfig::HF_DOWNLOAD_SPEED_WINDOW,
+            Instant::now(),
+        )
     }
 
-    fn abort_download_task(&self, download_id: &str) {
-        if let Some(handle) = self
-            .download_tasks
-            .lock()
-            .expect("HF download task lock poisoned")
-            .remove(download_id)
-        {
-            handle.abort();
+    fn with_window(file_count: usize, window: Duration, started: Instant) -> Self {
+        Self {
+            file_bytes: (0..file_count).map(|_| AtomicU64::new(0)).collect(),
+            transferred: AtomicU64::new(0),
+            samples: StdMutex::new(VecDeque::from([(started, 0)])),
+            window,
         }
     }
 
-    fn prune_finished_download_tasks(&self) {
-        let mut tasks = self
-            .download_tasks
-            .lock()
-            .expect("HF download task lock poisoned");
-        tasks.retain(|_, handle| !handle.is_finished());
+    /// Record the bytes a file has on disk before any transfer in this run.
+    fn set_file(&self, file_idx: usize, bytes: u64) {
+        self.file_bytes[file_idx].store(bytes, Ordering::Relaxed);
     }
 
-    async fn mark_download_task_registered(&self, download_id: &str) {
-        let mut downloads = self.downloads.write().await;
-        if let Some(state) = downloads.get_mut(download_id) {
-            state.task_registered = true;
+    /// Record bytes received for a file during this run.
+    fn add(&self, file_idx: usize, bytes: u64) {
+        self.file_bytes[file_idx].fetch_add(bytes, Ordering::Relaxed);
+        self.transferred.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Bytes on disk across all files.
+    fn total(&self) -> u64 {
+        self.file_bytes
+            .iter()
+            .map(|bytes| bytes.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Combined transfer rate of all files over the speed window, in bytes/s.
+    fn speed(&self) -> f64 {
+        self.speed_at(Instant::now())
+    }
+
+    fn speed_at(&self, now: Instant) -> f64 {
+        let transferred = self.transferred.load(Ordering::Relaxed);
+        let mut samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        if samples.back().is_none_or(|(at, _)| {
+            now.saturating_duration_since(*at) >= DOWNLOAD_SPEED_SAMPLE_INTERVAL
+        }) {
+            samples.push_back((now, transferred));
+        }
+        // Keep the newest sample at or before the window start as the baseline.
+        if let Some(window_start) = now.checked_sub(self.window) {
+            while samples.len() > 1 && samples[1].0 <= window_start {
+                samples.pop_front();
+            }
+        }
+
+        let Some(&(since, baseline)) = samples.front() else {
+            return 0.0;
+        };
+        let elapsed = now.saturating_duration_since(since).as_secs_f64();
+        if elapsed > 0.0 {
+            transferred.saturating_sub(baseline) as f64 / elapsed
+        } else {
+            0.0
         }
     }
+}
 
-    async fn reconcile_inactive_active_downloads(&self) {
-        self.prune_finished_download_tasks();
+/// Shared state for transferring the files of one download.
+struct FileTransfer<'a> {
+    client: &'a reqwest::Client,
+    downloads: &'a Arc<RwLock<HashMap<String, DownloadState>>>,
+    download_revision: &'a Arc<AtomicU64>,
+    download_updates: &'a broadcast::Sender<crate::models::ModelDownloadUpdateNotification>,
+    download_id: &'a str,
+    repo_id: &'a str,
+    dest_dir: &'a Path,
+    cancel_flag: &'a Arc<AtomicBool>,
+    pause_flag: &'a Arc<AtomicBool>,
+    persistence: Option<&'a Arc<DownloadPersistence>>,
+    auth_header: Option<&'a str>,
+    verify_hashes: bool,
+    retry_limit: Option<u32>,
+    max_retry_elapsed: Duration,
+    retry_config: &'a RetryConfig,
+    progress: &'a DownloadByteProgress,
+    rate_limiter: &'a BandwidthLimiter,
+    shared_rate_limiter: &'a BandwidthLimiter,
+    counters: &'a DownloadSessionCounters,
+    /// Longest wait for the next bytes before giving up; zero disables.
+    stall_timeout: Duration,
+}
 
-        let missing_registered_tasks = {
-            let running_task_ids = self
-                .download_tasks
-                .lock()
-                .expect("HF download task lock poisoned")
-                .keys()
-                .cloned()
-                .collect::<HashSet<_>>();
-            let downloads = self.downloads.read().await;
+impl FileTransfer<'_> {
+    async fn publish(&self) {
+        publish_download_snapshot_from_parts(
+            self.downloads,
+            self.download_revision,
+            self.download_updates,
+        )
+        .await;
+    }
 
-            downloads
-                .iter()
-                .filter_map(|(download_id, state)| {
-                    let is_active = matches!(
-                        state.status,
-                        DownloadStatus::Queued
-                            | DownloadStatus::Downloading
-                            | DownloadStatus::Pausing
-                    );
-                    let has_running_task = running_task_ids.contains(download_id);
+    fn part_path(&self, filename: &str) -> PathBuf {
+        self.dest_dir.join(format!(
+            "{}{}",
+            filename,
+            crate::config::NetworkConfig::DOWNLOAD_TEMP_SUFFIX
+        ))
+    }
 
-                    if is_active && state.task_registered && !has_running_task {
-                        Some(download_id.clone())
-                    } else {
-                        None
-                    }
+    /// Await `future`, giving up once the stall timeout passes without it
+    /// completing.
+    ///
+    /// A stall marks the download `stalled` and fails with a non-retryable
+    /// error, so the download ends in `Error` with its `.part` files kept for
+    /// `resume_download` instead of waiting on a dead connection forever.
+    async fn unless_stalled<T>(
+        &self,
+        url: &str,
+        future: impl std::future::Future<Output = T>,
+    ) -> Result<T> {
+        if self.stall_timeout.is_zero() {
+            return Ok(future.await);
+        }
+        match tokio::time::timeout(self.stall_timeout, future).await {
+            Ok(value) => Ok(value),
+            Err(_) => {
+                warn!(
+                    "Download {} stalled: no data from {} for {:?}",
+                    self.download_id, url, self.stall_timeout
+                );
+                self.counters.stalls.fetch_add(1, Ordering::Relaxed);
+                if let Some(state) = self.downloads.write().await.get_mut(self.download_id) {
+                    state.stalled = true;
+                    state.speed = 0.0;
+                }
+                self.publish().await;
+                Err(PumasError::DownloadFailed {
+                    url: url.to_string(),
+                    message: format!(
+                        "Download stalled: no data received for {}s",
+                        self.stall_timeout.as_secs()
+                    ),
                 })
-                .collect::<Vec<_>>()
-        };
+            }
+        }
+    }
 
-        if missing_registered_tasks.is_empty() {
-            return;
+    /// Remove the `.part` files of a cancelled download.
+    async fn remove_part_files(&self, files: &[FileToDownload]) {
+        for file_info in files {
+            let _ = tokio::fs::remove_file(self.part_path(&file_info.filename)).await;
         }
+    }
 
-        {
-            let mut downloads = self.downloads.write().await;
-            for download_id in &missing_registered_tasks {
+    /// Download one file with retry and resume. Files that already exist on
+    /// disk (from a previous partial download) are skipped.
+    async fn download_file(&self, file_idx: usize, file_info: &FileToDownload) -> Result<()> {
+        let downloads = self.downloads;
+        let download_id = self.download_id;
+        let repo_id = self.repo_id;
+        let retry_limit = self.retry_limit;
+        let filename = &file_info.filename;
+        let dest_path = self.dest_dir.join(filename);
+        let part_path = self.part_path(filename);
+
+        // Ensure parent directory exists (needed for subdirectory files
+        // like transformer/model.safetensors in diffusion repos)
+        if let Some(parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // Skip files that already exist (completed from previous run)
+        if tokio::fs::try_exists(&dest_path).await.unwrap_or(false) {
+            HuggingFaceClient::remove_stale_part_for_completed_file(&dest_path, &part_path).await;
+            let existing_size = tokio::fs::metadata(&dest_path)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+            self.progress.set_file(file_idx, existing_size);
+            info!(
+                "Skipping already-downloaded file {}/{} ({} bytes)",
+                repo_id, filename, existing_size
+            );
+
+            // Update state
+            {
+                let mut downloads = downloads.write().await;
                 if let Some(state) = downloads.get_mut(download_id) {
-                    if matches!(
-                        state.status,
-                        DownloadStatus::Queued
-                            | DownloadStatus::Downloading
-                            | DownloadStatus::Pausing
-                    ) {
-                        warn!(
-                            "Marking inactive download {} as paused because no task is running",
-                            download_id
-                        );
-                        state.status = DownloadStatus::Paused;
-                        state.speed = 0.0;
-                        state.retrying = false;
-                        state.next_retry_delay_seconds = None;
+                    state.files_completed += 1;
+                    state.downloaded_bytes = self.progress.total();
+                    if let Some(total) = state.total_bytes {
+                        state.progress = state.downloaded_bytes as f32 / total as f32;
                     }
                 }
             }
+            self.publish().await;
+            return Ok(());
         }
 
-        if let Some(ref persistence) = self.persistence {
-            for download_id in missing_registered_tasks {
-                Self::persist_status_update(
-                    persistence.clone(),
-                    download_id,
-                    DownloadStatus::Paused,
-                )
-                .await;
+        // Update current filename in state
+        {
+            let mut downloads = downloads.write().await;
+            if let Some(state) = downloads.get_mut(download_id) {
+                state.filename = filename.clone();
+                state.retry_attempt = 0;
+                state.retry_limit = retry_limit;
+                state.retrying = false;
+                state.next_retry_delay_seconds = None;
             }
         }
+        self.publish().await;
 
-        self.publish_download_snapshot().await;
-    }
-
-    /// Restore persisted downloads from disk.
-    ///
-    /// Called during startup to recover paused/errored downloads from a previous session.
-    /// Only restores entries whose `.part` file still exists on disk.
-    pub async fn restore_persisted_downloads(&self) {
-        let persistence = match &self.persistence {
-            Some(p) => p.clone(),
-            None => return,
-        };
-
-        let restored_entries = match tokio::task::spawn_blocking(move || {
-            let entries = persistence.load_all();
-            let mut restored = Vec::new();
-
-            for entry in entries {
-                let all_filenames = if entry.filenames.is_empty() {
-                    vec![entry.filename.clone()]
-                } else {
-                    entry.filenames.clone()
-                };
+        let url = format!("{}/{}/resolve/main/{}", HF_HUB_BASE, repo_id, filename);
 
-                let has_any_file = all_filenames.iter().any(|f| {
-                    let part = entry.dest_dir.join(format!(
-                        "{}{}",
-                        f,
-                        crate::config::NetworkConfig::DOWNLOAD_TEMP_SUFFIX
-                    ));
-                    let completed = entry.dest_dir.join(f);
-                    part.exists() || completed.exists()
-                });
+        let mut last_error: Option<PumasError> = None;
 
-                if !has_any_file {
-                    info!(
-                        "Removing stale persisted download {} (no files on disk)",
-                        entry.download_id
-                    );
-                    let _ = persistence.remove(&entry.download_id);
-                    continue;
+        let mut file_completed = false;
+        let mut attempt: u32 = 0;
+        let retry_started = Instant::now();
+        loop {
+            attempt += 1;
+            {
+                let mut downloads = downloads.write().await;
+                if let Some(state) = downloads.get_mut(download_id) {
+                    state.retry_attempt = attempt;
+                    state.retry_limit = retry_limit;
+                    state.retrying = false;
+                    state.next_retry_delay_seconds = None;
                 }
+            }
 
-                let downloaded_bytes: u64 = all_filenames
-                    .iter()
-                    .map(|f| {
-                        let completed = entry.dest_dir.join(f);
-                        let part = entry.dest_dir.join(format!(
-                            "{}{}",
-                            f,
-                            crate::config::NetworkConfig::DOWNLOAD_TEMP_SUFFIX
-                        ));
-                        if completed.exists() {
-                            std::fs::metadata(&completed).map(|m| m.len()).unwrap_or(0)
-                        } else if part.exists() {
-                            std::fs::metadata(&part).map(|m| m.len()).unwrap_or(0)
-                        } else {
-                            0
-                        }
+            // Check cancellation before each attempt
+            if self.cancel_flag.load(Ordering::Relaxed) {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                let mut download_states = downloads.write().await;
+                let downloaded_bytes = download_states
+                    .get_mut(download_id)
+                    .map(|state| {
+                        state.status = DownloadStatus::Cancelled;
+                        state.downloaded_bytes
                     })
-                    .sum();
+                    .unwrap_or(0);
+                drop(download_states);
+                self.publish().await;
+                if let Some(persistence) = self.persistence {
+                    HuggingFaceClient::finish_persisted_download(
+                        persistence.clone(),
+                        download_id.to_string(),
+                        DownloadStatus::Cancelled,
+                        downloaded_bytes,
+                        None,
+                    )
+                    .await;
+                }
+                return Err(PumasError::DownloadCancelled);
+            }
 
-                restored.push((entry, downloaded_bytes));
+            // Check pause before each attempt
+            if self.pause_flag.load(Ordering::Relaxed) {
+                let mut download_states = downloads.write().await;
+                if let Some(state) = download_states.get_mut(download_id) {
+                    state.status = DownloadStatus::Paused;
+                }
+                drop(download_states);
+                self.publish().await;
+                if let Some(persistence) = self.persistence {
+                    HuggingFaceClient::persist_status_update(
+                        persistence.clone(),
+                        download_id.to_string(),
+                        DownloadStatus::Paused,
+                    )
+                    .await;
+                }
+                return Err(PumasError::DownloadPaused);
             }
 
-            restored
-        })
+            // Determine resume offset from existing .part file
+            let resume_from_byte = tokio::fs::metadata(&part_path)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+
+            if attempt > 1 {
+                warn!(
+                    "Retry {}/{} for {}/{} (resuming from byte {})",
+                    attempt,
+                    retry_limit_display(retry_limit),
+                    repo_id,
+                    filename,
+                    resume_from_byte
+                );
+                self.counters.retries.fetch_add(1, Ordering::Relaxed);
+
+                // Reset status to Downloading for the retry
+                let mut download_states = downloads.write().await;
+                if let Some(state) = download_states.get_mut(download_id) {
+                    state.status = DownloadStatus::Downloading;
+                    state.error = None;
+                    state.retry_attempt = attempt;
+                    state.retry_limit = retry_limit;
+                    state.retrying = false;
+                    state.next_retry_delay_seconds = None;
+                }
+                drop(download_states);
+                self.publish().await;
+            }
+
+            match self
+                .download_attempt(file_idx, &url, &part_path, file_info.size, resume_from_byte)
+                .await
+            {
+                Ok(_) => {
+                    if let (true, Some(expected)) =
+                        (self.verify_hashes, file_info.sha256.as_deref())
+                    {
+                        HuggingFaceClient::verify_downloaded_file(&part_path, expected)
+                            .await
+                            .inspect_err(|e| {
+                                warn!(
+                                    "Downloaded file {}/{} failed verification: {}",
+                                    repo_id, filename, e
+                                )
+                            })?;
+                    }
+
+                    // Rename .part to final path atomically
+                    tokio::fs::rename(&part_path, &dest_path)
+                        .await
+                        .map_err(|e| PumasError::DownloadFailed {
+                            url: url.clone(),
+                            message: format!("Failed to rename temp file: {}", e),
+                        })?;
+
+                    file_completed = true;
+                    break;
+                }
+                Err(e) => {
+                    // Paused -- .part preserved, not a real error
+                    if matches!(e, PumasError::DownloadPaused) {
+                        if let Some(persistence) = self.persistence {
+                            HuggingFaceClient::persist_status_update(
+                                persistence.clone(),
+                                download_id.to_string(),
+                                DownloadStatus::Paused,
+                            )
+                            .await;
+                        }
+                        return Err(e);
+                    }
+
+                    if !e.is_retryable() || self.cancel_flag.load(Ordering::Relaxed) {
+                        if self.cancel_flag.load(Ordering::Relaxed) {
+                            let _ = tokio::fs::remove_file(&part_path).await;
+                            if let Some(persistence) = self.persistence {
+                                let downloaded_bytes = downloads
+                                    .read()
+                                    .await
+                                    .get(download_id)
+                                    .map_or(0, |state| state.downloaded_bytes);
+                                HuggingFaceClient::finish_persisted_download(
+                                    persistence.clone(),
+                                    download_id.to_string(),
+                                    DownloadStatus::Cancelled,
+                                    downloaded_bytes,
+                                    None,
+                                )
+                                .await;
+                            }
+                        }
+                        return Err(e);
+                    }
+
+                    warn!(
+                        "Download attempt {}/{} failed for {}/{}: {}",
+                        attempt,
+                        retry_limit_display(retry_limit),
+                        repo_id,
+                        filename,
+                        e
+                    );
+                    let error_text = e.to_string();
+                    last_error = Some(e);
+
+                    let elapsed = retry_started.elapsed();
+                    if retry_exhausted(attempt, retry_limit, elapsed, self.max_retry_elapsed) {
+                        break;
+                    }
+
+                    let delay = self.retry_config.calculate_delay(attempt.saturating_sub(1));
+                    let limit_text = retry_limit_display(retry_limit);
+                    let next_attempt = attempt + 1;
+                    {
+                        let mut downloads = downloads.write().await;
+                        if let Some(state) = downloads.get_mut(download_id) {
+                            state.retry_attempt = attempt;
+                            state.retry_limit = retry_limit;
+                            state.retrying = true;
+                            state.next_retry_delay_seconds = Some(delay.as_secs_f64());
+                            state.error = Some(format!(
+                                "Transient network error, retrying attempt {}/{} in {:.1}s: {}",
+                                next_attempt,
+                                limit_text,
+                                delay.as_secs_f64(),
+                                error_text
+                            ));
+                        }
+                    }
+                    self.publish().await;
+                    debug!("Waiting {:?} before retry", delay);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+
+        if !file_completed {
+            let elapsed = retry_started.elapsed();
+            if let Some(last_error) = last_error {
+                let detail =
+                    retry_exhausted_message(attempt, retry_limit, elapsed, &last_error.to_string());
+                return Err(PumasError::DownloadFailed {
+                    url,
+                    message: detail,
+                });
+            }
+            return Err(PumasError::DownloadFailed {
+                url,
+                message: "Download stopped before completion".to_string(),
+            });
+        }
+
+        // File completed -- use actual file size for accurate progress
+        let actual_size = tokio::fs::metadata(&dest_path)
+            .await
+            .map(|m| m.len())
+            .unwrap_or(file_info.size.unwrap_or(0));
+        self.progress.set_file(file_idx, actual_size);
+        {
+            let mut downloads = downloads.write().await;
+            if let Some(state) = downloads.get_mut(download_id) {
+                state.files_completed += 1;
+                state.downloaded_bytes = self.progress.total();
+                state.retry_attempt = 0;
+                state.retrying = false;
+                state.next_retry_delay_seconds = None;
+                state.error = None;
+            }
+        }
+        self.publish().await;
+
+        info!(
+            "File {}/{} complete ({}/{})",
+            repo_id,
+            filename,
+            file_idx + 1,
+            self.progress.file_bytes.len()
+        );
+        Ok(())
+    }
+
+    /// Execute a single download attempt, optionally resuming from a byte offset.
+    ///
+    /// `file_size_expected` is the expected size of this individual file.
+    /// Overall progress is the sum of every file's bytes on disk.
+    async fn download_attempt(
+        &self,
+        file_idx: usize,
+        url: &str,
+        part_path: &Path,
+        file_size_expected: Option<u64>,
+        resume_from_byte: u64,
+    ) -> Result<()> {
+        let (downloads, download_id) = (self.downloads, self.download_id);
+
+        let mut resume_from_byte = resume_from_byte;
+        if file_size_expected.is_some_and(|expected| resume_from_byte > expected) {
+            warn!(
+                "Partial file {} is larger than the remote file, restarting from zero",
+                part_path.display()
+            );
+            resume_from_byte = 0;
+        }
+
+        // A stale or mismatched partial is discarded by re-requesting without
+        // a Range header, so this loops at most twice.
+        let (response, range_total) = loop {
+            let mut request = self.client.get(url);
+            if let Some(auth) = self.auth_header {
+                request = request.header("Authorization", auth);
+            }
+            if resume_from_byte > 0 {
+                request = request.header("Range", format!("bytes={}-", resume_from_byte));
+                info!("Resuming download from byte {}", resume_from_byte);
+            }
+
+            let response = self
+                .unless_stalled(url, request.send())
+                .await?
+                .map_err(|e| PumasError::Network {
+                    message: format!("Download request failed: {}", e),
+                    cause: Some(e.to_string()),
+                })?;
+            if resume_from_byte == 0 {
+                break (response, None);
+            }
+
+            match response.status() {
+                reqwest::StatusCode::RANGE_NOT_SATISFIABLE => {
+                    warn!(
+                        "Server rejected resume from byte {}, restarting from zero",
+                        resume_from_byte
+                    );
+                }
+                reqwest::StatusCode::PARTIAL_CONTENT => {
+                    let content_range = response
+                        .headers()
+                        .get(reqwest::header::CONTENT_RANGE)
+                        .and_then(|value| value.to_str().ok());
+                    match validate_resume_range(
+                        content_range,
+                        response.content_length(),
+                        resume_from_byte,
+                        file_size_expected,
+                    ) {
+                        Ok(total) => break (response, total),
+                        Err(reason) => {
+                            warn!("Discarding partial download: {}", reason);
+                        }
+                    }
+                }
+                _ => break (response, None),
+            }
+            resume_from_byte = 0;
+        };
+
+        let status = response.status();
+
+        // Check for non-success responses (but 206 Partial Content is expected for resume)
+        if !status.is_success() && status != reqwest::StatusCode::PARTIAL_CONTENT {
+            return Err(PumasError::DownloadFailed {
+                url: url.to_string(),
+                message: format!("HTTP {}", status),
+            });
+        }
+
+        // Determine if we're actually resuming
+        let is_resuming = resume_from_byte > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+        if resume_from_byte > 0 && !is_resuming {
+            warn!("Server does not support Range requests, restarting from zero");
+        }
+
+        // Per-file total for completeness verification
+        let file_total = if is_resuming {
+            file_size_expected.or(range_total)
+        } else {
+            response.content_length().or(file_size_expected)
+        };
+
+        // Open file: append for resume, create for fresh start
+        let mut file = if is_resuming {
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(part_path)
+                .await?
+        } else {
+            tokio::fs::File::create(part_path).await?
+        };
+
+        let mut downloaded: u64 = if is_resuming { resume_from_byte } else { 0 };
+        self.progress.set_file(file_idx, downloaded);
+        let mut stream = response.bytes_stream();
+        let mut last_publish = Instant::now();
+
+        loop {
+            // Checked before each read so a wait on the bandwidth budget
+            // that was cut short by cancel or pause ends the transfer here.
+            if self.cancel_flag.load(Ordering::Relaxed) {
+                drop(file);
+                let _ = tokio::fs::remove_file(part_path).await;
+
+                let mut download_states = downloads.write().await;
+                if let Some(state) = download_states.get_mut(download_id) {
+                    state.status = DownloadStatus::Cancelled;
+                }
+                drop(download_states);
+                self.publish().await;
+
+                return Err(PumasError::DownloadCancelled);
+            }
+
+            if self.pause_flag.load(Ordering::Relaxed) {
+                file.flush().await?;
+                drop(file);
+                // Preserve .part file for resume
+
+                let mut download_states = downloads.write().await;
+                if let Some(state) = download_states.get_mut(download_id) {
+                    state.status = DownloadStatus::Paused;
+                }
+                drop(download_states);
+                self.publish().await;
+
+                return Err(PumasError::DownloadPaused);
+            }
+
+            let next = match self.unless_stalled(url, stream.next()).await {
+                Ok(next) => next,
+                Err(e) => {
+                    // Keep the bytes received so far for resume.
+                    file.flush().await?;
+                    return Err(e);
+                }
+            };
+            let Some(chunk) = next else {
+                break;
+            };
+            let chunk = chunk.map_err(|e| PumasError::Network {
+                message: format!("Download stream error: {}", e),
+                cause: Some(e.to_string()),
+            })?;
+
+            let stopped = || {
+                self.cancel_flag.load(Ordering::Relaxed) || self.pause_flag.load(Ordering::Relaxed)
+            };
+            if !self.rate_limiter.acquire(chunk.len() as u64, stopped).await
+                || !self
+                    .shared_rate_limiter
+                    .acquire(chunk.len() as u64, stopped)
+                    .await
+            {
+                continue;
+            }
+            file.write_all(&chunk).await?;
+            downloaded += chunk.len() as u64;
+            self.progress.add(file_idx, chunk.len() as u64);
+            self.counters
+                .bytes_downloaded
+                .fetch_add(chunk.len() as u64, Ordering::Relaxed);
+
+            // Update overall progress across all files of this download
+            let overall_downloaded = self.progress.total();
+
+            let mut download_states = downloads.write().await;
+            if let Some(state) = download_states.get_mut(download_id) {
+                state.downloaded_bytes = overall_downloaded;
+                state.speed = self.progress.speed();
+                state.progress = if let Some(total) = state.total_bytes {
+                    overall_downloaded as f32 / total as f32
+                } else {
+                    0.0
+                };
+            }
+            drop(download_states);
+
+            if last_publish.elapsed() >= DOWNLOAD_PROGRESS_PUBLISH_INTERVAL {
+                self.publish().await;
+                last_publish = Instant::now();
+            }
+        }
+
+        file.flush().await?;
+        drop(file);
+
+        // Verify this file's download completeness
+        if let Some(total) = file_total {
+            if downloaded != total {
+                return Err(PumasError::Network {
+                    message: format!("Incomplete download: got {} of {} bytes", downloaded, total),
+                    cause: None,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl HuggingFaceClient {
+    fn store_download_task(&self, download_id: String, handle: JoinHandle<()>) {
+        let mut tasks = self
+            .download_tasks
+            .lock()
+            .expect("HF download task lock poisoned");
+        tasks.retain(|_, handle| !handle.is_finished());
+        if let Some(previous) = tasks.insert(download_id, handle) {
+            previous.abort();
+        }
+    }
+
+    fn abort_download_task(&self, download_id: &str) {
+        if let Some(handle) = self
+            .download_tasks
+            .lock()
+            .expect("HF download task lock poisoned")
+            .remove(download_id)
+        {
+            handle.abort();
+        }
+    }
+
+    fn prune_finished_download_tasks(&self) {
+        let mut tasks = self
+            .download_tasks
+            .lock()
+            .expect("HF download task lock poisoned");
+        tasks.retain(|_, handle| !handle.is_finished());
+    }
+
+    async fn mark_download_task_registered(&self, download_id: &str) {
+        let mut downloads = self.downloads.write().await;
+        if let Some(state) = downloads.get_mut(download_id) {
+            state.task_registered = true;
+        }
+    }
+
+    async fn reconcile_inactive_active_downloads(&self) {
+        self.prune_finished_download_tasks();
+
+        let missing_registered_tasks = {
+            let running_task_ids = self
+                .download_tasks
+                .lock()
+                .expect("HF download task lock poisoned")
+                .keys()
+                .cloned()
+                .collect::<HashSet<_>>();
+            let downloads = self.downloads.read().await;
+
+            downloads
+                .iter()
+                .filter_map(|(download_id, state)| {
+                    let is_active = matches!(
+                        state.status,
+                        DownloadStatus::Queued
+                            | DownloadStatus::Downloading
+                            | DownloadStatus::Pausing
+                    );
+                    let has_running_task = running_task_ids.contains(download_id);
+
+                    if is_active && state.task_registered && !has_running_task {
+                        Some(download_id.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if missing_registered_tasks.is_empty() {
+            return;
+        }
+
+        {
+            let mut downloads = self.downloads.write().await;
+            for download_id in &missing_registered_tasks {
+                if let Some(state) = downloads.get_mut(download_id) {
+                    if matches!(
+                        state.status,
+                        DownloadStatus::Queued
+                            | DownloadStatus::Downloading
+                            | DownloadStatus::Pausing
+                    ) {
+                        warn!(
+                            "Marking inactive download {} as paused because no task is running",
+                            download_id
+                        );
+                        state.status = DownloadStatus::Paused;
+                        state.speed = 0.0;
+                        state.retrying = false;
+                        state.next_retry_delay_seconds = None;
+                    }
+                }
+            }
+        }
+
+        if let Some(ref persistence) = self.persistence {
+            for download_id in missing_registered_tasks {
+                Self::persist_status_update(
+                    persistence.clone(),
+                    download_id,
+                    DownloadStatus::Paused,
+                )
+                .await;
+            }
+        }
+
+        self.publish_download_snapshot().await;
+    }
+
+    /// Restore persisted downloads from disk.
+    ///
+    /// Called during startup to recover paused/errored downloads from a previous session.
+    /// Only restores entries whose `.part` file still exists on disk. Entries already
+    /// marked Completed or Cancelled are moved to download history instead.
+    pub async fn restore_persisted_downloads(&self) {
+        let persistence = match &self.persistence {
+            Some(p) => p.clone(),
+            None => return,
+        };
+
+        let restored_entries = match tokio::task::spawn_blocking(move || {
+            let entries = persistence.load_all();
+            let mut restored = Vec::new();
+
+            for entry in entries {
+                let all_filenames = if entry.filenames.is_empty() {
+                    vec![entry.filename.clone()]
+                } else {
+                    entry.filenames.clone()
+                };
+
+                let has_any_file = all_filenames.iter().any(|f| {
+                    let part = entry.dest_dir.join(format!(
+                        "{}{}",
+                        f,
+                        crate::config::NetworkConfig::DOWNLOAD_TEMP_SUFFIX
+                    ));
+                    let completed = entry.dest_dir.join(f);
+                    part.exists() || completed.exists()
+                });
+
+                if !has_any_file {
+                    info!(
+                        "Removing stale persisted download {} (no files on disk)",
+                        entry.download_id
+                    );
+                    let _ = persistence.remove(&entry.download_id);
+                    continue;
+                }
+
+                let downloaded_bytes: u64 = all_filenames
+                    .iter()
+                    .map(|f| {
+                        let completed = entry.dest_dir.join(f);
+                        let part = entry.dest_dir.join(format!(
+                            "{}{}",
+                            f,
+                            crate::config::NetworkConfig::DOWNLOAD_TEMP_SUFFIX
+                        ));
+                        if completed.exists() {
+                            std::fs::metadata(&completed).map(|m| m.len()).unwrap_or(0)
+                        } else if part.exists() {
+                            std::fs::metadata(&part).map(|m| m.len()).unwrap_or(0)
+                        } else {
+                            0
+                        }
+                    })
+                    .sum();
+
+                if matches!(
+                    entry.status,
+                    DownloadStatus::Completed | DownloadStatus::Cancelled
+                ) {
+                    info!(
+                        "Moving finished download {} ({:?}) to history",
+                        entry.download_id, entry.status
+                    );
+                    if let Err(e) = persistence.record_finished(
+                        &entry.download_id,
+                        entry.status,
+                        downloaded_bytes,
+                        None,
+                    ) {
+                        warn!(
+                            "Failed to record history for download {}: {}",
+                            entry.download_id, e
+                        );
+                    }
+                    let _ = persistence.remove(&entry.download_id);
+                    continue;
+                }
+
+                restored.push((entry, downloaded_bytes));
+            }
+
+            restored
+        })
         .await
         {
             Ok(entries) => entries,
@@ -457,6 +1339,15 @@ impl HuggingFaceClient {
         let mut downloads = self.downloads.write().await;
 
         for (entry, downloaded_bytes) in restored_entries {
+            // Keep live state for IDs that are already tracked (restore called twice).
+            if downloads.contains_key(&entry.download_id) {
+                debug!(
+                    "Download {} is already tracked, not restoring over it",
+                    entry.download_id
+                );
+                continue;
+            }
+
             // Log status transitions for visibility
             match entry.status {
                 DownloadStatus::Queued | DownloadStatus::Downloading => {
@@ -481,6 +1372,27 @@ impl HuggingFaceClient {
         self.publish_download_snapshot().await;
     }
 
+    /// Generate an ID that is not already tracked.
+    ///
+    /// A deterministic generator may repeat IDs that were restored from
+    /// persistence, so collisions are skipped rather than overwriting a
+    /// restored download. Falls back to a UUID if the generator keeps
+    /// colliding.
+    pub(super) async fn next_download_id(&self) -> String {
+        const MAX_GENERATOR_ATTEMPTS: usize = 16;
+
+        let downloads = self.downloads.read().await;
+        for _ in 0..MAX_GENERATOR_ATTEMPTS {
+            let id = (self.download_id_generator)();
+            if !downloads.contains_key(&id) {
+                return id;
+            }
+            debug!("Skipping download ID {} already in use", id);
+        }
+        warn!("Download ID generator kept returning tracked IDs, using a UUID");
+        uuid::Uuid::new_v4().to_string()
+    }
+
     /// Start a model download (supports multi-file models).
     ///
     /// Returns a download ID for tracking progress.
@@ -494,7 +1406,7 @@ impl HuggingFaceClient {
     ) -> Result<String> {
         self.prune_finished_download_tasks();
 
-        let download_id = uuid::Uuid::new_v4().to_string();
+        let download_id = self.next_download_id().await;
         let cancel_flag = Arc::new(AtomicBool::new(false));
 
         // Get file info
@@ -574,6 +1486,20 @@ impl HuggingFaceClient {
                     .collect()
             };
 
+        let filter = DownloadFileFilter::from_request(request);
+        let mut skipped_files = Vec::new();
+        let files =
+            DownloadFileFilter::retain(files, |name| filter.keeps_weight(name), &mut skipped_files);
+        if files.is_empty() {
+            return Err(PumasError::Validation {
+                field: "include_patterns".to_string(),
+                message: format!(
+                    "include/exclude patterns leave no weight files to download from {}",
+                    request.repo_id
+                ),
+            });
+        }
+
         // SHA256 of the primary (largest) weight file for import metadata
         // (must be computed before auxiliary files are appended)
         let primary_file = files.iter().max_by_key(|f| f.size.unwrap_or(0));
@@ -584,7 +1510,7 @@ impl HuggingFaceClient {
         // auxiliary selection that includes non-weight LFS files and
         // directory-scoped configs.  Otherwise fall back to the basic
         // pattern-only selection.
-        let mut aux_files = if request.filenames.is_some() {
+        let aux_files = if request.filenames.is_some() {
             select_auxiliary_files_for_download(&tree.regular_files, &tree.lfs_files, &files)
         } else {
             let auxiliary = select_auxiliary_files(&tree.regular_files);
@@ -597,6 +1523,19 @@ impl HuggingFaceClient {
                 })
                 .collect()
         };
+        let mut aux_files = DownloadFileFilter::retain(
+            aux_files,
+            |name| filter.keeps_auxiliary(name),
+            &mut skipped_files,
+        );
+        if !skipped_files.is_empty() {
+            info!(
+                "Skipping {} file(s) of {} filtered by download patterns: {}",
+                skipped_files.len(),
+                request.repo_id,
+                skipped_files.join(", ")
+            );
+        }
         if !aux_files.is_empty() {
             info!(
                 "Including {} auxiliary file(s) for {}",
@@ -686,6 +1625,7 @@ impl HuggingFaceClient {
         }
 
         let pause_flag = Arc::new(AtomicBool::new(false));
+        let queue_ticket = self.download_queue.enqueue(&download_id, request.priority);
 
         // Create download state
         let state = DownloadState {
@@ -703,6 +1643,9 @@ impl HuggingFaceClient {
             retry_limit: None,
             retrying: false,
             next_retry_delay_seconds: None,
+            queue_position: queue_ticket.blocked_position().map(|p| p as u32),
+            stalled: false,
+            skipped_files,
             task_registered: false,
             dest_dir: dest_dir.to_path_buf(),
             filename: first_filename.clone(),
@@ -788,21 +1731,153 @@ impl HuggingFaceClient {
             if files.len() == 1 { "" } else { "s" }
         );
 
-        // Spawn download task (uses download_client which has no total timeout)
+        self.spawn_download_task(
+            &download_id,
+            request.repo_id.clone(),
+            files,
+            dest_dir.to_path_buf(),
+            cancel_flag,
+            pause_flag,
+            queue_ticket,
+        )
+        .await;
+
+        Ok(download_id)
+    }
+
+    /// Mirror every file of a repository into `dest_dir`, keeping the repo's
+    /// directory structure.
+    ///
+    /// Unlike [`Self::start_download`], nothing is selected, placed in the
+    /// library layout, or imported: the aux-files and completion callbacks
+    /// never fire, and no `.pumas_download` marker is written. The mirror is
+    /// tracked like any other download, so it reports progress, waits in the
+    /// download queue, and can be paused, resumed, or cancelled. Mirrors are
+    /// not persisted and do not survive a restart. Files already present in
+    /// `dest_dir` are skipped. Returns the download ID.
+    pub async fn clone_repo(&self, repo_id: &str, dest_dir: &Path) -> Result<String> {
+        self.prune_finished_download_tasks();
+
+        let tree = self.get_repo_files(repo_id).await?;
+        let files = repo_mirror_files(&tree);
+        if files.is_empty() {
+            return Err(PumasError::ModelNotFound {
+                model_id: repo_id.to_string(),
+            });
+        }
+        let known_sum: u64 = files.iter().filter_map(|f| f.size).sum();
+        let total_bytes = (known_sum > 0).then_some(known_sum);
+
+        let download_id = self.next_download_id().await;
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let pause_flag = Arc::new(AtomicBool::new(false));
+        let queue_ticket = self
+            .download_queue
+            .enqueue(&download_id, DownloadPriority::Normal);
+
+        // Without a download request, `run_download` has nothing to hand to
+        // the import callbacks, so the mirror is never imported.
+        let state = DownloadState {
+            download_id: download_id.clone(),
+            repo_id: repo_id.to_string(),
+            status: DownloadStatus::Queued,
+            progress: 0.0,
+            downloaded_bytes: 0,
+            total_bytes,
+            speed: 0.0,
+            cancel_flag: cancel_flag.clone(),
+            pause_flag: pause_flag.clone(),
+            error: None,
+            retry_attempt: 0,
+            retry_limit: None,
+            retrying: false,
+            next_retry_delay_seconds: None,
+            queue_position: queue_ticket.blocked_position().map(|p| p as u32),
+            stalled: false,
+            skipped_files: Vec::new(),
+            task_registered: false,
+            dest_dir: dest_dir.to_path_buf(),
+            filename: files[0].filename.clone(),
+            files: files.clone(),
+            files_completed: 0,
+            download_request: None,
+            known_sha256: None,
+            huggingface_evidence: None,
+        };
+        self.downloads
+            .write()
+            .await
+            .insert(download_id.clone(), state);
+        self.publish_download_snapshot().await;
+
+        tokio::fs::create_dir_all(dest_dir).await?;
+
+        info!(
+            "Cloning repository {} into {} as download {} ({} files)",
+            repo_id,
+            dest_dir.display(),
+            download_id,
+            files.len()
+        );
+
+        self.spawn_download_task(
+            &download_id,
+            repo_id.to_string(),
+            files,
+            dest_dir.to_path_buf(),
+            cancel_flag,
+            pause_flag,
+            queue_ticket,
+        )
+        .await;
+
+        Ok(download_id)
+    }
+
+    /// Spawn the task that waits for a queue slot and then runs a download.
+    ///
+    /// Uses `download_client`, which has no total timeout. A failed download is
+    /// marked `Error` in memory and in persistence so it can be resumed.
+    #[allow(clippy::too_many_arguments)]
+    async fn spawn_download_task(
+        &self,
+        download_id: &str,
+        repo_id: String,
+        files: Vec<FileToDownload>,
+        dest_dir: PathBuf,
+        cancel_flag: Arc<AtomicBool>,
+        pause_flag: Arc<AtomicBool>,
+        queue_ticket: QueueTicket,
+    ) {
         let client = self.download_client.clone();
         let downloads = self.downloads.clone();
         let download_revision = self.download_revision.clone();
         let download_updates = self.download_updates.clone();
-        let download_id_clone = download_id.clone();
-        let repo_id = request.repo_id.clone();
-        let dest_dir = dest_dir.to_path_buf();
+        let download_id_clone = download_id.to_string();
         let persistence = self.persistence.clone();
         let completion_callback = self.completion_callback.clone();
         let aux_complete_callback = self.aux_complete_callback.clone();
         let auth_header = self.auth_header_value().await;
+        let rate_limiter = self.rate_limiter.clone();
+        let shared_rate_limiter = self.shared_rate_limiter.clone();
+        let stall_timeout = self.stall_timeout();
+        let counters = self.download_counters.clone();
         let dest_lock = self.destination_lock(&dest_dir).await;
 
         let task_handle = tokio::spawn(async move {
+            let Some(_slot) = Self::wait_for_download_slot(
+                queue_ticket,
+                &downloads,
+                &download_revision,
+                &download_updates,
+                persistence.as_ref(),
+                &download_id_clone,
+                &pause_flag,
+            )
+            .await
+            else {
+                return;
+            };
             // Serialize downloads targeting the same destination directory.
             let _destination_guard = dest_lock.lock().await;
 
@@ -821,9 +1896,22 @@ impl HuggingFaceClient {
                 completion_callback,
                 aux_complete_callback,
                 auth_header,
+                rate_limiter,
+                shared_rate_limiter,
+                counters.clone(),
+                stall_timeout,
             )
             .await;
 
+            match &result {
+                Ok(()) => {
+                    counters.completed.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(PumasError::DownloadPaused | PumasError::DownloadCancelled) => {}
+                Err(_) => {
+                    counters.failed.fetch_add(1, Ordering::Relaxed);
+                }
+            }
             if let Err(e) = result {
                 // DownloadPaused is not a real error -- status already set by run_download
                 if matches!(e, PumasError::DownloadPaused) {
@@ -833,10 +1921,14 @@ impl HuggingFaceClient {
                 }
                 error!("Download failed for {}: {}", repo_id, e);
                 let mut download_states = downloads.write().await;
-                if let Some(state) = download_states.get_mut(&download_id_clone) {
-                    state.status = DownloadStatus::Error;
-                    state.error = Some(e.to_string());
-                }
+                let downloaded_bytes = download_states
+                    .get_mut(&download_id_clone)
+                    .map(|state| {
+                        state.status = DownloadStatus::Error;
+                        state.error = Some(e.to_string());
+                        state.downloaded_bytes
+                    })
+                    .unwrap_or(0);
                 drop(download_states);
                 publish_download_snapshot_from_parts(
                     &downloads,
@@ -846,6 +1938,17 @@ impl HuggingFaceClient {
                 .await;
                 // Update persistence with error status (preserve for resume)
                 if let Some(ref persistence) = persistence {
+                    // Cancellation records its own history entry.
+                    if !matches!(e, PumasError::DownloadCancelled) {
+                        Self::record_download_history(
+                            persistence.clone(),
+                            download_id_clone.clone(),
+                            DownloadStatus::Error,
+                            downloaded_bytes,
+                            Some(e.to_string()),
+                        )
+                        .await;
+                    }
                     Self::persist_status_update(
                         persistence.clone(),
                         download_id_clone.clone(),
@@ -855,10 +1958,75 @@ impl HuggingFaceClient {
                 }
             }
         });
-        self.store_download_task(download_id.clone(), task_handle);
-        self.mark_download_task_registered(&download_id).await;
+        self.store_download_task(download_id.to_string(), task_handle);
+        self.mark_download_task_registered(download_id).await;
+    }
 
-        Ok(download_id)
+    /// Wait in the download queue until a slot is free for this download.
+    ///
+    /// Keeps the download's `queue_position` current while it waits. Returns
+    /// `None` if the download is paused before it starts, after marking it
+    /// `Paused` in memory and in persistence.
+    async fn wait_for_download_slot(
+        mut ticket: QueueTicket,
+        downloads: &Arc<RwLock<HashMap<String, DownloadState>>>,
+        download_revision: &Arc<AtomicU64>,
+        download_updates: &broadcast::Sender<crate::models::ModelDownloadUpdateNotification>,
+        persistence: Option<&Arc<DownloadPersistence>>,
+        download_id: &str,
+        pause_flag: &AtomicBool,
+    ) -> Option<DownloadSlot> {
+        loop {
+            if pause_flag.load(Ordering::Relaxed) {
+                if let Some(state) = downloads.write().await.get_mut(download_id) {
+                    state.status = DownloadStatus::Paused;
+                    state.queue_position = None;
+                    state.speed = 0.0;
+                }
+                publish_download_snapshot_from_parts(
+                    downloads,
+                    download_revision,
+                    download_updates,
+                )
+                .await;
+                if let Some(persistence) = persistence {
+                    Self::persist_status_update(
+                        persistence.clone(),
+                        download_id.to_string(),
+                        DownloadStatus::Paused,
+                    )
+                    .await;
+                }
+                info!("Download {} paused while queued", download_id);
+                return None;
+            }
+
+            let position = match ticket.try_start() {
+                Ok(slot) => {
+                    if let Some(state) = downloads.write().await.get_mut(download_id) {
+                        state.queue_position = None;
+                    }
+                    return Some(slot);
+                }
+                Err(position) => Some(position as u32),
+            };
+            let moved = match downloads.write().await.get_mut(download_id) {
+                Some(state) if state.queue_position != position => {
+                    state.queue_position = position;
+                    true
+                }
+                _ => false,
+            };
+            if moved {
+                publish_download_snapshot_from_parts(
+                    downloads,
+                    download_revision,
+                    download_updates,
+                )
+                .await;
+            }
+            ticket.changed().await;
+        }
     }
 
     async fn destination_lock(&self, dest_dir: &Path) -> Arc<tokio::sync::Mutex<()>> {
@@ -887,6 +2055,31 @@ impl HuggingFaceClient {
         }
     }
 
+    /// Check a finished `.part` file against its HuggingFace LFS SHA256.
+    ///
+    /// A mismatching file is deleted so a resumed download fetches it again
+    /// from scratch instead of appending to corrupt data.
+    async fn verify_downloaded_file(part_path: &Path, expected_sha256: &str) -> Result<()> {
+        let path = part_path.to_path_buf();
+        let expected = expected_sha256.to_string();
+        let result = tokio::task::spawn_blocking(move || verify_sha256(&path, &expected))
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join hash verification task: {}", e))
+            })?;
+
+        if matches!(result, Err(PumasError::HashMismatch { .. })) {
+            if let Err(err) = tokio::fs::remove_file(part_path).await {
+                warn!(
+                    "Failed to remove corrupt download {}: {}",
+                    part_path.display(),
+                    err
+                );
+            }
+        }
+        result
+    }
+
     async fn remove_download_marker(dest_dir: &Path) {
         let marker_path = dest_dir.join(".pumas_download");
         if let Err(err) = tokio::fs::remove_file(&marker_path).await {
@@ -902,8 +2095,9 @@ impl HuggingFaceClient {
 
     /// Run the download in the background with retry and resume support.
     ///
-    /// Downloads all files sequentially. Files that already exist on disk
-    /// (from a previous partial download) are skipped automatically.
+    /// Auxiliary files download first, then weight files with up to
+    /// `DownloadRequest::max_concurrent_files` in flight. Files that already
+    /// exist on disk (from a previous partial download) are skipped.
     #[allow(clippy::too_many_arguments)]
     async fn run_download(
         client: reqwest::Client,
@@ -914,390 +2108,167 @@ impl HuggingFaceClient {
         repo_id: &str,
         files: &[FileToDownload],
         dest_dir: &Path,
-        cancel_flag: Arc<AtomicBool>,
-        pause_flag: Arc<AtomicBool>,
-        persistence: Option<Arc<DownloadPersistence>>,
-        completion_callback: Option<DownloadCompletionCallback>,
-        aux_complete_callback: Option<AuxFilesCompleteCallback>,
-        auth_header: Option<String>,
-    ) -> Result<()> {
-        use crate::config::NetworkConfig;
-        use crate::network::RetryConfig;
-
-        // Update status to downloading
-        {
-            let mut downloads = downloads.write().await;
-            if let Some(state) = downloads.get_mut(download_id) {
-                state.status = DownloadStatus::Downloading;
-            }
-        }
-        publish_download_snapshot_from_parts(&downloads, &download_revision, &download_updates)
-            .await;
-
-        tokio::fs::create_dir_all(dest_dir).await?;
-
-        let max_attempts = NetworkConfig::hf_download_max_retries();
-        let retry_limit = retry_limit(max_attempts);
-        let max_retry_elapsed = NetworkConfig::hf_download_max_retry_elapsed();
-        let retry_config = RetryConfig::new()
-            .with_max_attempts(max_attempts.max(1))
-            .with_base_delay(NetworkConfig::HF_DOWNLOAD_RETRY_BASE_DELAY);
-
-        // Download each file sequentially
-        let mut bytes_offset: u64 = 0;
-        let mut aux_callback_fired = false;
-
-        for (file_idx, file_info) in files.iter().enumerate() {
-            let filename = &file_info.filename;
-            let dest_path = dest_dir.join(filename);
-            let part_path = dest_dir.join(format!(
-                "{}{}",
-                filename,
-                NetworkConfig::DOWNLOAD_TEMP_SUFFIX
-            ));
-
-            // Ensure parent directory exists (needed for subdirectory files
-            // like transformer/model.safetensors in diffusion repos)
-            if let Some(parent) = dest_path.parent() {
-                tokio::fs::create_dir_all(parent).await?;
-            }
-
-            // Skip files that already exist (completed from previous run)
-            if tokio::fs::try_exists(&dest_path).await.unwrap_or(false) {
-                Self::remove_stale_part_for_completed_file(&dest_path, &part_path).await;
-                let existing_size = tokio::fs::metadata(&dest_path)
-                    .await
-                    .map(|m| m.len())
-                    .unwrap_or(0);
-                bytes_offset += existing_size;
-                info!(
-                    "Skipping already-downloaded file {}/{} ({} bytes)",
-                    repo_id, filename, existing_size
-                );
-
-                // Update state
-                {
-                    let mut downloads = downloads.write().await;
-                    if let Some(state) = downloads.get_mut(download_id) {
-                        state.files_completed = file_idx + 1;
-                        state.downloaded_bytes = bytes_offset;
-                        if let Some(total) = state.total_bytes {
-                            state.progress = bytes_offset as f32 / total as f32;
-                        }
-                    }
-                }
-                publish_download_snapshot_from_parts(
-                    &downloads,
-                    &download_revision,
-                    &download_updates,
-                )
-                .await;
-                continue;
-            }
-
-            // Fire aux-complete callback at the boundary between auxiliary and weight files.
-            // Auxiliary files have size: None (non-LFS), weight files have size: Some (LFS).
-            if !aux_callback_fired && file_info.size.is_some() {
-                aux_callback_fired = true;
-                if let Some(ref callback) = aux_complete_callback {
-                    let info = {
-                        let downloads = downloads.read().await;
-                        downloads.get(download_id).and_then(|state| {
-                            state
-                                .download_request
-                                .as_ref()
-                                .map(|req| AuxFilesCompleteInfo {
-                                    download_id: download_id.to_string(),
-                                    dest_dir: state.dest_dir.clone(),
-                                    filenames: files.iter().map(|f| f.filename.clone()).collect(),
-                                    download_request: req.clone(),
-                                    total_bytes: state.total_bytes,
-                                    huggingface_evidence: state.huggingface_evidence.clone(),
-                                })
-                        })
-                    };
-                    if let Some(info) = info {
-                        callback(info);
-                    }
-                }
-            }
-
-            // Update current filename in state
-            {
-                let mut downloads = downloads.write().await;
-                if let Some(state) = downloads.get_mut(download_id) {
-                    state.filename = filename.clone();
-                    state.retry_attempt = 0;
-                    state.retry_limit = retry_limit;
-                    state.retrying = false;
-                    state.next_retry_delay_seconds = None;
-                }
-            }
-            publish_download_snapshot_from_parts(&downloads, &download_revision, &download_updates)
-                .await;
-
-            let url = format!("{}/{}/resolve/main/{}", HF_HUB_BASE, repo_id, filename);
-
-            let mut last_error: Option<PumasError> = None;
-
-            let mut file_completed = false;
-            let mut attempt: u32 = 0;
-            let retry_started = Instant::now();
-            loop {
-                attempt += 1;
-                {
-                    let mut downloads = downloads.write().await;
-                    if let Some(state) = downloads.get_mut(download_id) {
-                        state.retry_attempt = attempt;
-                        state.retry_limit = retry_limit;
-                        state.retrying = false;
-                        state.next_retry_delay_seconds = None;
-                    }
-                }
-
-                // Check cancellation before each attempt
-                if cancel_flag.load(Ordering::Relaxed) {
-                    let _ = tokio::fs::remove_file(&part_path).await;
-                    let mut download_states = downloads.write().await;
-                    if let Some(state) = download_states.get_mut(download_id) {
-                        state.status = DownloadStatus::Cancelled;
-                    }
-                    drop(download_states);
-                    publish_download_snapshot_from_parts(
-                        &downloads,
-                        &download_revision,
-                        &download_updates,
-                    )
-                    .await;
-                    if let Some(ref persistence) = persistence {
-                        Self::remove_persisted_download(
-                            persistence.clone(),
-                            download_id.to_string(),
-                        )
-                        .await;
-                    }
-                    return Err(PumasError::DownloadCancelled);
-                }
-
-                // Check pause before each attempt
-                if pause_flag.load(Ordering::Relaxed) {
-                    let mut download_states = downloads.write().await;
-                    if let Some(state) = download_states.get_mut(download_id) {
-                        state.status = DownloadStatus::Paused;
-                    }
-                    drop(download_states);
-                    publish_download_snapshot_from_parts(
-                        &downloads,
-                        &download_revision,
-                        &download_updates,
-                    )
-                    .await;
-                    if let Some(ref persistence) = persistence {
-                        Self::persist_status_update(
-                            persistence.clone(),
-                            download_id.to_string(),
-                            DownloadStatus::Paused,
-                        )
-                        .await;
-                    }
-                    return Err(PumasError::DownloadPaused);
-                }
-
-                // Determine resume offset from existing .part file
-                let resume_from_byte = tokio::fs::metadata(&part_path)
-                    .await
-                    .map(|m| m.len())
-                    .unwrap_or(0);
-
-                if attempt > 1 {
-                    warn!(
-                        "Retry {}/{} for {}/{} (resuming from byte {})",
-                        attempt,
-                        retry_limit_display(retry_limit),
-                        repo_id,
-                        filename,
-                        resume_from_byte
-                    );
+        cancel_flag: Arc<AtomicBool>,
+        pause_flag: Arc<AtomicBool>,
+        persistence: Option<Arc<DownloadPersistence>>,
+        completion_callback: Option<DownloadCompletionCallback>,
+        aux_complete_callback: Option<AuxFilesCompleteCallback>,
+        auth_header: Option<String>,
+        rate_limiter: Arc<BandwidthLimiter>,
+        shared_rate_limiter: Arc<BandwidthLimiter>,
+        counters: Arc<DownloadSessionCounters>,
+        stall_timeout: Duration,
+    ) -> Result<()> {
+        use crate::config::NetworkConfig;
 
-                    // Reset status to Downloading for the retry
-                    let mut download_states = downloads.write().await;
-                    if let Some(state) = download_states.get_mut(download_id) {
-                        state.status = DownloadStatus::Downloading;
-                        state.error = None;
-                        state.retry_attempt = attempt;
-                        state.retry_limit = retry_limit;
-                        state.retrying = false;
-                        state.next_retry_delay_seconds = None;
-                    }
-                    drop(download_states);
-                    publish_download_snapshot_from_parts(
-                        &downloads,
-                        &download_revision,
-                        &download_updates,
+        // Update status to downloading
+        let (verify_hashes, max_concurrent_files, rate_limit) = {
+            let mut downloads = downloads.write().await;
+            match downloads.get_mut(download_id) {
+                Some(state) => {
+                    state.status = DownloadStatus::Downloading;
+                    state.stalled = false;
+                    state.files_completed = 0;
+                    let request = state.download_request.as_ref();
+                    (
+                        request.is_none_or(|req| !req.skip_hash_verification),
+                        concurrent_file_limit(request),
+                        request.and_then(|req| req.rate_limit),
                     )
-                    .await;
                 }
+                None => (true, 1, None),
+            }
+        };
+        publish_download_snapshot_from_parts(&downloads, &download_revision, &download_updates)
+            .await;
 
-                match Self::download_attempt(
-                    &client,
-                    &downloads,
-                    &download_revision,
-                    &download_updates,
-                    download_id,
-                    &url,
-                    &part_path,
-                    file_info.size,
-                    resume_from_byte,
-                    bytes_offset,
-                    &cancel_flag,
-                    &pause_flag,
-                    auth_header.as_deref(),
-                )
-                .await
-                {
-                    Ok(_) => {
-                        // Rename .part to final path atomically
-                        tokio::fs::rename(&part_path, &dest_path)
-                            .await
-                            .map_err(|e| PumasError::DownloadFailed {
-                                url: url.clone(),
-                                message: format!("Failed to rename temp file: {}", e),
-                            })?;
-
-                        file_completed = true;
-                        break;
-                    }
-                    Err(e) => {
-                        // Paused -- .part preserved, not a real error
-                        if matches!(e, PumasError::DownloadPaused) {
-                            if let Some(ref persistence) = persistence {
-                                Self::persist_status_update(
-                                    persistence.clone(),
-                                    download_id.to_string(),
-                                    DownloadStatus::Paused,
-                                )
-                                .await;
-                            }
-                            return Err(e);
-                        }
+        tokio::fs::create_dir_all(dest_dir).await?;
 
-                        if !e.is_retryable() || cancel_flag.load(Ordering::Relaxed) {
-                            if cancel_flag.load(Ordering::Relaxed) {
-                                let _ = tokio::fs::remove_file(&part_path).await;
-                                if let Some(ref persistence) = persistence {
-                                    Self::remove_persisted_download(
-                                        persistence.clone(),
-                                        download_id.to_string(),
-                                    )
-                                    .await;
-                                }
-                            }
-                            return Err(e);
-                        }
+        let max_attempts = NetworkConfig::hf_download_max_retries();
+        let retry_limit = retry_limit(max_attempts);
+        let max_retry_elapsed = NetworkConfig::hf_download_max_retry_elapsed();
+        let retry_config = RetryConfig::new()
+            .with_max_attempts(max_attempts.max(1))
+            .with_base_delay(NetworkConfig::HF_DOWNLOAD_RETRY_BASE_DELAY);
 
-                        warn!(
-                            "Download attempt {}/{} failed for {}/{}: {}",
-                            attempt,
-                            retry_limit_display(retry_limit),
-                            repo_id,
-                            filename,
-                            e
-                        );
-                        let error_text = e.to_string();
-                        last_error = Some(e);
+        // A per-request cap replaces the client-wide one for this download.
+        let request_limiter = rate_limit.map(|limit| BandwidthLimiter::new(Some(limit)));
+        let progress = DownloadByteProgress::new(files.len());
+        let transfer = FileTransfer {
+            client: &client,
+            downloads: &downloads,
+            download_revision: &download_revision,
+            download_updates: &download_updates,
+            download_id,
+            repo_id,
+            dest_dir,
+            cancel_flag: &cancel_flag,
+            pause_flag: &pause_flag,
+            persistence: persistence.as_ref(),
+            auth_header: auth_header.as_deref(),
+            verify_hashes,
+            retry_limit,
+            max_retry_elapsed,
+            retry_config: &retry_config,
+            progress: &progress,
+            rate_limiter: request_limiter.as_ref().unwrap_or(&rate_limiter),
+            shared_rate_limiter: &shared_rate_limiter,
+            counters: &counters,
+            stall_timeout,
+        };
 
-                        let elapsed = retry_started.elapsed();
-                        if retry_exhausted(attempt, retry_limit, elapsed, max_retry_elapsed) {
-                            break;
-                        }
+        // Auxiliary files (no LFS size) are listed first and download one at
+        // a time. Weight files start at the first file with a known size.
+        let weights_start = files
+            .iter()
+            .position(|f| f.size.is_some())
+            .unwrap_or(files.len());
+        for (file_idx, file_info) in files.iter().enumerate().take(weights_start) {
+            transfer.download_file(file_idx, file_info).await?;
+        }
 
-                        let delay = retry_config.calculate_delay(attempt.saturating_sub(1));
-                        let limit_text = retry_limit_display(retry_limit);
-                        let next_attempt = attempt + 1;
-                        {
-                            let mut downloads = downloads.write().await;
-                            if let Some(state) = downloads.get_mut(download_id) {
-                                state.retry_attempt = attempt;
-                                state.retry_limit = retry_limit;
-                                state.retrying = true;
-                                state.next_retry_delay_seconds = Some(delay.as_secs_f64());
-                                state.error = Some(format!(
-                                    "Transient network error, retrying attempt {}/{} in {:.1}s: {}",
-                                    next_attempt,
-                                    limit_text,
-                                    delay.as_secs_f64(),
-                                    error_text
-                                ));
-                            }
-                        }
-                        publish_download_snapshot_from_parts(
-                            &downloads,
-                            &download_revision,
-                            &download_updates,
-                        )
-                        .await;
-                        debug!("Waiting {:?} before retry", delay);
-                        tokio::time::sleep(delay).await;
-                    }
-                }
+        // Fire aux-complete callback at the boundary between auxiliary and
+        // weight files, unless every weight file is already on disk.
+        let mut weights_pending = false;
+        for file_info in &files[weights_start..] {
+            if !tokio::fs::try_exists(dest_dir.join(&file_info.filename))
+                .await
+                .unwrap_or(false)
+            {
+                weights_pending = true;
+                break;
             }
-
-            if !file_completed {
-                let elapsed = retry_started.elapsed();
-                if let Some(last_error) = last_error {
-                    let detail = retry_exhausted_message(
-                        attempt,
-                        retry_limit,
-                        elapsed,
-                        &last_error.to_string(),
-                    );
-                    return Err(PumasError::DownloadFailed {
-                        url,
-                        message: detail,
-                    });
+        }
+        if weights_pending {
+            if let Some(ref callback) = aux_complete_callback {
+                let info = {
+                    let downloads = downloads.read().await;
+                    downloads.get(download_id).and_then(|state| {
+                        state
+                            .download_request
+                            .as_ref()
+                            .map(|req| AuxFilesCompleteInfo {
+                                download_id: download_id.to_string(),
+                                dest_dir: state.dest_dir.clone(),
+                                filenames: files.iter().map(|f| f.filename.clone()).collect(),
+                                download_request: req.clone(),
+                                total_bytes: state.total_bytes,
+                                huggingface_evidence: state.huggingface_evidence.clone(),
+                            })
+                    })
+                };
+                if let Some(info) = info {
+                    callback(info);
                 }
-                return Err(PumasError::DownloadFailed {
-                    url,
-                    message: "Download stopped before completion".to_string(),
-                });
             }
+        }
 
-            // File completed -- use actual file size for accurate offset
-            let actual_size = tokio::fs::metadata(&dest_path)
-                .await
-                .map(|m| m.len())
-                .unwrap_or(file_info.size.unwrap_or(0));
-            bytes_offset += actual_size;
-            {
-                let mut downloads = downloads.write().await;
-                if let Some(state) = downloads.get_mut(download_id) {
-                    state.files_completed = file_idx + 1;
-                    state.downloaded_bytes = bytes_offset;
-                    state.retry_attempt = 0;
-                    state.retrying = false;
-                    state.next_retry_delay_seconds = None;
-                    state.error = None;
+        // Weight files share a semaphore so up to `max_concurrent_files`
+        // transfer at once. Permits are granted in request order, so the
+        // default of one keeps the sequential file order.
+        let semaphore = Semaphore::new(max_concurrent_files);
+        let mut transfers: FuturesUnordered<_> = files
+            .iter()
+            .enumerate()
+            .skip(weights_start)
+            .map(|(file_idx, file_info)| {
+                let (transfer, semaphore) = (&transfer, &semaphore);
+                async move {
+                    let _permit = semaphore.acquire().await.map_err(|e| {
+                        PumasError::Other(format!("Download file semaphore closed: {}", e))
+                    })?;
+                    transfer.download_file(file_idx, file_info).await
                 }
-            }
-            publish_download_snapshot_from_parts(&downloads, &download_revision, &download_updates)
-                .await;
+            })
+            .collect();
 
-            info!(
-                "File {}/{} complete ({}/{})",
-                repo_id,
-                filename,
-                file_idx + 1,
-                files.len()
-            );
+        let mut failure = None;
+        while let Some(result) = transfers.next().await {
+            if let Err(err) = result {
+                failure = Some(err);
+                break;
+            }
+        }
+        // Dropping the remaining transfers stops files still in flight. Their
+        // `.part` files keep the bytes already written, so resume continues
+        // each file from where it stopped.
+        drop(transfers);
+        if let Some(err) = failure {
+            if matches!(err, PumasError::DownloadCancelled) {
+                transfer.remove_part_files(files).await;
+            }
+            return Err(err);
         }
 
         // All files completed -- update status and fire callback
+        let mut downloaded_bytes = 0;
         let completion_info = {
             let mut downloads = downloads.write().await;
             if let Some(state) = downloads.get_mut(download_id) {
                 state.status = DownloadStatus::Completed;
                 state.progress = 1.0;
                 state.files_completed = files.len();
+                downloaded_bytes = state.downloaded_bytes;
 
                 state.download_request.as_ref().map(|req| {
                     // Use the primary (largest) filename for the completion info
@@ -1329,187 +2300,22 @@ impl HuggingFaceClient {
             callback(info);
         }
 
-        // Remove from persistence -- download is done
+        // Move from persistence to history -- download is done
         if let Some(ref persistence) = persistence {
-            Self::remove_persisted_download(persistence.clone(), download_id.to_string()).await;
+            Self::finish_persisted_download(
+                persistence.clone(),
+                download_id.to_string(),
+                DownloadStatus::Completed,
+                downloaded_bytes,
+                None,
+            )
+            .await;
         }
         Self::remove_download_marker(dest_dir).await;
 
         Ok(())
     }
 
-    /// Execute a single download attempt, optionally resuming from a byte offset.
-    ///
-    /// `file_size_expected` is the expected size of this individual file.
-    /// `bytes_offset` is bytes already downloaded from previous files in a multi-file download.
-    /// Overall progress is calculated as `(bytes_offset + file_downloaded) / overall_total`.
-    #[allow(clippy::too_many_arguments)]
-    async fn download_attempt(
-        client: &reqwest::Client,
-        downloads: &Arc<RwLock<HashMap<String, DownloadState>>>,
-        download_revision: &Arc<AtomicU64>,
-        download_updates: &broadcast::Sender<crate::models::ModelDownloadUpdateNotification>,
-        download_id: &str,
-        url: &str,
-        part_path: &Path,
-        file_size_expected: Option<u64>,
-        resume_from_byte: u64,
-        bytes_offset: u64,
-        cancel_flag: &Arc<AtomicBool>,
-        pause_flag: &Arc<AtomicBool>,
-        auth_header: Option<&str>,
-    ) -> Result<()> {
-        use futures::StreamExt;
-
-        let mut request = client.get(url);
-        if let Some(auth) = auth_header {
-            request = request.header("Authorization", auth);
-        }
-        if resume_from_byte > 0 {
-            request = request.header("Range", format!("bytes={}-", resume_from_byte));
-            info!("Resuming download from byte {}", resume_from_byte);
-        }
-
-        let response = request.send().await.map_err(|e| PumasError::Network {
-            message: format!("Download request failed: {}", e),
-            cause: Some(e.to_string()),
-        })?;
-
-        let status = response.status();
-
-        // Check for non-success responses (but 206 Partial Content is expected for resume)
-        if !status.is_success() && status != reqwest::StatusCode::PARTIAL_CONTENT {
-            return Err(PumasError::DownloadFailed {
-                url: url.to_string(),
-                message: format!("HTTP {}", status),
-            });
-        }
-
-        // Determine if we're actually resuming
-        let is_resuming = resume_from_byte > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
-        if resume_from_byte > 0 && !is_resuming {
-            warn!("Server does not support Range requests, restarting from zero");
-        }
-
-        // Per-file total for completeness verification
-        let file_total = if is_resuming {
-            file_size_expected
-        } else {
-            response.content_length().or(file_size_expected)
-        };
-
-        // Open file: append for resume, create for fresh start
-        let mut file = if is_resuming {
-            tokio::fs::OpenOptions::new()
-                .append(true)
-                .open(part_path)
-                .await?
-        } else {
-            tokio::fs::File::create(part_path).await?
-        };
-
-        let mut downloaded: u64 = if is_resuming { resume_from_byte } else { 0 };
-        let mut stream = response.bytes_stream();
-        let start_time = std::time::Instant::now();
-        let mut last_publish = Instant::now();
-
-        while let Some(chunk) = stream.next().await {
-            if cancel_flag.load(Ordering::Relaxed) {
-                drop(file);
-                let _ = tokio::fs::remove_file(part_path).await;
-
-                let mut download_states = downloads.write().await;
-                if let Some(state) = download_states.get_mut(download_id) {
-                    state.status = DownloadStatus::Cancelled;
-                }
-                drop(download_states);
-                publish_download_snapshot_from_parts(
-                    downloads,
-                    download_revision,
-                    download_updates,
-                )
-                .await;
-
-                return Err(PumasError::DownloadCancelled);
-            }
-
-            if pause_flag.load(Ordering::Relaxed) {
-                file.flush().await?;
-                drop(file);
-                // Preserve .part file for resume
-
-                let mut download_states = downloads.write().await;
-                if let Some(state) = download_states.get_mut(download_id) {
-                    state.status = DownloadStatus::Paused;
-                }
-                drop(download_states);
-                publish_download_snapshot_from_parts(
-                    downloads,
-                    download_revision,
-                    download_updates,
-                )
-                .await;
-
-                return Err(PumasError::DownloadPaused);
-            }
-
-            let chunk = chunk.map_err(|e| PumasError::Network {
-                message: format!("Download stream error: {}", e),
-                cause: Some(e.to_string()),
-            })?;
-
-            file.write_all(&chunk).await?;
-            downloaded += chunk.len() as u64;
-
-            // Update overall progress (bytes_offset accounts for completed files)
-            let elapsed = start_time.elapsed().as_secs_f64();
-            let speed = if elapsed > 0.0 {
-                downloaded as f64 / elapsed
-            } else {
-                0.0
-            };
-
-            let overall_downloaded = bytes_offset + downloaded;
-
-            let mut download_states = downloads.write().await;
-            if let Some(state) = download_states.get_mut(download_id) {
-                state.downloaded_bytes = overall_downloaded;
-                state.speed = speed;
-                state.progress = if let Some(total) = state.total_bytes {
-                    overall_downloaded as f32 / total as f32
-                } else {
-                    0.0
-                };
-            }
-            drop(download_states);
-
-            if last_publish.elapsed() >= DOWNLOAD_PROGRESS_PUBLISH_INTERVAL {
-                publish_download_snapshot_from_parts(
-                    downloads,
-                    download_revision,
-                    download_updates,
-                )
-                .await;
-                last_publish = Instant::now();
-            }
-        }
-
-        file.flush().await?;
-        drop(file);
-
-        // Verify this file's download completeness
-        if let Some(total) = file_total {
-            if downloaded != total {
-                return Err(PumasError::Network {
-                    message: format!("Incomplete download: got {} of {} bytes", downloaded, total),
-                    cause: None,
-                });
-            }
-        }
-
-        Ok(())
-    }
-
     /// Helper: update status in persistence store (best-effort).
     async fn persist_status_update(
         persistence: Arc<DownloadPersistence>,
@@ -1526,18 +2332,54 @@ impl HuggingFaceClient {
         .await;
     }
 
-    async fn save_persisted_download(
+    async fn save_persisted_download(
+        persistence: Arc<DownloadPersistence>,
+        entry: PersistedDownload,
+    ) {
+        let _ = tokio::task::spawn_blocking(move || {
+            let _ = persistence.save(&entry);
+        })
+        .await;
+    }
+
+    /// Helper: record a download outcome in history (best-effort).
+    async fn record_download_history(
+        persistence: Arc<DownloadPersistence>,
+        download_id: String,
+        status: DownloadStatus,
+        downloaded_bytes: u64,
+        error: Option<String>,
+    ) {
+        let _ = tokio::task::spawn_blocking(move || {
+            if let Err(e) =
+                persistence.record_finished(&download_id, status, downloaded_bytes, error)
+            {
+                warn!(
+                    "Failed to record history for download {}: {}",
+                    download_id, e
+                );
+            }
+        })
+        .await;
+    }
+
+    /// Helper: record a terminal download in history, then drop its persisted entry.
+    async fn finish_persisted_download(
         persistence: Arc<DownloadPersistence>,
-        entry: PersistedDownload,
+        download_id: String,
+        status: DownloadStatus,
+        downloaded_bytes: u64,
+        error: Option<String>,
     ) {
         let _ = tokio::task::spawn_blocking(move || {
-            let _ = persistence.save(&entry);
-        })
-        .await;
-    }
-
-    async fn remove_persisted_download(persistence: Arc<DownloadPersistence>, download_id: String) {
-        let _ = tokio::task::spawn_blocking(move || {
+            if let Err(e) =
+                persistence.record_finished(&download_id, status, downloaded_bytes, error)
+            {
+                warn!(
+                    "Failed to record history for download {}: {}",
+                    download_id, e
+                );
+            }
             let _ = persistence.remove(&download_id);
         })
         .await;
@@ -1587,12 +2429,20 @@ impl HuggingFaceClient {
             state.cancel_flag.store(true, Ordering::Relaxed);
             state.status = DownloadStatus::Cancelling;
             state.speed = 0.0;
+            let downloaded_bytes = state.downloaded_bytes;
             drop(downloads);
             self.publish_download_snapshot().await;
             self.abort_download_task(download_id);
-            // Remove from persistence -- cancelled downloads don't survive restart
+            // Move to history -- cancelled downloads don't survive restart
             if let Some(ref persistence) = self.persistence {
-                Self::remove_persisted_download(persistence.clone(), download_id.to_string()).await;
+                Self::finish_persisted_download(
+                    persistence.clone(),
+                    download_id.to_string(),
+                    DownloadStatus::Cancelled,
+                    downloaded_bytes,
+                    None,
+                )
+                .await;
             }
             let mut downloads = self.downloads.write().await;
             if let Some(state) = downloads.get_mut(download_id) {
@@ -1606,6 +2456,36 @@ impl HuggingFaceClient {
         }
     }
 
+    /// Finished downloads (completed, cancelled, or errored), most recent first.
+    ///
+    /// Returns an empty list when no persistence store is configured.
+    pub async fn download_history(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DownloadHistoryEntry>> {
+        let Some(persistence) = self.persistence.clone() else {
+            return Ok(Vec::new());
+        };
+        tokio::task::spawn_blocking(move || persistence.load_history(limit, offset))
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join download history task: {err}"))
+            })
+    }
+
+    /// Delete all download history. Active and resumable downloads are kept.
+    pub async fn clear_download_history(&self) -> Result<()> {
+        let Some(persistence) = self.persistence.clone() else {
+            return Ok(());
+        };
+        tokio::task::spawn_blocking(move || persistence.clear_history())
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join download history task: {err}"))
+            })?
+    }
+
     /// List all downloads (active, paused, completed, etc.).
     pub async fn list_downloads(&self) -> Vec<ModelDownloadProgress> {
         self.reconcile_inactive_active_downloads().await;
@@ -1613,6 +2493,44 @@ impl HuggingFaceClient {
         downloads.values().map(progress_from_state).collect()
     }
 
+    /// Status counts, throughput, and session counters for the download
+    /// subsystem.
+    ///
+    /// Comparing throughput with the rate limit, and active downloads with
+    /// the queue limit, shows whether slow downloads are throttled, waiting
+    /// on concurrency, or limited by the network (retries and stalls).
+    pub async fn download_metrics(&self) -> crate::models::DownloadMetrics {
+        self.reconcile_inactive_active_downloads().await;
+        let mut metrics = crate::models::DownloadMetrics {
+            retries_this_session: self.download_counters.retries.load(Ordering::Relaxed),
+            stalls_this_session: self.download_counters.stalls.load(Ordering::Relaxed),
+            bytes_downloaded_this_session: self
+                .download_counters
+                .bytes_downloaded
+                .load(Ordering::Relaxed),
+            completed_this_session: self.download_counters.completed.load(Ordering::Relaxed),
+            failed_this_session: self.download_counters.failed.load(Ordering::Relaxed),
+            max_active_downloads: self.max_active_downloads(),
+            rate_limit_bytes_per_sec: self.rate_limit(),
+            ..Default::default()
+        };
+        for state in self.downloads.read().await.values() {
+            match state.status {
+                DownloadStatus::Downloading | DownloadStatus::Pausing => {
+                    metrics.active += 1;
+                    metrics.throughput_bytes_per_sec += state.speed;
+                }
+                DownloadStatus::Queued => metrics.queued += 1,
+                DownloadStatus::Paused => metrics.paused += 1,
+                DownloadStatus::Error => metrics.failed += 1,
+                DownloadStatus::Cancelling
+                | DownloadStatus::Cancelled
+                | DownloadStatus::Completed => {}
+            }
+        }
+        metrics
+    }
+
     /// Snapshot all tracked downloads with a monotonic cursor.
     pub async fn download_snapshot(&self) -> crate::models::ModelDownloadSnapshot {
         self.reconcile_inactive_active_downloads().await;
@@ -1732,6 +2650,8 @@ impl HuggingFaceClient {
                 }
                 drop(downloads);
                 self.publish_download_snapshot().await;
+                // Wake the download if it is still waiting in the queue.
+                self.download_queue.notify();
                 Ok(true)
             } else {
                 Ok(false)
@@ -1745,7 +2665,7 @@ impl HuggingFaceClient {
     pub async fn resume_download(&self, download_id: &str) -> Result<bool> {
         self.prune_finished_download_tasks();
 
-        let (repo_id, files, dest_dir, cancel_flag, pause_flag) = {
+        let (repo_id, files, dest_dir, cancel_flag, pause_flag, queue_ticket) = {
             let mut downloads = self.downloads.write().await;
             let state = match downloads.get_mut(download_id) {
                 Some(s) => s,
@@ -1762,6 +2682,15 @@ impl HuggingFaceClient {
             state.status = DownloadStatus::Queued;
             state.error = None;
             state.speed = 0.0;
+            let queue_ticket = self.download_queue.enqueue(
+                download_id,
+                state
+                    .download_request
+                    .as_ref()
+                    .map(|request| request.priority)
+                    .unwrap_or_default(),
+            );
+            state.queue_position = queue_ticket.blocked_position().map(|p| p as u32);
 
             (
                 state.repo_id.clone(),
@@ -1769,6 +2698,7 @@ impl HuggingFaceClient {
                 state.dest_dir.clone(),
                 state.cancel_flag.clone(),
                 state.pause_flag.clone(),
+                queue_ticket,
             )
         };
         self.publish_download_snapshot().await;
@@ -1783,71 +2713,63 @@ impl HuggingFaceClient {
             .await;
         }
 
-        // Re-spawn the download task
-        let client = self.download_client.clone();
-        let downloads = self.downloads.clone();
-        let download_revision = self.download_revision.clone();
-        let download_updates = self.download_updates.clone();
-        let download_id_clone = download_id.to_string();
-        let persistence = self.persistence.clone();
-        let completion_callback = self.completion_callback.clone();
-        let aux_complete_callback = self.aux_complete_callback.clone();
-        let auth_header = self.auth_header_value().await;
-        let dest_lock = self.destination_lock(&dest_dir).await;
+        self.spawn_download_task(
+            download_id,
+            repo_id,
+            files,
+            dest_dir,
+            cancel_flag,
+            pause_flag,
+            queue_ticket,
+        )
+        .await;
 
-        let task_handle = tokio::spawn(async move {
-            let _destination_guard = dest_lock.lock().await;
+        Ok(true)
+    }
 
-            let result = Self::run_download(
-                client,
-                downloads.clone(),
-                download_revision.clone(),
-                download_updates.clone(),
-                &download_id_clone,
-                &repo_id,
-                &files,
-                &dest_dir,
-                cancel_flag,
-                pause_flag,
-                persistence.clone(),
-                completion_callback,
-                aux_complete_callback,
-                auth_header,
-            )
-            .await;
+    /// Change the queue priority of a download.
+    ///
+    /// A download still waiting for a slot moves to its new place in the
+    /// queue; for any other unfinished download the priority applies the next
+    /// time it is queued, such as on resume. Returns `false` for unknown,
+    /// completed, or cancelled downloads.
+    pub async fn reorder_download(
+        &self,
+        download_id: &str,
+        priority: DownloadPriority,
+    ) -> Result<bool> {
+        {
+            let mut downloads = self.downloads.write().await;
+            let Some(state) = downloads.get_mut(download_id) else {
+                return Ok(false);
+            };
+            if matches!(
+                state.status,
+                DownloadStatus::Completed | DownloadStatus::Cancelling | DownloadStatus::Cancelled
+            ) {
+                return Ok(false);
+            }
+            if let Some(request) = state.download_request.as_mut() {
+                request.priority = priority;
+            }
+        }
+        self.download_queue.set_priority(download_id, priority);
 
-            if let Err(e) = result {
-                if matches!(e, PumasError::DownloadPaused) {
-                    info!("Download paused for {}", repo_id);
-                    return;
-                }
-                error!("Download failed for {}: {}", repo_id, e);
-                let mut download_states = downloads.write().await;
-                if let Some(state) = download_states.get_mut(&download_id_clone) {
-                    state.status = DownloadStatus::Error;
-                    state.error = Some(e.to_string());
-                }
-                drop(download_states);
-                publish_download_snapshot_from_parts(
-                    &downloads,
-                    &download_revision,
-                    &download_updates,
-                )
-                .await;
-                // Update persistence with error status
-                if let Some(ref persistence) = persistence {
-                    Self::persist_status_update(
-                        persistence.clone(),
-                        download_id_clone.clone(),
-                        DownloadStatus::Error,
-                    )
-                    .await;
+        if let Some(ref persistence) = self.persistence {
+            let persistence = persistence.clone();
+            let download_id = download_id.to_string();
+            let _ = tokio::task::spawn_blocking(move || {
+                let entries = persistence.load_all();
+                if let Some(mut entry) = entries.into_iter().find(|d| d.download_id == download_id)
+                {
+                    entry.download_request.priority = priority;
+                    let _ = persistence.save(&entry);
                 }
-            }
-        });
-        self.store_download_task(download_id.to_string(), task_handle);
-        self.mark_download_task_registered(download_id).await;
+            })
+            .await;
+        }
 
+        self.publish_download_snapshot().await;
         Ok(true)
     }
 }
@@ -1902,52 +2824,501 @@ mod tests {
         assert!(selected.is_empty());
     }
 
-    #[test]
-    fn test_select_auxiliary_files_no_matches() {
-        let regular_files = vec![
-            ".gitattributes".to_string(),
-            "README.md".to_string(),
-            "modeling_sdar.py".to_string(),
-        ];
-        let selected = select_auxiliary_files(&regular_files);
-        assert!(selected.is_empty());
+    #[test]
+    fn test_select_auxiliary_files_no_matches() {
+        let regular_files = vec![
+            ".gitattributes".to_string(),
+            "README.md".to_string(),
+            "modeling_sdar.py".to_string(),
+        ];
+        let selected = select_auxiliary_files(&regular_files);
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn test_select_auxiliary_files_ignores_subdirectory_paths() {
+        let regular_files = vec![
+            "subdir/config.json".to_string(),
+            "tokenizer.json".to_string(),
+        ];
+        let selected = select_auxiliary_files(&regular_files);
+        // Both should match — the subdirectory path matches by filename component
+        assert_eq!(selected.len(), 2);
+        assert!(selected.contains(&"subdir/config.json".to_string()));
+        assert!(selected.contains(&"tokenizer.json".to_string()));
+    }
+
+    #[test]
+    fn test_retry_limit_zero_means_unlimited() {
+        assert_eq!(retry_limit(0), None);
+        assert_eq!(retry_limit(4), Some(4));
+    }
+
+    #[test]
+    fn test_retry_exhausted_by_attempt_limit() {
+        let exhausted = retry_exhausted(
+            3,
+            Some(3),
+            Duration::from_secs(10),
+            Duration::from_secs(120),
+        );
+        assert!(exhausted);
+    }
+
+    #[test]
+    fn test_retry_exhausted_by_elapsed_budget() {
+        let exhausted =
+            retry_exhausted(2, None, Duration::from_secs(121), Duration::from_secs(120));
+        assert!(exhausted);
+    }
+
+    #[test]
+    fn test_concurrent_file_limit_defaults_to_one_and_is_capped() {
+        let mut request = DownloadRequest {
+            repo_id: "owner/model".to_string(),
+            family: "owner".to_string(),
+            official_name: "Model".to_string(),
+            model_type: None,
+            quant: None,
+            filename: None,
+            filenames: None,
+            pipeline_tag: None,
+            bundle_format: None,
+            pipeline_class: None,
+            release_date: None,
+            download_url: None,
+            model_card_json: None,
+            license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
+        };
+        assert_eq!(concurrent_file_limit(None), 1);
+        assert_eq!(concurrent_file_limit(Some(&request)), 1);
+
+        request.max_concurrent_files = Some(0);
+        assert_eq!(concurrent_file_limit(Some(&request)), 1);
+        request.max_concurrent_files = Some(4);
+        assert_eq!(concurrent_file_limit(Some(&request)), 4);
+        request.max_concurrent_files = Some(1000);
+        assert_eq!(
+            concurrent_file_limit(Some(&request)),
+            crate::config::NetworkConfig::HF_DOWNLOAD_MAX_CONCURRENT_FILES
+        );
+    }
+
+    #[test]
+    fn test_download_byte_progress_sums_concurrent_files() {
+        let progress = DownloadByteProgress::new(3);
+        progress.set_file(0, 100);
+        progress.set_file(1, 40);
+        progress.add(1, 10);
+        progress.add(2, 25);
+        assert_eq!(progress.total(), 175);
+
+        // A server that ignores Range restarts the file from zero.
+        progress.set_file(1, 0);
+        progress.add(1, 5);
+        assert_eq!(progress.total(), 130);
+        assert_eq!(progress.transferred.load(Ordering::Relaxed), 40);
+    }
+
+    #[test]
+    fn test_download_speed_follows_recent_window() {
+        let start = Instant::now();
+        let progress = DownloadByteProgress::with_window(1, Duration::from_secs(10), start);
+        let at = |secs: u64| start + Duration::from_secs(secs);
+
+        // 1000 B/s for 30 seconds, sampled every second.
+        for second in 1..=30 {
+            progress.add(0, 1000);
+            progress.speed_at(at(second));
+        }
+        assert!((progress.speed_at(at(30)) - 1000.0).abs() < 1.0);
+
+        // The rate doubles; the run average would still be 1250 B/s after
+        // 10 more seconds, but the window only sees the new rate.
+        for second in 31..=40 {
+            progress.add(0, 2000);
+            progress.speed_at(at(second));
+        }
+        assert!((progress.speed_at(at(40)) - 2000.0).abs() < 1.0);
+
+        // Nothing arrives for a full window, so the speed drops to zero.
+        assert_eq!(progress.speed_at(at(55)), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_download_attempt_fails_as_stalled_when_no_bytes_arrive() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.bin", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n0123456789")
+                .await
+                .unwrap();
+            // Hold the connection open without sending the rest.
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            drop(socket);
+        });
+
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        client.downloads.write().await.insert(
+            "dl-stall".to_string(),
+            queued_test_state("dl-stall", tmp.path().to_path_buf()),
+        );
+        let retry_config = RetryConfig::new();
+        let progress = DownloadByteProgress::new(1);
+        let transfer = FileTransfer {
+            client: &client.download_client,
+            downloads: &client.downloads,
+            download_revision: &client.download_revision,
+            download_updates: &client.download_updates,
+            download_id: "dl-stall",
+            repo_id: "owner/model",
+            dest_dir: tmp.path(),
+            cancel_flag: &Arc::new(AtomicBool::new(false)),
+            pause_flag: &Arc::new(AtomicBool::new(false)),
+            persistence: None,
+            auth_header: None,
+            verify_hashes: false,
+            retry_limit: None,
+            max_retry_elapsed: Duration::ZERO,
+            retry_config: &retry_config,
+            progress: &progress,
+            rate_limiter: &client.rate_limiter,
+            shared_rate_limiter: &client.shared_rate_limiter,
+            counters: &client.download_counters,
+            stall_timeout: Duration::from_millis(200),
+        };
+        let part_path = transfer.part_path("model.bin");
+
+        let started = Instant::now();
+        let err = transfer
+            .download_attempt(0, &url, &part_path, Some(100), 0)
+            .await
+            .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(matches!(err, PumasError::DownloadFailed { .. }), "{err}");
+        assert!(!err.is_retryable());
+        assert_eq!(std::fs::read(&part_path).unwrap(), b"0123456789");
+
+        let progress = client.get_download_progress("dl-stall").await.unwrap();
+        assert!(progress.stalled);
+        assert_eq!(progress.speed, Some(0.0));
+
+        let metrics = client.download_metrics().await;
+        assert_eq!(metrics.stalls_this_session, 1);
+        assert_eq!(metrics.bytes_downloaded_this_session, 10);
+    }
+
+    #[tokio::test]
+    async fn test_download_metrics_counts_statuses_and_throughput() {
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        client.set_rate_limit(Some(4096));
+        {
+            let mut downloads = client.downloads.write().await;
+            for (id, status, speed) in [
+                ("dl-a", DownloadStatus::Downloading, 1000.0),
+                ("dl-b", DownloadStatus::Downloading, 500.0),
+                ("dl-c", DownloadStatus::Queued, 0.0),
+                ("dl-d", DownloadStatus::Error, 0.0),
+                ("dl-e", DownloadStatus::Completed, 0.0),
+            ] {
+                let mut state = queued_test_state(id, tmp.path().to_path_buf());
+                state.status = status;
+                state.speed = speed;
+                downloads.insert(id.to_string(), state);
+            }
+        }
+
+        let metrics = client.download_metrics().await;
+
+        assert_eq!(metrics.active, 2);
+        assert_eq!(metrics.queued, 1);
+        assert_eq!(metrics.paused, 0);
+        assert_eq!(metrics.failed, 1);
+        assert_eq!(metrics.throughput_bytes_per_sec, 1500.0);
+        assert_eq!(metrics.retries_this_session, 0);
+        assert_eq!(
+            metrics.max_active_downloads,
+            crate::config::NetworkConfig::HF_MAX_ACTIVE_DOWNLOADS
+        );
+        assert_eq!(metrics.rate_limit_bytes_per_sec, Some(4096));
+    }
+
+    #[test]
+    fn test_validate_resume_range() {
+        assert_eq!(
+            validate_resume_range(Some("bytes 10-31/32"), Some(22), 10, Some(32)),
+            Ok(Some(32))
+        );
+        assert_eq!(
+            validate_resume_range(Some("bytes 10-31/*"), None, 10, None),
+            Ok(None)
+        );
+        // Missing header, wrong offset, changed remote size, and a body that
+        // does not cover the rest of the file all discard the partial.
+        assert!(validate_resume_range(None, Some(22), 10, Some(32)).is_err());
+        assert!(validate_resume_range(Some("bytes 0-31/32"), None, 10, Some(32)).is_err());
+        assert!(validate_resume_range(Some("bytes 10-39/40"), None, 10, Some(32)).is_err());
+        assert!(validate_resume_range(Some("bytes 10-31/32"), Some(5), 10, Some(32)).is_err());
+    }
+
+    /// Serve one canned response per connection and record each request's
+    /// `Range` header (`None` when absent).
+    async fn spawn_range_server(
+        responses: Vec<(String, Vec<u8>)>,
+    ) -> (String, Arc<std::sync::Mutex<Vec<Option<String>>>>) {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.bin", listener.local_addr().unwrap());
+        let ranges = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = ranges.clone();
+        tokio::spawn(async move {
+            for (head, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let range = String::from_utf8_lossy(&request).lines().find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("range")
+                        .then(|| value.trim().to_string())
+                });
+                seen.lock().unwrap().push(range);
+                let head = format!(
+                    "{}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    head,
+                    body.len()
+                );
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&body).await.unwrap();
+            }
+        });
+        (url, ranges)
+    }
+
+    /// Run one `download_attempt` against `url` into `part_path`.
+    async fn run_download_attempt(
+        url: &str,
+        part_path: &Path,
+        file_size_expected: Option<u64>,
+    ) -> Result<()> {
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        let retry_config = RetryConfig::new();
+        let progress = DownloadByteProgress::new(1);
+        let transfer = FileTransfer {
+            client: &client.download_client,
+            downloads: &client.downloads,
+            download_revision: &client.download_revision,
+            download_updates: &client.download_updates,
+            download_id: "dl-resume",
+            repo_id: "owner/model",
+            dest_dir: tmp.path(),
+            cancel_flag: &Arc::new(AtomicBool::new(false)),
+            pause_flag: &Arc::new(AtomicBool::new(false)),
+            persistence: None,
+            auth_header: None,
+            verify_hashes: false,
+            retry_limit: None,
+            max_retry_elapsed: Duration::ZERO,
+            retry_config: &retry_config,
+            progress: &progress,
+            rate_limiter: &client.rate_limiter,
+            shared_rate_limiter: &client.shared_rate_limiter,
+            counters: &client.download_counters,
+            stall_timeout: Duration::ZERO,
+        };
+        let resume_from_byte = tokio::fs::metadata(part_path)
+            .await
+            .map(|m| m.len())
+            .unwrap_or(0);
+        transfer
+            .download_attempt(0, url, part_path, file_size_expected, resume_from_byte)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_download_attempt_appends_partial_content() {
+        let body: Vec<u8> = (0..32u8).collect();
+        let (url, ranges) = spawn_range_server(vec![(
+            "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 10-31/32".to_string(),
+            body[10..].to_vec(),
+        )])
+        .await;
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.bin.part");
+        std::fs::write(&part_path, &body[..10]).unwrap();
+
+        run_download_attempt(&url, &part_path, Some(32))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&part_path).unwrap(), body);
+        assert_eq!(*ranges.lock().unwrap(), vec![Some("bytes=10-".to_string())]);
     }
 
-    #[test]
-    fn test_select_auxiliary_files_ignores_subdirectory_paths() {
-        let regular_files = vec![
-            "subdir/config.json".to_string(),
-            "tokenizer.json".to_string(),
-        ];
-        let selected = select_auxiliary_files(&regular_files);
-        // Both should match — the subdirectory path matches by filename component
-        assert_eq!(selected.len(), 2);
-        assert!(selected.contains(&"subdir/config.json".to_string()));
-        assert!(selected.contains(&"tokenizer.json".to_string()));
+    #[tokio::test]
+    async fn test_download_attempt_restarts_when_range_is_ignored() {
+        let body: Vec<u8> = (0..32u8).collect();
+        let (url, ranges) =
+            spawn_range_server(vec![("HTTP/1.1 200 OK".to_string(), body.clone())]).await;
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.bin.part");
+        std::fs::write(&part_path, b"stale-bytes").unwrap();
+
+        run_download_attempt(&url, &part_path, Some(32))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&part_path).unwrap(), body);
+        assert_eq!(*ranges.lock().unwrap(), vec![Some("bytes=11-".to_string())]);
     }
 
-    #[test]
-    fn test_retry_limit_zero_means_unlimited() {
-        assert_eq!(retry_limit(0), None);
-        assert_eq!(retry_limit(4), Some(4));
+    #[tokio::test]
+    async fn test_download_attempt_restarts_when_remote_size_changed() {
+        let old_body: Vec<u8> = (0..32u8).collect();
+        let new_body: Vec<u8> = (100..140u8).collect();
+        let (url, ranges) = spawn_range_server(vec![
+            (
+                "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 10-39/40".to_string(),
+                new_body[10..].to_vec(),
+            ),
+            ("HTTP/1.1 200 OK".to_string(), new_body.clone()),
+        ])
+        .await;
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.bin.part");
+        std::fs::write(&part_path, &old_body[..10]).unwrap();
+
+        run_download_attempt(&url, &part_path, Some(32))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&part_path).unwrap(), new_body);
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![Some("bytes=10-".to_string()), None]
+        );
     }
 
-    #[test]
-    fn test_retry_exhausted_by_attempt_limit() {
-        let exhausted = retry_exhausted(
-            3,
-            Some(3),
-            Duration::from_secs(10),
-            Duration::from_secs(120),
+    #[tokio::test]
+    async fn test_download_attempt_restarts_on_unsatisfiable_range() {
+        let body: Vec<u8> = (0..32u8).collect();
+        let (url, ranges) = spawn_range_server(vec![
+            (
+                "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */32".to_string(),
+                Vec::new(),
+            ),
+            ("HTTP/1.1 200 OK".to_string(), body.clone()),
+        ])
+        .await;
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.bin.part");
+        std::fs::write(&part_path, &body).unwrap();
+
+        run_download_attempt(&url, &part_path, None).await.unwrap();
+
+        assert_eq!(std::fs::read(&part_path).unwrap(), body);
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![Some("bytes=32-".to_string()), None]
         );
-        assert!(exhausted);
     }
 
-    #[test]
-    fn test_retry_exhausted_by_elapsed_budget() {
-        let exhausted =
-            retry_exhausted(2, None, Duration::from_secs(121), Duration::from_secs(120));
-        assert!(exhausted);
+    #[tokio::test]
+    async fn test_rate_limit_throttles_download_stream() {
+        use tokio::io::AsyncReadExt;
+
+        const LIMIT: u64 = 8 * 1024;
+        let body = vec![7u8; 2 * LIMIT as usize];
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.bin", listener.local_addr().unwrap());
+        let served = body.clone();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n",
+                served.len()
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            // Small writes keep chunks well under the one-second burst.
+            for chunk in served.chunks(1024) {
+                socket.write_all(chunk).await.unwrap();
+            }
+        });
+
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        assert_eq!(client.rate_limit(), None);
+        client.set_rate_limit(Some(LIMIT));
+        assert_eq!(client.rate_limit(), Some(LIMIT));
+
+        let retry_config = RetryConfig::new();
+        let progress = DownloadByteProgress::new(1);
+        let transfer = FileTransfer {
+            client: &client.download_client,
+            downloads: &client.downloads,
+            download_revision: &client.download_revision,
+            download_updates: &client.download_updates,
+            download_id: "dl-throttled",
+            repo_id: "owner/model",
+            dest_dir: tmp.path(),
+            cancel_flag: &Arc::new(AtomicBool::new(false)),
+            pause_flag: &Arc::new(AtomicBool::new(false)),
+            persistence: None,
+            auth_header: None,
+            verify_hashes: false,
+            retry_limit: None,
+            max_retry_elapsed: Duration::ZERO,
+            retry_config: &retry_config,
+            progress: &progress,
+            rate_limiter: &client.rate_limiter,
+            shared_rate_limiter: &client.shared_rate_limiter,
+            counters: &client.download_counters,
+            stall_timeout: Duration::ZERO,
+        };
+        let part_path = transfer.part_path("model.bin");
+
+        let started = Instant::now();
+        transfer
+            .download_attempt(0, &url, &part_path, Some(body.len() as u64), 0)
+            .await
+            .unwrap();
+        let elapsed = started.elapsed();
+
+        // One second of burst, then the second half at the cap.
+        assert!(elapsed >= Duration::from_millis(900), "{:?}", elapsed);
+        assert!(elapsed <= Duration::from_millis(2500), "{:?}", elapsed);
+        assert_eq!(std::fs::read(&part_path).unwrap(), body);
+        let throughput = body.len() as f64 / elapsed.as_secs_f64();
+        assert!(
+            progress.speed() <= throughput * 1.05,
+            "{}",
+            progress.speed()
+        );
     }
 
     #[tokio::test]
@@ -1977,6 +3348,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         persistence
@@ -2014,6 +3391,9 @@ mod tests {
                     retry_limit: None,
                     retrying: false,
                     next_retry_delay_seconds: None,
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: false,
                     dest_dir: old_dest.clone(),
                     filename: "model.safetensors".to_string(),
@@ -2079,6 +3459,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         {
@@ -2100,6 +3486,9 @@ mod tests {
                     retry_limit: Some(5),
                     retrying: true,
                     next_retry_delay_seconds: Some(4.0),
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: false,
                     dest_dir: tmp.path().join("owner-model"),
                     filename: "model.safetensors".to_string(),
@@ -2153,6 +3542,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         {
@@ -2174,6 +3569,9 @@ mod tests {
                     retry_limit: None,
                     retrying: false,
                     next_retry_delay_seconds: None,
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: false,
                     dest_dir: tmp.path().join("owner-multi-file"),
                     filename: "config.json".to_string(),
@@ -2231,6 +3629,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         {
@@ -2252,6 +3656,9 @@ mod tests {
                     retry_limit: None,
                     retrying: false,
                     next_retry_delay_seconds: None,
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: false,
                     dest_dir: tmp.path().join("owner-multi-file"),
                     filename: "config.json".to_string(),
@@ -2311,6 +3718,12 @@ mod tests {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         persistence
@@ -2348,6 +3761,9 @@ mod tests {
                     retry_limit: None,
                     retrying: true,
                     next_retry_delay_seconds: Some(1.0),
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: true,
                     dest_dir: tmp.path().join("owner-model"),
                     filename: "model.Q4_K_M.gguf".to_string(),
@@ -2378,6 +3794,161 @@ mod tests {
         assert_eq!(persistence.load_all()[0].status, DownloadStatus::Paused);
     }
 
+    #[tokio::test]
+    async fn test_restored_downloads_keep_ids_and_generator_skips_them() {
+        let tmp = TempDir::new().unwrap();
+        let mut client = HuggingFaceClient::new(tmp.path()).unwrap();
+        let persistence = Arc::new(DownloadPersistence::new(tmp.path()));
+        client.set_persistence(persistence.clone());
+        let counter = Arc::new(AtomicU64::new(0));
+        client.set_download_id_generator(Arc::new(move || {
+            format!("dl-{}", counter.fetch_add(1, Ordering::Relaxed) + 1)
+        }));
+
+        let dest_dir = tmp.path().join("owner-model");
+        std::fs::create_dir_all(&dest_dir).unwrap();
+        std::fs::write(dest_dir.join("model.gguf.part"), b"partial").unwrap();
+        persistence
+            .save(&PersistedDownload {
+                download_id: "dl-1".to_string(),
+                repo_id: "owner/model".to_string(),
+                filename: "model.gguf".to_string(),
+                filenames: vec!["model.gguf".to_string()],
+                dest_dir: dest_dir.clone(),
+                total_bytes: Some(1024),
+                status: DownloadStatus::Downloading,
+                download_request: DownloadRequest {
+                    repo_id: "owner/model".to_string(),
+                    family: "owner".to_string(),
+                    official_name: "Model".to_string(),
+                    model_type: Some("llm".to_string()),
+                    quant: None,
+                    filename: Some("model.gguf".to_string()),
+                    filenames: None,
+                    pipeline_tag: None,
+                    bundle_format: None,
+                    pipeline_class: None,
+                    release_date: None,
+                    download_url: None,
+                    model_card_json: None,
+                    license_status: None,
+                    skip_hash_verification: false,
+                    max_concurrent_files: None,
+                    rate_limit: None,
+                    priority: Default::default(),
+                    include_patterns: None,
+                    exclude_patterns: None,
+                },
+                created_at: chrono::Utc::now().to_rfc3339(),
+                known_sha256: None,
+                huggingface_evidence: None,
+            })
+            .unwrap();
+
+        client.restore_persisted_downloads().await;
+        client.restore_persisted_downloads().await;
+
+        let downloads = client.list_downloads().await;
+        assert_eq!(downloads.len(), 1);
+        assert_eq!(downloads[0].download_id, "dl-1");
+        assert_eq!(downloads[0].status, DownloadStatus::Paused);
+        assert_eq!(client.next_download_id().await, "dl-2");
+    }
+
+    #[tokio::test]
+    async fn test_restore_moves_finished_downloads_to_history() {
+        let tmp = TempDir::new().unwrap();
+        let mut client = HuggingFaceClient::new(tmp.path()).unwrap();
+        let persistence = Arc::new(DownloadPersistence::new(tmp.path()));
+        client.set_persistence(persistence.clone());
+
+        let dest_dir = tmp.path().join("owner-model");
+        std::fs::create_dir_all(&dest_dir).unwrap();
+        std::fs::write(dest_dir.join("model.gguf"), b"complete").unwrap();
+        persistence
+            .save(&PersistedDownload {
+                download_id: "dl-done".to_string(),
+                repo_id: "owner/model".to_string(),
+                filename: "model.gguf".to_string(),
+                filenames: vec!["model.gguf".to_string()],
+                dest_dir: dest_dir.clone(),
+                total_bytes: Some(8),
+                status: DownloadStatus::Completed,
+                download_request: DownloadRequest {
+                    repo_id: "owner/model".to_string(),
+                    family: "owner".to_string(),
+                    official_name: "Model".to_string(),
+                    model_type: Some("llm".to_string()),
+                    quant: None,
+                    filename: Some("model.gguf".to_string()),
+                    filenames: None,
+                    pipeline_tag: None,
+                    bundle_format: None,
+                    pipeline_class: None,
+                    release_date: None,
+                    download_url: None,
+                    model_card_json: None,
+                    license_status: None,
+                    skip_hash_verification: false,
+                    max_concurrent_files: None,
+                    rate_limit: None,
+                    priority: Default::default(),
+                    include_patterns: None,
+                    exclude_patterns: None,
+                },
+                created_at: chrono::Utc::now().to_rfc3339(),
+                known_sha256: None,
+                huggingface_evidence: None,
+            })
+            .unwrap();
+
+        client.restore_persisted_downloads().await;
+
+        assert!(client.list_downloads().await.is_empty());
+        assert!(persistence.load_all().is_empty());
+        let history = client.download_history(10, 0).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].download_id, "dl-done");
+        assert_eq!(history[0].status, DownloadStatus::Completed);
+        assert_eq!(history[0].bytes_downloaded, 8);
+
+        client.clear_download_history().await.unwrap();
+        assert!(client.download_history(10, 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_verify_downloaded_file_keeps_matching_file() {
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.safetensors.part");
+        tokio::fs::write(&part_path, b"hello world").await.unwrap();
+
+        HuggingFaceClient::verify_downloaded_file(
+            &part_path,
+            "B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9",
+        )
+        .await
+        .unwrap();
+
+        assert!(tokio::fs::try_exists(&part_path).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_downloaded_file_removes_truncated_file() {
+        let tmp = TempDir::new().unwrap();
+        let part_path = tmp.path().join("model.safetensors.part");
+        tokio::fs::write(&part_path, b"hello").await.unwrap();
+
+        let err = HuggingFaceClient::verify_downloaded_file(
+            &part_path,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, PumasError::HashMismatch { .. }));
+        assert!(!tokio::fs::try_exists(&part_path).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_remove_stale_part_for_completed_file_only_removes_matching_part() {
         let tmp = TempDir::new().unwrap();
@@ -2438,6 +4009,9 @@ mod tests {
                     retry_limit: None,
                     retrying: false,
                     next_retry_delay_seconds: None,
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     task_registered: true,
                     dest_dir: tmp.path().join("owner-model"),
                     filename: "model.safetensors".to_string(),
@@ -2524,4 +4098,376 @@ mod tests {
         assert!(notification.stale_cursor);
         assert!(notification.snapshot_required);
     }
+
+    fn queued_test_state(download_id: &str, dest_dir: PathBuf) -> DownloadState {
+        DownloadState {
+            download_id: download_id.to_string(),
+            repo_id: "owner/model".to_string(),
+            status: DownloadStatus::Queued,
+            progress: 0.0,
+            downloaded_bytes: 0,
+            total_bytes: None,
+            speed: 0.0,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            pause_flag: Arc::new(AtomicBool::new(false)),
+            error: None,
+            retry_attempt: 0,
+            retry_limit: None,
+            retrying: false,
+            next_retry_delay_seconds: None,
+            queue_position: None,
+            stalled: false,
+            skipped_files: Vec::new(),
+            task_registered: false,
+            dest_dir,
+            filename: "model.gguf".to_string(),
+            files: Vec::new(),
+            files_completed: 0,
+            download_request: None,
+            known_sha256: None,
+            huggingface_evidence: None,
+        }
+    }
+
+    /// Insert a queued download and spawn the task that waits for its slot.
+    async fn spawn_queued_download(
+        client: &HuggingFaceClient,
+        download_id: &str,
+    ) -> JoinHandle<Option<DownloadSlot>> {
+        let state = queued_test_state(download_id, client.cache_dir.join(download_id));
+        let pause_flag = state.pause_flag.clone();
+        client
+            .downloads
+            .write()
+            .await
+            .insert(download_id.to_string(), state);
+        let ticket = client
+            .download_queue
+            .enqueue(download_id, DownloadPriority::Normal);
+        let downloads = client.downloads.clone();
+        let revision = client.download_revision.clone();
+        let updates = client.download_updates.clone();
+        let download_id = download_id.to_string();
+        tokio::spawn(async move {
+            HuggingFaceClient::wait_for_download_slot(
+                ticket,
+                &downloads,
+                &revision,
+                &updates,
+                None,
+                &download_id,
+                &pause_flag,
+            )
+            .await
+        })
+    }
+
+    async fn wait_for_queue_positions(
+        client: &HuggingFaceClient,
+        expected: &[(&str, Option<u32>)],
+    ) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let downloads = client.list_downloads().await;
+            let matches = expected.iter().all(|(id, position)| {
+                downloads
+                    .iter()
+                    .any(|d| d.download_id == *id && d.queue_position == *position)
+            });
+            if matches {
+                return;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "queue positions: {:?}",
+                downloads
+            );
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queued_downloads_report_position_and_follow_priority() {
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        client.set_max_active_downloads(1);
+        let mut running = client
+            .download_queue
+            .enqueue("dl-running", DownloadPriority::Normal);
+        let slot = running.try_start().ok().unwrap();
+
+        let first = spawn_queued_download(&client, "dl-first").await;
+        let second = spawn_queued_download(&client, "dl-second").await;
+        wait_for_queue_positions(&client, &[("dl-first", Some(1)), ("dl-second", Some(2))]).await;
+        assert!(client
+            .list_downloads()
+            .await
+            .iter()
+            .all(|d| d.status == DownloadStatus::Queued));
+
+        assert!(client
+            .reorder_download("dl-second", DownloadPriority::High)
+            .await
+            .unwrap());
+        wait_for_queue_positions(&client, &[("dl-second", Some(1)), ("dl-first", Some(2))]).await;
+        assert!(!client
+            .reorder_download("dl-missing", DownloadPriority::High)
+            .await
+            .unwrap());
+
+        drop(slot);
+        let second_slot = tokio::time::timeout(Duration::from_secs(5), second)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(second_slot.is_some());
+        wait_for_queue_positions(&client, &[("dl-second", None), ("dl-first", Some(1))]).await;
+        assert!(!first.is_finished());
+
+        drop(second_slot);
+        let first_slot = tokio::time::timeout(Duration::from_secs(5), first)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(first_slot.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_pause_while_queued_marks_download_paused() {
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path()).unwrap();
+        client.set_max_active_downloads(1);
+        let mut running = client
+            .download_queue
+            .enqueue("dl-running", DownloadPriority::Normal);
+        let _slot = running.try_start().ok().unwrap();
+
+        let waiting = spawn_queued_download(&client, "dl-waiting").await;
+        wait_for_queue_positions(&client, &[("dl-waiting", Some(1))]).await;
+
+        assert!(client.pause_download("dl-waiting").await.unwrap());
+        let slot = tokio::time::timeout(Duration::from_secs(5), waiting)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(slot.is_none());
+        let progress = client.get_download_progress("dl-waiting").await.unwrap();
+        assert_eq!(progress.status, DownloadStatus::Paused);
+        assert_eq!(progress.queue_position, None);
+    }
+
+    fn mirror_test_tree() -> RepoFileTree {
+        RepoFileTree {
+            repo_id: "owner/model".to_string(),
+            lfs_files: vec![crate::model_library::types::LfsFileInfo {
+                filename: "weights/model.safetensors".to_string(),
+                size: 4,
+                sha256: "abcd".to_string(),
+            }],
+            regular_files: vec![
+                "README.md".to_string(),
+                "custom/layout/config.json".to_string(),
+                "../escape.txt".to_string(),
+                "/etc/passwd".to_string(),
+            ],
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            last_modified: None,
+            cache_version: crate::model_library::types::REPO_FILE_TREE_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_repo_mirror_files_keeps_structure_and_drops_unsafe_paths() {
+        let files = repo_mirror_files(&mirror_test_tree());
+        let names: Vec<&str> = files.iter().map(|f| f.filename.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "README.md",
+                "custom/layout/config.json",
+                "weights/model.safetensors"
+            ]
+        );
+        assert_eq!(files[0].size, None);
+        assert_eq!(files[2].size, Some(4));
+        assert_eq!(files[2].sha256.as_deref(), Some("abcd"));
+    }
+
+    #[tokio::test]
+    async fn test_clone_repo_mirrors_structure_without_import() {
+        let tmp = TempDir::new().unwrap();
+        let mut client = HuggingFaceClient::new(tmp.path().join("cache")).unwrap();
+        let imported = Arc::new(AtomicBool::new(false));
+        let imported_flag = imported.clone();
+        client.set_completion_callback(Arc::new(move |_| {
+            imported_flag.store(true, Ordering::SeqCst);
+        }));
+        let aux_fired = Arc::new(AtomicBool::new(false));
+        let aux_flag = aux_fired.clone();
+        client.set_aux_complete_callback(Arc::new(move |_| {
+            aux_flag.store(true, Ordering::SeqCst);
+        }));
+        std::fs::write(
+            client.get_cache_path("owner/model", "files"),
+            serde_json::to_vec(&mirror_test_tree()).unwrap(),
+        )
+        .unwrap();
+
+        // Files already on disk are skipped, so the mirror completes offline.
+        let dest = tmp.path().join("mirror");
+        for name in [
+            "README.md",
+            "custom/layout/config.json",
+            "weights/model.safetensors",
+        ] {
+            let path = dest.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"data").unwrap();
+        }
+
+        let download_id = client.clone_repo("owner/model", &dest).await.unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let progress = loop {
+            let progress = client.get_download_progress(&download_id).await.unwrap();
+            if progress.status == DownloadStatus::Completed {
+                break progress;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "clone did not finish: {:?}",
+                progress
+            );
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+
+        assert_eq!(progress.repo_id.as_deref(), Some("owner/model"));
+        assert_eq!(progress.total_bytes, Some(4));
+        assert!(!imported.load(Ordering::SeqCst));
+        assert!(!aux_fired.load(Ordering::SeqCst));
+        assert!(!dest.join(".pumas_download").exists());
+        assert!(!tmp.path().join("escape.txt").exists());
+    }
+
+    fn filtered_test_request(
+        include: Option<Vec<&str>>,
+        exclude: Option<Vec<&str>>,
+    ) -> DownloadRequest {
+        let patterns =
+            |list: Option<Vec<&str>>| list.map(|list| list.into_iter().map(String::from).collect());
+        DownloadRequest {
+            repo_id: "owner/model".to_string(),
+            family: "owner".to_string(),
+            official_name: "Model".to_string(),
+            model_type: None,
+            quant: None,
+            filename: None,
+            filenames: None,
+            pipeline_tag: None,
+            bundle_format: None,
+            pipeline_class: None,
+            release_date: None,
+            download_url: None,
+            model_card_json: None,
+            license_status: None,
+            skip_hash_verification: true,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: Default::default(),
+            include_patterns: patterns(include),
+            exclude_patterns: patterns(exclude),
+        }
+    }
+
+    fn filtered_test_tree() -> RepoFileTree {
+        let lfs = |filename: &str| crate::model_library::types::LfsFileInfo {
+            filename: filename.to_string(),
+            size: 4,
+            sha256: "abcd".to_string(),
+        };
+        RepoFileTree {
+            repo_id: "owner/model".to_string(),
+            lfs_files: vec![
+                lfs("model-fp16.safetensors"),
+                lfs("model-fp32.safetensors"),
+                lfs("images/preview.png"),
+            ],
+            regular_files: vec![
+                "config.json".to_string(),
+                "generation_config.json".to_string(),
+                "README.md".to_string(),
+            ],
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            last_modified: None,
+            cache_version: crate::model_library::types::REPO_FILE_TREE_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_download_file_filter_include_selects_weights_and_exclude_applies_to_all() {
+        let filter = DownloadFileFilter::from_request(&filtered_test_request(
+            Some(vec!["*.safetensors"]),
+            Some(vec!["*fp32*", "*.md"]),
+        ));
+        assert!(filter.keeps_weight("model-fp16.safetensors"));
+        assert!(filter.keeps_weight("transformer/model-fp16.safetensors"));
+        assert!(!filter.keeps_weight("model-fp32.safetensors"));
+        assert!(!filter.keeps_weight("images/preview.png"));
+        assert!(filter.keeps_auxiliary("config.json"));
+        assert!(!filter.keeps_auxiliary("README.md"));
+
+        let by_directory =
+            DownloadFileFilter::from_request(&filtered_test_request(None, Some(vec!["images"])));
+        assert!(!by_directory.keeps_weight("images/preview.png"));
+        assert!(by_directory.keeps_weight("model-fp32.safetensors"));
+
+        let empty = DownloadFileFilter::from_request(&filtered_test_request(Some(vec![]), None));
+        assert!(empty.keeps_weight("anything.bin"));
+    }
+
+    #[tokio::test]
+    async fn test_start_download_reports_files_skipped_by_patterns() {
+        let tmp = TempDir::new().unwrap();
+        let client = HuggingFaceClient::new(tmp.path().join("cache")).unwrap();
+        std::fs::write(
+            client.get_cache_path("owner/model", "files"),
+            serde_json::to_vec(&filtered_test_tree()).unwrap(),
+        )
+        .unwrap();
+
+        // Kept files are already on disk, so the download completes offline.
+        let dest = tmp.path().join("model");
+        std::fs::create_dir_all(&dest).unwrap();
+        for name in ["config.json", "model-fp16.safetensors"] {
+            std::fs::write(dest.join(name), b"data").unwrap();
+        }
+
+        let request = filtered_test_request(
+            None,
+            Some(vec!["*fp32*", "images", "generation_config.json"]),
+        );
+        let download_id = client.start_download(&request, &dest, None).await.unwrap();
+        let progress = client.get_download_progress(&download_id).await.unwrap();
+        assert_eq!(
+            progress.skipped_files,
+            vec![
+                "model-fp32.safetensors".to_string(),
+                "images/preview.png".to_string(),
+                "generation_config.json".to_string(),
+            ]
+        );
+        assert_eq!(progress.total_bytes, Some(4));
+        let files: Vec<String> = client.downloads.read().await[&download_id]
+            .files
+            .iter()
+            .map(|file| file.filename.clone())
+            .collect();
+        assert_eq!(files, vec!["config.json", "model-fp16.safetensors"]);
+
+        let unmatched = filtered_test_request(Some(vec!["*.gguf"]), None);
+        let err = client
+            .start_download(&unmatched, &tmp.path().join("other"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PumasError::Validation { .. }));
+    }
 }
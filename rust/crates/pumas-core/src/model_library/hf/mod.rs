@@ -12,31 +12,34 @@
 //! - [`search`] - Model search with caching and enrichment
 //! - [`metadata`] - Direct model info, repo file trees, and metadata lookup
 //! - [`download`] - Download management with pause/resume/cancel
+//! - [`queue`] - Priority queue capping how many downloads run at once
 //! - [`auth`] - Authentication token management
 
 mod auth;
 mod bundles;
 mod download;
 mod metadata;
+mod queue;
 mod search;
 mod types;
 
 pub use auth::HfAuthStatus;
+use queue::DownloadQueue;
 pub use types::{
     AuxFilesCompleteCallback, AuxFilesCompleteInfo, DownloadCompletionCallback,
-    DownloadCompletionInfo,
+    DownloadCompletionInfo, DownloadIdGenerator,
 };
-use types::{DownloadState, REPO_CACHE_TTL_SECS};
+use types::{DownloadSessionCounters, DownloadState, REPO_CACHE_TTL_SECS};
 
 use crate::error::{PumasError, Result};
 use crate::model_library::download_store::DownloadPersistence;
 use crate::model_library::hf_cache::HfSearchCache;
-use crate::network::{CacheStrategy, WebSource, WebSourceId};
+use crate::network::{BandwidthLimiter, CacheStrategy, WebSource, WebSourceId};
 use async_trait::async_trait;
 use reqwest::Client;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
 use tokio::sync::{broadcast, Mutex, RwLock};
@@ -72,6 +75,21 @@ pub struct HuggingFaceClient {
     pub(super) aux_complete_callback: Option<AuxFilesCompleteCallback>,
     /// Authentication token for accessing gated/private models.
     pub(super) auth_token: Arc<RwLock<Option<String>>>,
+    /// Whether `auth_token` came from [`HuggingFaceClient::set_session_auth_token`].
+    session_auth_token: AtomicBool,
+    /// Source of IDs for new downloads.
+    pub(super) download_id_generator: DownloadIdGenerator,
+    /// Speed cap shared by this client's downloads.
+    pub(super) rate_limiter: Arc<BandwidthLimiter>,
+    /// Instance-wide cap shared with the owner's other download paths.
+    pub(super) shared_rate_limiter: Arc<BandwidthLimiter>,
+    /// Queue deciding which downloads may transfer at once.
+    download_queue: Arc<DownloadQueue>,
+    /// Session counters reported by `download_metrics`.
+    pub(super) download_counters: Arc<DownloadSessionCounters>,
+    /// Time without received bytes after which a download stops as stalled,
+    /// in milliseconds. `0` disables stall detection.
+    stall_timeout_ms: AtomicU64,
 }
 
 impl std::fmt::Debug for HuggingFaceClient {
@@ -92,12 +110,20 @@ impl HuggingFaceClient {
     ///
     /// * `cache_dir` - Directory for caching API responses
     pub fn new(cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        Self::with_http_settings(cache_dir, &crate::network::HttpSettings::default())
+    }
+
+    /// Create a HuggingFace client that uses the given proxy, CA, and user-agent settings.
+    pub fn with_http_settings(
+        cache_dir: impl Into<PathBuf>,
+        http: &crate::network::HttpSettings,
+    ) -> Result<Self> {
         let cache_dir = cache_dir.into();
         std::fs::create_dir_all(&cache_dir)?;
 
-        let client = Client::builder()
+        let client = http
+            .client_builder()
             .timeout(Duration::from_secs(30))
-            .user_agent("pumas-library/1.0")
             .build()
             .map_err(|e| PumasError::Network {
                 message: format!("Failed to create HTTP client: {}", e),
@@ -107,9 +133,9 @@ impl HuggingFaceClient {
         // Separate client for downloads: connect timeout only, no total timeout.
         // The total timeout would kill multi-gigabyte downloads that take longer
         // than 30 seconds. The stream loop handles progress and cancellation.
-        let download_client = Client::builder()
+        let download_client = http
+            .client_builder()
             .connect_timeout(Duration::from_secs(30))
-            .user_agent("pumas-library/1.0")
             .build()
             .map_err(|e| PumasError::Network {
                 message: format!("Failed to create download HTTP client: {}", e),
@@ -135,6 +161,17 @@ impl HuggingFaceClient {
             completion_callback: None,
             aux_complete_callback: None,
             auth_token: Arc::new(RwLock::new(initial_token)),
+            session_auth_token: AtomicBool::new(false),
+            download_id_generator: Arc::new(|| uuid::Uuid::new_v4().to_string()),
+            rate_limiter: Arc::new(BandwidthLimiter::new(None)),
+            shared_rate_limiter: Arc::new(BandwidthLimiter::new(None)),
+            download_queue: Arc::new(DownloadQueue::new(
+                crate::config::NetworkConfig::HF_MAX_ACTIVE_DOWNLOADS,
+            )),
+            download_counters: Arc::new(DownloadSessionCounters::default()),
+            stall_timeout_ms: AtomicU64::new(
+                crate::config::NetworkConfig::HF_DOWNLOAD_STALL_TIMEOUT.as_millis() as u64,
+            ),
         })
     }
 
@@ -188,6 +225,66 @@ impl HuggingFaceClient {
         self.aux_complete_callback = Some(callback);
     }
 
+    /// Replace the generator used for new download IDs.
+    ///
+    /// Restored downloads keep their persisted IDs; only downloads started
+    /// afterwards use this generator.
+    pub fn set_download_id_generator(&mut self, generator: DownloadIdGenerator) {
+        self.download_id_generator = generator;
+    }
+
+    /// Cap the combined download speed of this client's downloads.
+    ///
+    /// `None` or `Some(0)` removes the cap. Takes effect immediately, including
+    /// for downloads already running. A download whose request sets
+    /// `rate_limit` uses that cap instead. A limiter attached with
+    /// [`Self::set_shared_rate_limiter`] still applies on top.
+    pub fn set_rate_limit(&self, bytes_per_sec: Option<u64>) {
+        self.rate_limiter.set_limit(bytes_per_sec);
+    }
+
+    /// Current client-wide download speed cap, if any.
+    pub fn rate_limit(&self) -> Option<u64> {
+        self.rate_limiter.limit()
+    }
+
+    /// Draw this client's downloads from a budget shared with other clients.
+    ///
+    /// `PumasApi` attaches its instance-wide limiter here. Applies to
+    /// downloads started afterwards.
+    pub fn set_shared_rate_limiter(&mut self, limiter: Arc<BandwidthLimiter>) {
+        self.shared_rate_limiter = limiter;
+    }
+
+    /// Set how many downloads transfer at once; further downloads stay
+    /// `Queued` until a slot frees up.
+    ///
+    /// Values below 1 are raised to 1. Raising the limit starts waiting
+    /// downloads immediately; lowering it lets running downloads finish.
+    pub fn set_max_active_downloads(&self, max_active: usize) {
+        self.download_queue.set_max_active(max_active);
+    }
+
+    /// Maximum number of downloads that transfer at once.
+    pub fn max_active_downloads(&self) -> usize {
+        self.download_queue.max_active()
+    }
+
+    /// Set how long a download may go without receiving any bytes before it
+    /// is stopped as stalled and marked `Error`, ready to be resumed.
+    ///
+    /// `Duration::ZERO` disables stall detection. Applies to downloads started
+    /// or resumed afterwards.
+    pub fn set_stall_timeout(&self, timeout: Duration) {
+        self.stall_timeout_ms
+            .store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Current stall timeout; `Duration::ZERO` when disabled.
+    pub fn stall_timeout(&self) -> Duration {
+        Duration::from_millis(self.stall_timeout_ms.load(Ordering::Relaxed))
+    }
+
     // ========================================
     // Authentication
     // ========================================
@@ -205,10 +302,20 @@ impl HuggingFaceClient {
                 PumasError::Other(format!("Failed to join set_auth_token task: {}", e))
             })??;
         *self.auth_token.write().await = Some(token);
+        self.session_auth_token.store(false, Ordering::Relaxed);
         info!("HuggingFace auth token saved");
         Ok(())
     }
 
+    /// Use a HuggingFace authentication token for this client only.
+    ///
+    /// Unlike [`Self::set_auth_token`], nothing is written to disk; the token
+    /// replaces any found on disk or in `HF_TOKEN` until it is set or cleared.
+    pub async fn set_session_auth_token(&self, token: &str) {
+        *self.auth_token.write().await = Some(token.trim().to_string());
+        self.session_auth_token.store(true, Ordering::Relaxed);
+    }
+
     /// Clear the HuggingFace authentication token.
     ///
     /// Removes the persisted token file and clears the in-memory value.
@@ -219,6 +326,7 @@ impl HuggingFaceClient {
                 PumasError::Other(format!("Failed to join clear_auth_token task: {}", e))
             })??;
         *self.auth_token.write().await = None;
+        self.session_auth_token.store(false, Ordering::Relaxed);
         info!("HuggingFace auth token cleared");
         Ok(())
     }
@@ -277,6 +385,9 @@ impl HuggingFaceClient {
 
     /// Determine where the current token was resolved from.
     async fn resolve_token_source(&self) -> String {
+        if self.session_auth_token.load(Ordering::Relaxed) {
+            return "session".to_string();
+        }
         if let Ok(path) = auth::hf_token_path() {
             if tokio::fs::try_exists(&path).await.unwrap_or(false) {
                 return "pumas_config".to_string();
@@ -423,6 +534,19 @@ mod tests {
             .contains("TheBloke_Llama-2-7B-GGUF"));
     }
 
+    #[tokio::test]
+    async fn test_session_auth_token_is_used_without_persisting() {
+        let (_temp, client) = setup();
+
+        client.set_session_auth_token(" hf_session ").await;
+
+        assert_eq!(
+            client.auth_header_value().await.as_deref(),
+            Some("Bearer hf_session")
+        );
+        assert_eq!(client.resolve_token_source().await, "session");
+    }
+
     #[tokio::test]
     async fn test_search_converts_results() {
         // This test verifies the conversion logic without making actual API calls
@@ -0,0 +1,311 @@
+//! Priority queue limiting how many downloads transfer at once.
+//!
+//! Each download task takes a [`QueueTicket`] when it is created and waits for
+//! the ticket to become a [`DownloadSlot`] before transferring any bytes.
+//! Waiting tickets start in priority order, and in request order within a
+//! priority, while fewer than `max_active` slots are held. Dropping a slot or
+//! an unstarted ticket frees its place and wakes the remaining waiters.
+
+use crate::model_library::types::DownloadPriority;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::watch;
+
+/// A download waiting for a slot.
+struct WaitingEntry {
+    seq: u64,
+    download_id: String,
+    priority: DownloadPriority,
+}
+
+struct QueueState {
+    max_active: usize,
+    /// Tickets holding a slot, by sequence number.
+    active: HashSet<u64>,
+    /// Waiting tickets, kept sorted by priority then sequence number.
+    waiting: Vec<WaitingEntry>,
+    next_seq: u64,
+}
+
+impl QueueState {
+    fn sort_waiting(&mut self) {
+        self.waiting
+            .sort_by_key(|entry| (entry.priority, entry.seq));
+    }
+
+    fn waiting_index(&self, seq: u64) -> Option<usize> {
+        self.waiting.iter().position(|entry| entry.seq == seq)
+    }
+
+    fn free_slots(&self) -> usize {
+        self.max_active.saturating_sub(self.active.len())
+    }
+}
+
+/// Shared download queue for one [`super::HuggingFaceClient`].
+pub(super) struct DownloadQueue {
+    state: Mutex<QueueState>,
+    /// Bumped on every change so waiting tickets re-check their place.
+    changes: watch::Sender<u64>,
+}
+
+impl DownloadQueue {
+    /// Create a queue that runs at most `max_active` downloads at once.
+    pub(super) fn new(max_active: usize) -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                max_active: max_active.max(1),
+                active: HashSet::new(),
+                waiting: Vec::new(),
+                next_seq: 0,
+            }),
+            changes: watch::channel(0).0,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Wake every waiting ticket so it re-checks its place and flags.
+    pub(super) fn notify(&self) {
+        self.changes
+            .send_modify(|version| *version = version.wrapping_add(1));
+    }
+
+    /// Add a download to the back of its priority band.
+    pub(super) fn enqueue(
+        self: &Arc<Self>,
+        download_id: &str,
+        priority: DownloadPriority,
+    ) -> QueueTicket {
+        let seq = {
+            let mut state = self.lock();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiting.push(WaitingEntry {
+                seq,
+                download_id: download_id.to_string(),
+                priority,
+            });
+            state.sort_waiting();
+            seq
+        };
+        self.notify();
+        QueueTicket {
+            queue: self.clone(),
+            seq,
+            changes: self.changes.subscribe(),
+            started: false,
+        }
+    }
+
+    /// Change the priority of a waiting download.
+    ///
+    /// Returns `false` when the download is not waiting for a slot.
+    pub(super) fn set_priority(&self, download_id: &str, priority: DownloadPriority) -> bool {
+        let found = {
+            let mut state = self.lock();
+            let mut found = false;
+            for entry in state
+                .waiting
+                .iter_mut()
+                .filter(|entry| entry.download_id == download_id)
+            {
+                entry.priority = priority;
+                found = true;
+            }
+            state.sort_waiting();
+            found
+        };
+        if found {
+            self.notify();
+        }
+        found
+    }
+
+    /// Change how many downloads may run at once. Values below 1 are raised
+    /// to 1. Lowering the limit does not stop downloads already running.
+    pub(super) fn set_max_active(&self, max_active: usize) {
+        self.lock().max_active = max_active.max(1);
+        self.notify();
+    }
+
+    /// Maximum number of downloads that run at once.
+    pub(super) fn max_active(&self) -> usize {
+        self.lock().max_active
+    }
+}
+
+/// A download's place in the queue, held until it starts or is dropped.
+pub(super) struct QueueTicket {
+    queue: Arc<DownloadQueue>,
+    seq: u64,
+    changes: watch::Receiver<u64>,
+    started: bool,
+}
+
+impl QueueTicket {
+    /// 1-based place in line, or `None` if a slot is free for this ticket.
+    pub(super) fn blocked_position(&self) -> Option<usize> {
+        let state = self.queue.lock();
+        let index = state.waiting_index(self.seq)?;
+        (index >= state.free_slots()).then_some(index + 1)
+    }
+
+    /// Take a slot if one is free for this ticket, otherwise return its
+    /// 1-based place in line.
+    pub(super) fn try_start(&mut self) -> std::result::Result<DownloadSlot, usize> {
+        self.changes.borrow_and_update();
+        {
+            let mut state = self.queue.lock();
+            let index = state.waiting_index(self.seq).unwrap_or(0);
+            if index >= state.free_slots() {
+                return Err(index + 1);
+            }
+            state.waiting.retain(|entry| entry.seq != self.seq);
+            state.active.insert(self.seq);
+        }
+        self.started = true;
+        self.queue.notify();
+        Ok(DownloadSlot {
+            queue: self.queue.clone(),
+            seq: self.seq,
+        })
+    }
+
+    /// Wait until the queue changes.
+    pub(super) async fn changed(&mut self) {
+        if self.changes.changed().await.is_err() {
+            // The queue outlives its tickets, so the sender is never dropped
+            // while a ticket waits; avoid spinning if it somehow is.
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+impl Drop for QueueTicket {
+    fn drop(&mut self) {
+        if self.started {
+            return;
+        }
+        self.queue
+            .lock()
+            .waiting
+            .retain(|entry| entry.seq != self.seq);
+        self.queue.notify();
+    }
+}
+
+/// A running download's claim on one of the queue's active slots.
+pub(super) struct DownloadSlot {
+    queue: Arc<DownloadQueue>,
+    seq: u64,
+}
+
+impl Drop for DownloadSlot {
+    fn drop(&mut self) {
+        self.queue.lock().active.remove(&self.seq);
+        self.queue.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tickets_start_up_to_max_active() {
+        let queue = Arc::new(DownloadQueue::new(2));
+        let mut first = queue.enqueue("a", DownloadPriority::Normal);
+        let mut second = queue.enqueue("b", DownloadPriority::Normal);
+        let mut third = queue.enqueue("c", DownloadPriority::Normal);
+
+        assert_eq!(third.blocked_position(), Some(3));
+        let _a = first.try_start().expect("first slot");
+        let _b = second.try_start().expect("second slot");
+        assert_eq!(third.try_start().err(), Some(1));
+    }
+
+    #[test]
+    fn test_higher_priority_starts_first() {
+        let queue = Arc::new(DownloadQueue::new(1));
+        let mut running = queue.enqueue("running", DownloadPriority::Normal);
+        let slot = running.try_start().expect("slot");
+
+        let mut low = queue.enqueue("low", DownloadPriority::Low);
+        let mut normal = queue.enqueue("normal", DownloadPriority::Normal);
+        let mut high = queue.enqueue("high", DownloadPriority::High);
+        assert_eq!(high.blocked_position(), Some(1));
+        assert_eq!(normal.blocked_position(), Some(2));
+        assert_eq!(low.blocked_position(), Some(3));
+
+        drop(slot);
+        assert_eq!(low.try_start().err(), Some(3));
+        assert_eq!(normal.try_start().err(), Some(2));
+        let _high = high.try_start().expect("high starts first");
+        assert_eq!(normal.try_start().err(), Some(1));
+    }
+
+    #[test]
+    fn test_set_priority_moves_waiting_ticket() {
+        let queue = Arc::new(DownloadQueue::new(1));
+        let mut running = queue.enqueue("running", DownloadPriority::Normal);
+        let _slot = running.try_start().expect("slot");
+        let first = queue.enqueue("first", DownloadPriority::Normal);
+        let second = queue.enqueue("second", DownloadPriority::Normal);
+
+        assert!(queue.set_priority("second", DownloadPriority::High));
+        assert_eq!(second.blocked_position(), Some(1));
+        assert_eq!(first.blocked_position(), Some(2));
+        assert!(!queue.set_priority("running", DownloadPriority::Low));
+    }
+
+    #[test]
+    fn test_dropped_ticket_gives_up_its_place() {
+        let queue = Arc::new(DownloadQueue::new(1));
+        let mut running = queue.enqueue("running", DownloadPriority::Normal);
+        let _slot = running.try_start().expect("slot");
+        let first = queue.enqueue("first", DownloadPriority::Normal);
+        let second = queue.enqueue("second", DownloadPriority::Normal);
+
+        drop(first);
+        assert_eq!(second.blocked_position(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_released_slot_wakes_waiting_ticket() {
+        let queue = Arc::new(DownloadQueue::new(1));
+        let mut running = queue.enqueue("running", DownloadPriority::Normal);
+        let slot = running.try_start().expect("slot");
+        let mut waiting = queue.enqueue("waiting", DownloadPriority::Normal);
+        assert!(waiting.try_start().is_err());
+
+        let handle = tokio::spawn(async move {
+            loop {
+                if let Ok(slot) = waiting.try_start() {
+                    return slot;
+                }
+                waiting.changed().await;
+            }
+        });
+        drop(slot);
+        let _slot = tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+            .await
+            .expect("waiting ticket should start")
+            .expect("task should not panic");
+    }
+
+    #[test]
+    fn test_raising_max_active_frees_slots() {
+        let queue = Arc::new(DownloadQueue::new(1));
+        let mut running = queue.enqueue("running", DownloadPriority::Normal);
+        let _slot = running.try_start().expect("slot");
+        let mut waiting = queue.enqueue("waiting", DownloadPriority::Normal);
+        assert!(waiting.try_start().is_err());
+
+        queue.set_max_active(2);
+        assert_eq!(queue.max_active(), 2);
+        assert!(waiting.try_start().is_ok());
+    }
+}
@@ -10,7 +10,7 @@ use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::sync::Arc;
 
 /// HuggingFace API base URL.
@@ -66,6 +66,25 @@ pub struct AuxFilesCompleteInfo {
 /// Callback invoked when auxiliary files finish downloading (before weight files begin).
 pub type AuxFilesCompleteCallback = Arc<dyn Fn(AuxFilesCompleteInfo) + Send + Sync + 'static>;
 
+/// Generator for new download IDs. Defaults to random UUIDv4 strings; tests can
+/// install a deterministic sequence.
+pub type DownloadIdGenerator = Arc<dyn Fn() -> String + Send + Sync + 'static>;
+
+/// Download counters accumulated over a client's lifetime.
+#[derive(Debug, Default)]
+pub(crate) struct DownloadSessionCounters {
+    /// Bytes received across all downloads.
+    pub bytes_downloaded: AtomicU64,
+    /// File attempts started after a failed attempt.
+    pub retries: AtomicU64,
+    /// Attempts stopped by the stall timeout.
+    pub stalls: AtomicU64,
+    /// Downloads that finished every file.
+    pub completed: AtomicU64,
+    /// Downloads that ended in `Error`.
+    pub failed: AtomicU64,
+}
+
 /// A single file to download as part of a (possibly multi-file) model download.
 #[derive(Debug, Clone)]
 pub(crate) struct FileToDownload {
@@ -104,6 +123,12 @@ pub(crate) struct DownloadState {
     pub retrying: bool,
     /// Delay (seconds) until the next retry, when `retrying` is true.
     pub next_retry_delay_seconds: Option<f64>,
+    /// 1-based place in the download queue while waiting for a slot.
+    pub queue_position: Option<u32>,
+    /// Whether the last attempt stopped because no bytes arrived in time.
+    pub stalled: bool,
+    /// Repo files left out by the request's include/exclude patterns.
+    pub skipped_files: Vec<String>,
     /// Whether a Tokio task was registered for this in-memory download.
     pub task_registered: bool,
     /// Destination directory (needed for resume after restart)
@@ -186,6 +211,9 @@ impl DownloadState {
             retry_limit: None,
             retrying: false,
             next_retry_delay_seconds: None,
+            queue_position: None,
+            stalled: false,
+            skipped_files: Vec::new(),
             task_registered: false,
             dest_dir: entry.dest_dir.clone(),
             filename: entry.filename.clone(),
@@ -8,6 +8,7 @@
 //!
 //! See CACHING.md for detailed documentation.
 
+use crate::clock::{system_clock, SharedClock};
 use crate::error::{PumasError, Result};
 use crate::models::{DownloadOption, HuggingFaceModel};
 use rusqlite::{params, Connection, OptionalExtension};
@@ -134,6 +135,8 @@ pub struct HfSearchCache {
     conn: Arc<Mutex<Connection>>,
     /// Cache configuration.
     config: HfCacheConfig,
+    /// Time source for freshness checks.
+    clock: SharedClock,
 }
 
 impl HfSearchCache {
@@ -165,6 +168,7 @@ impl HfSearchCache {
         let cache = Self {
             conn: Arc::new(Mutex::new(conn)),
             config,
+            clock: system_clock(),
         };
 
         cache.init_schema()?;
@@ -174,6 +178,12 @@ impl HfSearchCache {
         Ok(cache)
     }
 
+    /// Use `clock` instead of system time for TTL and refresh checks.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Initialize database schema.
     fn init_schema(&self) -> Result<()> {
         let conn = self.conn.lock().map_err(|e| PumasError::Database {
@@ -457,7 +467,9 @@ impl HfSearchCache {
                 source: None,
             })?;
 
-        let age = chrono::Utc::now()
+        let age = self
+            .clock
+            .now()
             .signed_duration_since(searched_at)
             .num_seconds() as u64;
 
@@ -478,7 +490,7 @@ impl HfSearchCache {
 
         // Get details for each repo
         let mut models = Vec::with_capacity(repo_ids.len());
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         for repo_id in &repo_ids {
             if let Some(details) = self.get_repo_details_internal(&conn, repo_id, &now)? {
@@ -556,7 +568,7 @@ impl HfSearchCache {
 
         let cached_at = chrono::DateTime::parse_from_rfc3339(&row.11)
             .map(|dt| dt.with_timezone(&chrono::Utc))
-            .unwrap_or_else(|_| chrono::Utc::now());
+            .unwrap_or_else(|_| self.clock.now());
 
         Ok(Some(CachedRepoDetails {
             repo_id: row.0,
@@ -581,7 +593,7 @@ impl HfSearchCache {
             source: None,
         })?;
 
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
         self.get_repo_details_internal(&conn, repo_id, &now)
     }
 
@@ -600,7 +612,9 @@ impl HfSearchCache {
         };
 
         // Check age threshold
-        let age = chrono::Utc::now()
+        let age = self
+            .clock
+            .now()
             .signed_duration_since(cached.cached_at)
             .num_seconds() as u64;
 
@@ -639,7 +653,7 @@ impl HfSearchCache {
             message: format!("Failed to serialize repo IDs: {}", e),
             source: None,
         })?;
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         let conn = self.conn.lock().map_err(|e| PumasError::Database {
             message: format!("Failed to lock database: {}", e),
@@ -691,7 +705,7 @@ impl HfSearchCache {
         let download_options_json =
             serde_json::to_string(download_options).unwrap_or_else(|_| "[]".into());
 
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = self.clock.now().to_rfc3339();
 
         // Estimate data size for LRU tracking
         let data_size = formats_json.len()
@@ -1007,6 +1021,33 @@ mod tests {
         assert_eq!(results.unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_search_results_expire_with_clock() {
+        let clock = crate::clock::ManualClock::default();
+        let (_temp, cache) = create_test_cache();
+        let cache = cache.with_clock(Arc::new(clock.clone()));
+        cache
+            .cache_repo_details(&create_test_model("test/model1"))
+            .unwrap();
+        let repo_ids = vec!["test/model1".to_string()];
+        cache
+            .cache_search_results("test query", None, 25, 0, &repo_ids)
+            .unwrap();
+        let ttl = cache.get_config().unwrap().search_ttl_seconds;
+
+        clock.advance(std::time::Duration::from_secs(ttl));
+        assert!(cache
+            .get_search_results("test query", None, 25, 0)
+            .unwrap()
+            .is_some());
+
+        clock.advance(std::time::Duration::from_secs(1));
+        assert!(cache
+            .get_search_results("test query", None, 25, 0)
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_needs_refresh() {
         let (_temp, cache) = create_test_cache();
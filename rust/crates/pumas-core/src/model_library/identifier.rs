@@ -174,6 +174,20 @@ fn detect_format(header: &[u8], extension: &str) -> FileFormat {
     }
 }
 
+/// Format a file's extension declares, if it names exactly one format.
+///
+/// `.bin` is excluded because it is used for both GGML and PyTorch checkpoints.
+pub(crate) fn declared_format(path: &Path) -> Option<FileFormat> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "gguf" => Some(FileFormat::Gguf),
+        "safetensors" => Some(FileFormat::Safetensors),
+        "pt" | "pth" | "ckpt" => Some(FileFormat::Pickle),
+        "onnx" => Some(FileFormat::Onnx),
+        _ => None,
+    }
+}
+
 /// Identify GGUF model details.
 fn identify_gguf<R: Read + Seek>(file: &mut R, path: &Path) -> Result<ModelTypeInfo> {
     // GGUF header format:
@@ -3,21 +3,23 @@
 //! Handles importing local model files into the canonical library structure
 //! with content-based type detection and integrity verification.
 
+use crate::cancel::CancellationToken;
 use crate::error::{PumasError, Result};
+use crate::model_library::download_store::DownloadPersistence;
 use crate::model_library::external_assets::{
     build_diffusers_bundle_metadata, build_external_diffusers_metadata,
     validate_diffusers_directory_for_import, DiffusersBundleMetadataSpec,
     DiffusersValidationResult,
 };
-use crate::model_library::hashing::{compute_dual_hash, DualHash};
-use crate::model_library::identifier::{identify_model_type, ModelTypeInfo};
+use crate::model_library::hashing::{compute_dual_hash, DualHash, DualHasher};
+use crate::model_library::identifier::{declared_format, identify_model_type, ModelTypeInfo};
 use crate::model_library::library::ModelLibrary;
-use crate::model_library::naming::{normalize_filename, normalize_name};
+use crate::model_library::naming::{disambiguated_name, normalize_filename, normalize_name};
 use crate::model_library::sharding;
 use crate::model_library::types::{
-    BatchImportProgress, ExternalDiffusersImportSpec, HuggingFaceEvidence, ImportStage,
-    ModelFileInfo, ModelHashes, ModelImportResult, ModelImportSpec, ModelMetadata, ModelType,
-    SecurityTier,
+    BatchImportProgress, ExternalDiffusersImportSpec, FileFormat, HuggingFaceEvidence,
+    ImportSpecValidation, ImportStage, ModelFileInfo, ModelHashes, ModelImportResult,
+    ModelImportSpec, ModelMetadata, ModelReadiness, ModelType, SecurityTier,
 };
 use crate::model_library::{
     apply_download_artifact_metadata, normalize_artifact_path_slug, normalize_task_signature,
@@ -25,8 +27,10 @@ use crate::model_library::{
     AuxFilesCompleteInfo, DownloadCompletionInfo, SelectedArtifactIdentity,
     TaskNormalizationStatus,
 };
-use crate::models::resolve_inference_settings;
+use crate::models::{resolve_inference_settings, DedupStrategy};
+use crate::platform::{extended_length_path, is_cross_filesystem, reflink_file};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -70,8 +74,257 @@ use walkdir::WalkDir;
 /// Prefix for temporary import directories.
 const TEMP_IMPORT_PREFIX: &str = ".tmp_import_";
 
+/// Import error for a source directory whose download has not finished.
+const DOWNLOAD_IN_PROGRESS_ERROR: &str =
+    "Directory contains a download that is still in progress; import it after the download completes.";
+
+/// Import error for a destination that already holds the incoming model.
+const MODEL_EXISTS_ERROR: &str = "Model already exists at this location";
+
+mod progress;
 mod recovery;
 
+pub use progress::{
+    BatchImportTracker, ImportJobProgress, ImportProgressReporter, ImportProgressTracker,
+};
+
+/// Chunk size for copies that report progress.
+const PROGRESS_COPY_CHUNK: usize = 8 * 1024 * 1024;
+
+/// Total size of the regular files an import of `source` will copy.
+fn source_size(source: &Path) -> u64 {
+    WalkDir::new(source)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Optional progress reporting and cancellation threaded through one import.
+#[derive(Debug, Clone, Default)]
+struct ImportHooks {
+    progress: Option<ImportProgressReporter>,
+    cancel: Option<CancellationToken>,
+}
+
+impl ImportHooks {
+    fn set_stage(&self, stage: ImportStage) {
+        if let Some(progress) = &self.progress {
+            progress.set_stage(stage);
+        }
+    }
+
+    fn check_cancelled(&self) -> Result<()> {
+        match &self.cancel {
+            Some(cancel) if cancel.is_cancelled() => Err(PumasError::ImportCancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Decisions an import makes before writing anything.
+struct ImportPlan {
+    source_path: PathBuf,
+    type_info: ModelTypeInfo,
+    security_tier: SecurityTier,
+    bundle_validation: Option<DiffusersValidationResult>,
+    model_type: String,
+    family: String,
+}
+
+impl ImportPlan {
+    fn is_valid_diffusers_bundle(&self) -> bool {
+        self.bundle_validation.as_ref().is_some_and(|validation| {
+            validation.validation_state == crate::models::AssetValidationState::Valid
+        })
+    }
+}
+
+/// Outcome of [`ModelImporter::plan_import`].
+enum PlannedImport {
+    /// The source directory belongs to a download that has not finished.
+    DownloadInProgress,
+    Ready(Box<ImportPlan>),
+}
+
+/// How a copy import tells an existing same-named model apart from the
+/// incoming one when repo IDs cannot decide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollisionCheck {
+    /// Compare the primary file's SHA256 with the recorded hash.
+    Hash,
+    /// Compare primary file sizes only, so nothing is read. Used by dry runs.
+    Size,
+}
+
+/// Library directory chosen for a copy import.
+enum ImportTarget {
+    /// Unused directory, the cleaned name that maps to it, and directories
+    /// skipped because they hold a different model.
+    Free {
+        dir: PathBuf,
+        cleaned_name: String,
+        skipped: Vec<PathBuf>,
+    },
+    /// Directory that already holds this model.
+    Existing(PathBuf),
+}
+
+/// Files written by [`ModelImporter::copy_files`].
+struct CopiedFiles {
+    files: Vec<ModelFileInfo>,
+    /// Hashes of the primary file.
+    hashes: Option<DualHash>,
+    /// Model whose primary file the new primary was linked to.
+    shares_storage_with: Option<String>,
+}
+
+/// Copy one file, reporting copied bytes and checking for cancellation
+/// between chunks when hooks are present. With a `hasher`, every chunk is
+/// also hashed on its way to `dest`, so the data is read only once.
+fn copy_file(
+    source: &Path,
+    dest: &Path,
+    hooks: &ImportHooks,
+    mut hasher: Option<&mut DualHasher>,
+) -> Result<()> {
+    let (source, dest) = (&extended_length_path(source), &extended_length_path(dest));
+    if hooks.progress.is_none() && hooks.cancel.is_none() && hasher.is_none() {
+        std::fs::copy(source, dest)?;
+        return Ok(());
+    }
+
+    use std::io::{Read, Write};
+    let mut reader = std::fs::File::open(source)?;
+    let mut writer = std::fs::File::create(dest)?;
+    let mut buffer = vec![0u8; PROGRESS_COPY_CHUNK];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hooks.check_cancelled()?;
+        writer.write_all(&buffer[..read])?;
+        if let Some(hasher) = hasher.as_deref_mut() {
+            hasher.update(&buffer[..read]);
+        }
+        if let Some(progress) = &hooks.progress {
+            progress.add_bytes(read as u64);
+        }
+    }
+    writer.flush()?;
+    std::fs::set_permissions(dest, std::fs::metadata(source)?.permissions())?;
+    Ok(())
+}
+
+/// Link one file into the library for a by-reference import.
+///
+/// Prefers a symlink so the reference works across drives, falling back to a
+/// hardlink. Never copies: a reference import that cannot link fails instead.
+fn link_file(source: &Path, dest: &Path, hooks: &ImportHooks) -> Result<()> {
+    let source = source
+        .canonicalize()
+        .map_err(|err| PumasError::io_with_path(err, source))?;
+    let dest = &extended_length_path(dest);
+
+    #[cfg(unix)]
+    let symlinked = std::os::unix::fs::symlink(&source, dest).is_ok();
+    #[cfg(windows)]
+    let symlinked = std::os::windows::fs::symlink_file(&source, dest).is_ok();
+    #[cfg(not(any(unix, windows)))]
+    let symlinked = false;
+
+    if !symlinked {
+        if is_cross_filesystem(&source, dest).unwrap_or(false) {
+            return Err(PumasError::Other(format!(
+                "Cannot reference {} from the library: symlinks are unavailable and hardlinks cannot cross filesystems",
+                source.display()
+            )));
+        }
+        std::fs::hard_link(&source, dest).map_err(|err| PumasError::io_with_path(err, dest))?;
+    }
+    if let Some(progress) = &hooks.progress {
+        progress.add_bytes(std::fs::metadata(&source)?.len());
+    }
+    Ok(())
+}
+
+/// Store `dest` as a reflink or hardlink of `existing`, per `strategy`.
+///
+/// Returns false when `strategy` is [`DedupStrategy::Copy`] or no link could
+/// be made; the caller then copies instead.
+fn share_file(existing: &Path, dest: &Path, strategy: DedupStrategy) -> bool {
+    let dest = &extended_length_path(dest);
+    let reflink = || reflink_file(existing, dest);
+    let hardlink = || std::fs::hard_link(existing, dest);
+    let result = match strategy {
+        DedupStrategy::Copy => return false,
+        DedupStrategy::Reflink => reflink(),
+        DedupStrategy::Hardlink => hardlink(),
+        DedupStrategy::Auto => reflink().or_else(|_| hardlink()),
+    };
+    if let Err(err) = result {
+        tracing::warn!(
+            "Could not share storage with {} ({:?}), copying instead: {}",
+            existing.display(),
+            strategy,
+            err
+        );
+        return false;
+    }
+    true
+}
+
+/// Move a finished temp import directory to its library location.
+async fn move_into_library(temp_dir: &Path, target_dir: &Path) -> std::io::Result<()> {
+    let target_dir = extended_length_path(target_dir);
+    if let Some(parent) = target_dir.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::rename(extended_length_path(temp_dir), &target_dir).await
+}
+
+/// Reject a primary file whose content does not match the format its extension
+/// declares.
+///
+/// A pickle under another extension is let through only when the import
+/// acknowledges the security risk; the pickle gate then applies as usual.
+fn check_declared_format(
+    file: &Path,
+    detected: FileFormat,
+    security_acknowledged: bool,
+) -> Result<()> {
+    let Some(declared) = declared_format(file) else {
+        return Ok(());
+    };
+    if detected == declared || detected == FileFormat::Unknown {
+        return Ok(());
+    }
+    if detected == FileFormat::Pickle && security_acknowledged {
+        return Ok(());
+    }
+    Err(PumasError::InvalidFileType {
+        expected: declared.as_str().to_string(),
+        actual: detected.as_str().to_string(),
+    })
+}
+
+/// Refuse a pickle-tier import unless the spec acknowledges the risk.
+fn check_security_acknowledged(security_tier: SecurityTier, spec: &ModelImportSpec) -> Result<()> {
+    if security_tier == SecurityTier::Pickle && !spec.security_acknowledged.unwrap_or(false) {
+        return Err(PumasError::Validation {
+            field: "security_acknowledged".to_string(),
+            message: format!(
+                "{} is a pickle file, which can execute arbitrary code when loaded. Set security_acknowledged=true to import it anyway.",
+                spec.path
+            ),
+        });
+    }
+    Ok(())
+}
+
 fn join_validation_errors(errors: &[crate::models::AssetValidationError]) -> String {
     errors
         .iter()
@@ -112,6 +365,29 @@ async fn path_is_dir(path: &Path) -> Result<bool> {
     }
 }
 
+/// First shard set with missing parts, as `(base_name, found, total)`.
+///
+/// Uses `extract_shard_info` per file to catch even single-shard-of-set cases
+/// (which `detect_sharded_sets` would treat as standalone). Only the first
+/// sharded file is checked, matching one shard set per model directory.
+fn find_incomplete_shard_set(files: &[ModelFileInfo]) -> Option<(String, usize, usize)> {
+    let (base_name, total) = files.iter().find_map(
+        |file_info| match sharding::extract_shard_info(&file_info.name) {
+            Some((base_name, _idx, Some(total))) if total > 1 => Some((base_name, total)),
+            _ => None,
+        },
+    )?;
+    let found_count = files
+        .iter()
+        .filter(|f| {
+            sharding::extract_shard_info(&f.name)
+                .map(|(b, _, _)| b == base_name)
+                .unwrap_or(false)
+        })
+        .count();
+    (found_count < total).then_some((base_name, found_count, total))
+}
+
 async fn resolve_model_type_with_rules_async(
     index: crate::index::ModelIndex,
     model_dir: PathBuf,
@@ -163,6 +439,10 @@ async fn load_model_metadata_or_default(
 pub struct ModelImporter {
     /// Reference to the model library
     library: Arc<ModelLibrary>,
+    /// Download store used to leave in-progress download directories alone
+    download_persistence: Option<Arc<DownloadPersistence>>,
+    /// Shutdown token that stops orphan adoption between directories
+    shutdown: Option<CancellationToken>,
 }
 
 impl ModelImporter {
@@ -172,7 +452,35 @@ impl ModelImporter {
     ///
     /// * `library` - Reference to the model library
     pub fn new(library: Arc<ModelLibrary>) -> Self {
-        Self { library }
+        Self {
+            library,
+            download_persistence: None,
+            shutdown: None,
+        }
+    }
+
+    /// Skip directories that belong to downloads tracked in `persistence`.
+    ///
+    /// Orphan adoption and directory imports leave a download's destination
+    /// alone until it completes or is cancelled.
+    pub fn with_download_persistence(mut self, persistence: Arc<DownloadPersistence>) -> Self {
+        self.download_persistence = Some(persistence);
+        self
+    }
+
+    /// Stop orphan adoption once `token` is cancelled.
+    ///
+    /// The directory being adopted finishes, so its metadata is never left
+    /// half-written; remaining directories are skipped.
+    pub fn with_shutdown_token(mut self, token: CancellationToken) -> Self {
+        self.shutdown = Some(token);
+        self
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutdown
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
     }
 
     /// Import a single model file or directory.
@@ -184,6 +492,114 @@ impl ModelImporter {
     ///
     /// * `spec` - Import specification with path and metadata hints
     pub async fn import(&self, spec: &ModelImportSpec) -> Result<ModelImportResult> {
+        self.import_inner(spec, &ImportHooks::default()).await
+    }
+
+    /// Import a single model, publishing stage and copied bytes to `progress`.
+    ///
+    /// Behaves exactly like [`import`](Self::import). The caller records the
+    /// final result with [`ImportProgressReporter::finish`].
+    pub async fn import_tracked(
+        &self,
+        spec: &ModelImportSpec,
+        progress: &ImportProgressReporter,
+    ) -> Result<ModelImportResult> {
+        let hooks = ImportHooks {
+            progress: Some(progress.clone()),
+            cancel: None,
+        };
+        self.import_inner(spec, &hooks).await
+    }
+
+    /// Check what importing `spec` would do, without writing anything.
+    ///
+    /// Runs the same checks as [`import`](Self::import) up to choosing the
+    /// library directory: the source must exist and not be a live download,
+    /// its content must match its extension, pickle files need
+    /// `security_acknowledged`, and the name must map to a free directory.
+    /// Problems are collected in the report instead of returned as errors, so
+    /// a confirmation step can show all of them at once.
+    ///
+    /// Nothing is copied, hashed, or created. A same-named model without a
+    /// repo ID to compare is told apart by primary file size instead of
+    /// SHA256, so an import may still disambiguate a model this reports as
+    /// already existing.
+    pub async fn validate_spec(&self, spec: &ModelImportSpec) -> Result<ImportSpecValidation> {
+        let mut report = ImportSpecValidation {
+            path: spec.path.clone(),
+            ..Default::default()
+        };
+
+        let plan = match self.plan_import(spec).await {
+            Ok(PlannedImport::Ready(plan)) => plan,
+            Ok(PlannedImport::DownloadInProgress) => {
+                report.errors.push(DOWNLOAD_IN_PROGRESS_ERROR.to_string());
+                return Ok(report);
+            }
+            Err(err) => {
+                report.errors.push(err.to_string());
+                return Ok(report);
+            }
+        };
+        report.model_type = Some(plan.model_type.clone());
+        report.family = Some(plan.family.clone());
+        report.file_format = Some(plan.type_info.format.as_str().to_string());
+        report.security_tier = Some(plan.security_tier);
+        if let Err(err) = check_security_acknowledged(plan.security_tier, spec) {
+            report.errors.push(err.to_string());
+        }
+
+        if plan.is_valid_diffusers_bundle() && spec.by_reference.unwrap_or(false) {
+            // Mirrors import_external_diffusers_directory, which never disambiguates.
+            let cleaned_name = normalize_name(&spec.official_name);
+            let dir = self
+                .library
+                .build_model_path("diffusion", &spec.family, &cleaned_name);
+            if tokio::fs::try_exists(&dir).await? {
+                report.already_exists = true;
+                report.conflicts.push(dir.display().to_string());
+                report.errors.push(MODEL_EXISTS_ERROR.to_string());
+            }
+            report.destination = Some(dir.display().to_string());
+            report.cleaned_name = Some(cleaned_name);
+        } else {
+            match self
+                .resolve_import_target(
+                    &plan.source_path,
+                    spec,
+                    &plan.model_type,
+                    &plan.family,
+                    !plan.is_valid_diffusers_bundle(),
+                    CollisionCheck::Size,
+                )
+                .await?
+            {
+                ImportTarget::Free {
+                    dir,
+                    cleaned_name,
+                    skipped,
+                } => {
+                    report.destination = Some(dir.display().to_string());
+                    report.cleaned_name = Some(cleaned_name);
+                    report
+                        .conflicts
+                        .extend(skipped.iter().map(|dir| dir.display().to_string()));
+                }
+                ImportTarget::Existing(dir) => {
+                    report.already_exists = true;
+                    report.destination = Some(dir.display().to_string());
+                    report.conflicts.push(dir.display().to_string());
+                    report.errors.push(MODEL_EXISTS_ERROR.to_string());
+                }
+            }
+        }
+
+        report.valid = report.errors.is_empty();
+        Ok(report)
+    }
+
+    /// Resolve everything an import decides before touching the library.
+    async fn plan_import(&self, spec: &ModelImportSpec) -> Result<PlannedImport> {
         let source_path = PathBuf::from(&spec.path);
 
         // Validate source exists
@@ -192,31 +608,39 @@ impl ModelImporter {
         }
         let source_metadata = tokio::fs::metadata(&source_path).await?;
 
+        if source_metadata.is_dir() {
+            let importer = self.clone();
+            let source_dir = source_path.clone();
+            let downloading = tokio::task::spawn_blocking(move || {
+                recovery::is_download_in_progress(&source_dir, &importer.live_download_dirs())
+            })
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!(
+                    "Failed to join import download check task: {}",
+                    err
+                ))
+            })?;
+            if downloading {
+                return Ok(PlannedImport::DownloadInProgress);
+            }
+        }
+
         // Detect file type and model info
         let importer = self.clone();
         let source_path_for_detection = source_path.clone();
-        let type_info =
-            tokio::task::spawn_blocking(move || importer.detect_type(&source_path_for_detection))
-                .await
-                .map_err(|err| {
-                    PumasError::Other(format!(
-                        "Failed to join import type detection task: {}",
-                        err
-                    ))
-                })??;
-
-        // Check security tier
+        let security_acknowledged = spec.security_acknowledged.unwrap_or(false);
+        let type_info = tokio::task::spawn_blocking(move || {
+            importer.detect_type(&source_path_for_detection, security_acknowledged)
+        })
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!(
+                "Failed to join import type detection task: {}",
+                err
+            ))
+        })??;
         let security_tier = type_info.format.security_tier();
-        if security_tier == SecurityTier::Pickle && !spec.security_acknowledged.unwrap_or(false) {
-            return Ok(ModelImportResult {
-                path: spec.path.clone(),
-                success: false,
-                model_id: None,
-                model_path: None,
-                error: Some("Pickle files may contain malicious code. Set security_acknowledged=true to proceed.".to_string()),
-                security_tier: Some(security_tier),
-            });
-        }
 
         let bundle_validation = if source_metadata.is_dir() {
             let validation_source_path = source_path.clone();
@@ -263,22 +687,99 @@ impl ModelImporter {
                 .unwrap_or_else(|| spec.family.clone())
         };
 
-        // Build target path
-        let cleaned_name = normalize_name(&spec.official_name);
-        let target_dir = self
-            .library
-            .build_model_path(&model_type, &family, &cleaned_name);
+        Ok(PlannedImport::Ready(Box::new(ImportPlan {
+            source_path,
+            type_info,
+            security_tier,
+            bundle_validation,
+            model_type,
+            family,
+        })))
+    }
 
-        // Check if already exists
-        if tokio::fs::try_exists(&target_dir).await? {
-            return Ok(ModelImportResult {
-                path: spec.path.clone(),
-                success: false,
-                model_id: None,
-                model_path: Some(target_dir.display().to_string()),
-                error: Some("Model already exists at this location".to_string()),
-                security_tier: Some(security_tier),
-            });
+    async fn import_inner(
+        &self,
+        spec: &ModelImportSpec,
+        hooks: &ImportHooks,
+    ) -> Result<ModelImportResult> {
+        let ImportPlan {
+            source_path,
+            type_info,
+            security_tier,
+            bundle_validation,
+            model_type,
+            family,
+        } = match self.plan_import(spec).await? {
+            PlannedImport::Ready(plan) => *plan,
+            PlannedImport::DownloadInProgress => {
+                return Ok(ModelImportResult {
+                    path: spec.path.clone(),
+                    success: false,
+                    model_id: None,
+                    model_path: None,
+                    error: Some(DOWNLOAD_IN_PROGRESS_ERROR.to_string()),
+                    security_tier: None,
+                });
+            }
+        };
+
+        // Check security tier
+        check_security_acknowledged(security_tier, spec)?;
+
+        let is_valid_diffusers_bundle = bundle_validation.as_ref().is_some_and(|validation| {
+            validation.validation_state == crate::models::AssetValidationState::Valid
+        });
+
+        // Bundles already have a reference-only registration path.
+        if is_valid_diffusers_bundle && spec.by_reference.unwrap_or(false) {
+            return self
+                .import_external_diffusers_directory(&ExternalDiffusersImportSpec {
+                    source_path: spec.path.clone(),
+                    family: spec.family.clone(),
+                    official_name: spec.official_name.clone(),
+                    repo_id: spec.repo_id.clone(),
+                    tags: spec.tags.clone(),
+                })
+                .await;
+        }
+
+        // Build target path. Bundles register through in-place import, which
+        // derives the model ID from the official name, so they are not
+        // disambiguated.
+        let (target_dir, cleaned_name) = match self
+            .resolve_import_target(
+                &source_path,
+                spec,
+                &model_type,
+                &family,
+                !is_valid_diffusers_bundle,
+                CollisionCheck::Hash,
+            )
+            .await?
+        {
+            ImportTarget::Free {
+                dir, cleaned_name, ..
+            } => (dir, cleaned_name),
+            ImportTarget::Existing(dir) => {
+                return Ok(ModelImportResult {
+                    path: spec.path.clone(),
+                    success: false,
+                    model_id: None,
+                    model_path: Some(dir.display().to_string()),
+                    error: Some(MODEL_EXISTS_ERROR.to_string()),
+                    security_tier: Some(security_tier),
+                });
+            }
+        };
+
+        if let Some(progress) = &hooks.progress {
+            let source_for_size = source_path.clone();
+            let bytes_total = tokio::task::spawn_blocking(move || source_size(&source_for_size))
+                .await
+                .map_err(|err| {
+                    PumasError::Other(format!("Failed to join import size task: {}", err))
+                })?;
+            progress.set_total(bytes_total);
         }
 
         if let Some(validation) = bundle_validation.as_ref().filter(|validation| {
@@ -301,15 +802,23 @@ impl ModelImporter {
 
         // Perform the import atomically
         match self
-            .do_import(&source_path, &temp_dir, spec, &type_info)
+            .do_import(
+                &source_path,
+                &temp_dir,
+                spec,
+                &type_info,
+                &cleaned_name,
+                hooks,
+            )
             .await
         {
             Ok(_metadata) => {
                 // Atomic rename to final location
-                tokio::fs::create_dir_all(target_dir.parent().unwrap()).await?;
-                tokio::fs::rename(&temp_dir, &target_dir).await?;
+                hooks.set_stage(ImportStage::Syncing);
+                move_into_library(&temp_dir, &target_dir).await?;
 
                 // Index the imported model
+                hooks.set_stage(ImportStage::Indexing);
                 if let Err(e) = self.library.index_model_dir(&target_dir).await {
                     tracing::warn!("Failed to index imported model: {}", e);
                 }
@@ -358,7 +867,7 @@ impl ModelImporter {
                 success: false,
                 model_id: self.library.get_model_id(&target_dir),
                 model_path: None,
-                error: Some("Model already exists at this location".to_string()),
+                error: Some(MODEL_EXISTS_ERROR.to_string()),
                 security_tier: None,
             });
         }
@@ -427,8 +936,7 @@ impl ModelImporter {
             return Err(err);
         }
 
-        tokio::fs::create_dir_all(target_dir.parent().unwrap()).await?;
-        if let Err(err) = tokio::fs::rename(&temp_dir, target_dir).await {
+        if let Err(err) = move_into_library(&temp_dir, target_dir).await {
             let _ = tokio::fs::remove_dir_all(&temp_dir).await;
             return Err(PumasError::Io {
                 message: format!("failed to finalize diffusers bundle import: {}", err),
@@ -547,7 +1055,13 @@ impl ModelImporter {
         info: &DownloadCompletionInfo,
     ) -> Result<ModelImportResult> {
         let metadata_path = info.dest_dir.join("metadata.json");
+        let mut stub = None;
         if path_exists(&metadata_path).await? {
+            // Keep the row visible but not-ready while the importer rebuilds
+            // metadata from scratch.
+            stub = self
+                .set_download_stub_readiness(&info.dest_dir, ModelReadiness::Importing)
+                .await;
             tracing::info!(
                 "Removing stale metadata before re-import: {}",
                 metadata_path.display()
@@ -572,7 +1086,43 @@ impl ModelImporter {
             model_card_json: info.download_request.model_card_json.clone(),
             license_status: info.download_request.license_status.clone(),
         };
-        self.import_in_place(&spec).await
+        let result = self.import_in_place(&spec).await;
+        let failed = match &result {
+            Ok(import_result) => !import_result.success,
+            Err(_) => true,
+        };
+        if failed && !path_exists(&metadata_path).await.unwrap_or(true) {
+            if let Some(mut metadata) = stub {
+                metadata.readiness = Some(ModelReadiness::Error);
+                if let Err(err) = self.library.save_metadata(&info.dest_dir, &metadata).await {
+                    tracing::warn!("Failed to restore download metadata stub: {}", err);
+                } else if let Err(err) = self.library.index_model_dir(&info.dest_dir).await {
+                    tracing::warn!("Failed to index failed download: {}", err);
+                }
+            }
+        }
+        result
+    }
+
+    /// Rewrite an existing download stub with `readiness` and re-index it.
+    ///
+    /// Returns the updated stub, or `None` when it could not be loaded.
+    async fn set_download_stub_readiness(
+        &self,
+        model_dir: &Path,
+        readiness: ModelReadiness,
+    ) -> Option<ModelMetadata> {
+        let mut metadata =
+            load_model_metadata_or_default(self.library.clone(), model_dir.to_path_buf())
+                .await
+                .ok()?;
+        metadata.readiness = Some(readiness);
+        if let Err(err) = self.library.save_metadata(model_dir, &metadata).await {
+            tracing::warn!("Failed to update download readiness: {}", err);
+        } else if let Err(err) = self.library.index_model_dir(model_dir).await {
+            tracing::warn!("Failed to index download readiness: {}", err);
+        }
+        Some(metadata)
     }
 
     /// Persist a preliminary metadata record for a queued/partial download.
@@ -633,6 +1183,7 @@ impl ModelImporter {
             parse_model_card_json(info.download_request.model_card_json.as_deref());
         metadata.size_bytes = info.total_bytes;
         metadata.match_source = Some("download_partial".to_string());
+        metadata.readiness = Some(ModelReadiness::Downloading);
         metadata.match_method = Some("repo_id".to_string());
         metadata.match_confidence = Some(1.0);
         metadata.pending_online_lookup = Some(false);
@@ -716,28 +1267,21 @@ impl ModelImporter {
 
         let importer = self.clone();
         let source_path_for_detection = source_path.clone();
-        let type_info =
-            tokio::task::spawn_blocking(move || importer.detect_type(&source_path_for_detection))
-                .await
-                .map_err(|err| {
-                    PumasError::Other(format!(
-                        "Failed to join progress import type detection task: {}",
-                        err
-                    ))
-                })??;
+        let security_acknowledged = spec.security_acknowledged.unwrap_or(false);
+        let type_info = tokio::task::spawn_blocking(move || {
+            importer.detect_type(&source_path_for_detection, security_acknowledged)
+        })
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!(
+                "Failed to join progress import type detection task: {}",
+                err
+            ))
+        })??;
         let security_tier = type_info.format.security_tier();
 
         // Security check
-        if security_tier == SecurityTier::Pickle && !spec.security_acknowledged.unwrap_or(false) {
-            return Ok(ModelImportResult {
-                path: spec.path.clone(),
-                success: false,
-                model_id: None,
-                model_path: None,
-                error: Some("Pickle files require security acknowledgment".to_string()),
-                security_tier: Some(security_tier),
-            });
-        }
+        check_security_acknowledged(security_tier, spec)?;
 
         // Resolve through SQLite model-type mapping rules first.
         let model_type = if let Some(hint) = spec.model_type.as_deref() {
@@ -754,21 +1298,31 @@ impl ModelImporter {
             .map(|f| f.to_string())
             .unwrap_or_else(|| spec.family.clone());
 
-        let cleaned_name = normalize_name(&spec.official_name);
-        let target_dir = self
-            .library
-            .build_model_path(&model_type, &family, &cleaned_name);
-
-        if tokio::fs::try_exists(&target_dir).await? {
-            return Ok(ModelImportResult {
-                path: spec.path.clone(),
-                success: false,
-                model_id: None,
-                model_path: Some(target_dir.display().to_string()),
-                error: Some("Model already exists".to_string()),
-                security_tier: Some(security_tier),
-            });
-        }
+        let (target_dir, cleaned_name) = match self
+            .resolve_import_target(
+                &source_path,
+                spec,
+                &model_type,
+                &family,
+                true,
+                CollisionCheck::Hash,
+            )
+            .await?
+        {
+            ImportTarget::Free {
+                dir, cleaned_name, ..
+            } => (dir, cleaned_name),
+            ImportTarget::Existing(dir) => {
+                return Ok(ModelImportResult {
+                    path: spec.path.clone(),
+                    success: false,
+                    model_id: None,
+                    model_path: Some(dir.display().to_string()),
+                    error: Some("Model already exists".to_string()),
+                    security_tier: Some(security_tier),
+                });
+            }
+        };
 
         // Create temp dir
         let temp_dir = self.create_temp_import_dir().await?;
@@ -785,8 +1339,16 @@ impl ModelImporter {
         let importer = self.clone();
         let source_path_for_copy = source_path.clone();
         let temp_dir_for_copy = temp_dir.clone();
-        let files = tokio::task::spawn_blocking(move || {
-            importer.copy_files(&source_path_for_copy, &temp_dir_for_copy)
+        let by_reference = spec.by_reference.unwrap_or(false);
+        let dedup = spec.dedup.unwrap_or_default();
+        let copied = tokio::task::spawn_blocking(move || {
+            importer.copy_files(
+                &source_path_for_copy,
+                &temp_dir_for_copy,
+                &ImportHooks::default(),
+                by_reference,
+                dedup,
+            )
         })
         .await
         .map_err(|err| {
@@ -796,43 +1358,15 @@ impl ModelImporter {
             ))
         })??;
 
-        // Compute hashes
+        // The primary file was hashed during the copy.
         let _ = progress_tx
             .send(ImportProgress {
                 stage: ImportStage::Hashing,
                 progress: 0.5,
-                message: "Computing hashes".to_string(),
+                message: "Computed hashes".to_string(),
             })
             .await;
 
-        let importer = self.clone();
-        let temp_dir_for_primary = temp_dir.clone();
-        let primary_file = tokio::task::spawn_blocking(move || {
-            importer.choose_primary_file(&temp_dir_for_primary)
-        })
-        .await
-        .map_err(|err| {
-            PumasError::Other(format!(
-                "Failed to join progress import primary file selection task: {}",
-                err
-            ))
-        })??;
-        let hashes = if let Some(ref primary) = primary_file {
-            let primary_for_hash = primary.clone();
-            Some(
-                tokio::task::spawn_blocking(move || compute_dual_hash(&primary_for_hash))
-                    .await
-                    .map_err(|err| {
-                        PumasError::Other(format!(
-                            "Failed to join import hash computation task: {}",
-                            err
-                        ))
-                    })??,
-            )
-        } else {
-            None
-        };
-
         // Create metadata
         let _ = progress_tx
             .send(ImportProgress {
@@ -842,7 +1376,14 @@ impl ModelImporter {
             })
             .await;
 
-        let metadata = self.create_metadata(spec, &type_info, &files, hashes)?;
+        let mut metadata = self.create_metadata(
+            spec,
+            &type_info,
+            &cleaned_name,
+            &copied.files,
+            copied.hashes,
+        )?;
+        metadata.shares_storage_with = copied.shares_storage_with;
         self.library.save_metadata(&temp_dir, &metadata).await?;
 
         // Finalize
@@ -854,8 +1395,7 @@ impl ModelImporter {
             })
             .await;
 
-        tokio::fs::create_dir_all(target_dir.parent().unwrap()).await?;
-        tokio::fs::rename(&temp_dir, &target_dir).await?;
+        move_into_library(&temp_dir, &target_dir).await?;
 
         // Index
         let _ = progress_tx
@@ -894,42 +1434,73 @@ impl ModelImporter {
     ///
     /// * `specs` - List of import specifications
     /// * `progress_tx` - Optional channel for batch progress updates
+    /// * `cancel` - Aborts the batch when cancelled. Completed items keep their
+    ///   results; the in-flight item and every remaining item are reported as
+    ///   cancelled.
     pub async fn batch_import(
         &self,
         specs: Vec<ModelImportSpec>,
         progress_tx: Option<mpsc::Sender<BatchImportProgress>>,
+        cancel: &CancellationToken,
     ) -> Vec<ModelImportResult> {
         let total = specs.len();
         let mut results = Vec::with_capacity(total);
         let mut progress = BatchImportProgress::new(total);
+        let hooks = ImportHooks {
+            progress: None,
+            cancel: Some(cancel.clone()),
+        };
 
         for (idx, spec) in specs.into_iter().enumerate() {
+            if cancel.is_cancelled() {
+                let result = ModelImportResult {
+                    path: spec.path.clone(),
+                    success: false,
+                    model_id: None,
+                    model_path: None,
+                    error: Some(PumasError::ImportCancelled.to_string()),
+                    security_tier: None,
+                };
+                progress.record_result(result.clone());
+                results.push(result);
+                continue;
+            }
+
             // Update progress
             progress.update(idx, Some(spec.path.clone()), ImportStage::Copying);
+            progress.current_index = Some(idx);
 
             if let Some(ref tx) = progress_tx {
                 let _ = tx.send(progress.clone()).await;
             }
 
             // Import
-            let result = self
-                .import(&spec)
-                .await
-                .unwrap_or_else(|e| ModelImportResult {
-                    path: spec.path.clone(),
-                    success: false,
-                    model_id: None,
-                    model_path: None,
-                    error: Some(e.to_string()),
-                    security_tier: None,
-                });
-
-            progress.results.push(result.clone());
+            let result =
+                self.import_inner(&spec, &hooks)
+                    .await
+                    .unwrap_or_else(|e| ModelImportResult {
+                        path: spec.path.clone(),
+                        success: false,
+                        model_id: None,
+                        model_path: None,
+                        error: Some(e.to_string()),
+                        security_tier: None,
+                    });
+
+            progress.record_result(result.clone());
+            progress.update(idx + 1, Some(spec.path.clone()), progress.stage);
+            if result.success {
+                progress.stage = ImportStage::Complete;
+            }
+            if let Some(ref tx) = progress_tx {
+                let _ = tx.send(progress.clone()).await;
+            }
             results.push(result);
         }
 
         // Final progress
         progress.update(total, None, ImportStage::Complete);
+        progress.current_index = None;
         if let Some(ref tx) = progress_tx {
             let _ = tx.send(progress).await;
         }
@@ -942,19 +1513,29 @@ impl ModelImporter {
     // ========================================
 
     /// Detect file type from content.
-    fn detect_type(&self, path: &Path) -> Result<ModelTypeInfo> {
-        if path.is_file() {
-            identify_model_type(path)
+    ///
+    /// Fails with [`PumasError::InvalidFileType`] when the primary file's
+    /// content contradicts its extension (see [`check_declared_format`]).
+    fn detect_type(&self, path: &Path, security_acknowledged: bool) -> Result<ModelTypeInfo> {
+        let primary = if path.is_file() {
+            Some(path.to_path_buf())
         } else if path.is_dir() {
             // For directories, find the primary model file
-            self.find_primary_and_detect(path)
+            self.find_primary_model_file(path)
         } else {
-            Ok(ModelTypeInfo::default())
-        }
+            None
+        };
+        let Some(primary) = primary else {
+            return Ok(ModelTypeInfo::default());
+        };
+
+        let info = identify_model_type(&primary)?;
+        check_declared_format(&primary, info.format, security_acknowledged)?;
+        Ok(info)
     }
 
-    /// Find primary file in directory and detect its type.
-    fn find_primary_and_detect(&self, dir: &Path) -> Result<ModelTypeInfo> {
+    /// Find the largest model file in a directory.
+    fn find_primary_model_file(&self, dir: &Path) -> Option<PathBuf> {
         // Find the largest model file
         let mut largest: Option<(PathBuf, u64)> = None;
 
@@ -982,13 +1563,152 @@ impl ModelImporter {
             }
         }
 
-        if let Some((path, _)) = largest {
-            identify_model_type(&path)
-        } else {
-            Ok(ModelTypeInfo::default())
+        largest.map(|(path, _)| path)
+    }
+
+    /// Choose the library directory for a copy import.
+    ///
+    /// Different official names can normalize to the same directory. With
+    /// `disambiguate`, a directory already holding a different model (another
+    /// repo or primary-file hash) is skipped for `-2`, `-3`, ... suffixed
+    /// names; metadata keeps the original `official_name`. A directory that
+    /// cannot be told apart from the incoming model counts as
+    /// [`ImportTarget::Existing`]; `check` decides how they are compared.
+    async fn resolve_import_target(
+        &self,
+        source: &Path,
+        spec: &ModelImportSpec,
+        model_type: &str,
+        family: &str,
+        disambiguate: bool,
+        check: CollisionCheck,
+    ) -> Result<ImportTarget> {
+        let base_name = normalize_name(&spec.official_name);
+        let mut source_sha256 = None;
+        let mut skipped = Vec::new();
+        let mut attempt = 1;
+        loop {
+            let cleaned_name = if attempt == 1 {
+                base_name.clone()
+            } else {
+                disambiguated_name(&base_name, attempt)
+            };
+            let dir = self
+                .library
+                .build_model_path(model_type, family, &cleaned_name);
+            let library = self.library.clone();
+            let dir_for_lookup = dir.clone();
+            let colliding = tokio::task::spawn_blocking(move || {
+                library.find_colliding_model_path(&dir_for_lookup)
+            })
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!(
+                    "Failed to join import target collision check task: {}",
+                    err
+                ))
+            })?;
+            let Some(existing) = colliding else {
+                return Ok(ImportTarget::Free {
+                    dir,
+                    cleaned_name,
+                    skipped,
+                });
+            };
+            if !disambiguate
+                || !self
+                    .holds_different_model(&existing, source, spec, check, &mut source_sha256)
+                    .await?
+            {
+                return Ok(ImportTarget::Existing(existing));
+            }
+            skipped.push(existing);
+            attempt += 1;
         }
     }
 
+    /// Whether `dir` provably holds a model other than the one at `source`.
+    ///
+    /// Repo IDs decide when both sides have one; otherwise the recorded
+    /// primary-file SHA256 is compared with a hash of the source, computed once
+    /// into `source_sha256`. With [`CollisionCheck::Size`], only the largest
+    /// recorded file size is compared with the source's primary file, and
+    /// equal sizes count as the same model.
+    async fn holds_different_model(
+        &self,
+        dir: &Path,
+        source: &Path,
+        spec: &ModelImportSpec,
+        check: CollisionCheck,
+        source_sha256: &mut Option<Option<String>>,
+    ) -> Result<bool> {
+        let existing =
+            load_model_metadata_or_default(self.library.clone(), dir.to_path_buf()).await?;
+        if let (Some(existing_repo), Some(repo)) = (&existing.repo_id, &spec.repo_id) {
+            return Ok(existing_repo != repo);
+        }
+        if check == CollisionCheck::Size {
+            let existing_size = existing
+                .files
+                .iter()
+                .flatten()
+                .filter_map(|file| file.size)
+                .max();
+            let Some(existing_size) = existing_size else {
+                return Ok(false);
+            };
+            let importer = self.clone();
+            let source = source.to_path_buf();
+            let source_size = tokio::task::spawn_blocking(move || {
+                let primary = if source.is_file() {
+                    Some(source)
+                } else {
+                    importer.find_primary_model_file(&source)
+                };
+                primary.and_then(|primary| std::fs::metadata(primary).ok().map(|m| m.len()))
+            })
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!(
+                    "Failed to join import collision size task: {}",
+                    err
+                ))
+            })?;
+            return Ok(source_size.is_some_and(|size| size != existing_size));
+        }
+        let Some(existing_sha256) = existing.hashes.and_then(|hashes| hashes.sha256) else {
+            return Ok(false);
+        };
+
+        if source_sha256.is_none() {
+            let importer = self.clone();
+            let source = source.to_path_buf();
+            let hash = tokio::task::spawn_blocking(move || {
+                let primary = if source.is_file() {
+                    Some(source)
+                } else {
+                    importer.find_primary_model_file(&source)
+                };
+                primary
+                    .map(|primary| compute_dual_hash(&primary).map(|hash| hash.sha256))
+                    .transpose()
+            })
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!(
+                    "Failed to join import collision hash task: {}",
+                    err
+                ))
+            })??;
+            *source_sha256 = Some(hash);
+        }
+
+        Ok(source_sha256
+            .as_ref()
+            .and_then(Option::as_ref)
+            .is_some_and(|sha256| *sha256 != existing_sha256))
+    }
+
     /// Create a temporary directory for atomic import.
     async fn create_temp_import_dir(&self) -> Result<PathBuf> {
         let uuid = uuid::Uuid::new_v4();
@@ -1005,13 +1725,26 @@ impl ModelImporter {
         temp_dir: &Path,
         spec: &ModelImportSpec,
         type_info: &ModelTypeInfo,
+        cleaned_name: &str,
+        hooks: &ImportHooks,
     ) -> Result<ModelMetadata> {
-        // Copy files
+        // Copy (or, by reference, link) files
+        let by_reference = spec.by_reference.unwrap_or(false);
+        let dedup = spec.dedup.unwrap_or_default();
         let importer = self.clone();
         let source_for_copy = source.to_path_buf();
         let temp_dir_for_copy = temp_dir.to_path_buf();
-        let files = tokio::task::spawn_blocking(move || {
-            importer.copy_files(&source_for_copy, &temp_dir_for_copy)
+        let copy_hooks = hooks.clone();
+        // The primary file is hashed while it is copied, so the data is read
+        // once.
+        let copied = tokio::task::spawn_blocking(move || {
+            importer.copy_files(
+                &source_for_copy,
+                &temp_dir_for_copy,
+                &copy_hooks,
+                by_reference,
+                dedup,
+            )
         })
         .await
         .map_err(|err| {
@@ -1021,37 +1754,12 @@ impl ModelImporter {
             ))
         })??;
 
-        // Compute hashes for primary file
-        let importer = self.clone();
-        let temp_dir_for_primary = temp_dir.to_path_buf();
-        let primary_file = tokio::task::spawn_blocking(move || {
-            importer.choose_primary_file(&temp_dir_for_primary)
-        })
-        .await
-        .map_err(|err| {
-            PumasError::Other(format!(
-                "Failed to join temp import primary file selection task: {}",
-                err
-            ))
-        })??;
-        let hashes = if let Some(ref primary) = primary_file {
-            let primary_for_hash = primary.clone();
-            Some(
-                tokio::task::spawn_blocking(move || compute_dual_hash(&primary_for_hash))
-                    .await
-                    .map_err(|err| {
-                        PumasError::Other(format!(
-                            "Failed to join temp import hash computation task: {}",
-                            err
-                        ))
-                    })??,
-            )
-        } else {
-            None
-        };
-
         // Create metadata
-        let metadata = self.create_metadata(spec, type_info, &files, hashes)?;
+        hooks.check_cancelled()?;
+        hooks.set_stage(ImportStage::WritingMetadata);
+        let mut metadata =
+            self.create_metadata(spec, type_info, cleaned_name, &copied.files, copied.hashes)?;
+        metadata.shares_storage_with = copied.shares_storage_with;
 
         // Save metadata
         self.library.save_metadata(temp_dir, &metadata).await?;
@@ -1059,10 +1767,74 @@ impl ModelImporter {
         Ok(metadata)
     }
 
-    /// Copy files from source to destination.
+    /// Copy files from source to destination and hash the primary file.
     ///
-    /// Returns list of copied file info.
-    fn copy_files(&self, source: &Path, dest_dir: &Path) -> Result<Vec<ModelFileInfo>> {
+    /// Returns list of copied file info and the primary file's hashes. The
+    /// primary file is hashed as it is copied, so its data is read once;
+    /// when that is not possible (by-reference imports, or a primary that
+    /// was not copied) it is hashed from `dest_dir` afterwards, under the
+    /// `Hashing` stage.
+    ///
+    /// With hooks, files are copied in chunks so copied bytes can be reported
+    /// and cancellation takes effect mid-file. With `by_reference`, files are
+    /// linked instead of copied.
+    ///
+    /// Unless `dedup` is [`DedupStrategy::Copy`], the primary file is hashed
+    /// before copying, and when the index already holds a library-owned file
+    /// with the same SHA256 and size, the primary is linked to it instead.
+    fn copy_files(
+        &self,
+        source: &Path,
+        dest_dir: &Path,
+        hooks: &ImportHooks,
+        by_reference: bool,
+        dedup: DedupStrategy,
+    ) -> Result<CopiedFiles> {
+        let (source, dest_dir) = (
+            &extended_length_path(source),
+            &extended_length_path(dest_dir),
+        );
+        let primary_source = if by_reference {
+            None
+        } else {
+            self.choose_primary_file(source)?
+        };
+        let mut primary_hash = None;
+        let mut identical = None;
+        if let Some(primary) = primary_source.as_deref() {
+            if dedup != DedupStrategy::Copy {
+                hooks.set_stage(ImportStage::Hashing);
+                let hash = compute_dual_hash(primary)?;
+                identical = self.find_identical_primary(&hash, std::fs::metadata(primary)?.len());
+                primary_hash = Some(hash);
+                hooks.set_stage(ImportStage::Copying);
+            }
+        }
+        let mut shares_storage_with = None;
+        let mut transfer = |from: &Path, to: &Path| {
+            if by_reference {
+                return link_file(from, to, hooks);
+            }
+            if primary_source.as_deref() != Some(from) {
+                return copy_file(from, to, hooks, None);
+            }
+            if let Some((model_id, existing)) = &identical {
+                if share_file(existing, to, dedup) {
+                    if let Some(progress) = &hooks.progress {
+                        progress.add_bytes(std::fs::metadata(to)?.len());
+                    }
+                    shares_storage_with = Some(model_id.clone());
+                    return Ok(());
+                }
+            }
+            if primary_hash.is_some() {
+                return copy_file(from, to, hooks, None);
+            }
+            let mut hasher = DualHasher::new();
+            copy_file(from, to, hooks, Some(&mut hasher))?;
+            primary_hash = Some(hasher.finalize());
+            Ok(())
+        };
         let mut files = Vec::new();
 
         if source.is_file() {
@@ -1074,7 +1846,7 @@ impl ModelImporter {
             let normalized = normalize_filename(original_name);
             let dest_path = dest_dir.join(&normalized);
 
-            std::fs::copy(source, &dest_path)?;
+            transfer(source, &dest_path)?;
 
             let size = std::fs::metadata(&dest_path)?.len();
 
@@ -1108,7 +1880,7 @@ impl ModelImporter {
                     std::fs::create_dir_all(parent)?;
                 }
 
-                std::fs::copy(entry.path(), &dest_path)?;
+                transfer(entry.path(), &dest_path)?;
 
                 let size = std::fs::metadata(&dest_path)?.len();
 
@@ -1122,7 +1894,62 @@ impl ModelImporter {
             }
         }
 
-        Ok(files)
+        hooks.check_cancelled()?;
+        hooks.set_stage(ImportStage::Hashing);
+        let hashes = match primary_hash {
+            Some(hash) => Some(hash),
+            None => self
+                .choose_primary_file(dest_dir)?
+                .map(compute_dual_hash)
+                .transpose()?,
+        };
+        Ok(CopiedFiles {
+            files,
+            hashes,
+            shares_storage_with,
+        })
+    }
+
+    /// Find a library-owned model whose primary file has this content.
+    ///
+    /// Returns the canonical model ID and its primary file, following
+    /// `shares_storage_with` from whichever copy the index returns. Files
+    /// reached through a symlink (by-reference imports) are skipped, since
+    /// their bytes live outside the library.
+    fn find_identical_primary(&self, hash: &DualHash, size: u64) -> Option<(String, PathBuf)> {
+        let record = match self.library.index().find_by_hash(&hash.sha256) {
+            Ok(record) => record?,
+            Err(err) => {
+                tracing::warn!("Hash lookup for import deduplication failed: {}", err);
+                return None;
+            }
+        };
+        let model_id = self.storage_owner(record.id);
+        let existing = self.library.get_primary_model_file(&model_id)?;
+        let meta = std::fs::symlink_metadata(&existing).ok()?;
+        (meta.is_file() && meta.len() == size).then_some((model_id, existing))
+    }
+
+    /// Follow `shares_storage_with` links from `model_id` to the model that
+    /// owns the bytes.
+    ///
+    /// Stops at the last model that still exists, and at the first repeat if
+    /// the links form a cycle.
+    fn storage_owner(&self, model_id: String) -> String {
+        let mut current = model_id;
+        let mut seen = HashSet::new();
+        while seen.insert(current.clone()) {
+            let dir = self.library.library_root().join(&current);
+            let next = match self.library.load_metadata(&dir) {
+                Ok(Some(metadata)) => metadata.shares_storage_with,
+                _ => None,
+            };
+            match next {
+                Some(next) if self.library.library_root().join(&next).is_dir() => current = next,
+                _ => break,
+            }
+        }
+        current
     }
 
     /// Choose the primary model file from a directory.
@@ -1131,7 +1958,12 @@ impl ModelImporter {
     fn choose_primary_file(&self, dir: &Path) -> Result<Option<PathBuf>> {
         let mut largest: Option<(PathBuf, u64)> = None;
 
-        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+        // Follow links so by-reference imports hash their linked files.
+        for entry in WalkDir::new(dir)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
             if !entry.file_type().is_file() {
                 continue;
             }
@@ -1166,15 +1998,17 @@ impl ModelImporter {
         Ok(largest.map(|(p, _)| p))
     }
 
-    /// Create metadata for an imported model.
+    /// Create metadata for an imported model stored under `cleaned_name`.
     fn create_metadata(
         &self,
         spec: &ModelImportSpec,
         type_info: &ModelTypeInfo,
+        cleaned_name: &str,
         files: &[ModelFileInfo],
         hashes: Option<DualHash>,
     ) -> Result<ModelMetadata> {
         let now = chrono::Utc::now().to_rfc3339();
+        let security_tier = type_info.format.security_tier();
 
         // Resolve through SQLite model-type mapping rules first.
         let model_type = if let Some(hint) = spec.model_type.as_deref() {
@@ -1193,8 +2027,9 @@ impl ModelImporter {
             .map(|f| f.to_string())
             .unwrap_or_else(|| spec.family.clone());
 
-        let cleaned_name = normalize_name(&spec.official_name);
-        let model_id = format!("{}/{}/{}", model_type, family, cleaned_name);
+        let model_id = self
+            .library
+            .build_model_id(&model_type, &family, cleaned_name);
 
         // Calculate total size
         let total_size: u64 = files.iter().filter_map(|f| f.size).sum();
@@ -1211,7 +2046,7 @@ impl ModelImporter {
             model_type: Some(model_type),
             subtype: spec.subtype.clone(),
             official_name: Some(spec.official_name.clone()),
-            cleaned_name: Some(cleaned_name),
+            cleaned_name: Some(cleaned_name.to_string()),
             tags: spec.tags.clone(),
             base_model: None,
             preview_image: None,
@@ -1226,6 +2061,8 @@ impl ModelImporter {
             updated_date: Some(now),
             size_bytes: Some(total_size),
             files: Some(files.to_vec()),
+            security_tier: Some(security_tier),
+            security_acknowledged: (security_tier == SecurityTier::Pickle).then_some(true),
             match_source: Some("import".to_string()),
             match_method: None,
             match_confidence: None,
@@ -1257,12 +2094,23 @@ impl ModelImporter {
             model_card_artifact: None,
             license_artifact: None,
             license_status: Some("license_unknown".to_string()),
+            readiness: Some(ModelReadiness::Ready),
             ..Default::default()
         };
 
         metadata.inference_settings =
             resolve_inference_settings(&metadata, type_info.format.as_str());
 
+        if spec.by_reference.unwrap_or(false) {
+            let source = Path::new(&spec.path);
+            let source = source
+                .canonicalize()
+                .unwrap_or_else(|_| source.to_path_buf());
+            metadata.source_path = Some(source.display().to_string());
+            metadata.storage_kind = Some(crate::models::StorageKind::ExternalReference);
+            metadata.validation_state = Some(crate::models::AssetValidationState::Valid);
+        }
+
         Ok(metadata)
     }
 
@@ -1401,44 +2249,24 @@ impl ModelImporter {
         })??;
 
         // Validate shard completeness — reject if any file is part of an incomplete set.
-        // Uses extract_shard_info per file to catch even single-shard-of-set cases
-        // (which detect_sharded_sets would treat as standalone).
-        for file_info in &files {
-            if let Some((base_name, _idx, Some(total))) =
-                sharding::extract_shard_info(&file_info.name)
-            {
-                if total > 1 {
-                    // Count how many shards of this set we actually have
-                    let found_count = files
-                        .iter()
-                        .filter(|f| {
-                            sharding::extract_shard_info(&f.name)
-                                .map(|(b, _, _)| b == base_name)
-                                .unwrap_or(false)
-                        })
-                        .count();
-                    if found_count < total {
-                        tracing::warn!(
-                            "Incomplete shard set '{}': found {}/{} shards",
-                            base_name,
-                            found_count,
-                            total,
-                        );
-                        return Ok(ModelImportResult {
-                            path: model_dir.display().to_string(),
-                            success: false,
-                            model_id: None,
-                            model_path: None,
-                            error: Some(format!(
-                                "Incomplete shard set '{}': have {}/{} shards",
-                                base_name, found_count, total,
-                            )),
-                            security_tier: None,
-                        });
-                    }
-                    break; // Only need to validate once per directory
-                }
-            }
+        if let Some((base_name, found_count, total)) = find_incomplete_shard_set(&files) {
+            tracing::warn!(
+                "Incomplete shard set '{}': found {}/{} shards",
+                base_name,
+                found_count,
+                total,
+            );
+            return Ok(ModelImportResult {
+                path: model_dir.display().to_string(),
+                success: false,
+                model_id: None,
+                model_path: None,
+                error: Some(format!(
+                    "Incomplete shard set '{}': have {}/{} shards",
+                    base_name, found_count, total,
+                )),
+                security_tier: None,
+            });
         }
 
         // Build hashes from known value or compute
@@ -1473,9 +2301,13 @@ impl ModelImporter {
             subtype: resolved_subtype,
             tags: None,
             security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
         };
 
-        let mut metadata = self.create_metadata(&import_spec, &type_info, &files, hashes)?;
+        let cleaned_name = normalize_name(&spec.official_name);
+        let mut metadata =
+            self.create_metadata(&import_spec, &type_info, &cleaned_name, &files, hashes)?;
 
         // Tag the match source based on origin
         metadata.match_source = Some(if spec.repo_id.is_some() {
@@ -1835,6 +2667,88 @@ pub struct OrphanScanResult {
     pub adopted: usize,
     /// Errors encountered (directory path, error message).
     pub errors: Vec<(PathBuf, String)>,
+    /// Whether this was a preview that left the library untouched.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// What a dry run would adopt, one entry per adoptable orphan.
+    #[serde(default)]
+    pub previews: Vec<OrphanPreview>,
+}
+
+/// What adopting one orphan directory would produce.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanPreview {
+    /// Orphan directory that would be adopted in place.
+    pub model_dir: PathBuf,
+    /// Model ID the adopted model would be indexed under.
+    pub proposed_model_id: Option<String>,
+    /// Detected model type.
+    pub model_type: String,
+    /// Family inferred from the directory path.
+    pub family: String,
+    /// Display name inferred from the directory name.
+    pub official_name: String,
+    /// Signal the model type was resolved from.
+    pub model_type_source: String,
+    /// Confidence of the model type resolution (0.0-1.0).
+    pub model_type_confidence: f64,
+    /// Reasons the guess may be wrong; adoption would flag these for review.
+    pub ambiguities: Vec<String>,
+}
+
+/// Progress update for orphan adoption.
+#[derive(Debug, Clone)]
+pub struct OrphanScanProgress {
+    /// Orphan directory being processed (1-indexed)
+    pub current: usize,
+    /// Total orphan directories found
+    pub total: usize,
+    /// Orphans adopted so far
+    pub adopted: usize,
+    /// Directory being processed
+    pub current_dir: PathBuf,
+}
+
+/// Controls when orphan adoption runs without being asked.
+///
+/// Applies to the startup scan and to full-library reconciles. Manual scans
+/// (`PumasApi::scan_orphans`) always run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrphanScanConfig {
+    /// Adopt orphans automatically.
+    pub automatic: bool,
+    /// Skip automatic scans once the index holds more than this many models.
+    ///
+    /// Large, curated libraries rarely gain orphans and pay the most for a
+    /// full tree walk. `None` means no limit.
+    pub max_indexed_models: Option<usize>,
+}
+
+impl Default for OrphanScanConfig {
+    fn default() -> Self {
+        Self {
+            automatic: true,
+            max_indexed_models: None,
+        }
+    }
+}
+
+impl OrphanScanConfig {
+    /// Never scan automatically; only manual scans run.
+    pub fn manual_only() -> Self {
+        Self {
+            automatic: false,
+            max_indexed_models: None,
+        }
+    }
+
+    /// Whether an automatic scan should run for a library of this size.
+    pub fn should_scan_automatically(&self, indexed_models: usize) -> bool {
+        self.automatic
+            && self
+                .max_indexed_models
+                .is_none_or(|limit| indexed_models <= limit)
+    }
 }
 
 /// Progress update during import.
@@ -1867,6 +2781,19 @@ mod tests {
         path
     }
 
+    #[test]
+    fn test_orphan_scan_config_respects_toggle_and_size_limit() {
+        assert!(OrphanScanConfig::default().should_scan_automatically(100_000));
+        assert!(!OrphanScanConfig::manual_only().should_scan_automatically(0));
+
+        let limited = OrphanScanConfig {
+            automatic: true,
+            max_indexed_models: Some(500),
+        };
+        assert!(limited.should_scan_automatically(500));
+        assert!(!limited.should_scan_automatically(501));
+    }
+
     #[tokio::test]
     async fn test_has_orphan_candidates_detects_missing_metadata_model_dir() {
         let (_temp_dir, library) = setup().await;
@@ -1883,64 +2810,255 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_has_orphan_candidates_async_detects_missing_metadata_model_dir() {
+    async fn test_adopt_orphans_stops_after_shutdown() {
         let (_temp_dir, library) = setup().await;
-        let importer = ModelImporter::new(library.clone());
-        let orphan_dir = library
-            .library_root()
-            .join("llm")
-            .join("llama")
-            .join("candidate");
-        std::fs::create_dir_all(&orphan_dir).unwrap();
-        create_test_file(&orphan_dir, "weights.gguf", b"ok");
+        let shutdown = CancellationToken::new();
+        let importer = ModelImporter::new(library.clone()).with_shutdown_token(shutdown.clone());
+        for name in ["first", "second"] {
+            let orphan_dir = library.library_root().join("llm").join("llama").join(name);
+            std::fs::create_dir_all(&orphan_dir).unwrap();
+            create_test_file(&orphan_dir, "weights.bin", &[0u8; 4096]);
+        }
 
-        assert!(importer.has_orphan_candidates_async().await);
+        let result = importer
+            .adopt_orphans_with_progress(
+                false,
+                false,
+                Some(|_: OrphanScanProgress| shutdown.cancel()),
+            )
+            .await;
+
+        assert_eq!(result.orphans_found, 2);
+        assert_eq!(result.adopted, 1, "{:?}", result.errors);
     }
 
     #[tokio::test]
-    async fn test_recover_incomplete_shards_async_detects_missing_shard_set() {
+    async fn test_adopt_orphans_with_progress_reports_each_directory() {
         let (_temp_dir, library) = setup().await;
         let importer = ModelImporter::new(library.clone());
-        let shard_dir = library
-            .library_root()
-            .join("llm")
-            .join("llama")
-            .join("tiny-model");
-        std::fs::create_dir_all(&shard_dir).unwrap();
-        create_test_file(&shard_dir, "weights-00001-of-00002.gguf", b"partial");
+        for name in ["first", "second"] {
+            let orphan_dir = library.library_root().join("llm").join("llama").join(name);
+            std::fs::create_dir_all(&orphan_dir).unwrap();
+            create_test_file(&orphan_dir, "weights.bin", &[0u8; 4096]);
+        }
 
-        let recoveries = importer.recover_incomplete_shards_async().await;
-        assert_eq!(recoveries.len(), 1);
-        assert_eq!(recoveries[0].repo_id, "llama/tiny-model");
+        let mut updates = Vec::new();
+        let result = importer
+            .adopt_orphans_with_progress(
+                false,
+                false,
+                Some(|progress: OrphanScanProgress| updates.push(progress)),
+            )
+            .await;
+
+        assert_eq!(result.adopted, 2, "{:?}", result.errors);
         assert_eq!(
-            recoveries[0].existing_files,
-            vec!["weights-00001-of-00002.gguf"]
+            updates
+                .iter()
+                .map(|update| (update.current, update.total, update.adopted))
+                .collect::<Vec<_>>(),
+            vec![(1, 2, 0), (2, 2, 1)]
         );
     }
 
     #[tokio::test]
-    async fn test_find_interrupted_downloads_async_detects_partial_download_dir() {
+    async fn test_adopt_orphans_dry_run_previews_without_writing() {
         let (_temp_dir, library) = setup().await;
         let importer = ModelImporter::new(library.clone());
-        let model_dir = library
+        let orphan_dir = library
             .library_root()
             .join("llm")
             .join("llama")
-            .join("partial-model");
-        std::fs::create_dir_all(&model_dir).unwrap();
-        create_test_file(&model_dir, "weights.gguf.part", b"partial");
+            .join("tiny_chat");
+        std::fs::create_dir_all(&orphan_dir).unwrap();
+        create_test_file(&orphan_dir, "weights.bin", &[0u8; 4096]);
+        let loose_dir = library.library_root().join("loose_model");
+        std::fs::create_dir_all(&loose_dir).unwrap();
+        create_test_file(&loose_dir, "weights.bin", &[0u8; 4096]);
+
+        let result = importer.adopt_orphans(false, true).await;
+
+        assert!(result.dry_run);
+        assert_eq!(result.orphans_found, 2);
+        assert_eq!(result.adopted, 0);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(!orphan_dir.join("metadata.json").exists());
+        assert!(!loose_dir.join("metadata.json").exists());
+        assert_eq!(library.index().count().unwrap(), 0);
+
+        let preview = result
+            .previews
+            .iter()
+            .find(|preview| preview.model_dir == orphan_dir)
+            .unwrap();
+        assert_eq!(
+            preview.proposed_model_id.as_deref(),
+            Some("llm/llama/tiny_chat")
+        );
+        assert_eq!(preview.family, "llama");
+        assert_eq!(preview.official_name, "tiny chat");
+        let loose = result
+            .previews
+            .iter()
+            .find(|preview| preview.model_dir == loose_dir)
+            .unwrap();
+        assert!(loose
+            .ambiguities
+            .contains(&"family-not-in-path".to_string()));
 
-        let interrupted = importer
-            .find_interrupted_downloads_async(std::collections::HashSet::new())
-            .await;
-        assert_eq!(interrupted.len(), 1);
-        assert_eq!(interrupted[0].family, "llama");
-        assert_eq!(interrupted[0].inferred_name, "partial-model");
-        assert_eq!(interrupted[0].part_files, vec!["weights.gguf.part"]);
+        let adopted = importer.adopt_orphans(false, false).await;
+        assert_eq!(adopted.adopted, 2, "{:?}", adopted.errors);
     }
 
-    fn write_min_safetensors(path: &Path) {
-        let header = b"{}";
+    #[tokio::test]
+    async fn test_adopt_orphans_skips_ignored_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join(".pumasignore"), "scratch\n").unwrap();
+        let library = Arc::new(ModelLibrary::new(temp_dir.path()).await.unwrap());
+        let importer = ModelImporter::new(library.clone());
+        for dir in [".staging/llama/partial", "scratch/llama/draft"] {
+            let orphan_dir = library.library_root().join(dir);
+            std::fs::create_dir_all(&orphan_dir).unwrap();
+            create_test_file(&orphan_dir, "weights.bin", &[0u8; 4096]);
+        }
+
+        assert!(!importer.has_orphan_candidates());
+        let result = importer.adopt_orphans(false, false).await;
+
+        assert_eq!(result.orphans_found, 0);
+        assert_eq!(library.model_dirs().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_adopt_orphans_skips_live_downloads() {
+        use crate::model_library::download_store::PersistedDownload;
+        use crate::model_library::types::DownloadRequest;
+        use crate::models::DownloadStatus;
+
+        let (temp_dir, library) = setup().await;
+        let persistence = Arc::new(DownloadPersistence::new(temp_dir.path()));
+        let importer =
+            ModelImporter::new(library.clone()).with_download_persistence(persistence.clone());
+        let llama_dir = library.library_root().join("llm").join("llama");
+        for name in ["paused", "cancelled", "nested-part"] {
+            std::fs::create_dir_all(llama_dir.join(name)).unwrap();
+            create_test_file(&llama_dir.join(name), "weights.bin", &[0u8; 4096]);
+        }
+        std::fs::create_dir_all(llama_dir.join("nested-part").join("shards")).unwrap();
+        create_test_file(
+            &llama_dir.join("nested-part").join("shards"),
+            "weights-2.bin.part",
+            &[0u8; 1024],
+        );
+        for (name, status) in [
+            ("paused", DownloadStatus::Paused),
+            ("cancelled", DownloadStatus::Cancelled),
+        ] {
+            persistence
+                .save(&PersistedDownload {
+                    download_id: format!("dl-{}", name),
+                    repo_id: format!("test/{}", name),
+                    filename: "weights.bin".to_string(),
+                    filenames: vec!["weights.bin".to_string()],
+                    dest_dir: llama_dir.join(name),
+                    total_bytes: None,
+                    status,
+                    download_request: DownloadRequest {
+                        repo_id: format!("test/{}", name),
+                        family: "llama".to_string(),
+                        official_name: name.to_string(),
+                        model_type: Some("llm".to_string()),
+                        quant: None,
+                        filename: None,
+                        filenames: None,
+                        pipeline_tag: None,
+                        bundle_format: None,
+                        pipeline_class: None,
+                        release_date: None,
+                        download_url: None,
+                        model_card_json: None,
+                        license_status: None,
+                        skip_hash_verification: false,
+                        max_concurrent_files: None,
+                        rate_limit: None,
+                        priority: Default::default(),
+                        include_patterns: None,
+                        exclude_patterns: None,
+                    },
+                    created_at: "2025-01-01T00:00:00Z".to_string(),
+                    known_sha256: None,
+                    huggingface_evidence: None,
+                })
+                .unwrap();
+        }
+
+        let result = importer.adopt_orphans(false, false).await;
+
+        assert_eq!(result.orphans_found, 1, "{:?}", result.errors);
+        assert!(llama_dir.join("cancelled").join("metadata.json").exists());
+        assert!(!llama_dir.join("paused").join("metadata.json").exists());
+        assert!(!llama_dir.join("nested-part").join("metadata.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_has_orphan_candidates_async_detects_missing_metadata_model_dir() {
+        let (_temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let orphan_dir = library
+            .library_root()
+            .join("llm")
+            .join("llama")
+            .join("candidate");
+        std::fs::create_dir_all(&orphan_dir).unwrap();
+        create_test_file(&orphan_dir, "weights.gguf", b"ok");
+
+        assert!(importer.has_orphan_candidates_async().await);
+    }
+
+    #[tokio::test]
+    async fn test_recover_incomplete_shards_async_detects_missing_shard_set() {
+        let (_temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let shard_dir = library
+            .library_root()
+            .join("llm")
+            .join("llama")
+            .join("tiny-model");
+        std::fs::create_dir_all(&shard_dir).unwrap();
+        create_test_file(&shard_dir, "weights-00001-of-00002.gguf", b"partial");
+
+        let recoveries = importer.recover_incomplete_shards_async().await;
+        assert_eq!(recoveries.len(), 1);
+        assert_eq!(recoveries[0].repo_id, "llama/tiny-model");
+        assert_eq!(
+            recoveries[0].existing_files,
+            vec!["weights-00001-of-00002.gguf"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_interrupted_downloads_async_detects_partial_download_dir() {
+        let (_temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let model_dir = library
+            .library_root()
+            .join("llm")
+            .join("llama")
+            .join("partial-model");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        create_test_file(&model_dir, "weights.gguf.part", b"partial");
+
+        let interrupted = importer
+            .find_interrupted_downloads_async(std::collections::HashSet::new())
+            .await;
+        assert_eq!(interrupted.len(), 1);
+        assert_eq!(interrupted[0].family, "llama");
+        assert_eq!(interrupted[0].inferred_name, "partial-model");
+        assert_eq!(interrupted[0].part_files, vec!["weights.gguf.part"]);
+    }
+
+    fn write_min_safetensors(path: &Path) {
+        let header = b"{}";
         let header_size: u64 = header.len() as u64;
         let mut content = header_size.to_le_bytes().to_vec();
         content.extend_from_slice(header);
@@ -2013,6 +3131,8 @@ mod tests {
             subtype: None,
             tags: Some(vec!["test".to_string()]),
             security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
         };
 
         let result = importer.import(&spec).await.unwrap();
@@ -2020,6 +3140,223 @@ mod tests {
         assert!(result.model_path.is_some());
     }
 
+    #[tokio::test]
+    async fn test_import_by_reference_links_and_delete_keeps_original() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("external-drive");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let source_file = source_dir.join("Linked Model.safetensors");
+        write_min_safetensors(&source_file);
+
+        let result = importer
+            .import(&ModelImportSpec {
+                path: source_file.display().to_string(),
+                family: "test".to_string(),
+                official_name: "Linked Model".to_string(),
+                repo_id: None,
+                model_type: Some("llm".to_string()),
+                subtype: None,
+                tags: None,
+                security_acknowledged: None,
+                by_reference: Some(true),
+                dedup: None,
+            })
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let model_id = result.model_id.unwrap();
+        let model_dir = library.library_root().join(&model_id);
+        let linked = model_dir.join("linked_model.safetensors");
+        #[cfg(unix)]
+        assert!(linked.symlink_metadata().unwrap().file_type().is_symlink());
+        assert_eq!(
+            std::fs::read(&linked).unwrap(),
+            std::fs::read(&source_file).unwrap()
+        );
+
+        let canonical_source = source_file.canonicalize().unwrap();
+        let metadata = library.load_metadata(&model_dir).unwrap().unwrap();
+        assert_eq!(
+            metadata.storage_kind,
+            Some(crate::models::StorageKind::ExternalReference)
+        );
+        assert_eq!(
+            metadata.source_path.as_deref(),
+            Some(canonical_source.to_string_lossy().as_ref())
+        );
+        assert!(metadata.hashes.and_then(|hashes| hashes.sha256).is_some());
+        assert_eq!(
+            library.get_primary_model_file(&model_id),
+            Some(linked.clone())
+        );
+
+        library.delete_model(&model_id, true).await.unwrap();
+        assert!(!model_dir.exists());
+        assert!(source_file.is_file());
+    }
+
+    #[tokio::test]
+    async fn test_import_tracked_reports_copied_bytes() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let source_file = source_dir.join("model.safetensors");
+        write_min_safetensors(&source_file);
+        let source_len = std::fs::metadata(&source_file).unwrap().len();
+
+        let spec = ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Tracked Model".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
+        };
+        let tracker = Arc::new(ImportProgressTracker::new());
+        let reporter = tracker.start(&spec.path);
+
+        let result = importer.import_tracked(&spec, &reporter).await.unwrap();
+        assert!(result.success);
+
+        let progress = tracker.get(reporter.import_id()).unwrap();
+        assert_eq!(progress.stage, ImportStage::Indexing);
+        assert_eq!(progress.bytes_total, source_len);
+        assert_eq!(progress.bytes_done, source_len);
+
+        reporter.finish(result.clone());
+        let progress = tracker.get(reporter.import_id()).unwrap();
+        assert_eq!(progress.stage, ImportStage::Complete);
+        assert_eq!(progress.model_path, result.model_path);
+    }
+
+    #[tokio::test]
+    async fn test_validate_spec_reports_destination_without_writing() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_file = temp_dir.path().join("source").join("model.safetensors");
+        std::fs::create_dir_all(source_file.parent().unwrap()).unwrap();
+        write_min_safetensors(&source_file);
+
+        let spec = ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Test Model".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: None,
+            by_reference: None,
+            dedup: None,
+        };
+
+        let report = importer.validate_spec(&spec).await.unwrap();
+        assert!(report.valid, "{:?}", report.errors);
+        assert_eq!(report.cleaned_name.as_deref(), Some("test_model"));
+        assert_eq!(report.model_type.as_deref(), Some("llm"));
+        assert_eq!(report.file_format.as_deref(), Some("safetensors"));
+        assert_eq!(report.security_tier, Some(SecurityTier::Safe));
+        assert!(report.conflicts.is_empty());
+        assert!(!report.already_exists);
+        assert_eq!(library.model_dirs().count(), 0);
+
+        let result = importer.import(&spec).await.unwrap();
+        let model_dir = library.library_root().join(result.model_id.unwrap());
+        assert_eq!(
+            report.destination.as_deref(),
+            Some(model_dir.display().to_string().as_str())
+        );
+
+        let report = importer.validate_spec(&spec).await.unwrap();
+        assert!(!report.valid);
+        assert!(report.already_exists);
+        assert_eq!(report.conflicts, vec![model_dir.display().to_string()]);
+        assert_eq!(report.errors, vec![MODEL_EXISTS_ERROR.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_validate_spec_collects_blocking_errors() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_file = create_test_file(temp_dir.path(), "model.pt", &[0x50, 0x4B, 0x03, 0x04]);
+
+        let spec = ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Test Model".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(false),
+            by_reference: None,
+            dedup: None,
+        };
+        let report = importer.validate_spec(&spec).await.unwrap();
+        assert!(!report.valid);
+        assert_eq!(report.security_tier, Some(SecurityTier::Pickle));
+        assert!(report.destination.is_some());
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].contains("pickle"));
+
+        let report = importer
+            .validate_spec(&ModelImportSpec {
+                path: temp_dir.path().join("missing.gguf").display().to_string(),
+                ..spec
+            })
+            .await
+            .unwrap();
+        assert!(!report.valid);
+        assert!(report.destination.is_none());
+        assert_eq!(library.model_dirs().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_validate_spec_compares_sizes_of_same_named_models() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let spec = |file: &Path| ModelImportSpec {
+            path: file.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Test Model".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: None,
+            by_reference: None,
+            dedup: None,
+        };
+        let imported = create_test_file(&source_dir, "first.bin", b"first model weights");
+        importer.import(&spec(&imported)).await.unwrap();
+        let existing = library.library_root().join("llm/test/test_model");
+
+        let larger = create_test_file(&source_dir, "larger.bin", b"larger model weights!");
+        let report = importer.validate_spec(&spec(&larger)).await.unwrap();
+        assert!(report.valid, "{:?}", report.errors);
+        assert!(!report.already_exists);
+        assert_eq!(report.cleaned_name.as_deref(), Some("test_model-2"));
+        assert_eq!(report.conflicts, vec![existing.display().to_string()]);
+
+        let same_size = create_test_file(&source_dir, "same.bin", b"other model weights");
+        let report = importer.validate_spec(&spec(&same_size)).await.unwrap();
+        assert!(report.already_exists);
+        assert_eq!(
+            report.destination.as_deref(),
+            Some(existing.display().to_string().as_str())
+        );
+        assert_eq!(library.model_dirs().count(), 1);
+    }
+
     #[tokio::test]
     async fn test_import_pickle_requires_ack() {
         let (temp_dir, library) = setup().await;
@@ -2041,14 +3378,297 @@ mod tests {
             subtype: None,
             tags: None,
             security_acknowledged: Some(false), // Not acknowledged
+            by_reference: None,
+            dedup: None,
         };
 
+        match importer.import(&spec).await {
+            Err(PumasError::Validation { field, message }) => {
+                assert_eq!(field, "security_acknowledged");
+                assert!(message.contains("pickle"));
+            }
+            other => panic!("expected Validation error, got {other:?}"),
+        }
+        assert_eq!(library.model_dirs().count(), 0);
+
+        let spec = ModelImportSpec {
+            security_acknowledged: Some(true),
+            ..spec
+        };
         let result = importer.import(&spec).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.security_tier, Some(SecurityTier::Pickle));
+
+        let model_dir = library.library_root().join(result.model_path.unwrap());
+        let metadata = library.load_metadata(&model_dir).unwrap().unwrap();
+        assert_eq!(metadata.security_tier, Some(SecurityTier::Pickle));
+        assert_eq!(metadata.security_acknowledged, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_import_disambiguates_names_that_normalize_to_the_same_directory() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+
+        let spec = |file: &Path, official_name: &str| ModelImportSpec {
+            path: file.display().to_string(),
+            family: "test".to_string(),
+            official_name: official_name.to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: None,
+            by_reference: None,
+            dedup: None,
+        };
+        let first = create_test_file(&source_dir, "first.bin", b"first model weights");
+        let second = create_test_file(&source_dir, "second.bin", b"second model weights");
+
+        let result = importer
+            .import(&spec(&first, "Modèle Rapide"))
+            .await
+            .unwrap();
+        assert_eq!(result.model_id.as_deref(), Some("llm/test/mod_le_rapide"));
+
+        let result = importer
+            .import(&spec(&second, "Modéle Rapide"))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.model_id.as_deref(), Some("llm/test/mod_le_rapide-2"));
+        let metadata = library
+            .load_metadata(&library.library_root().join("llm/test/mod_le_rapide-2"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.official_name.as_deref(), Some("Modéle Rapide"));
+        assert_eq!(metadata.cleaned_name.as_deref(), Some("mod_le_rapide-2"));
+
+        // Re-importing either file finds the copy already in the library.
+        for (file, name) in [(&first, "Modèle Rapide"), (&second, "Modéle Rapide")] {
+            let result = importer.import(&spec(file, name)).await.unwrap();
+            assert!(!result.success);
+            assert_eq!(
+                result.error.as_deref(),
+                Some("Model already exists at this location")
+            );
+        }
+        assert_eq!(library.model_dirs().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_case_variant_names_do_not_clobber_on_case_insensitive_fs() {
+        let temp_dir = TempDir::new().unwrap();
+        let library = Arc::new(
+            ModelLibrary::new(temp_dir.path().join("library"))
+                .await
+                .unwrap()
+                .with_case_insensitive_fs(true),
+        );
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+
+        let spec = |file: &Path, official_name: &str| ModelImportSpec {
+            path: file.display().to_string(),
+            family: "test".to_string(),
+            official_name: official_name.to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: None,
+            by_reference: None,
+            dedup: None,
+        };
+        let upper = create_test_file(&source_dir, "upper.bin", b"Llama weights");
+        let lower = create_test_file(&source_dir, "lower.bin", b"llama weights");
+
+        let result = importer.import(&spec(&upper, "Llama")).await.unwrap();
+        assert_eq!(result.model_id.as_deref(), Some("llm/test/llama"));
+        // Hold the first model under different case, as a case-insensitive
+        // filesystem may report it.
+        let test_dir = library.library_root().join("llm/test");
+        std::fs::rename(test_dir.join("llama"), test_dir.join("Llama")).unwrap();
+
+        let result = importer.import(&spec(&lower, "llama")).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.model_id.as_deref(), Some("llm/test/llama-2"));
+        assert_eq!(
+            std::fs::read(test_dir.join("Llama/upper.bin")).unwrap(),
+            b"Llama weights"
+        );
+
+        let result = importer.import(&spec(&upper, "llama")).await.unwrap();
         assert!(!result.success);
-        assert!(result.error.as_ref().unwrap().contains("Pickle"));
+        assert_eq!(
+            result.model_path,
+            Some(test_dir.join("Llama").display().to_string())
+        );
+        assert!(library.preview_model_path("llm", "test", "LLAMA").exists);
+    }
+
+    #[tokio::test]
+    async fn test_import_and_delete_model_beyond_max_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let library_root = temp_dir.path().join("l".repeat(60)).join("m".repeat(60));
+        let library = Arc::new(ModelLibrary::new(&library_root).await.unwrap());
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let file_name = format!("{}.bin", "w".repeat(100));
+        let source_file = create_test_file(&source_dir, &file_name, b"deep model weights");
+
+        let result = importer
+            .import(&ModelImportSpec {
+                path: source_file.display().to_string(),
+                family: "f".repeat(60),
+                official_name: "n".repeat(100),
+                repo_id: None,
+                model_type: Some("llm".to_string()),
+                subtype: None,
+                tags: None,
+                security_acknowledged: None,
+                by_reference: None,
+                dedup: None,
+            })
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let model_id = result.model_id.unwrap();
+        let imported = library.library_root().join(&model_id).join(&file_name);
+        assert!(imported.as_os_str().len() > 260);
+        assert_eq!(
+            std::fs::read(extended_length_path(&imported)).unwrap(),
+            b"deep model weights"
+        );
+        assert!(library.get_model(&model_id).await.unwrap().is_some());
+
+        library.delete_model(&model_id, false).await.unwrap();
+        assert!(!extended_length_path(&imported).exists());
+    }
+
+    #[tokio::test]
+    async fn test_import_records_original_filenames() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_dir = temp_dir.path().join("Source Bundle");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        create_test_file(&source_dir, "Llama-3 8B Instruct.bin", b"weights");
+        create_test_file(&source_dir, "Tokenizer Config.json", b"{}");
+
+        let result = importer
+            .import(&ModelImportSpec {
+                path: source_dir.display().to_string(),
+                family: "test".to_string(),
+                official_name: "Original Names".to_string(),
+                repo_id: None,
+                model_type: Some("llm".to_string()),
+                subtype: None,
+                tags: None,
+                security_acknowledged: None,
+                by_reference: None,
+                dedup: None,
+            })
+            .await
+            .unwrap();
+
+        let originals = library
+            .original_filenames(result.model_id.as_deref().unwrap())
+            .unwrap();
+        assert_eq!(
+            originals.get("llama-3_8b_instruct.bin").map(String::as_str),
+            Some("Llama-3 8B Instruct.bin")
+        );
+        assert_eq!(
+            originals.get("tokenizer_config.json").map(String::as_str),
+            Some("Tokenizer Config.json")
+        );
+        assert!(matches!(
+            library.original_filenames("llm/test/missing"),
+            Err(PumasError::ModelNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_content_that_contradicts_extension() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+
+        // A pickle (ZIP magic) labeled as safetensors
+        let source_file = create_test_file(
+            &source_dir,
+            "disguised.safetensors",
+            &[0x50, 0x4B, 0x03, 0x04, 0x00, 0x00],
+        );
+        let mut spec = ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Disguised Model".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(false),
+            by_reference: None,
+            dedup: None,
+        };
+
+        match importer.import(&spec).await {
+            Err(PumasError::InvalidFileType { expected, actual }) => {
+                assert_eq!(expected, "safetensors");
+                assert_eq!(actual, "pickle");
+            }
+            other => panic!("expected InvalidFileType, got {other:?}"),
+        }
+        assert_eq!(library.model_dirs().count(), 0);
+
+        // Acknowledging the pickle risk lets the import proceed as a pickle.
+        spec.security_acknowledged = Some(true);
+        let result = importer.import(&spec).await.unwrap();
+        assert!(result.success);
         assert_eq!(result.security_tier, Some(SecurityTier::Pickle));
     }
 
+    #[tokio::test]
+    async fn test_import_directory_with_part_files_waits_for_download() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+
+        let source_dir = temp_dir.path().join("source").join("downloading");
+        std::fs::create_dir_all(source_dir.join("text_encoder")).unwrap();
+        create_test_file(&source_dir, "model.safetensors", &[0u8; 4096]);
+        create_test_file(
+            &source_dir.join("text_encoder"),
+            "model.safetensors.part",
+            &[0u8; 1024],
+        );
+
+        let spec = ModelImportSpec {
+            path: source_dir.display().to_string(),
+            family: "test".to_string(),
+            official_name: "Downloading".to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
+        };
+
+        let result = importer.import(&spec).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("still in progress"));
+        assert_eq!(library.model_dirs().count(), 0);
+    }
+
     #[tokio::test]
     async fn test_batch_import() {
         let (temp_dir, library) = setup().await;
@@ -2078,14 +3698,130 @@ mod tests {
                 subtype: None,
                 tags: None,
                 security_acknowledged: Some(true),
+                by_reference: None,
+                dedup: None,
             });
         }
 
-        let results = importer.batch_import(specs, None).await;
-        assert_eq!(results.len(), 3);
+        let mut missing = specs[0].clone();
+        missing.path = source_dir.join("missing.safetensors").display().to_string();
+        specs.push(missing);
+
+        let (tx, mut rx) = mpsc::channel(32);
+        let results = importer
+            .batch_import(specs, Some(tx), &CancellationToken::new())
+            .await;
+        assert_eq!(results.len(), 4);
 
         let success_count = results.iter().filter(|r| r.success).count();
         assert_eq!(success_count, 3);
+
+        let mut updates = Vec::new();
+        while let Some(update) = rx.recv().await {
+            updates.push(update);
+        }
+        // A start and an end update per item, then the final summary.
+        assert_eq!(updates.len(), 9);
+        assert_eq!(updates[6].current_index, Some(3));
+        assert_eq!((updates[6].succeeded, updates[6].failed), (3, 0));
+        let last = updates.last().unwrap();
+        assert_eq!(last.current_index, None);
+        assert_eq!(last.stage, ImportStage::Complete);
+        assert_eq!((last.completed, last.succeeded, last.failed), (4, 3, 1));
+    }
+
+    #[tokio::test]
+    async fn test_batch_import_cancelled_marks_remaining_items() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+
+        let source_dir = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source_dir).unwrap();
+
+        let header = b"{}";
+        let mut content = (header.len() as u64).to_le_bytes().to_vec();
+        content.extend_from_slice(header);
+        content.extend_from_slice(&[0u8; 100]);
+
+        let specs: Vec<ModelImportSpec> = (0..2)
+            .map(|i| {
+                let source_file =
+                    create_test_file(&source_dir, &format!("model{}.safetensors", i), &content);
+                ModelImportSpec {
+                    path: source_file.display().to_string(),
+                    family: "test".to_string(),
+                    official_name: format!("Cancelled Model {}", i),
+                    repo_id: None,
+                    model_type: Some("llm".to_string()),
+                    subtype: None,
+                    tags: None,
+                    security_acknowledged: Some(true),
+                    by_reference: None,
+                    dedup: None,
+                }
+            })
+            .collect();
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let results = importer.batch_import(specs, None, &cancel).await;
+
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert!(!result.success);
+            assert_eq!(result.error.as_deref(), Some("Model import cancelled"));
+        }
+        assert_eq!(library.model_dirs().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_copy_files_hashes_primary_while_copying() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library);
+        let source = temp_dir.path().join("source");
+        std::fs::create_dir_all(&source).unwrap();
+        let primary = create_test_file(&source, "model.safetensors", &[7u8; 4096]);
+        create_test_file(&source, "config.json", b"{}");
+        let dest = temp_dir.path().join("dest");
+        std::fs::create_dir_all(&dest).unwrap();
+        let tracker = Arc::new(ImportProgressTracker::new());
+        let reporter = tracker.start(&source.display().to_string());
+        let hooks = ImportHooks {
+            progress: Some(reporter.clone()),
+            cancel: None,
+        };
+
+        let CopiedFiles { files, hashes, .. } = importer
+            .copy_files(&source, &dest, &hooks, false, DedupStrategy::Copy)
+            .unwrap();
+
+        let expected = compute_dual_hash(&primary).unwrap();
+        let hashes = hashes.expect("primary file should be hashed");
+        assert_eq!(hashes.sha256, expected.sha256);
+        assert_eq!(hashes.blake3, expected.blake3);
+        assert_eq!(files.len(), 2);
+        assert_eq!(
+            std::fs::read(dest.join("model.safetensors")).unwrap(),
+            vec![7u8; 4096]
+        );
+        let progress = tracker.get(reporter.import_id()).unwrap();
+        assert_eq!(progress.stage, ImportStage::Hashing);
+        assert_eq!(progress.bytes_done, 4096 + 2);
+    }
+
+    #[test]
+    fn test_copy_file_stops_when_cancelled() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "model.gguf", b"GGUF-data");
+        let dest = temp_dir.path().join("copy.gguf");
+        let hooks = ImportHooks {
+            progress: None,
+            cancel: Some(CancellationToken::new()),
+        };
+        hooks.cancel.as_ref().unwrap().cancel();
+
+        let err = copy_file(&source, &dest, &hooks, None).unwrap_err();
+        assert!(matches!(err, PumasError::ImportCancelled));
     }
 
     #[tokio::test]
@@ -2121,6 +3857,12 @@ mod tests {
                     r#"{"license":"apache-2.0","tags":["reranker"]}"#.to_string(),
                 ),
                 license_status: Some("apache-2.0".to_string()),
+                skip_hash_verification: false,
+                max_concurrent_files: None,
+                rate_limit: None,
+                priority: Default::default(),
+                include_patterns: None,
+                exclude_patterns: None,
             },
             total_bytes: Some(1024),
             huggingface_evidence: Some(HuggingFaceEvidence {
@@ -2138,6 +3880,13 @@ mod tests {
 
         let metadata = library.load_metadata(&model_dir).unwrap().unwrap();
         assert_eq!(metadata.match_source.as_deref(), Some("download_partial"));
+        assert_eq!(metadata.readiness, Some(ModelReadiness::Downloading));
+        let indexed = library
+            .get_model(metadata.model_id.as_deref().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(indexed.readiness(), ModelReadiness::Downloading);
         assert_eq!(
             metadata.repo_id.as_deref(),
             Some("QuantFactory/Qwen3-Reranker-4B-GGUF")
@@ -2250,6 +3999,8 @@ mod tests {
             subtype: None,
             tags: None,
             security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
         };
 
         let result = importer.import(&spec).await.unwrap();
@@ -2267,6 +4018,148 @@ mod tests {
         assert!(!keys.contains(&"guidance_scale"));
     }
 
+    #[tokio::test]
+    async fn test_import_hardlinks_identical_primary_and_survives_delete() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_file = create_test_file(temp_dir.path(), "model.gguf", &[7u8; 4096]);
+        let spec = |name: &str| ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "llama".to_string(),
+            official_name: name.to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: Some(DedupStrategy::Hardlink),
+        };
+
+        let first = importer.import(&spec("first")).await.unwrap();
+        let second = importer.import(&spec("second")).await.unwrap();
+        let third = importer.import(&spec("third")).await.unwrap();
+        let (first_id, second_id, third_id) = (
+            first.model_id.unwrap(),
+            second.model_id.unwrap(),
+            third.model_id.unwrap(),
+        );
+        let second_dir = library.library_root().join(&second_id);
+        let third_dir = library.library_root().join(&third_id);
+        let second_file = library.get_primary_model_file(&second_id).unwrap();
+
+        assert_eq!(crate::platform::hardlink_count(&second_file).unwrap(), 3);
+        let second_metadata = library.load_metadata(&second_dir).unwrap().unwrap();
+        assert_eq!(second_metadata.shares_storage_with, Some(first_id.clone()));
+
+        library.delete_model(&first_id, false).await.unwrap();
+
+        assert_eq!(std::fs::read(&second_file).unwrap(), vec![7u8; 4096]);
+        let second_metadata = library.load_metadata(&second_dir).unwrap().unwrap();
+        let third_metadata = library.load_metadata(&third_dir).unwrap().unwrap();
+        assert_eq!(second_metadata.shares_storage_with, None);
+        assert_eq!(third_metadata.shares_storage_with, Some(second_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_keeps_model_when_sharers_cannot_be_repointed() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_file = create_test_file(temp_dir.path(), "model.gguf", &[5u8; 4096]);
+        let spec = |name: &str| ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "llama".to_string(),
+            official_name: name.to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: Some(DedupStrategy::Hardlink),
+        };
+
+        let first_id = importer
+            .import(&spec("first"))
+            .await
+            .unwrap()
+            .model_id
+            .unwrap();
+        let second_id = importer
+            .import(&spec("second"))
+            .await
+            .unwrap()
+            .model_id
+            .unwrap();
+        let first_dir = library.library_root().join(&first_id);
+        let second_dir = library.library_root().join(&second_id);
+        std::fs::write(second_dir.join("metadata.json"), b"{").unwrap();
+
+        assert!(library.delete_model(&first_id, false).await.is_err());
+
+        assert!(first_dir.is_dir());
+        assert!(library.index().get(&first_id).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_shared_storage_chains_resolve_to_canonical_model() {
+        let (temp_dir, library) = setup().await;
+        let importer = ModelImporter::new(library.clone());
+        let source_file = create_test_file(temp_dir.path(), "model.gguf", &[9u8; 4096]);
+        let spec = |name: &str| ModelImportSpec {
+            path: source_file.display().to_string(),
+            family: "llama".to_string(),
+            official_name: name.to_string(),
+            repo_id: None,
+            model_type: Some("llm".to_string()),
+            subtype: None,
+            tags: None,
+            security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: Some(DedupStrategy::Hardlink),
+        };
+
+        let first_id = importer
+            .import(&spec("first"))
+            .await
+            .unwrap()
+            .model_id
+            .unwrap();
+        let second_id = importer
+            .import(&spec("second"))
+            .await
+            .unwrap()
+            .model_id
+            .unwrap();
+        let third_id = importer
+            .import(&spec("third"))
+            .await
+            .unwrap()
+            .model_id
+            .unwrap();
+        let third_dir = library.library_root().join(&third_id);
+
+        // Chain third -> second -> first, as older imports could leave behind.
+        let mut third_metadata = library.load_metadata(&third_dir).unwrap().unwrap();
+        third_metadata.shares_storage_with = Some(second_id.clone());
+        library
+            .save_metadata(&third_dir, &third_metadata)
+            .await
+            .unwrap();
+
+        let hash = DualHash {
+            sha256: third_metadata.hashes.unwrap().sha256.unwrap(),
+            blake3: String::new(),
+        };
+        let (owner, _) = importer.find_identical_primary(&hash, 4096).unwrap();
+        assert_eq!(owner, first_id);
+
+        // Deleting the middle link re-points its sharer at the canonical copy.
+        library.delete_model(&second_id, false).await.unwrap();
+        let third_metadata = library.load_metadata(&third_dir).unwrap().unwrap();
+        assert_eq!(third_metadata.shares_storage_with, Some(first_id));
+    }
+
     #[tokio::test]
     async fn test_import_external_diffusers_directory_creates_registry_artifact() {
         let (temp_dir, library) = setup().await;
@@ -2590,6 +4483,12 @@ mod tests {
                 ),
                 model_card_json: None,
                 license_status: Some("apache-2.0".to_string()),
+                skip_hash_verification: false,
+                max_concurrent_files: None,
+                rate_limit: None,
+                priority: Default::default(),
+                include_patterns: None,
+                exclude_patterns: None,
             },
             known_sha256: None,
             huggingface_evidence: None,
@@ -2604,6 +4503,13 @@ mod tests {
             Some("grounding-dino-base")
         );
         assert_eq!(persisted.match_source.as_deref(), Some("download"));
+        assert_eq!(persisted.readiness, Some(ModelReadiness::Ready));
+        let indexed = library
+            .get_model(result.model_id.as_deref().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(indexed.is_ready());
         assert_eq!(
             persisted.repo_id.as_deref(),
             Some("IDEA-Research/grounding-dino-base")
@@ -2632,6 +4538,8 @@ mod tests {
             subtype: None,
             tags: Some(vec!["diffusers".to_string()]),
             security_acknowledged: Some(true),
+            by_reference: None,
+            dedup: None,
         };
 
         let result = importer.import(&spec).await.unwrap();
@@ -0,0 +1,236 @@
+//! Poll-based progress tracking for imports started in the background.
+//!
+//! [`ImportProgressTracker`] hands out an import ID and an
+//! [`ImportProgressReporter`] per import. The import pipeline publishes its
+//! stage and copied bytes through the reporter, and callers poll
+//! [`ImportProgressTracker::get`] with the ID until a result is recorded.
+//! [`BatchImportTracker`] does the same for whole batches, keeping the latest
+//! [`BatchImportProgress`] per batch ID.
+
+use crate::model_library::types::{BatchImportProgress, ImportStage, ModelImportResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Snapshot of one tracked import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportJobProgress {
+    /// ID returned when the import was started
+    pub import_id: String,
+    /// Source path being imported
+    pub source_path: String,
+    /// Current pipeline stage
+    pub stage: ImportStage,
+    /// Bytes copied into the library so far
+    pub bytes_done: u64,
+    /// Total bytes to copy (0 until the source has been measured)
+    pub bytes_total: u64,
+    /// Library-relative model path once the import has finished
+    pub model_path: Option<String>,
+    /// Final result, `None` while the import is still running
+    pub result: Option<ModelImportResult>,
+}
+
+/// Tracks progress of active and recently finished imports.
+#[derive(Debug, Default)]
+pub struct ImportProgressTracker {
+    jobs: Mutex<HashMap<String, ImportJobProgress>>,
+    next_id: AtomicU64,
+}
+
+impl ImportProgressTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new import of `source_path` and return its reporter.
+    pub fn start(self: &Arc<Self>, source_path: &str) -> ImportProgressReporter {
+        let import_id = format!(
+            "import-{}",
+            self.next_id.fetch_add(1, Ordering::Relaxed) + 1
+        );
+        let progress = ImportJobProgress {
+            import_id: import_id.clone(),
+            source_path: source_path.to_string(),
+            stage: ImportStage::Copying,
+            bytes_done: 0,
+            bytes_total: 0,
+            model_path: None,
+            result: None,
+        };
+        self.jobs
+            .lock()
+            .expect("import progress lock poisoned")
+            .insert(import_id.clone(), progress);
+        ImportProgressReporter {
+            tracker: self.clone(),
+            import_id,
+        }
+    }
+
+    /// Get progress for a specific import.
+    pub fn get(&self, import_id: &str) -> Option<ImportJobProgress> {
+        self.jobs
+            .lock()
+            .expect("import progress lock poisoned")
+            .get(import_id)
+            .cloned()
+    }
+
+    fn update(&self, import_id: &str, apply: impl FnOnce(&mut ImportJobProgress)) {
+        let mut jobs = self.jobs.lock().expect("import progress lock poisoned");
+        if let Some(progress) = jobs.get_mut(import_id) {
+            apply(progress);
+        }
+    }
+}
+
+/// Handle an in-flight import uses to publish its progress.
+#[derive(Debug, Clone)]
+pub struct ImportProgressReporter {
+    tracker: Arc<ImportProgressTracker>,
+    import_id: String,
+}
+
+impl ImportProgressReporter {
+    /// ID callers poll with.
+    pub fn import_id(&self) -> &str {
+        &self.import_id
+    }
+
+    pub(crate) fn set_stage(&self, stage: ImportStage) {
+        self.tracker
+            .update(&self.import_id, |progress| progress.stage = stage);
+    }
+
+    pub(crate) fn set_total(&self, bytes_total: u64) {
+        self.tracker.update(&self.import_id, |progress| {
+            progress.bytes_total = bytes_total
+        });
+    }
+
+    pub(crate) fn add_bytes(&self, bytes: u64) {
+        self.tracker.update(&self.import_id, |progress| {
+            progress.bytes_done = progress.bytes_done.saturating_add(bytes);
+        });
+    }
+
+    /// Record the final result. Successful imports move to
+    /// [`ImportStage::Complete`]; failed ones keep the stage they stopped at.
+    pub fn finish(&self, result: ModelImportResult) {
+        self.tracker.update(&self.import_id, |progress| {
+            if result.success {
+                progress.stage = ImportStage::Complete;
+                progress.bytes_done = progress.bytes_done.max(progress.bytes_total);
+            }
+            progress.model_path = result.model_path.clone();
+            progress.result = Some(result);
+        });
+    }
+}
+
+/// Tracks aggregate progress of batches started in the background.
+#[derive(Debug, Default)]
+pub struct BatchImportTracker {
+    batches: Mutex<HashMap<String, BatchImportProgress>>,
+    next_id: AtomicU64,
+}
+
+impl BatchImportTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a batch of `total` items and return its batch ID.
+    pub fn start(&self, total: usize) -> String {
+        let batch_id = format!("batch-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        self.batches
+            .lock()
+            .expect("batch import progress lock poisoned")
+            .insert(batch_id.clone(), BatchImportProgress::new(total));
+        batch_id
+    }
+
+    /// Get the latest progress of a batch.
+    ///
+    /// The batch has finished once `completed == total` and `current_index`
+    /// is `None`.
+    pub fn get(&self, batch_id: &str) -> Option<BatchImportProgress> {
+        self.batches
+            .lock()
+            .expect("batch import progress lock poisoned")
+            .get(batch_id)
+            .cloned()
+    }
+
+    /// Replace the progress of a batch with a newer snapshot.
+    pub fn update(&self, batch_id: &str, progress: BatchImportProgress) {
+        let mut batches = self
+            .batches
+            .lock()
+            .expect("batch import progress lock poisoned");
+        if let Some(current) = batches.get_mut(batch_id) {
+            *current = progress;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reporter_updates_are_visible_through_the_tracker() {
+        let tracker = Arc::new(ImportProgressTracker::new());
+        let reporter = tracker.start("/tmp/model.safetensors");
+        let other = tracker.start("/tmp/other.gguf");
+        assert_ne!(reporter.import_id(), other.import_id());
+
+        reporter.set_total(100);
+        reporter.add_bytes(40);
+        reporter.set_stage(ImportStage::Hashing);
+
+        let progress = tracker.get(reporter.import_id()).unwrap();
+        assert_eq!(progress.stage, ImportStage::Hashing);
+        assert_eq!((progress.bytes_done, progress.bytes_total), (40, 100));
+        assert!(progress.result.is_none());
+
+        reporter.finish(ModelImportResult {
+            path: "/tmp/model.safetensors".to_string(),
+            success: true,
+            model_id: Some("llm/family/model".to_string()),
+            model_path: Some("llm/family/model".to_string()),
+            error: None,
+            security_tier: None,
+        });
+
+        let progress = tracker.get(reporter.import_id()).unwrap();
+        assert_eq!(progress.stage, ImportStage::Complete);
+        assert_eq!(progress.bytes_done, 100);
+        assert_eq!(progress.model_path.as_deref(), Some("llm/family/model"));
+        assert!(tracker.get("import-missing").is_none());
+    }
+
+    #[test]
+    fn batch_tracker_keeps_latest_snapshot_per_batch() {
+        let tracker = BatchImportTracker::new();
+        let batch_id = tracker.start(2);
+        let other_id = tracker.start(1);
+        assert_ne!(batch_id, other_id);
+        assert_eq!(tracker.get(&batch_id).unwrap().total, 2);
+
+        let mut progress = BatchImportProgress::new(2);
+        progress.update(1, Some("/tmp/b.gguf".to_string()), ImportStage::Copying);
+        progress.current_index = Some(1);
+        tracker.update(&batch_id, progress);
+
+        let progress = tracker.get(&batch_id).unwrap();
+        assert_eq!(progress.current_index, Some(1));
+        assert_eq!(progress.completed, 1);
+        assert_eq!(tracker.get(&other_id).unwrap().completed, 0);
+        assert!(tracker.get("batch-missing").is_none());
+    }
+}
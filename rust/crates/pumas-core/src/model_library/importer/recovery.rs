@@ -1,8 +1,13 @@
 use super::{
-    InPlaceImportSpec, IncompleteShardRecovery, InterruptedDownload, ModelImporter,
+    find_incomplete_shard_set, resolve_model_type_with_rules_async, InPlaceImportSpec,
+    IncompleteShardRecovery, InterruptedDownload, ModelImporter, OrphanPreview, OrphanScanProgress,
     OrphanScanResult, TEMP_IMPORT_PREFIX,
 };
+use crate::error::{PumasError, Result};
+use crate::model_library::external_assets::validate_diffusers_directory_for_import;
 use crate::model_library::sharding;
+use crate::model_library::types::ModelType;
+use crate::models::{AssetValidationState, DownloadStatus};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
@@ -13,8 +18,31 @@ impl ModelImporter {
     /// An orphan is a directory that contains model files but no `metadata.json`.
     /// Metadata is inferred from the directory path structure
     /// (`{library_root}/{model_type}/{family}/{name}/`).
-    pub async fn adopt_orphans(&self, compute_hashes: bool) -> OrphanScanResult {
-        let mut result = OrphanScanResult::default();
+    ///
+    /// `compute_hashes` hashes each adopted primary file up front instead of
+    /// leaving it for a later backfill. With `dry_run` nothing is written or
+    /// indexed; [`OrphanScanResult::previews`] lists what would be adopted.
+    pub async fn adopt_orphans(&self, compute_hashes: bool, dry_run: bool) -> OrphanScanResult {
+        self.adopt_orphans_with_progress(compute_hashes, dry_run, None::<fn(OrphanScanProgress)>)
+            .await
+    }
+
+    /// Adopt orphans like [`Self::adopt_orphans`], reporting progress.
+    ///
+    /// The callback runs before each orphan directory is processed.
+    pub async fn adopt_orphans_with_progress<F>(
+        &self,
+        compute_hashes: bool,
+        dry_run: bool,
+        mut progress_callback: Option<F>,
+    ) -> OrphanScanResult
+    where
+        F: FnMut(OrphanScanProgress) + Send,
+    {
+        let mut result = OrphanScanResult {
+            dry_run,
+            ..OrphanScanResult::default()
+        };
         let importer = self.clone();
         let orphan_dirs = tokio::task::spawn_blocking(move || {
             importer.find_orphan_dirs(importer.library.library_root(), false)
@@ -30,7 +58,21 @@ impl ModelImporter {
 
         tracing::info!("Found {} orphan model directories", orphan_dirs.len());
 
-        for orphan_dir in orphan_dirs {
+        let total = orphan_dirs.len();
+        for (idx, orphan_dir) in orphan_dirs.into_iter().enumerate() {
+            if self.is_shutting_down() {
+                tracing::info!("Stopping orphan adoption for shutdown");
+                break;
+            }
+            if let Some(ref mut callback) = progress_callback {
+                callback(OrphanScanProgress {
+                    current: idx + 1,
+                    total,
+                    adopted: result.adopted,
+                    current_dir: orphan_dir.clone(),
+                });
+            }
+
             let inferred = match self.infer_spec_from_path(&orphan_dir) {
                 Some(spec) => spec,
                 None => {
@@ -42,6 +84,14 @@ impl ModelImporter {
                 }
             };
 
+            if dry_run {
+                match self.preview_orphan(&orphan_dir, inferred).await {
+                    Ok(preview) => result.previews.push(preview),
+                    Err(err) => result.errors.push((orphan_dir, err.to_string())),
+                }
+                continue;
+            }
+
             let spec = InPlaceImportSpec {
                 model_dir: orphan_dir.clone(),
                 official_name: inferred.official_name,
@@ -85,15 +135,84 @@ impl ModelImporter {
         }
 
         tracing::info!(
-            "Orphan scan complete: {} found, {} adopted, {} errors",
+            "Orphan scan complete: {} found, {} adopted, {} previewed, {} errors",
             result.orphans_found,
             result.adopted,
+            result.previews.len(),
             result.errors.len()
         );
 
         result
     }
 
+    /// Resolve what [`Self::import_in_place`] would record for an orphan,
+    /// without writing metadata or touching the index.
+    async fn preview_orphan(
+        &self,
+        orphan_dir: &Path,
+        inferred: InferredSpec,
+    ) -> Result<OrphanPreview> {
+        let importer = self.clone();
+        let model_dir = orphan_dir.to_path_buf();
+        let files = tokio::task::spawn_blocking(move || -> Result<Option<Vec<_>>> {
+            let bundle = validate_diffusers_directory_for_import(&model_dir);
+            if bundle.validation_state == AssetValidationState::Valid {
+                return Ok(Some(Vec::new()));
+            }
+            if importer.choose_primary_file(&model_dir)?.is_none() {
+                return Ok(None);
+            }
+            importer.enumerate_model_files(&model_dir).map(Some)
+        })
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!("Failed to join orphan preview task: {}", err))
+        })??;
+        let Some(files) = files else {
+            return Err(PumasError::Other(
+                "No model files found in directory".to_string(),
+            ));
+        };
+        if let Some((base_name, found_count, total)) = find_incomplete_shard_set(&files) {
+            return Err(PumasError::Other(format!(
+                "Incomplete shard set '{}': have {}/{} shards",
+                base_name, found_count, total,
+            )));
+        }
+
+        let resolution = resolve_model_type_with_rules_async(
+            self.library.index().clone(),
+            orphan_dir.to_path_buf(),
+            None,
+            inferred.model_type.clone(),
+            None,
+        )
+        .await?;
+
+        let mut ambiguities = resolution.review_reasons.clone();
+        if let Some(path_type) = inferred.model_type.as_deref() {
+            if resolution.model_type != ModelType::Unknown
+                && path_type != resolution.model_type.as_str()
+            {
+                ambiguities.push("model-type-path-mismatch".to_string());
+            }
+        }
+        if inferred.family == "unknown" {
+            ambiguities.push("family-not-in-path".to_string());
+        }
+
+        Ok(OrphanPreview {
+            model_dir: orphan_dir.to_path_buf(),
+            proposed_model_id: self.library.get_model_id(orphan_dir),
+            model_type: resolution.model_type.as_str().to_string(),
+            family: inferred.family,
+            official_name: inferred.official_name,
+            model_type_source: resolution.source,
+            model_type_confidence: resolution.confidence,
+            ambiguities,
+        })
+    }
+
     /// Cheap clean-state probe used to avoid spawning startup orphan adoption
     /// work when the library tree has no orphan candidates.
     pub fn has_orphan_candidates(&self) -> bool {
@@ -125,26 +244,8 @@ impl ModelImporter {
             &["gguf", "safetensors", "pt", "pth", "ckpt", "bin", "onnx"];
         let mut results = Vec::new();
 
-        for entry in WalkDir::new(library_root)
-            .min_depth(1)
-            .max_depth(3)
-            .into_iter()
-            .filter_map(|entry| entry.ok())
-        {
-            if !entry.file_type().is_dir() {
-                continue;
-            }
-
-            let dir = entry.path();
-            let dir_name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("");
-
-            if dir_name.starts_with(TEMP_IMPORT_PREFIX) || dir_name.starts_with('.') {
-                continue;
-            }
-
-            if dir.join("metadata.json").exists() {
-                continue;
-            }
+        for dir in self.unindexed_dirs(library_root) {
+            let dir = dir.as_path();
 
             let file_entries: Vec<_> = match std::fs::read_dir(dir) {
                 Ok(reader) => reader.filter_map(|entry| entry.ok()).collect(),
@@ -247,26 +348,8 @@ impl ModelImporter {
         let library_root = self.library.library_root();
         let mut results = Vec::new();
 
-        for entry in WalkDir::new(library_root)
-            .min_depth(1)
-            .max_depth(3)
-            .into_iter()
-            .filter_map(|entry| entry.ok())
-        {
-            if !entry.file_type().is_dir() {
-                continue;
-            }
-
-            let dir = entry.path();
-            let dir_name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("");
-
-            if dir_name.starts_with(TEMP_IMPORT_PREFIX) || dir_name.starts_with('.') {
-                continue;
-            }
-
-            if dir.join("metadata.json").exists() {
-                continue;
-            }
+        for dir in self.unindexed_dirs(library_root) {
+            let dir = dir.as_path();
 
             if known_dest_dirs.contains(dir) {
                 continue;
@@ -360,30 +443,65 @@ impl ModelImporter {
             .unwrap_or_default()
     }
 
-    /// Find directories with model files but no metadata.json.
-    fn find_orphan_dirs(&self, library_root: &Path, stop_after_first: bool) -> Vec<PathBuf> {
-        let mut orphans = Vec::new();
-        let model_extensions: &[&str] =
-            &["gguf", "safetensors", "pt", "pth", "ckpt", "bin", "onnx"];
+    /// Destination directories of downloads the download manager still owns.
+    ///
+    /// Everything persisted except completed and cancelled downloads counts,
+    /// including paused and errored ones that can still resume.
+    pub(super) fn live_download_dirs(&self) -> HashSet<PathBuf> {
+        let Some(persistence) = self.download_persistence.as_ref() else {
+            return HashSet::new();
+        };
+        persistence
+            .load_all()
+            .into_iter()
+            .filter(|entry| {
+                !matches!(
+                    entry.status,
+                    DownloadStatus::Completed | DownloadStatus::Cancelled
+                )
+            })
+            .map(|entry| entry.dest_dir)
+            .collect()
+    }
 
-        for entry in WalkDir::new(library_root)
+    /// Walk candidate model directories that have no metadata.json.
+    ///
+    /// Covers the `{type}/{family}/{name}` depth, pruning ignored subtrees and
+    /// in-flight import temp directories.
+    fn unindexed_dirs<'a>(&'a self, library_root: &'a Path) -> impl Iterator<Item = PathBuf> + 'a {
+        let scan_ignore = self.library.scan_ignore();
+        WalkDir::new(library_root)
             .min_depth(1)
             .max_depth(3)
             .into_iter()
+            .filter_entry(move |entry| {
+                !entry.file_type().is_dir()
+                    || !scan_ignore.is_ignored_under(library_root, entry.path())
+            })
             .filter_map(|entry| entry.ok())
-        {
-            if !entry.file_type().is_dir() {
-                continue;
-            }
+            .filter(|entry| entry.file_type().is_dir())
+            .filter(|entry| {
+                !entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(TEMP_IMPORT_PREFIX)
+            })
+            .map(|entry| entry.into_path())
+            .filter(|dir| !dir.join("metadata.json").exists())
+    }
 
-            let dir = entry.path();
-            let dir_name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("");
+    /// Find directories with model files but no metadata.json.
+    fn find_orphan_dirs(&self, library_root: &Path, stop_after_first: bool) -> Vec<PathBuf> {
+        let mut orphans = Vec::new();
+        let model_extensions: &[&str] =
+            &["gguf", "safetensors", "pt", "pth", "ckpt", "bin", "onnx"];
 
-            if dir_name.starts_with(TEMP_IMPORT_PREFIX) || dir_name.starts_with('.') {
-                continue;
-            }
+        let live_download_dirs = self.live_download_dirs();
 
-            if dir.join("metadata.json").exists() {
+        for dir in self.unindexed_dirs(library_root) {
+            let dir = dir.as_path();
+
+            if is_download_in_progress(dir, &live_download_dirs) {
                 continue;
             }
 
@@ -392,13 +510,6 @@ impl ModelImporter {
                 Err(_) => continue,
             };
 
-            if entries
-                .iter()
-                .any(|entry| entry.file_name().to_string_lossy().ends_with(".part"))
-            {
-                continue;
-            }
-
             let has_model_files = entries.iter().any(|entry| {
                 if !entry.file_type().ok().is_some_and(|ty| ty.is_file()) {
                     return false;
@@ -459,3 +570,20 @@ struct InferredSpec {
     family: String,
     official_name: String,
 }
+
+/// Whether files under `dir` are still being written by a download.
+///
+/// True when `dir` lies inside a live download's destination or contains
+/// `.part` files anywhere below it.
+pub(super) fn is_download_in_progress(dir: &Path, live_download_dirs: &HashSet<PathBuf>) -> bool {
+    live_download_dirs
+        .iter()
+        .any(|download_dir| dir.starts_with(download_dir))
+        || WalkDir::new(dir)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                entry.file_type().is_file()
+                    && entry.file_name().to_string_lossy().ends_with(".part")
+            })
+}
@@ -7,14 +7,16 @@
 //! - SQLite indexing with FTS5 full-text search
 //! - Model enumeration and querying
 
+mod consistency;
 mod migration;
 mod projection;
 
+use crate::cancel::CancellationToken;
 use crate::error::{PumasError, Result};
 use crate::index::{
     DependencyProfileRecord, ModelDependencyBindingRecord, ModelIndex,
     ModelPackageFactsCacheRecord, ModelPackageFactsCacheRowState, ModelPackageFactsCacheScope,
-    ModelRecord, SearchResult,
+    ModelRecord, SearchOptions, SearchResult,
 };
 use crate::metadata::{atomic_read_json, atomic_write_json};
 use crate::model_library::artifact_load_target::{
@@ -24,10 +26,12 @@ use crate::model_library::external_assets::{
     get_diffusers_bundle_lookup_hints, is_diffusers_bundle, is_external_reference,
     refresh_external_metadata_validation, MODEL_EXECUTION_CONTRACT_VERSION,
 };
-use crate::model_library::hashing::{verify_blake3, verify_sha256};
+use crate::model_library::hashing::{compute_dual_hash, verify_blake3, verify_sha256, DualHash};
 use crate::model_library::identifier::{identify_model_type, ModelTypeInfo};
 use crate::model_library::importer::detect_dllm_from_config_json;
-use crate::model_library::naming::normalize_name;
+use crate::model_library::naming::{
+    find_entry_ignoring_case, is_case_insensitive_fs, normalize_name,
+};
 use crate::model_library::package_facts::{
     artifact_logical_size_facts, auto_map_sources_from_config, backend_hint_facts,
     companion_artifacts, custom_generate_dependency_manifests, custom_generate_sources,
@@ -36,37 +40,43 @@ use crate::model_library::package_facts::{
     package_class_references, package_component_facts, package_facts_summary,
     transformers_package_evidence, PackageInspectionContext,
 };
+use crate::model_library::path_template::ModelPathTemplate;
+use crate::model_library::scan_ignore::ScanIgnore;
 use crate::model_library::types::{
-    HuggingFaceEvidence, ModelMetadata, ModelOverrides, ModelReviewFilter, ModelReviewItem,
-    ModelType, SubmitModelReviewResult,
+    HuggingFaceEvidence, ModelFileLinkCount, ModelMetadata, ModelOverrides, ModelReviewFilter,
+    ModelReviewItem, ModelType, SubmitModelReviewResult,
 };
 use crate::model_library::{
     normalize_architecture_family, normalize_artifact_path_slug, normalize_recommended_backend,
     normalize_review_reasons, normalize_task_signature, push_review_reason,
     resolve_model_type_with_rules, validate_metadata_v2_with_index,
-    versioned_architecture_family_from_text, LinkRegistry, ModelTypeResolution,
+    versioned_architecture_family_from_text, LinkRegistry, ModelTypeResolution, RebuildProgress,
     SelectedArtifactIdentity, TaskNormalizationStatus,
 };
 use crate::models::{
     AssetValidationState, CustomCodeFacts, ModelExecutionDescriptor,
-    ModelExecutionDescriptorBatchItem, ModelFactFamily, ModelLibraryChangeKind,
+    ModelExecutionDescriptorBatchItem, ModelFactFamily, ModelHashes, ModelLibraryChangeKind,
     ModelLibraryRefreshScope, ModelPackageFactsSummaryBatchItem, ModelPackageFactsSummaryResult,
-    ModelPackageFactsSummarySnapshot, ModelPackageFactsSummaryStatus, ModelRefMigrationDiagnostic,
-    PackageArtifactKind, PumasArtifactLoadTargetResolutionMode, PumasModelRef,
-    ResolveModelArtifactLoadTargetRequest, ResolveModelArtifactLoadTargetResponse,
+    ModelPackageFactsSummarySnapshot, ModelPackageFactsSummaryStatus, ModelReadiness,
+    ModelRefMigrationDiagnostic, PackageArtifactKind, PumasArtifactLoadTargetResolutionMode,
+    PumasModelRef, ResolveModelArtifactLoadTargetRequest, ResolveModelArtifactLoadTargetResponse,
     ResolvedArtifactFacts, ResolvedModelPackageFacts, ResolvedModelPackageFactsSummary,
     StorageKind, TaskEvidence, PACKAGE_FACTS_CONTRACT_VERSION,
 };
+use crate::platform::{
+    can_create_symlinks, extended_length_path, hardlink_count, is_cross_filesystem,
+};
 use serde_json::Value;
-use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::io::{BufReader, Read};
 use std::path::{Component, Path, PathBuf};
 use std::sync::OnceLock;
 use std::sync::{Arc, Mutex as StdMutex};
 use tokio::sync::broadcast;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock, Semaphore};
 use walkdir::WalkDir;
 
+pub use consistency::{IndexConsistencyReport, IndexInconsistency, IndexInconsistencyKind};
 use migration::{
     MigrationCheckpointState, MigrationReportIndex, MigrationReportIndexEntry,
     PackageFactsCacheMigrationCheckpointState,
@@ -133,11 +143,11 @@ fn parse_model_card_json(model_card_json: Option<&str>) -> Option<HashMap<String
 const MODEL_EXTENSIONS: &[&str] = &["gguf", "safetensors", "pt", "pth", "ckpt", "bin", "onnx"];
 
 /// Filename for model metadata in each model directory.
-const METADATA_FILENAME: &str = "metadata.json";
+pub(crate) const METADATA_FILENAME: &str = "metadata.json";
 /// Filename for user overrides in each model directory.
-const OVERRIDES_FILENAME: &str = "overrides.json";
+pub(crate) const OVERRIDES_FILENAME: &str = "overrides.json";
 /// SQLite database filename.
-const DB_FILENAME: &str = "models.db";
+pub(crate) const DB_FILENAME: &str = "models.db";
 /// Checkpoint file used by metadata v2 migration runner.
 const MIGRATION_CHECKPOINT_FILENAME: &str = ".metadata_v2_migration_checkpoint.json";
 /// Checkpoint file used by package-facts cache migration runner.
@@ -202,6 +212,14 @@ pub struct ModelLibrary {
     /// Optional callback used by primaries to suppress watcher feedback from
     /// Pumas-owned metadata projection writes.
     metadata_write_notifier: Arc<StdMutex<Option<MetadataWriteNotifier>>>,
+    /// Directories skipped by library scans and the watcher
+    scan_ignore: Arc<ScanIgnore>,
+    /// Layout of new model directories below the library root
+    path_template: Arc<ModelPathTemplate>,
+    /// Whether paths differing only by case name the same directory
+    case_insensitive_fs: bool,
+    /// Whether symlinks can be created below the library root
+    symlinks_supported: bool,
 }
 
 impl ModelLibrary {
@@ -213,16 +231,44 @@ impl ModelLibrary {
     ///
     /// * `library_root` - Root directory for the model library
     pub async fn new(library_root: impl Into<PathBuf>) -> Result<Self> {
+        Self::new_with_ignore_patterns(library_root, Vec::new()).await
+    }
+
+    /// Create a new ModelLibrary instance that skips extra directories in scans.
+    ///
+    /// `ignore_patterns` are added to the default ignore-list and the library
+    /// root's `.pumasignore` file; see [`ScanIgnore`].
+    pub async fn new_with_ignore_patterns(
+        library_root: impl Into<PathBuf>,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Self> {
         let library_root = library_root.into();
 
-        let (library_root, index, link_registry) = tokio::task::spawn_blocking(move || {
+        let (
+            library_root,
+            index,
+            link_registry,
+            scan_ignore,
+            case_insensitive_fs,
+            symlinks_supported,
+        ) = tokio::task::spawn_blocking(move || {
             std::fs::create_dir_all(&library_root)?;
             let library_root = library_root.canonicalize()?;
             let db_path = library_root.join(DB_FILENAME);
             let registry_path = library_root.join("link_registry.json");
             let index = ModelIndex::new(&db_path)?;
             let link_registry = LinkRegistry::new(registry_path);
-            Ok::<_, PumasError>((library_root, index, link_registry))
+            let scan_ignore = ScanIgnore::load(&library_root, &ignore_patterns);
+            let case_insensitive_fs = is_case_insensitive_fs(&library_root);
+            let symlinks_supported = can_create_symlinks(&library_root);
+            Ok::<_, PumasError>((
+                library_root,
+                index,
+                link_registry,
+                scan_ignore,
+                case_insensitive_fs,
+                symlinks_supported,
+            ))
         })
         .await
         .map_err(|err| {
@@ -241,12 +287,27 @@ impl ModelLibrary {
             write_lock: Arc::new(Mutex::new(())),
             package_facts_locks: Arc::new(Mutex::new(HashMap::new())),
             metadata_write_notifier: Arc::new(StdMutex::new(None)),
+            scan_ignore: Arc::new(scan_ignore),
+            path_template: Arc::new(ModelPathTemplate::default()),
+            case_insensitive_fs,
+            symlinks_supported,
         };
 
         // Rebuild index from existing metadata files on disk
-        // This ensures models are available immediately on startup
-        if let Err(e) = library.rebuild_index().await {
-            tracing::warn!("Failed to rebuild model index on startup: {}", e);
+        // This ensures models are available immediately on startup, and
+        // repopulates an index that was recreated after corruption.
+        match library.rebuild_index().await {
+            Ok(count) => {
+                if let Some(backup) = library.index.recovered_from() {
+                    tracing::warn!(
+                        "Recovered corrupt model index: re-indexed {} model(s) from metadata.json \
+                         (corrupt copy kept at {})",
+                        count,
+                        backup.display()
+                    );
+                }
+            }
+            Err(e) => tracing::warn!("Failed to rebuild model index on startup: {}", e),
         }
 
         Ok(library)
@@ -262,6 +323,53 @@ impl ModelLibrary {
         self.library_root.join(DB_FILENAME)
     }
 
+    /// Get the ignore-list applied to library scans.
+    pub fn scan_ignore(&self) -> &Arc<ScanIgnore> {
+        &self.scan_ignore
+    }
+
+    /// Use `template` for the directories of models added from now on.
+    ///
+    /// Existing models are not moved; see [`ModelPathTemplate`].
+    pub fn with_path_template(mut self, template: ModelPathTemplate) -> Self {
+        self.path_template = Arc::new(template);
+        self
+    }
+
+    /// Whether the library root is on a case-insensitive filesystem (the
+    /// macOS and Windows defaults), probed when the library is opened.
+    pub fn is_case_insensitive_fs(&self) -> bool {
+        self.case_insensitive_fs
+    }
+
+    /// Treat the library root as case-insensitive regardless of the probe.
+    #[cfg(test)]
+    pub(crate) fn with_case_insensitive_fs(mut self, case_insensitive_fs: bool) -> Self {
+        self.case_insensitive_fs = case_insensitive_fs;
+        self
+    }
+
+    /// Whether symlinks can be created on the library's filesystem, probed
+    /// when the library is opened. When false, mapping uses hardlinks or
+    /// copies.
+    pub fn can_create_symlinks(&self) -> bool {
+        self.symlinks_supported
+    }
+
+    /// Whether `src` and `dest` are on different filesystems, so a hardlink
+    /// between them is impossible.
+    ///
+    /// Compares device IDs on Unix and volume GUIDs on Windows. Paths that do
+    /// not exist yet are checked through their nearest existing parent.
+    pub fn is_cross_filesystem(&self, src: &Path, dest: &Path) -> Result<bool> {
+        is_cross_filesystem(src, dest).map_err(|err| PumasError::io_with_path(err, dest))
+    }
+
+    /// Get the layout used for new model directories.
+    pub fn path_template(&self) -> &ModelPathTemplate {
+        &self.path_template
+    }
+
     /// Get a reference to the link registry.
     pub fn link_registry(&self) -> &Arc<RwLock<LinkRegistry>> {
         &self.link_registry
@@ -292,37 +400,90 @@ impl ModelLibrary {
 
     /// Build the canonical path for a model.
     ///
-    /// Structure: library_root/{model_type}/{family}/{cleaned_name}/
+    /// Structure follows the library's [`ModelPathTemplate`], by default
+    /// library_root/{model_type}/{family}/{cleaned_name}/
     ///
     /// # Arguments
     ///
     /// * `model_type` - Type of model (llm, diffusion)
     /// * `family` - Model family/architecture
     /// * `cleaned_name` - Normalized model name
+    ///
+    /// On a case-insensitive filesystem the path can name an existing
+    /// directory spelled with different case; callers placing a new model
+    /// check [`find_colliding_model_path`](Self::find_colliding_model_path).
     pub fn build_model_path(&self, model_type: &str, family: &str, cleaned_name: &str) -> PathBuf {
-        let type_normalized = normalize_name(model_type);
-        let family_normalized = normalize_name(family);
-        let name_normalized = normalize_name(cleaned_name);
+        self.path_template.render_path(
+            &self.library_root,
+            &normalize_name(model_type),
+            &normalize_name(family),
+            &normalize_name(cleaned_name),
+        )
+    }
 
-        self.library_root
-            .join(&type_normalized)
-            .join(&family_normalized)
-            .join(&name_normalized)
+    /// Build the model ID that [`build_model_path`](Self::build_model_path) yields.
+    pub fn build_model_id(&self, model_type: &str, family: &str, cleaned_name: &str) -> String {
+        self.path_template.render_id(
+            &normalize_name(model_type),
+            &normalize_name(family),
+            &normalize_name(cleaned_name),
+        )
+    }
+
+    /// Existing directory that a model placed at `path` would occupy.
+    ///
+    /// Returns `path` itself when it exists. On a case-insensitive filesystem
+    /// a directory whose path differs only by case also collides, and is
+    /// returned in its on-disk spelling so model IDs derived from it match
+    /// the existing model.
+    pub fn find_colliding_model_path(&self, path: &Path) -> Option<PathBuf> {
+        if !self.case_insensitive_fs {
+            return path.exists().then(|| path.to_path_buf());
+        }
+
+        let relative = path.strip_prefix(&self.library_root).ok()?;
+        let mut resolved = self.library_root.clone();
+        for component in relative.components() {
+            let name = component.as_os_str().to_string_lossy();
+            resolved = find_entry_ignoring_case(&resolved, &name)?;
+        }
+        Some(resolved)
+    }
+
+    /// Show where a model would be stored without creating anything.
+    ///
+    /// `exists` reports a directory already at that path, which an import or
+    /// download would collide with.
+    pub fn preview_model_path(
+        &self,
+        model_type: &str,
+        family: &str,
+        official_name: &str,
+    ) -> ModelPathPreview {
+        let model_path = self.build_model_path(model_type, family, official_name);
+        ModelPathPreview {
+            model_id: self.build_model_id(model_type, family, official_name),
+            exists: self.find_colliding_model_path(&model_path).is_some(),
+            model_path: model_path.display().to_string(),
+        }
     }
 
     /// Build the canonical path for a selected repository artifact.
     ///
-    /// Structure: library_root/{model_type}/{family}/{artifact_slug}/
+    /// Structure follows the library's [`ModelPathTemplate`] with the artifact
+    /// slug as `{name}`, by default library_root/{model_type}/{family}/{artifact_slug}/
     pub fn build_artifact_model_path(
         &self,
         model_type: &str,
         family: &str,
         artifact_id: &str,
     ) -> PathBuf {
-        self.library_root
-            .join(normalize_name(model_type))
-            .join(normalize_name(family))
-            .join(normalize_artifact_path_slug(artifact_id))
+        self.path_template.render_path(
+            &self.library_root,
+            &normalize_name(model_type),
+            &normalize_name(family),
+            &normalize_artifact_path_slug(artifact_id),
+        )
     }
 
     /// Build the canonical model id for a selected repository artifact.
@@ -332,14 +493,39 @@ impl ModelLibrary {
         family: &str,
         artifact_id: &str,
     ) -> String {
-        format!(
-            "{}/{}/{}",
-            normalize_name(model_type),
-            normalize_name(family),
-            normalize_artifact_path_slug(artifact_id)
+        self.path_template.render_id(
+            &normalize_name(model_type),
+            &normalize_name(family),
+            &normalize_artifact_path_slug(artifact_id),
         )
     }
 
+    /// Async wrapper for [`Self::prepare_artifact_download_destination`].
+    ///
+    /// Looking for an earlier unknown partial entry walks the library tree,
+    /// so it runs on a blocking worker.
+    pub async fn prepare_artifact_download_destination_async(
+        &self,
+        model_type: &str,
+        family: &str,
+        artifact_id: &str,
+    ) -> Result<PathBuf> {
+        let library = self.clone();
+        let model_type = model_type.to_string();
+        let family = family.to_string();
+        let artifact_id = artifact_id.to_string();
+        tokio::task::spawn_blocking(move || {
+            library.prepare_artifact_download_destination(&model_type, &family, &artifact_id)
+        })
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!(
+                "Failed to join artifact download destination task: {}",
+                err
+            ))
+        })?
+    }
+
     /// Return the destination for a selected artifact download, moving an
     /// earlier unknown partial entry into the resolved artifact path when safe.
     pub fn prepare_artifact_download_destination(
@@ -419,10 +605,17 @@ impl ModelLibrary {
     ///
     /// Yields paths to model directories (directories containing metadata.json).
     /// Recursively searches all depths to match Python backend behavior.
+    /// Directories on the scan ignore-list are skipped with their subtrees.
     pub fn model_dirs(&self) -> impl Iterator<Item = PathBuf> + '_ {
         WalkDir::new(&self.library_root)
             .min_depth(1)
             .into_iter()
+            .filter_entry(|e| {
+                !e.file_type().is_dir()
+                    || !self
+                        .scan_ignore
+                        .is_ignored_under(&self.library_root, e.path())
+            })
             .filter_map(|e| e.ok())
             .filter(|e| e.file_type().is_file() && e.file_name() == METADATA_FILENAME)
             .map(|e| e.path().parent().unwrap().to_path_buf())
@@ -501,6 +694,21 @@ impl ModelLibrary {
     // Index Operations
     // ========================================
 
+    /// Reload one model's metadata and re-upsert its index row.
+    ///
+    /// A targeted alternative to [`Self::rebuild_index`] after a single model's
+    /// `metadata.json` or overrides changed. Fails with `ModelNotFound` when
+    /// the model directory has no `metadata.json`.
+    pub async fn refresh_model(&self, model_id: &str) -> Result<()> {
+        let model_dir = self.library_root.join(model_id);
+        if !tokio::fs::try_exists(model_dir.join("metadata.json")).await? {
+            return Err(PumasError::ModelNotFound {
+                model_id: model_id.to_string(),
+            });
+        }
+        self.index_model_dir(&model_dir).await
+    }
+
     /// Index a single model directory.
     ///
     /// Reads the metadata and adds/updates the model in the SQLite index.
@@ -916,6 +1124,23 @@ impl ModelLibrary {
     /// This is a fast operation that reads metadata.json files without
     /// re-computing hashes.
     pub async fn rebuild_index(&self) -> Result<usize> {
+        let (tx, _) = mpsc::channel(1);
+        self.rebuild_index_with_progress(tx, &CancellationToken::new())
+            .await
+    }
+
+    /// Rebuild the index, reporting each model directory read on `tx`.
+    ///
+    /// Progress is sent with `try_send`, so a full or closed channel drops
+    /// updates instead of stalling the rebuild. All directories are read
+    /// before the index is touched; cancelling `cancel` during that walk
+    /// returns [`PumasError::IndexRebuildCancelled`] with the index unchanged.
+    /// Once index writes begin the rebuild runs to completion.
+    pub async fn rebuild_index_with_progress(
+        &self,
+        tx: mpsc::Sender<RebuildProgress>,
+        cancel: &CancellationToken,
+    ) -> Result<usize> {
         tracing::info!("Rebuilding model index");
 
         let mut discovered_model_ids: HashSet<String> = HashSet::new();
@@ -932,7 +1157,18 @@ impl ModelLibrary {
             }
         }
 
-        for model_dir in self.model_dirs() {
+        let model_dirs = collect_model_dirs_async(self.clone()).await?;
+        let total = model_dirs.len();
+        for (current, model_dir) in model_dirs.into_iter().enumerate() {
+            if cancel.is_cancelled() {
+                tracing::info!("Model index rebuild cancelled after {} models", current);
+                return Err(PumasError::IndexRebuildCancelled);
+            }
+            let _ = tx.try_send(RebuildProgress {
+                current,
+                total,
+                current_model_id: self.get_model_id(&model_dir),
+            });
             if let Ok(Some(mut metadata)) =
                 load_model_metadata_async(self.clone(), model_dir.clone()).await
             {
@@ -1029,6 +1265,14 @@ impl ModelLibrary {
                 }
             }
         }
+        if cancel.is_cancelled() {
+            return Err(PumasError::IndexRebuildCancelled);
+        }
+        let _ = tx.try_send(RebuildProgress {
+            current: total,
+            total,
+            current_model_id: None,
+        });
 
         // Remove stale index rows for models that no longer exist on disk.
         // Existing rows for still-present model IDs are kept so FK-linked tables
@@ -1198,16 +1442,22 @@ impl ModelLibrary {
     /// Deep scan and rebuild with optional hash verification.
     ///
     /// This is a slower operation that can optionally recompute hashes
-    /// for verification.
+    /// for verification. Hashing runs on up to one blocking worker per CPU;
+    /// progress counts finished models, and mismatches and errors are sorted
+    /// by model directory.
     ///
     /// # Arguments
     ///
     /// * `verify_hashes` - Whether to recompute and verify file hashes
     /// * `progress_callback` - Optional callback for progress updates
+    /// * `cancel` - Checked before each model; once cancelled the scan stops and
+    ///   returns the partial result with `cancelled` set. The WAL is still
+    ///   checkpointed so models indexed so far are persisted.
     pub async fn deep_scan_rebuild<F>(
         &self,
         verify_hashes: bool,
         mut progress_callback: Option<F>,
+        cancel: &CancellationToken,
     ) -> Result<DeepScanResult>
     where
         F: FnMut(DeepScanProgress),
@@ -1224,25 +1474,45 @@ impl ModelLibrary {
             hash_verified: 0,
             hash_mismatches: Vec::new(),
             errors: Vec::new(),
+            cancelled: false,
         };
 
         // Clear and rebuild
         self.index.clear()?;
 
-        for (idx, model_dir) in model_dirs.iter().enumerate() {
-            // Report progress
-            if let Some(ref mut callback) = progress_callback {
+        // Progress counts finished models, so it stays monotonic while hash
+        // workers complete out of order.
+        let stage = if verify_hashes {
+            "Verifying"
+        } else {
+            "Indexing"
+        };
+        let mut processed = 0;
+        let mut report_done = |model_dir: &Path| {
+            processed += 1;
+            if let Some(callback) = progress_callback.as_mut() {
                 callback(DeepScanProgress {
-                    current: idx + 1,
+                    current: processed,
                     total,
                     current_model: model_dir.display().to_string(),
-                    stage: if verify_hashes {
-                        "Verifying"
-                    } else {
-                        "Indexing"
-                    },
+                    stage,
                 });
             }
+        };
+
+        // Hash verification runs on blocking workers, at most one per CPU.
+        // Outcomes are folded into `result` on this task as workers finish.
+        let hash_workers = std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1);
+        let semaphore = Arc::new(Semaphore::new(hash_workers));
+        let mut verifications = tokio::task::JoinSet::new();
+
+        for model_dir in &model_dirs {
+            if cancel.is_cancelled() {
+                result.cancelled = true;
+                break;
+            }
 
             // Load metadata
             let metadata = match load_model_metadata_async(self.clone(), model_dir.clone()).await {
@@ -1251,38 +1521,16 @@ impl ModelLibrary {
                     result
                         .errors
                         .push((model_dir.clone(), "No metadata".to_string()));
+                    report_done(model_dir);
                     continue;
                 }
                 Err(e) => {
                     result.errors.push((model_dir.clone(), e.to_string()));
+                    report_done(model_dir);
                     continue;
                 }
             };
 
-            // Optionally verify hashes
-            if verify_hashes {
-                match verify_model_hash(model_dir, &metadata) {
-                    Ok(true) => {
-                        result.hash_verified += 1;
-                    }
-                    Ok(false) => {
-                        // Hash mismatch - record it
-                        let model_name = metadata
-                            .official_name
-                            .clone()
-                            .unwrap_or_else(|| model_dir.display().to_string());
-                        result.hash_mismatches.push((
-                            model_dir.clone(),
-                            format!("Hash mismatch for {}", model_name),
-                        ));
-                    }
-                    Err(e) => {
-                        // Verification error - record as error but continue
-                        result.errors.push((model_dir.clone(), e));
-                    }
-                }
-            }
-
             // Index the model
             if let Some(model_id) = self.get_model_id(model_dir) {
                 let record = metadata_to_record(&model_id, model_dir, &metadata);
@@ -1290,13 +1538,53 @@ impl ModelLibrary {
                     result.indexed += 1;
                 }
             }
+
+            if !verify_hashes {
+                report_done(model_dir);
+                continue;
+            }
+
+            // Optionally verify hashes
+            let permit = semaphore.clone().acquire_owned().await.map_err(|e| {
+                PumasError::Other(format!("Hash verification semaphore closed: {}", e))
+            })?;
+            let model_dir = model_dir.clone();
+            verifications.spawn_blocking(move || {
+                let _permit = permit;
+                let outcome = verify_model_hash(&model_dir, &metadata);
+                HashVerification {
+                    model_dir,
+                    official_name: metadata.official_name,
+                    outcome,
+                }
+            });
+
+            while let Some(joined) = verifications.try_join_next() {
+                if let Some(model_dir) = record_hash_verification(&mut result, joined) {
+                    report_done(&model_dir);
+                }
+            }
+        }
+
+        // Workers already running finish even after cancellation.
+        while let Some(joined) = verifications.join_next().await {
+            if let Some(model_dir) = record_hash_verification(&mut result, joined) {
+                report_done(&model_dir);
+            }
         }
+        result.hash_mismatches.sort();
+        result.errors.sort();
 
         // Checkpoint WAL
         self.index.checkpoint_wal()?;
 
         tracing::info!(
-            "Deep scan complete: {} indexed, {} verified, {} errors",
+            "Deep scan {}: {} indexed, {} verified, {} errors",
+            if result.cancelled {
+                "cancelled"
+            } else {
+                "complete"
+            },
             result.indexed,
             result.hash_verified,
             result.errors.len()
@@ -1305,6 +1593,165 @@ impl ModelLibrary {
         Ok(result)
     }
 
+    /// Compute missing SHA256/BLAKE3 hashes for library models.
+    ///
+    /// Models whose metadata lacks either hash (older imports, in-place
+    /// imports that deferred BLAKE3) have their primary file hashed on up to
+    /// `concurrency` blocking workers. Each model's metadata is saved and its
+    /// index row refreshed as soon as its hash finishes, so a cancelled run
+    /// keeps its progress and the next run only picks up models that are still
+    /// missing a hash. A stored hash that disagrees with the file is reported
+    /// as an error and left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - Maximum number of models hashed at once (at least one)
+    /// * `progress_callback` - Optional callback, called as each model finishes
+    /// * `cancel` - Checked before each model; once cancelled no new models
+    ///   are started, models already hashing are saved, and the partial result
+    ///   is returned with `cancelled` set.
+    pub async fn backfill_hashes<F>(
+        &self,
+        concurrency: usize,
+        mut progress_callback: Option<F>,
+        cancel: &CancellationToken,
+    ) -> Result<HashBackfillResult>
+    where
+        F: FnMut(HashBackfillProgress),
+    {
+        let model_dirs = collect_model_dirs_async(self.clone()).await?;
+        let mut result = HashBackfillResult::default();
+
+        let mut pending = Vec::new();
+        for model_dir in model_dirs {
+            match load_model_metadata_async(self.clone(), model_dir.clone()).await {
+                Ok(Some(metadata)) if is_missing_hashes(&metadata) => pending.push(model_dir),
+                Ok(_) => {}
+                Err(e) => result.errors.push((model_dir, e.to_string())),
+            }
+        }
+        result.missing = pending.len();
+        let total = pending.len();
+        tracing::info!(
+            "Starting hash backfill for {} models (concurrency={})",
+            total,
+            concurrency
+        );
+
+        let mut processed = 0;
+        let mut report_done = |model_dir: &Path| {
+            processed += 1;
+            if let Some(callback) = progress_callback.as_mut() {
+                callback(HashBackfillProgress {
+                    current: processed,
+                    total,
+                    current_model: model_dir.display().to_string(),
+                });
+            }
+        };
+
+        // At most `concurrency` workers run at once. When all are busy, the
+        // next one to finish is saved before cancellation is re-checked, so a
+        // cancel from the progress callback stops new work immediately.
+        let concurrency = concurrency.max(1);
+        let mut workers = tokio::task::JoinSet::new();
+
+        for model_dir in pending {
+            while workers.len() >= concurrency {
+                let Some(joined) = workers.join_next().await else {
+                    break;
+                };
+                if let Some(model_dir) = self.record_backfilled_hash(&mut result, joined).await {
+                    report_done(&model_dir);
+                }
+            }
+            if cancel.is_cancelled() {
+                result.cancelled = true;
+                break;
+            }
+            workers.spawn_blocking(move || {
+                let hashes = find_primary_model_file(&model_dir)
+                    .map(compute_dual_hash)
+                    .transpose();
+                (model_dir, hashes)
+            });
+        }
+
+        while let Some(joined) = workers.join_next().await {
+            if let Some(model_dir) = self.record_backfilled_hash(&mut result, joined).await {
+                report_done(&model_dir);
+            }
+        }
+        result.errors.sort();
+
+        tracing::info!(
+            "Hash backfill {}: {} hashed, {} without weights, {} errors",
+            if result.cancelled {
+                "cancelled"
+            } else {
+                "complete"
+            },
+            result.hashed,
+            result.no_weights,
+            result.errors.len()
+        );
+
+        Ok(result)
+    }
+
+    /// Save one backfill worker's hashes, returning the model directory.
+    async fn record_backfilled_hash(
+        &self,
+        result: &mut HashBackfillResult,
+        joined: std::result::Result<(PathBuf, Result<Option<DualHash>>), tokio::task::JoinError>,
+    ) -> Option<PathBuf> {
+        let (model_dir, hashes) = match joined {
+            Ok(joined) => joined,
+            Err(err) => {
+                tracing::warn!("Hash backfill worker failed: {}", err);
+                return None;
+            }
+        };
+
+        let saved = match hashes {
+            Ok(Some(hashes)) => self.save_backfilled_hashes(&model_dir, hashes).await,
+            Ok(None) => {
+                result.no_weights += 1;
+                return Some(model_dir);
+            }
+            Err(e) => Err(e),
+        };
+        match saved {
+            Ok(()) => result.hashed += 1,
+            Err(e) => result.errors.push((model_dir.clone(), e.to_string())),
+        }
+        Some(model_dir)
+    }
+
+    /// Fill in a model's missing hashes and refresh its index row.
+    ///
+    /// Metadata is re-read here rather than reused from the scan, so edits
+    /// made while the file was hashing are kept.
+    async fn save_backfilled_hashes(&self, model_dir: &Path, hashes: DualHash) -> Result<()> {
+        let mut metadata = load_model_metadata_async(self.clone(), model_dir.to_path_buf())
+            .await?
+            .ok_or_else(|| PumasError::Other("No metadata".to_string()))?;
+        let stored = metadata.hashes.take().unwrap_or_default();
+        let check = |stored: Option<String>, actual: String| match stored {
+            Some(expected) if !expected.is_empty() && !expected.eq_ignore_ascii_case(&actual) => {
+                Err(PumasError::HashMismatch { expected, actual })
+            }
+            _ => Ok(Some(actual)),
+        };
+        metadata.hashes = Some(ModelHashes {
+            sha256: check(stored.sha256, hashes.sha256)?,
+            blake3: check(stored.blake3, hashes.blake3)?,
+        });
+
+        self.save_metadata(model_dir, &metadata).await?;
+        self.index_model_dir(model_dir).await
+    }
+
     // ========================================
     // Query Operations
     // ========================================
@@ -1371,6 +1818,57 @@ impl ModelLibrary {
             .map_err(|err| PumasError::Other(format!("Failed to join get_model task: {}", err)))?
     }
 
+    /// Report the hardlink count of every regular file in a model directory.
+    ///
+    /// Files with a count above 1 share their data with links outside the
+    /// library (for example hardlinks mapped into an app), so deleting the
+    /// model does not free that space. Symlinks are skipped.
+    pub async fn file_link_count(&self, model_id: &str) -> Result<Vec<ModelFileLinkCount>> {
+        let library = self.clone();
+        let model_id = model_id.to_string();
+        tokio::task::spawn_blocking(move || library.file_link_count_sync(&model_id))
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join file_link_count task: {}", err))
+            })?
+    }
+
+    fn file_link_count_sync(&self, model_id: &str) -> Result<Vec<ModelFileLinkCount>> {
+        let model_dir = self.library_root.join(model_id);
+        let io_dir = extended_length_path(&model_dir);
+        if !io_dir.is_dir() {
+            return Err(PumasError::ModelNotFound {
+                model_id: model_id.to_string(),
+            });
+        }
+
+        let mut files = Vec::new();
+        for entry in WalkDir::new(&io_dir).follow_links(false) {
+            let entry = entry.map_err(|err| PumasError::Other(err.to_string()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let metadata = entry
+                .metadata()
+                .map_err(|err| PumasError::Other(err.to_string()))?;
+            let link_count = hardlink_count(entry.path())
+                .map_err(|err| PumasError::io_with_path(err, entry.path()))?;
+            let relative = entry
+                .path()
+                .strip_prefix(&io_dir)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.push(ModelFileLinkCount {
+                path: relative,
+                size: metadata.len(),
+                link_count,
+            });
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+
     /// Search models using FTS5 full-text search.
     ///
     /// # Arguments
@@ -1432,6 +1930,32 @@ impl ModelLibrary {
         })?
     }
 
+    /// Search with field filters and ordering.
+    ///
+    /// `query` may contain field filters such as `family:llama` or
+    /// `size>4gb` (see [`crate::index::parse_search_query`]). Without
+    /// `options.sort_by`, results keep the default ID ordering.
+    pub async fn search_models_with_options(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+        options: SearchOptions,
+    ) -> Result<SearchResult> {
+        let library = self.clone();
+        let query = query.to_string();
+        tokio::task::spawn_blocking(move || {
+            library.search_models_with_options_sync(&query, limit, offset, &options)
+        })
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!(
+                "Failed to join search_models_with_options task: {}",
+                err
+            ))
+        })?
+    }
+
     /// List models currently requiring metadata review.
     pub async fn list_models_needing_review(
         &self,
@@ -1457,6 +1981,25 @@ impl ModelLibrary {
         self.load_effective_metadata_by_id(model_id)
     }
 
+    /// Map each of a model's stored filenames to the filename it was imported under.
+    ///
+    /// Copy imports normalize filenames; this recovers names such as the
+    /// Hugging Face filename a workflow refers to. Files without a recorded
+    /// original name are omitted.
+    pub fn original_filenames(&self, model_id: &str) -> Result<BTreeMap<String, String>> {
+        let metadata =
+            self.get_effective_metadata(model_id)?
+                .ok_or_else(|| PumasError::ModelNotFound {
+                    model_id: model_id.to_string(),
+                })?;
+        Ok(metadata
+            .files
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|file| Some((file.name, file.original_name?)))
+            .collect())
+    }
+
     /// Submit a metadata review patch for a model.
     ///
     /// The patch is applied as JSON Merge Patch against the current effective metadata.
@@ -1677,6 +2220,55 @@ impl ModelLibrary {
         Ok(())
     }
 
+    /// Add and remove user tags on a model, returning the resulting tags.
+    ///
+    /// Matching is case-insensitive: a tag already present under another case
+    /// is not added again, and a removal drops every casing. Added tags are
+    /// trimmed; empty tags and tags containing line breaks are rejected.
+    /// Removals apply before additions.
+    pub async fn update_model_tags(
+        &self,
+        model_id: &str,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> Result<Vec<String>> {
+        for tag in add.iter().chain(&remove) {
+            if tag.trim().is_empty() || tag.contains(['\n', '\r']) {
+                return Err(PumasError::Validation {
+                    field: "tags".to_string(),
+                    message: format!("Tags must be non-empty single-line text, got {:?}", tag),
+                });
+            }
+        }
+
+        let model_dir = self.library_root.join(model_id);
+        let mut metadata = load_model_metadata_async(self.clone(), model_dir.clone())
+            .await?
+            .ok_or_else(|| PumasError::ModelNotFound {
+                model_id: model_id.to_string(),
+            })?;
+
+        let remove: HashSet<String> = remove.iter().map(|tag| tag.trim().to_lowercase()).collect();
+        let mut seen = HashSet::new();
+        let tags: Vec<String> = metadata
+            .tags
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|tag| !remove.contains(&tag.to_lowercase()))
+            .chain(add.iter().map(|tag| tag.trim().to_string()))
+            .filter(|tag| seen.insert(tag.to_lowercase()))
+            .collect();
+
+        metadata.tags = (!tags.is_empty()).then(|| tags.clone());
+        metadata.updated_date = Some(chrono::Utc::now().to_rfc3339());
+
+        self.save_metadata(&model_dir, &metadata).await?;
+        self.index_model_dir(&model_dir).await?;
+
+        Ok(tags)
+    }
+
     fn load_effective_metadata_by_id(&self, model_id: &str) -> Result<Option<ModelMetadata>> {
         if let Some(effective_json) = self.index.get_effective_metadata_json(model_id)? {
             let mut effective_value: Value = serde_json::from_str(&effective_json)?;
@@ -1775,10 +2367,44 @@ impl ModelLibrary {
         model_types: Option<&[String]>,
         tags: Option<&[String]>,
     ) -> Result<SearchResult> {
-        let mut result = self.index.search(query, model_types, tags, limit, offset)?;
+        let options = SearchOptions {
+            model_types: model_types.map(<[String]>::to_vec).unwrap_or_default(),
+            tags: tags.map(<[String]>::to_vec).unwrap_or_default(),
+            ..SearchOptions::default()
+        };
+        self.search_models_with_options_sync(query, limit, offset, &options)
+    }
+
+    fn search_models_with_options_sync(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+        options: &SearchOptions,
+    ) -> Result<SearchResult> {
+        let mut result = self
+            .index
+            .search_with_options(query, options, limit, offset)?;
+        let sql_order: HashMap<String, usize> = options
+            .sort_by
+            .map(|_| {
+                result
+                    .models
+                    .iter()
+                    .enumerate()
+                    .map(|(position, record)| (record.id.clone(), position))
+                    .collect()
+            })
+            .unwrap_or_default();
         self.project_dependency_bindings_for_records(&mut result.models)?;
         self.project_display_fields_for_records(&mut result.models);
         annotate_and_dedupe_records_by_repo_id(&mut result.models);
+        if !sql_order.is_empty() {
+            // Deduping reorders by ID; restore the requested sort.
+            result
+                .models
+                .sort_by_key(|record| sql_order.get(&record.id).copied());
+        }
         result.total_count = result.models.len();
         Ok(result)
     }
@@ -1997,13 +2623,15 @@ impl ModelLibrary {
     /// Delete a model from the library.
     ///
     /// This removes the model directory and cleans up all associated links.
+    /// For by-reference imports only the library-side links and metadata are
+    /// removed; the external originals are left in place.
     ///
     /// # Arguments
     ///
     /// * `model_id` - Model ID to delete
     /// * `cascade` - Whether to remove all symlinks pointing to this model
     pub async fn delete_model(&self, model_id: &str, cascade: bool) -> Result<()> {
-        let model_dir = self.library_root.join(model_id);
+        let model_dir = extended_length_path(&self.library_root.join(model_id));
 
         if !tokio::fs::try_exists(&model_dir).await? {
             return Err(PumasError::ModelNotFound {
@@ -2011,12 +2639,26 @@ impl ModelLibrary {
             });
         }
 
-        let storage_kind = load_model_metadata_async(self.clone(), model_dir.clone())
-            .await?
+        let metadata = load_model_metadata_async(self.clone(), model_dir.clone()).await?;
+        let storage_kind = metadata
+            .as_ref()
             .and_then(|metadata| metadata.storage_kind)
             .unwrap_or(StorageKind::LibraryOwned);
+        let shares_storage_with = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.shares_storage_with.clone());
+        let sha256 = metadata
+            .and_then(|metadata| metadata.hashes)
+            .and_then(|hashes| hashes.sha256);
+
+        // Re-point sharers before anything is removed. If that fails, the
+        // model stays in place so no sharer is left pointing at a missing
+        // model, and the delete can be retried.
+        if let Some(sha256) = sha256 {
+            self.release_shared_storage(model_id, &sha256, shares_storage_with)
+                .await?;
+        }
 
-        // Remove from index first
         self.index.delete(model_id)?;
 
         // Cascade delete symlinks if requested
@@ -2048,6 +2690,47 @@ impl ModelLibrary {
         Ok(())
     }
 
+    /// Re-point models that share `model_id`'s primary file via a link.
+    ///
+    /// Hardlinked and reflinked bytes outlive the deleted directory on their
+    /// own; this only moves the `shares_storage_with` bookkeeping. Sharers
+    /// point at `canonical` (the model the deleted one itself shared with);
+    /// without one, the first sharer by ID becomes canonical.
+    async fn release_shared_storage(
+        &self,
+        model_id: &str,
+        sha256: &str,
+        mut canonical: Option<String>,
+    ) -> Result<()> {
+        let mut sharers = Vec::new();
+        for record in self.list_models().await? {
+            if record.hashes.get("sha256").map(String::as_str) != Some(sha256) {
+                continue;
+            }
+            let dir = self.library_root.join(&record.id);
+            let metadata = load_model_metadata_async(self.clone(), dir.clone()).await?;
+            if let Some(metadata) = metadata
+                .filter(|metadata| metadata.shares_storage_with.as_deref() == Some(model_id))
+            {
+                sharers.push((record.id, dir, metadata));
+            }
+        }
+        sharers.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (id, dir, mut metadata) in sharers {
+            metadata.shares_storage_with = canonical.clone();
+            self.save_metadata(&dir, &metadata).await?;
+            self.index_model_dir(&dir).await?;
+            tracing::info!(
+                "Model {} keeps the storage it shared with deleted model {}",
+                id,
+                model_id
+            );
+            canonical.get_or_insert(id);
+        }
+        Ok(())
+    }
+
     /// Get the total size of all models in the library.
     pub async fn total_size(&self) -> Result<u64> {
         let model_dirs = collect_model_dirs_async(self.clone()).await?;
@@ -3191,12 +3874,7 @@ impl ModelLibrary {
         });
 
         let new_dir = self.build_model_path(&new_type_str, &new_family, &cleaned_name);
-        let new_model_id = format!(
-            "{}/{}/{}",
-            normalize_name(&new_type_str),
-            normalize_name(&new_family),
-            normalize_name(&cleaned_name)
-        );
+        let new_model_id = self.build_model_id(&new_type_str, &new_family, &cleaned_name);
 
         if new_dir == model_dir {
             // Path didn't change (directory already correct)
@@ -3640,7 +4318,11 @@ async fn calculate_total_size_async(model_dirs: Vec<PathBuf>) -> Result<u64> {
         let mut total = 0_u64;
 
         for model_dir in model_dirs {
-            for entry in WalkDir::new(&model_dir).into_iter().filter_map(|e| e.ok()) {
+            for entry in WalkDir::new(&model_dir)
+                .follow_links(true)
+                .into_iter()
+                .filter_map(|e| e.ok())
+            {
                 if entry.file_type().is_file() {
                     let filename = entry.file_name().to_string_lossy();
                     if filename == METADATA_FILENAME || filename == OVERRIDES_FILENAME {
@@ -3664,7 +4346,7 @@ async fn calculate_total_size_async(model_dirs: Vec<PathBuf>) -> Result<u64> {
     })?
 }
 
-async fn collect_model_dirs_async(library: ModelLibrary) -> Result<Vec<PathBuf>> {
+pub(crate) async fn collect_model_dirs_async(library: ModelLibrary) -> Result<Vec<PathBuf>> {
     tokio::task::spawn_blocking(move || library.model_dirs().collect())
         .await
         .map_err(|err| {
@@ -3916,6 +4598,17 @@ fn runtime_binding_expectation(
     }
 }
 
+/// Destination an import or download would use, from [`ModelLibrary::preview_model_path`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModelPathPreview {
+    /// Model ID the destination maps to.
+    pub model_id: String,
+    /// Absolute destination directory.
+    pub model_path: String,
+    /// Whether something already exists at the destination.
+    pub exists: bool,
+}
+
 /// Result of a library-wide reclassification operation.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct ReclassifyResult {
@@ -5517,10 +6210,12 @@ fn render_migration_execution_markdown(report: &MigrationExecutionReport) -> Str
 ///
 /// This is used for hash verification - the hashes in metadata correspond to the
 /// primary (largest) model file in the directory.
-fn find_primary_model_file(model_dir: &Path) -> Option<PathBuf> {
+pub(crate) fn find_primary_model_file(model_dir: &Path) -> Option<PathBuf> {
     let mut largest: Option<(PathBuf, u64)> = None;
 
+    // Follow links so by-reference imports resolve to their linked files.
     for entry in WalkDir::new(model_dir)
+        .follow_links(true)
         .min_depth(1)
         .max_depth(2) // Allow one level of nesting
         .into_iter()
@@ -5991,6 +6686,62 @@ fn detect_model_type_from_name_tokens(model_dir: &Path) -> Option<ModelType> {
 ///
 /// Returns Ok(true) if hash matches or no hash stored, Ok(false) if mismatch,
 /// or Err if verification failed due to I/O error.
+/// Outcome of one deep-scan hash worker.
+struct HashVerification {
+    model_dir: PathBuf,
+    official_name: Option<String>,
+    outcome: std::result::Result<bool, String>,
+}
+
+/// Fold one hash worker's outcome into `result`, returning the model directory.
+fn record_hash_verification(
+    result: &mut DeepScanResult,
+    joined: std::result::Result<HashVerification, tokio::task::JoinError>,
+) -> Option<PathBuf> {
+    let HashVerification {
+        model_dir,
+        official_name,
+        outcome,
+    } = match joined {
+        Ok(joined) => joined,
+        Err(err) => {
+            tracing::warn!("Hash verification worker failed: {}", err);
+            return None;
+        }
+    };
+
+    match outcome {
+        Ok(true) => {
+            result.hash_verified += 1;
+        }
+        Ok(false) => {
+            // Hash mismatch - record it
+            let model_name = official_name.unwrap_or_else(|| model_dir.display().to_string());
+            result.hash_mismatches.push((
+                model_dir.clone(),
+                format!("Hash mismatch for {}", model_name),
+            ));
+        }
+        Err(e) => {
+            // Verification error - record as error but continue
+            result.errors.push((model_dir.clone(), e));
+        }
+    }
+    Some(model_dir)
+}
+
+/// Whether a model's metadata lacks a SHA256 or BLAKE3 hash.
+///
+/// Empty strings count as missing; in-place imports store an empty BLAKE3
+/// when only the SHA256 was known.
+fn is_missing_hashes(metadata: &ModelMetadata) -> bool {
+    let missing = |hash: &Option<String>| hash.as_deref().is_none_or(str::is_empty);
+    metadata
+        .hashes
+        .as_ref()
+        .is_none_or(|hashes| missing(&hashes.sha256) || missing(&hashes.blake3))
+}
+
 fn verify_model_hash(
     model_dir: &Path,
     metadata: &ModelMetadata,
@@ -6294,6 +7045,34 @@ pub struct DeepScanResult {
     pub hash_mismatches: Vec<(PathBuf, String)>,
     /// Errors encountered
     pub errors: Vec<(PathBuf, String)>,
+    /// Whether the scan was cancelled before every model was processed
+    pub cancelled: bool,
+}
+
+/// Result of [`ModelLibrary::backfill_hashes`].
+#[derive(Debug, Clone, Default)]
+pub struct HashBackfillResult {
+    /// Models that were missing a SHA256 or BLAKE3 hash
+    pub missing: usize,
+    /// Models whose hashes were computed and saved
+    pub hashed: usize,
+    /// Models with no primary weight file to hash
+    pub no_weights: usize,
+    /// Errors encountered, sorted by model directory
+    pub errors: Vec<(PathBuf, String)>,
+    /// Whether the backfill was cancelled before every model was hashed
+    pub cancelled: bool,
+}
+
+/// Progress update for hash backfill.
+#[derive(Debug, Clone)]
+pub struct HashBackfillProgress {
+    /// Models finished so far (1-indexed)
+    pub current: usize,
+    /// Models missing hashes when the backfill started
+    pub total: usize,
+    /// Model that just finished
+    pub current_model: String,
 }
 
 /// Progress update for deep scan.
@@ -6782,8 +7561,47 @@ mod tests {
     async fn test_build_model_path() {
         let (_, library) = setup_library().await;
 
-        let path = library.build_model_path("llm", "llama", "Llama 2 7B");
-        assert!(path.ends_with("llm/llama/llama_2_7b"));
+        let path = library.build_model_path("llm", "llama", "Llama 2 7B");
+        assert!(path.ends_with("llm/llama/llama_2_7b"));
+    }
+
+    #[tokio::test]
+    async fn test_build_model_path_follows_configured_template() {
+        let (_, library) = setup_library().await;
+        let library =
+            library.with_path_template(ModelPathTemplate::parse("{family}/{name}").unwrap());
+
+        let path = library.build_model_path("llm", "llama", "Llama 2 7B");
+        assert_eq!(
+            path,
+            library.library_root().join("llama").join("llama_2_7b")
+        );
+        assert_eq!(
+            library.build_model_id("llm", "llama", "Llama 2 7B"),
+            "llama/llama_2_7b"
+        );
+        assert_eq!(
+            library.get_model_id(&path).as_deref(),
+            Some("llama/llama_2_7b")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preview_model_path_reports_existing_destination() {
+        let (_, library) = setup_library().await;
+
+        let preview = library.preview_model_path("llm", "llama", "Llama 2 7B");
+        assert_eq!(preview.model_id, "llm/llama/llama_2_7b");
+        assert!(!preview.exists);
+        assert!(!library.library_root().join("llm").exists());
+
+        std::fs::create_dir_all(library.build_model_path("llm", "llama", "Llama 2 7B")).unwrap();
+        let preview = library.preview_model_path("llm", "llama", "Llama 2 7B");
+        assert!(preview.exists);
+        assert_eq!(
+            PathBuf::from(&preview.model_path),
+            library.library_root().join("llm/llama/llama_2_7b")
+        );
     }
 
     #[tokio::test]
@@ -6877,6 +7695,411 @@ mod tests {
         assert_eq!(all_models.len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_rebuild_index_with_progress_reports_models_and_cancels_cleanly() {
+        let (_, library) = setup_library().await;
+        for i in 0..2 {
+            let model_dir = library.build_model_path("llm", "llama", &format!("model-{}", i));
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let metadata = ModelMetadata {
+                model_id: Some(format!("llm/llama/model-{}", i)),
+                model_type: Some("llm".to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+        }
+
+        let (tx, mut rx) = mpsc::channel(16);
+        let count = library
+            .rebuild_index_with_progress(tx, &CancellationToken::new())
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        let mut updates = Vec::new();
+        while let Some(update) = rx.recv().await {
+            updates.push(update);
+        }
+        assert_eq!(updates.len(), 3);
+        assert_eq!((updates[0].current, updates[0].total), (0, 2));
+        assert!(updates[0]
+            .current_model_id
+            .as_deref()
+            .is_some_and(|id| id.starts_with("llm/llama/model-")));
+        assert_eq!(
+            updates[2],
+            RebuildProgress {
+                current: 2,
+                total: 2,
+                current_model_id: None,
+            }
+        );
+
+        // A cancelled rebuild leaves rows for models removed from disk in place.
+        std::fs::remove_dir_all(library.build_model_path("llm", "llama", "model-0")).unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let (tx, _rx) = mpsc::channel(16);
+        let err = library
+            .rebuild_index_with_progress(tx, &cancel)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PumasError::IndexRebuildCancelled));
+        assert_eq!(library.list_models().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_deep_scan_rebuild_stops_when_cancelled() {
+        let (_, library) = setup_library().await;
+        for i in 0..3 {
+            let model_dir = library.build_model_path("llm", "llama", &format!("model-{}", i));
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+        }
+
+        let cancel = CancellationToken::new();
+        let result = library
+            .deep_scan_rebuild(
+                false,
+                Some(|progress: DeepScanProgress| {
+                    if progress.current == 2 {
+                        cancel.cancel();
+                    }
+                }),
+                &cancel,
+            )
+            .await
+            .unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!((result.total_models, result.indexed), (3, 2));
+        assert_eq!(library.list_models().await.unwrap().len(), 2);
+
+        let result = library
+            .deep_scan_rebuild(
+                false,
+                None::<fn(DeepScanProgress)>,
+                &CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+        assert!(!result.cancelled);
+        assert_eq!(result.indexed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_deep_scan_rebuild_verifies_hashes_in_parallel() {
+        let (_, library) = setup_library().await;
+        for i in 0..6 {
+            let model_dir = library.build_model_path("llm", "llama", &format!("model-{}", i));
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let weights = model_dir.join("model.gguf");
+            std::fs::write(&weights, format!("weights-{}", i)).unwrap();
+            let mut sha256 = crate::model_library::compute_dual_hash(&weights)
+                .unwrap()
+                .sha256;
+            if i % 3 == 0 {
+                sha256 = "0".repeat(64);
+            }
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                hashes: Some(crate::models::ModelHashes {
+                    sha256: Some(sha256),
+                    blake3: None,
+                }),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+        }
+
+        let mut progress = Vec::new();
+        let result = library
+            .deep_scan_rebuild(
+                true,
+                Some(|update: DeepScanProgress| progress.push((update.current, update.total))),
+                &CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((result.indexed, result.hash_verified), (6, 4));
+        assert_eq!(result.hash_mismatches.len(), 2);
+        assert!(result.hash_mismatches[0].0 < result.hash_mismatches[1].0);
+        assert!(result.errors.is_empty(), "errors: {:?}", result.errors);
+        assert_eq!(
+            progress,
+            (1..=6).map(|current| (current, 6)).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backfill_hashes_fills_only_missing_hashes() {
+        let (_, library) = setup_library().await;
+        let mut dirs = Vec::new();
+        for (i, name) in ["unhashed", "partial", "complete", "stale", "no-weights"]
+            .into_iter()
+            .enumerate()
+        {
+            let model_dir = library.build_model_path("llm", "llama", name);
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let weights = model_dir.join("model.gguf");
+            let hashes = if name == "no-weights" {
+                None
+            } else {
+                std::fs::write(&weights, format!("weights-{}", i)).unwrap();
+                let actual = compute_dual_hash(&weights).unwrap();
+                match name {
+                    "partial" => Some(ModelHashes {
+                        sha256: Some(actual.sha256),
+                        blake3: Some(String::new()),
+                    }),
+                    "complete" => Some(ModelHashes {
+                        sha256: Some(actual.sha256),
+                        blake3: Some(actual.blake3),
+                    }),
+                    "stale" => Some(ModelHashes {
+                        sha256: Some("0".repeat(64)),
+                        blake3: None,
+                    }),
+                    _ => None,
+                }
+            };
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                hashes,
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+            dirs.push(model_dir);
+        }
+
+        let result = library
+            .backfill_hashes(
+                2,
+                None::<fn(HashBackfillProgress)>,
+                &CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            (result.missing, result.hashed, result.no_weights),
+            (4, 2, 1)
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].0, dirs[3]);
+        for dir in &dirs[..3] {
+            let expected = compute_dual_hash(dir.join("model.gguf")).unwrap();
+            let hashes = library.load_metadata(dir).unwrap().unwrap().hashes.unwrap();
+            assert_eq!(hashes.sha256, Some(expected.sha256));
+            assert_eq!(hashes.blake3, Some(expected.blake3));
+        }
+        let stale = library.load_metadata(&dirs[3]).unwrap().unwrap();
+        assert_eq!(stale.hashes.unwrap().sha256, Some("0".repeat(64)));
+    }
+
+    #[tokio::test]
+    async fn test_backfill_hashes_resumes_after_cancel() {
+        let (_, library) = setup_library().await;
+        for i in 0..3 {
+            let model_dir = library.build_model_path("llm", "llama", &format!("model-{}", i));
+            std::fs::create_dir_all(&model_dir).unwrap();
+            std::fs::write(model_dir.join("model.gguf"), format!("weights-{}", i)).unwrap();
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+        }
+
+        let cancel = CancellationToken::new();
+        let result = library
+            .backfill_hashes(1, Some(|_: HashBackfillProgress| cancel.cancel()), &cancel)
+            .await
+            .unwrap();
+        assert!(result.cancelled);
+        assert_eq!((result.missing, result.hashed), (3, 1));
+
+        let mut progress = Vec::new();
+        let result = library
+            .backfill_hashes(
+                1,
+                Some(|update: HashBackfillProgress| progress.push((update.current, update.total))),
+                &CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+        assert!(!result.cancelled);
+        assert_eq!((result.missing, result.hashed), (2, 2));
+        assert_eq!(progress, vec![(1, 2), (2, 2)]);
+
+        let models = library.list_models().await.unwrap();
+        assert_eq!(models.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_verify_index_consistency_reports_and_repairs_drift() {
+        let (_, library) = setup_library().await;
+        for name in ["renamed", "unindexed", "current"] {
+            let model_dir = library.build_model_path("llm", "llama", name);
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                official_name: Some(format!("{} v2", name)),
+                updated_date: Some("2026-01-01T00:00:00+00:00".to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+            library.index_model_dir(&model_dir).await.unwrap();
+        }
+
+        let mut stale = library.index.get("llm/llama/renamed").unwrap().unwrap();
+        stale.official_name = "renamed v1".to_string();
+        stale.updated_at = "2025-01-01T00:00:00+00:00".to_string();
+        library.index.upsert(&stale).unwrap();
+        library.index.delete("llm/llama/unindexed").unwrap();
+        let mut staged = stale.clone();
+        staged.id = "llm/llama/staged-download".to_string();
+        library.index.upsert(&staged).unwrap();
+
+        let report = library.verify_index_consistency(false).await.unwrap();
+        assert_eq!((report.checked, report.repaired), (3, 0));
+        assert!(report.errors.is_empty(), "errors: {:?}", report.errors);
+        let found: Vec<_> = report
+            .inconsistencies
+            .iter()
+            .map(|item| (item.model_id.as_str(), item.kind, item.fields.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "llm/llama/renamed",
+                    IndexInconsistencyKind::StaleRow,
+                    vec!["official_name".to_string(), "updated_at".to_string()]
+                ),
+                (
+                    "llm/llama/staged-download",
+                    IndexInconsistencyKind::MissingMetadata,
+                    Vec::new()
+                ),
+                (
+                    "llm/llama/unindexed",
+                    IndexInconsistencyKind::MissingRow,
+                    Vec::new()
+                ),
+            ]
+        );
+        assert_eq!(
+            library
+                .index
+                .get("llm/llama/renamed")
+                .unwrap()
+                .unwrap()
+                .official_name,
+            "renamed v1"
+        );
+
+        let report = library.verify_index_consistency(true).await.unwrap();
+        assert_eq!(report.repaired, 2);
+        assert_eq!(
+            library
+                .index
+                .get("llm/llama/renamed")
+                .unwrap()
+                .unwrap()
+                .official_name,
+            "renamed v2"
+        );
+        assert!(library.index.get("llm/llama/unindexed").unwrap().is_some());
+
+        let report = library.verify_index_consistency(false).await.unwrap();
+        assert_eq!(report.inconsistencies.len(), 1);
+        assert_eq!(
+            report.inconsistencies[0].kind,
+            IndexInconsistencyKind::MissingMetadata
+        );
+        assert!(library
+            .index
+            .get("llm/llama/staged-download")
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_model_reindexes_only_that_model() {
+        let (_, library) = setup_library().await;
+        let mut dirs = Vec::new();
+        for name in ["edited", "untouched"] {
+            let model_dir = library.build_model_path("llm", "llama", name);
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let metadata = ModelMetadata {
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                official_name: Some(name.to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+            library.index_model_dir(&model_dir).await.unwrap();
+            dirs.push((model_dir, metadata));
+        }
+
+        for (model_dir, metadata) in &dirs {
+            let renamed = ModelMetadata {
+                official_name: Some("Renamed".to_string()),
+                ..metadata.clone()
+            };
+            atomic_write_json(&model_dir.join("metadata.json"), &renamed, true).unwrap();
+        }
+        library.refresh_model("llm/llama/edited").await.unwrap();
+
+        let name = |id: &str| library.index().get(id).unwrap().unwrap().official_name;
+        assert_eq!(name("llm/llama/edited"), "Renamed");
+        assert_eq!(name("llm/llama/untouched"), "untouched");
+        assert!(matches!(
+            library.refresh_model("llm/llama/missing").await,
+            Err(PumasError::ModelNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_index_skips_ignored_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let library =
+            ModelLibrary::new_with_ignore_patterns(temp_dir.path(), vec!["archive".to_string()])
+                .await
+                .unwrap();
+
+        for relative in [
+            "llm/llama/kept",
+            ".trash/llama/deleted",
+            "archive/llama/old",
+        ] {
+            let model_dir = library.library_root().join(relative);
+            std::fs::create_dir_all(&model_dir).unwrap();
+            let metadata = ModelMetadata {
+                model_id: Some(relative.to_string()),
+                model_type: Some("llm".to_string()),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+        }
+
+        assert_eq!(library.rebuild_index().await.unwrap(), 1);
+        assert_eq!(
+            library.model_dirs().collect::<Vec<_>>(),
+            vec![library.library_root().join("llm/llama/kept")]
+        );
+    }
+
     #[tokio::test]
     async fn test_rebuild_index_preserves_db_model_type_when_metadata_omits_type() {
         let (_, library) = setup_library().await;
@@ -12274,6 +13497,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_file_link_count_reports_hardlinks() {
+        let (tmp, library) = setup_library().await;
+        let model_dir = library.build_model_path("llm", "llama", "shared");
+        std::fs::create_dir_all(model_dir.join("extra")).unwrap();
+        write_min_safetensors(&model_dir.join("model.safetensors"));
+        std::fs::write(model_dir.join("extra").join("config.json"), b"{}").unwrap();
+        std::fs::hard_link(
+            model_dir.join("model.safetensors"),
+            tmp.path().join("app-model.safetensors"),
+        )
+        .unwrap();
+
+        let files = library.file_link_count("llm/llama/shared").await.unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "extra/config.json");
+        assert_eq!(files[0].link_count, 1);
+        assert!(!files[0].is_hard_linked());
+        assert_eq!(files[1].path, "model.safetensors");
+        assert_eq!(files[1].link_count, 2);
+        assert!(files[1].is_hard_linked());
+
+        assert!(matches!(
+            library.file_link_count("llm/llama/missing").await,
+            Err(PumasError::ModelNotFound { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_update_metadata_from_hf_advances_update_feed() {
         let (_tmp, library) = setup_library().await;
@@ -12426,6 +13678,66 @@ mod tests {
         assert!(updated.last_lookup_attempt.is_some());
     }
 
+    #[tokio::test]
+    async fn test_update_model_tags_dedups_case_insensitively_and_reindexes() {
+        let (_tmp, library) = setup_library().await;
+        let model_id = "llm/llama/tagged";
+        let model_dir = library.build_model_path("llm", "llama", "tagged");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        library
+            .save_metadata(
+                &model_dir,
+                &ModelMetadata {
+                    model_id: Some(model_id.to_string()),
+                    model_type: Some("llm".to_string()),
+                    family: Some("llama".to_string()),
+                    official_name: Some("tagged".to_string()),
+                    tags: Some(vec!["Chat".to_string(), "old".to_string()]),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+
+        let tags = library
+            .update_model_tags(
+                model_id,
+                vec![
+                    "chat".to_string(),
+                    " zebrafinch ".to_string(),
+                    "ZebraFinch".to_string(),
+                ],
+                vec!["OLD".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tags, vec!["Chat".to_string(), "zebrafinch".to_string()]);
+        let saved = library.load_metadata(&model_dir).unwrap().unwrap();
+        assert_eq!(saved.tags, Some(tags));
+        let results = library.search_models("zebrafinch", 10, 0).await.unwrap();
+        assert_eq!(results.models.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_model_tags_rejects_blank_and_multiline_tags() {
+        let (_tmp, library) = setup_library().await;
+
+        for tag in ["  ", "two\nlines"] {
+            let err = library
+                .update_model_tags("llm/llama/missing", vec![tag.to_string()], Vec::new())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PumasError::Validation { .. }), "{err}");
+        }
+        let err = library
+            .update_model_tags("llm/llama/missing", vec!["ok".to_string()], Vec::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PumasError::ModelNotFound { .. }), "{err}");
+    }
+
     #[tokio::test]
     async fn test_get_model_refreshes_external_validation_to_degraded() {
         let (temp_dir, library) = setup_library().await;
@@ -0,0 +1,166 @@
+use super::*;
+
+/// How an index row disagrees with its model's `metadata.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexInconsistencyKind {
+    /// Key fields of the row differ from the projection of `metadata.json`.
+    StaleRow,
+    /// `metadata.json` exists but the index has no row for the model.
+    MissingRow,
+    /// The index has a row but the model directory has no `metadata.json`.
+    MissingMetadata,
+}
+
+/// One model whose index row disagrees with its `metadata.json`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IndexInconsistency {
+    pub model_id: String,
+    pub kind: IndexInconsistencyKind,
+    /// Row fields that differ from `metadata.json`, for `StaleRow`.
+    pub fields: Vec<String>,
+    /// Whether the row was re-upserted from `metadata.json`.
+    pub repaired: bool,
+}
+
+/// Result of [`ModelLibrary::verify_index_consistency`].
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct IndexConsistencyReport {
+    /// Models with a `metadata.json` that were compared against the index
+    pub checked: usize,
+    /// Disagreements, sorted by model ID
+    pub inconsistencies: Vec<IndexInconsistency>,
+    /// Number of rows re-upserted from `metadata.json`
+    pub repaired: usize,
+    /// Models whose metadata could not be projected or repaired, sorted by
+    /// model directory
+    pub errors: Vec<(PathBuf, String)>,
+}
+
+impl ModelLibrary {
+    /// Compare every index row with its model's `metadata.json`.
+    ///
+    /// `metadata.json` is authoritative. Each model's expected row is
+    /// projected the same way indexing does, and the key fields (path, names,
+    /// model type, tags, hashes, and `updated_at` when the metadata records an
+    /// `updated_date`) are compared against the stored row. With `repair`,
+    /// stale and missing rows are re-upserted from `metadata.json`. Rows
+    /// without a `metadata.json` are reported but never removed, because
+    /// downloads in progress are indexed before their metadata is written.
+    pub async fn verify_index_consistency(&self, repair: bool) -> Result<IndexConsistencyReport> {
+        let mut report = IndexConsistencyReport::default();
+        let mut checked_ids = HashSet::new();
+
+        for model_dir in collect_model_dirs_async(self.clone()).await? {
+            let prepared = match self.prepare_index_projection_async(&model_dir).await {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    report.errors.push((model_dir, e.to_string()));
+                    continue;
+                }
+            };
+            report.checked += 1;
+            let model_id = prepared.model_id.clone();
+            checked_ids.insert(model_id.clone());
+
+            let (kind, fields) = match self.index.get(&model_id)? {
+                None => (IndexInconsistencyKind::MissingRow, Vec::new()),
+                Some(existing) => {
+                    let fields = stale_index_fields(&existing, &prepared);
+                    if fields.is_empty() {
+                        continue;
+                    }
+                    (IndexInconsistencyKind::StaleRow, fields)
+                }
+            };
+
+            let mut repaired = false;
+            if repair {
+                match self.persist_index_projection(&model_dir, prepared).await {
+                    Ok(_) => {
+                        repaired = true;
+                        report.repaired += 1;
+                    }
+                    Err(e) => report.errors.push((model_dir, e.to_string())),
+                }
+            }
+            report.inconsistencies.push(IndexInconsistency {
+                model_id,
+                kind,
+                fields: fields.into_iter().map(str::to_string).collect(),
+                repaired,
+            });
+        }
+
+        for model_id in self.index.get_all_ids()? {
+            if checked_ids.contains(&model_id) {
+                continue;
+            }
+            let metadata_path = self.library_root.join(&model_id).join(METADATA_FILENAME);
+            if tokio::fs::try_exists(&metadata_path).await.unwrap_or(false) {
+                // Projection failed; already reported in `errors`.
+                continue;
+            }
+            report.inconsistencies.push(IndexInconsistency {
+                model_id,
+                kind: IndexInconsistencyKind::MissingMetadata,
+                fields: Vec::new(),
+                repaired: false,
+            });
+        }
+
+        report
+            .inconsistencies
+            .sort_by(|left, right| left.model_id.cmp(&right.model_id));
+        report.errors.sort();
+
+        tracing::info!(
+            "Index consistency check: {} checked, {} inconsistent, {} repaired",
+            report.checked,
+            report.inconsistencies.len(),
+            report.repaired
+        );
+        Ok(report)
+    }
+}
+
+/// Key fields of `existing` that differ from the row indexing would write.
+fn stale_index_fields(
+    existing: &ModelRecord,
+    prepared: &PreparedIndexProjection,
+) -> Vec<&'static str> {
+    let expected = &prepared.record;
+    let metadata = &prepared.metadata;
+    // Rows for metadata without a type keep whatever type the index already
+    // had, and rows without `updated_date` take a filesystem timestamp, so
+    // neither is a reliable signal of drift.
+    let has_model_type = metadata
+        .model_type
+        .as_deref()
+        .is_some_and(|model_type| !model_type.trim().is_empty());
+
+    [
+        ("path", existing.path != expected.path),
+        (
+            "cleaned_name",
+            existing.cleaned_name != expected.cleaned_name,
+        ),
+        (
+            "official_name",
+            existing.official_name != expected.official_name,
+        ),
+        (
+            "model_type",
+            has_model_type && existing.model_type != expected.model_type,
+        ),
+        ("tags", existing.tags != expected.tags),
+        ("hashes", existing.hashes != expected.hashes),
+        (
+            "updated_at",
+            metadata.updated_date.is_some() && existing.updated_at != expected.updated_at,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, differs)| differs.then_some(field))
+    .collect()
+}
@@ -8,6 +8,38 @@ async fn path_exists(path: &Path) -> Result<bool> {
         .map_err(|err| PumasError::io_with_path(err, path))
 }
 
+async fn generate_migration_dry_run_report_async(
+    library: ModelLibrary,
+) -> Result<MigrationDryRunReport> {
+    tokio::task::spawn_blocking(move || library.generate_migration_dry_run_report())
+        .await
+        .map_err(|err| {
+            PumasError::Other(format!(
+                "Failed to join migration dry-run report task: {}",
+                err
+            ))
+        })?
+}
+
+async fn validate_post_migration_integrity_async(
+    library: ModelLibrary,
+) -> Result<(PostMigrationIntegritySummary, Vec<String>)> {
+    tokio::task::spawn_blocking(move || {
+        let integrity = library.validate_post_migration_integrity()?;
+        Ok((
+            integrity,
+            collect_orphan_payload_dirs(&library.library_root),
+        ))
+    })
+    .await
+    .map_err(|err| {
+        PumasError::Other(format!(
+            "Failed to join post-migration integrity task: {}",
+            err
+        ))
+    })?
+}
+
 async fn load_migration_checkpoint_async(
     path: PathBuf,
 ) -> Result<Option<MigrationCheckpointState>> {
@@ -1151,12 +1183,7 @@ impl ModelLibrary {
                 let target_cleaned_name = cleaned_name.clone();
                 (
                     self.build_model_path(&resolved_type, &resolved_family, &target_cleaned_name),
-                    format!(
-                        "{}/{}/{}",
-                        normalize_name(&resolved_type),
-                        normalize_name(&resolved_family),
-                        normalize_name(&target_cleaned_name)
-                    ),
+                    self.build_model_id(&resolved_type, &resolved_family, &target_cleaned_name),
                 )
             };
         let expected_files = metadata
@@ -1352,7 +1379,7 @@ impl ModelLibrary {
                     ))
                 })?
         } else {
-            let dry_run = self.generate_migration_dry_run_report()?;
+            let dry_run = generate_migration_dry_run_report_async(self.clone()).await?;
             let pending_moves = dry_run
                 .items
                 .iter()
@@ -1431,7 +1458,8 @@ impl ModelLibrary {
         }
 
         report.reindexed_model_count = self.rebuild_index().await?;
-        let integrity = self.validate_post_migration_integrity()?;
+        let (integrity, orphan_payload_dirs) =
+            validate_post_migration_integrity_async(self.clone()).await?;
         report.metadata_dir_count = integrity.metadata_dir_count;
         report.index_model_count = integrity.index_model_count;
         report.index_metadata_model_count = integrity.index_metadata_model_count;
@@ -1439,7 +1467,7 @@ impl ModelLibrary {
         report.index_stale_model_count = integrity.index_stale_model_count;
         report.referential_integrity_errors = integrity.errors;
         report.referential_integrity_ok = report.referential_integrity_errors.is_empty();
-        report.orphan_payload_dirs = collect_orphan_payload_dirs(&self.library_root);
+        report.orphan_payload_dirs = orphan_payload_dirs;
         report.orphan_payload_dir_count = report.orphan_payload_dirs.len();
         if !report.referential_integrity_ok {
             report.error_count += report.referential_integrity_errors.len();
@@ -1882,8 +1910,10 @@ impl ModelLibrary {
 
     async fn rewrite_conversion_source_refs(&self, old_id: &str, new_id: &str) -> Result<usize> {
         let mut updated = 0;
-        for model_dir in self.model_dirs() {
-            let Some(mut metadata) = self.load_metadata(&model_dir)? else {
+        for model_dir in collect_model_dirs_async(self.clone()).await? {
+            let Some(mut metadata) =
+                load_model_metadata_async(self.clone(), model_dir.clone()).await?
+            else {
                 continue;
             };
             let Some(conversion_source) = metadata.conversion_source.as_mut() else {
@@ -52,8 +52,19 @@ pub(super) fn metadata_to_record(
         .unwrap_or_else(|| "unknown".to_string());
     let (download_incomplete, download_has_part_files, download_missing_expected_files) =
         download_projection_status(model_dir, metadata);
+    let readiness = metadata.readiness.unwrap_or(
+        if download_incomplete || metadata.match_source.as_deref() == Some("download_partial") {
+            ModelReadiness::Downloading
+        } else {
+            ModelReadiness::Ready
+        },
+    );
     let mut metadata_json = serde_json::to_value(metadata).unwrap_or(serde_json::Value::Null);
     if let Some(obj) = metadata_json.as_object_mut() {
+        obj.insert(
+            "readiness".to_string(),
+            serde_json::to_value(readiness).unwrap_or(Value::Null),
+        );
         obj.insert(
             "download_incomplete".to_string(),
             Value::Bool(download_incomplete),
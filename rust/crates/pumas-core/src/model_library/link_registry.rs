@@ -6,11 +6,13 @@
 use crate::error::Result;
 use crate::metadata::{atomic_read_json, atomic_write_json};
 use crate::model_library::types::{LinkEntry, LinkType};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs;
 use tokio::sync::RwLock;
+use walkdir::WalkDir;
 
 /// Tracks all symlinks/hardlinks created for model mapping.
 ///
@@ -26,6 +28,31 @@ pub struct LinkRegistry {
     links: Arc<RwLock<LinkData>>,
 }
 
+/// An application models directory to scan during [`LinkRegistry::reconcile`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionModelsPath {
+    /// Application ID recorded on links discovered here
+    pub app_id: String,
+    /// Application version recorded on links discovered here
+    #[serde(default)]
+    pub app_version: Option<String>,
+    /// Directory the application loads models from
+    pub models_path: PathBuf,
+}
+
+/// Result of reconciling the registry against links on disk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LinkReconcileReport {
+    /// Untracked links into the library that were added to the registry.
+    pub added: Vec<LinkEntry>,
+    /// Registry entries whose link or library source no longer exists.
+    ///
+    /// These are reported but kept; `cleanup_broken` removes them.
+    pub missing: Vec<LinkEntry>,
+    /// Untracked links into the library whose source or model could not be resolved.
+    pub unresolved: Vec<PathBuf>,
+}
+
 /// Internal link storage structure.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 struct LinkData {
@@ -92,16 +119,7 @@ impl LinkRegistry {
     /// * `entry` - Link entry to register
     pub async fn register(&self, entry: LinkEntry) -> Result<()> {
         let mut data = self.links.write().await;
-
-        // Add to by_model index
-        data.by_model
-            .entry(entry.model_id.clone())
-            .or_default()
-            .push(entry.target.clone());
-
-        // Add to by_target
-        data.by_target.insert(entry.target.clone(), entry);
-
+        data.insert(entry);
         drop(data);
         self.save().await
     }
@@ -202,17 +220,7 @@ impl LinkRegistry {
             data.by_target.values().cloned().collect::<Vec<_>>()
         };
         let broken = tokio::task::spawn_blocking(move || {
-            Ok::<_, crate::PumasError>(
-                entries
-                    .into_iter()
-                    .filter(|entry| {
-                        if !entry.target.exists() {
-                            return true;
-                        }
-                        entry.target.is_symlink() && !entry.source.exists()
-                    })
-                    .collect::<Vec<_>>(),
-            )
+            Ok::<_, crate::PumasError>(entries.into_iter().filter(is_broken).collect::<Vec<_>>())
         })
         .await
         .map_err(|err| {
@@ -230,6 +238,52 @@ impl LinkRegistry {
         Ok(broken)
     }
 
+    /// Reconcile the registry with the links that actually exist on disk.
+    ///
+    /// Scans each models path for symlinks that resolve into `library_root`
+    /// and registers any the registry does not know about, attributing them
+    /// to the model directory that contains the link source. Registry entries
+    /// whose link or source has vanished are flagged in the report but left in
+    /// place. Hardlinks and copies are not discoverable this way.
+    ///
+    /// # Arguments
+    ///
+    /// * `library_root` - Root directory of the model library
+    /// * `version_models_paths` - Application models directories to scan
+    pub async fn reconcile(
+        &self,
+        library_root: &Path,
+        version_models_paths: &[VersionModelsPath],
+    ) -> Result<LinkReconcileReport> {
+        let entries = self.get_all().await;
+        let library_root = library_root.to_path_buf();
+        let version_models_paths = version_models_paths.to_vec();
+        let mut report = tokio::task::spawn_blocking(move || {
+            scan_for_reconcile(&library_root, &version_models_paths, entries)
+        })
+        .await
+        .map_err(|err| {
+            crate::PumasError::Other(format!(
+                "Failed to join link registry reconcile task: {}",
+                err
+            ))
+        })?;
+
+        let mut data = self.links.write().await;
+        report
+            .added
+            .retain(|entry| !data.by_target.contains_key(&entry.target));
+        for entry in &report.added {
+            data.insert(entry.clone());
+        }
+        drop(data);
+
+        if !report.added.is_empty() {
+            self.save().await?;
+        }
+        Ok(report)
+    }
+
     /// Get links for a specific app.
     pub async fn get_links_for_app(&self, app_id: &str) -> Vec<LinkEntry> {
         let data = self.links.read().await;
@@ -270,6 +324,112 @@ impl LinkRegistry {
     }
 }
 
+impl LinkData {
+    fn insert(&mut self, entry: LinkEntry) {
+        // Add to by_model index
+        self.by_model
+            .entry(entry.model_id.clone())
+            .or_default()
+            .push(entry.target.clone());
+
+        // Add to by_target
+        self.by_target.insert(entry.target.clone(), entry);
+    }
+}
+
+/// Whether a registered link or the library file behind it no longer exists.
+fn is_broken(entry: &LinkEntry) -> bool {
+    if !entry.target.exists() {
+        return true;
+    }
+    entry.target.is_symlink() && !entry.source.exists()
+}
+
+/// Blocking half of [`LinkRegistry::reconcile`]: walk the models paths and
+/// compare what is on disk with the registry snapshot in `entries`.
+fn scan_for_reconcile(
+    library_root: &Path,
+    version_models_paths: &[VersionModelsPath],
+    entries: Vec<LinkEntry>,
+) -> LinkReconcileReport {
+    let library_root = library_root
+        .canonicalize()
+        .unwrap_or_else(|_| library_root.to_path_buf());
+    let tracked: HashMap<PathBuf, LinkEntry> = entries
+        .into_iter()
+        .map(|entry| (entry.target.clone(), entry))
+        .collect();
+    let mut report = LinkReconcileReport {
+        missing: tracked
+            .values()
+            .filter(|entry| is_broken(entry))
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+
+    for models_path in version_models_paths {
+        let symlinks = WalkDir::new(&models_path.models_path)
+            .min_depth(1)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path_is_symlink());
+        for link in symlinks {
+            let target = link.into_path();
+            if tracked.contains_key(&target) {
+                continue;
+            }
+            let Ok(raw_source) = std::fs::read_link(&target) else {
+                continue;
+            };
+            let raw_source = match target.parent() {
+                Some(parent) if raw_source.is_relative() => parent.join(raw_source),
+                _ => raw_source,
+            };
+            let Ok(source) = raw_source.canonicalize() else {
+                // Dangling: only report links that were meant to point into the library.
+                if raw_source.starts_with(&library_root) {
+                    report.unresolved.push(target);
+                }
+                continue;
+            };
+            if !source.starts_with(&library_root) {
+                continue;
+            }
+
+            match owning_model_id(&library_root, &source) {
+                Some(model_id) => report.added.push(create_link_entry(
+                    &model_id,
+                    source,
+                    target,
+                    LinkType::Symlink,
+                    &models_path.app_id,
+                    models_path.app_version.as_deref(),
+                )),
+                None => report.unresolved.push(target),
+            }
+        }
+    }
+
+    report
+}
+
+/// Model ID of the nearest library directory holding `source` that has metadata.
+fn owning_model_id(library_root: &Path, source: &Path) -> Option<String> {
+    source
+        .ancestors()
+        .take_while(|dir| *dir != library_root)
+        .find(|dir| dir.join("metadata.json").is_file())
+        .and_then(|dir| dir.strip_prefix(library_root).ok())
+        .map(|relative| {
+            relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+}
+
 /// Create a new link entry with the current timestamp for tracking in the registry.
 pub fn create_link_entry(
     model_id: &str,
@@ -426,4 +586,77 @@ mod tests {
         assert_eq!(app1_links.len(), 1);
         assert_eq!(app1_links[0].model_id, "model1");
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_reconcile_adopts_untracked_links_and_flags_missing() {
+        use std::os::unix::fs::symlink;
+
+        let (temp, registry) = setup_registry().await;
+        let library_root = temp.path().join("library");
+        let model_dir = library_root.join("llm").join("llama").join("tiny");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("metadata.json"), "{}").unwrap();
+        std::fs::write(model_dir.join("tiny.gguf"), b"gguf").unwrap();
+        std::fs::write(library_root.join("stray.gguf"), b"gguf").unwrap();
+
+        let app_models = temp.path().join("app").join("models");
+        std::fs::create_dir_all(&app_models).unwrap();
+        symlink(model_dir.join("tiny.gguf"), app_models.join("tiny.gguf")).unwrap();
+        symlink(
+            library_root.join("stray.gguf"),
+            app_models.join("stray.gguf"),
+        )
+        .unwrap();
+        symlink(
+            temp.path().join("elsewhere.gguf"),
+            app_models.join("other.gguf"),
+        )
+        .unwrap();
+
+        let vanished = create_link_entry(
+            "llm/llama/gone",
+            library_root.join("llm/llama/gone/gone.gguf"),
+            app_models.join("gone.gguf"),
+            LinkType::Symlink,
+            "comfyui",
+            None,
+        );
+        registry.register(vanished.clone()).await.unwrap();
+
+        let report = registry
+            .reconcile(
+                &library_root,
+                &[VersionModelsPath {
+                    app_id: "comfyui".to_string(),
+                    app_version: Some("0.6.0".to_string()),
+                    models_path: app_models.clone(),
+                }],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.added.len(), 1);
+        assert_eq!(report.added[0].model_id, "llm/llama/tiny");
+        assert_eq!(report.added[0].app_version.as_deref(), Some("0.6.0"));
+        assert_eq!(report.unresolved, vec![app_models.join("stray.gguf")]);
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].target, vanished.target);
+
+        // Discovered links are persisted; vanished entries are only flagged.
+        assert!(registry.contains_target(app_models.join("tiny.gguf")).await);
+        assert!(registry.contains_target(&vanished.target).await);
+        let again = registry
+            .reconcile(
+                &library_root,
+                &[VersionModelsPath {
+                    app_id: "comfyui".to_string(),
+                    app_version: Some("0.6.0".to_string()),
+                    models_path: app_models,
+                }],
+            )
+            .await
+            .unwrap();
+        assert!(again.added.is_empty());
+    }
 }
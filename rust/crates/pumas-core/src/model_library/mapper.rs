@@ -8,16 +8,31 @@ use crate::metadata::{atomic_read_json, atomic_write_json};
 use crate::model_library::library::ModelLibrary;
 use crate::model_library::link_registry::{create_link_entry, LinkRegistry};
 use crate::model_library::types::{
-    ConflictResolution, LinkType, MappingAction, MappingActionType, MappingConfig, MappingPreview,
-    MappingRule, SandboxInfo,
+    ConflictResolution, LinkEntry, LinkType, LinkTypeCheck, MappingAction, MappingActionType,
+    MappingConfig, MappingConfigIssue, MappingConfigValidationReport, MappingPreview, MappingRule,
+    MappingSettings, SandboxInfo,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock as StdRwLock};
 use tokio::fs;
 use tokio::sync::RwLock;
 use walkdir::WalkDir;
 
+/// Mapping preferences file, kept in the library root next to the link registry.
+pub const MAPPING_SETTINGS_FILENAME: &str = "mapping_settings.json";
+
+/// Final component of a recorded original filename, usable as a link name.
+///
+/// Directory imports record paths relative to the source directory, so only
+/// the last component is kept; `..` and empty names are rejected.
+fn original_link_name(original: &str) -> Option<&str> {
+    match Path::new(original).components().next_back()? {
+        std::path::Component::Normal(name) => name.to_str(),
+        _ => None,
+    }
+}
+
 /// Model mapper for creating links between library and applications.
 #[derive(Clone)]
 pub struct ModelMapper {
@@ -27,6 +42,8 @@ pub struct ModelMapper {
     config_dir: PathBuf,
     /// Link registry for tracking created links
     link_registry: Arc<RwLock<LinkRegistry>>,
+    /// Default link type, loaded from the settings file on first use
+    default_link_type: Arc<StdRwLock<Option<LinkType>>>,
 }
 
 impl ModelMapper {
@@ -42,6 +59,7 @@ impl ModelMapper {
             library,
             config_dir: config_dir.into(),
             link_registry,
+            default_link_type: Arc::new(StdRwLock::new(None)),
         }
     }
 
@@ -56,6 +74,11 @@ impl ModelMapper {
     /// 2. {app}_{version}_default.json
     /// 3. {app}_*_custom.json
     /// 4. {app}_*_default.json
+    ///
+    /// A rule replaces a lower-precedence rule with the same `target_dir`;
+    /// other rules are combined. A config with `replace_inherited` drops all
+    /// lower-precedence rules, so a custom config can define its own
+    /// type-to-folder layout in place of the built-in one.
     pub fn load_config(
         &self,
         app_id: &str,
@@ -63,28 +86,30 @@ impl ModelMapper {
     ) -> Result<Option<MappingConfig>> {
         let configs = self.find_matching_configs(app_id, version)?;
 
-        if configs.is_empty() {
+        // Merge from lowest to highest precedence
+        let mut iter = configs.into_iter().rev();
+        let Some(mut merged) = iter.next() else {
             return Ok(None);
-        }
-
-        // Merge configs (later configs override earlier ones)
-        let mut iter = configs.into_iter();
-        let mut merged = iter.next().unwrap();
+        };
 
         for config in iter {
-            // Merge mappings
+            let mut mappings = if config.replace_inherited {
+                Vec::new()
+            } else {
+                std::mem::take(&mut merged.mappings)
+            };
             for rule in config.mappings {
                 // Check if this rule replaces an existing one
-                if let Some(existing) = merged
-                    .mappings
+                if let Some(existing) = mappings
                     .iter_mut()
                     .find(|r| r.target_dir == rule.target_dir)
                 {
                     *existing = rule;
                 } else {
-                    merged.mappings.push(rule);
+                    mappings.push(rule);
                 }
             }
+            merged = MappingConfig { mappings, ..config };
         }
 
         Ok(Some(merged))
@@ -162,6 +187,137 @@ impl ModelMapper {
         Ok(configs.into_iter().map(|(c, _)| c).collect())
     }
 
+    /// Check every config file in the config directory.
+    ///
+    /// Reports files that fail to parse (with line and column), file names the
+    /// loader ignores, header fields that disagree with the file name, and
+    /// rules whose target escapes the app's models directory or whose filters
+    /// can never match. Loading silently skips unparseable files, so this is
+    /// the way to find out why a config has no effect.
+    pub fn validate_configs(&self) -> Result<MappingConfigValidationReport> {
+        let mut report = MappingConfigValidationReport::default();
+        if !self.config_dir.exists() {
+            return Ok(report);
+        }
+
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(&self.config_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        for path in paths {
+            report.files_checked += 1;
+            let file = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let mut issue = |field: Option<String>, message: String| {
+                report.issues.push(MappingConfigIssue {
+                    file: file.clone(),
+                    field,
+                    message,
+                });
+            };
+
+            let stem = path.file_stem().and_then(|n| n.to_str()).unwrap_or("");
+            let parts: Vec<&str> = stem.split('_').collect();
+            if parts.len() < 2 {
+                issue(
+                    None,
+                    "file name must be {app}_{version}[_{variant}].json; the file is ignored"
+                        .to_string(),
+                );
+            }
+
+            let contents = std::fs::read_to_string(&path)?;
+            let config = match serde_json::from_str::<MappingConfig>(&contents) {
+                Ok(config) => config,
+                Err(err) => {
+                    issue(None, format!("invalid mapping config: {}", err));
+                    continue;
+                }
+            };
+
+            if parts.len() >= 2 {
+                let file_variant = parts.get(2).copied().unwrap_or("default");
+                for (field, value, expected) in [
+                    ("app", config.app.as_str(), parts[0]),
+                    ("version", config.version.as_str(), parts[1]),
+                    (
+                        "variant",
+                        config.variant.as_deref().unwrap_or("default"),
+                        file_variant,
+                    ),
+                ] {
+                    if value != expected {
+                        issue(
+                            Some(field.to_string()),
+                            format!(
+                                "'{}' does not match '{}' from the file name, which is what the loader uses",
+                                value, expected
+                            ),
+                        );
+                    }
+                }
+            }
+
+            for (index, rule) in config.mappings.iter().enumerate() {
+                let field = |name: &str| Some(format!("mappings[{}].{}", index, name));
+                let target = Path::new(&rule.target_dir);
+                if rule.target_dir.trim().is_empty() {
+                    issue(
+                        field("target_dir"),
+                        "target_dir is empty; use \".\" for the models root".to_string(),
+                    );
+                } else if target.is_absolute()
+                    || target
+                        .components()
+                        .any(|c| matches!(c, std::path::Component::ParentDir))
+                {
+                    issue(
+                        field("target_dir"),
+                        format!(
+                            "'{}' must be a relative path inside the app's models directory",
+                            rule.target_dir
+                        ),
+                    );
+                }
+
+                for (name, filter) in [
+                    ("model_types", &rule.model_types),
+                    ("subtypes", &rule.subtypes),
+                    ("families", &rule.families),
+                ] {
+                    if filter.as_ref().is_some_and(|values| values.is_empty()) {
+                        issue(
+                            field(name),
+                            format!(
+                                "empty {} list matches no models; omit it to match all",
+                                name
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Validate mapping configs without blocking the async runtime.
+    pub async fn validate_configs_async(&self) -> Result<MappingConfigValidationReport> {
+        let mapper = self.clone();
+        tokio::task::spawn_blocking(move || mapper.validate_configs())
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join validate_configs task: {}", e))
+            })?
+    }
+
     /// Calculate specificity score for config precedence.
     fn calculate_specificity(
         &self,
@@ -205,8 +361,9 @@ impl ModelMapper {
 
     /// Create and persist a default ComfyUI mapping configuration.
     ///
-    /// Generates rules for standard ComfyUI model directories (checkpoints, loras,
-    /// vae, controlnet, clip, embeddings, upscale_models) and saves the config to disk.
+    /// The built-in rule links every model into the app's models root. The
+    /// file is rewritten on each call, so user layouts belong in a
+    /// `comfyui_{version}_custom.json` config, which takes precedence.
     pub fn create_default_comfyui_config(
         &self,
         version: &str,
@@ -216,6 +373,7 @@ impl ModelMapper {
             app: "comfyui".to_string(),
             version: version.to_string(),
             variant: Some("default".to_string()),
+            replace_inherited: false,
             mappings: vec![MappingRule {
                 target_dir: ".".to_string(),
                 model_types: None,
@@ -223,6 +381,7 @@ impl ModelMapper {
                 families: None,
                 tags: None,
                 exclude_tags: None,
+                use_original_filenames: false,
             }],
         };
 
@@ -252,6 +411,100 @@ impl ModelMapper {
         })?
     }
 
+    // ========================================
+    // Link Type Preference
+    // ========================================
+
+    fn settings_path(&self) -> PathBuf {
+        self.library.library_root().join(MAPPING_SETTINGS_FILENAME)
+    }
+
+    /// Get the link type tried first when creating links.
+    ///
+    /// Defaults to symlinks until [`Self::set_default_link_type`] persists a choice.
+    pub fn default_link_type(&self) -> Result<LinkType> {
+        if let Some(link_type) = *self
+            .default_link_type
+            .read()
+            .expect("default link type lock poisoned")
+        {
+            return Ok(link_type);
+        }
+
+        let settings: MappingSettings =
+            atomic_read_json(&self.settings_path())?.unwrap_or_default();
+        *self
+            .default_link_type
+            .write()
+            .expect("default link type lock poisoned") = Some(settings.default_link_type);
+        Ok(settings.default_link_type)
+    }
+
+    /// Async wrapper for [`Self::default_link_type`].
+    pub async fn default_link_type_async(&self) -> Result<LinkType> {
+        let mapper = self.clone();
+        tokio::task::spawn_blocking(move || mapper.default_link_type())
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join default_link_type task: {}", e))
+            })?
+    }
+
+    /// Persist the link type tried first when creating links.
+    ///
+    /// The other types stay as fallbacks, so a type that turns out to be
+    /// infeasible for a target degrades instead of failing the mapping.
+    /// Use [`Self::check_link_type`] to warn about such a choice upfront.
+    pub fn set_default_link_type(&self, link_type: LinkType) -> Result<()> {
+        let settings = MappingSettings {
+            default_link_type: link_type,
+        };
+        atomic_write_json(&self.settings_path(), &settings, false)?;
+        *self
+            .default_link_type
+            .write()
+            .expect("default link type lock poisoned") = Some(link_type);
+        Ok(())
+    }
+
+    /// Async wrapper for [`Self::set_default_link_type`].
+    pub async fn set_default_link_type_async(&self, link_type: LinkType) -> Result<()> {
+        let mapper = self.clone();
+        tokio::task::spawn_blocking(move || mapper.set_default_link_type(link_type))
+            .await
+            .map_err(|e| {
+                PumasError::Other(format!("Failed to join set_default_link_type task: {}", e))
+            })?
+    }
+
+    /// Check whether links of `link_type` can be created under `app_models_root`.
+    ///
+    /// Symlinks need the library filesystem to support them and hardlinks
+    /// cannot cross filesystems; copies always work.
+    pub fn check_link_type(
+        &self,
+        link_type: LinkType,
+        app_models_root: &Path,
+    ) -> Result<LinkTypeCheck> {
+        let reason = match link_type {
+            LinkType::Symlink if !self.library.can_create_symlinks() => Some(
+                "Symlinks cannot be created on the library filesystem; links fall back to hardlinks or copies"
+                    .to_string(),
+            ),
+            LinkType::Hardlink if self.check_cross_filesystem(app_models_root)? => Some(
+                "Hardlinks cannot cross filesystems, and the library and app models directory are on different filesystems; links fall back to symlinks or copies"
+                    .to_string(),
+            ),
+            _ => None,
+        };
+
+        Ok(LinkTypeCheck {
+            link_type,
+            feasible: reason.is_none(),
+            reason,
+        })
+    }
+
     // ========================================
     // Mapping Operations
     // ========================================
@@ -429,6 +682,186 @@ impl ModelMapper {
         Ok(result)
     }
 
+    /// Bring an application's links in line with the mapping, touching only the delta.
+    ///
+    /// Links registered for `app_id`/`version` under `app_models_root` are
+    /// compared with the links the config asks for. Registered links that are
+    /// no longer wanted (or now point at a different source) are deleted and
+    /// unregistered, missing links are created, and links already registered
+    /// with the same source are counted as unchanged without touching the
+    /// filesystem.
+    ///
+    /// Targets occupied by unrelated files are handled with `on_conflict`. With
+    /// `Rename`, a registered link to the same source next to the conflicting
+    /// target counts as that target's earlier rename and is kept.
+    pub async fn sync_incremental(
+        &self,
+        app_id: &str,
+        version: Option<&str>,
+        app_models_root: &Path,
+        on_conflict: ConflictResolution,
+    ) -> Result<IncrementalSyncResult> {
+        let config = self
+            .load_config_async(app_id, version)
+            .await?
+            .ok_or_else(|| PumasError::Config {
+                message: format!("No mapping config found for {} {:?}", app_id, version),
+            })?;
+
+        let excluded_ids = self.load_excluded_model_ids_async(app_id).await?;
+        let models = self.library.list_models().await?;
+        let mapper = self.clone();
+        let root = app_models_root.to_path_buf();
+        let desired = tokio::task::spawn_blocking(move || {
+            mapper.desired_links(&config, &excluded_ids, models, &root)
+        })
+        .await
+        .map_err(|e| PumasError::Other(format!("Failed to join sync_incremental task: {}", e)))??;
+
+        let registered: HashMap<PathBuf, LinkEntry> = self
+            .link_registry
+            .read()
+            .await
+            .get_links_for_app_version(app_id, version)
+            .await
+            .into_iter()
+            .filter(|entry| entry.target.starts_with(app_models_root))
+            .map(|entry| (entry.target.clone(), entry))
+            .collect();
+
+        let mut result = IncrementalSyncResult::default();
+        let mut seen_targets = HashSet::new();
+        let mut kept_targets = HashSet::new();
+        let mut missing = Vec::new();
+        let mut targets_by_source: HashMap<&Path, Vec<&Path>> = HashMap::new();
+        for entry in registered.values() {
+            targets_by_source
+                .entry(entry.source.as_path())
+                .or_default()
+                .push(entry.target.as_path());
+        }
+        for action in desired {
+            if !seen_targets.insert(action.target.clone()) {
+                continue;
+            }
+            match registered.get(&action.target) {
+                Some(entry) if entry.source == action.source => {
+                    kept_targets.insert(action.target);
+                    result.unchanged += 1;
+                }
+                _ => {
+                    let earlier_rename = (on_conflict == ConflictResolution::Rename)
+                        .then(|| targets_by_source.get(action.source.as_path()))
+                        .flatten()
+                        .and_then(|targets| {
+                            targets.iter().find(|target| {
+                                target.parent() == action.target.parent()
+                                    && !registered.contains_key(&action.target)
+                            })
+                        });
+                    match earlier_rename {
+                        Some(target) => {
+                            kept_targets.insert(target.to_path_buf());
+                            result.unchanged += 1;
+                        }
+                        None => missing.push(action),
+                    }
+                }
+            }
+        }
+
+        // Remove stale links first so their targets are free for replacements.
+        for entry in registered.values() {
+            if kept_targets.contains(&entry.target) {
+                continue;
+            }
+            match fs::remove_file(&entry.target).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    result.errors.push((entry.target.clone(), e.to_string()));
+                    continue;
+                }
+            }
+            self.link_registry
+                .write()
+                .await
+                .unregister(&entry.target)
+                .await?;
+            result.removed += 1;
+        }
+
+        let classified = tokio::task::spawn_blocking(move || {
+            missing
+                .into_iter()
+                .map(Self::classify_target)
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|e| PumasError::Other(format!("Failed to join sync_incremental task: {}", e)))?;
+
+        for action in classified {
+            match action.action {
+                MappingActionType::SkipConflict => {
+                    let action = match on_conflict {
+                        ConflictResolution::Skip => {
+                            result.conflicts += 1;
+                            continue;
+                        }
+                        ConflictResolution::Overwrite => {
+                            if let Err(e) = fs::remove_file(&action.target).await {
+                                result.errors.push((action.target, e.to_string()));
+                                continue;
+                            }
+                            action
+                        }
+                        ConflictResolution::Rename => MappingAction {
+                            target: self.get_renamed_path_async(&action.target).await?,
+                            ..action
+                        },
+                    };
+                    match self
+                        .create_link(&action, app_id, version.map(String::from))
+                        .await
+                    {
+                        Ok(()) => result.added += 1,
+                        Err(e) => result.errors.push((action.target, e.to_string())),
+                    }
+                }
+                MappingActionType::SkipExists => {
+                    // Correct link made outside the registry: adopt it.
+                    let entry = create_link_entry(
+                        &action.model_id,
+                        &action.source,
+                        &action.target,
+                        LinkType::Symlink,
+                        app_id,
+                        version,
+                    );
+                    self.link_registry.write().await.register(entry).await?;
+                    result.unchanged += 1;
+                }
+                MappingActionType::Create | MappingActionType::RemoveBroken => {
+                    if action.action == MappingActionType::RemoveBroken {
+                        if let Err(e) = fs::remove_file(&action.target).await {
+                            result.errors.push((action.target, e.to_string()));
+                            continue;
+                        }
+                    }
+                    match self
+                        .create_link(&action, app_id, version.map(String::from))
+                        .await
+                    {
+                        Ok(()) => result.added += 1,
+                        Err(e) => result.errors.push((action.target, e.to_string())),
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Create a link for a mapping action.
     async fn create_link(
         &self,
@@ -443,15 +876,18 @@ impl ModelMapper {
                 .map_err(|err| PumasError::io_with_path(err, parent))?;
         }
 
-        // Try symlink first
+        // Try the configured type first, skipping symlinks if the platform
+        // probe ruled them out
         let source = action.source.clone();
         let target = action.target.clone();
-        let link_type =
-            tokio::task::spawn_blocking(move || Self::create_symlink_or_copy(&source, &target))
-                .await
-                .map_err(|e| {
-                    PumasError::Other(format!("Failed to join create_link task: {}", e))
-                })??;
+        let try_symlink = self.library.can_create_symlinks();
+        let mapper = self.clone();
+        let link_type = tokio::task::spawn_blocking(move || {
+            let preferred = mapper.default_link_type()?;
+            Self::create_link_file(&source, &target, preferred, try_symlink)
+        })
+        .await
+        .map_err(|e| PumasError::Other(format!("Failed to join create_link task: {}", e)))??;
 
         // Register the link
         let entry = create_link_entry(
@@ -469,31 +905,53 @@ impl ModelMapper {
         Ok(())
     }
 
-    /// Create a symlink, falling back to hardlink or copy.
-    fn create_symlink_or_copy(source: &Path, target: &Path) -> Result<LinkType> {
-        // Try symlink first
+    /// Create `target` as a `preferred` link, falling back through symlink,
+    /// hardlink, and finally copy. Symlinks are skipped unless `try_symlink`.
+    fn create_link_file(
+        source: &Path,
+        target: &Path,
+        preferred: LinkType,
+        try_symlink: bool,
+    ) -> Result<LinkType> {
+        let mut order = vec![preferred];
+        order.extend(
+            [LinkType::Symlink, LinkType::Hardlink]
+                .into_iter()
+                .filter(|link_type| *link_type != preferred),
+        );
+
+        for link_type in order {
+            let created = match link_type {
+                LinkType::Symlink => try_symlink && Self::create_symlink(source, target),
+                // Hardlinks only work on the same filesystem
+                LinkType::Hardlink => std::fs::hard_link(source, target).is_ok(),
+                LinkType::Copy => break,
+            };
+            if created {
+                return Ok(link_type);
+            }
+        }
+
+        std::fs::copy(source, target)?;
+        Ok(LinkType::Copy)
+    }
+
+    /// Create a file symlink, returning whether it succeeded.
+    fn create_symlink(source: &Path, target: &Path) -> bool {
         #[cfg(unix)]
         {
-            if std::os::unix::fs::symlink(source, target).is_ok() {
-                return Ok(LinkType::Symlink);
-            }
+            std::os::unix::fs::symlink(source, target).is_ok()
         }
 
         #[cfg(windows)]
         {
-            if std::os::windows::fs::symlink_file(source, target).is_ok() {
-                return Ok(LinkType::Symlink);
-            }
+            std::os::windows::fs::symlink_file(source, target).is_ok()
         }
 
-        // Try hardlink (only works on same filesystem)
-        if std::fs::hard_link(source, target).is_ok() {
-            return Ok(LinkType::Hardlink);
+        #[cfg(not(any(unix, windows)))]
+        {
+            false
         }
-
-        // Fall back to copy
-        std::fs::copy(source, target)?;
-        Ok(LinkType::Copy)
     }
 
     /// Get a renamed path to avoid conflict.
@@ -615,6 +1073,7 @@ impl ModelMapper {
         let mut files = Vec::new();
 
         for entry in WalkDir::new(model_dir)
+            .follow_links(true)
             .min_depth(1)
             .max_depth(1)
             .into_iter()
@@ -681,10 +1140,39 @@ impl ModelMapper {
     ) -> Result<MappingPreview> {
         let mut preview = MappingPreview::new();
 
+        for desired in self.desired_links(&config, &excluded_ids, models, app_models_root)? {
+            let action = Self::classify_target(desired);
+            match action.action {
+                MappingActionType::Create => preview.creates.push(action),
+                MappingActionType::SkipExists => preview.skips.push(action),
+                MappingActionType::SkipConflict => preview.conflicts.push(action),
+                MappingActionType::RemoveBroken => preview.broken.push(action),
+            }
+        }
+
+        Ok(preview)
+    }
+
+    /// Every link the config asks for, as `Create` actions.
+    ///
+    /// Only reads the model directories; target paths are not inspected.
+    fn desired_links(
+        &self,
+        config: &MappingConfig,
+        excluded_ids: &std::collections::HashSet<String>,
+        models: Vec<crate::index::ModelRecord>,
+        app_models_root: &Path,
+    ) -> Result<Vec<MappingAction>> {
+        let mut desired = Vec::new();
+
         for model in models {
             if excluded_ids.contains(&model.id) {
                 continue;
             }
+            // Partial downloads and failed imports have no usable files to link.
+            if !model.is_ready() {
+                continue;
+            }
             if model
                 .metadata
                 .get("storage_kind")
@@ -707,75 +1195,59 @@ impl ModelMapper {
 
                 let model_dir = self.library.library_root().join(&model.path);
                 let files = self.get_model_files(&model_dir)?;
+                let original_filenames = if rule.use_original_filenames {
+                    self.library.original_filenames(&model.id)?
+                } else {
+                    Default::default()
+                };
 
                 for file_path in files {
                     let filename = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                    let target_path = app_models_root.join(&rule.target_dir).join(filename);
-
-                    let action = if target_path.exists() {
-                        if target_path.is_symlink() {
-                            if let Ok(link_target) = std::fs::read_link(&target_path) {
-                                if link_target == file_path {
-                                    MappingAction {
-                                        action: MappingActionType::SkipExists,
-                                        model_id: model.id.clone(),
-                                        model_name: model_name.clone(),
-                                        source: file_path.clone(),
-                                        target: target_path,
-                                        reason: Some("Link already exists".to_string()),
-                                    }
-                                } else {
-                                    MappingAction {
-                                        action: MappingActionType::SkipConflict,
-                                        model_id: model.id.clone(),
-                                        model_name: model_name.clone(),
-                                        source: file_path.clone(),
-                                        target: target_path,
-                                        reason: Some("Different file exists".to_string()),
-                                    }
-                                }
-                            } else {
-                                MappingAction {
-                                    action: MappingActionType::RemoveBroken,
-                                    model_id: model.id.clone(),
-                                    model_name: model_name.clone(),
-                                    source: file_path.clone(),
-                                    target: target_path,
-                                    reason: Some("Broken symlink".to_string()),
-                                }
-                            }
-                        } else {
-                            MappingAction {
-                                action: MappingActionType::SkipConflict,
-                                model_id: model.id.clone(),
-                                model_name: model_name.clone(),
-                                source: file_path.clone(),
-                                target: target_path,
-                                reason: Some("Regular file exists".to_string()),
-                            }
-                        }
-                    } else {
-                        MappingAction {
-                            action: MappingActionType::Create,
-                            model_id: model.id.clone(),
-                            model_name: model_name.clone(),
-                            source: file_path.clone(),
-                            target: target_path,
-                            reason: None,
-                        }
-                    };
+                    let link_name = original_filenames
+                        .get(filename)
+                        .and_then(|original| original_link_name(original))
+                        .unwrap_or(filename);
+                    let target_path = app_models_root.join(&rule.target_dir).join(link_name);
+
+                    desired.push(MappingAction {
+                        action: MappingActionType::Create,
+                        model_id: model.id.clone(),
+                        model_name: model_name.clone(),
+                        source: file_path,
+                        target: target_path,
+                        reason: None,
+                    });
+                }
+            }
+        }
+
+        Ok(desired)
+    }
 
-                    match action.action {
-                        MappingActionType::Create => preview.creates.push(action),
-                        MappingActionType::SkipExists => preview.skips.push(action),
-                        MappingActionType::SkipConflict => preview.conflicts.push(action),
-                        MappingActionType::RemoveBroken => preview.broken.push(action),
+    /// Decide what to do with a desired link given what is at its target.
+    fn classify_target(desired: MappingAction) -> MappingAction {
+        let target_path = &desired.target;
+        let (action, reason) = if target_path.exists() {
+            if target_path.is_symlink() {
+                match std::fs::read_link(target_path) {
+                    Ok(link_target) if link_target == desired.source => {
+                        (MappingActionType::SkipExists, "Link already exists")
                     }
+                    Ok(_) => (MappingActionType::SkipConflict, "Different file exists"),
+                    Err(_) => (MappingActionType::RemoveBroken, "Broken symlink"),
                 }
+            } else {
+                (MappingActionType::SkipConflict, "Regular file exists")
             }
-        }
+        } else {
+            return desired;
+        };
 
-        Ok(preview)
+        MappingAction {
+            action,
+            reason: Some(reason.to_string()),
+            ..desired
+        }
     }
 
     // ========================================
@@ -817,34 +1289,10 @@ impl ModelMapper {
         SandboxInfo::default()
     }
 
-    /// Check if library and app are on the same filesystem.
+    /// Check if library and app are on different filesystems.
     pub fn check_cross_filesystem(&self, app_models_root: &Path) -> Result<bool> {
-        // On Unix, compare device IDs from filesystem metadata
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::MetadataExt;
-            let library_stat = std::fs::metadata(self.library.library_root())?;
-            let app_stat = std::fs::metadata(app_models_root).or_else(|_| {
-                app_models_root
-                    .parent()
-                    .map(std::fs::metadata)
-                    .unwrap_or_else(|| {
-                        Err(std::io::Error::new(
-                            std::io::ErrorKind::NotFound,
-                            "Cannot determine app filesystem",
-                        ))
-                    })
-            })?;
-            Ok(library_stat.dev() != app_stat.dev())
-        }
-
-        // On Windows, compare drive letter / path prefix
-        #[cfg(not(unix))]
-        {
-            let lib_root = self.library.library_root().components().next();
-            let app_root = app_models_root.components().next();
-            Ok(lib_root != app_root)
-        }
+        self.library
+            .is_cross_filesystem(self.library.library_root(), app_models_root)
     }
 }
 
@@ -863,6 +1311,21 @@ pub struct MappingResult {
     pub errors: Vec<(PathBuf, String)>,
 }
 
+/// Result of an incremental mapping sync.
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalSyncResult {
+    /// Number of links created
+    pub added: usize,
+    /// Number of registered links deleted because they are no longer wanted
+    pub removed: usize,
+    /// Number of wanted links that were already in place
+    pub unchanged: usize,
+    /// Number of wanted links blocked by an unrelated file at the target
+    pub conflicts: usize,
+    /// Errors encountered
+    pub errors: Vec<(PathBuf, String)>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -913,6 +1376,7 @@ mod tests {
             families: None,
             tags: None,
             exclude_tags: None,
+            use_original_filenames: false,
         };
 
         let diffusion_model = create_mock_model_record("diffusion", "checkpoints", vec![]);
@@ -933,6 +1397,7 @@ mod tests {
             families: None,
             tags: Some(vec!["anime".to_string(), "realistic".to_string()]),
             exclude_tags: None,
+            use_original_filenames: false,
         };
 
         let anime_model = create_mock_model_record("diffusion", "loras", vec!["anime"]);
@@ -953,6 +1418,7 @@ mod tests {
             families: None,
             tags: None,
             exclude_tags: Some(vec!["nsfw".to_string()]),
+            use_original_filenames: false,
         };
 
         let safe_model = create_mock_model_record("diffusion", "checkpoints", vec!["anime"]);
@@ -970,6 +1436,7 @@ mod tests {
             app: "comfyui".to_string(),
             version: "0.6.0".to_string(),
             variant: Some("custom".to_string()),
+            replace_inherited: false,
             mappings: vec![MappingRule {
                 target_dir: "checkpoints".to_string(),
                 model_types: Some(vec!["diffusion".to_string()]),
@@ -977,6 +1444,7 @@ mod tests {
                 families: None,
                 tags: None,
                 exclude_tags: None,
+                use_original_filenames: false,
             }],
         };
 
@@ -990,6 +1458,116 @@ mod tests {
         assert_eq!(loaded.mappings.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_custom_config_overrides_built_in_rules() {
+        let (_temp, _library, mapper) = setup().await;
+        mapper
+            .create_default_comfyui_config("*", Path::new("/unused"))
+            .unwrap();
+
+        let rule = |target_dir: &str, model_type: &str| MappingRule {
+            target_dir: target_dir.to_string(),
+            model_types: Some(vec![model_type.to_string()]),
+            subtypes: None,
+            families: None,
+            tags: None,
+            exclude_tags: None,
+            use_original_filenames: false,
+        };
+        let custom = |replace_inherited, mappings| MappingConfig {
+            app: "comfyui".to_string(),
+            version: "*".to_string(),
+            variant: Some("custom".to_string()),
+            replace_inherited,
+            mappings,
+        };
+
+        // Merged: the custom "." rule replaces the built-in catch-all.
+        mapper
+            .save_config(&custom(
+                false,
+                vec![rule(".", "diffusion"), rule("upscale_models", "upscaler")],
+            ))
+            .unwrap();
+        let loaded = mapper
+            .load_config("comfyui", Some("0.6.0"))
+            .unwrap()
+            .unwrap();
+        let targets: Vec<_> = loaded
+            .mappings
+            .iter()
+            .map(|rule| (rule.target_dir.as_str(), rule.model_types.clone()))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                (".", Some(vec!["diffusion".to_string()])),
+                ("upscale_models", Some(vec!["upscaler".to_string()])),
+            ]
+        );
+
+        // Replacing: only the custom rules remain.
+        mapper
+            .save_config(&custom(true, vec![rule("my_loras", "lora")]))
+            .unwrap();
+        let loaded = mapper
+            .load_config("comfyui", Some("0.6.0"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.variant.as_deref(), Some("custom"));
+        assert_eq!(loaded.mappings.len(), 1);
+        assert_eq!(loaded.mappings[0].target_dir, "my_loras");
+    }
+
+    #[tokio::test]
+    async fn test_validate_configs_reports_file_and_field() {
+        let (temp, _library, mapper) = setup().await;
+        mapper
+            .create_default_comfyui_config("*", Path::new("/unused"))
+            .unwrap();
+        let config_dir = temp.path().join("config");
+        std::fs::write(
+            config_dir.join("comfyui_*_custom.json"),
+            "{\"app\": \"comfyui\", \"version\": \"*\",\n \"mappings\": [}",
+        )
+        .unwrap();
+        std::fs::write(
+            config_dir.join("comfyui_0.6.0_custom.json"),
+            serde_json::json!({
+                "app": "forge",
+                "version": "0.6.0",
+                "variant": "custom",
+                "mappings": [
+                    {"target_dir": "checkpoints"},
+                    {"target_dir": "../outside", "model_types": []}
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(config_dir.join("notes.txt"), "ignored").unwrap();
+
+        let report = mapper.validate_configs().unwrap();
+
+        assert_eq!(report.files_checked, 3);
+        assert!(!report.is_valid());
+        let fields: Vec<_> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.file.as_str(), issue.field.as_deref()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("comfyui_*_custom.json", None),
+                ("comfyui_0.6.0_custom.json", Some("app")),
+                ("comfyui_0.6.0_custom.json", Some("mappings[1].target_dir")),
+                ("comfyui_0.6.0_custom.json", Some("mappings[1].model_types")),
+            ]
+        );
+        assert!(report.issues[0].message.contains("line 2"));
+    }
+
     #[tokio::test]
     async fn test_preview_mapping_skips_external_reference_assets() {
         let (temp_dir, library, mapper) = setup().await;
@@ -1023,6 +1601,7 @@ mod tests {
                 app: "comfyui".to_string(),
                 version: "0.1.0".to_string(),
                 variant: None,
+                replace_inherited: false,
                 mappings: vec![MappingRule {
                     target_dir: "checkpoints".to_string(),
                     model_types: Some(vec!["diffusion".to_string()]),
@@ -1030,6 +1609,7 @@ mod tests {
                     families: None,
                     tags: None,
                     exclude_tags: None,
+                    use_original_filenames: false,
                 }],
             })
             .unwrap();
@@ -1048,6 +1628,299 @@ mod tests {
         assert!(preview.conflicts.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_apply_mapping_can_name_links_by_original_filename() {
+        let (temp_dir, library, mapper) = setup().await;
+        let model_dir = library.build_model_path("llm", "llama", "llama-3-8b");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("llama-3-8b_q4_k_m.gguf"), b"weights").unwrap();
+        std::fs::write(model_dir.join("adapter.safetensors"), b"weights").unwrap();
+
+        let metadata = crate::models::ModelMetadata {
+            model_id: Some("llm/llama/llama-3-8b".to_string()),
+            model_type: Some("llm".to_string()),
+            family: Some("llama".to_string()),
+            official_name: Some("Llama 3 8B".to_string()),
+            cleaned_name: Some("llama-3-8b".to_string()),
+            files: Some(vec![
+                crate::models::ModelFileInfo {
+                    name: "llama-3-8b_q4_k_m.gguf".to_string(),
+                    original_name: Some("Llama-3-8B.Q4_K_M.gguf".to_string()),
+                    size: Some(7),
+                    sha256: None,
+                    blake3: None,
+                },
+                crate::models::ModelFileInfo {
+                    name: "adapter.safetensors".to_string(),
+                    original_name: Some("../escape.safetensors".to_string()),
+                    size: Some(7),
+                    sha256: None,
+                    blake3: None,
+                },
+            ]),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+
+        let rule = |target_dir: &str, use_original_filenames| MappingRule {
+            target_dir: target_dir.to_string(),
+            model_types: Some(vec!["llm".to_string()]),
+            subtypes: None,
+            families: None,
+            tags: None,
+            exclude_tags: None,
+            use_original_filenames,
+        };
+        mapper
+            .save_config(&MappingConfig {
+                app: "comfyui".to_string(),
+                version: "0.1.0".to_string(),
+                variant: None,
+                replace_inherited: false,
+                mappings: vec![rule("canonical", false), rule("original", true)],
+            })
+            .unwrap();
+
+        let app_models = temp_dir.path().join("app-models");
+        let result = mapper
+            .apply_mapping("comfyui", Some("0.1.0"), &app_models)
+            .await
+            .unwrap();
+
+        assert_eq!(result.created, 4, "errors: {:?}", result.errors);
+        assert!(app_models
+            .join("canonical/llama-3-8b_q4_k_m.gguf")
+            .is_symlink());
+        assert!(app_models
+            .join("original/Llama-3-8B.Q4_K_M.gguf")
+            .is_symlink());
+        assert!(app_models.join("original/escape.safetensors").is_symlink());
+        assert!(!app_models.join("original/llama-3-8b_q4_k_m.gguf").exists());
+    }
+
+    #[tokio::test]
+    async fn test_apply_mapping_skips_models_that_are_not_ready() {
+        let (temp_dir, library, mapper) = setup().await;
+        for (name, readiness) in [
+            ("ready-model", crate::models::ModelReadiness::Ready),
+            (
+                "downloading-model",
+                crate::models::ModelReadiness::Downloading,
+            ),
+            ("failed-model", crate::models::ModelReadiness::Error),
+        ] {
+            let model_dir = library.build_model_path("llm", "llama", name);
+            std::fs::create_dir_all(&model_dir).unwrap();
+            std::fs::write(model_dir.join(format!("{name}.gguf")), b"weights").unwrap();
+            let metadata = crate::models::ModelMetadata {
+                model_id: Some(format!("llm/llama/{name}")),
+                model_type: Some("llm".to_string()),
+                family: Some("llama".to_string()),
+                official_name: Some(name.to_string()),
+                cleaned_name: Some(name.to_string()),
+                readiness: Some(readiness),
+                ..Default::default()
+            };
+            library.save_metadata(&model_dir, &metadata).await.unwrap();
+            library.index_model_dir(&model_dir).await.unwrap();
+        }
+
+        mapper
+            .save_config(&MappingConfig {
+                app: "comfyui".to_string(),
+                version: "0.1.0".to_string(),
+                variant: None,
+                replace_inherited: false,
+                mappings: vec![MappingRule {
+                    target_dir: "llm".to_string(),
+                    model_types: Some(vec!["llm".to_string()]),
+                    subtypes: None,
+                    families: None,
+                    tags: None,
+                    exclude_tags: None,
+                    use_original_filenames: false,
+                }],
+            })
+            .unwrap();
+
+        let app_models = temp_dir.path().join("app-models");
+        let result = mapper
+            .apply_mapping("comfyui", Some("0.1.0"), &app_models)
+            .await
+            .unwrap();
+
+        assert_eq!(result.created, 1, "errors: {:?}", result.errors);
+        assert!(app_models.join("llm/ready-model.gguf").is_symlink());
+        assert!(!app_models.join("llm/downloading-model.gguf").exists());
+        assert!(!app_models.join("llm/failed-model.gguf").exists());
+    }
+
+    #[tokio::test]
+    async fn test_sync_incremental_only_touches_the_delta() {
+        let (temp_dir, library, mapper) = setup().await;
+        let add_model = |name: &'static str| {
+            let library = library.clone();
+            async move {
+                let model_dir = library.build_model_path("llm", "llama", name);
+                std::fs::create_dir_all(&model_dir).unwrap();
+                std::fs::write(model_dir.join(format!("{name}.gguf")), b"weights").unwrap();
+                let metadata = crate::models::ModelMetadata {
+                    model_type: Some("llm".to_string()),
+                    family: Some("llama".to_string()),
+                    cleaned_name: Some(name.to_string()),
+                    ..Default::default()
+                };
+                library.save_metadata(&model_dir, &metadata).await.unwrap();
+                library.index_model_dir(&model_dir).await.unwrap();
+            }
+        };
+        let save_config = |target_dir: &str| {
+            mapper
+                .save_config(&MappingConfig {
+                    app: "comfyui".to_string(),
+                    version: "0.1.0".to_string(),
+                    variant: None,
+                    replace_inherited: false,
+                    mappings: vec![MappingRule {
+                        target_dir: target_dir.to_string(),
+                        model_types: Some(vec!["llm".to_string()]),
+                        subtypes: None,
+                        families: None,
+                        tags: None,
+                        exclude_tags: None,
+                        use_original_filenames: false,
+                    }],
+                })
+                .unwrap();
+        };
+        let app_models = temp_dir.path().join("app-models");
+        let counts = |result: &IncrementalSyncResult| {
+            assert!(result.errors.is_empty(), "errors: {:?}", result.errors);
+            (result.added, result.removed, result.unchanged)
+        };
+
+        add_model("first").await;
+        save_config("llm");
+        let result = mapper
+            .sync_incremental(
+                "comfyui",
+                Some("0.1.0"),
+                &app_models,
+                ConflictResolution::Skip,
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts(&result), (1, 0, 0));
+
+        add_model("second").await;
+        let result = mapper
+            .sync_incremental(
+                "comfyui",
+                Some("0.1.0"),
+                &app_models,
+                ConflictResolution::Skip,
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts(&result), (1, 0, 1));
+        assert!(app_models.join("llm/second.gguf").is_symlink());
+
+        save_config("text");
+        let result = mapper
+            .sync_incremental(
+                "comfyui",
+                Some("0.1.0"),
+                &app_models,
+                ConflictResolution::Skip,
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts(&result), (2, 2, 0));
+        assert!(!app_models.join("llm/first.gguf").exists());
+        assert!(app_models.join("text/first.gguf").is_symlink());
+
+        // A user's file at a target is left alone and reported as a conflict.
+        std::fs::remove_file(app_models.join("text/second.gguf")).unwrap();
+        std::fs::write(app_models.join("text/second.gguf"), b"user file").unwrap();
+        mapper
+            .link_registry
+            .read()
+            .await
+            .unregister(app_models.join("text/second.gguf"))
+            .await
+            .unwrap();
+        let result = mapper
+            .sync_incremental(
+                "comfyui",
+                Some("0.1.0"),
+                &app_models,
+                ConflictResolution::Skip,
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts(&result), (0, 0, 1));
+        assert_eq!(result.conflicts, 1);
+        assert_eq!(
+            std::fs::read(app_models.join("text/second.gguf")).unwrap(),
+            b"user file"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_incremental_applies_conflict_resolution() {
+        let (temp_dir, library, mapper) = setup().await;
+        let model_dir = library.build_model_path("llm", "llama", "tiny");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("tiny.gguf"), b"weights").unwrap();
+        let metadata = crate::models::ModelMetadata {
+            model_type: Some("llm".to_string()),
+            family: Some("llama".to_string()),
+            cleaned_name: Some("tiny".to_string()),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+        mapper
+            .save_config(&MappingConfig {
+                app: "comfyui".to_string(),
+                version: "0.1.0".to_string(),
+                variant: None,
+                replace_inherited: false,
+                mappings: vec![MappingRule {
+                    target_dir: "llm".to_string(),
+                    model_types: Some(vec!["llm".to_string()]),
+                    subtypes: None,
+                    families: None,
+                    tags: None,
+                    exclude_tags: None,
+                    use_original_filenames: false,
+                }],
+            })
+            .unwrap();
+
+        let app_models = temp_dir.path().join("app-models");
+        std::fs::create_dir_all(app_models.join("llm")).unwrap();
+        std::fs::write(app_models.join("llm/tiny.gguf"), b"user file").unwrap();
+        let sync = |on_conflict| {
+            mapper.sync_incremental("comfyui", Some("0.1.0"), &app_models, on_conflict)
+        };
+
+        let result = sync(ConflictResolution::Rename).await.unwrap();
+        assert_eq!((result.added, result.conflicts), (1, 0));
+        assert!(app_models.join("llm/tiny_1.gguf").is_symlink());
+
+        // The earlier rename is recognized instead of renaming again.
+        let result = sync(ConflictResolution::Rename).await.unwrap();
+        assert_eq!((result.added, result.removed, result.unchanged), (0, 0, 1));
+        assert!(!app_models.join("llm/tiny_2.gguf").exists());
+
+        let result = sync(ConflictResolution::Overwrite).await.unwrap();
+        assert_eq!((result.added, result.removed), (1, 1));
+        assert!(app_models.join("llm/tiny.gguf").is_symlink());
+        assert!(!app_models.join("llm/tiny_1.gguf").exists());
+    }
+
     #[test]
     fn test_sandbox_detection() {
         let sandbox = ModelMapper::detect_sandbox();
@@ -1057,6 +1930,87 @@ mod tests {
         assert!(!sandbox.sandbox_type.is_empty());
     }
 
+    #[test]
+    fn test_links_skip_symlinks_when_unsupported() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("model.gguf");
+        std::fs::write(&source, b"weights").unwrap();
+
+        let target = temp_dir.path().join("linked.gguf");
+        let link_type =
+            ModelMapper::create_link_file(&source, &target, LinkType::Symlink, false).unwrap();
+
+        assert_eq!(link_type, LinkType::Hardlink);
+        assert!(!target.is_symlink());
+        assert_eq!(std::fs::read(&target).unwrap(), b"weights");
+    }
+
+    #[test]
+    fn test_links_use_preferred_type_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("model.gguf");
+        std::fs::write(&source, b"weights").unwrap();
+
+        let copied = temp_dir.path().join("copied.gguf");
+        let link_type =
+            ModelMapper::create_link_file(&source, &copied, LinkType::Copy, true).unwrap();
+        assert_eq!(link_type, LinkType::Copy);
+        assert!(!copied.is_symlink());
+
+        let hardlinked = temp_dir.path().join("hardlinked.gguf");
+        let link_type =
+            ModelMapper::create_link_file(&source, &hardlinked, LinkType::Hardlink, true).unwrap();
+        assert_eq!(link_type, LinkType::Hardlink);
+        assert!(!hardlinked.is_symlink());
+    }
+
+    #[tokio::test]
+    async fn test_default_link_type_persists() {
+        let (temp_dir, library, mapper) = setup().await;
+        assert_eq!(mapper.default_link_type().unwrap(), LinkType::Symlink);
+
+        mapper
+            .set_default_link_type_async(LinkType::Hardlink)
+            .await
+            .unwrap();
+        assert_eq!(mapper.default_link_type().unwrap(), LinkType::Hardlink);
+        assert!(library
+            .library_root()
+            .join(MAPPING_SETTINGS_FILENAME)
+            .exists());
+
+        let reopened = ModelMapper::new(library.clone(), temp_dir.path().join("config"));
+        assert_eq!(
+            reopened.default_link_type_async().await.unwrap(),
+            LinkType::Hardlink
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_link_type_same_filesystem() {
+        let (temp_dir, library, mapper) = setup().await;
+        let app_models = temp_dir.path().join("app").join("models");
+        std::fs::create_dir_all(&app_models).unwrap();
+
+        let hardlink = mapper
+            .check_link_type(LinkType::Hardlink, &app_models)
+            .unwrap();
+        assert!(hardlink.feasible);
+        assert!(hardlink.reason.is_none());
+
+        let symlink = mapper
+            .check_link_type(LinkType::Symlink, &app_models)
+            .unwrap();
+        assert_eq!(symlink.feasible, library.can_create_symlinks());
+
+        assert!(
+            mapper
+                .check_link_type(LinkType::Copy, &app_models)
+                .unwrap()
+                .feasible
+        );
+    }
+
     #[tokio::test]
     async fn test_renamed_path() {
         let (_temp, _library, mapper) = setup().await;
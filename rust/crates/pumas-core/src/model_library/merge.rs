@@ -23,7 +23,7 @@ use std::sync::Arc;
 use tokio::fs;
 use tracing::{debug, info, warn};
 
-use super::library::ModelLibrary;
+use super::library::{collect_model_dirs_async, ModelLibrary};
 use super::naming::normalize_name;
 
 /// Result of a library merge operation.
@@ -89,7 +89,7 @@ impl LibraryMerger {
                     message: format!("Failed to open source library: {}", e),
                 })?;
 
-        let source_dirs: Vec<PathBuf> = source.model_dirs().collect();
+        let source_dirs: Vec<PathBuf> = collect_model_dirs_async(source.clone()).await?;
         info!(
             "Merge: found {} model directories in source",
             source_dirs.len()
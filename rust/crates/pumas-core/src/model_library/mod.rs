@@ -41,10 +41,16 @@ mod metadata_v2;
 mod model_type_resolver;
 mod naming;
 mod package_facts;
+mod path_template;
 mod read_only;
+mod rebuild_progress;
+mod scan_ignore;
+mod security_audit;
 pub mod sharding;
+mod shared_storage;
 mod task_signature;
 mod types;
+mod verify;
 mod watcher;
 
 pub(crate) use artifact_identity::versioned_architecture_family_from_text;
@@ -60,32 +66,35 @@ pub use dependencies::{
     ModelDependencyRequirementsResolution, DEPENDENCY_CONTRACT_VERSION,
 };
 pub use directory_import::classify_import_path;
-pub use download_store::DownloadPersistence;
+pub use download_store::{DownloadHistoryEntry, DownloadPersistence};
 pub(crate) use external_assets::get_diffusers_bundle_lookup_hints;
 pub use external_assets::{get_diffusers_component_manifest, MODEL_EXECUTION_CONTRACT_VERSION};
-pub use hashing::{compute_dual_hash, compute_fast_hash, DualHash};
+pub use hashing::{compute_dual_hash, compute_fast_hash, DualHash, DualHasher};
 pub use hf::{
     AuxFilesCompleteCallback, AuxFilesCompleteInfo, DownloadCompletionCallback,
-    DownloadCompletionInfo, HfAuthStatus, HuggingFaceClient,
+    DownloadCompletionInfo, DownloadIdGenerator, HfAuthStatus, HuggingFaceClient,
 };
 pub use hf_cache::{CacheStats, CachedRepoDetails, HfCacheConfig, HfSearchCache};
 pub use identifier::{extract_gguf_metadata, identify_model_type, ModelTypeInfo};
 pub use importer::{
-    InPlaceImportSpec, IncompleteShardRecovery, InterruptedDownload, ModelImporter,
-    OrphanScanResult,
+    BatchImportTracker, ImportJobProgress, ImportProgressReporter, ImportProgressTracker,
+    InPlaceImportSpec, IncompleteShardRecovery, InterruptedDownload, ModelImporter, OrphanPreview,
+    OrphanScanConfig, OrphanScanProgress, OrphanScanResult,
 };
+pub(crate) use library::DB_FILENAME as MODEL_INDEX_DB_FILENAME;
 pub use library::{
-    MetadataProjectionCleanupDryRunItem, MetadataProjectionCleanupDryRunReport,
-    MetadataProjectionCleanupExecutionReport, MigrationDryRunItem, MigrationDryRunReport,
-    MigrationExecutionItem, MigrationExecutionReport, MigrationPlannedMove,
-    MigrationReportArtifact, ModelLibrary, ModelLibraryUpdateSubscriber,
-    PackageFactsCacheMigrationDryRunItem, PackageFactsCacheMigrationDryRunReport,
+    HashBackfillProgress, HashBackfillResult, IndexConsistencyReport, IndexInconsistency,
+    IndexInconsistencyKind, MetadataProjectionCleanupDryRunItem,
+    MetadataProjectionCleanupDryRunReport, MetadataProjectionCleanupExecutionReport,
+    MigrationDryRunItem, MigrationDryRunReport, MigrationExecutionItem, MigrationExecutionReport,
+    MigrationPlannedMove, MigrationReportArtifact, ModelLibrary, ModelLibraryUpdateSubscriber,
+    ModelPathPreview, PackageFactsCacheMigrationDryRunItem, PackageFactsCacheMigrationDryRunReport,
     PackageFactsCacheMigrationExecutionItem, PackageFactsCacheMigrationExecutionReport,
     PackageFactsCacheMigrationPlannedWork, PackageFactsCacheMigrationValidationReport,
     ReclassifyResult,
 };
-pub use link_registry::LinkRegistry;
-pub use mapper::ModelMapper;
+pub use link_registry::{LinkReconcileReport, LinkRegistry, VersionModelsPath};
+pub use mapper::{IncrementalSyncResult, ModelMapper, MAPPING_SETTINGS_FILENAME};
 pub use merge::{LibraryMerger, MergeResult};
 pub use metadata_v2::{
     normalize_recommended_backend, normalize_review_reasons, push_review_reason,
@@ -96,9 +105,17 @@ pub use model_type_resolver::{
     ModelTypeResolution,
 };
 pub use naming::normalize_name;
+pub use path_template::{ModelPathTemplate, DEFAULT_MODEL_PATH_TEMPLATE};
 pub use read_only::PumasReadOnlyLibrary;
+pub use rebuild_progress::{
+    IndexRebuildJob, IndexRebuildState, IndexRebuildTracker, RebuildProgress,
+};
+pub use scan_ignore::{ScanIgnore, DEFAULT_IGNORE_PATTERNS, IGNORE_FILENAME};
+pub use security_audit::{SecurityAuditEntry, SecurityAuditReport, SecurityTierSource};
+pub use shared_storage::{SharedStorageCandidate, SharedStorageScanReport};
 pub use task_signature::{
     normalize_task_signature, NormalizedTaskSignature, TaskNormalizationStatus,
 };
 pub use types::*;
+pub use verify::{FileVerifyEntry, FileVerifyStatus, ModelVerifyResult};
 pub use watcher::{ChangeCallback, ModelLibraryWatcher};
@@ -3,6 +3,7 @@
 //! Ensures model names are safe for use as filenames across platforms.
 
 use regex::Regex;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 /// Maximum length for normalized names.
@@ -91,6 +92,59 @@ pub fn normalize_name(name: &str) -> String {
     result
 }
 
+/// Disambiguate a normalized name with a numeric `-{n}` suffix.
+///
+/// The base is shortened when needed so the suffix survives length truncation.
+pub(crate) fn disambiguated_name(base: &str, n: u32) -> String {
+    let suffix = format!("-{}", n);
+    let max_base = MAX_NAME_LENGTH - suffix.len();
+    let base = if base.len() > max_base {
+        base[..max_base].trim_end_matches(['-', '_'])
+    } else {
+        base
+    };
+    format!("{}{}", base, suffix)
+}
+
+/// Whether `dir` is on a case-insensitive filesystem.
+///
+/// Probed by creating a mixed-case file and looking it up in lowercase. When
+/// the probe cannot run (for example a read-only directory), falls back to
+/// the platform default: case-insensitive on macOS and Windows.
+pub(crate) fn is_case_insensitive_fs(dir: &Path) -> bool {
+    let probe = dir.join(format!(".Pumas-Case-Probe-{}", std::process::id()));
+    if std::fs::write(&probe, b"").is_err() {
+        return cfg!(any(target_os = "macos", windows));
+    }
+    let lowercase = dir.join(format!(".pumas-case-probe-{}", std::process::id()));
+    let insensitive = lowercase.exists();
+    let _ = std::fs::remove_file(&probe);
+    insensitive
+}
+
+/// Entry of `parent` named `name`, or failing that one whose name differs
+/// only by case.
+///
+/// Returns the entry in its on-disk spelling.
+pub(crate) fn find_entry_ignoring_case(parent: &Path, name: &str) -> Option<PathBuf> {
+    let wanted = name.to_lowercase();
+    let mut variant = None;
+    for entry in std::fs::read_dir(parent)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+    {
+        let entry_name = entry.file_name();
+        let entry_name = entry_name.to_string_lossy();
+        if entry_name == name {
+            return Some(entry.path());
+        }
+        if variant.is_none() && entry_name.to_lowercase() == wanted {
+            variant = Some(entry.path());
+        }
+    }
+    variant
+}
+
 /// Normalize a filename while preserving its extension.
 ///
 /// # Examples
@@ -156,6 +210,34 @@ pub fn extract_base_name(filename: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_case_insensitive_probe_matches_filesystem() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("probe"), b"").unwrap();
+        let expected = temp_dir.path().join("PROBE").exists();
+
+        assert_eq!(is_case_insensitive_fs(temp_dir.path()), expected);
+        let leftovers: Vec<_> = std::fs::read_dir(temp_dir.path()).unwrap().collect();
+        assert_eq!(leftovers.len(), 1);
+    }
+
+    #[test]
+    fn test_find_entry_ignoring_case_prefers_exact_spelling() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("Llama")).unwrap();
+
+        assert_eq!(
+            find_entry_ignoring_case(temp_dir.path(), "llama"),
+            Some(temp_dir.path().join("Llama"))
+        );
+        assert_eq!(
+            find_entry_ignoring_case(temp_dir.path(), "Llama"),
+            Some(temp_dir.path().join("Llama"))
+        );
+        assert_eq!(find_entry_ignoring_case(temp_dir.path(), "mistral"), None);
+    }
 
     #[test]
     fn test_normalize_name_basic() {
@@ -194,6 +276,29 @@ mod tests {
         assert_eq!(normalize_name("nul"), "nul_model");
     }
 
+    #[test]
+    fn test_normalize_name_collides_on_unicode_and_punctuation() {
+        assert_eq!(
+            normalize_name("Modèle Rapide"),
+            normalize_name("Modéle Rapide")
+        );
+        assert_eq!(
+            normalize_name("Qwen2.5 Coder"),
+            normalize_name("qwen2,5 (coder)")
+        );
+        assert_eq!(normalize_name("模型 A"), normalize_name("模型-a"));
+    }
+
+    #[test]
+    fn test_disambiguated_name_survives_normalization() {
+        assert_eq!(disambiguated_name("mod_le_rapide", 2), "mod_le_rapide-2");
+
+        let long = "a".repeat(MAX_NAME_LENGTH);
+        let name = disambiguated_name(&long, 12);
+        assert!(name.ends_with("-12"));
+        assert_eq!(normalize_name(&name), name);
+    }
+
     #[test]
     fn test_normalize_name_empty() {
         assert_eq!(normalize_name(""), "unnamed_model");
@@ -0,0 +1,214 @@
+//! Configurable on-disk layout for library model directories.
+//!
+//! [`ModelPathTemplate`] decides where `build_model_path` places a model
+//! relative to the library root, and therefore the model's ID. Templates are
+//! `/`-separated segments built from literal text and the placeholders
+//! `{type}`, `{family}`, and `{name}`; the default is
+//! [`DEFAULT_MODEL_PATH_TEMPLATE`].
+//!
+//! Changing the template only affects where new models are placed. Existing
+//! models keep their directories and IDs until a metadata migration
+//! (`execute_model_migration`) moves them to the current layout.
+
+use crate::error::{PumasError, Result};
+use std::path::{Path, PathBuf};
+
+/// Layout used when no template is configured.
+pub const DEFAULT_MODEL_PATH_TEMPLATE: &str = "{type}/{family}/{name}";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Literal(String),
+    Type,
+    Family,
+    Name,
+}
+
+/// Validated model directory layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelPathTemplate {
+    template: String,
+    segments: Vec<Vec<TemplatePart>>,
+}
+
+impl Default for ModelPathTemplate {
+    fn default() -> Self {
+        Self::parse(DEFAULT_MODEL_PATH_TEMPLATE).expect("default model path template is valid")
+    }
+}
+
+impl ModelPathTemplate {
+    /// Parse and validate a template such as `{family}/{name}`.
+    ///
+    /// The template must contain `{name}` so every model gets its own
+    /// directory. Literal text is limited to ASCII letters, digits, `-`, `_`,
+    /// and `.`, and segments may not be empty, `.`, or `..`.
+    pub fn parse(template: &str) -> Result<Self> {
+        let invalid = |message: String| PumasError::Validation {
+            field: "model_path_template".to_string(),
+            message,
+        };
+
+        let template = template.trim();
+        let mut segments = Vec::new();
+        let mut has_name = false;
+        for segment in template.split('/') {
+            if segment.is_empty() || segment == "." || segment == ".." {
+                return Err(invalid(format!(
+                    "'{}' has an empty, '.', or '..' path segment",
+                    template
+                )));
+            }
+
+            let mut parts = Vec::new();
+            let mut rest = segment;
+            while !rest.is_empty() {
+                if let Some(after_open) = rest.strip_prefix('{') {
+                    let Some(close) = after_open.find('}') else {
+                        return Err(invalid(format!("unclosed '{{' in '{}'", template)));
+                    };
+                    let part = match &after_open[..close] {
+                        "type" => TemplatePart::Type,
+                        "family" => TemplatePart::Family,
+                        "name" => TemplatePart::Name,
+                        other => {
+                            return Err(invalid(format!(
+                                "unknown placeholder '{{{}}}'; expected {{type}}, {{family}}, or {{name}}",
+                                other
+                            )))
+                        }
+                    };
+                    has_name |= part == TemplatePart::Name;
+                    parts.push(part);
+                    rest = &after_open[close + 1..];
+                } else {
+                    let end = rest.find('{').unwrap_or(rest.len());
+                    let literal = &rest[..end];
+                    if let Some(bad) = literal
+                        .chars()
+                        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                    {
+                        return Err(invalid(format!(
+                            "character '{}' is not allowed in '{}'",
+                            bad, template
+                        )));
+                    }
+                    parts.push(TemplatePart::Literal(literal.to_string()));
+                    rest = &rest[end..];
+                }
+            }
+            segments.push(parts);
+        }
+
+        if !has_name {
+            return Err(invalid(format!("'{}' must contain {{name}}", template)));
+        }
+
+        Ok(Self {
+            template: template.to_string(),
+            segments,
+        })
+    }
+
+    /// The template string as configured.
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Number of directory levels between the library root and a model directory.
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Render the relative path segments for already-normalized values.
+    ///
+    /// Segments that render empty (for example from an empty family) are
+    /// dropped, matching how an empty component joins onto a path.
+    pub fn render_segments(&self, model_type: &str, family: &str, name: &str) -> Vec<String> {
+        self.segments
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| match part {
+                        TemplatePart::Literal(text) => text.as_str(),
+                        TemplatePart::Type => model_type,
+                        TemplatePart::Family => family,
+                        TemplatePart::Name => name,
+                    })
+                    .collect::<String>()
+            })
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+
+    /// Join rendered segments below `root`.
+    pub fn render_path(&self, root: &Path, model_type: &str, family: &str, name: &str) -> PathBuf {
+        let mut path = root.to_path_buf();
+        for segment in self.render_segments(model_type, family, name) {
+            path.push(segment);
+        }
+        path
+    }
+
+    /// Render the model ID (the `/`-joined relative path).
+    pub fn render_id(&self, model_type: &str, family: &str, name: &str) -> String {
+        self.render_segments(model_type, family, name).join("/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_template_matches_type_family_name_layout() {
+        let template = ModelPathTemplate::default();
+        assert_eq!(template.as_str(), DEFAULT_MODEL_PATH_TEMPLATE);
+        assert_eq!(
+            template.render_id("llm", "llama", "llama-3-8b"),
+            "llm/llama/llama-3-8b"
+        );
+        assert_eq!(
+            template.render_path(Path::new("/library"), "llm", "llama", "llama-3-8b"),
+            Path::new("/library/llm/llama/llama-3-8b")
+        );
+    }
+
+    #[test]
+    fn custom_templates_support_literals_and_fewer_levels() {
+        let template = ModelPathTemplate::parse("{family}/{name}").unwrap();
+        assert_eq!(template.render_id("llm", "meta", "llama"), "meta/llama");
+
+        let template = ModelPathTemplate::parse("models/{type}-{family}/{name}").unwrap();
+        assert_eq!(
+            template.render_id("llm", "meta", "llama"),
+            "models/llm-meta/llama"
+        );
+
+        let template = ModelPathTemplate::parse("{family}/{type}/{name}").unwrap();
+        assert_eq!(template.render_id("llm", "", "llama"), "llm/llama");
+    }
+
+    #[test]
+    fn invalid_templates_are_rejected() {
+        for template in [
+            "{type}/{family}",
+            "{type}//{name}",
+            "../{name}",
+            "/{name}",
+            "{type}/{publisher}/{name}",
+            "{type}/{name",
+            "my models/{name}",
+            "{family}\\{name}",
+        ] {
+            assert!(
+                matches!(
+                    ModelPathTemplate::parse(template),
+                    Err(PumasError::Validation { .. })
+                ),
+                "{template} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Poll-based progress tracking for index rebuilds started in the background.
+//!
+//! [`ModelLibrary::rebuild_index_with_progress`](super::ModelLibrary::rebuild_index_with_progress)
+//! streams [`RebuildProgress`] over a channel. [`IndexRebuildTracker`] turns
+//! that stream into snapshots keyed by a rebuild ID, so callers that cannot
+//! hold a channel poll [`IndexRebuildTracker::get`] and cancel by ID.
+
+use crate::cancel::CancellationToken;
+use crate::error::{PumasError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// One step of an index rebuild.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RebuildProgress {
+    /// Model directories read so far
+    pub current: usize,
+    /// Model directories found when the rebuild started
+    pub total: usize,
+    /// Model being read, `None` once every directory has been read
+    pub current_model_id: Option<String>,
+}
+
+/// Lifecycle of a tracked rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexRebuildState {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// Snapshot of one tracked rebuild.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexRebuildJob {
+    /// ID returned when the rebuild was started
+    pub rebuild_id: String,
+    pub state: IndexRebuildState,
+    /// Latest progress reported by the rebuild
+    pub progress: RebuildProgress,
+    /// Indexed model count once the rebuild has completed
+    pub model_count: Option<usize>,
+    /// Error message if the rebuild failed
+    pub error: Option<String>,
+}
+
+/// Tracks active and recently finished index rebuilds.
+#[derive(Debug, Default)]
+pub struct IndexRebuildTracker {
+    jobs: Mutex<HashMap<String, (IndexRebuildJob, CancellationToken)>>,
+    next_id: AtomicU64,
+}
+
+impl IndexRebuildTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new rebuild and return its ID and cancellation token.
+    pub fn start(&self) -> (String, CancellationToken) {
+        let rebuild_id = format!(
+            "rebuild-{}",
+            self.next_id.fetch_add(1, Ordering::Relaxed) + 1
+        );
+        let cancel = CancellationToken::new();
+        let job = IndexRebuildJob {
+            rebuild_id: rebuild_id.clone(),
+            state: IndexRebuildState::Running,
+            progress: RebuildProgress {
+                current: 0,
+                total: 0,
+                current_model_id: None,
+            },
+            model_count: None,
+            error: None,
+        };
+        self.jobs
+            .lock()
+            .expect("rebuild progress lock poisoned")
+            .insert(rebuild_id.clone(), (job, cancel.clone()));
+        (rebuild_id, cancel)
+    }
+
+    /// Get a snapshot of a specific rebuild.
+    pub fn get(&self, rebuild_id: &str) -> Option<IndexRebuildJob> {
+        self.jobs
+            .lock()
+            .expect("rebuild progress lock poisoned")
+            .get(rebuild_id)
+            .map(|(job, _)| job.clone())
+    }
+
+    /// Request cancellation of a running rebuild.
+    ///
+    /// Returns `false` for unknown or already finished rebuilds.
+    pub fn cancel(&self, rebuild_id: &str) -> bool {
+        let jobs = self.jobs.lock().expect("rebuild progress lock poisoned");
+        match jobs.get(rebuild_id) {
+            Some((job, cancel)) if job.state == IndexRebuildState::Running => {
+                cancel.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Record the latest progress of a running rebuild.
+    pub fn update(&self, rebuild_id: &str, progress: RebuildProgress) {
+        self.apply(rebuild_id, |job| job.progress = progress);
+    }
+
+    /// Record how a rebuild ended.
+    pub fn finish(&self, rebuild_id: &str, result: &Result<usize>) {
+        self.apply(rebuild_id, |job| match result {
+            Ok(count) => {
+                job.state = IndexRebuildState::Completed;
+                job.model_count = Some(*count);
+            }
+            Err(PumasError::IndexRebuildCancelled) => {
+                job.state = IndexRebuildState::Cancelled;
+            }
+            Err(err) => {
+                job.state = IndexRebuildState::Failed;
+                job.error = Some(err.to_string());
+            }
+        });
+    }
+
+    fn apply(&self, rebuild_id: &str, apply: impl FnOnce(&mut IndexRebuildJob)) {
+        let mut jobs = self.jobs.lock().expect("rebuild progress lock poisoned");
+        if let Some((job, _)) = jobs.get_mut(rebuild_id) {
+            apply(job);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_reports_progress_and_cancels_only_running_rebuilds() {
+        let tracker = IndexRebuildTracker::new();
+        let (rebuild_id, cancel) = tracker.start();
+        let (other_id, _) = tracker.start();
+        assert_ne!(rebuild_id, other_id);
+
+        tracker.update(
+            &rebuild_id,
+            RebuildProgress {
+                current: 2,
+                total: 5,
+                current_model_id: Some("llm/family/model".to_string()),
+            },
+        );
+        let job = tracker.get(&rebuild_id).unwrap();
+        assert_eq!(job.state, IndexRebuildState::Running);
+        assert_eq!((job.progress.current, job.progress.total), (2, 5));
+
+        assert!(tracker.cancel(&rebuild_id));
+        assert!(cancel.is_cancelled());
+        tracker.finish(&rebuild_id, &Err(PumasError::IndexRebuildCancelled));
+        assert_eq!(
+            tracker.get(&rebuild_id).unwrap().state,
+            IndexRebuildState::Cancelled
+        );
+        assert!(!tracker.cancel(&rebuild_id));
+
+        tracker.finish(&other_id, &Ok(7));
+        let other = tracker.get(&other_id).unwrap();
+        assert_eq!(other.state, IndexRebuildState::Completed);
+        assert_eq!(other.model_count, Some(7));
+        assert!(tracker.get("rebuild-missing").is_none());
+    }
+}
@@ -0,0 +1,226 @@
+//! Ignore-list for directories excluded from library scans.
+//!
+//! Library walks (`model_dirs`, orphan adoption, and the filesystem watcher)
+//! skip any directory matching an ignore pattern, together with everything
+//! below it. Patterns come from [`DEFAULT_IGNORE_PATTERNS`], the caller, and an
+//! optional `.pumasignore` file in the library root.
+//!
+//! # Pattern syntax
+//!
+//! One pattern per line in `.pumasignore`; blank lines and lines starting with
+//! `#` are skipped. `*` matches any run of characters within one path
+//! component and `?` matches a single character. A pattern without `/`
+//! matches a directory name at any depth; a pattern containing `/` is matched
+//! against the directory's path relative to the library root.
+
+use std::path::{Component, Path};
+use tracing::warn;
+
+/// Name of the optional ignore file in the library root.
+pub const IGNORE_FILENAME: &str = ".pumasignore";
+
+/// Patterns every library ignores: trash, staging areas, and hidden directories.
+pub const DEFAULT_IGNORE_PATTERNS: &[&str] = &[".trash", ".staging", ".*"];
+
+/// Compiled set of directory ignore patterns for one library root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanIgnore {
+    /// Patterns matched against a single directory name.
+    name_patterns: Vec<String>,
+    /// Patterns matched against the root-relative directory path.
+    path_patterns: Vec<String>,
+}
+
+impl Default for ScanIgnore {
+    fn default() -> Self {
+        Self::new(DEFAULT_IGNORE_PATTERNS.iter().copied())
+    }
+}
+
+impl ScanIgnore {
+    /// Build an ignore-list from explicit patterns, without the defaults.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut ignore = Self {
+            name_patterns: Vec::new(),
+            path_patterns: Vec::new(),
+        };
+        for pattern in patterns {
+            ignore.add_pattern(pattern.as_ref());
+        }
+        ignore
+    }
+
+    /// Build the ignore-list for a library root.
+    ///
+    /// Combines the defaults, `extra_patterns`, and the root's `.pumasignore`
+    /// file. An unreadable ignore file is logged and skipped.
+    pub fn load(library_root: &Path, extra_patterns: &[String]) -> Self {
+        let mut ignore = Self::default();
+        for pattern in extra_patterns {
+            ignore.add_pattern(pattern);
+        }
+
+        let ignore_file = library_root.join(IGNORE_FILENAME);
+        match std::fs::read_to_string(&ignore_file) {
+            Ok(contents) => {
+                for line in contents.lines() {
+                    ignore.add_pattern(line);
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => warn!("Failed to read {}: {}", ignore_file.display(), err),
+        }
+        ignore
+    }
+
+    fn add_pattern(&mut self, pattern: &str) {
+        let pattern = pattern.trim();
+        if pattern.is_empty() || pattern.starts_with('#') {
+            return;
+        }
+        // A trailing slash only marks the pattern as a directory.
+        let pattern = pattern.trim_end_matches('/');
+        if pattern.contains('/') {
+            let pattern = pattern.trim_start_matches('/');
+            if !pattern.is_empty() {
+                self.path_patterns.push(pattern.to_string());
+            }
+        } else if !pattern.is_empty() {
+            self.name_patterns.push(pattern.to_string());
+        }
+    }
+
+    /// Check whether a directory, given relative to the library root, is ignored.
+    ///
+    /// A directory is ignored when it or any of its ancestors matches.
+    pub fn is_ignored(&self, relative_dir: &Path) -> bool {
+        let mut prefix = String::new();
+        for component in relative_dir.components() {
+            let Component::Normal(name) = component else {
+                continue;
+            };
+            let name = name.to_string_lossy();
+            if self
+                .name_patterns
+                .iter()
+                .any(|pattern| wildcard_match(pattern, &name))
+            {
+                return true;
+            }
+
+            if !prefix.is_empty() {
+                prefix.push('/');
+            }
+            prefix.push_str(&name);
+            if self
+                .path_patterns
+                .iter()
+                .any(|pattern| wildcard_match(pattern, &prefix))
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Check whether an absolute directory below `library_root` is ignored.
+    ///
+    /// Paths outside the library root are never ignored.
+    pub fn is_ignored_under(&self, library_root: &Path, dir: &Path) -> bool {
+        dir.strip_prefix(library_root)
+            .is_ok_and(|relative| self.is_ignored(relative))
+    }
+}
+
+/// Match `text` against a pattern where `*` and `?` never cross a `/`.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0usize, 0usize);
+    // Position of the last `*` and the text offset it is currently absorbing.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some('?') if text[t] != '/' => {
+                p += 1;
+                t += 1;
+            }
+            Some(&ch) if ch == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, absorbed)) if text[absorbed] != '/' => {
+                    p = star + 1;
+                    t = absorbed + 1;
+                    backtrack = Some((star, t));
+                }
+                _ => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&ch| ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn defaults_ignore_trash_staging_and_hidden_dirs() {
+        let ignore = ScanIgnore::default();
+
+        assert!(ignore.is_ignored(Path::new(".staging/llm/family/model")));
+        assert!(ignore.is_ignored(Path::new("llm/.trash")));
+        assert!(ignore.is_ignored(Path::new("llm/family/.cache")));
+        assert!(!ignore.is_ignored(Path::new("llm/family/model")));
+    }
+
+    #[test]
+    fn name_and_path_patterns_match_separately() {
+        let ignore = ScanIgnore::new(["scratch-*", "llm/experiments/*"]);
+
+        assert!(ignore.is_ignored(Path::new("diffusion/scratch-01/model")));
+        assert!(ignore.is_ignored(Path::new("llm/experiments/model")));
+        assert!(!ignore.is_ignored(Path::new("llm/experiments")));
+        assert!(!ignore.is_ignored(Path::new("diffusion/experiments/model")));
+    }
+
+    #[test]
+    fn wildcards_stay_within_one_component() {
+        assert!(wildcard_match("llm/*", "llm/family"));
+        assert!(!wildcard_match("llm/*", "llm/family/model"));
+        assert!(wildcard_match("model-?", "model-a"));
+        assert!(!wildcard_match("model-?", "model-ab"));
+        assert!(wildcard_match("*-tmp", "download-tmp"));
+    }
+
+    #[test]
+    fn load_reads_pumasignore_file() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join(IGNORE_FILENAME),
+            "# working copies\n\nwip/\n/llm/drafts\n",
+        )
+        .unwrap();
+
+        let ignore = ScanIgnore::load(temp_dir.path(), &["archive".to_string()]);
+
+        assert!(ignore.is_ignored(Path::new("llm/family/wip")));
+        assert!(ignore.is_ignored(Path::new("llm/drafts/model")));
+        assert!(ignore.is_ignored(Path::new("archive/model")));
+        assert!(ignore.is_ignored(Path::new(".staging")));
+        assert!(!ignore.is_ignored(Path::new("llm/family/model")));
+    }
+}
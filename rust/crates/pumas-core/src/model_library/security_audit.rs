@@ -0,0 +1,233 @@
+//! Library-wide security audit of model file formats.
+//!
+//! Reports each model's [`SecurityTier`] so users can review or remove
+//! pickle-format and unidentified models. The tier recorded at import time is
+//! used when present; otherwise the primary model file is re-sniffed with the
+//! importer's content detection.
+
+use crate::error::{PumasError, Result};
+use crate::index::ModelRecord;
+use crate::model_library::identifier::identify_model_type;
+use crate::model_library::library::{find_primary_model_file, ModelLibrary};
+use crate::models::{ModelMetadata, SecurityTier, StorageKind};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// How a security audit entry's tier was determined.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum SecurityTierSource {
+    /// Recorded in `metadata.json` when the model was imported.
+    Metadata,
+    /// Re-detected from the primary model file.
+    Sniffed,
+    /// No model file could be found to inspect.
+    NoModelFile,
+}
+
+/// Security audit finding for one model.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SecurityAuditEntry {
+    pub model_id: String,
+    pub security_tier: SecurityTier,
+    pub tier_source: SecurityTierSource,
+    /// Primary file that was inspected when the tier was re-sniffed.
+    pub primary_file: Option<String>,
+    /// Whether the pickle risk was acknowledged when the model was imported.
+    pub security_acknowledged: bool,
+    /// Set for `Pickle` and `Unknown` tiers.
+    pub flagged: bool,
+}
+
+/// Report of the security tier of every indexed model.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SecurityAuditReport {
+    pub generated_at: String,
+    pub total_models_scanned: u32,
+    pub safe_count: u32,
+    pub pickle_count: u32,
+    pub unknown_count: u32,
+    pub flagged_count: u32,
+    /// One entry per model, sorted by model ID.
+    pub entries: Vec<SecurityAuditEntry>,
+}
+
+impl ModelLibrary {
+    /// Audit the security tier of every indexed model.
+    ///
+    /// Read-only: tiers missing from metadata are re-sniffed but not written back.
+    pub async fn security_audit(&self) -> Result<SecurityAuditReport> {
+        let models = self.list_models().await?;
+        let library = self.clone();
+        tokio::task::spawn_blocking(move || library.security_audit_for_models(models))
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join security audit task: {}", err))
+            })?
+    }
+
+    fn security_audit_for_models(&self, models: Vec<ModelRecord>) -> Result<SecurityAuditReport> {
+        let mut report = SecurityAuditReport {
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            ..Default::default()
+        };
+
+        for model in &models {
+            let metadata = self.get_effective_metadata(&model.id)?.unwrap_or_default();
+            let entry = self.audit_model(&model.id, &metadata);
+            match entry.security_tier {
+                SecurityTier::Safe => report.safe_count += 1,
+                SecurityTier::Pickle => report.pickle_count += 1,
+                SecurityTier::Unknown => report.unknown_count += 1,
+            }
+            if entry.flagged {
+                report.flagged_count += 1;
+            }
+            report.entries.push(entry);
+        }
+
+        report.total_models_scanned = report.entries.len() as u32;
+        report
+            .entries
+            .sort_by(|left, right| left.model_id.cmp(&right.model_id));
+        Ok(report)
+    }
+
+    fn audit_model(&self, model_id: &str, metadata: &ModelMetadata) -> SecurityAuditEntry {
+        let (security_tier, tier_source, primary_file) = match metadata.security_tier {
+            Some(tier) => (tier, SecurityTierSource::Metadata, None),
+            None => match self.audit_primary_file(model_id, metadata) {
+                Some(file) => (
+                    sniff_security_tier(&file),
+                    SecurityTierSource::Sniffed,
+                    Some(file),
+                ),
+                None => (SecurityTier::Unknown, SecurityTierSource::NoModelFile, None),
+            },
+        };
+
+        SecurityAuditEntry {
+            model_id: model_id.to_string(),
+            security_tier,
+            tier_source,
+            primary_file: primary_file.map(|file| file.display().to_string()),
+            security_acknowledged: metadata.security_acknowledged.unwrap_or(false),
+            flagged: security_tier != SecurityTier::Safe,
+        }
+    }
+
+    /// Primary model file to sniff, following external references to their source.
+    fn audit_primary_file(&self, model_id: &str, metadata: &ModelMetadata) -> Option<PathBuf> {
+        if metadata.storage_kind == Some(StorageKind::ExternalReference) {
+            let source = Path::new(metadata.source_path.as_deref()?);
+            if source.is_file() {
+                return Some(source.to_path_buf());
+            }
+            return find_primary_model_file(source);
+        }
+        find_primary_model_file(&self.library_root().join(model_id))
+    }
+}
+
+fn sniff_security_tier(file: &Path) -> SecurityTier {
+    identify_model_type(file)
+        .map(|info| info.format.security_tier())
+        .unwrap_or(SecurityTier::Unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn add_model(
+        library: &ModelLibrary,
+        name: &str,
+        file: (&str, &[u8]),
+        security_tier: Option<SecurityTier>,
+    ) {
+        let model_dir = library.build_model_path("llm", "audit", name);
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join(file.0), file.1).unwrap();
+        let metadata = ModelMetadata {
+            model_id: Some(format!("llm/audit/{name}")),
+            model_type: Some("llm".to_string()),
+            family: Some("audit".to_string()),
+            cleaned_name: Some(name.to_string()),
+            security_tier,
+            security_acknowledged: (security_tier == Some(SecurityTier::Pickle)).then_some(true),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn security_audit_flags_pickle_and_unknown_models() {
+        let temp_dir = TempDir::new().unwrap();
+        let library = ModelLibrary::new(temp_dir.path()).await.unwrap();
+
+        let mut safetensors = 2u64.to_le_bytes().to_vec();
+        safetensors.extend_from_slice(b"{}");
+        safetensors.extend_from_slice(&[0u8; 16]);
+
+        add_model(
+            &library,
+            "acknowledged",
+            ("model.pt", &[0x50, 0x4B, 0x03, 0x04]),
+            Some(SecurityTier::Pickle),
+        )
+        .await;
+        add_model(
+            &library,
+            "downloaded",
+            ("model.safetensors", &safetensors),
+            None,
+        )
+        .await;
+        add_model(
+            &library,
+            "legacy",
+            ("model.ckpt", &[0x80, 0x02, 0x7D, 0x71]),
+            None,
+        )
+        .await;
+        add_model(&library, "notes-only", ("README.md", b"# notes"), None).await;
+
+        let report = library.security_audit().await.unwrap();
+
+        assert_eq!(report.total_models_scanned, 4);
+        assert_eq!(
+            (report.safe_count, report.pickle_count, report.unknown_count),
+            (1, 2, 1)
+        );
+        assert_eq!(report.flagged_count, 3);
+
+        let entry = |name: &str| {
+            report
+                .entries
+                .iter()
+                .find(|entry| entry.model_id == format!("llm/audit/{name}"))
+                .unwrap()
+        };
+        assert_eq!(
+            entry("acknowledged").tier_source,
+            SecurityTierSource::Metadata
+        );
+        assert!(entry("acknowledged").security_acknowledged);
+        assert_eq!(entry("downloaded").tier_source, SecurityTierSource::Sniffed);
+        assert!(!entry("downloaded").flagged);
+        assert_eq!(entry("legacy").security_tier, SecurityTier::Pickle);
+        assert!(!entry("legacy").security_acknowledged);
+        assert_eq!(
+            entry("notes-only").tier_source,
+            SecurityTierSource::NoModelFile
+        );
+        assert!(entry("notes-only").flagged);
+    }
+}
@@ -0,0 +1,290 @@
+//! Discovery of model files kept outside the library.
+//!
+//! Onboarding for users with an existing collection (for example a ComfyUI
+//! `models/` folder): [`ModelLibrary::scan_shared_storage`] walks the given
+//! directories and reports model files the library does not hold yet, with
+//! their detected type and family, so the caller can offer to import them.
+//! Nothing is imported or modified.
+
+use crate::error::{PumasError, Result};
+use crate::index::ModelRecord;
+use crate::model_library::identifier::identify_model_type;
+use crate::model_library::library::ModelLibrary;
+use crate::model_library::naming::normalize_filename;
+use crate::model_library::sharding::detect_sharded_sets;
+use crate::models::{SecurityTier, StorageKind};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Extensions treated as model weights.
+const MODEL_EXTENSIONS: &[&str] = &["gguf", "safetensors", "pt", "pth", "ckpt", "bin", "onnx"];
+
+/// A model found outside the library.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SharedStorageCandidate {
+    /// File to import (the first shard for sharded sets)
+    pub path: String,
+    /// Total size of the file or every shard
+    pub size_bytes: u64,
+    /// Detected model type, `unknown` when detection found nothing
+    pub model_type: String,
+    pub family: Option<String>,
+    pub security_tier: SecurityTier,
+    /// Every shard of a sharded set, in order; empty for single files
+    pub shard_files: Vec<String>,
+}
+
+/// Result of scanning external directories for models to import.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SharedStorageScanReport {
+    /// Directories that were walked
+    pub scanned_paths: Vec<String>,
+    /// Model files seen across all directories
+    pub files_scanned: u32,
+    /// Model files skipped because the library already holds them
+    pub already_in_library: u32,
+    /// Importable models, sorted by path
+    pub candidates: Vec<SharedStorageCandidate>,
+    /// Directories or files that could not be read
+    pub errors: Vec<String>,
+}
+
+impl ModelLibrary {
+    /// Scan external directories for model files not yet in the library.
+    ///
+    /// A file counts as already in the library when it resolves into the
+    /// library root (such as a mapped link), lies under an external-reference
+    /// model's source, or matches a library file's normalized name and size.
+    pub async fn scan_shared_storage(
+        &self,
+        extra_paths: Vec<PathBuf>,
+    ) -> Result<SharedStorageScanReport> {
+        let models = self.list_models().await?;
+        let library = self.clone();
+        tokio::task::spawn_blocking(move || library.scan_shared_storage_sync(&extra_paths, &models))
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join shared storage scan task: {}", err))
+            })?
+    }
+
+    fn scan_shared_storage_sync(
+        &self,
+        extra_paths: &[PathBuf],
+        models: &[ModelRecord],
+    ) -> Result<SharedStorageScanReport> {
+        let mut external_sources = Vec::new();
+        for model in models {
+            let metadata = self.get_effective_metadata(&model.id)?.unwrap_or_default();
+            if metadata.storage_kind == Some(StorageKind::ExternalReference) {
+                if let Some(source) = metadata.source_path {
+                    let source = PathBuf::from(source);
+                    external_sources.push(source.canonicalize().unwrap_or(source));
+                }
+            }
+        }
+        let library_root = self
+            .library_root()
+            .canonicalize()
+            .unwrap_or_else(|_| self.library_root().to_path_buf());
+        let library_files = library_file_keys(&library_root);
+        let mut report = SharedStorageScanReport::default();
+
+        for root in extra_paths {
+            if !root.is_dir() {
+                report
+                    .errors
+                    .push(format!("{}: not a directory", root.display()));
+                continue;
+            }
+            report.scanned_paths.push(root.display().to_string());
+
+            // Candidate files grouped by directory so shards stay together.
+            let mut by_dir: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+            for entry in WalkDir::new(root).min_depth(1) {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        report.errors.push(err.to_string());
+                        continue;
+                    }
+                };
+                if entry.file_type().is_dir() || !is_model_file(entry.path()) {
+                    continue;
+                }
+                report.files_scanned += 1;
+
+                let Ok(resolved) = entry.path().canonicalize() else {
+                    report
+                        .errors
+                        .push(format!("{}: broken link", entry.path().display()));
+                    continue;
+                };
+                let size = resolved.metadata().map(|m| m.len()).unwrap_or(0);
+                let in_library = resolved.starts_with(&library_root)
+                    || external_sources
+                        .iter()
+                        .any(|source| resolved.starts_with(source))
+                    || library_files.contains(&file_key(&resolved, size));
+                if in_library {
+                    report.already_in_library += 1;
+                    continue;
+                }
+
+                if let Some(parent) = resolved.parent() {
+                    by_dir
+                        .entry(parent.to_path_buf())
+                        .or_default()
+                        .push(resolved);
+                }
+            }
+
+            for files in by_dir.into_values() {
+                for mut group in detect_sharded_sets(&files).into_values() {
+                    group.sort();
+                    report.candidates.push(candidate(group));
+                }
+            }
+        }
+
+        report
+            .candidates
+            .sort_by(|left, right| left.path.cmp(&right.path));
+        report
+            .candidates
+            .dedup_by(|left, right| left.path == right.path);
+        Ok(report)
+    }
+}
+
+fn is_model_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| MODEL_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Imports normalize file names, so library files are matched on the
+/// normalized name plus size.
+fn file_key(path: &Path, size: u64) -> (String, u64) {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    (normalize_filename(&name), size)
+}
+
+fn library_file_keys(library_root: &Path) -> HashSet<(String, u64)> {
+    WalkDir::new(library_root)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && is_model_file(entry.path()))
+        .filter_map(|entry| {
+            let size = entry.metadata().ok()?.len();
+            Some(file_key(entry.path(), size))
+        })
+        .collect()
+}
+
+fn candidate(group: Vec<PathBuf>) -> SharedStorageCandidate {
+    let primary = &group[0];
+    let info = identify_model_type(primary).unwrap_or_default();
+    let size_bytes = group
+        .iter()
+        .filter_map(|file| file.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    let shard_files = if group.len() > 1 {
+        group
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    SharedStorageCandidate {
+        path: primary.display().to_string(),
+        size_bytes,
+        model_type: info.model_type.as_str().to_string(),
+        family: info.family.map(|family| family.0),
+        security_tier: info.format.security_tier(),
+        shard_files,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMetadata;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn scan_reports_models_missing_from_the_library() {
+        let temp_dir = TempDir::new().unwrap();
+        let library = ModelLibrary::new(temp_dir.path().join("library"))
+            .await
+            .unwrap();
+
+        let model_dir = library.build_model_path("llm", "llama", "imported");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("imported_q4.gguf"), b"GGUF-imported").unwrap();
+        library
+            .save_metadata(
+                &model_dir,
+                &ModelMetadata {
+                    model_type: Some("llm".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+
+        let comfy_models = temp_dir.path().join("ComfyUI/models");
+        let checkpoints = comfy_models.join("checkpoints");
+        std::fs::create_dir_all(&checkpoints).unwrap();
+        std::fs::write(comfy_models.join("Imported Q4.gguf"), b"GGUF-imported").unwrap();
+        std::fs::write(checkpoints.join("legacy.ckpt"), [0x80, 0x02, 0x7D, 0x71]).unwrap();
+        std::fs::write(checkpoints.join("notes.txt"), b"not a model").unwrap();
+        for shard in 1..=2 {
+            std::fs::write(
+                checkpoints.join(format!("big-{shard:05}-of-00002.safetensors")),
+                b"shard",
+            )
+            .unwrap();
+        }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(
+            model_dir.join("imported_q4.gguf"),
+            comfy_models.join("linked.gguf"),
+        )
+        .unwrap();
+
+        let report = library
+            .scan_shared_storage(vec![comfy_models.clone(), temp_dir.path().join("missing")])
+            .await
+            .unwrap();
+
+        let names: Vec<_> = report
+            .candidates
+            .iter()
+            .map(|candidate| Path::new(&candidate.path).file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(names, ["big-00001-of-00002.safetensors", "legacy.ckpt"]);
+        assert_eq!(report.candidates[0].shard_files.len(), 2);
+        assert_eq!(report.candidates[0].size_bytes, 10);
+        assert_eq!(report.candidates[1].security_tier, SecurityTier::Pickle);
+        let linked = u32::from(cfg!(unix));
+        assert_eq!(report.already_in_library, 1 + linked);
+        assert_eq!(report.files_scanned, 4 + linked);
+        assert_eq!(report.scanned_paths.len(), 1);
+        assert_eq!(report.errors.len(), 1);
+    }
+}
@@ -10,9 +10,10 @@ use std::path::PathBuf;
 pub use crate::models::{
     BundleFormat, DetectedFileType, DownloadOption, DownloadStatus, ExternalDiffusersImportSpec,
     FileGroup, FtsSearchModel, HuggingFaceEvidence, HuggingFaceModel, ImportPathCandidate,
-    ImportPathCandidateKind, ImportPathClassification, ImportPathClassificationKind, ImportStage,
-    MatchMethod, ModelData, ModelDownloadProgress, ModelFileInfo, ModelHashes, ModelImportResult,
-    ModelImportSpec, ModelMetadata, ModelOverrides, SecurityTier,
+    ImportPathCandidateKind, ImportPathClassification, ImportPathClassificationKind,
+    ImportSpecValidation, ImportStage, MatchMethod, ModelData, ModelDownloadProgress,
+    ModelFileInfo, ModelHashes, ModelImportResult, ModelImportSpec, ModelMetadata, ModelOverrides,
+    ModelReadiness, SecurityTier,
 };
 
 /// Supported model types.
@@ -386,6 +387,9 @@ pub struct MappingConfig {
     /// Config variant (default, custom)
     #[serde(default)]
     pub variant: Option<String>,
+    /// Drop rules from less specific configs instead of merging with them
+    #[serde(default)]
+    pub replace_inherited: bool,
     /// Mapping rules
     pub mappings: Vec<MappingRule>,
 }
@@ -412,6 +416,40 @@ pub struct MappingRule {
     /// Exclude by tags
     #[serde(default)]
     pub exclude_tags: Option<Vec<String>>,
+    /// Name links after the filename a file was imported under instead of
+    /// its normalized library name
+    #[serde(default)]
+    pub use_original_filenames: bool,
+}
+
+/// Problem found in a mapping config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MappingConfigIssue {
+    /// Config file name within the mapping-configs directory
+    pub file: String,
+    /// Offending field, e.g. `mappings[1].target_dir`
+    #[serde(default)]
+    pub field: Option<String>,
+    /// What is wrong and how it affects mapping
+    pub message: String,
+}
+
+/// Result of validating every mapping config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MappingConfigValidationReport {
+    /// Number of `.json` files checked
+    pub files_checked: usize,
+    /// Issues found, in file order
+    pub issues: Vec<MappingConfigIssue>,
+}
+
+impl MappingConfigValidationReport {
+    /// Whether every config file is valid.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
 }
 
 /// Action type for mapping operations.
@@ -517,6 +555,20 @@ impl Default for SandboxInfo {
     }
 }
 
+/// Scheduling priority of a queued download.
+///
+/// When more downloads are requested than the client runs at once, higher
+/// priorities start first and equal priorities start in request order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DownloadPriority {
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
 /// Download request parameters.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -563,6 +615,32 @@ pub struct DownloadRequest {
     /// Resolved license identifier or fallback status.
     #[serde(default)]
     pub license_status: Option<String>,
+    /// Skip checking downloaded LFS files against their HuggingFace SHA256.
+    /// Verification is on by default to catch truncated or corrupt files.
+    #[serde(default)]
+    pub skip_hash_verification: bool,
+    /// Number of weight files to download at once for multi-file repos.
+    /// Defaults to 1 (sequential) and is capped at 8.
+    #[serde(default)]
+    pub max_concurrent_files: Option<u32>,
+    /// Download speed cap for this download in bytes per second, replacing
+    /// the client-wide `HuggingFaceClient::set_rate_limit` cap. `Some(0)`
+    /// removes the cap for this download.
+    #[serde(default)]
+    pub rate_limit: Option<u64>,
+    /// Queue priority relative to other downloads waiting for a free slot.
+    #[serde(default)]
+    pub priority: DownloadPriority,
+    /// Glob patterns selecting which weight files to fetch from the repo file
+    /// tree. Auxiliary config and tokenizer files are still fetched unless
+    /// excluded. `*` and `?` stay within one path component; a pattern
+    /// without `/` matches any file or directory name.
+    #[serde(default)]
+    pub include_patterns: Option<Vec<String>>,
+    /// Glob patterns for repo files to skip, such as `*fp32*` or `*.md`.
+    /// Applied after `include_patterns` to every file, auxiliary or not.
+    #[serde(default)]
+    pub exclude_patterns: Option<Vec<String>>,
 }
 
 /// Batch import progress tracking.
@@ -578,8 +656,17 @@ pub struct BatchImportProgress {
     /// Currently processing item
     #[serde(default)]
     pub current: Option<String>,
+    /// Index into the submitted specs of the item being processed
+    #[serde(default)]
+    pub current_index: Option<usize>,
     /// Current stage
     pub stage: ImportStage,
+    /// Items imported successfully so far
+    #[serde(default)]
+    pub succeeded: usize,
+    /// Items that failed or were cancelled so far
+    #[serde(default)]
+    pub failed: usize,
     /// List of results so far
     pub results: Vec<ModelImportResult>,
     /// Overall progress (0.0-1.0)
@@ -593,7 +680,10 @@ impl BatchImportProgress {
             total,
             completed: 0,
             current: None,
+            current_index: None,
             stage: ImportStage::Copying,
+            succeeded: 0,
+            failed: 0,
             results: Vec::with_capacity(total),
             progress: 0.0,
         }
@@ -610,6 +700,16 @@ impl BatchImportProgress {
             1.0
         };
     }
+
+    /// Append an item result and update the success/failure counts.
+    pub fn record_result(&mut self, result: ModelImportResult) {
+        if result.success {
+            self.succeeded += 1;
+        } else {
+            self.failed += 1;
+        }
+        self.results.push(result);
+    }
 }
 
 /// Link type for model mapping.
@@ -625,6 +725,47 @@ pub enum LinkType {
     Copy,
 }
 
+/// Persisted model-mapping preferences.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct MappingSettings {
+    /// Link type tried first when creating links; the others are fallbacks
+    pub default_link_type: LinkType,
+}
+
+/// Whether a link type can be used for an app models directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LinkTypeCheck {
+    /// Link type that was checked
+    pub link_type: LinkType,
+    /// Whether links of this type can be created for the target
+    pub feasible: bool,
+    /// Why the link type cannot be used, when infeasible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Hardlink count for a single file in a model directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ModelFileLinkCount {
+    /// Path relative to the model directory
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+    /// OS hardlink count (1 when only the library references the data)
+    pub link_count: u64,
+}
+
+impl ModelFileLinkCount {
+    /// Whether other hardlinks keep this file's data on disk after the
+    /// library copy is deleted.
+    pub fn is_hard_linked(&self) -> bool {
+        self.link_count > 1
+    }
+}
+
 /// Link registry entry for tracking created links.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,376 @@
+//! On-demand integrity check of a single library model.
+//!
+//! Re-hashes the files recorded in a model's `metadata.json` and compares them
+//! against the recorded SHA256/BLAKE3, so one model can be checked without a
+//! full [`ModelLibrary::deep_scan_rebuild`]. Files on disk that the metadata
+//! does not list are reported as extras.
+
+use crate::config::NetworkConfig;
+use crate::error::{PumasError, Result};
+use crate::model_library::hashing::compute_dual_hash;
+use crate::model_library::library::{
+    find_primary_model_file, ModelLibrary, METADATA_FILENAME, OVERRIDES_FILENAME,
+};
+use crate::models::{ModelFileInfo, ModelMetadata, StorageKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Outcome of checking one model file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum FileVerifyStatus {
+    /// Recomputed hashes match the recorded ones.
+    Ok,
+    /// A recomputed hash or the file size differs from the recorded value.
+    Mismatch,
+    /// The file is recorded in metadata but not on disk.
+    Missing,
+    /// The file is on disk but not recorded in metadata.
+    Extra,
+    /// The file exists but metadata records no hash to compare against.
+    Unverified,
+}
+
+/// Verification result for one file of a model.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct FileVerifyEntry {
+    /// Path relative to the model directory.
+    pub name: String,
+    pub status: FileVerifyStatus,
+    /// Size on disk, when the file exists.
+    pub size: Option<u64>,
+    /// Recomputed SHA256, when the file was hashed.
+    pub sha256: Option<String>,
+    /// Recomputed BLAKE3, when the file was hashed.
+    pub blake3: Option<String>,
+    /// What differed, for `Mismatch` entries.
+    pub message: Option<String>,
+}
+
+/// Result of [`ModelLibrary::verify_model`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ModelVerifyResult {
+    pub model_id: String,
+    /// Set when no file is `Mismatch` or `Missing`.
+    pub verified: bool,
+    /// Recorded files in metadata order, then extra files sorted by name.
+    pub files: Vec<FileVerifyEntry>,
+}
+
+impl ModelLibrary {
+    /// Re-hash one model's files and compare them against its metadata.
+    ///
+    /// Files recorded without their own hashes are checked against the
+    /// model-level `hashes` when they are the primary model file. Models with
+    /// no `files` list are checked through their primary file alone, and
+    /// extra files are only reported for library-owned models with a `files`
+    /// list. Read-only; fails with `ModelNotFound` when the model has no
+    /// `metadata.json`.
+    pub async fn verify_model(&self, model_id: &str) -> Result<ModelVerifyResult> {
+        let library = self.clone();
+        let model_id = model_id.to_string();
+        tokio::task::spawn_blocking(move || library.verify_model_blocking(&model_id))
+            .await
+            .map_err(|err| {
+                PumasError::Other(format!("Failed to join verify model task: {}", err))
+            })?
+    }
+
+    fn verify_model_blocking(&self, model_id: &str) -> Result<ModelVerifyResult> {
+        let model_dir = self.library_root().join(model_id);
+        let metadata =
+            self.load_metadata(&model_dir)?
+                .ok_or_else(|| PumasError::ModelNotFound {
+                    model_id: model_id.to_string(),
+                })?;
+
+        let external = metadata.storage_kind == Some(StorageKind::ExternalReference);
+        let base_dir = if external {
+            external_base_dir(&metadata).unwrap_or_else(|| model_dir.clone())
+        } else {
+            model_dir
+        };
+        let primary_name =
+            find_primary_model_file(&base_dir).and_then(|path| relative_name(&base_dir, &path));
+
+        let recorded = metadata.files.clone().unwrap_or_default();
+        let mut files: Vec<FileVerifyEntry> = if recorded.is_empty() {
+            primary_name
+                .iter()
+                .map(|name| {
+                    let info = ModelFileInfo {
+                        name: name.clone(),
+                        original_name: None,
+                        size: None,
+                        sha256: None,
+                        blake3: None,
+                    };
+                    verify_recorded_file(&base_dir, &info, &metadata, true)
+                })
+                .collect()
+        } else {
+            recorded
+                .iter()
+                .map(|info| {
+                    let is_primary = primary_name.as_deref() == Some(info.name.as_str());
+                    verify_recorded_file(&base_dir, info, &metadata, is_primary)
+                })
+                .collect()
+        };
+
+        if !external && !recorded.is_empty() {
+            let known: HashSet<&str> = recorded.iter().map(|info| info.name.as_str()).collect();
+            let mut extras = find_extra_files(&base_dir, &known);
+            extras.sort_by(|left, right| left.name.cmp(&right.name));
+            files.extend(extras);
+        }
+
+        Ok(ModelVerifyResult {
+            model_id: model_id.to_string(),
+            verified: files.iter().all(|file| {
+                !matches!(
+                    file.status,
+                    FileVerifyStatus::Mismatch | FileVerifyStatus::Missing
+                )
+            }),
+            files,
+        })
+    }
+}
+
+/// Directory holding an external reference's files.
+fn external_base_dir(metadata: &ModelMetadata) -> Option<PathBuf> {
+    let source = Path::new(metadata.source_path.as_deref()?);
+    if source.is_file() {
+        source.parent().map(Path::to_path_buf)
+    } else {
+        Some(source.to_path_buf())
+    }
+}
+
+fn relative_name(base_dir: &Path, path: &Path) -> Option<String> {
+    path.strip_prefix(base_dir)
+        .ok()
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+}
+
+fn non_empty(hash: &Option<String>) -> Option<&str> {
+    hash.as_deref().filter(|hash| !hash.is_empty())
+}
+
+fn verify_recorded_file(
+    base_dir: &Path,
+    info: &ModelFileInfo,
+    metadata: &ModelMetadata,
+    is_primary: bool,
+) -> FileVerifyEntry {
+    let mut entry = FileVerifyEntry {
+        name: info.name.clone(),
+        status: FileVerifyStatus::Missing,
+        size: None,
+        sha256: None,
+        blake3: None,
+        message: None,
+    };
+    let path = base_dir.join(&info.name);
+    let Ok(file_metadata) = std::fs::metadata(&path) else {
+        return entry;
+    };
+    if !file_metadata.is_file() {
+        return entry;
+    }
+    let size = file_metadata.len();
+    entry.size = Some(size);
+
+    let (mut expected_sha256, mut expected_blake3) =
+        (non_empty(&info.sha256), non_empty(&info.blake3));
+    if expected_sha256.is_none() && expected_blake3.is_none() && is_primary {
+        if let Some(hashes) = &metadata.hashes {
+            expected_sha256 = non_empty(&hashes.sha256);
+            expected_blake3 = non_empty(&hashes.blake3);
+        }
+    }
+
+    if expected_sha256.is_none() && expected_blake3.is_none() {
+        entry.status = match info.size {
+            Some(expected) if expected != size => {
+                entry.message = Some(format!("size {} bytes, expected {}", size, expected));
+                FileVerifyStatus::Mismatch
+            }
+            _ => FileVerifyStatus::Unverified,
+        };
+        return entry;
+    }
+
+    let actual = match compute_dual_hash(&path) {
+        Ok(actual) => actual,
+        Err(err) => {
+            entry.status = FileVerifyStatus::Mismatch;
+            entry.message = Some(format!("Failed to hash file: {}", err));
+            return entry;
+        }
+    };
+    let mismatches: Vec<String> = [
+        ("SHA256", expected_sha256, &actual.sha256),
+        ("BLAKE3", expected_blake3, &actual.blake3),
+    ]
+    .into_iter()
+    .filter_map(|(label, expected, actual)| {
+        let expected = expected?;
+        (!expected.eq_ignore_ascii_case(actual))
+            .then(|| format!("{} {}, expected {}", label, actual, expected))
+    })
+    .collect();
+
+    entry.status = if mismatches.is_empty() {
+        FileVerifyStatus::Ok
+    } else {
+        entry.message = Some(mismatches.join("; "));
+        FileVerifyStatus::Mismatch
+    };
+    entry.sha256 = Some(actual.sha256);
+    entry.blake3 = Some(actual.blake3);
+    entry
+}
+
+/// Files under `base_dir` not listed in `known`, skipping Pumas sidecars,
+/// hidden files, and partial downloads.
+fn find_extra_files(base_dir: &Path, known: &HashSet<&str>) -> Vec<FileVerifyEntry> {
+    WalkDir::new(base_dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let name = relative_name(base_dir, entry.path())?;
+            let skip = name == METADATA_FILENAME
+                || name == OVERRIDES_FILENAME
+                || name.ends_with(NetworkConfig::DOWNLOAD_TEMP_SUFFIX)
+                || known.contains(name.as_str());
+            (!skip).then(|| FileVerifyEntry {
+                name,
+                status: FileVerifyStatus::Extra,
+                size: entry.metadata().ok().map(|metadata| metadata.len()),
+                sha256: None,
+                blake3: None,
+                message: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelHashes;
+    use tempfile::TempDir;
+
+    fn recorded(name: &str, size: Option<u64>, sha256: Option<String>) -> ModelFileInfo {
+        ModelFileInfo {
+            name: name.to_string(),
+            original_name: None,
+            size,
+            sha256,
+            blake3: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_model_reports_each_file_status() {
+        let temp_dir = TempDir::new().unwrap();
+        let library = ModelLibrary::new(temp_dir.path()).await.unwrap();
+        let model_dir = library.build_model_path("llm", "verify", "model");
+        std::fs::create_dir_all(model_dir.join("nested")).unwrap();
+        std::fs::write(model_dir.join("model.gguf"), b"weights").unwrap();
+        std::fs::write(model_dir.join("config.json"), b"{}").unwrap();
+        std::fs::write(model_dir.join("nested/vocab.txt"), b"tampered").unwrap();
+        std::fs::write(model_dir.join("notes.txt"), b"extra").unwrap();
+        std::fs::write(model_dir.join("model.gguf.part"), b"partial").unwrap();
+        std::fs::write(model_dir.join(".pumas_download"), b"").unwrap();
+
+        let primary = compute_dual_hash(model_dir.join("model.gguf")).unwrap();
+        let wrong_sha256 = compute_dual_hash(model_dir.join("config.json"))
+            .unwrap()
+            .sha256;
+        let metadata = ModelMetadata {
+            hashes: Some(ModelHashes {
+                sha256: Some(primary.sha256.clone()),
+                blake3: Some(primary.blake3.to_uppercase()),
+            }),
+            files: Some(vec![
+                recorded("model.gguf", Some(7), None),
+                recorded("config.json", Some(2), None),
+                recorded("nested/vocab.txt", Some(8), Some(wrong_sha256)),
+                recorded("tokenizer.json", None, None),
+            ]),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+
+        let result = library.verify_model("llm/verify/model").await.unwrap();
+
+        assert_eq!(result.model_id, "llm/verify/model");
+        assert!(!result.verified);
+        let statuses: Vec<_> = result
+            .files
+            .iter()
+            .map(|file| (file.name.as_str(), file.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("model.gguf", FileVerifyStatus::Ok),
+                ("config.json", FileVerifyStatus::Unverified),
+                ("nested/vocab.txt", FileVerifyStatus::Mismatch),
+                ("tokenizer.json", FileVerifyStatus::Missing),
+                ("notes.txt", FileVerifyStatus::Extra),
+            ]
+        );
+        assert_eq!(
+            result.files[0].sha256.as_deref(),
+            Some(primary.sha256.as_str())
+        );
+        assert!(result.files[2]
+            .message
+            .as_deref()
+            .unwrap()
+            .starts_with("SHA256"));
+        assert_eq!(result.files[4].size, Some(5));
+    }
+
+    #[tokio::test]
+    async fn verify_model_without_file_list_checks_primary_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let library = ModelLibrary::new(temp_dir.path()).await.unwrap();
+        let model_dir = library.build_model_path("llm", "verify", "legacy");
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("model.safetensors"), b"weights").unwrap();
+        std::fs::write(model_dir.join("README.md"), b"# notes").unwrap();
+        let hashes = compute_dual_hash(model_dir.join("model.safetensors")).unwrap();
+        let metadata = ModelMetadata {
+            hashes: Some(ModelHashes {
+                sha256: Some(hashes.sha256),
+                blake3: None,
+            }),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+
+        let result = library.verify_model("llm/verify/legacy").await.unwrap();
+        assert!(result.verified);
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].name, "model.safetensors");
+        assert_eq!(result.files[0].status, FileVerifyStatus::Ok);
+
+        let missing = library.verify_model("llm/verify/absent").await;
+        assert!(matches!(missing, Err(PumasError::ModelNotFound { .. })));
+    }
+}
@@ -4,6 +4,7 @@
 //! index rebuilds when files are added, modified, or removed.
 
 use crate::error::Result;
+use crate::model_library::scan_ignore::ScanIgnore;
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -44,10 +45,12 @@ impl ModelLibraryWatcher {
     ///
     /// * `library_root` - Root directory of the model library to watch
     /// * `debounce_duration` - How long to wait after changes before triggering callback
+    /// * `scan_ignore` - Ignore-list whose directories never trigger the callback
     /// * `on_change` - Callback invoked with deduplicated changed paths
     pub fn new(
         library_root: impl AsRef<Path>,
         debounce_duration: Duration,
+        scan_ignore: Arc<ScanIgnore>,
         on_change: ChangeCallback,
     ) -> Result<Self> {
         let library_root = library_root.as_ref().to_path_buf();
@@ -79,6 +82,7 @@ impl ModelLibraryWatcher {
         // Spawn a task to handle debounced events
         let on_change = Arc::new(on_change);
         let on_change_clone = Arc::clone(&on_change);
+        let watched_root = library_root.clone();
 
         std::thread::spawn(move || {
             let mut pending_paths = Vec::<PathBuf>::new();
@@ -95,7 +99,12 @@ impl ModelLibraryWatcher {
                 // Check for file events with timeout and flush accumulated
                 // paths only after the debounce window goes quiet.
                 match event_rx.recv_timeout(debounce_duration) {
-                    Ok(result) => handle_watcher_event(result, &mut pending_paths),
+                    Ok(result) => handle_watcher_event(
+                        result,
+                        &watched_root,
+                        &scan_ignore,
+                        &mut pending_paths,
+                    ),
                     Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
                         flush_relevant_paths(&on_change_clone, &mut pending_paths);
                     }
@@ -119,7 +128,12 @@ impl ModelLibraryWatcher {
     }
 }
 
-fn handle_watcher_event(result: notify::Result<Event>, pending_paths: &mut Vec<PathBuf>) {
+fn handle_watcher_event(
+    result: notify::Result<Event>,
+    library_root: &Path,
+    scan_ignore: &ScanIgnore,
+    pending_paths: &mut Vec<PathBuf>,
+) {
     match result {
         Ok(event) => {
             if !is_relevant_event_kind(&event.kind) {
@@ -130,7 +144,8 @@ fn handle_watcher_event(result: notify::Result<Event>, pending_paths: &mut Vec<P
                 event
                     .paths
                     .into_iter()
-                    .filter(|path| is_relevant_change(path)),
+                    .filter(|path| is_relevant_change(path))
+                    .filter(|path| !is_ignored_change(library_root, scan_ignore, path)),
             );
         }
         Err(error) => {
@@ -160,6 +175,22 @@ fn is_relevant_event_kind(kind: &EventKind) -> bool {
     )
 }
 
+/// Check if a change happened inside a directory on the scan ignore-list.
+fn is_ignored_change(library_root: &Path, scan_ignore: &ScanIgnore, path: &Path) -> bool {
+    // Directory events (including deleted directories, which arrive without an
+    // extension) are checked as-is; file events are checked by their parent.
+    let is_dir = path.is_dir() || (!path.exists() && path.extension().is_none());
+    let dir = if is_dir {
+        path
+    } else {
+        match path.parent() {
+            Some(parent) => parent,
+            None => return false,
+        }
+    };
+    scan_ignore.is_ignored_under(library_root, dir)
+}
+
 /// Check if a path change is relevant (model file or metadata).
 fn is_relevant_change(path: &Path) -> bool {
     // Check file extension
@@ -199,6 +230,28 @@ mod tests {
         assert!(!is_relevant_change(Path::new("/models/test.txt")));
     }
 
+    #[test]
+    fn test_changes_in_ignored_dirs_are_dropped() {
+        let root = Path::new("/models");
+        let ignore = ScanIgnore::default();
+
+        assert!(is_ignored_change(
+            root,
+            &ignore,
+            Path::new("/models/.staging/llm/model.gguf")
+        ));
+        assert!(is_ignored_change(
+            root,
+            &ignore,
+            Path::new("/models/.trash")
+        ));
+        assert!(!is_ignored_change(
+            root,
+            &ignore,
+            Path::new("/models/llm/family/model.gguf")
+        ));
+    }
+
     #[test]
     fn test_access_events_are_not_relevant() {
         use notify::event::{AccessKind, AccessMode};
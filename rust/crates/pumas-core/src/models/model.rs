@@ -263,6 +263,22 @@ pub enum ImportState {
     Failed,
 }
 
+/// Whether a model is complete and usable, as tracked through the
+/// download/import pipeline.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ModelReadiness {
+    /// Files are still being downloaded; only a preliminary record exists.
+    Downloading,
+    /// Files are on disk and the importer is finalizing the model.
+    Importing,
+    /// The model is complete and usable.
+    Ready,
+    /// Download finalization or import failed.
+    Error,
+}
+
 /// Current validation health of an external asset reference.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -394,6 +410,13 @@ pub struct ModelMetadata {
     pub size_bytes: Option<u64>,
     #[serde(default)]
     pub files: Option<Vec<ModelFileInfo>>,
+    // Import security gating
+    /// Security tier detected from the primary file at import time.
+    #[serde(default)]
+    pub security_tier: Option<SecurityTier>,
+    /// Set when the importer accepted a pickle-tier file on explicit acknowledgment.
+    #[serde(default)]
+    pub security_acknowledged: Option<bool>,
     // Metadata source tracking
     #[serde(default)]
     pub match_source: Option<String>,
@@ -449,6 +472,10 @@ pub struct ModelMetadata {
     /// Storage ownership classification for the model asset.
     #[serde(default)]
     pub storage_kind: Option<StorageKind>,
+    /// Model whose primary file this model's primary file shares storage
+    /// with (hardlink or reflink), set by a deduplicating import.
+    #[serde(default)]
+    pub shares_storage_with: Option<String>,
     /// Bundle layout format for executable directory-root assets.
     #[serde(default)]
     pub bundle_format: Option<BundleFormat>,
@@ -458,6 +485,10 @@ pub struct ModelMetadata {
     /// Current import lifecycle state for this model asset.
     #[serde(default)]
     pub import_state: Option<ImportState>,
+    /// Download/import pipeline state. `None` on metadata written before this
+    /// field existed; the index derives it from download completeness.
+    #[serde(default)]
+    pub readiness: Option<ModelReadiness>,
     /// Current health of the persisted asset reference.
     #[serde(default)]
     pub validation_state: Option<AssetValidationState>,
@@ -735,6 +766,17 @@ pub struct ModelDownloadProgress {
     pub retrying: Option<bool>,
     #[serde(default)]
     pub next_retry_delay_seconds: Option<f64>,
+    /// 1-based place in the download queue while `Queued` behind other
+    /// downloads.
+    #[serde(default)]
+    pub queue_position: Option<u32>,
+    /// Whether the download stopped because no bytes arrived within the
+    /// stall timeout.
+    #[serde(default)]
+    pub stalled: bool,
+    /// Repo files left out by the request's include/exclude patterns.
+    #[serde(default)]
+    pub skipped_files: Vec<String>,
     #[serde(default)]
     pub error: Option<String>,
 }
@@ -748,6 +790,35 @@ pub struct ModelDownloadSnapshot {
     pub downloads: Vec<ModelDownloadProgress>,
 }
 
+/// Download subsystem counters for diagnosing slow downloads.
+///
+/// Status counts and throughput describe the downloads tracked right now;
+/// the `*_this_session` counters cover the client's lifetime.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadMetrics {
+    /// Downloads transferring or pausing.
+    pub active: usize,
+    /// Downloads waiting for a slot in the download queue.
+    pub queued: usize,
+    pub paused: usize,
+    /// Downloads currently in `Error`.
+    pub failed: usize,
+    /// Combined speed of active downloads, in bytes/s.
+    pub throughput_bytes_per_sec: f64,
+    /// File attempts that were retried after a transient failure.
+    pub retries_this_session: u64,
+    /// Attempts stopped because no bytes arrived within the stall timeout.
+    pub stalls_this_session: u64,
+    pub bytes_downloaded_this_session: u64,
+    pub completed_this_session: u64,
+    pub failed_this_session: u64,
+    /// Concurrency limit of the download queue.
+    pub max_active_downloads: usize,
+    /// Client-wide speed cap, if any.
+    pub rate_limit_bytes_per_sec: Option<u64>,
+}
+
 /// Download-state notification delivered to local GUI clients.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -830,6 +901,22 @@ pub enum ImportStage {
     Complete,
 }
 
+/// How an import stores a primary file whose content is already in the library.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DedupStrategy {
+    /// Always copy; identical content is not looked up.
+    #[default]
+    Copy,
+    /// Hardlink to the existing file; both models then share one inode.
+    Hardlink,
+    /// Copy-on-write clone of the existing file, on filesystems that support it.
+    Reflink,
+    /// Reflink when supported, else hardlink, else copy.
+    Auto,
+}
+
 /// Model import specification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -848,6 +935,16 @@ pub struct ModelImportSpec {
     pub tags: Option<Vec<String>>,
     #[serde(default)]
     pub security_acknowledged: Option<bool>,
+    /// Link the source files into the library instead of copying them.
+    ///
+    /// The model is recorded as an external reference, so deleting it removes
+    /// only the library-side links and metadata.
+    #[serde(default)]
+    pub by_reference: Option<bool>,
+    /// Share storage with an identical (by SHA256) file already in the
+    /// library instead of copying it. Defaults to [`DedupStrategy::Copy`].
+    #[serde(default)]
+    pub dedup: Option<DedupStrategy>,
 }
 
 /// External diffusers-directory import specification.
@@ -882,6 +979,40 @@ pub struct ModelImportResult {
     pub security_tier: Option<SecurityTier>,
 }
 
+/// What importing a [`ModelImportSpec`] would do, from a dry run that writes nothing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ImportSpecValidation {
+    pub path: String,
+    /// True when nothing blocks the import.
+    pub valid: bool,
+    /// Library directory the model would be stored in.
+    #[serde(default)]
+    pub destination: Option<String>,
+    /// Directory name derived from `official_name`, after any disambiguation.
+    #[serde(default)]
+    pub cleaned_name: Option<String>,
+    #[serde(default)]
+    pub model_type: Option<String>,
+    #[serde(default)]
+    pub family: Option<String>,
+    /// File format detected from content (e.g. `safetensors`, `pickle`).
+    #[serde(default)]
+    pub file_format: Option<String>,
+    #[serde(default)]
+    pub security_tier: Option<SecurityTier>,
+    /// True when `destination` already holds this model.
+    #[serde(default)]
+    pub already_exists: bool,
+    /// Existing library directories already using the normalized name.
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+    /// Problems that would make the import fail.
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
 /// Classification of an import path before any persistence side effects occur.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -1087,6 +1218,9 @@ mod tests {
                     retry_limit: None,
                     retrying: None,
                     next_retry_delay_seconds: None,
+                    queue_position: None,
+                    stalled: false,
+                    skipped_files: Vec::new(),
                     error: None,
                 }],
             },
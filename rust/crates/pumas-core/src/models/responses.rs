@@ -349,6 +349,12 @@ pub struct CrossFilesystemWarningResponse {
     pub warning: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recommendation: Option<String>,
+    /// Link type mapping tries first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_link_type: Option<LinkType>,
+    /// Why the default link type cannot be used for this app, if it cannot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_type_warning: Option<String>,
 }
 
 /// Deep scan progress.
@@ -420,6 +426,21 @@ pub struct CleanBrokenLinksResponse {
     pub cleaned: usize,
 }
 
+/// Response for removing every model link created for an app.
+///
+/// Note: Not FFI-compatible due to `usize` fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RemoveAppLinksResponse {
+    pub success: bool,
+    /// Number of registered links removed from disk and the registry.
+    pub links_removed: usize,
+    /// Models deleted because no other app still links to them.
+    pub deleted_models: Vec<String>,
+    /// Links or models that could not be removed, with the reason.
+    pub errors: Vec<String>,
+}
+
 /// Link information for a model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -516,7 +537,14 @@ pub struct SyncModelsResponse {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Links created (same as `links_added`)
     pub synced: usize,
+    #[serde(default)]
+    pub links_added: usize,
+    #[serde(default)]
+    pub links_removed: usize,
+    #[serde(default)]
+    pub links_unchanged: usize,
     pub errors: Vec<String>,
 }
 
@@ -617,6 +645,114 @@ pub struct SandboxInfoResponse {
     pub limitations: Vec<String>,
 }
 
+/// A setup problem reported by environment diagnosis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct EnvironmentIssue {
+    /// Check that found the problem (e.g. `git`, `directories`, `write_access`).
+    pub check: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Result of diagnosing and repairing the launcher environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct EnvironmentRepairReport {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Issues that were repaired automatically.
+    pub fixed: Vec<EnvironmentIssue>,
+    /// Issues that need manual action from the user.
+    pub needs_action: Vec<EnvironmentIssue>,
+}
+
+impl EnvironmentRepairReport {
+    /// True when no issues remain that need manual action.
+    pub fn is_healthy(&self) -> bool {
+        self.needs_action.is_empty()
+    }
+}
+
+/// Outcome of compacting one SQLite database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct DatabaseMaintenanceResult {
+    /// Short database name, e.g. `models` or `registry`.
+    pub name: String,
+    pub path: String,
+    /// Combined size of the database and its WAL file before maintenance.
+    pub size_before: u64,
+    pub size_after: u64,
+    pub reclaimed_bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of running `VACUUM`, WAL checkpoint, and `ANALYZE` across databases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct DatabaseMaintenanceReport {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub databases: Vec<DatabaseMaintenanceResult>,
+    pub total_reclaimed_bytes: u64,
+}
+
+/// A subsystem check that could not run while building a health summary or
+/// diagnostic bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthCheckFailure {
+    /// Check that failed (e.g. `hf_auth`, `conversion_environment`, `disk_space`).
+    pub check: String,
+    pub error: String,
+}
+
+/// Health of every subsystem in one response, for an app-health panel.
+///
+/// Checks run independently; a failed check leaves its field at the default
+/// and is listed in `failed_checks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthSummaryResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Connectivity, request counters, and circuit breaker states.
+    pub network: NetworkStatusResponse,
+    pub hf_authenticated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hf_username: Option<String>,
+    pub conversion_environment_ready: bool,
+    /// Disk holding the launcher root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_space: Option<DiskSpaceResponse>,
+    /// Built-in apps with a running process (`comfyui`, `ollama`, `torch`).
+    pub running_apps: Vec<String>,
+    /// False while filesystem changes await reconciliation into the index.
+    pub index_consistent: bool,
+    /// Downloads queued, in progress, or paused.
+    pub pending_downloads: u32,
+    pub failed_checks: Vec<HealthCheckFailure>,
+}
+
+/// A diagnostic bundle written for a bug report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DiagnosticBundleResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Zip file that was written.
+    pub path: String,
+    /// Files inside the zip.
+    pub entries: Vec<String>,
+    /// Sections left out because they could not be collected.
+    pub failed_sections: Vec<HealthCheckFailure>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
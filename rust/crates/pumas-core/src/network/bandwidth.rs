@@ -0,0 +1,230 @@
+//! Shared download bandwidth budget.
+//!
+//! A token bucket that every active download stream draws from, so a limit is
+//! split across concurrent downloads rather than applied per stream. Streams
+//! borrow against the bucket before writing each chunk and wait off any debt,
+//! which interleaves concurrent streams at roughly equal rates. Each
+//! `PumasApi` owns one limiter and hands it to its download clients.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+/// How often a waiting stream re-checks its stop condition.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Token bucket shared by download streams.
+pub struct BandwidthLimiter {
+    state: Mutex<BucketState>,
+    /// Wakes waiting streams when the limit changes.
+    changed: Notify,
+}
+
+impl std::fmt::Debug for BandwidthLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BandwidthLimiter")
+            .field("limit", &self.limit())
+            .finish()
+    }
+}
+
+struct BucketState {
+    /// Bytes per second, or `None` for unlimited.
+    limit: Option<u64>,
+    /// Available bytes; negative while streams owe the bucket.
+    tokens: f64,
+    last_refill: Instant,
+    /// Bumped on every limit change so waiters drop debt owed under the old one.
+    generation: u64,
+}
+
+impl BandwidthLimiter {
+    /// Create a limiter. `None` or `Some(0)` means unlimited.
+    pub fn new(bytes_per_sec: Option<u64>) -> Self {
+        let limit = bytes_per_sec.filter(|limit| *limit > 0);
+        Self {
+            state: Mutex::new(BucketState {
+                limit,
+                tokens: limit.unwrap_or(0) as f64,
+                last_refill: Instant::now(),
+                generation: 0,
+            }),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Change the limit. `None` or `Some(0)` removes it.
+    ///
+    /// Streams waiting under the old limit stop waiting immediately.
+    pub fn set_limit(&self, bytes_per_sec: Option<u64>) {
+        let limit = bytes_per_sec.filter(|limit| *limit > 0);
+        {
+            let mut state = self.lock_state();
+            state.limit = limit;
+            // Allow up to one second of burst under the new limit.
+            state.tokens = limit.unwrap_or(0) as f64;
+            state.last_refill = Instant::now();
+            state.generation += 1;
+        }
+        self.changed.notify_waiters();
+    }
+
+    /// Current limit in bytes per second, if any.
+    pub fn limit(&self) -> Option<u64> {
+        self.lock_state().limit
+    }
+
+    /// Take `bytes` from the budget, waiting as long as the limit requires.
+    ///
+    /// Returns `false` without finishing the wait once `stopped` reports
+    /// true, so a cancelled or paused download is not held by its debt.
+    pub async fn acquire(&self, bytes: u64, stopped: impl Fn() -> bool) -> bool {
+        let (deadline, generation) = {
+            let mut state = self.lock_state();
+            let Some(limit) = state.limit else {
+                return true;
+            };
+            let limit = limit as f64;
+            let now = Instant::now();
+            let refill = now.duration_since(state.last_refill).as_secs_f64() * limit;
+            state.tokens = (state.tokens + refill).min(limit) - bytes as f64;
+            state.last_refill = now;
+            if state.tokens >= 0.0 {
+                return true;
+            }
+            (
+                now + Duration::from_secs_f64(-state.tokens / limit),
+                state.generation,
+            )
+        };
+
+        loop {
+            let changed = self.changed.notified();
+            if stopped() {
+                return false;
+            }
+            if self.lock_state().generation != generation {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline.min(now + STOP_POLL_INTERVAL)) => {}
+                _ = changed => {}
+            }
+        }
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, BucketState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_once_burst_is_spent() {
+        let limiter = BandwidthLimiter::new(Some(1000));
+        let start = Instant::now();
+
+        limiter.acquire(1000, || false).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire(500, || false).await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(499), "{:?}", elapsed);
+        assert!(elapsed <= Duration::from_millis(510), "{:?}", elapsed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_streams_share_the_budget() {
+        let limiter = Arc::new(BandwidthLimiter::new(Some(1000)));
+        let start = Instant::now();
+
+        let streams: Vec<_> = (0..2)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move {
+                    for _ in 0..4 {
+                        limiter.acquire(500, || false).await;
+                    }
+                })
+            })
+            .collect();
+        for stream in streams {
+            stream.await.unwrap();
+        }
+
+        // 4000 bytes at 1000 B/s with a 1000-byte burst.
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(2990), "{:?}", elapsed);
+        assert!(elapsed <= Duration::from_millis(3100), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn unlimited_and_zero_limits_do_not_wait() {
+        let limiter = BandwidthLimiter::new(Some(0));
+        assert_eq!(limiter.limit(), None);
+        assert!(limiter.acquire(u64::MAX, || false).await);
+
+        limiter.set_limit(Some(2048));
+        assert_eq!(limiter.limit(), Some(2048));
+        limiter.set_limit(None);
+        assert!(limiter.acquire(u64::MAX, || false).await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stopping_ends_the_wait_early() {
+        let limiter = Arc::new(BandwidthLimiter::new(Some(1000)));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let start = Instant::now();
+
+        let waiter = {
+            let limiter = limiter.clone();
+            let cancelled = cancelled.clone();
+            tokio::spawn(async move {
+                limiter
+                    .acquire(60_000, || cancelled.load(Ordering::Relaxed))
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        cancelled.store(true, Ordering::Relaxed);
+
+        assert!(!waiter.await.unwrap());
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn raising_the_limit_releases_waiting_streams() {
+        let limiter = Arc::new(BandwidthLimiter::new(Some(1000)));
+        let start = Instant::now();
+
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire(60_000, || false).await })
+        };
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        limiter.set_limit(None);
+
+        assert!(waiter.await.unwrap());
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+}
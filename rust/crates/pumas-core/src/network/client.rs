@@ -4,7 +4,7 @@
 //! - Rate limit tracking from response headers
 //! - Automatic throttling when approaching limits
 //! - Configurable timeouts
-//! - User-agent management
+//! - User-agent management (via [`super::HttpSettings::client_builder`])
 
 use crate::config::NetworkConfig;
 use crate::{PumasError, Result};
@@ -77,9 +77,14 @@ impl HttpClient {
 
     /// Create a new HTTP client with a custom default timeout.
     pub fn with_timeout(timeout: Duration) -> Result<Self> {
-        let client = Client::builder()
+        Self::with_http_settings(&super::HttpSettings::default(), timeout)
+    }
+
+    /// Create a new HTTP client that uses the given proxy, CA, and user-agent settings.
+    pub fn with_http_settings(http: &super::HttpSettings, timeout: Duration) -> Result<Self> {
+        let client = http
+            .client_builder()
             .timeout(timeout)
-            .user_agent("Pumas-Library/1.0")
             .build()
             .map_err(|e| PumasError::Network {
                 message: format!("Failed to create HTTP client: {}", e),
@@ -4,21 +4,26 @@
 //! - Download with progress callbacks
 //! - Cancellation support
 //! - Retry logic for transient failures
+//! - Resume via HTTP Range when the connection drops mid-stream
+//! - Optional multi-connection downloads of large files split into byte ranges
+//! - Throughput capped by the shared [`super::BandwidthLimiter`] budget
 //! - Atomic file operations (temp file → final)
 
 use crate::config::NetworkConfig;
+use crate::network::bandwidth::BandwidthLimiter;
 use crate::network::client::HttpClient;
 use crate::network::retry::{retry_async, RetryConfig};
 use crate::{PumasError, Result};
+use futures::future::try_join_all;
 use futures::StreamExt;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 use tokio::sync::mpsc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Progress information for a download.
 #[derive(Debug, Clone)]
@@ -76,12 +81,27 @@ pub struct DownloadManager {
     progress_interval: Duration,
     /// Temp file suffix.
     temp_suffix: String,
+    /// Consecutive resume attempts allowed after a dropped stream.
+    max_resume_attempts: u32,
+    /// Delay before reissuing a dropped download.
+    resume_delay: Duration,
+    /// Concurrent range connections per file (1 = single stream).
+    parallel_connections: usize,
+    /// Smallest file size that is split across connections.
+    parallel_min_size: u64,
+    /// Budget shared with the owning instance's other downloads.
+    bandwidth_limiter: Arc<BandwidthLimiter>,
 }
 
 impl DownloadManager {
     /// Create a new download manager.
     pub fn new() -> Result<Self> {
-        let http = HttpClient::new()?;
+        Self::with_http_settings(&super::HttpSettings::default())
+    }
+
+    /// Create a download manager that uses the given outbound HTTP settings.
+    pub fn with_http_settings(http: &super::HttpSettings) -> Result<Self> {
+        let http = HttpClient::with_http_settings(http, NetworkConfig::REQUEST_TIMEOUT)?;
         Ok(Self {
             http: Arc::new(http),
             cancelled: AtomicBool::new(false),
@@ -89,6 +109,11 @@ impl DownloadManager {
             last_error_retryable: AtomicBool::new(false),
             progress_interval: NetworkConfig::DOWNLOAD_PROGRESS_INTERVAL,
             temp_suffix: NetworkConfig::DOWNLOAD_TEMP_SUFFIX.to_string(),
+            max_resume_attempts: NetworkConfig::DOWNLOAD_MAX_RESUME_ATTEMPTS,
+            resume_delay: NetworkConfig::DOWNLOAD_RESUME_DELAY,
+            parallel_connections: 1,
+            parallel_min_size: NetworkConfig::DOWNLOAD_PARALLEL_MIN_SIZE,
+            bandwidth_limiter: Arc::new(BandwidthLimiter::new(None)),
         })
     }
 
@@ -101,9 +126,55 @@ impl DownloadManager {
             last_error_retryable: AtomicBool::new(false),
             progress_interval: NetworkConfig::DOWNLOAD_PROGRESS_INTERVAL,
             temp_suffix: NetworkConfig::DOWNLOAD_TEMP_SUFFIX.to_string(),
+            max_resume_attempts: NetworkConfig::DOWNLOAD_MAX_RESUME_ATTEMPTS,
+            resume_delay: NetworkConfig::DOWNLOAD_RESUME_DELAY,
+            parallel_connections: 1,
+            parallel_min_size: NetworkConfig::DOWNLOAD_PARALLEL_MIN_SIZE,
+            bandwidth_limiter: Arc::new(BandwidthLimiter::new(None)),
         }
     }
 
+    /// Draw download throughput from a shared bandwidth budget.
+    ///
+    /// Pass `PumasApi::bandwidth_limiter` so this manager's downloads count
+    /// against the instance-wide cap.
+    ///
+    /// Default: unlimited
+    pub fn with_bandwidth_limiter(mut self, limiter: Arc<BandwidthLimiter>) -> Self {
+        self.bandwidth_limiter = limiter;
+        self
+    }
+
+    /// Set how many consecutive Range resumes are attempted after the stream
+    /// drops. The count resets whenever an attempt makes progress. `0`
+    /// disables resuming.
+    pub fn with_max_resume_attempts(mut self, attempts: u32) -> Self {
+        self.max_resume_attempts = attempts;
+        self
+    }
+
+    /// Set the delay before a dropped download is reissued.
+    pub fn with_resume_delay(mut self, delay: Duration) -> Self {
+        self.resume_delay = delay;
+        self
+    }
+
+    /// Fetch large files over `connections` concurrent Range requests.
+    ///
+    /// Only used when a HEAD probe reports `Accept-Ranges: bytes` and a size
+    /// of at least the parallel minimum; otherwise the file is downloaded over
+    /// a single stream. Default: `1` (single stream).
+    pub fn with_parallel_connections(mut self, connections: usize) -> Self {
+        self.parallel_connections = connections.max(1);
+        self
+    }
+
+    /// Set the smallest file size split across parallel connections.
+    pub fn with_parallel_min_size(mut self, bytes: u64) -> Self {
+        self.parallel_min_size = bytes;
+        self
+    }
+
     /// Request cancellation of the current download.
     pub fn cancel(&self) {
         self.cancelled.store(true, Ordering::SeqCst);
@@ -169,7 +240,13 @@ impl DownloadManager {
         let temp_path = PathBuf::from(format!("{}{}", destination.display(), self.temp_suffix));
 
         // Perform download to temp file
-        let result = self.do_download(url, &temp_path, progress_tx.clone()).await;
+        let result = match self.parallel_download_size(url).await {
+            Some(total_bytes) => {
+                self.do_parallel_download(url, &temp_path, total_bytes, progress_tx.clone())
+                    .await
+            }
+            None => self.do_download(url, &temp_path, progress_tx.clone()).await,
+        };
 
         match result {
             Ok(bytes) => {
@@ -235,13 +312,92 @@ impl DownloadManager {
         self.last_error_retryable.store(retryable, Ordering::SeqCst);
     }
 
+    /// Download into `temp_path`, resuming with `Range: bytes=<offset>-` when
+    /// the connection drops mid-stream.
     async fn do_download(
         &self,
         url: &str,
         temp_path: &Path,
         progress_tx: Option<mpsc::Sender<DownloadProgress>>,
     ) -> Result<u64> {
-        let response = self.http.get(url).await?;
+        let mut transfer = Transfer {
+            bytes_downloaded: 0,
+            total_bytes: None,
+            speed_tracker: SpeedTracker::new(),
+            last_progress_update: Instant::now(),
+        };
+        let mut resume_attempts = 0;
+
+        loop {
+            let offset_before = transfer.bytes_downloaded;
+            match self
+                .stream_to_file(url, temp_path, &mut transfer, progress_tx.as_ref())
+                .await
+            {
+                Ok(()) => break,
+                Err(err @ PumasError::Network { .. }) => {
+                    if transfer.bytes_downloaded > offset_before {
+                        resume_attempts = 0;
+                    }
+                    if resume_attempts >= self.max_resume_attempts || self.was_cancelled() {
+                        return Err(err);
+                    }
+                    resume_attempts += 1;
+                    warn!(
+                        "Download of {} interrupted at byte {} ({}); resuming (attempt {}/{})",
+                        url,
+                        transfer.bytes_downloaded,
+                        err,
+                        resume_attempts,
+                        self.max_resume_attempts
+                    );
+                    tokio::time::sleep(self.resume_delay).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        // Send final progress
+        if let Some(ref tx) = progress_tx {
+            let _ = tx.send(transfer.progress()).await;
+        }
+
+        Ok(transfer.bytes_downloaded)
+    }
+
+    /// Issue one request starting at `transfer.bytes_downloaded` and append the
+    /// body to `temp_path`.
+    async fn stream_to_file(
+        &self,
+        url: &str,
+        temp_path: &Path,
+        transfer: &mut Transfer,
+        progress_tx: Option<&mpsc::Sender<DownloadProgress>>,
+    ) -> Result<()> {
+        let response = loop {
+            let offset = transfer.bytes_downloaded;
+            let response = if offset > 0 {
+                let range = vec![("Range".to_string(), format!("bytes={}-", offset))];
+                self.http.get_with_headers(url, &range).await?
+            } else {
+                self.http.get(url).await?
+            };
+            if offset == 0 || response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+                break response;
+            }
+            match check_content_range(&response, offset, None, transfer.total_bytes) {
+                Ok(()) => break response,
+                Err(reason) => {
+                    warn!(
+                        "Discarding partial download of {} ({}), restarting from zero",
+                        url, reason
+                    );
+                    transfer.bytes_downloaded = 0;
+                    transfer.total_bytes = None;
+                }
+            }
+        };
+        let offset = transfer.bytes_downloaded;
         let status = response.status();
 
         if !status.is_success() {
@@ -255,23 +411,36 @@ impl DownloadManager {
             });
         }
 
-        let total_bytes = response.content_length();
-        let mut file = fs::File::create(temp_path)
-            .await
-            .map_err(|e| PumasError::Io {
-                message: format!("Failed to create temp file: {}", e),
-                path: Some(temp_path.to_path_buf()),
-                source: Some(e),
-            })?;
+        let resuming = offset > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+        if offset > 0 && !resuming {
+            warn!(
+                "Server ignored Range request for {}, restarting from zero",
+                url
+            );
+            transfer.bytes_downloaded = 0;
+        }
+        let start = transfer.bytes_downloaded;
+        transfer.total_bytes = response
+            .content_length()
+            .map(|len| start + len)
+            .or(transfer.total_bytes);
+
+        let open_result = if resuming {
+            fs::OpenOptions::new().append(true).open(temp_path).await
+        } else {
+            fs::File::create(temp_path).await
+        };
+        let mut file = open_result.map_err(|e| PumasError::Io {
+            message: format!("Failed to open temp file: {}", e),
+            path: Some(temp_path.to_path_buf()),
+            source: Some(e),
+        })?;
 
-        let mut bytes_downloaded: u64 = 0;
-        let mut last_progress_update = Instant::now();
-        let mut speed_tracker = SpeedTracker::new();
         let mut stream = response.bytes_stream();
 
         // Send initial progress
-        if let Some(ref tx) = progress_tx {
-            let _ = tx.send(DownloadProgress::new(0, total_bytes, 0.0)).await;
+        if let Some(tx) = progress_tx {
+            let _ = tx.send(transfer.progress()).await;
         }
 
         while let Some(chunk_result) = stream.next().await {
@@ -280,32 +449,42 @@ impl DownloadManager {
                 return Err(PumasError::DownloadCancelled);
             }
 
-            let chunk = chunk_result.map_err(|e| {
-                let message = format!("Error reading download stream: {}", e);
-                self.set_error(message.clone(), true);
-                PumasError::Network {
-                    message,
-                    cause: Some(e.to_string()),
+            let chunk = match chunk_result {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    // Keep what was received so the next attempt can resume.
+                    let _ = file.flush().await;
+                    let message = format!("Error reading download stream: {}", e);
+                    self.set_error(message.clone(), true);
+                    return Err(PumasError::Network {
+                        message,
+                        cause: Some(e.to_string()),
+                    });
                 }
-            })?;
+            };
 
+            if !self
+                .bandwidth_limiter
+                .acquire(chunk.len() as u64, || self.cancelled.load(Ordering::SeqCst))
+                .await
+            {
+                return Err(PumasError::DownloadCancelled);
+            }
             file.write_all(&chunk).await.map_err(|e| PumasError::Io {
                 message: format!("Failed to write to temp file: {}", e),
                 path: Some(temp_path.to_path_buf()),
                 source: Some(e),
             })?;
 
-            bytes_downloaded += chunk.len() as u64;
-            speed_tracker.record(chunk.len() as u64);
+            transfer.bytes_downloaded += chunk.len() as u64;
+            transfer.speed_tracker.record(chunk.len() as u64);
 
             // Send progress updates at intervals
-            if last_progress_update.elapsed() >= self.progress_interval {
-                if let Some(ref tx) = progress_tx {
-                    let speed = speed_tracker.speed();
-                    let progress = DownloadProgress::new(bytes_downloaded, total_bytes, speed);
-                    let _ = tx.send(progress).await;
+            if transfer.last_progress_update.elapsed() >= self.progress_interval {
+                if let Some(tx) = progress_tx {
+                    let _ = tx.send(transfer.progress()).await;
                 }
-                last_progress_update = Instant::now();
+                transfer.last_progress_update = Instant::now();
             }
         }
 
@@ -316,14 +495,342 @@ impl DownloadManager {
             source: Some(e),
         })?;
 
-        // Send final progress
+        if let Some(total) = transfer.total_bytes {
+            if transfer.bytes_downloaded < total {
+                let message = format!(
+                    "Download stream ended early ({} of {} bytes)",
+                    transfer.bytes_downloaded, total
+                );
+                self.set_error(message.clone(), true);
+                return Err(PumasError::Network {
+                    message,
+                    cause: None,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Size to split across connections, if parallel download applies to `url`.
+    async fn parallel_download_size(&self, url: &str) -> Option<u64> {
+        if self.parallel_connections <= 1 {
+            return None;
+        }
+        let response = match self.http.head(url).await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                debug!(
+                    "HEAD {} returned {}; using single stream",
+                    url,
+                    response.status()
+                );
+                return None;
+            }
+            Err(err) => {
+                debug!("HEAD {} failed ({}); using single stream", url, err);
+                return None;
+            }
+        };
+        let headers = response.headers();
+        let accepts_ranges = headers
+            .get(reqwest::header::ACCEPT_RANGES)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.eq_ignore_ascii_case("bytes"));
+        // Read the header directly: HEAD responses have no body to size.
+        let total_bytes = headers
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())?;
+
+        (accepts_ranges && total_bytes >= self.parallel_min_size.max(1)).then_some(total_bytes)
+    }
+
+    /// Download `total_bytes` as concurrent byte ranges written in place.
+    async fn do_parallel_download(
+        &self,
+        url: &str,
+        temp_path: &Path,
+        total_bytes: u64,
+        progress_tx: Option<mpsc::Sender<DownloadProgress>>,
+    ) -> Result<u64> {
+        let file = fs::File::create(temp_path)
+            .await
+            .map_err(|e| PumasError::Io {
+                message: format!("Failed to create temp file: {}", e),
+                path: Some(temp_path.to_path_buf()),
+                source: Some(e),
+            })?;
+        file.set_len(total_bytes)
+            .await
+            .map_err(|e| PumasError::Io {
+                message: format!("Failed to preallocate temp file: {}", e),
+                path: Some(temp_path.to_path_buf()),
+                source: Some(e),
+            })?;
+        drop(file);
+
+        let ranges = split_ranges(total_bytes, self.parallel_connections);
+        info!(
+            "Downloading {} bytes from {} over {} connections",
+            total_bytes,
+            url,
+            ranges.len()
+        );
+
+        let downloaded = AtomicU64::new(0);
+        let started = Instant::now();
+        let progress = |bytes: u64| {
+            let elapsed = started.elapsed().as_secs_f64();
+            let speed = if elapsed > 0.0 {
+                bytes as f64 / elapsed
+            } else {
+                0.0
+            };
+            DownloadProgress::new(bytes, Some(total_bytes), speed)
+        };
+
         if let Some(ref tx) = progress_tx {
-            let speed = speed_tracker.speed();
-            let progress = DownloadProgress::new(bytes_downloaded, total_bytes, speed);
-            let _ = tx.send(progress).await;
+            let _ = tx.send(progress(0)).await;
         }
 
-        Ok(bytes_downloaded)
+        let chunks = try_join_all(
+            ranges
+                .iter()
+                .map(|&(start, end)| self.download_range(url, temp_path, start, end, &downloaded)),
+        );
+        tokio::pin!(chunks);
+        let mut ticker = tokio::time::interval(self.progress_interval);
+        let result = loop {
+            tokio::select! {
+                result = &mut chunks => break result,
+                _ = ticker.tick() => {
+                    if let Some(ref tx) = progress_tx {
+                        let _ = tx.send(progress(downloaded.load(Ordering::Relaxed))).await;
+                    }
+                }
+            }
+        };
+        result?;
+
+        if let Some(ref tx) = progress_tx {
+            let _ = tx.send(progress(total_bytes)).await;
+        }
+
+        Ok(total_bytes)
+    }
+
+    /// Fetch the inclusive byte range `start..=end` into its slot of
+    /// `temp_path`, resuming within the range when the stream drops.
+    async fn download_range(
+        &self,
+        url: &str,
+        temp_path: &Path,
+        start: u64,
+        end: u64,
+        downloaded: &AtomicU64,
+    ) -> Result<()> {
+        let mut position = start;
+        let mut resume_attempts = 0;
+
+        loop {
+            let position_before = position;
+            match self
+                .stream_range(url, temp_path, &mut position, end, downloaded)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(err @ PumasError::Network { .. }) => {
+                    if position > position_before {
+                        resume_attempts = 0;
+                    }
+                    if resume_attempts >= self.max_resume_attempts || self.was_cancelled() {
+                        return Err(err);
+                    }
+                    resume_attempts += 1;
+                    warn!(
+                        "Range {}-{} of {} interrupted at byte {} ({}); resuming (attempt {}/{})",
+                        start, end, url, position, err, resume_attempts, self.max_resume_attempts
+                    );
+                    tokio::time::sleep(self.resume_delay).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn stream_range(
+        &self,
+        url: &str,
+        temp_path: &Path,
+        position: &mut u64,
+        end: u64,
+        downloaded: &AtomicU64,
+    ) -> Result<()> {
+        let range = vec![("Range".to_string(), format!("bytes={}-{}", position, end))];
+        let response = self.http.get_with_headers(url, &range).await?;
+        let status = response.status();
+
+        if status != reqwest::StatusCode::PARTIAL_CONTENT {
+            let retryable = HttpClient::is_retryable_status(status);
+            let message = format!("Range request failed with status {}", status);
+            self.set_error(message.clone(), retryable);
+            return Err(PumasError::DownloadFailed {
+                url: url.to_string(),
+                message,
+            });
+        }
+        if let Err(reason) = check_content_range(&response, *position, Some(end), None) {
+            let message = format!("Range request for {}-{} failed: {}", position, end, reason);
+            self.set_error(message.clone(), false);
+            return Err(PumasError::DownloadFailed {
+                url: url.to_string(),
+                message,
+            });
+        }
+
+        let io_error = |e: std::io::Error| PumasError::Io {
+            message: format!("Failed to write to temp file: {}", e),
+            path: Some(temp_path.to_path_buf()),
+            source: Some(e),
+        };
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .open(temp_path)
+            .await
+            .map_err(io_error)?;
+        file.seek(std::io::SeekFrom::Start(*position))
+            .await
+            .map_err(io_error)?;
+
+        let mut stream = response.bytes_stream();
+        while let Some(chunk_result) = stream.next().await {
+            if self.cancelled.load(Ordering::SeqCst) {
+                return Err(PumasError::DownloadCancelled);
+            }
+
+            let chunk = match chunk_result {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    let _ = file.flush().await;
+                    let message = format!("Error reading download stream: {}", e);
+                    self.set_error(message.clone(), true);
+                    return Err(PumasError::Network {
+                        message,
+                        cause: Some(e.to_string()),
+                    });
+                }
+            };
+
+            // Never write past the end of this range, even if the server
+            // sends more than asked for.
+            let remaining = (end + 1).saturating_sub(*position) as usize;
+            let chunk = &chunk[..chunk.len().min(remaining)];
+            if !self
+                .bandwidth_limiter
+                .acquire(chunk.len() as u64, || self.cancelled.load(Ordering::SeqCst))
+                .await
+            {
+                return Err(PumasError::DownloadCancelled);
+            }
+            file.write_all(chunk).await.map_err(io_error)?;
+            *position += chunk.len() as u64;
+            downloaded.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+        }
+
+        file.flush().await.map_err(io_error)?;
+
+        if *position <= end {
+            let message = format!(
+                "Range stream ended early at byte {} (expected through {})",
+                position, end
+            );
+            self.set_error(message.clone(), true);
+            return Err(PumasError::Network {
+                message,
+                cause: None,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a `Content-Range: bytes <start>-<end>/<total>` header.
+///
+/// The total is `None` when the server reports it as `*`.
+pub(crate) fn parse_content_range(value: &str) -> Option<(u64, u64, Option<u64>)> {
+    let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+    let (start, end) = range.split_once('-')?;
+    let total = match total.trim() {
+        "*" => None,
+        total => Some(total.parse().ok()?),
+    };
+    Some((start.trim().parse().ok()?, end.trim().parse().ok()?, total))
+}
+
+/// Check that a `206 Partial Content` response starts at byte `start` and,
+/// when they are known, ends at `expected_end` and reports `expected_total`
+/// as the file size.
+fn check_content_range(
+    response: &reqwest::Response,
+    start: u64,
+    expected_end: Option<u64>,
+    expected_total: Option<u64>,
+) -> std::result::Result<(), String> {
+    let content_range = response
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .ok_or("206 response without Content-Range")?;
+    let (range_start, range_end, total) = parse_content_range(content_range)
+        .ok_or_else(|| format!("unparseable Content-Range '{}'", content_range))?;
+    if range_start != start
+        || range_end < range_start
+        || expected_end.is_some_and(|end| end != range_end)
+    {
+        return Err(format!(
+            "server sent range {}-{} for a request from byte {}",
+            range_start, range_end, start
+        ));
+    }
+    if let (Some(total), Some(expected)) = (total, expected_total) {
+        if total != expected {
+            return Err(format!(
+                "remote size changed from {} to {} bytes",
+                expected, total
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Split `total_bytes` into at most `parts` contiguous inclusive ranges.
+fn split_ranges(total_bytes: u64, parts: usize) -> Vec<(u64, u64)> {
+    let parts = (parts.max(1) as u64).min(total_bytes.max(1));
+    let chunk = total_bytes.div_ceil(parts);
+    (0..parts)
+        .map(|index| index * chunk)
+        .take_while(|&start| start < total_bytes)
+        .map(|start| (start, (start + chunk).min(total_bytes) - 1))
+        .collect()
+}
+
+/// Progress carried across resumed requests for one download.
+struct Transfer {
+    bytes_downloaded: u64,
+    total_bytes: Option<u64>,
+    speed_tracker: SpeedTracker,
+    last_progress_update: Instant,
+}
+
+impl Transfer {
+    fn progress(&self) -> DownloadProgress {
+        DownloadProgress::new(
+            self.bytes_downloaded,
+            self.total_bytes,
+            self.speed_tracker.speed(),
+        )
     }
 }
 
@@ -412,6 +919,313 @@ mod tests {
         assert!(manager.last_error().is_none());
     }
 
+    /// Serve `body` over raw HTTP, dropping the first connection after
+    /// `cut_at` bytes and honoring `Range` on later requests. With
+    /// `misreport_resume`, the resume is answered with the whole file as a
+    /// 206 and a third, plain request is served. Returns the `Range` header
+    /// seen on each request.
+    async fn spawn_flaky_server(
+        body: &'static [u8],
+        cut_at: usize,
+        misreport_resume: bool,
+    ) -> (String, tokio::task::JoinHandle<Vec<Option<String>>>) {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.bin", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut ranges = Vec::new();
+            let request_count = if misreport_resume { 3 } else { 2 };
+            for request_index in 0..request_count {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request).to_lowercase();
+                let range = request
+                    .lines()
+                    .find_map(|line| line.strip_prefix("range: bytes="))
+                    .map(|value| value.trim().to_string());
+                ranges.push(range.clone());
+
+                if request_index == 0 {
+                    let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                    socket.write_all(head.as_bytes()).await.unwrap();
+                    socket.write_all(&body[..cut_at]).await.unwrap();
+                    // Dropping the socket truncates the body mid-stream.
+                } else if range.is_none() {
+                    let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                    socket.write_all(head.as_bytes()).await.unwrap();
+                    socket.write_all(body).await.unwrap();
+                } else {
+                    let start: usize = if misreport_resume {
+                        0
+                    } else {
+                        range
+                            .as_deref()
+                            .and_then(|r| r.trim_end_matches('-').parse().ok())
+                            .unwrap_or(0)
+                    };
+                    let head = format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\n\r\n",
+                        body.len() - start,
+                        start,
+                        body.len() - 1,
+                        body.len()
+                    );
+                    socket.write_all(head.as_bytes()).await.unwrap();
+                    socket.write_all(&body[start..]).await.unwrap();
+                }
+            }
+            ranges
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn download_resumes_with_range_after_dropped_connection() {
+        let body: &'static [u8] = b"0123456789abcdef";
+        let (url, server) = spawn_flaky_server(body, 6, false).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new()
+            .unwrap()
+            .with_resume_delay(Duration::from_millis(10));
+        let (tx, mut rx) = mpsc::channel(64);
+
+        let bytes = manager
+            .download(&url, &destination, Some(tx))
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, body.len() as u64);
+        assert_eq!(std::fs::read(&destination).unwrap(), body);
+        assert_eq!(server.await.unwrap(), vec![None, Some("6-".to_string())]);
+
+        let mut last = None;
+        while let Ok(progress) = rx.try_recv() {
+            last = Some(progress);
+        }
+        let last = last.unwrap();
+        assert_eq!(last.bytes_downloaded, body.len() as u64);
+        assert_eq!(last.total_bytes, Some(body.len() as u64));
+    }
+
+    #[tokio::test]
+    async fn download_restarts_when_resume_returns_another_range() {
+        let body: &'static [u8] = b"0123456789abcdef";
+        let (url, server) = spawn_flaky_server(body, 6, true).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new()
+            .unwrap()
+            .with_resume_delay(Duration::from_millis(10));
+
+        let bytes = manager.download(&url, &destination, None).await.unwrap();
+
+        assert_eq!(bytes, body.len() as u64);
+        assert_eq!(std::fs::read(&destination).unwrap(), body);
+        assert_eq!(
+            server.await.unwrap(),
+            vec![None, Some("6-".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn parse_content_range_reads_start_end_and_total() {
+        assert_eq!(
+            parse_content_range("bytes 10-31/32"),
+            Some((10, 31, Some(32)))
+        );
+        assert_eq!(parse_content_range("bytes 10-31/*"), Some((10, 31, None)));
+        assert_eq!(parse_content_range("items 10-31/32"), None);
+    }
+
+    #[tokio::test]
+    async fn download_without_resume_attempts_fails_on_dropped_connection() {
+        let (url, _server) = spawn_flaky_server(b"0123456789abcdef", 6, false).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new().unwrap().with_max_resume_attempts(0);
+
+        let error = manager
+            .download(&url, &destination, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, PumasError::Network { .. }));
+        assert!(manager.was_last_error_retryable());
+        assert!(!destination.exists());
+    }
+
+    /// Serve `body` over raw HTTP until the test ends, answering HEAD and
+    /// Range GETs. With `misreport_ranges`, each range after the first is
+    /// served from one byte earlier than asked. Records `(method, range)` for
+    /// every request.
+    async fn spawn_range_server(
+        body: &'static [u8],
+        accept_ranges: bool,
+        misreport_ranges: bool,
+    ) -> (String, Arc<std::sync::Mutex<Vec<(String, Option<String>)>>>) {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.bin", listener.local_addr().unwrap());
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let log = log.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = socket.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    let request = String::from_utf8_lossy(&request).to_lowercase();
+                    let method = request.split(' ').next().unwrap_or("").to_string();
+                    let range = request
+                        .lines()
+                        .find_map(|line| line.strip_prefix("range: bytes="))
+                        .map(|value| value.trim().to_string());
+                    log.lock().unwrap().push((method.clone(), range.clone()));
+
+                    let accept = if accept_ranges {
+                        "Accept-Ranges: bytes\r\n"
+                    } else {
+                        ""
+                    };
+                    let (status, slice, extra) = match range.filter(|_| accept_ranges) {
+                        Some(range) => {
+                            let (start, end) = range.split_once('-').unwrap();
+                            let mut start: usize = start.parse().unwrap();
+                            if misreport_ranges {
+                                start = start.saturating_sub(1);
+                            }
+                            let end: usize = end.parse().unwrap_or(body.len() - 1);
+                            (
+                                "206 Partial Content",
+                                &body[start..=end],
+                                format!(
+                                    "Content-Range: bytes {}-{}/{}\r\n",
+                                    start,
+                                    end,
+                                    body.len()
+                                ),
+                            )
+                        }
+                        None => ("200 OK", body, String::new()),
+                    };
+                    let head = format!(
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\n{}{}Connection: close\r\n\r\n",
+                        status,
+                        slice.len(),
+                        accept,
+                        extra
+                    );
+                    socket.write_all(head.as_bytes()).await.unwrap();
+                    if method != "head" {
+                        socket.write_all(slice).await.unwrap();
+                    }
+                });
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn split_ranges_covers_the_file_without_overlap() {
+        assert_eq!(split_ranges(10, 3), vec![(0, 3), (4, 7), (8, 9)]);
+        assert_eq!(split_ranges(2, 4), vec![(0, 0), (1, 1)]);
+        assert_eq!(split_ranges(5, 1), vec![(0, 4)]);
+    }
+
+    #[tokio::test]
+    async fn parallel_download_fetches_ranges_and_reassembles_file() {
+        let body: &'static [u8] = b"the quick brown fox jumps over the lazy dog 0123456789";
+        let (url, requests) = spawn_range_server(body, true, false).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new()
+            .unwrap()
+            .with_parallel_connections(4)
+            .with_parallel_min_size(1);
+        let (tx, mut rx) = mpsc::channel(64);
+
+        let bytes = manager
+            .download(&url, &destination, Some(tx))
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, body.len() as u64);
+        assert_eq!(std::fs::read(&destination).unwrap(), body);
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests[0].0, "head");
+        let ranged_gets = requests
+            .iter()
+            .filter(|(method, range)| method == "get" && range.is_some())
+            .count();
+        assert_eq!(ranged_gets, 4);
+
+        let mut last = None;
+        while let Ok(progress) = rx.try_recv() {
+            last = Some(progress);
+        }
+        assert_eq!(last.unwrap().percent, Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn parallel_download_rejects_ranges_the_server_did_not_ask_for() {
+        let body: &'static [u8] = b"the quick brown fox jumps over the lazy dog 0123456789";
+        let (url, _requests) = spawn_range_server(body, true, true).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new()
+            .unwrap()
+            .with_parallel_connections(4)
+            .with_parallel_min_size(1);
+
+        let error = manager
+            .download(&url, &destination, None)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, PumasError::DownloadFailed { .. }),
+            "{error}"
+        );
+        assert!(!destination.exists());
+    }
+
+    #[tokio::test]
+    async fn parallel_download_falls_back_to_single_stream_without_range_support() {
+        let body: &'static [u8] = b"no ranges here";
+        let (url, requests) = spawn_range_server(body, false, false).await;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let destination = temp_dir.path().join("model.bin");
+        let manager = DownloadManager::new()
+            .unwrap()
+            .with_parallel_connections(4)
+            .with_parallel_min_size(1);
+
+        manager.download(&url, &destination, None).await.unwrap();
+
+        assert_eq!(std::fs::read(&destination).unwrap(), body);
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![("head".to_string(), None), ("get".to_string(), None)]
+        );
+    }
+
     #[tokio::test]
     async fn test_download_manager_cancel() {
         let manager = DownloadManager::new().unwrap();
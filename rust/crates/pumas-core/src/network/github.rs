@@ -7,13 +7,14 @@
 //! - Rate limit handling
 
 use super::web_source::{CacheStrategy, WebSource, WebSourceId};
+use crate::clock::{system_clock, SharedClock};
 use crate::config::{AppId, NetworkConfig};
 use crate::models::{CacheStatus, GitHubReleasesCache};
 use crate::network::client::HttpClient;
 use crate::network::retry::{retry_async, RetryConfig};
 use crate::{PumasError, Result};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::DateTime;
 use mini_moka::sync::Cache;
 use reqwest::StatusCode;
 use std::collections::HashMap;
@@ -36,6 +37,8 @@ pub struct ReleasesCache {
     cache_dir: PathBuf,
     /// Default TTL for cache entries.
     default_ttl: Duration,
+    /// Time source for disk cache freshness.
+    clock: SharedClock,
 }
 
 impl ReleasesCache {
@@ -45,9 +48,18 @@ impl ReleasesCache {
             memory_cache: Cache::builder().time_to_live(ttl).max_capacity(10).build(),
             cache_dir,
             default_ttl: ttl,
+            clock: system_clock(),
         }
     }
 
+    /// Use `clock` instead of system time for disk cache timestamps and TTLs.
+    ///
+    /// The in-memory tier keeps its own real-time TTL.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Get releases from memory cache.
     pub fn get_memory(&self, key: &str) -> Option<Vec<GitHubRelease>> {
         self.memory_cache.get(&key.to_string())
@@ -92,7 +104,7 @@ impl ReleasesCache {
         }
 
         let cache = GitHubReleasesCache {
-            last_fetched: Utc::now().to_rfc3339(),
+            last_fetched: self.clock.now().to_rfc3339(),
             ttl: self.default_ttl.as_secs(),
             releases: releases.to_vec(),
         };
@@ -141,7 +153,7 @@ impl ReleasesCache {
         }
 
         let cache = GitHubReleasesCache {
-            last_fetched: Utc::now().to_rfc3339(),
+            last_fetched: self.clock.now().to_rfc3339(),
             ttl: self.default_ttl.as_secs(),
             releases: releases.to_vec(),
         };
@@ -161,7 +173,7 @@ impl ReleasesCache {
     /// Check if disk cache is valid (not expired).
     pub fn is_disk_cache_valid(&self, cache: &GitHubReleasesCache) -> bool {
         if let Ok(last_fetched) = DateTime::parse_from_rfc3339(&cache.last_fetched) {
-            let age = Utc::now().signed_duration_since(last_fetched);
+            let age = self.clock.now().signed_duration_since(last_fetched);
             age.num_seconds() < cache.ttl as i64
         } else {
             false
@@ -179,7 +191,7 @@ impl ReleasesCache {
 
         let (age_seconds, last_fetched, releases_count) = if let Some(cache) = disk_cache {
             let age = DateTime::parse_from_rfc3339(&cache.last_fetched)
-                .map(|t| Utc::now().signed_duration_since(t).num_seconds() as u64)
+                .map(|t| self.clock.now().signed_duration_since(t).num_seconds() as u64)
                 .ok();
             (
                 age,
@@ -252,7 +264,12 @@ impl LlamaCppReleaseVariant {
 impl GitHubClient {
     /// Create a new GitHub client.
     pub fn new(cache_dir: PathBuf) -> Result<Self> {
-        let http = HttpClient::new()?;
+        Self::with_http_settings(cache_dir, &super::HttpSettings::default())
+    }
+
+    /// Create a new GitHub client that uses the given outbound HTTP settings.
+    pub fn with_http_settings(cache_dir: PathBuf, http: &super::HttpSettings) -> Result<Self> {
+        let http = HttpClient::with_http_settings(http, NetworkConfig::REQUEST_TIMEOUT)?;
         Ok(Self {
             http: Arc::new(http),
             cache: ReleasesCache::new(cache_dir, NetworkConfig::GITHUB_RELEASES_TTL),
@@ -844,6 +861,22 @@ mod tests {
         assert!(cache.is_disk_cache_valid(&cached));
     }
 
+    #[test]
+    fn test_releases_disk_cache_expires_with_clock() {
+        let temp_dir = TempDir::new().unwrap();
+        let clock = crate::clock::ManualClock::default();
+        let cache = ReleasesCache::new(temp_dir.path().to_path_buf(), Duration::from_secs(3600))
+            .with_clock(Arc::new(clock.clone()));
+
+        cache.set_disk("test/repo", &[]).unwrap();
+        let cached = cache.get_disk("test/repo").unwrap();
+        clock.advance(Duration::from_secs(3599));
+        assert!(cache.is_disk_cache_valid(&cached));
+
+        clock.advance(Duration::from_secs(1));
+        assert!(!cache.is_disk_cache_valid(&cached));
+    }
+
     #[test]
     fn test_releases_cache_memory() {
         let temp_dir = TempDir::new().unwrap();
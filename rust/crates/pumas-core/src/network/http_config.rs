@@ -0,0 +1,275 @@
+//! Outbound HTTP settings.
+//!
+//! Every reqwest client in pumas (HuggingFace, GitHub, downloads, Ollama, and
+//! connectivity probes) is created through [`HttpSettings::client_builder`],
+//! which applies the effective proxy, any extra trusted root certificates,
+//! and the pumas user-agent (optionally extended by the host app). Each
+//! `PumasApi` resolves its own [`HttpSettings`] from the builder and hands
+//! them to the clients it creates, so two instances in one process never
+//! share or overwrite each other's settings. The proxy comes from
+//! [`crate::PumasApiBuilder::with_proxy`], falling back to the standard
+//! `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY` environment variables. Extra
+//! roots come from [`crate::PumasApiBuilder::with_ca_bundle`] and are trusted
+//! in addition to the system store. Code outside an instance uses
+//! [`client_builder`], which applies the defaults.
+//!
+//! Loopback hosts always bypass the proxy so local runtimes (Ollama, Torch,
+//! llama.cpp) stay reachable.
+
+use crate::{PumasError, Result};
+use std::path::Path;
+use tracing::warn;
+
+/// Hosts that never go through the proxy.
+const LOOPBACK_NO_PROXY: &str = "localhost,127.0.0.1,::1";
+
+/// Proxy environment variables, in precedence order.
+const PROXY_ENV_VARS: [&str; 6] = [
+    "HTTPS_PROXY",
+    "https_proxy",
+    "HTTP_PROXY",
+    "http_proxy",
+    "ALL_PROXY",
+    "all_proxy",
+];
+
+/// User-agent sent by every client; host apps may append their own product.
+pub const BASE_USER_AGENT: &str = "pumas-library/1.0";
+
+/// Proxy used for outbound HTTP requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// Proxy URL, e.g. `http://proxy.corp:3128`.
+    pub url: String,
+    /// Comma-separated hosts that bypass the proxy (same format as `NO_PROXY`).
+    pub no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Create a proxy config for all schemes.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            no_proxy: None,
+        }
+    }
+
+    /// Set hosts that bypass the proxy.
+    pub fn with_no_proxy(mut self, no_proxy: impl Into<String>) -> Self {
+        self.no_proxy = Some(no_proxy.into());
+        self
+    }
+
+    /// Read the proxy from `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY` and
+    /// `NO_PROXY`, if set.
+    pub fn from_env() -> Option<Self> {
+        let url = PROXY_ENV_VARS
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty())?;
+        let no_proxy = ["NO_PROXY", "no_proxy"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty());
+        Some(Self {
+            url: url.trim().to_string(),
+            no_proxy,
+        })
+    }
+
+    /// Check that the proxy URL is usable.
+    pub fn validate(&self) -> Result<()> {
+        self.to_reqwest().map(|_| ())
+    }
+
+    fn to_reqwest(&self) -> Result<reqwest::Proxy> {
+        let proxy = reqwest::Proxy::all(&self.url).map_err(|e| PumasError::Config {
+            message: format!("Invalid proxy URL '{}': {}", self.url, e),
+        })?;
+        let no_proxy = match self.no_proxy.as_deref() {
+            Some(hosts) if !hosts.trim().is_empty() => {
+                format!("{},{}", hosts.trim(), LOOPBACK_NO_PROXY)
+            }
+            _ => LOOPBACK_NO_PROXY.to_string(),
+        };
+        Ok(proxy.no_proxy(reqwest::NoProxy::from_string(&no_proxy)))
+    }
+}
+
+/// Outbound HTTP settings for the clients of one `PumasApi` instance.
+///
+/// The default uses the environment proxy and the base user-agent.
+#[derive(Debug, Clone, Default)]
+pub struct HttpSettings {
+    proxy: Option<ProxyConfig>,
+    root_certificates: Vec<reqwest::Certificate>,
+    user_agent_suffix: Option<String>,
+}
+
+impl HttpSettings {
+    /// Route requests through `proxy` instead of the environment proxy.
+    ///
+    /// Fails without changing anything if the proxy URL is invalid.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Result<Self> {
+        proxy.validate()?;
+        self.proxy = Some(proxy);
+        Ok(self)
+    }
+
+    /// Trust these root certificates in addition to the system store.
+    pub fn with_root_certificates(mut self, certificates: Vec<reqwest::Certificate>) -> Self {
+        self.root_certificates = certificates;
+        self
+    }
+
+    /// Extra root certificates trusted by new clients.
+    pub fn root_certificates(&self) -> &[reqwest::Certificate] {
+        &self.root_certificates
+    }
+
+    /// Append a product token such as `MyApp/2.3` to [`BASE_USER_AGENT`].
+    ///
+    /// Blank values leave the base user-agent unchanged. Fails without
+    /// changing anything if the value cannot be sent as an HTTP header.
+    pub fn with_user_agent_suffix(mut self, suffix: &str) -> Result<Self> {
+        let suffix = suffix.trim();
+        if suffix.is_empty() {
+            return Ok(self);
+        }
+        reqwest::header::HeaderValue::from_str(suffix).map_err(|_| PumasError::Config {
+            message: format!("Invalid user-agent '{}'", suffix),
+        })?;
+        self.user_agent_suffix = Some(suffix.to_string());
+        Ok(self)
+    }
+
+    /// User-agent for new clients, e.g. `pumas-library/1.0 MyApp/2.3`.
+    pub fn user_agent(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {}", BASE_USER_AGENT, suffix),
+            None => BASE_USER_AGENT.to_string(),
+        }
+    }
+
+    /// Proxy that new clients will use: the explicit one, else the environment.
+    pub fn effective_proxy(&self) -> Option<ProxyConfig> {
+        self.proxy.clone().or_else(ProxyConfig::from_env)
+    }
+
+    /// Start a reqwest client builder with these settings applied.
+    ///
+    /// The user-agent is preset; callers only add timeouts and other
+    /// per-client options.
+    pub fn client_builder(&self) -> reqwest::ClientBuilder {
+        let mut builder = reqwest::Client::builder().user_agent(self.user_agent());
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        match self.effective_proxy() {
+            Some(proxy) => match proxy.to_reqwest() {
+                Ok(proxy) => builder.proxy(proxy),
+                Err(err) => {
+                    warn!("Ignoring proxy configuration: {}", err);
+                    builder
+                }
+            },
+            None => builder,
+        }
+    }
+}
+
+/// Parse a PEM bundle of root certificates.
+///
+/// `source` is only used in error messages.
+pub fn parse_ca_bundle(pem: &[u8], source: &Path) -> Result<Vec<reqwest::Certificate>> {
+    let certificates =
+        reqwest::Certificate::from_pem_bundle(pem).map_err(|e| PumasError::Config {
+            message: format!("Invalid CA bundle {}: {}", source.display(), e),
+        })?;
+    if certificates.is_empty() {
+        return Err(PumasError::Config {
+            message: format!("CA bundle {} contains no certificates", source.display()),
+        });
+    }
+    Ok(certificates)
+}
+
+/// Start a reqwest client builder with the default [`HttpSettings`].
+pub fn client_builder() -> reqwest::ClientBuilder {
+    HttpSettings::default().client_builder()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_malformed_proxy_url() {
+        assert!(ProxyConfig::new("http://proxy.corp:3128")
+            .validate()
+            .is_ok());
+        assert!(ProxyConfig::new("not a url").validate().is_err());
+    }
+
+    const TEST_ROOT_CA: &str = "-----BEGIN CERTIFICATE-----
+MIIBkjCCATegAwIBAgIUJe0SCVo8TiPBwYarRO18jdFIDnQwCgYIKoZIzj0EAwIw
+HTEbMBkGA1UEAwwSUHVtYXMgVGVzdCBSb290IENBMCAXDTI2MTAxNDA3MTc1NVoY
+DzIxMjYwOTIwMDcxNzU1WjAdMRswGQYDVQQDDBJQdW1hcyBUZXN0IFJvb3QgQ0Ew
+WTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAASoVxRPHi1ulpsqs5GFgB/tG25ulRCB
+zF2Dj+z5RwGZ0IqjSoXNloKrd3ZcER6moq8qKZYfuhzJgKU6PmD57V9wo1MwUTAd
+BgNVHQ4EFgQUQ8Ft37HIMfIKN9ArP9PWfY7+wNEwHwYDVR0jBBgwFoAUQ8Ft37HI
+MfIKN9ArP9PWfY7+wNEwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNJADBG
+AiEA0NYRIrj+WpJ6jSlXr5fhWqLanPAntEFzCj1ea0xR59ECIQDSqj5SdpsyQ1Pw
+zq/vv/+A0qXJlb/97yLx+CuI7S8ZPg==
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn parse_ca_bundle_accepts_pem_and_rejects_empty_input() {
+        let source = Path::new("corp-ca.pem");
+
+        let certificates = parse_ca_bundle(TEST_ROOT_CA.as_bytes(), source).unwrap();
+        assert_eq!(certificates.len(), 1);
+
+        let error = parse_ca_bundle(b"", source).unwrap_err();
+        assert!(error.to_string().contains("contains no certificates"));
+
+        let settings = HttpSettings::default().with_root_certificates(certificates);
+        assert_eq!(settings.root_certificates().len(), 1);
+        assert!(settings.client_builder().build().is_ok());
+        assert!(HttpSettings::default().root_certificates().is_empty());
+    }
+
+    #[test]
+    fn user_agent_appends_host_product_to_base() {
+        let settings = HttpSettings::default()
+            .with_user_agent_suffix("MyApp/2.3")
+            .unwrap();
+        assert_eq!(settings.user_agent(), "pumas-library/1.0 MyApp/2.3");
+
+        assert!(settings
+            .clone()
+            .with_user_agent_suffix("bad\nagent")
+            .is_err());
+        assert_eq!(
+            HttpSettings::default()
+                .with_user_agent_suffix("  ")
+                .unwrap()
+                .user_agent(),
+            BASE_USER_AGENT
+        );
+        assert_eq!(HttpSettings::default().user_agent(), BASE_USER_AGENT);
+    }
+
+    #[test]
+    fn explicit_proxy_takes_precedence_and_builds_clients() {
+        let proxy = ProxyConfig::new("http://proxy.corp:3128").with_no_proxy("internal.corp");
+        let settings = HttpSettings::default().with_proxy(proxy.clone()).unwrap();
+
+        assert_eq!(settings.effective_proxy(), Some(proxy));
+        assert!(settings.client_builder().build().is_ok());
+        assert!(HttpSettings::default()
+            .with_proxy(ProxyConfig::new("not a url"))
+            .is_err());
+    }
+}
@@ -102,8 +102,10 @@ impl AtomicConnectivityState {
 /// - WebSource registration for extensible source management
 /// - Request execution with automatic cache fallback when offline
 pub struct NetworkManager {
-    /// Shared HTTP client for connectivity probes.
+    /// Shared HTTP client for source requests.
     http_client: Arc<HttpClient>,
+    /// Client for connectivity probes, built from the same HTTP settings.
+    probe_client: reqwest::Client,
     /// Current connectivity state.
     connectivity_state: AtomicConnectivityState,
     /// Last successful connectivity check time.
@@ -130,10 +132,30 @@ impl NetworkManager {
 
     /// Create with custom configuration.
     pub fn with_config(config: ConnectivityConfig) -> Result<Self> {
-        let http_client = Arc::new(HttpClient::new()?);
+        Self::with_http_settings(config, &super::HttpSettings::default())
+    }
+
+    /// Create with custom configuration and outbound HTTP settings.
+    pub fn with_http_settings(
+        config: ConnectivityConfig,
+        http: &super::HttpSettings,
+    ) -> Result<Self> {
+        let http_client = Arc::new(HttpClient::with_http_settings(
+            http,
+            NetworkConfig::REQUEST_TIMEOUT,
+        )?);
+        let probe_client = http
+            .client_builder()
+            .timeout(config.probe_timeout)
+            .build()
+            .map_err(|e| PumasError::Network {
+                message: format!("Failed to create probe client: {}", e),
+                cause: None,
+            })?;
 
         Ok(Self {
             http_client,
+            probe_client,
             connectivity_state: AtomicConnectivityState::new(ConnectivityState::Unknown),
             last_connectivity_check: RwLock::new(None),
             last_offline_time: RwLock::new(None),
@@ -233,15 +255,7 @@ impl NetworkManager {
 
     /// Probe a URL to check connectivity (HEAD request with short timeout).
     async fn probe_url(&self, url: &str) -> Result<bool> {
-        let client = reqwest::Client::builder()
-            .timeout(self.config.probe_timeout)
-            .build()
-            .map_err(|e| PumasError::Network {
-                message: format!("Failed to create probe client: {}", e),
-                cause: None,
-            })?;
-
-        match client.head(url).send().await {
+        match self.probe_client.head(url).send().await {
             Ok(resp) => {
                 let status = resp.status();
                 // Accept success, redirects, and even some client errors (like 403 for rate limiting)
@@ -586,6 +600,43 @@ mod tests {
         assert!(!manager.is_offline());
     }
 
+    #[tokio::test]
+    async fn test_connectivity_probe_uses_configured_proxy() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let proxy = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_url = format!("http://{}", proxy.local_addr().unwrap());
+        let proxied = tokio::spawn(async move {
+            let (mut stream, _) = proxy.accept().await.unwrap();
+            let mut request = vec![0u8; 1024];
+            let read = stream.read(&mut request).await.unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&request[..read]).into_owned()
+        });
+
+        let config = ConnectivityConfig {
+            probe_urls: vec!["http://probe.invalid/".to_string()],
+            ..ConnectivityConfig::default()
+        };
+        let http = crate::network::HttpSettings::default()
+            .with_proxy(crate::network::ProxyConfig::new(proxy_url))
+            .unwrap();
+        let manager = NetworkManager::with_http_settings(config, &http).unwrap();
+
+        assert_eq!(
+            manager.check_connectivity().await,
+            ConnectivityState::Online
+        );
+        let request = proxied.await.unwrap();
+        assert!(
+            request.starts_with("HEAD http://probe.invalid/"),
+            "{request}"
+        );
+    }
+
     #[tokio::test]
     async fn test_circuit_breaker_integration() {
         let manager = NetworkManager::new().unwrap();
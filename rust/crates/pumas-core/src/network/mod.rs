@@ -4,25 +4,34 @@
 //! - Retry logic with exponential backoff and jitter
 //! - Circuit breaker pattern for network resilience
 //! - HTTP client with rate limiting awareness
+//! - Per-instance proxy, CA, and user-agent settings for outbound clients
 //! - GitHub API integration
 //! - Download manager with progress tracking
+//! - Bandwidth budget shared by an instance's active downloads
 //! - NetworkManager for centralized connectivity management
 //! - WebSource traits for extensible web source registration
 
+mod bandwidth;
 mod circuit_breaker;
 mod client;
 mod download;
 mod github;
+mod http_config;
 mod manager;
 mod retry;
 mod web_source;
 
+pub use bandwidth::BandwidthLimiter;
 pub use circuit_breaker::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerStats, CircuitState,
 };
 pub use client::{HttpClient, RateLimitState};
+pub(crate) use download::parse_content_range;
 pub use download::{DownloadManager, DownloadProgress};
 pub use github::{GitHubAsset, GitHubClient, GitHubRelease, ReleasesCache};
+pub use http_config::{
+    client_builder, parse_ca_bundle, HttpSettings, ProxyConfig, BASE_USER_AGENT,
+};
 pub use manager::{ConnectivityConfig, ConnectivityState, NetworkManager, NetworkStatus};
 pub use retry::{retry_async, RetryConfig, RetryStats};
 pub use web_source::{CacheStrategy, DynWebSource, WebSource, WebSourceId};
@@ -8,7 +8,7 @@
 //!
 //! Each submodule handles a specific cross-platform concern:
 //! - `paths` - Platform-specific directory and file paths
-//! - `permissions` - File permission handling (executable bits, etc.)
+//! - `permissions` - File permission handling (executable bits, symlink support, etc.)
 //! - `process` - Process management (signals, termination)
 //!
 //! # Supported Platforms
@@ -23,12 +23,13 @@ pub mod process;
 
 // Re-export commonly used items
 pub use paths::{
-    apps_dir, desktop_dir, platform_display_path, pumas_config_dir, registry_db_path, venv_python,
+    apps_dir, desktop_dir, extended_length_path, hardlink_count, is_cross_filesystem,
+    platform_display_path, pumas_config_dir, reflink_file, registry_db_path, venv_python,
 };
-pub use permissions::set_executable;
+pub use permissions::{can_create_symlinks, set_executable};
 pub use process::{
-    configure_detached_command, find_processes_by_cmdline, is_process_alive, terminate_process,
-    terminate_process_tree,
+    configure_detached_command, find_processes_by_cmdline, is_process_alive, set_command_title,
+    supports_command_title, terminate_process, terminate_process_tree,
 };
 
 /// Returns the current platform name.
@@ -235,6 +235,257 @@ fn expand_windows_long_path(path: &Path) -> Option<PathBuf> {
     Some(PathBuf::from(OsString::from_wide(&buffer)))
 }
 
+/// Path usable by file operations beyond Windows' 260-character `MAX_PATH`.
+///
+/// Deep library trees (`{type}/{family}/{name}/filename` below the library
+/// root) can exceed the limit, so library and importer file operations pass
+/// their paths through here.
+///
+/// # Platform Behavior
+/// - **Windows**: Absolute paths get the `\\?\` extended-length prefix
+///   (`\\?\UNC\` for network shares). Windows passes prefixed paths through
+///   verbatim, so `/` separators and `.`/`..` components are resolved first.
+///   Relative paths are returned unchanged.
+/// - **Linux/macOS**: Returns the path unchanged
+pub fn extended_length_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        windows_extended_length_path(path)
+    }
+
+    #[cfg(not(windows))]
+    {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(windows)]
+fn windows_extended_length_path(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let Some(raw) = path.to_str() else {
+        return path.to_path_buf();
+    };
+    if let Some(rest) = raw.strip_prefix(r"\\?\") {
+        return PathBuf::from(format!(r"\\?\{}", rest.replace('/', r"\")));
+    }
+    if !path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    let normalized = normalized.display().to_string();
+    match normalized.strip_prefix(r"\\") {
+        Some(unc) => PathBuf::from(format!(r"\\?\UNC\{}", unc)),
+        None => PathBuf::from(format!(r"\\?\{}", normalized)),
+    }
+}
+
+/// Check whether two paths are on different filesystems (volumes).
+///
+/// Hardlinks cannot cross filesystems, so link-type decisions depend on this.
+/// Paths that do not exist yet are resolved through their nearest existing
+/// parent directory, since that is where they would be created.
+///
+/// # Platform Behavior
+/// - **Linux/macOS**: Compares `st_dev` device IDs
+/// - **Windows**: Compares volume GUIDs (`\\?\Volume{...}\`), falling back to
+///   the volume mount point (drive letter or UNC share) when a volume has no GUID
+pub fn is_cross_filesystem(a: &Path, b: &Path) -> std::io::Result<bool> {
+    Ok(filesystem_id(a)? != filesystem_id(b)?)
+}
+
+fn nearest_existing_path(path: &Path) -> std::io::Result<PathBuf> {
+    let absolute = std::path::absolute(path)?;
+    absolute
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Cannot determine filesystem for {}", path.display()),
+            )
+        })
+}
+
+#[cfg(unix)]
+fn filesystem_id(path: &Path) -> std::io::Result<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    Ok(std::fs::metadata(nearest_existing_path(path)?)?.dev())
+}
+
+#[cfg(windows)]
+fn filesystem_id(path: &Path) -> std::io::Result<String> {
+    let volume_path = windows_volume_path(&nearest_existing_path(path)?)?;
+    Ok(windows_volume_guid(&volume_path).unwrap_or_else(|| volume_path.to_lowercase()))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn filesystem_id(path: &Path) -> std::io::Result<PathBuf> {
+    let existing = nearest_existing_path(path)?;
+    Ok(existing.components().take(1).collect())
+}
+
+/// Number of hardlinks to the file at `path` (1 when the file is not shared).
+///
+/// Symlinks are not followed, so a symlink reports its own count.
+///
+/// # Platform Behavior
+/// - **Linux/macOS**: `st_nlink`
+/// - **Windows**: `nNumberOfLinks` from `GetFileInformationByHandle`
+pub fn hardlink_count(path: &Path) -> std::io::Result<u64> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        Ok(std::fs::symlink_metadata(path)?.nlink())
+    }
+
+    #[cfg(windows)]
+    {
+        windows_hardlink_count(path)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        std::fs::symlink_metadata(path).map(|_| 1)
+    }
+}
+
+/// Create `dest` as a copy-on-write clone of `source`.
+///
+/// The clone shares data blocks with `source` until either file is written,
+/// so it costs no extra space. Fails with `Unsupported` when the filesystem
+/// cannot clone (for example ext4, or `source` and `dest` on different
+/// filesystems); `dest` is not left behind on failure.
+///
+/// # Platform Behavior
+/// - **Linux**: `FICLONE` ioctl (Btrfs, XFS, bcachefs)
+/// - **Other platforms**: not implemented, always `Unsupported`
+pub fn reflink_file(source: &Path, dest: &Path) -> std::io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        linux_reflink_file(source, dest)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (source, dest);
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "reflinks are not supported on this platform",
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn linux_reflink_file(source: &Path, dest: &Path) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let reader = std::fs::File::open(source)?;
+    let writer = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(dest)?;
+    // SAFETY: both descriptors are owned by open files that outlive the call,
+    // and FICLONE only reads the source descriptor passed by value.
+    let result = unsafe { libc::ioctl(writer.as_raw_fd(), libc::FICLONE, reader.as_raw_fd()) };
+    if result == -1 {
+        let err = std::io::Error::last_os_error();
+        drop(writer);
+        let _ = std::fs::remove_file(dest);
+        return Err(match err.raw_os_error() {
+            Some(libc::EOPNOTSUPP) | Some(libc::EXDEV) | Some(libc::EINVAL) => {
+                std::io::Error::new(std::io::ErrorKind::Unsupported, err)
+            }
+            _ => err,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn windows_hardlink_count(path: &Path) -> std::io::Result<u64> {
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{
+        GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS,
+        FILE_FLAG_OPEN_REPARSE_POINT,
+    };
+
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(FILE_FLAG_BACKUP_SEMANTICS | FILE_FLAG_OPEN_REPARSE_POINT)
+        .open(path)?;
+    // SAFETY: zeroed memory is a valid `BY_HANDLE_FILE_INFORMATION` (plain
+    // integers and FILETIMEs), and Windows fills it in on success.
+    let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+    // SAFETY: the handle is owned by `file`, which outlives the call, and
+    // `info` is a valid output buffer.
+    let ok = unsafe { GetFileInformationByHandle(file.as_raw_handle() as _, &mut info) };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(u64::from(info.nNumberOfLinks))
+}
+
+/// Mount point of the volume holding `path` (for example `C:\`).
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn windows_volume_path(path: &Path) -> std::io::Result<String> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetVolumePathNameW;
+
+    let input: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    // The mount point is never longer than the path it contains.
+    let mut buffer = vec![0u16; input.len().max(261)];
+    // SAFETY: `input` is null-terminated, `buffer` holds `buffer.len()` u16s,
+    // and both stay alive for the duration of the call.
+    let ok =
+        unsafe { GetVolumePathNameW(input.as_ptr(), buffer.as_mut_ptr(), buffer.len() as u32) };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Ok(String::from_utf16_lossy(&buffer[..len]))
+}
+
+/// Volume GUID path (`\\?\Volume{...}\`) for a mount point, if it has one.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn windows_volume_guid(volume_path: &str) -> Option<String> {
+    use windows_sys::Win32::Storage::FileSystem::GetVolumeNameForVolumeMountPointW;
+
+    let input: Vec<u16> = volume_path.encode_utf16().chain(Some(0)).collect();
+    // Volume GUID paths are 49 characters plus the trailing null.
+    let mut buffer = [0u16; 64];
+    // SAFETY: `input` is null-terminated, `buffer` holds `buffer.len()` u16s,
+    // and both stay alive for the duration of the call.
+    let ok = unsafe {
+        GetVolumeNameForVolumeMountPointW(input.as_ptr(), buffer.as_mut_ptr(), buffer.len() as u32)
+    };
+    if ok == 0 {
+        return None;
+    }
+
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Some(String::from_utf16_lossy(&buffer[..len]))
+}
+
 /// Check if a command exists in the system PATH.
 ///
 /// # Platform Behavior
@@ -346,6 +597,99 @@ mod tests {
         assert_eq!(ext, "ps1");
     }
 
+    #[test]
+    fn test_extended_length_path_handles_paths_beyond_max_path() {
+        let deep = Path::new("/library")
+            .join("a".repeat(100))
+            .join("b".repeat(100))
+            .join("c".repeat(100))
+            .join("model.safetensors");
+        assert!(deep.as_os_str().len() > 260);
+
+        #[cfg(not(windows))]
+        assert_eq!(extended_length_path(&deep), deep);
+
+        #[cfg(windows)]
+        {
+            let deep = Path::new(r"C:\").join(deep.strip_prefix("/").unwrap());
+            let extended = extended_length_path(&deep);
+            assert!(extended.to_str().unwrap().starts_with(r"\\?\C:\library\"));
+            assert!(!extended.to_str().unwrap().contains('/'));
+        }
+    }
+
+    #[test]
+    fn test_is_cross_filesystem_same_filesystem() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let library = temp_dir.path().join("library");
+        std::fs::create_dir_all(&library).unwrap();
+        // Not created yet: resolved through the temp dir
+        let app_models = temp_dir.path().join("app").join("models");
+
+        assert!(!is_cross_filesystem(&library, &app_models).unwrap());
+        assert!(!is_cross_filesystem(&library, &library).unwrap());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_is_cross_filesystem_different_filesystem() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        // procfs is always its own filesystem
+        assert!(is_cross_filesystem(temp_dir.path(), Path::new("/proc/self")).unwrap());
+    }
+
+    #[test]
+    fn test_hardlink_count() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("model.gguf");
+        std::fs::write(&file, b"weights").unwrap();
+        assert_eq!(hardlink_count(&file).unwrap(), 1);
+
+        std::fs::hard_link(&file, temp_dir.path().join("linked.gguf")).unwrap();
+        assert_eq!(hardlink_count(&file).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_reflink_file_clones_or_reports_unsupported() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("model.gguf");
+        let dest = temp_dir.path().join("clone.gguf");
+        std::fs::write(&source, b"weights").unwrap();
+
+        match reflink_file(&source, &dest) {
+            Ok(()) => assert_eq!(std::fs::read(&dest).unwrap(), b"weights"),
+            Err(err) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+                assert!(!dest.exists());
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_is_cross_filesystem_same_drive() {
+        let windows_dir = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".into());
+        let windows_dir = Path::new(&windows_dir);
+        assert!(!is_cross_filesystem(windows_dir, &windows_dir.join("System32")).unwrap());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_extended_length_path_normalizes_windows_forms() {
+        for (input, expected) in [
+            (r"C:\lib/llm/model", r"\\?\C:\lib\llm\model"),
+            (r"C:\lib\a\..\.\b", r"\\?\C:\lib\b"),
+            (r"\\server\share\models", r"\\?\UNC\server\share\models"),
+            (r"\\?\C:\lib/llm/model", r"\\?\C:\lib\llm\model"),
+            ("relative/model", "relative/model"),
+        ] {
+            assert_eq!(
+                extended_length_path(Path::new(input)),
+                PathBuf::from(expected)
+            );
+        }
+    }
+
     #[test]
     fn test_apps_dir() {
         // Should not panic on supported platforms
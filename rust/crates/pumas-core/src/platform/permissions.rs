@@ -96,6 +96,37 @@ pub fn set_private(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Check whether symlinks can be created inside `dir`.
+///
+/// Probes by creating a small file and a symlink to it, then removes both.
+///
+/// # Platform Behavior
+/// - **Linux/macOS**: Usually true; false on filesystems without symlinks
+///   (FAT/exFAT drives, some network shares)
+/// - **Windows**: Requires Developer Mode or the create-symbolic-link
+///   privilege, so unprivileged users typically get false
+pub fn can_create_symlinks(dir: &Path) -> bool {
+    let target = dir.join(format!(".pumas-symlink-probe-{}", std::process::id()));
+    let link = target.with_extension("link");
+    if std::fs::write(&target, b"").is_err() {
+        return false;
+    }
+
+    #[cfg(unix)]
+    let created = std::os::unix::fs::symlink(&target, &link).is_ok();
+    #[cfg(windows)]
+    let created = std::os::windows::fs::symlink_file(&target, &link).is_ok();
+    #[cfg(not(any(unix, windows)))]
+    let created = false;
+
+    if created {
+        let _ = std::fs::remove_file(&link);
+    }
+    let _ = std::fs::remove_file(&target);
+    debug!("Symlink probe in {}: {}", dir.display(), created);
+    created
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +198,17 @@ mod tests {
             assert_eq!(mode & 0o777, 0o600);
         }
     }
+
+    #[test]
+    fn test_can_create_symlinks_cleans_up_probe_files() {
+        let temp_dir = TempDir::new().unwrap();
+
+        #[cfg(unix)]
+        assert!(can_create_symlinks(temp_dir.path()));
+        #[cfg(windows)]
+        can_create_symlinks(temp_dir.path());
+
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+        assert!(!can_create_symlinks(&temp_dir.path().join("missing")));
+    }
 }
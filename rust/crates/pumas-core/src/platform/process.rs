@@ -97,6 +97,34 @@ pub fn configure_detached_command(command: &mut Command) {
     }
 }
 
+/// Whether [`set_command_title`] can title child processes on this platform.
+pub fn supports_command_title() -> bool {
+    cfg!(unix)
+}
+
+/// Set the title a spawned process shows in process listings.
+///
+/// # Platform Behavior
+/// - **Linux/macOS**: Replaces `argv[0]`, which `ps` and `/proc/<pid>/cmdline` report
+/// - **Windows/Other**: Leaves the command unchanged and returns `false`
+///
+/// The program is still resolved from the path the command was created with.
+pub fn set_command_title(command: &mut Command, title: &str) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        command.arg0(title);
+        true
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (command, title);
+        false
+    }
+}
+
 /// Terminate a process gracefully, then forcefully if needed.
 ///
 /// # Platform Behavior
@@ -465,6 +493,28 @@ mod tests {
         assert!(result.unwrap());
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_set_command_title_replaces_argv0() {
+        let mut command = Command::new("sleep");
+        command.arg("5");
+        assert!(set_command_title(&mut command, "Pumas Title Test"));
+
+        let mut child = command.spawn().unwrap();
+        let cmdline_path = format!("/proc/{}/cmdline", child.id());
+        // The child reports the parent's cmdline until exec completes.
+        wait_until(std::time::Duration::from_secs(5), || {
+            std::fs::read(&cmdline_path)
+                .map(|cmdline| cmdline.starts_with(b"Pumas Title Test\0"))
+                .unwrap_or(false)
+        });
+        let cmdline = std::fs::read(&cmdline_path).unwrap();
+        child.kill().ok();
+        child.wait().ok();
+
+        assert!(cmdline.starts_with(b"Pumas Title Test\0"));
+    }
+
     #[test]
     fn test_find_processes() {
         // Should find at least something (like our test runner)
@@ -494,7 +544,7 @@ mod tests {
             .stderr(Stdio::null());
         configure_detached_command(&mut command);
 
-        let child = command.spawn().unwrap();
+        let mut child = command.spawn().unwrap();
         let parent_pid = child.id();
 
         wait_until(Duration::from_secs(5), || ready_file.exists());
@@ -510,6 +560,7 @@ mod tests {
         let stopped = terminate_process_tree(parent_pid, 1_000).unwrap();
 
         assert!(stopped);
+        let _ = child.wait();
         wait_until(Duration::from_secs(5), || !is_process_alive(worker_pid));
         assert!(!is_process_alive(worker_pid));
     }
@@ -3,6 +3,7 @@
 //! Loads plugin configurations from JSON files in the plugins directory.
 
 use super::schema::PluginConfig;
+use crate::config::AppId;
 use crate::error::{PumasError, Result};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -131,6 +132,10 @@ impl PluginLoader {
             });
         }
 
+        // Plugin IDs name version directories, so reject any that are not
+        // valid app IDs before the plugin is registered.
+        config.app_id()?;
+
         if config.display_name.is_empty() {
             return Err(PumasError::Config {
                 message: format!("Plugin '{}' has empty display_name", config.id),
@@ -148,6 +153,15 @@ impl PluginLoader {
             .and_then(|plugins| plugins.get(id).cloned())
     }
 
+    /// Resolve a loaded plugin's [`AppId`].
+    ///
+    /// Like [`AppId::from_str`], the lookup is case-insensitive; returns `None`
+    /// if no plugin with that ID is loaded.
+    pub fn app_id(&self, id: &str) -> Option<AppId> {
+        self.get(&id.to_ascii_lowercase())
+            .and_then(|config| config.app_id().ok())
+    }
+
     /// Get all loaded plugins.
     pub fn get_all(&self) -> Vec<PluginConfig> {
         self.plugins
@@ -367,4 +381,39 @@ mod tests {
         assert_eq!(loader.count(), 1);
         assert!(loader.exists("valid"));
     }
+
+    #[test]
+    fn test_plugin_app_ids_are_validated_and_resolved() {
+        let temp_dir = TempDir::new().unwrap();
+        let plugins_dir = temp_dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        create_test_plugin_file(
+            &plugins_dir,
+            "text-gen",
+            r#"{
+                "id": "text-gen",
+                "displayName": "Text Gen",
+                "installationType": "binary"
+            }"#,
+        );
+        create_test_plugin_file(
+            &plugins_dir,
+            "escape",
+            r#"{
+                "id": "../escape",
+                "displayName": "Escape",
+                "installationType": "binary"
+            }"#,
+        );
+
+        let loader = PluginLoader::new(&plugins_dir).unwrap();
+        assert_eq!(loader.count(), 1);
+        assert!(!loader.exists("../escape"));
+
+        let app_id = loader.app_id("Text-Gen").unwrap();
+        assert_eq!(app_id, AppId::plugin("text-gen").unwrap());
+        assert_eq!(app_id.versions_dir_name(), "text-gen-versions");
+        assert_eq!(loader.app_id("unknown"), None);
+    }
 }
@@ -14,5 +14,5 @@ mod schema;
 pub use loader::PluginLoader;
 pub use schema::{
     ApiEndpoint, AppCapabilities, ConnectionConfig, InstallationType, ModelCompatibility,
-    PanelSection, PluginConfig, VersionFilter,
+    PanelSection, PluginConfig, PluginLaunchConfig, VersionFilter,
 };
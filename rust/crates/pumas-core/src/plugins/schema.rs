@@ -2,8 +2,11 @@
 //!
 //! Defines the structure for plugin JSON configuration files.
 
+use crate::config::AppId;
+use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// How the app is installed and managed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -135,6 +138,41 @@ fn default_entry_point() -> String {
     "main.py".to_string()
 }
 
+/// Generic launch command for apps without built-in launch support.
+///
+/// `{version_dir}` and `{port}` in `args` and `env` values are replaced with
+/// the installed version directory and the connection port.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginLaunchConfig {
+    /// Executable path, relative to the installed version directory.
+    pub command: String,
+    /// Arguments passed to the command.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Extra environment variables.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Seconds to wait for the health endpoint after spawning.
+    #[serde(default = "default_ready_timeout_secs")]
+    pub ready_timeout_secs: u64,
+}
+
+fn default_ready_timeout_secs() -> u64 {
+    60
+}
+
+impl PluginLaunchConfig {
+    /// Replace launch placeholders in a single argument or env value.
+    pub fn expand(&self, value: &str, version_dir: &Path, port: Option<u16>) -> String {
+        let mut expanded = value.replace("{version_dir}", &version_dir.to_string_lossy());
+        if let Some(port) = port {
+            expanded = expanded.replace("{port}", &port.to_string());
+        }
+        expanded
+    }
+}
+
 /// A panel section type for the UI.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -187,6 +225,10 @@ pub struct PluginConfig {
     #[serde(default)]
     pub python_config: Option<PythonConfig>,
 
+    /// Generic launch command, used instead of the installation-type default.
+    #[serde(default)]
+    pub launch: Option<PluginLaunchConfig>,
+
     /// API endpoint definitions.
     #[serde(default)]
     pub api: HashMap<String, ApiEndpoint>,
@@ -213,6 +255,11 @@ fn default_true() -> bool {
 }
 
 impl PluginConfig {
+    /// Resolve this plugin's [`AppId`], failing if the ID is not a valid directory name.
+    pub fn app_id(&self) -> Result<AppId> {
+        AppId::plugin(&self.id)
+    }
+
     /// Get the connection URL for this app.
     pub fn connection_url(&self) -> Option<String> {
         self.connection
@@ -290,6 +337,7 @@ mod tests {
             version_filter: None,
             model_compatibility: None,
             python_config: None,
+            launch: None,
             api: HashMap::new(),
             panel_layout: vec![],
             sidebar_priority: 100,
@@ -319,6 +367,7 @@ mod tests {
                 import_command: None,
             }),
             python_config: None,
+            launch: None,
             api: HashMap::new(),
             panel_layout: vec![],
             sidebar_priority: 100,
@@ -331,6 +380,41 @@ mod tests {
         assert!(!config.supports_format("onnx"));
     }
 
+    #[test]
+    fn test_deserialize_launch_config() {
+        let json = r#"{
+            "id": "text-gen",
+            "displayName": "Text Gen",
+            "installationType": "binary",
+            "connection": {"defaultPort": 7860},
+            "launch": {
+                "command": "bin/server",
+                "args": ["--port", "{port}", "--models", "{version_dir}/models"],
+                "env": {"APP_HOME": "{version_dir}"}
+            }
+        }"#;
+
+        let config: PluginConfig = serde_json::from_str(json).unwrap();
+        let launch = config.launch.unwrap();
+        assert_eq!(launch.command, "bin/server");
+        assert_eq!(launch.ready_timeout_secs, 60);
+
+        let version_dir = Path::new("/apps/text-gen/v1");
+        let args: Vec<_> = launch
+            .args
+            .iter()
+            .map(|arg| launch.expand(arg, version_dir, Some(7860)))
+            .collect();
+        assert_eq!(
+            args,
+            ["--port", "7860", "--models", "/apps/text-gen/v1/models"]
+        );
+        assert_eq!(
+            launch.expand(&launch.env["APP_HOME"], version_dir, None),
+            "/apps/text-gen/v1"
+        );
+    }
+
     #[test]
     fn test_deserialize_in_process_plugin() {
         let json = r#"{
@@ -33,6 +33,8 @@ pub struct LaunchConfig {
     pub ready_timeout: Duration,
     /// URL to check for server readiness.
     pub health_check_url: Option<String>,
+    /// Title to show in process listings instead of the Python command line.
+    pub process_title: Option<String>,
 }
 
 /// Configuration for launching a binary application (like Ollama).
@@ -106,6 +108,27 @@ impl BinaryLaunchConfig {
         }
     }
 
+    /// Create a Torch server launch config that binds `host:port`.
+    pub fn torch_at(
+        tag: impl Into<String>,
+        version_dir: impl AsRef<Path>,
+        host: impl Into<String>,
+        port: u16,
+    ) -> Self {
+        let host = host.into();
+        Self {
+            extra_args: vec![
+                "serve.py".to_string(),
+                "--host".to_string(),
+                host.clone(),
+                "--port".to_string(),
+                port.to_string(),
+            ],
+            health_check_url: Some(format!("http://{host}:{port}/health")),
+            ..Self::torch(tag, version_dir)
+        }
+    }
+
     /// Create a new binary launch config for llama.cpp router mode.
     ///
     /// Router mode is selected by launching `llama-server` without a model path.
@@ -358,6 +381,22 @@ fn find_named_binary(root: &Path, binary_name: &str) -> Option<PathBuf> {
     None
 }
 
+/// Read the `(major, minor)` Python version from a venv's `pyvenv.cfg`.
+fn venv_python_version(python_path: &Path) -> Option<(u32, u32)> {
+    let venv_dir = python_path.parent()?.parent()?;
+    let config = fs::read_to_string(venv_dir.join("pyvenv.cfg")).ok()?;
+    config.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if !matches!(key.trim(), "version" | "version_info") {
+            return None;
+        }
+        let mut parts = value.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some((major, minor))
+    })
+}
+
 impl LaunchConfig {
     /// Create a new launch config with sensible defaults.
     pub fn new(tag: impl Into<String>, version_dir: impl AsRef<Path>) -> Self {
@@ -377,6 +416,7 @@ impl LaunchConfig {
             log_file: None,
             ready_timeout: Duration::from_secs(60),
             health_check_url: Some(AppId::ComfyUI.default_base_url().to_string()),
+            process_title: None,
         }
     }
 
@@ -415,6 +455,12 @@ impl LaunchConfig {
         self.health_check_url = Some(url.into());
         self
     }
+
+    /// Set the process title applied at spawn, where supported.
+    pub fn with_process_title(mut self, title: impl Into<String>) -> Self {
+        self.process_title = Some(title.into());
+        self
+    }
 }
 
 /// Result of launching a process.
@@ -473,6 +519,10 @@ impl ProcessLauncher {
             cmd.env(key, value);
         }
 
+        if let Some(ref title) = config.process_title {
+            Self::apply_process_title(&mut cmd, &config.python_path, title);
+        }
+
         // Set up stdio
         let log_path = config.log_file.clone();
         if let Some(ref log_file) = log_path {
@@ -550,6 +600,32 @@ impl ProcessLauncher {
         })
     }
 
+    /// Title the Python process at spawn so main.py needs no setproctitle patch.
+    ///
+    /// Replacing `argv[0]` hides the interpreter path Python uses to locate its
+    /// venv, so `PYTHONEXECUTABLE` points it back. Interpreters older than 3.11
+    /// ignore that variable on Linux and are left untitled.
+    fn apply_process_title(cmd: &mut Command, python_path: &Path, title: &str) -> bool {
+        match venv_python_version(python_path) {
+            Some(version) if version >= (3, 11) => {}
+            version => {
+                debug!(
+                    "Not setting process title for Python {:?} at {}",
+                    version,
+                    python_path.display()
+                );
+                return false;
+            }
+        }
+
+        if !platform::set_command_title(cmd, title) {
+            return false;
+        }
+        cmd.env("PYTHONEXECUTABLE", python_path);
+        debug!("Setting process title to '{}'", title);
+        true
+    }
+
     /// Wait for the server to become ready.
     fn wait_for_ready(url: &str, timeout: Duration) -> bool {
         let start = Instant::now();
@@ -789,6 +865,40 @@ mod tests {
         assert_eq!(config.ready_timeout, Duration::from_secs(30));
     }
 
+    #[test]
+    fn test_process_title_requires_python_311_venv() {
+        let temp_dir = TempDir::new().unwrap();
+        let python_path = temp_dir.path().join("venv").join("bin").join("python");
+        std::fs::create_dir_all(python_path.parent().unwrap()).unwrap();
+
+        let mut cmd = Command::new(&python_path);
+        assert!(!ProcessLauncher::apply_process_title(
+            &mut cmd,
+            &python_path,
+            "ComfyUI Server - v1.0.0"
+        ));
+
+        let pyvenv_cfg = temp_dir.path().join("venv").join("pyvenv.cfg");
+        std::fs::write(&pyvenv_cfg, "home = /usr/bin\nversion = 3.10.12\n").unwrap();
+        assert_eq!(venv_python_version(&python_path), Some((3, 10)));
+        assert!(!ProcessLauncher::apply_process_title(
+            &mut cmd,
+            &python_path,
+            "ComfyUI Server - v1.0.0"
+        ));
+
+        std::fs::write(
+            &pyvenv_cfg,
+            "home = /usr/bin\nversion_info = 3.12.1.final.0\n",
+        )
+        .unwrap();
+        assert_eq!(venv_python_version(&python_path), Some((3, 12)));
+        assert_eq!(
+            ProcessLauncher::apply_process_title(&mut cmd, &python_path, "ComfyUI Server - v1.0.0"),
+            platform::supports_command_title()
+        );
+    }
+
     #[test]
     fn test_binary_launch_config_profile_overrides() {
         let temp_dir = TempDir::new().unwrap();
@@ -824,6 +934,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_torch_binary_launch_config_binds_saved_address() {
+        let temp_dir = TempDir::new().unwrap();
+        let version_dir = temp_dir.path().join("torch-versions/v1.0.0");
+
+        let config = BinaryLaunchConfig::torch_at("v1.0.0", &version_dir, "127.0.0.1", 8512);
+
+        assert_eq!(config.pid_file, version_dir.join("torch.pid"));
+        assert_eq!(
+            config.extra_args,
+            vec!["serve.py", "--host", "127.0.0.1", "--port", "8512"]
+        );
+        assert_eq!(
+            config.health_check_url.as_deref(),
+            Some("http://127.0.0.1:8512/health")
+        );
+    }
+
     #[test]
     fn test_llama_cpp_router_binary_launch_config() {
         let temp_dir = TempDir::new().unwrap();
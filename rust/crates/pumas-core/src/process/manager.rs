@@ -9,6 +9,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use tracing::{debug, error, info, warn};
@@ -57,6 +58,8 @@ pub struct ProcessManager {
     ollama_status: Arc<Mutex<CachedProcessStatus>>,
     /// Cached Torch liveness from startup, launch, stop, or explicit refresh.
     torch_status: Arc<Mutex<CachedProcessStatus>>,
+    /// Whether ComfyUI processes are titled at spawn instead of by the main.py patch.
+    native_process_title: Arc<AtomicBool>,
 }
 
 impl ProcessManager {
@@ -95,9 +98,24 @@ impl ProcessManager {
             comfyui_status: Arc::new(Mutex::new(comfyui_status)),
             ollama_status: Arc::new(Mutex::new(ollama_status)),
             torch_status: Arc::new(Mutex::new(torch_status)),
+            native_process_title: Arc::new(AtomicBool::new(true)),
         })
     }
 
+    /// Enable or disable titling ComfyUI processes at spawn.
+    ///
+    /// Has no effect where the platform cannot title child processes; the
+    /// setproctitle patch is still needed there.
+    pub fn set_native_process_title(&self, enabled: bool) {
+        self.native_process_title.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether ComfyUI processes will be titled at spawn.
+    pub fn native_process_title(&self) -> bool {
+        self.native_process_title.load(Ordering::Relaxed)
+            && crate::platform::supports_command_title()
+    }
+
     /// Update the known version paths.
     pub fn set_version_paths(&self, version_paths: HashMap<String, PathBuf>) {
         {
@@ -184,6 +202,9 @@ impl ProcessManager {
         if let Some(ref log_path) = log_file {
             config = config.with_log_file(log_path);
         }
+        if self.native_process_title() {
+            config = config.with_process_title(format!("ComfyUI Server - {}", tag));
+        }
 
         // Launch
         let mut result = match ProcessLauncher::launch(&config) {
@@ -499,6 +520,30 @@ impl ProcessManager {
         version_dir: &Path,
         log_dir: Option<&Path>,
     ) -> LaunchResult {
+        self.launch_with_torch_config(BinaryLaunchConfig::torch(tag, version_dir), log_dir)
+    }
+
+    /// Launch the Torch inference server bound to `host:port`.
+    pub fn launch_torch_at(
+        &self,
+        tag: &str,
+        version_dir: &Path,
+        host: &str,
+        port: u16,
+        log_dir: Option<&Path>,
+    ) -> LaunchResult {
+        self.launch_with_torch_config(
+            BinaryLaunchConfig::torch_at(tag, version_dir, host, port),
+            log_dir,
+        )
+    }
+
+    fn launch_with_torch_config(
+        &self,
+        mut config: BinaryLaunchConfig,
+        log_dir: Option<&Path>,
+    ) -> LaunchResult {
+        let tag = config.tag.clone();
         // Clear previous error
         {
             let mut error = self.last_launch_error.lock().unwrap();
@@ -511,8 +556,6 @@ impl ProcessManager {
             dir.join(format!("torch_{}_{}.log", tag, timestamp))
         });
 
-        // Build launch config
-        let mut config = BinaryLaunchConfig::torch(tag, version_dir);
         if let Some(ref log_path) = log_file {
             config = config.with_log_file(log_path);
         }
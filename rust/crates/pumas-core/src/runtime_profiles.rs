@@ -344,6 +344,7 @@ impl RuntimeProviderAdapter for OnnxRuntimeProviderAdapter {
 #[derive(Debug, Clone)]
 pub struct RuntimeProfileService {
     launcher_root: PathBuf,
+    models_dir: PathBuf,
     config_path: PathBuf,
     write_lock: Arc<RwLock<()>>,
     event_journal: Arc<RwLock<RuntimeProfileEventJournal>>,
@@ -408,6 +409,7 @@ impl RuntimeProfileService {
                 .join("launcher-data")
                 .join("metadata")
                 .join("runtime-profiles.json"),
+            models_dir: crate::config::PathsConfig::default_model_library_dir(&launcher_root),
             launcher_root,
             write_lock: Arc::new(RwLock::new(())),
             event_journal: Arc::new(RwLock::new(RuntimeProfileEventJournal::default())),
@@ -418,6 +420,12 @@ impl RuntimeProfileService {
         }
     }
 
+    /// Use a model library directory outside the launcher root.
+    pub fn with_models_dir(mut self, models_dir: impl AsRef<Path>) -> Self {
+        self.models_dir = models_dir.as_ref().to_path_buf();
+        self
+    }
+
     pub async fn snapshot(&self) -> Result<RuntimeProfilesSnapshotResponse> {
         let config_path = self.config_path.clone();
         let write_lock = self.write_lock.clone();
@@ -532,13 +540,19 @@ impl RuntimeProfileService {
         let config_path = self.config_path.clone();
         let write_lock = self.write_lock.clone();
         let launcher_root = self.launcher_root.clone();
+        let models_dir = self.models_dir.clone();
         let provider_registry = self.provider_registry.clone();
         tokio::task::spawn_blocking(move || {
             let _guard = write_lock.write().map_err(|_| {
                 PumasError::Other("Failed to acquire runtime profile config lock".to_string())
             })?;
             let config = load_or_initialize_config(&config_path)?;
-            derive_managed_profile_launch_specs(&launcher_root, &config, &provider_registry)
+            derive_managed_profile_launch_specs(
+                &launcher_root,
+                &models_dir,
+                &config,
+                &provider_registry,
+            )
         })
         .await
         .map_err(|err| {
@@ -596,6 +610,7 @@ impl RuntimeProfileService {
         validate_profile_config(&profile, &self.provider_registry, &self.provider_adapters).await?;
         let profile_id = profile.profile_id.clone();
         let launcher_root = self.launcher_root.clone();
+        let models_dir = self.models_dir.clone();
         let provider_registry = self.provider_registry.clone();
         self.mutate_config(move |config| {
             if let Some(existing) = config
@@ -607,7 +622,12 @@ impl RuntimeProfileService {
             } else {
                 config.profiles.push(profile);
             }
-            derive_managed_profile_launch_specs(&launcher_root, config, &provider_registry)?;
+            derive_managed_profile_launch_specs(
+                &launcher_root,
+                &models_dir,
+                config,
+                &provider_registry,
+            )?;
             Ok(RuntimeProfileMutationResponse::success(Some(profile_id)))
         })
         .await
@@ -719,6 +739,7 @@ impl RuntimeProfileService {
         let config_path = self.config_path.clone();
         let write_lock = self.write_lock.clone();
         let launcher_root = self.launcher_root.clone();
+        let models_dir = self.models_dir.clone();
         let provider_registry = self.provider_registry.clone();
         tokio::task::spawn_blocking(move || {
             let _guard = write_lock.write().map_err(|_| {
@@ -732,6 +753,7 @@ impl RuntimeProfileService {
                 })?;
             resolve_config_profile_endpoint(
                 &launcher_root,
+                &models_dir,
                 &config,
                 &provider_registry,
                 provider,
@@ -787,6 +809,7 @@ impl RuntimeProfileService {
         let config_path = self.config_path.clone();
         let write_lock = self.write_lock.clone();
         let launcher_root = self.launcher_root.clone();
+        let models_dir = self.models_dir.clone();
         let provider_registry = self.provider_registry.clone();
         tokio::task::spawn_blocking(move || {
             let _guard = write_lock.write().map_err(|_| {
@@ -815,6 +838,7 @@ impl RuntimeProfileService {
                 })?;
             resolve_config_profile_endpoint(
                 &launcher_root,
+                &models_dir,
                 &config,
                 &provider_registry,
                 provider,
@@ -1081,6 +1105,7 @@ impl RuntimeProfileEventJournal {
 
 fn resolve_config_profile_endpoint(
     launcher_root: &Path,
+    models_dir: &Path,
     config: &RuntimeProfilesConfigFile,
     provider_registry: &ProviderRegistry,
     provider: RuntimeProviderId,
@@ -1105,16 +1130,21 @@ fn resolve_config_profile_endpoint(
     let endpoint_url = match &profile.endpoint_url {
         Some(endpoint_url) => endpoint_url.clone(),
         None if profile.management_mode == RuntimeManagementMode::Managed => {
-            derive_managed_profile_launch_specs(launcher_root, config, provider_registry)?
-                .into_iter()
-                .find(|spec| spec.profile_id == profile_id)
-                .map(|spec| spec.endpoint_url)
-                .ok_or_else(|| PumasError::InvalidParams {
-                    message: format!(
-                        "managed runtime profile endpoint could not be derived: {}",
-                        profile_id.as_str()
-                    ),
-                })?
+            derive_managed_profile_launch_specs(
+                launcher_root,
+                models_dir,
+                config,
+                provider_registry,
+            )?
+            .into_iter()
+            .find(|spec| spec.profile_id == profile_id)
+            .map(|spec| spec.endpoint_url)
+            .ok_or_else(|| PumasError::InvalidParams {
+                message: format!(
+                    "managed runtime profile endpoint could not be derived: {}",
+                    profile_id.as_str()
+                ),
+            })?
         }
         None => {
             return Err(PumasError::InvalidParams {
@@ -1776,7 +1806,7 @@ mod tests {
             RuntimeProviderId::Ollama
         );
         assert_eq!(snapshot.snapshot.routes[0].model_id, "llm/test/model");
-        assert_eq!(snapshot.snapshot.routes[0].auto_load, false);
+        assert!(!snapshot.snapshot.routes[0].auto_load);
 
         let persisted: Value =
             serde_json::from_slice(&std::fs::read(config_path).unwrap()).unwrap();
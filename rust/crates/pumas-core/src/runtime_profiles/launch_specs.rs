@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use crate::models::{
     RuntimeDeviceMode, RuntimeDeviceSettings, RuntimeEndpointUrl, RuntimeManagementMode,
@@ -20,6 +20,7 @@ const IMPLICIT_RUNTIME_PORT_SPAN: u16 = 10_000;
 
 pub(super) fn derive_managed_profile_launch_specs(
     launcher_root: &Path,
+    models_dir: &Path,
     config: &RuntimeProfilesConfigFile,
     provider_registry: &ProviderRegistry,
 ) -> Result<Vec<RuntimeProfileLaunchSpec>> {
@@ -89,7 +90,7 @@ pub(super) fn derive_managed_profile_launch_specs(
             endpoint_url: endpoint_url.clone(),
             port,
             extra_args: profile_runtime_extra_args(
-                launcher_root,
+                models_dir,
                 profile,
                 &endpoint_url,
                 port,
@@ -190,7 +191,7 @@ fn profile_runtime_env_vars(
 }
 
 fn profile_runtime_extra_args(
-    launcher_root: &Path,
+    models_dir: &Path,
     profile: &RuntimeProfileConfig,
     endpoint_url: &RuntimeEndpointUrl,
     port: RuntimePort,
@@ -206,9 +207,7 @@ fn profile_runtime_extra_args(
             let mut args = llama_cpp_runtime_args(endpoint_url, port)?;
             args.extend([
                 "--models-dir".to_string(),
-                llama_cpp_router_models_dir(launcher_root)
-                    .to_string_lossy()
-                    .to_string(),
+                models_dir.to_string_lossy().to_string(),
             ]);
             apply_llama_cpp_device_args(&mut args, profile);
             Ok(args)
@@ -266,10 +265,6 @@ fn llama_cpp_gpu_layers_arg(device: &RuntimeDeviceSettings) -> Option<i32> {
     }
 }
 
-fn llama_cpp_router_models_dir(launcher_root: &Path) -> PathBuf {
-    launcher_root.join("shared-resources").join("models")
-}
-
 fn runtime_host(endpoint_url: &RuntimeEndpointUrl) -> Result<String> {
     let parsed =
         url::Url::parse(endpoint_url.as_str()).map_err(|err| PumasError::InvalidParams {
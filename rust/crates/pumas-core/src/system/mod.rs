@@ -5,6 +5,7 @@
 //! - System resource monitoring (CPU, GPU, RAM)
 //! - File manager integration
 //! - URL/browser opening
+//! - Write-access prechecks for files and directories
 //!
 //! # Example
 //!
@@ -29,7 +30,11 @@
 mod gpu;
 mod resources;
 mod utils;
+mod writability;
 
 pub use gpu::{GpuInfo, GpuMonitor, NvidiaSmiMonitor};
 pub use resources::{ProcessResources, ResourceTracker, SystemResourceSnapshot};
-pub use utils::{check_brave, check_git, check_setproctitle, SystemCheckResult, SystemUtils};
+pub use utils::{
+    check_brave, check_git, check_setproctitle, check_uv, SystemCheckResult, SystemUtils,
+};
+pub use writability::{check_path_writable, NotWritableReason, PathWritability};
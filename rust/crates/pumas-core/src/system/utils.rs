@@ -614,6 +614,38 @@ pub fn check_git() -> SystemCheckResult {
     }
 }
 
+/// Check if the uv Python package manager is available on the system.
+pub fn check_uv() -> SystemCheckResult {
+    let available = command_exists("uv");
+    let path = if available {
+        Command::new("which")
+            .arg("uv")
+            .output()
+            .ok()
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .map(|s| s.trim().to_string())
+    } else {
+        None
+    };
+
+    let info = if available {
+        Command::new("uv")
+            .arg("--version")
+            .output()
+            .ok()
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .map(|s| s.trim().to_string())
+    } else {
+        None
+    };
+
+    SystemCheckResult {
+        available,
+        path,
+        info,
+    }
+}
+
 /// Check if Brave browser is available on the system.
 pub fn check_brave() -> SystemCheckResult {
     // Check common Brave binary names
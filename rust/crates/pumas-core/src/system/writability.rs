@@ -0,0 +1,161 @@
+//! Write-access prechecks for model files and mapping directories.
+//!
+//! Conversions, metadata edits, and mapping all modify files in place. Checking
+//! up front lets the UI warn about read-only files or mounts instead of failing
+//! partway through an operation.
+
+use crate::platform::extended_length_path;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Why a path cannot be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotWritableReason {
+    /// The current user lacks write permission
+    Permissions,
+    /// The file is marked read-only
+    ReadonlyFile,
+    /// The filesystem is mounted read-only
+    ReadonlyMount,
+    /// Neither the path nor any parent directory exists
+    NotFound,
+    /// Any other I/O failure (for example a file locked by another process)
+    Other,
+}
+
+/// Write access for a single path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PathWritability {
+    /// Path that was checked
+    pub path: PathBuf,
+    /// Whether the path can be written
+    pub writable: bool,
+    /// Why the path cannot be written, when it cannot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<NotWritableReason>,
+    /// Underlying error message, when the path cannot be written
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Check whether `path` can be written.
+///
+/// Existing files are opened for writing (without truncating), directories
+/// get a probe file created and removed, and paths that do not exist yet are
+/// checked through their nearest existing parent directory, since that is
+/// where they would be created.
+pub fn check_path_writable(path: &Path) -> PathWritability {
+    let io_path = extended_length_path(path);
+    let result = match std::fs::metadata(&io_path) {
+        Ok(metadata) if metadata.is_dir() => probe_directory(&io_path),
+        Ok(_) => OpenOptions::new().write(true).open(&io_path).map(|_| ()),
+        Err(err) if err.kind() == ErrorKind::NotFound => match nearest_existing_dir(&io_path) {
+            Some(dir) => probe_directory(&dir),
+            None => Err(err),
+        },
+        Err(err) => Err(err),
+    };
+
+    match result {
+        Ok(()) => PathWritability {
+            path: path.to_path_buf(),
+            writable: true,
+            reason: None,
+            message: None,
+        },
+        Err(err) => PathWritability {
+            path: path.to_path_buf(),
+            writable: false,
+            reason: Some(classify_error(&io_path, &err)),
+            message: Some(err.to_string()),
+        },
+    }
+}
+
+/// Create and remove a probe file to confirm `dir` accepts writes.
+fn probe_directory(dir: &Path) -> std::io::Result<()> {
+    let probe = dir.join(format!(".pumas-write-test-{}", std::process::id()));
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)?;
+    std::fs::remove_file(&probe)
+}
+
+fn nearest_existing_dir(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.is_dir())
+        .map(Path::to_path_buf)
+}
+
+fn classify_error(path: &Path, err: &std::io::Error) -> NotWritableReason {
+    match err.kind() {
+        ErrorKind::ReadOnlyFilesystem => NotWritableReason::ReadonlyMount,
+        ErrorKind::NotFound => NotWritableReason::NotFound,
+        ErrorKind::PermissionDenied => {
+            let readonly_file = std::fs::metadata(path)
+                .map(|metadata| metadata.is_file() && metadata.permissions().readonly())
+                .unwrap_or(false);
+            if readonly_file {
+                NotWritableReason::ReadonlyFile
+            } else {
+                NotWritableReason::Permissions
+            }
+        }
+        _ => NotWritableReason::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_writable_file_and_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("model.safetensors");
+        std::fs::write(&file, b"weights").unwrap();
+
+        assert!(check_path_writable(&file).writable);
+        assert!(check_path_writable(temp_dir.path()).writable);
+        assert_eq!(std::fs::read(&file).unwrap(), b"weights");
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_missing_path_uses_nearest_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("models").join("checkpoints");
+
+        let result = check_path_writable(&target);
+        assert!(result.writable);
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_readonly_file_reports_reason() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("model.gguf");
+        std::fs::write(&file, b"weights").unwrap();
+        let mut permissions = std::fs::metadata(&file).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&file, permissions.clone()).unwrap();
+
+        let result = check_path_writable(&file);
+        // Root bypasses permission bits on Unix
+        if !result.writable {
+            assert_eq!(result.reason, Some(NotWritableReason::ReadonlyFile));
+            assert!(result.message.is_some());
+        }
+
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        std::fs::set_permissions(&file, permissions).unwrap();
+    }
+}
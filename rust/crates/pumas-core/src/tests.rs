@@ -133,6 +133,361 @@ async fn test_api_paths() {
         .ends_with("comfyui-versions"));
 }
 
+#[tokio::test]
+async fn test_model_library_dir_can_live_outside_launcher_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let launcher_root = temp_dir.path().join("launcher");
+    let models_dir = temp_dir.path().join("bulk-drive").join("models");
+
+    let api = PumasApi::builder(&launcher_root)
+        .auto_create_dirs(true)
+        .with_model_library_dir(&models_dir)
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(api.model_library_dir(), models_dir);
+    assert!(models_dir.is_dir());
+    let model_path = api
+        .primary()
+        .model_library
+        .build_model_path("llm", "acme", "tiny");
+    assert!(model_path.starts_with(&models_dir));
+}
+
+#[tokio::test]
+async fn test_model_library_dir_is_validated_at_startup() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+
+    let relative = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_model_library_dir("models")
+        .build()
+        .await;
+    assert!(matches!(relative, Err(PumasError::Config { .. })));
+
+    let missing = PumasApi::builder(temp_dir.path())
+        .with_model_library_dir(temp_dir.path().join("missing"))
+        .build()
+        .await;
+    assert!(
+        matches!(missing, Err(PumasError::Config { message }) if message.contains("does not exist"))
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_remove_links_for_app_keeps_models_other_apps_still_use() {
+    use crate::model_library::{LinkEntry, LinkType};
+
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let api = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+    let library = api.model_library();
+    let app_models = temp_dir.path().join("app-models");
+    std::fs::create_dir_all(&app_models).unwrap();
+
+    for name in ["shared", "solo"] {
+        let model_dir = library.build_model_path("llm", "llama", name);
+        std::fs::create_dir_all(&model_dir).unwrap();
+        std::fs::write(model_dir.join("weights.gguf"), b"gguf").unwrap();
+        let metadata = ModelMetadata {
+            model_id: Some(format!("llm/llama/{}", name)),
+            model_type: Some("llm".to_string()),
+            ..Default::default()
+        };
+        library.save_metadata(&model_dir, &metadata).await.unwrap();
+        library.index_model_dir(&model_dir).await.unwrap();
+    }
+    let links = [
+        ("shared", "comfyui"),
+        ("solo", "comfyui"),
+        ("shared", "ollama"),
+    ];
+    for (name, app_id) in links {
+        let source = library
+            .build_model_path("llm", "llama", name)
+            .join("weights.gguf");
+        let target = app_models.join(format!("{}-{}.gguf", app_id, name));
+        std::os::unix::fs::symlink(&source, &target).unwrap();
+        let registry = library.link_registry().read().await;
+        registry
+            .register(LinkEntry {
+                model_id: format!("llm/llama/{}", name),
+                source,
+                target,
+                link_type: LinkType::Symlink,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                app_id: app_id.to_string(),
+                app_version: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    let response = api.remove_links_for_app("comfyui", true).await.unwrap();
+
+    assert!(response.success, "{:?}", response.errors);
+    assert_eq!(response.links_removed, 2);
+    assert_eq!(response.deleted_models, vec!["llm/llama/solo".to_string()]);
+    assert!(!app_models.join("comfyui-shared.gguf").exists());
+    assert!(app_models.join("ollama-shared.gguf").exists());
+    assert!(library
+        .build_model_path("llm", "llama", "shared")
+        .join("weights.gguf")
+        .exists());
+    assert!(!library.build_model_path("llm", "llama", "solo").exists());
+}
+
+#[tokio::test]
+async fn test_http_settings_are_scoped_to_each_instance() {
+    let proxied_root = TempDir::new().unwrap();
+    let plain_root = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(proxied_root.path());
+    let proxy = network::ProxyConfig::new("http://proxy.corp:3128");
+
+    let proxied = PumasApi::builder(proxied_root.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .with_proxy(proxy.clone())
+        .with_user_agent("MyApp/2.3")
+        .build()
+        .await
+        .unwrap();
+    let plain = PumasApi::builder(plain_root.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(proxied.http_settings().effective_proxy(), Some(proxy));
+    assert_eq!(
+        proxied.http_settings().user_agent(),
+        "pumas-library/1.0 MyApp/2.3"
+    );
+    assert_eq!(
+        plain.http_settings().effective_proxy(),
+        network::ProxyConfig::from_env()
+    );
+    assert_eq!(plain.http_settings().user_agent(), network::BASE_USER_AGENT);
+}
+
+#[tokio::test]
+async fn test_bandwidth_limit_is_scoped_to_each_instance() {
+    let limited_root = TempDir::new().unwrap();
+    let other_root = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(limited_root.path());
+
+    let limited = PumasApi::builder(limited_root.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+    let other = PumasApi::builder(other_root.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+
+    limited.set_global_bandwidth_limit(Some(4096));
+
+    assert_eq!(limited.global_bandwidth_limit(), Some(4096));
+    assert_eq!(limited.bandwidth_limiter().limit(), Some(4096));
+    assert_eq!(other.global_bandwidth_limit(), None);
+}
+
+#[tokio::test]
+async fn test_invalid_ca_bundle_fails_build() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let bundle = temp_dir.path().join("corp-ca.pem");
+    std::fs::write(&bundle, b"not a certificate").unwrap();
+
+    let result = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .with_user_agent("MyApp/2.3")
+        .with_ca_bundle(&bundle)
+        .build()
+        .await;
+
+    assert!(matches!(result, Err(PumasError::Config { .. })));
+    assert!(!temp_dir.path().join("launcher-data").exists());
+}
+
+#[tokio::test]
+async fn test_run_maintenance_compacts_library_databases() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let api = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+
+    let report = api.run_maintenance().await.unwrap();
+
+    assert!(report.success, "{:?}", report.error);
+    let names: Vec<_> = report.databases.iter().map(|db| db.name.as_str()).collect();
+    assert!(names.contains(&"models"));
+    assert!(names.contains(&"registry"));
+    assert_eq!(
+        report.total_reclaimed_bytes,
+        report
+            .databases
+            .iter()
+            .map(|db| db.reclaimed_bytes)
+            .sum::<u64>()
+    );
+}
+
+/// Replace the model index with bytes SQLite cannot open.
+fn corrupt_model_index(models_dir: &std::path::Path) -> std::path::PathBuf {
+    let db_path = models_dir.join("models.db");
+    for suffix in ["-wal", "-shm"] {
+        let _ = std::fs::remove_file(models_dir.join(format!("models.db{}", suffix)));
+    }
+    std::fs::write(&db_path, vec![0xAB; 8192]).unwrap();
+    db_path
+}
+
+#[tokio::test]
+async fn test_startup_reports_corrupt_model_index_with_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let models_dir = temp_dir.path().join("shared-resources").join("models");
+    std::fs::create_dir_all(&models_dir).unwrap();
+    let db_path = corrupt_model_index(&models_dir);
+
+    let err = match PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .rebuild_corrupt_model_index(false)
+        .build()
+        .await
+    {
+        Ok(_) => panic!("corrupt model index should fail startup"),
+        Err(err) => err,
+    };
+
+    let message = err.to_string();
+    assert!(matches!(err, PumasError::Database { .. }));
+    assert!(
+        message.contains(&db_path.display().to_string()),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("rebuild_corrupt_model_index"),
+        "{}",
+        message
+    );
+    assert!(db_path.is_file());
+}
+
+#[tokio::test]
+async fn test_startup_reports_corrupt_search_cache_with_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let cache_dir = temp_dir.path().join("shared-resources").join("cache");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    let db_path = cache_dir.join("search.sqlite");
+    std::fs::write(&db_path, vec![0xAB; 8192]).unwrap();
+
+    let err = match PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .build()
+        .await
+    {
+        Ok(_) => panic!("corrupt search cache should fail startup"),
+        Err(err) => err,
+    };
+
+    let message = err.to_string();
+    assert!(matches!(err, PumasError::Database { .. }));
+    assert!(
+        message.contains(&db_path.display().to_string()),
+        "{}",
+        message
+    );
+    assert!(message.contains("delete it"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_startup_rebuilds_corrupt_model_index_from_metadata() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    seed_stale_library_state(temp_dir.path()).await;
+    let models_dir = temp_dir.path().join("shared-resources").join("models");
+    corrupt_model_index(&models_dir);
+
+    let api = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .build()
+        .await
+        .unwrap();
+
+    let record = api
+        .primary()
+        .model_library
+        .index()
+        .get("llm/vit/qwen-image-2512-heretic")
+        .unwrap();
+    assert!(record.is_some());
+    let quarantined = std::fs::read_dir(&models_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("models.db.corrupt-")
+        });
+    assert!(quarantined);
+}
+
+#[tokio::test]
+async fn test_manual_only_orphan_scan_waits_for_scan_orphans() {
+    let temp_dir = TempDir::new().unwrap();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    let orphan_dir = temp_dir
+        .path()
+        .join("shared-resources")
+        .join("models")
+        .join("llm")
+        .join("llama")
+        .join("orphan");
+    std::fs::create_dir_all(&orphan_dir).unwrap();
+    std::fs::write(orphan_dir.join("weights.bin"), vec![0u8; 4096]).unwrap();
+
+    let api = PumasApi::builder(temp_dir.path())
+        .auto_create_dirs(true)
+        .with_hf_client(false)
+        .with_orphan_scan(crate::model_library::OrphanScanConfig::manual_only())
+        .build()
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert!(!orphan_dir.join("metadata.json").exists());
+
+    let result = api.scan_orphans().await.unwrap();
+    assert_eq!(result.orphans_found, 1);
+    assert_eq!(result.adopted, 1, "{:?}", result.errors);
+    assert!(orphan_dir.join("metadata.json").exists());
+}
+
 #[tokio::test]
 async fn test_get_status() {
     let temp_dir = TempDir::new().unwrap();
@@ -545,7 +545,7 @@ async fn test_model_library_list_with_empty_library() {
     let api = PumasApi::builder(temp_dir.path()).build().await.unwrap();
 
     // With no models, list should return empty
-    let models = api.list_models().await;
+    let models = api.list_models(false).await;
     assert!(models.is_ok());
     assert!(models.unwrap().is_empty());
 }
@@ -557,13 +557,51 @@ async fn test_model_search_with_empty_library() {
     let api = PumasApi::builder(temp_dir.path()).build().await.unwrap();
 
     // Searching empty library should return empty results
-    let result = api.search_models("test", 10, 0).await;
+    let result = api.search_models("test", 10, 0, false).await;
     assert!(result.is_ok());
     let search = result.unwrap();
     assert!(search.models.is_empty());
     assert_eq!(search.total_count, 0);
 }
 
+#[tokio::test]
+async fn test_model_list_and_search_hide_models_that_are_not_ready() {
+    let temp_dir = create_test_env();
+    let _registry = RegistryTestGuard::new(temp_dir.path());
+    create_indexable_test_model(temp_dir.path(), "llm/test/ready-model", "Ready Model");
+    create_indexable_test_model(temp_dir.path(), "llm/test/pending-model", "Pending Model");
+    let pending_metadata = temp_dir
+        .path()
+        .join("shared-resources/models/llm/test/pending-model/metadata.json");
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&pending_metadata).unwrap()).unwrap();
+    metadata["readiness"] = serde_json::json!("downloading");
+    std::fs::write(&pending_metadata, metadata.to_string()).unwrap();
+
+    let api = PumasApi::builder(temp_dir.path()).build().await.unwrap();
+    api.rebuild_model_index().await.unwrap();
+
+    let ids = |models: &[pumas_library::ModelRecord]| {
+        let mut ids: Vec<_> = models.iter().map(|model| model.id.clone()).collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(
+        ids(&api.list_models(false).await.unwrap()),
+        vec!["llm/test/ready-model"]
+    );
+    assert_eq!(
+        ids(&api.list_models(true).await.unwrap()),
+        vec!["llm/test/pending-model", "llm/test/ready-model"]
+    );
+
+    let search = api.search_models("model", 10, 0, false).await.unwrap();
+    assert_eq!(ids(&search.models), vec!["llm/test/ready-model"]);
+    assert_eq!(search.total_count, 1);
+    let search = api.search_models("model", 10, 0, true).await.unwrap();
+    assert_eq!(search.total_count, 2);
+}
+
 #[tokio::test]
 async fn test_api_creation_clean_startup_remains_idle() {
     let temp_dir = create_test_env();
@@ -931,7 +969,7 @@ async fn test_subscribe_model_library_updates_since_recovers_snapshot_gap() {
     )
     .unwrap();
 
-    let models = api.list_models().await.unwrap();
+    let models = api.list_models(false).await.unwrap();
     assert!(models.iter().any(|model| model.id == model_id));
 
     let subscription = api
@@ -997,7 +1035,7 @@ async fn test_subscribe_model_library_update_stream_since_recovers_then_delivers
     create_indexable_test_model(temp_dir.path(), recovered_model_id, "Stream Recovered");
     api.rebuild_model_index().await.unwrap();
     assert!(api
-        .list_models()
+        .list_models(false)
         .await
         .unwrap()
         .iter()
@@ -1022,7 +1060,7 @@ async fn test_subscribe_model_library_update_stream_since_recovers_then_delivers
     create_indexable_test_model(temp_dir.path(), live_model_id, "Stream Live");
     api.rebuild_model_index().await.unwrap();
     assert!(api
-        .list_models()
+        .list_models(false)
         .await
         .unwrap()
         .iter()
@@ -1111,7 +1149,7 @@ async fn test_list_models_reconciliation_advances_update_feed() {
     )
     .unwrap();
 
-    let models = api.list_models().await.unwrap();
+    let models = api.list_models(false).await.unwrap();
     assert!(models.iter().any(|model| model.id == model_id));
 
     let feed = api
@@ -0,0 +1,231 @@
+//! Optional `pumas-rpc.toml` configuration file.
+//!
+//! Values from the file are defaults; command-line flags override them. A
+//! relative `launcher_root` or `models_dir` is resolved against the config
+//! file's directory so a packaged config can ship next to the binary.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tracing::Level;
+
+/// Default RPC bind host.
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+
+/// Settings accepted in `pumas-rpc.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    /// Launcher root directory.
+    pub launcher_root: Option<PathBuf>,
+    /// Model library directory (defaults to `<launcher_root>/shared-resources/models`).
+    pub models_dir: Option<PathBuf>,
+    /// Host to bind to.
+    pub host: Option<String>,
+    /// Port to listen on (0 = auto-assign).
+    pub port: Option<u16>,
+    /// Allow binding to a non-loopback interface.
+    pub allow_lan: Option<bool>,
+    /// Log level: `error`, `warn`, `info`, `debug`, or `trace`.
+    pub log_level: Option<String>,
+    /// Level for `launcher-data/logs/pumas-rpc.log`, or `off` to disable it.
+    pub file_log_level: Option<String>,
+    /// HuggingFace auth token for this server; not written to disk.
+    pub hf_token: Option<String>,
+    /// Number of HuggingFace downloads that transfer at once.
+    pub download_concurrency: Option<usize>,
+}
+
+impl FileConfig {
+    /// Read and parse a config file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let mut config: Self = toml::from_str(&contents)
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        config.launcher_root = config.launcher_root.take().map(|root| base.join(root));
+        config.models_dir = config.models_dir.take().map(|dir| base.join(dir));
+
+        Ok(config)
+    }
+}
+
+/// Command-line values that take precedence over the config file.
+#[derive(Debug, Default, Clone)]
+pub struct CliOverrides {
+    pub launcher_root: Option<PathBuf>,
+    pub models_dir: Option<PathBuf>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub allow_lan: bool,
+    pub debug: bool,
+    pub file_log_level: Option<String>,
+}
+
+/// Effective server settings after merging the file with CLI overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcConfig {
+    pub launcher_root: Option<PathBuf>,
+    pub models_dir: Option<PathBuf>,
+    pub host: String,
+    pub port: u16,
+    pub allow_lan: bool,
+    pub log_level: Level,
+    /// File log level; `None` when file logging is off.
+    pub file_log_level: Option<Level>,
+    pub hf_token: Option<String>,
+    pub download_concurrency: Option<usize>,
+}
+
+impl RpcConfig {
+    /// Merge file values with CLI overrides, falling back to built-in defaults.
+    pub fn resolve(file: FileConfig, cli: CliOverrides) -> Result<Self> {
+        let log_level = if cli.debug {
+            Level::DEBUG
+        } else {
+            match file.log_level.as_deref() {
+                Some(level) => parse_log_level(level)?,
+                None => Level::INFO,
+            }
+        };
+
+        let file_log_level = match cli.file_log_level.or(file.file_log_level) {
+            Some(level) if level.eq_ignore_ascii_case("off") => None,
+            Some(level) => Some(parse_log_level(&level)?),
+            None => Some(Level::INFO),
+        };
+
+        Ok(Self {
+            launcher_root: cli.launcher_root.or(file.launcher_root),
+            models_dir: cli.models_dir.or(file.models_dir),
+            host: cli
+                .host
+                .or(file.host)
+                .unwrap_or_else(|| DEFAULT_HOST.to_string()),
+            port: cli.port.or(file.port).unwrap_or(0),
+            allow_lan: cli.allow_lan || file.allow_lan.unwrap_or(false),
+            log_level,
+            file_log_level,
+            hf_token: file.hf_token.filter(|token| !token.trim().is_empty()),
+            download_concurrency: file.download_concurrency,
+        })
+    }
+}
+
+fn parse_log_level(level: &str) -> Result<Level> {
+    level
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid log level '{level}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn cli_values_override_file_values() {
+        let file = FileConfig {
+            launcher_root: Some(PathBuf::from("/from/file")),
+            models_dir: Some(PathBuf::from("/models/from/file")),
+            host: Some("127.0.0.2".to_string()),
+            port: Some(9000),
+            allow_lan: Some(false),
+            log_level: Some("warn".to_string()),
+            file_log_level: Some("trace".to_string()),
+            hf_token: Some("hf_secret".to_string()),
+            download_concurrency: Some(2),
+        };
+        let cli = CliOverrides {
+            models_dir: Some(PathBuf::from("/models/from/cli")),
+            port: Some(9100),
+            debug: true,
+            ..CliOverrides::default()
+        };
+
+        let config = RpcConfig::resolve(file, cli).unwrap();
+
+        assert_eq!(config.launcher_root, Some(PathBuf::from("/from/file")));
+        assert_eq!(config.models_dir, Some(PathBuf::from("/models/from/cli")));
+        assert_eq!(config.host, "127.0.0.2");
+        assert_eq!(config.port, 9100);
+        assert_eq!(config.log_level, Level::DEBUG);
+        assert_eq!(config.file_log_level, Some(Level::TRACE));
+        assert_eq!(config.hf_token.as_deref(), Some("hf_secret"));
+        assert_eq!(config.download_concurrency, Some(2));
+    }
+
+    #[test]
+    fn defaults_apply_without_file_or_flags() {
+        let config = RpcConfig::resolve(FileConfig::default(), CliOverrides::default()).unwrap();
+
+        assert_eq!(config.launcher_root, None);
+        assert_eq!(config.models_dir, None);
+        assert_eq!(config.host, DEFAULT_HOST);
+        assert_eq!(config.port, 0);
+        assert!(!config.allow_lan);
+        assert_eq!(config.log_level, Level::INFO);
+        assert_eq!(config.file_log_level, Some(Level::INFO));
+        assert_eq!(config.hf_token, None);
+        assert_eq!(config.download_concurrency, None);
+    }
+
+    #[test]
+    fn file_log_level_is_independent_and_can_be_disabled() {
+        let file = FileConfig {
+            file_log_level: Some("debug".to_string()),
+            ..FileConfig::default()
+        };
+        let cli = CliOverrides {
+            file_log_level: Some("off".to_string()),
+            ..CliOverrides::default()
+        };
+        assert_eq!(
+            RpcConfig::resolve(file.clone(), cli)
+                .unwrap()
+                .file_log_level,
+            None
+        );
+
+        let config = RpcConfig::resolve(file, CliOverrides::default()).unwrap();
+        assert_eq!(config.log_level, Level::INFO);
+        assert_eq!(config.file_log_level, Some(Level::DEBUG));
+    }
+
+    #[test]
+    fn load_resolves_relative_launcher_root_against_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pumas-rpc.toml");
+        std::fs::write(
+            &path,
+            "launcher_root = \"..\"\nmodels_dir = \"models\"\nport = 8123\nlog_level = \"debug\"\n\
+             hf_token = \"hf_secret\"\ndownload_concurrency = 4\n",
+        )
+        .unwrap();
+
+        let config = FileConfig::load(&path).unwrap();
+
+        assert_eq!(config.launcher_root, Some(temp_dir.path().join("..")));
+        assert_eq!(config.models_dir, Some(temp_dir.path().join("models")));
+        assert_eq!(config.port, Some(8123));
+        assert_eq!(config.log_level.as_deref(), Some("debug"));
+        assert_eq!(config.hf_token.as_deref(), Some("hf_secret"));
+        assert_eq!(config.download_concurrency, Some(4));
+    }
+
+    #[test]
+    fn load_rejects_unknown_keys_and_bad_log_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pumas-rpc.toml");
+        std::fs::write(&path, "prot = 8123\n").unwrap();
+        assert!(FileConfig::load(&path).is_err());
+
+        let file = FileConfig {
+            log_level: Some("loud".to_string()),
+            ..FileConfig::default()
+        };
+        assert!(RpcConfig::resolve(file, CliOverrides::default()).is_err());
+    }
+}
@@ -1,15 +1,36 @@
 //! Link management handlers.
 
 use super::{
-    get_str_param, path_exists, require_str_param, validate_existing_local_path,
+    get_bool_param, get_str_param, require_str_param, validate_existing_local_path,
     validate_local_write_target_path,
 };
 use crate::server::AppState;
-use pumas_library::model_library::ConflictResolution;
+use pumas_library::model_library::{ConflictResolution, LinkType};
+use pumas_library::platform::hardlink_count;
+use pumas_library::system::{NotWritableReason, PathWritability};
+use pumas_library::AutoSyncTarget;
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+fn parse_conflict_resolution(action: &str) -> Option<ConflictResolution> {
+    match action.trim().to_ascii_lowercase().as_str() {
+        "skip" => Some(ConflictResolution::Skip),
+        "overwrite" => Some(ConflictResolution::Overwrite),
+        "rename" => Some(ConflictResolution::Rename),
+        _ => None,
+    }
+}
+
+fn parse_link_type(link_type: &str) -> Option<LinkType> {
+    match link_type.trim().to_ascii_lowercase().as_str() {
+        "symlink" => Some(LinkType::Symlink),
+        "hardlink" => Some(LinkType::Hardlink),
+        "copy" => Some(LinkType::Copy),
+        _ => None,
+    }
+}
+
 fn parse_conflict_resolutions(
     raw_resolutions: HashMap<String, String>,
 ) -> std::result::Result<HashMap<String, ConflictResolution>, Vec<String>> {
@@ -17,14 +38,7 @@ fn parse_conflict_resolutions(
     let mut invalid_actions = Vec::new();
 
     for (target, action) in raw_resolutions {
-        let parsed = match action.trim().to_ascii_lowercase().as_str() {
-            "skip" => Some(ConflictResolution::Skip),
-            "overwrite" => Some(ConflictResolution::Overwrite),
-            "rename" => Some(ConflictResolution::Rename),
-            _ => None,
-        };
-
-        if let Some(value) = parsed {
+        if let Some(value) = parse_conflict_resolution(&action) {
             resolutions.insert(target, value);
         } else {
             invalid_actions.push(format!("{}={}", target, action));
@@ -123,6 +137,17 @@ pub async fn apply_model_mapping(state: &AppState, params: &Value) -> pumas_libr
     }
 }
 
+pub async fn validate_mapping_configs(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    let report = state.api.validate_mapping_configs().await?;
+    Ok(json!({
+        "success": true,
+        "report": report
+    }))
+}
+
 pub async fn sync_models_incremental(
     state: &AppState,
     params: &Value,
@@ -147,6 +172,124 @@ pub async fn sync_models_incremental(
     }
 }
 
+/// Enable watch-and-sync into the active ComfyUI version, or disable it.
+///
+/// `switch_version` moves an enabled auto-sync to the new active version.
+pub async fn set_auto_sync(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    if !get_bool_param(params, "enabled", "enabled").unwrap_or(true) {
+        state.api.set_auto_sync(None);
+        return Ok(json!({
+            "success": true,
+            "auto_sync": Value::Null
+        }));
+    }
+
+    let conflict_resolution = match get_str_param(
+        params,
+        "conflict_resolution",
+        "conflictResolution",
+    ) {
+        None => ConflictResolution::Skip,
+        Some(raw) => match parse_conflict_resolution(raw) {
+            Some(parsed) => parsed,
+            None => {
+                return Ok(json!({
+                    "success": false,
+                    "error": format!(
+                        "Invalid conflict resolution action: {}. Supported values: skip, overwrite, rename",
+                        raw
+                    ),
+                }));
+            }
+        },
+    };
+
+    let managers = state.version_managers.read().await;
+    let Some(vm) = managers.get("comfyui") else {
+        return Ok(json!({
+            "success": false,
+            "error": "Version manager not initialized for comfyui"
+        }));
+    };
+    let Some(version_tag) = vm.get_active_version().await? else {
+        return Ok(json!({
+            "success": false,
+            "error": "No active comfyui version to sync into"
+        }));
+    };
+    let target = AutoSyncTarget {
+        models_path: vm.version_path(&version_tag).join("models"),
+        version_tag,
+        conflict_resolution,
+    };
+    drop(managers);
+
+    state.api.set_auto_sync(Some(target.clone()));
+    Ok(json!({
+        "success": true,
+        "auto_sync": target
+    }))
+}
+
+pub async fn get_auto_sync(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    Ok(json!({
+        "success": true,
+        "auto_sync": state.api.auto_sync_target()
+    }))
+}
+
+pub async fn get_default_link_type(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    Ok(json!({
+        "success": true,
+        "link_type": state.api.default_link_type().await?
+    }))
+}
+
+/// Persist the link type mapping tries first.
+///
+/// The choice is checked against the active ComfyUI version's models
+/// directory; an infeasible choice is still saved and reported in `check`.
+pub async fn set_default_link_type(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let raw = require_str_param(params, "link_type", "linkType")?;
+    let Some(link_type) = parse_link_type(&raw) else {
+        return Ok(json!({
+            "success": false,
+            "error": format!(
+                "Invalid link type: {}. Supported values: symlink, hardlink, copy",
+                raw
+            ),
+        }));
+    };
+
+    state.api.set_default_link_type(link_type).await?;
+
+    let managers = state.version_managers.read().await;
+    let models_path = match managers.get("comfyui") {
+        Some(vm) => vm
+            .get_active_version()
+            .await?
+            .map(|tag| vm.version_path(&tag).join("models")),
+        None => None,
+    };
+    drop(managers);
+
+    let check = match models_path {
+        Some(models_path) => Some(state.api.check_link_type(link_type, &models_path).await?),
+        None => None,
+    };
+    Ok(json!({
+        "success": true,
+        "link_type": link_type,
+        "check": check
+    }))
+}
+
 pub async fn sync_with_resolutions(
     state: &AppState,
     params: &Value,
@@ -218,76 +361,104 @@ pub async fn get_cross_filesystem_warning(
     }
 }
 
-pub async fn get_file_link_count(
-    _state: &AppState,
-    params: &Value,
-) -> pumas_library::Result<Value> {
+/// Report hardlink counts for a single file or for every file in a model.
+///
+/// With `model_id`, returns per-file counts and `shared_bytes`, the size of
+/// files that stay on disk through other hardlinks after the model is deleted.
+pub async fn get_file_link_count(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    if let Some(model_id) = get_str_param(params, "model_id", "modelId") {
+        let files = state.api.file_link_count(model_id).await?;
+        let shared_bytes: u64 = files
+            .iter()
+            .filter(|file| file.is_hard_linked())
+            .map(|file| file.size)
+            .sum();
+        return Ok(json!({
+            "success": true,
+            "model_id": model_id,
+            "files": files,
+            "shared_bytes": shared_bytes,
+        }));
+    }
+
     let file_path: PathBuf = validate_existing_local_path(
         require_str_param(params, "file_path", "filePath")?,
         "file_path",
     )
     .await?;
-    // Count hard links to a file
-    if path_exists(&file_path).await? {
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::MetadataExt;
-            if let Ok(metadata) = tokio::fs::metadata(&file_path).await {
-                return Ok(json!({
-                    "success": true,
-                    "count": metadata.nlink()
-                }));
-            }
-        }
-    }
+    let link_count = tokio::task::spawn_blocking(move || hardlink_count(&file_path))
+        .await
+        .map_err(|e| {
+            pumas_library::PumasError::Other(format!(
+                "Failed to join get_file_link_count task: {}",
+                e
+            ))
+        })?
+        .unwrap_or(1);
     Ok(json!({
         "success": true,
-        "count": 1
+        "count": link_count,
+        "link_count": link_count,
+        "is_hard_linked": link_count > 1,
     }))
 }
 
+/// Check write access for files before conversions or edits modify them.
+///
+/// With `version_tag`, the ComfyUI version's models directory is checked too,
+/// since mapping writes links there.
 pub async fn check_files_writable(
-    _state: &AppState,
+    state: &AppState,
     params: &Value,
 ) -> pumas_library::Result<Value> {
-    // Check if files can be written/modified
     let file_paths: Vec<String> = params
         .get("file_paths")
         .or_else(|| params.get("filePaths"))
         .and_then(|v| serde_json::from_value(v.clone()).ok())
         .unwrap_or_default();
 
-    let mut results = Vec::with_capacity(file_paths.len());
-    for file_path in &file_paths {
-        let writable = if let Ok(path) =
-            validate_local_write_target_path(file_path.to_string(), "file_paths").await
-        {
-            if path_exists(&path).await? {
-                tokio::fs::metadata(&path)
-                    .await
-                    .map(|metadata| !metadata.permissions().readonly())
-                    .unwrap_or(false)
-            } else if let Some(parent) = path.parent() {
-                tokio::fs::metadata(parent)
-                    .await
-                    .map(|metadata| !metadata.permissions().readonly())
-                    .unwrap_or(false)
-            } else {
-                false
+    let mut requested = Vec::with_capacity(file_paths.len() + 1);
+    let mut paths = Vec::with_capacity(file_paths.len() + 1);
+    let mut rejected = Vec::new();
+    for file_path in file_paths {
+        match validate_local_write_target_path(file_path.clone(), "file_paths").await {
+            Ok(path) => {
+                requested.push(PathBuf::from(file_path));
+                paths.push(path);
             }
-        } else {
-            false
-        };
+            Err(err) => rejected.push(PathWritability {
+                path: PathBuf::from(file_path),
+                writable: false,
+                reason: Some(NotWritableReason::Other),
+                message: Some(err.to_string()),
+            }),
+        }
+    }
 
-        results.push(json!({
-            "path": file_path,
-            "writable": writable
-        }));
+    if let Some(version_tag) = get_str_param(params, "version_tag", "versionTag") {
+        let managers = state.version_managers.read().await;
+        let Some(vm) = managers.get("comfyui") else {
+            return Ok(json!({
+                "success": false,
+                "error": "Version manager not initialized for comfyui"
+            }));
+        };
+        let models_path = vm.version_path(version_tag).join("models");
+        requested.push(models_path.clone());
+        paths.push(models_path);
     }
 
+    // Report results under the paths the caller passed, not canonical ones
+    let mut details = state.api.check_files_writable(paths).await?;
+    for (detail, path) in details.iter_mut().zip(requested) {
+        detail.path = path;
+    }
+    details.extend(rejected);
+    let all_writable = details.iter().all(|detail| detail.writable);
     Ok(json!({
         "success": true,
-        "results": results
+        "all_writable": all_writable,
+        "details": details
     }))
 }
 
@@ -339,4 +510,12 @@ mod tests {
         let invalid = parse_conflict_resolutions(raw).expect_err("expected invalid action");
         assert_eq!(invalid, vec!["b=invalid".to_string()]);
     }
+
+    #[test]
+    fn test_parse_link_type() {
+        assert_eq!(parse_link_type("symlink"), Some(LinkType::Symlink));
+        assert_eq!(parse_link_type(" Hardlink "), Some(LinkType::Hardlink));
+        assert_eq!(parse_link_type("copy"), Some(LinkType::Copy));
+        assert_eq!(parse_link_type("junction"), None);
+    }
 }
@@ -1,11 +1,14 @@
 //! Model catalog and mapping handlers.
 
-use crate::handlers::{get_str_param, get_version_manager, require_str_param};
+use crate::handlers::{get_bool_param, get_str_param, get_version_manager, require_str_param};
 use crate::server::AppState;
 use serde_json::{json, Value};
+use std::path::PathBuf;
 
-pub async fn get_models(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
-    let models = state.api.list_models().await?;
+pub async fn get_models(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let include_incomplete =
+        get_bool_param(params, "include_incomplete", "includeIncomplete").unwrap_or(false);
+    let models = state.api.list_models(include_incomplete).await?;
     // Convert to a format with model_id as keys for frontend compatibility
     let mut result = serde_json::Map::new();
     for model in models {
@@ -14,14 +17,57 @@ pub async fn get_models(state: &AppState, _params: &Value) -> pumas_library::Res
     Ok(json!(result))
 }
 
-pub async fn refresh_model_index(
+/// Refresh one model's index row when `model_id` is given, else rebuild the index.
+pub async fn refresh_model_index(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    if let Some(model_id) = get_str_param(params, "model_id", "modelId") {
+        state.api.refresh_model(model_id).await?;
+        return Ok(json!({
+            "success": true,
+            "model_id": model_id,
+            "indexed_count": 1
+        }));
+    }
+
+    let count = state.api.rebuild_model_index().await?;
+    Ok(json!({
+        "success": true,
+        "indexed_count": count
+    }))
+}
+
+/// Start a metadata-only index rebuild in the background and return its rebuild ID.
+pub async fn start_rebuild_model_index(
     state: &AppState,
     _params: &Value,
 ) -> pumas_library::Result<Value> {
-    let count = state.api.rebuild_model_index().await?;
+    let rebuild_id = state.api.start_rebuild_model_index();
     Ok(json!({
         "success": true,
-        "indexed_count": count
+        "rebuild_id": rebuild_id
+    }))
+}
+
+pub async fn get_rebuild_progress(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let rebuild_id = require_str_param(params, "rebuild_id", "rebuildId")?;
+    let progress = state.api.get_rebuild_progress(&rebuild_id);
+    Ok(json!({
+        "success": true,
+        "progress": progress
+    }))
+}
+
+pub async fn cancel_rebuild_model_index(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let rebuild_id = require_str_param(params, "rebuild_id", "rebuildId")?;
+    let cancelled = state.api.cancel_rebuild_model_index(&rebuild_id);
+    Ok(json!({
+        "success": true,
+        "cancelled": cancelled
     }))
 }
 
@@ -71,10 +117,19 @@ pub async fn refresh_model_mappings(
     }
 }
 
-pub async fn scan_shared_storage(
-    state: &AppState,
-    _params: &Value,
-) -> pumas_library::Result<Value> {
+/// Scan `paths` for models outside the library when given, else rebuild the index.
+pub async fn scan_shared_storage(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    if let Some(paths) = params.get("paths").and_then(|v| v.as_array()) {
+        let paths = paths
+            .iter()
+            .filter_map(|v| v.as_str().map(PathBuf::from))
+            .collect();
+        let report = state.api.scan_shared_storage(paths).await?;
+        let mut result = serde_json::to_value(&report)?;
+        result["modelsFound"] = json!(report.candidates.len());
+        return Ok(result);
+    }
+
     // Rebuild the model index from metadata files on disk
     let count = state.api.rebuild_model_index().await?;
     Ok(json!({
@@ -84,6 +139,23 @@ pub async fn scan_shared_storage(
     }))
 }
 
+pub async fn security_audit(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    let report = state.api.security_audit().await?;
+    Ok(json!({
+        "success": true,
+        "report": report
+    }))
+}
+
+pub async fn verify_local_model(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let model_id = require_str_param(params, "model_id", "modelId")?;
+    let result = state.api.verify_local_model(&model_id).await?;
+    Ok(json!({
+        "success": true,
+        "result": result
+    }))
+}
+
 pub async fn refetch_model_metadata_from_hf(
     state: &AppState,
     params: &Value,
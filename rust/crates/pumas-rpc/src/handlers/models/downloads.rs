@@ -34,6 +34,18 @@ struct DownloadModelFromHfParams {
     model_card_json: Option<String>,
     #[serde(default, alias = "licenseStatus")]
     license_status: Option<String>,
+    #[serde(default, alias = "skipHashVerification")]
+    skip_hash_verification: bool,
+    #[serde(default, alias = "maxConcurrentFiles")]
+    max_concurrent_files: Option<u32>,
+    #[serde(default, alias = "rateLimit")]
+    rate_limit: Option<u64>,
+    #[serde(default)]
+    priority: pumas_library::DownloadPriority,
+    #[serde(default, alias = "includePatterns")]
+    include_patterns: Option<Vec<String>>,
+    #[serde(default, alias = "excludePatterns")]
+    exclude_patterns: Option<Vec<String>>,
 }
 
 impl DownloadModelFromHfParams {
@@ -53,6 +65,12 @@ impl DownloadModelFromHfParams {
             download_url: self.download_url,
             model_card_json: self.model_card_json,
             license_status: self.license_status,
+            skip_hash_verification: self.skip_hash_verification,
+            max_concurrent_files: self.max_concurrent_files,
+            rate_limit: self.rate_limit,
+            priority: self.priority,
+            include_patterns: self.include_patterns,
+            exclude_patterns: self.exclude_patterns,
         })
     }
 }
@@ -169,6 +187,34 @@ pub async fn pause_model_download(
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct ReorderModelDownloadParams {
+    #[serde(alias = "downloadId")]
+    download_id: String,
+    priority: pumas_library::DownloadPriority,
+}
+
+pub async fn reorder_model_download(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let command: ReorderModelDownloadParams = parse_params("reorder_model_download", params)?;
+    let download_id = validate_non_empty(command.download_id, "download_id")?;
+    match state
+        .api
+        .reorder_hf_download(&download_id, command.priority)
+        .await
+    {
+        Ok(reordered) => Ok(json!({
+            "success": reordered
+        })),
+        Err(e) => Ok(json!({
+            "success": false,
+            "error": e.to_string()
+        })),
+    }
+}
+
 pub async fn resume_model_download(
     state: &AppState,
     params: &Value,
@@ -6,6 +6,7 @@ use crate::handlers::{
     validate_existing_local_path, validate_non_empty,
 };
 use crate::server::AppState;
+use pumas_library::cancel::CancellationToken;
 use pumas_library::model_library::get_diffusers_component_manifest;
 use serde::Deserialize;
 use serde_json::{json, Value};
@@ -27,6 +28,13 @@ struct ImportModelParams {
     subtype: Option<String>,
     #[serde(default, alias = "securityAcknowledged")]
     security_acknowledged: Option<bool>,
+    #[serde(default, alias = "byReference")]
+    by_reference: Option<bool>,
+    #[serde(default)]
+    dedup: Option<pumas_library::models::DedupStrategy>,
+    /// Report what `import_model` would do instead of importing.
+    #[serde(default)]
+    preview: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -95,12 +103,21 @@ async fn extract_diffusers_component_manifest(
         })?
 }
 
-pub async fn import_model(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
-    let command: ImportModelParams = parse_params("import_model", params)?;
+async fn import_spec_from_params(
+    method: &str,
+    params: &Value,
+) -> pumas_library::Result<pumas_library::model_library::ModelImportSpec> {
+    let command: ImportModelParams = parse_params(method, params)?;
+    import_spec_from_command(command).await
+}
+
+async fn import_spec_from_command(
+    command: ImportModelParams,
+) -> pumas_library::Result<pumas_library::model_library::ModelImportSpec> {
     let local_path: PathBuf =
         validate_existing_local_path(command.local_path, "local_path").await?;
 
-    let spec = pumas_library::model_library::ModelImportSpec {
+    Ok(pumas_library::model_library::ModelImportSpec {
         path: local_path.to_string_lossy().to_string(),
         family: validate_non_empty(command.family, "family")?,
         official_name: validate_non_empty(command.official_name, "official_name")?,
@@ -109,12 +126,75 @@ pub async fn import_model(state: &AppState, params: &Value) -> pumas_library::Re
         subtype: command.subtype,
         tags: None,
         security_acknowledged: command.security_acknowledged,
-    };
+        by_reference: command.by_reference,
+        dedup: command.dedup,
+    })
+}
 
+/// Import a model, or with `preview` report where it would land without
+/// copying, hashing, or creating anything.
+pub async fn import_model(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let command: ImportModelParams = parse_params("import_model", params)?;
+    let preview = command.preview;
+    let spec = import_spec_from_command(command).await?;
+    if preview {
+        let report = state.api.validate_import_spec(&spec).await?;
+        return Ok(json!({
+            "success": true,
+            "preview": true,
+            "report": report
+        }));
+    }
     let result = state.api.import_model(&spec).await?;
     Ok(serde_json::to_value(result)?)
 }
 
+/// Dry-run an `import_model` spec: destination, detected type, conflicts, and blocking errors.
+pub async fn validate_import_spec(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let spec = import_spec_from_params("validate_import_spec", params).await?;
+    let report = state.api.validate_import_spec(&spec).await?;
+    Ok(json!({
+        "success": true,
+        "report": report
+    }))
+}
+
+/// Show where a download or import would store a model, without creating it.
+pub async fn preview_model_path(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let model_type = require_str_param(params, "model_type", "modelType")?;
+    let family = require_str_param(params, "family", "family")?;
+    let official_name = require_str_param(params, "official_name", "officialName")?;
+    let preview = state
+        .api
+        .preview_model_path(&model_type, &family, &official_name);
+    Ok(json!({
+        "success": true,
+        "preview": preview
+    }))
+}
+
+/// Start an `import_model` import in the background and return its import ID.
+pub async fn start_import(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let spec = import_spec_from_params("start_import", params).await?;
+    let import_id = state.api.start_import(spec);
+    Ok(json!({
+        "success": true,
+        "import_id": import_id
+    }))
+}
+
+pub async fn get_import_progress(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let import_id = require_str_param(params, "import_id", "importId")?;
+    let progress = state.api.get_import_progress(&import_id);
+    Ok(json!({
+        "success": true,
+        "progress": progress
+    }))
+}
+
 pub async fn import_batch(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     // Parse the imports array from params
     let imports: Vec<pumas_library::model_library::ModelImportSpec> = params
@@ -122,7 +202,10 @@ pub async fn import_batch(state: &AppState, params: &Value) -> pumas_library::Re
         .and_then(|v| serde_json::from_value(v.clone()).ok())
         .unwrap_or_default();
 
-    let results = state.api.import_models_batch(imports).await;
+    let results = state
+        .api
+        .import_models_batch(imports, &CancellationToken::new())
+        .await;
     let imported = results.iter().filter(|r| r.success).count();
     let failed = results.iter().filter(|r| !r.success).count();
 
@@ -516,11 +599,9 @@ pub async fn model_package_facts_summary_snapshot(
     Ok(serde_json::to_value(snapshot)?)
 }
 
-pub async fn adopt_orphan_models(
-    state: &AppState,
-    _params: &Value,
-) -> pumas_library::Result<Value> {
-    let result = state.api.adopt_orphan_models().await?;
+pub async fn adopt_orphan_models(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let dry_run = get_bool_param(params, "dry_run", "dryRun").unwrap_or(false);
+    let result = state.api.adopt_orphan_models(dry_run).await?;
     Ok(serde_json::to_value(result)?)
 }
 
@@ -1,9 +1,20 @@
-//! Inference settings handlers.
+//! Inference settings, notes, and tag handlers.
 
-use crate::handlers::require_str_param;
+use crate::handlers::{parse_params, require_str_param};
 use crate::server::AppState;
+use serde::Deserialize;
 use serde_json::{json, Value};
 
+#[derive(Debug, Deserialize)]
+struct UpdateModelTagsParams {
+    #[serde(alias = "modelId")]
+    model_id: String,
+    #[serde(default)]
+    add: Vec<String>,
+    #[serde(default)]
+    remove: Vec<String>,
+}
+
 pub async fn get_inference_settings(
     state: &AppState,
     params: &Value,
@@ -51,3 +62,16 @@ pub async fn update_model_notes(state: &AppState, params: &Value) -> pumas_libra
     let response = state.api.update_model_notes(&model_id, notes).await?;
     Ok(serde_json::to_value(response)?)
 }
+
+pub async fn update_model_tags(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let command: UpdateModelTagsParams = parse_params("update_model_tags", params)?;
+    let tags = state
+        .api
+        .update_model_tags(&command.model_id, command.add, command.remove)
+        .await?;
+    Ok(json!({
+        "success": true,
+        "model_id": command.model_id,
+        "tags": tags
+    }))
+}
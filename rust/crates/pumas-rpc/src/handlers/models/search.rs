@@ -1,9 +1,46 @@
 //! Model search handlers.
 
-use crate::handlers::{get_i64_param, get_str_param, require_str_param};
+use crate::handlers::{
+    get_bool_param, get_i64_param, get_str_param, parse_params, require_str_param,
+};
 use crate::server::AppState;
+use pumas_library::{SearchOptions, SearchSortBy, SortDirection};
+use serde::Deserialize;
 use serde_json::{json, Value};
 
+/// Optional filters and ordering accepted by `search_models_fts`.
+///
+/// Every field may be omitted or null; `model_type` is the single-type form
+/// the desktop bridge has always sent.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SearchOptionsParams {
+    #[serde(alias = "modelType")]
+    model_type: Option<String>,
+    #[serde(alias = "modelTypes")]
+    model_types: Option<Vec<String>>,
+    family: Option<String>,
+    tags: Option<Vec<String>>,
+    #[serde(alias = "sortBy")]
+    sort_by: Option<SearchSortBy>,
+    #[serde(alias = "sortDir")]
+    sort_dir: Option<SortDirection>,
+}
+
+impl From<SearchOptionsParams> for SearchOptions {
+    fn from(params: SearchOptionsParams) -> Self {
+        let mut model_types = params.model_types.unwrap_or_default();
+        model_types.extend(params.model_type);
+        Self {
+            model_types,
+            family: params.family,
+            tags: params.tags.unwrap_or_default(),
+            sort_by: params.sort_by,
+            sort_dir: params.sort_dir,
+        }
+    }
+}
+
 pub async fn search_hf_models(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let query = require_str_param(params, "query", "query")?;
     let kind = get_str_param(params, "kind", "kind");
@@ -74,8 +111,15 @@ pub async fn search_models_fts(state: &AppState, params: &Value) -> pumas_librar
     let query = require_str_param(params, "query", "query")?;
     let limit = get_i64_param(params, "limit", "limit").unwrap_or(100) as usize;
     let offset = get_i64_param(params, "offset", "offset").unwrap_or(0) as usize;
+    let include_incomplete =
+        get_bool_param(params, "include_incomplete", "includeIncomplete").unwrap_or(false);
+    let options: SearchOptionsParams = parse_params("search_models_fts", params)?;
 
-    match state.api.search_models(&query, limit, offset).await {
+    match state
+        .api
+        .search_models_with_options(&query, limit, offset, include_incomplete, options.into())
+        .await
+    {
         Ok(result) => Ok(json!({
             "success": true,
             "models": result.models,
@@ -94,6 +94,7 @@ async fn gateway_test_state_with_clients(
             pumas_app_manager::OllamaHttpClients::new().unwrap(),
         ),
         onnx_session_manager,
+        readiness: Arc::default(),
     });
     (temp_dir, state)
 }
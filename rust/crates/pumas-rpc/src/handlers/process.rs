@@ -1,8 +1,9 @@
 //! Process management handlers.
 
 use super::{
-    get_str_param, get_version_manager, parse_params, path_exists,
+    get_bool_param, get_str_param, get_version_manager, parse_params, path_exists,
     sync_version_paths_to_process_manager, validate_existing_local_path, validate_external_url,
+    version_manager_unavailable,
 };
 use crate::server::AppState;
 use serde::Deserialize;
@@ -52,11 +53,28 @@ pub async fn launch_comfyui(state: &AppState, _params: &Value) -> pumas_library:
     } else {
         Ok(json!({
             "success": false,
-            "error": "Version manager not initialized for comfyui"
+            "error": version_manager_unavailable(state, "comfyui").to_string()
         }))
     }
 }
 
+pub async fn set_native_process_title(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let enabled = get_bool_param(params, "enabled", "enabled").ok_or_else(|| {
+        pumas_library::PumasError::InvalidParams {
+            message: "Missing required parameter: enabled".to_string(),
+        }
+    })?;
+    let active = state.api.set_native_process_title(enabled).await;
+    Ok(json!({
+        "success": true,
+        "enabled": active,
+        "supported": pumas_library::platform::supports_command_title()
+    }))
+}
+
 pub async fn launch_ollama(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
     // Get the active version from ollama version_manager and launch it
     info!("launch_ollama: checking for ollama version manager");
@@ -85,7 +103,7 @@ pub async fn launch_ollama(state: &AppState, _params: &Value) -> pumas_library::
         warn!("launch_ollama: version manager not initialized");
         Ok(json!({
             "success": false,
-            "error": "Version manager not initialized for ollama"
+            "error": version_manager_unavailable(state, "ollama").to_string()
         }))
     }
 }
@@ -108,9 +126,24 @@ pub async fn launch_torch(state: &AppState, _params: &Value) -> pumas_library::R
         if let Some(tag) = active {
             let version_dir = vm.version_path(&tag);
             info!("launch_torch: launching tag={} from {:?}", tag, version_dir);
-            let response = state.api.launch_torch(&tag, &version_dir).await?;
+            let saved_config = super::torch::saved_torch_config(state).await;
+            let address = saved_config.clone().unwrap_or_default();
+            let response = state
+                .api
+                .launch_torch_at(&tag, &version_dir, &address.host, address.api_port)
+                .await?;
             info!("launch_torch: result success={}", response.success);
-            Ok(serde_json::to_value(response)?)
+            let preloaded = match saved_config {
+                Some(config) if response.success && response.ready == Some(true) => {
+                    super::torch::restore_torch_config(state, &config).await
+                }
+                _ => Vec::new(),
+            };
+            let mut value = serde_json::to_value(response)?;
+            if !preloaded.is_empty() {
+                value["preloaded"] = serde_json::to_value(preloaded)?;
+            }
+            Ok(value)
         } else {
             warn!("launch_torch: no active version set");
             Ok(json!({
@@ -122,7 +155,7 @@ pub async fn launch_torch(state: &AppState, _params: &Value) -> pumas_library::R
         warn!("launch_torch: version manager not initialized");
         Ok(json!({
             "success": false,
-            "error": "Version manager not initialized for torch"
+            "error": version_manager_unavailable(state, "torch").to_string()
         }))
     }
 }
@@ -175,7 +208,7 @@ pub async fn open_active_install(state: &AppState, params: &Value) -> pumas_libr
         }
     } else {
         Ok(
-            json!({"success": false, "error": format!("Version manager not initialized for app: {}", app_id_str)}),
+            json!({"success": false, "error": version_manager_unavailable(state, app_id_str).to_string()}),
         )
     }
 }
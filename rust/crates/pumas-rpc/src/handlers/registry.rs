@@ -0,0 +1,374 @@
+//! Method registry for the JSON-RPC dispatcher.
+//!
+//! Maps method names to handlers so the supported surface can be enumerated
+//! (`get_capabilities`) and unknown methods can suggest close matches.
+
+use super::{
+    conversion, custom_nodes, links, models, ollama, plugins, process, runtime_profiles, serving,
+    shortcuts, status, torch, versions,
+};
+use crate::server::AppState;
+use futures::future::BoxFuture;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Handler signature shared by every dispatched method.
+pub(super) type RpcHandler =
+    for<'a> fn(&'a AppState, &'a Value) -> BoxFuture<'a, pumas_library::Result<Value>>;
+
+/// Methods answered by `handle_json_rpc` before dispatch.
+const TRANSPORT_METHODS: &[&str] = &["health_check", "shutdown"];
+
+/// Maximum edit distance for a did-you-mean suggestion.
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+macro_rules! rpc_methods {
+    ($($name:literal => $handler:path,)*) => {
+        &[$(($name, (|state, params| Box::pin($handler(state, params))) as RpcHandler),)*]
+    };
+}
+
+fn method_table() -> &'static [(&'static str, RpcHandler)] {
+    rpc_methods! {
+        // Status & System
+        "get_status" => status::get_status,
+        "get_disk_space" => status::get_disk_space,
+        "get_system_resources" => status::get_system_resources,
+        "get_status_telemetry_snapshot" => status::get_status_telemetry_snapshot,
+        "get_launcher_version" => status::get_launcher_version,
+        "check_launcher_updates" => status::check_launcher_updates,
+        "preview_launcher_update" => status::preview_launcher_update,
+        "apply_launcher_update" => status::apply_launcher_update,
+        "stage_launcher_update" => status::stage_launcher_update,
+        "build_launcher_update" => status::build_launcher_update,
+        "restart_launcher" => status::restart_launcher,
+        "get_sandbox_info" => status::get_sandbox_info,
+        "check_git" => status::check_git,
+        "check_brave" => status::check_brave,
+        "check_setproctitle" => status::check_setproctitle,
+        "get_network_status" => status::get_network_status,
+        "get_health_summary" => status::get_health_summary,
+        "set_global_bandwidth_limit" => status::set_global_bandwidth_limit,
+        "get_library_status" => status::get_library_status,
+        "get_app_status" => status::get_app_status,
+        "get_capabilities" => status::get_capabilities,
+        "get_readiness" => status::get_readiness,
+        "diagnose_and_repair" => status::diagnose_and_repair,
+        "run_maintenance" => status::run_maintenance,
+        "generate_diagnostic_bundle" => status::generate_diagnostic_bundle,
+
+        // Local Runtime Profiles
+        "get_runtime_profiles_snapshot" => runtime_profiles::get_runtime_profiles_snapshot,
+        "list_runtime_profile_updates_since" => runtime_profiles::list_runtime_profile_updates_since,
+        "upsert_runtime_profile" => runtime_profiles::upsert_runtime_profile,
+        "delete_runtime_profile" => runtime_profiles::delete_runtime_profile,
+        "set_model_runtime_route" => runtime_profiles::set_model_runtime_route,
+        "clear_model_runtime_route" => runtime_profiles::clear_model_runtime_route,
+        "launch_runtime_profile" => runtime_profiles::launch_runtime_profile,
+        "stop_runtime_profile" => runtime_profiles::stop_runtime_profile,
+
+        // User-Directed Serving
+        "get_serving_status" => serving::get_serving_status,
+        "list_serving_status_updates_since" => serving::list_serving_status_updates_since,
+        "validate_model_serving_config" => serving::validate_model_serving_config,
+        "serve_model" => serving::serve_model,
+        "unserve_model" => serving::unserve_model,
+
+        // Version Management
+        "get_available_versions" => versions::get_available_versions,
+        "get_installed_versions" => versions::get_installed_versions,
+        "get_active_version" => versions::get_active_version,
+        "get_default_version" => versions::get_default_version,
+        "set_default_version" => versions::set_default_version,
+        "switch_version" => versions::switch_version,
+        "install_version" => versions::install_version,
+        "remove_version" => versions::remove_version,
+        "uninstall_app" => versions::uninstall_app,
+        "cancel_installation" => versions::cancel_installation,
+        "get_installation_progress" => versions::get_installation_progress,
+        "validate_installations" => versions::validate_installations,
+        "get_version_status" => versions::get_version_status,
+        "get_version_info" => versions::get_version_info,
+        "get_release_size_info" => versions::get_release_size_info,
+        "get_release_size_breakdown" => versions::get_release_size_breakdown,
+        "calculate_release_size" => versions::calculate_release_size,
+        "calculate_all_release_sizes" => versions::calculate_all_release_sizes,
+        "get_reclaimable_size" => versions::get_reclaimable_size,
+        "has_background_fetch_completed" => versions::has_background_fetch_completed,
+        "reset_background_fetch_flag" => versions::reset_background_fetch_flag,
+        "get_github_cache_status" => versions::get_github_cache_status,
+        "check_version_dependencies" => versions::check_version_dependencies,
+        "install_version_dependencies" => versions::install_version_dependencies,
+        "get_release_dependencies" => versions::get_release_dependencies,
+        "is_patched" => versions::is_patched,
+        "get_patch_state" => versions::get_patch_state,
+        "toggle_patch" => versions::toggle_patch,
+        "restore_patch_backup" => versions::restore_patch_backup,
+        "apply_patch_to_all" => versions::apply_patch_to_all,
+
+        // Model Library
+        "get_models" => models::get_models,
+        "refresh_model_index" => models::refresh_model_index,
+        "start_rebuild_model_index" => models::start_rebuild_model_index,
+        "get_rebuild_progress" => models::get_rebuild_progress,
+        "cancel_rebuild_model_index" => models::cancel_rebuild_model_index,
+        "refresh_model_mappings" => models::refresh_model_mappings,
+        "import_model" => models::import_model,
+        "validate_import_spec" => models::validate_import_spec,
+        "preview_model_path" => models::preview_model_path,
+        "start_import" => models::start_import,
+        "get_import_progress" => models::get_import_progress,
+        "download_model_from_hf" => models::download_model_from_hf,
+        "start_model_download_from_hf" => models::start_model_download_from_hf,
+        "get_model_download_status" => models::get_model_download_status,
+        "cancel_model_download" => models::cancel_model_download,
+        "pause_model_download" => models::pause_model_download,
+        "resume_model_download" => models::resume_model_download,
+        "reorder_model_download" => models::reorder_model_download,
+        "list_model_downloads" => models::list_model_downloads,
+        "list_interrupted_downloads" => models::list_interrupted_downloads,
+        "recover_download" => models::recover_download,
+        "resume_partial_download" => models::resume_partial_download,
+        "search_hf_models" => models::search_hf_models,
+        "get_hf_download_details" => models::get_hf_download_details,
+        "get_related_models" => models::get_related_models,
+        "search_models_fts" => models::search_models_fts,
+        "import_batch" => models::import_batch,
+        "import_external_diffusers_directory" => models::import_external_diffusers_directory,
+        "classify_model_import_paths" => models::classify_model_import_paths,
+        "lookup_hf_metadata_for_file" => models::lookup_hf_metadata_for_file,
+        "lookup_hf_metadata_for_bundle_directory" => models::lookup_hf_metadata_for_bundle_directory,
+        "detect_sharded_sets" => models::detect_sharded_sets,
+        "validate_file_type" => models::validate_file_type,
+        "get_embedded_metadata" => models::get_embedded_metadata,
+        "get_library_model_metadata" => models::get_library_model_metadata,
+        "resolve_model_execution_descriptor" => models::resolve_model_execution_descriptor,
+        "resolve_model_artifact_load_target" => models::resolve_model_artifact_load_target,
+        "resolve_model_package_facts" => models::resolve_model_package_facts,
+        "list_model_library_updates_since" => models::list_model_library_updates_since,
+        "resolve_model_package_facts_summary" => models::resolve_model_package_facts_summary,
+        "model_package_facts_summary_snapshot" => models::model_package_facts_summary_snapshot,
+        "refetch_model_metadata_from_hf" => models::refetch_model_metadata_from_hf,
+        "adopt_orphan_models" => models::adopt_orphan_models,
+        "import_model_in_place" => models::import_model_in_place,
+        "scan_shared_storage" => models::scan_shared_storage,
+        "security_audit" => models::security_audit,
+        "verify_local_model" => models::verify_local_model,
+
+        // Inference Settings
+        "get_inference_settings" => models::get_inference_settings,
+        "update_inference_settings" => models::update_inference_settings,
+        "update_model_notes" => models::update_model_notes,
+        "update_model_tags" => models::update_model_tags,
+        "resolve_model_dependency_requirements" => models::resolve_model_dependency_requirements,
+        "audit_dependency_pin_compliance" => models::audit_dependency_pin_compliance,
+        "list_models_needing_review" => models::list_models_needing_review,
+        "submit_model_review" => models::submit_model_review,
+        "reset_model_review" => models::reset_model_review,
+        "generate_model_migration_dry_run_report" => models::generate_model_migration_dry_run_report,
+        "execute_model_migration" => models::execute_model_migration,
+        "list_model_migration_reports" => models::list_model_migration_reports,
+        "delete_model_migration_report" => models::delete_model_migration_report,
+        "prune_model_migration_reports" => models::prune_model_migration_reports,
+
+        // HuggingFace Authentication
+        "set_hf_token" => models::set_hf_token,
+        "clear_hf_token" => models::clear_hf_token,
+        "get_hf_auth_status" => models::get_hf_auth_status,
+
+        // Process Management
+        "is_comfyui_running" => process::is_comfyui_running,
+        "stop_comfyui" => process::stop_comfyui,
+        "launch_comfyui" => process::launch_comfyui,
+        "set_native_process_title" => process::set_native_process_title,
+        "launch_ollama" => process::launch_ollama,
+        "stop_ollama" => process::stop_ollama,
+        "is_ollama_running" => process::is_ollama_running,
+        "launch_torch" => process::launch_torch,
+        "stop_torch" => process::stop_torch,
+        "is_torch_running" => process::is_torch_running,
+        "open_path" => process::open_path,
+        "open_url" => process::open_url,
+        "open_active_install" => process::open_active_install,
+
+        // Ollama Model Management
+        "ollama_list_models" => ollama::ollama_list_models,
+        "ollama_list_models_for_profile" => ollama::ollama_list_models_for_profile,
+        "ollama_create_model" => ollama::ollama_create_model,
+        "ollama_create_model_for_profile" => ollama::ollama_create_model_for_profile,
+        "ollama_delete_model" => ollama::ollama_delete_model,
+        "ollama_delete_model_for_profile" => ollama::ollama_delete_model_for_profile,
+        "ollama_load_model" => ollama::ollama_load_model,
+        "ollama_load_model_for_profile" => ollama::ollama_load_model_for_profile,
+        "ollama_unload_model" => ollama::ollama_unload_model,
+        "ollama_unload_model_for_profile" => ollama::ollama_unload_model_for_profile,
+        "ollama_list_running" => ollama::ollama_list_running,
+
+        // Torch Inference Server
+        "torch_list_slots" => torch::torch_list_slots,
+        "torch_load_model" => torch::torch_load_model,
+        "torch_unload_model" => torch::torch_unload_model,
+        "torch_get_status" => torch::torch_get_status,
+        "torch_list_devices" => torch::torch_list_devices,
+        "torch_configure" => torch::torch_configure,
+        "torch_get_saved_config" => torch::torch_get_saved_config,
+
+        // Link Management
+        "get_link_health" => links::get_link_health,
+        "clean_broken_links" => links::clean_broken_links,
+        "remove_orphaned_links" => links::remove_orphaned_links,
+        "get_links_for_model" => links::get_links_for_model,
+        "delete_model_with_cascade" => links::delete_model_with_cascade,
+        "preview_model_mapping" => links::preview_model_mapping,
+        "apply_model_mapping" => links::apply_model_mapping,
+        "sync_models_incremental" => links::sync_models_incremental,
+        "validate_mapping_configs" => links::validate_mapping_configs,
+        "set_auto_sync" => links::set_auto_sync,
+        "get_auto_sync" => links::get_auto_sync,
+        "get_default_link_type" => links::get_default_link_type,
+        "set_default_link_type" => links::set_default_link_type,
+        "sync_with_resolutions" => links::sync_with_resolutions,
+        "get_cross_filesystem_warning" => links::get_cross_filesystem_warning,
+        "get_file_link_count" => links::get_file_link_count,
+        "check_files_writable" => links::check_files_writable,
+        "set_model_link_exclusion" => links::set_model_link_exclusion,
+        "get_link_exclusions" => links::get_link_exclusions,
+
+        // Shortcuts
+        "get_version_shortcuts" => shortcuts::get_version_shortcuts,
+        "get_all_shortcut_states" => shortcuts::get_all_shortcut_states,
+        "toggle_menu" => shortcuts::toggle_menu,
+        "toggle_desktop" => shortcuts::toggle_desktop,
+        "menu_exists" => shortcuts::menu_exists,
+        "desktop_exists" => shortcuts::desktop_exists,
+        "install_icon" => shortcuts::install_icon,
+        "create_menu_shortcut" => shortcuts::create_menu_shortcut,
+        "create_desktop_shortcut" => shortcuts::create_desktop_shortcut,
+        "remove_menu_shortcut" => shortcuts::remove_menu_shortcut,
+        "remove_desktop_shortcut" => shortcuts::remove_desktop_shortcut,
+
+        // Conversion
+        "start_model_conversion" => conversion::start_model_conversion,
+        "get_conversion_progress" => conversion::get_conversion_progress,
+        "cancel_model_conversion" => conversion::cancel_model_conversion,
+        "list_model_conversions" => conversion::list_model_conversions,
+        "check_conversion_environment" => conversion::check_conversion_environment,
+        "setup_conversion_environment" => conversion::setup_conversion_environment,
+        "get_supported_quant_types" => conversion::get_supported_quant_types,
+        "get_backend_status" => conversion::get_backend_status,
+        "setup_quantization_backend" => conversion::setup_quantization_backend,
+
+        // Plugins
+        "get_plugins" => plugins::get_plugins,
+        "get_plugin" => plugins::get_plugin,
+        "call_plugin_endpoint" => plugins::call_plugin_endpoint,
+        "check_plugin_health" => plugins::check_plugin_health,
+
+        // Custom Nodes
+        "get_custom_nodes" => custom_nodes::get_custom_nodes,
+        "install_custom_node" => custom_nodes::install_custom_node,
+        "update_custom_node" => custom_nodes::update_custom_node,
+        "remove_custom_node" => custom_nodes::remove_custom_node,
+    }
+}
+
+fn registry() -> &'static HashMap<&'static str, RpcHandler> {
+    static REGISTRY: OnceLock<HashMap<&'static str, RpcHandler>> = OnceLock::new();
+    REGISTRY.get_or_init(|| method_table().iter().copied().collect())
+}
+
+/// Look up the handler for a dispatched method.
+pub(super) fn lookup(method: &str) -> Option<RpcHandler> {
+    registry().get(method).copied()
+}
+
+/// Every JSON-RPC method this server answers, in registration order.
+pub(crate) fn method_names() -> Vec<&'static str> {
+    TRANSPORT_METHODS
+        .iter()
+        .copied()
+        .chain(method_table().iter().map(|(name, _)| *name))
+        .collect()
+}
+
+/// Closest known method name to `method`, if any is near enough to be a typo.
+pub(super) fn suggest(method: &str) -> Option<&'static str> {
+    TRANSPORT_METHODS
+        .iter()
+        .copied()
+        .chain(method_table().iter().map(|(name, _)| *name))
+        .map(|name| (edit_distance(method, name), name))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+/// Error message for an unknown method, with a suggestion when available.
+pub(super) fn method_not_found_message(method: &str) -> String {
+    match suggest(method) {
+        Some(suggestion) => format!(
+            "Method not found: {} (did you mean '{}'?)",
+            method, suggestion
+        ),
+        None => format!("Method not found: {}", method),
+    }
+}
+
+/// Levenshtein distance between two method names.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn method_names_are_unique() {
+        let names = method_names();
+        let unique: HashSet<_> = names.iter().collect();
+        assert_eq!(unique.len(), names.len());
+        assert!(names.contains(&"get_capabilities"));
+        assert!(names.contains(&"shutdown"));
+    }
+
+    #[test]
+    fn lookup_skips_transport_methods() {
+        assert!(lookup("get_status").is_some());
+        assert!(lookup("shutdown").is_none());
+        assert!(lookup("no_such_method").is_none());
+    }
+
+    #[test]
+    fn unknown_method_suggests_close_match() {
+        assert_eq!(suggest("get_statuss"), Some("get_status"));
+        assert_eq!(suggest("torch_lod_model"), Some("torch_load_model"));
+        assert_eq!(suggest("completely_unrelated_name"), None);
+        assert_eq!(
+            method_not_found_message("get_disk_spcae"),
+            "Method not found: get_disk_spcae (did you mean 'get_disk_space'?)"
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+}
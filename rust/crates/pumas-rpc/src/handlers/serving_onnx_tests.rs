@@ -40,6 +40,7 @@ async fn serving_test_state() -> (TempDir, AppState) {
                 pumas_app_manager::OllamaHttpClients::new().unwrap(),
             ),
             onnx_session_manager,
+            readiness: Arc::default(),
         },
     )
 }
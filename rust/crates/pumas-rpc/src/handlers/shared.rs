@@ -247,9 +247,45 @@ pub(crate) async fn require_version_manager(
 ) -> pumas_library::Result<VersionManager> {
     get_version_manager(state, app_id)
         .await
-        .ok_or_else(|| pumas_library::PumasError::Config {
-            message: format!("Version manager not initialized for app: {}", app_id),
-        })
+        .ok_or_else(|| version_manager_unavailable(state, app_id))
+}
+
+/// Like [`get_version_manager`], but errors when the manager failed to
+/// initialize at startup. Apps that simply have no manager still yield `None`
+/// so read-only handlers can return their empty defaults.
+pub(crate) async fn lookup_version_manager(
+    state: &AppState,
+    app_id: &str,
+) -> pumas_library::Result<Option<VersionManager>> {
+    if let Some(vm) = get_version_manager(state, app_id).await {
+        return Ok(Some(vm));
+    }
+    match version_manager_failure(state, app_id) {
+        Some(_) => Err(version_manager_unavailable(state, app_id)),
+        None => Ok(None),
+    }
+}
+
+/// Startup error recorded for an app's version manager, if it failed.
+pub(crate) fn version_manager_failure(state: &AppState, app_id: &str) -> Option<String> {
+    state
+        .readiness
+        .failure(&format!("version_manager:{}", app_id))
+}
+
+/// Error explaining why an app's version manager is not available.
+pub(crate) fn version_manager_unavailable(
+    state: &AppState,
+    app_id: &str,
+) -> pumas_library::PumasError {
+    let message = match version_manager_failure(state, app_id) {
+        Some(reason) => format!(
+            "Version manager for app {} is unavailable: failed to initialize: {}",
+            app_id, reason
+        ),
+        None => format!("Version manager not initialized for app: {}", app_id),
+    };
+    pumas_library::PumasError::Config { message }
 }
 
 pub(crate) async fn path_exists(path: &Path) -> pumas_library::Result<bool> {
@@ -418,6 +454,30 @@ mod tests {
         assert!(error.to_string().contains("Unsupported url scheme"));
     }
 
+    #[tokio::test]
+    async fn lookup_version_manager_reports_startup_failure_reason() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+        state
+            .readiness
+            .record_failure("version_manager:comfyui", "git not found");
+
+        let Err(error) = lookup_version_manager(&state, "comfyui").await else {
+            panic!("expected failed version manager to error");
+        };
+        assert!(error.to_string().contains("unavailable"));
+        assert!(error.to_string().contains("git not found"));
+
+        assert!(lookup_version_manager(&state, "ollama")
+            .await
+            .unwrap()
+            .is_none());
+        let Err(error) = require_version_manager(&state, "ollama").await else {
+            panic!("expected missing version manager to error");
+        };
+        assert!(error.to_string().contains("not initialized"));
+    }
+
     #[tokio::test]
     async fn validate_existing_local_file_path_canonicalizes_existing_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,12 +1,18 @@
 //! Status & system check handlers.
 
 use super::{
-    detect_sandbox_environment, get_bool_param, get_version_manager, require_str_param,
-    sync_version_paths_to_process_manager,
+    detect_sandbox_environment, get_bool_param, get_i64_param, get_version_manager,
+    require_str_param, sync_version_paths_to_process_manager,
 };
 use crate::server::AppState;
+use pumas_app_manager::PluginApiProxy;
 use pumas_library::models::{StatusResponse, StatusTelemetrySnapshot};
 use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::warn;
+
+/// Keeps a slow plugin stats endpoint from stalling status polling.
+const PLUGIN_STATS_TIMEOUT: Duration = Duration::from_secs(2);
 
 pub async fn get_status(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
     let response = enriched_status_response(state).await?;
@@ -97,6 +103,23 @@ pub async fn check_launcher_updates(
     Ok(serde_json::to_value(result)?)
 }
 
+pub async fn preview_launcher_update(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    match state.api.preview_launcher_update().await {
+        Ok(preview) => {
+            let mut response = serde_json::to_value(preview)?;
+            response["success"] = json!(true);
+            Ok(response)
+        }
+        Err(e) => Ok(json!({
+            "success": false,
+            "error": e.to_string()
+        })),
+    }
+}
+
 pub async fn apply_launcher_update(
     state: &AppState,
     _params: &Value,
@@ -105,6 +128,22 @@ pub async fn apply_launcher_update(
     Ok(serde_json::to_value(result)?)
 }
 
+pub async fn stage_launcher_update(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    let result = state.api.stage_launcher_update().await;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub async fn build_launcher_update(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    let result = state.api.build_launcher_update().await;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub async fn restart_launcher(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
     match state.api.restart_launcher().await {
         Ok(success) => Ok(json!({
@@ -142,26 +181,251 @@ pub async fn check_setproctitle(state: &AppState, _params: &Value) -> pumas_libr
     Ok(serde_json::to_value(result)?)
 }
 
+pub async fn diagnose_and_repair(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    let report = state.api.diagnose_and_repair().await;
+    Ok(serde_json::to_value(report)?)
+}
+
+pub async fn run_maintenance(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    let report = state.api.run_maintenance().await?;
+    Ok(serde_json::to_value(report)?)
+}
+
+pub async fn generate_diagnostic_bundle(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let dest = require_str_param(params, "dest", "dest")?;
+    let response = state
+        .api
+        .generate_diagnostic_bundle(std::path::Path::new(&dest))
+        .await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn set_global_bandwidth_limit(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let bytes_per_sec = match get_i64_param(params, "bytes_per_sec", "bytesPerSec") {
+        Some(limit) if limit < 0 => {
+            return Err(pumas_library::PumasError::InvalidParams {
+                message: "bytes_per_sec must be zero or positive".to_string(),
+            });
+        }
+        limit => limit.map(|limit| limit as u64),
+    };
+    state.api.set_global_bandwidth_limit(bytes_per_sec);
+
+    Ok(json!({
+        "success": true,
+        "bytes_per_sec": state.api.global_bandwidth_limit()
+    }))
+}
+
 pub async fn get_network_status(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
     let status = state.api.get_network_status_response().await;
     Ok(serde_json::to_value(status)?)
 }
 
+pub async fn get_health_summary(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    let summary = state.api.health_summary().await;
+    Ok(serde_json::to_value(summary)?)
+}
+
 pub async fn get_library_status(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
     let status = state.api.get_library_status().await?;
     Ok(serde_json::to_value(status)?)
 }
 
-pub async fn get_app_status(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
-    let app_id = require_str_param(params, "app_id", "appId")?;
-    let running = match app_id.as_str() {
+/// Plugin API proxy with the short timeout used for status polling.
+pub(crate) fn plugin_status_proxy(state: &AppState) -> pumas_library::Result<PluginApiProxy> {
+    Ok(PluginApiProxy::new(state.plugin_loader.clone())?.with_timeout(PLUGIN_STATS_TIMEOUT))
+}
+
+/// Whether an app is running, using its built-in check or plugin health endpoint.
+pub(crate) async fn is_app_running(state: &AppState, proxy: &PluginApiProxy, app_id: &str) -> bool {
+    match app_id {
         "comfyui" => state.api.is_comfyui_running().await,
         "ollama" => state.api.is_ollama_running().await,
         "torch" => state.api.is_torch_running().await,
-        _ => false,
+        // Plugin-defined apps are running when their health endpoint answers
+        _ => proxy.check_health(app_id).await.unwrap_or(false),
+    }
+}
+
+pub async fn get_app_status(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let app_id = require_str_param(params, "app_id", "appId")?;
+    let include_stats = get_bool_param(params, "include_stats", "includeStats").unwrap_or(true);
+    let proxy = plugin_status_proxy(state)?;
+    let running = is_app_running(state, &proxy, &app_id).await;
+    let stats = if running && include_stats {
+        proxy.poll_stats(&app_id).await
+    } else {
+        None
     };
     Ok(json!({
         "success": true,
-        "running": running
+        "running": running,
+        "stats": stats
     }))
 }
+
+pub async fn get_readiness(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    let snapshot = state.readiness.snapshot();
+    Ok(json!({
+        "success": true,
+        "ready": snapshot.ready,
+        "subsystems": snapshot.subsystems,
+        "failed": snapshot.failed
+    }))
+}
+
+pub async fn get_capabilities(state: &AppState, _params: &Value) -> pumas_library::Result<Value> {
+    let mut version_managers: Vec<String> = state
+        .version_managers
+        .read()
+        .await
+        .keys()
+        .cloned()
+        .collect();
+    version_managers.sort();
+
+    // Managers that failed at startup, keyed by app id, with the recorded error.
+    let unavailable_version_managers: serde_json::Map<String, Value> = state
+        .readiness
+        .snapshot()
+        .subsystems
+        .into_iter()
+        .filter(|status| !status.ok)
+        .filter_map(|status| {
+            let app_id = status.name.strip_prefix("version_manager:")?.to_string();
+            Some((app_id, json!(status.error)))
+        })
+        .collect();
+
+    let conversion_environment_ready = match state.api.is_conversion_environment_ready().await {
+        Ok(ready) => ready,
+        Err(err) => {
+            warn!(
+                "Failed to check conversion environment for capabilities: {}",
+                err
+            );
+            false
+        }
+    };
+
+    Ok(json!({
+        "success": true,
+        "version": env!("CARGO_PKG_VERSION"),
+        "methods": super::registry::method_names(),
+        "version_managers": version_managers,
+        "unavailable_version_managers": unavailable_version_managers,
+        "features": {
+            "hf_client": state.api.is_hf_client_enabled(),
+            "conversion_environment_ready": conversion_environment_ready,
+            "symlinks": state.api.can_create_symlinks()
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn get_capabilities_reports_methods_and_features() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+
+        let response = get_capabilities(&state, &json!({})).await.unwrap();
+
+        assert_eq!(response["success"], true);
+        assert_eq!(response["version"], env!("CARGO_PKG_VERSION"));
+        let methods = response["methods"].as_array().unwrap();
+        assert!(methods.contains(&json!("get_capabilities")));
+        assert_eq!(
+            methods.len(),
+            crate::handlers::registry::method_names().len()
+        );
+        assert_eq!(response["version_managers"], json!([]));
+        assert_eq!(response["unavailable_version_managers"], json!({}));
+        assert_eq!(response["features"]["hf_client"], false);
+        assert_eq!(
+            response["features"]["symlinks"],
+            state.api.can_create_symlinks()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_app_status_skips_stats_for_stopped_apps() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+
+        let response = get_app_status(&state, &json!({"app_id": "unknown-plugin"}))
+            .await
+            .unwrap();
+
+        assert_eq!(response["success"], true);
+        assert_eq!(response["running"], false);
+        assert!(response["stats"].is_null());
+    }
+
+    #[tokio::test]
+    async fn get_capabilities_reports_failed_version_managers() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+        state.readiness.record_ok("model_library");
+        state
+            .readiness
+            .record_failure("version_manager:comfyui", "git not found");
+
+        let response = get_capabilities(&state, &json!({})).await.unwrap();
+
+        assert_eq!(
+            response["unavailable_version_managers"],
+            json!({"comfyui": "git not found"})
+        );
+    }
+
+    #[tokio::test]
+    async fn set_global_bandwidth_limit_updates_and_clears_the_cap() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+
+        let response = set_global_bandwidth_limit(&state, &json!({"bytes_per_sec": 4096}))
+            .await
+            .unwrap();
+        assert_eq!(response["bytes_per_sec"], 4096);
+
+        let response = set_global_bandwidth_limit(&state, &json!({"bytes_per_sec": null}))
+            .await
+            .unwrap();
+        assert_eq!(response["bytes_per_sec"], Value::Null);
+
+        assert!(
+            set_global_bandwidth_limit(&state, &json!({"bytes_per_sec": -1}))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_readiness_reports_subsystem_failures() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+        state
+            .readiness
+            .record_failure("version_manager:comfyui", "git not found");
+
+        let response = get_readiness(&state, &json!({})).await.unwrap();
+
+        assert_eq!(response["ready"], false);
+        assert_eq!(response["failed"], json!(["version_manager:comfyui"]));
+        assert_eq!(response["subsystems"][0]["error"], "git not found");
+    }
+}
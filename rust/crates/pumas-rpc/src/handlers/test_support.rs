@@ -57,5 +57,6 @@ pub(crate) async fn build_test_app_state(launcher_root: &Path) -> AppState {
             pumas_app_manager::OllamaHttpClients::new().unwrap(),
         ),
         onnx_session_manager,
+        readiness: Arc::default(),
     }
 }
@@ -3,10 +3,52 @@
 use super::{get_str_param, require_str_param};
 use crate::server::AppState;
 use serde_json::{json, Value};
+use tracing::{info, warn};
 
-pub async fn torch_list_slots(_state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+/// Build a Torch client that persists configuration under `launcher-data`.
+fn torch_client(state: &AppState, connection_url: Option<&str>) -> pumas_app_manager::TorchClient {
+    pumas_app_manager::TorchClient::new(connection_url)
+        .with_config_dir(state.api.launcher_data_dir())
+}
+
+/// Read the persisted Torch configuration, if any.
+pub(super) async fn saved_torch_config(
+    state: &AppState,
+) -> Option<pumas_app_manager::TorchServerConfig> {
+    match torch_client(state, None).saved_config().await {
+        Ok(config) => config,
+        Err(err) => {
+            warn!("Failed to read saved Torch server configuration: {}", err);
+            None
+        }
+    }
+}
+
+/// Re-apply the persisted Torch configuration after the server has started
+/// on its saved address and preload any configured models.
+pub(super) async fn restore_torch_config(
+    state: &AppState,
+    config: &pumas_app_manager::TorchServerConfig,
+) -> Vec<pumas_app_manager::TorchPreloadResult> {
+    let client = torch_client(state, Some(&config.base_url()));
+    match client.restore_config(config).await {
+        Ok(()) => {
+            info!("Restored saved Torch server configuration");
+            client.preload_models(&config.preload_models).await
+        }
+        Err(err) => {
+            warn!(
+                "Failed to restore saved Torch server configuration: {}",
+                err
+            );
+            Vec::new()
+        }
+    }
+}
+
+pub async fn torch_list_slots(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let connection_url = get_str_param(params, "connection_url", "connectionUrl");
-    let client = pumas_app_manager::TorchClient::new(connection_url);
+    let client = torch_client(state, connection_url);
     let slots = client.list_slots().await?;
     Ok(json!({
         "success": true,
@@ -47,31 +89,32 @@ pub async fn torch_load_model(state: &AppState, params: &Value) -> pumas_library
         });
 
     let compute_device = pumas_app_manager::ComputeDevice::from_server_string(device);
-    let client = pumas_app_manager::TorchClient::new(connection_url);
-    let slot = client
+    let client = torch_client(state, connection_url);
+    let result = client
         .load_model(&descriptor.entry_path, &model_name, &compute_device, None)
         .await?;
 
     Ok(json!({
         "success": true,
-        "slot": slot
+        "slot": result.slot,
+        "evicted": result.evicted
     }))
 }
 
-pub async fn torch_unload_model(_state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+pub async fn torch_unload_model(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let slot_id = require_str_param(params, "slot_id", "slotId")?;
     let connection_url = get_str_param(params, "connection_url", "connectionUrl");
 
-    let client = pumas_app_manager::TorchClient::new(connection_url);
+    let client = torch_client(state, connection_url);
     client.unload_model(&slot_id).await?;
 
     Ok(json!({ "success": true }))
 }
 
-pub async fn torch_get_status(_state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+pub async fn torch_get_status(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let connection_url = get_str_param(params, "connection_url", "connectionUrl");
 
-    let client = pumas_app_manager::TorchClient::new(connection_url);
+    let client = torch_client(state, connection_url);
     let status = client.get_status().await?;
 
     Ok(json!({
@@ -80,10 +123,10 @@ pub async fn torch_get_status(_state: &AppState, params: &Value) -> pumas_librar
     }))
 }
 
-pub async fn torch_list_devices(_state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+pub async fn torch_list_devices(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let connection_url = get_str_param(params, "connection_url", "connectionUrl");
 
-    let client = pumas_app_manager::TorchClient::new(connection_url);
+    let client = torch_client(state, connection_url);
     let devices = client.list_devices().await?;
 
     Ok(json!({
@@ -92,7 +135,7 @@ pub async fn torch_list_devices(_state: &AppState, params: &Value) -> pumas_libr
     }))
 }
 
-pub async fn torch_configure(_state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+pub async fn torch_configure(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let connection_url = get_str_param(params, "connection_url", "connectionUrl");
     let config: pumas_app_manager::TorchServerConfig =
         serde_json::from_value(params.get("config").cloned().unwrap_or_default()).map_err(|e| {
@@ -101,8 +144,20 @@ pub async fn torch_configure(_state: &AppState, params: &Value) -> pumas_library
             }
         })?;
 
-    let client = pumas_app_manager::TorchClient::new(connection_url);
+    let client = torch_client(state, connection_url);
     client.configure(&config).await?;
 
     Ok(json!({ "success": true }))
 }
+
+pub async fn torch_get_saved_config(
+    state: &AppState,
+    _params: &Value,
+) -> pumas_library::Result<Value> {
+    let config = torch_client(state, None).saved_config().await?;
+
+    Ok(json!({
+        "success": true,
+        "config": config
+    }))
+}
@@ -1,8 +1,8 @@
 //! Version dependency handlers.
 
 use crate::handlers::{
-    get_str_param, get_version_manager, path_exists, read_utf8_file, require_str_param,
-    require_version_manager,
+    get_str_param, lookup_version_manager, path_exists, read_utf8_file, require_str_param,
+    require_version_manager, version_manager_unavailable,
 };
 use crate::server::AppState;
 use serde_json::Value;
@@ -35,7 +35,7 @@ pub async fn get_release_dependencies(
 ) -> pumas_library::Result<Value> {
     let tag = require_str_param(params, "tag", "tag")?;
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let version_path = vm.version_path(&tag);
         let requirements_path = version_path.join("requirements.txt");
 
@@ -64,8 +64,6 @@ pub async fn get_release_dependencies(
 
         Ok(serde_json::to_value(packages)?)
     } else {
-        Err(pumas_library::PumasError::Config {
-            message: format!("Version manager not initialized for app: {}", app_id_str),
-        })
+        Err(version_manager_unavailable(state, app_id_str))
     }
 }
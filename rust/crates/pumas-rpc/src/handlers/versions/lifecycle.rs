@@ -1,9 +1,13 @@
 //! Version lifecycle handlers.
 
+use crate::handlers::status::{is_app_running, plugin_status_proxy};
 use crate::handlers::{
-    get_str_param, get_version_manager, require_str_param, require_version_manager,
+    get_bool_param, get_str_param, lookup_version_manager, require_str_param,
+    require_version_manager, sync_version_paths_to_process_manager, version_manager_unavailable,
 };
 use crate::server::AppState;
+use pumas_app_manager::version_manager::ProgressUpdate;
+use pumas_library::LibraryEvent;
 use serde_json::{json, Value};
 use tracing::warn;
 
@@ -12,7 +16,7 @@ pub async fn get_installed_versions(
     params: &Value,
 ) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let versions = vm.get_installed_versions().await?;
         // Return raw array - wrapper.rs will add {success, versions} wrapper
         Ok(serde_json::to_value(versions)?)
@@ -23,7 +27,7 @@ pub async fn get_installed_versions(
 
 pub async fn get_active_version(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let version = vm.get_active_version().await?;
         // Return raw value - wrapper.rs will add {success, version} wrapper
         Ok(serde_json::to_value(version)?)
@@ -34,7 +38,7 @@ pub async fn get_active_version(state: &AppState, params: &Value) -> pumas_libra
 
 pub async fn get_default_version(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let version = vm.get_default_version().await?;
         // Return raw value - wrapper.rs will add {success, version} wrapper
         Ok(serde_json::to_value(version)?)
@@ -56,6 +60,15 @@ pub async fn switch_version(state: &AppState, params: &Value) -> pumas_library::
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
     let vm = require_version_manager(state, app_id_str).await?;
     let result = vm.set_active_version(&tag).await?;
+    if result && app_id_str == "comfyui" {
+        if let Some(auto_sync) = state.api.auto_sync_target() {
+            state.api.set_auto_sync(Some(pumas_library::AutoSyncTarget {
+                models_path: vm.version_path(&tag).join("models"),
+                version_tag: tag.clone(),
+                ..auto_sync
+            }));
+        }
+    }
     Ok(serde_json::to_value(result)?)
 }
 
@@ -63,12 +76,26 @@ pub async fn install_version(state: &AppState, params: &Value) -> pumas_library:
     let tag = require_str_param(params, "tag", "tag")?;
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
 
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         // Start the installation (returns a progress receiver)
         match vm.install_version(&tag).await {
-            Ok(_rx) => {
-                // Installation started successfully
-                // Progress can be monitored via get_installation_progress
+            Ok(mut rx) => {
+                // Progress can be monitored via get_installation_progress;
+                // the channel is only watched for the final status.
+                let events = state.api.library_event_sender();
+                let app_id = app_id_str.to_string();
+                let installed_tag = tag.clone();
+                tokio::spawn(async move {
+                    while let Some(update) = rx.recv().await {
+                        if let ProgressUpdate::Completed { success: true } = update {
+                            let _ = events.send(LibraryEvent::VersionInstalled {
+                                app_id,
+                                tag: installed_tag,
+                            });
+                            break;
+                        }
+                    }
+                });
                 Ok(json!({
                     "success": true,
                     "message": format!("Installation of {} started", tag)
@@ -85,7 +112,7 @@ pub async fn install_version(state: &AppState, params: &Value) -> pumas_library:
     } else {
         Ok(json!({
             "success": false,
-            "error": format!("Version manager not initialized for app: {}", app_id_str)
+            "error": version_manager_unavailable(state, app_id_str).to_string()
         }))
     }
 }
@@ -98,9 +125,98 @@ pub async fn remove_version(state: &AppState, params: &Value) -> pumas_library::
     Ok(serde_json::to_value(result)?)
 }
 
+/// Remove an app entirely: every installed version, its shortcuts, its model
+/// links, and its PID file. Models stay in the library unless `keep_models`
+/// is false, in which case models only this app linked to are deleted too.
+///
+/// Refuses while the app is running.
+pub async fn uninstall_app(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let app_id = require_str_param(params, "app_id", "appId")?;
+    let keep_models = get_bool_param(params, "keep_models", "keepModels").unwrap_or(true);
+
+    let proxy = plugin_status_proxy(state)?;
+    sync_version_paths_to_process_manager(state).await;
+    if is_app_running(state, &proxy, &app_id).await {
+        return Ok(json!({
+            "success": false,
+            "error": format!("{} is running; stop it before uninstalling", app_id)
+        }));
+    }
+
+    let mut removed_versions = Vec::new();
+    let mut errors = Vec::new();
+    let mut shortcuts_removed = 0;
+    if let Some(vm) = lookup_version_manager(state, &app_id).await? {
+        // Desktop and menu shortcuts exist only for ComfyUI versions.
+        let shortcut_manager = if app_id == "comfyui" {
+            state.shortcut_manager.read().await.clone()
+        } else {
+            None
+        };
+
+        for tag in vm.get_installed_versions().await? {
+            if let Some(sm) = shortcut_manager.clone() {
+                let shortcut_tag = tag.clone();
+                let had_shortcuts = tokio::task::spawn_blocking(move || {
+                    let before = sm.get_version_shortcut_state(&shortcut_tag);
+                    sm.remove_version_shortcuts(&shortcut_tag, true, true)
+                        .map(|_| before.menu || before.desktop)
+                })
+                .await
+                .map_err(|e| {
+                    pumas_library::PumasError::Other(format!("Shortcut removal task failed: {}", e))
+                })?;
+                match had_shortcuts {
+                    Ok(true) => shortcuts_removed += 1,
+                    Ok(false) => {}
+                    Err(e) => errors.push(format!("shortcuts for {}: {}", tag, e)),
+                }
+            }
+
+            match vm.remove_version(&tag).await {
+                Ok(true) => removed_versions.push(tag),
+                Ok(false) => errors.push(format!("version {} was not removed", tag)),
+                Err(e) => errors.push(format!("version {}: {}", tag, e)),
+            }
+        }
+    }
+
+    let links = state
+        .api
+        .remove_links_for_app(&app_id, !keep_models)
+        .await?;
+    errors.extend(links.errors);
+
+    // Plugin-launched apps track their process in `<launcher_root>/<app_id>.pid`;
+    // built-in apps keep PID files inside version directories removed above.
+    let pid_file = state.api.launcher_root().join(format!("{}.pid", app_id));
+    let pid_file_removed = match tokio::fs::remove_file(&pid_file).await {
+        Ok(()) => true,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+        Err(e) => {
+            errors.push(format!("{}: {}", pid_file.display(), e));
+            false
+        }
+    };
+
+    if !errors.is_empty() {
+        warn!("Uninstall of {} finished with errors: {:?}", app_id, errors);
+    }
+    Ok(json!({
+        "success": errors.is_empty(),
+        "app_id": app_id,
+        "removed_versions": removed_versions,
+        "shortcuts_removed": shortcuts_removed,
+        "links_removed": links.links_removed,
+        "deleted_models": links.deleted_models,
+        "pid_file_removed": pid_file_removed,
+        "errors": errors
+    }))
+}
+
 pub async fn cancel_installation(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let result = vm.cancel_installation().await?;
         Ok(serde_json::to_value(result)?)
     } else {
@@ -113,7 +229,7 @@ pub async fn get_installation_progress(
     params: &Value,
 ) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let progress = vm.get_installation_progress().await;
         Ok(serde_json::to_value(progress)?)
     } else {
@@ -128,7 +244,7 @@ pub async fn validate_installations(
     params: &Value,
 ) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let result = vm.validate_installations().await?;
         Ok(serde_json::to_value(result)?)
     } else {
@@ -139,3 +255,27 @@ pub async fn validate_installations(
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn uninstall_app_removes_plugin_pid_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = crate::handlers::test_support::build_test_app_state(temp_dir.path()).await;
+        let pid_file = state.api.launcher_root().join("echo-server.pid");
+        std::fs::write(&pid_file, "999999").unwrap();
+
+        let response = uninstall_app(&state, &json!({"app_id": "echo-server"}))
+            .await
+            .unwrap();
+
+        assert_eq!(response["success"], true, "{}", response);
+        assert_eq!(response["removed_versions"], json!([]));
+        assert_eq!(response["links_removed"], 0);
+        assert_eq!(response["pid_file_removed"], true);
+        assert!(!pid_file.exists());
+    }
+}
@@ -1,6 +1,6 @@
 //! Version patch toggle handlers.
 
-use crate::handlers::get_str_param;
+use crate::handlers::{get_bool_param, get_str_param};
 use crate::server::AppState;
 use serde_json::{json, Value};
 
@@ -10,6 +10,12 @@ pub async fn is_patched(state: &AppState, params: &Value) -> pumas_library::Resu
     Ok(json!(is_patched))
 }
 
+pub async fn get_patch_state(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let tag = get_str_param(params, "tag", "tag");
+    let patch_state = state.api.patch_state(tag).await?;
+    Ok(serde_json::to_value(patch_state)?)
+}
+
 pub async fn toggle_patch(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let tag = get_str_param(params, "tag", "tag");
     match state.api.toggle_patch(tag).await {
@@ -20,3 +26,35 @@ pub async fn toggle_patch(state: &AppState, params: &Value) -> pumas_library::Re
         })),
     }
 }
+
+pub async fn restore_patch_backup(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let tag = get_str_param(params, "tag", "tag");
+    match state.api.restore_patch_backup(tag).await {
+        Ok(()) => Ok(json!(true)),
+        Err(e) => Ok(json!({
+            "success": false,
+            "error": e.to_string()
+        })),
+    }
+}
+
+pub async fn apply_patch_to_all(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
+    let enable = get_bool_param(params, "enable", "enable").ok_or_else(|| {
+        pumas_library::PumasError::InvalidParams {
+            message: "Missing required parameter: enable".to_string(),
+        }
+    })?;
+    match state.api.apply_patch_to_all(enable).await {
+        Ok(results) => Ok(json!({
+            "success": results.iter().all(|result| result.success),
+            "results": results
+        })),
+        Err(e) => Ok(json!({
+            "success": false,
+            "error": e.to_string()
+        })),
+    }
+}
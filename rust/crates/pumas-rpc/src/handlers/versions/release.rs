@@ -1,9 +1,10 @@
 //! Release metadata, sizing, and cache handlers.
 
 use crate::handlers::{
-    get_bool_param, get_i64_param, get_str_param, get_version_manager, require_str_param,
+    get_bool_param, get_i64_param, get_str_param, lookup_version_manager, require_str_param,
 };
 use crate::server::AppState;
+use pumas_app_manager::SizeCalculator;
 use serde_json::{json, Value};
 use tracing::warn;
 
@@ -14,7 +15,7 @@ pub async fn get_available_versions(
     let force_refresh = get_bool_param(params, "force_refresh", "forceRefresh").unwrap_or(false);
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
 
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         // Handle rate limit errors specially to return structured response
         match vm.get_available_releases(force_refresh).await {
             Ok(releases) => {
@@ -51,7 +52,7 @@ pub async fn get_available_versions(
 
 pub async fn get_version_status(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         // Return version status combining active/default/installed
         let active = vm.get_active_version().await?;
         let default = vm.get_default_version().await?;
@@ -75,19 +76,26 @@ pub async fn get_version_status(state: &AppState, params: &Value) -> pumas_libra
             );
         }
 
+        // Release lookup failures (offline, rate limited) just omit the update hint
+        let newer = vm.check_for_newer(false).await.ok();
+
         // Return raw status object - wrapper.rs will add {success, status} wrapper
         Ok(json!({
             "installedCount": installed.len(),
             "activeVersion": active,
             "defaultVersion": default,
-            "versions": versions_map
+            "versions": versions_map,
+            "updateAvailable": newer.as_ref().is_some_and(|n| n.update_available),
+            "latestVersion": newer.and_then(|n| n.latest_version)
         }))
     } else {
         Ok(json!({
             "installedCount": 0,
             "activeVersion": null,
             "defaultVersion": null,
-            "versions": {}
+            "versions": {},
+            "updateAvailable": false,
+            "latestVersion": null
         }))
     }
 }
@@ -95,7 +103,7 @@ pub async fn get_version_status(state: &AppState, params: &Value) -> pumas_libra
 pub async fn get_version_info(state: &AppState, params: &Value) -> pumas_library::Result<Value> {
     let tag = require_str_param(params, "tag", "tag")?;
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let installed = vm.get_installed_versions().await?;
         let is_installed = installed.contains(&tag);
         Ok(json!({
@@ -170,7 +178,7 @@ pub async fn calculate_all_release_sizes(
 ) -> pumas_library::Result<Value> {
     // Get all available versions and calculate sizes
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
-    let versions = if let Some(vm) = get_version_manager(state, app_id_str).await {
+    let versions = if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let releases = vm.get_available_releases(false).await?;
         releases
             .into_iter()
@@ -199,6 +207,47 @@ pub async fn calculate_all_release_sizes(
     Ok(json!(results))
 }
 
+/// Estimate the disk space freed by removing one version (`tag`) or, without
+/// a tag, every installed version of the app.
+pub async fn get_reclaimable_size(
+    state: &AppState,
+    params: &Value,
+) -> pumas_library::Result<Value> {
+    let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
+    let tag = get_str_param(params, "tag", "tag").map(String::from);
+
+    let (tags, dirs) = match lookup_version_manager(state, app_id_str).await? {
+        Some(vm) => {
+            let tags = match &tag {
+                Some(tag) => vec![tag.clone()],
+                None => vm.get_installed_versions().await?,
+            };
+            let dirs = tags.iter().map(|tag| vm.version_path(tag)).collect();
+            (tags, dirs)
+        }
+        None => (Vec::new(), Vec::new()),
+    };
+
+    let estimate =
+        tokio::task::spawn_blocking(move || SizeCalculator::estimate_reclaimable_size(&dirs))
+            .await
+            .map_err(|e| {
+                pumas_library::PumasError::Other(format!("Size estimation task failed: {}", e))
+            })?;
+
+    Ok(json!({
+        "success": true,
+        "app_id": app_id_str,
+        "tag": tag,
+        "versions": tags,
+        "total_bytes": estimate.total_bytes,
+        "shared_bytes": estimate.shared_bytes,
+        "reclaimable_bytes": estimate.reclaimable_bytes,
+        "reclaimable_formatted": SizeCalculator::format_size(estimate.reclaimable_bytes),
+        "file_count": estimate.file_count
+    }))
+}
+
 pub async fn has_background_fetch_completed(
     state: &AppState,
     _params: &Value,
@@ -221,7 +270,7 @@ pub async fn get_github_cache_status(
 ) -> pumas_library::Result<Value> {
     let app_id_str = get_str_param(params, "app_id", "appId").unwrap_or("comfyui");
     // Return cache status in format expected by frontend
-    if let Some(vm) = get_version_manager(state, app_id_str).await {
+    if let Some(vm) = lookup_version_manager(state, app_id_str).await? {
         let cache_status = vm.get_github_cache_status().await;
         Ok(json!({
             "has_cache": cache_status.has_cache,
@@ -0,0 +1,187 @@
+//! Tracing setup: console logs on stderr plus a rotated log file.
+//!
+//! Stdout is reserved for the `RPC_PORT=` / `RPC_READY=` handshake, so no
+//! layer writes there. The file layer has its own level so users can attach
+//! a detailed `launcher-data/logs/pumas-rpc.log` to bug reports while the
+//! console stays quiet.
+
+use crate::config::RpcConfig;
+use pumas_library::config::PathsConfig;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::warn;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// Log file name under `launcher-data/logs`.
+pub const LOG_FILE_NAME: &str = "pumas-rpc.log";
+/// Size at which the log file is rotated.
+const MAX_LOG_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Rotated files kept as `pumas-rpc.log.1` (newest) to `pumas-rpc.log.N`.
+const ROTATED_LOG_FILES: usize = 5;
+
+/// Install the global subscriber.
+///
+/// A log file that cannot be opened is reported on the console and skipped
+/// rather than failing startup.
+pub fn init(config: &RpcConfig, launcher_root: &Path) {
+    let console = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_target(false)
+        .with_thread_ids(false)
+        .compact()
+        .with_filter(LevelFilter::from_level(config.log_level));
+
+    let log_path = log_file_path(launcher_root);
+    let (file, file_error) = match config.file_log_level {
+        Some(level) => {
+            match RotatingLogFile::open(&log_path, MAX_LOG_FILE_BYTES, ROTATED_LOG_FILES) {
+                Ok(writer) => (
+                    Some(
+                        tracing_subscriber::fmt::layer()
+                            .with_writer(Mutex::new(writer))
+                            .with_ansi(false)
+                            .with_filter(LevelFilter::from_level(level)),
+                    ),
+                    None,
+                ),
+                Err(err) => (None, Some(err)),
+            }
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(console)
+        .with(file)
+        .init();
+
+    if let Some(err) = file_error {
+        warn!("Failed to open log file {}: {}", log_path.display(), err);
+    }
+}
+
+/// Location of the RPC server log file for a launcher root.
+pub fn log_file_path(launcher_root: &Path) -> PathBuf {
+    launcher_root
+        .join("launcher-data")
+        .join(PathsConfig::LOGS_DIR_NAME)
+        .join(LOG_FILE_NAME)
+}
+
+/// Append-only log file that rotates by size.
+///
+/// When a write would push the file past `max_bytes`, existing rotations shift
+/// up by one (the oldest is dropped) and a fresh file is started. A failed
+/// rotation keeps appending to the current file instead of losing lines.
+#[derive(Debug)]
+pub struct RotatingLogFile {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingLogFile {
+    /// Open `path` for appending, creating its directory if needed.
+    pub fn open(path: &Path, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = open_append(path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            len,
+            max_bytes,
+            keep,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            self.file.set_len(0)?;
+            self.len = 0;
+            return Ok(());
+        }
+        for index in (1..self.keep).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))?;
+        self.file = open_append(&self.path)?;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.len > 0 && self.len + buf.len() as u64 > self.max_bytes {
+            if let Err(err) = self.rotate() {
+                eprintln!("Failed to rotate {}: {}", self.path.display(), err);
+            }
+        }
+        let written = self.file.write(buf)?;
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn rotating_log_file_shifts_old_logs_and_drops_oldest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("logs").join(LOG_FILE_NAME);
+        let mut log = RotatingLogFile::open(&path, 8, 2).unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            log.write_all(line.as_bytes()).unwrap();
+        }
+
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(path.clone()), "fourth\n");
+        assert_eq!(read(log.rotated_path(1)), "third\n");
+        assert_eq!(read(log.rotated_path(2)), "second\n");
+        assert!(!log.rotated_path(3).exists());
+    }
+
+    #[test]
+    fn rotating_log_file_appends_to_existing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(LOG_FILE_NAME);
+        std::fs::write(&path, "earlier\n").unwrap();
+
+        let mut log = RotatingLogFile::open(&path, 1024, 2).unwrap();
+        log.write_all(b"later\n").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "earlier\nlater\n");
+    }
+}
@@ -3,22 +3,28 @@
 //! This binary provides a JSON-RPC 2.0 server that wraps the pumas-core library
 //! for communication with the Electron main process.
 
+mod config;
 mod handlers;
+mod logging;
 mod provider_clients;
+mod readiness;
 mod server;
 mod shortcut;
 mod wrapper;
 
 use anyhow::Result;
 use clap::Parser;
+use config::{CliOverrides, FileConfig, RpcConfig};
 use pumas_app_manager::{CustomNodesManager, SizeCalculator, VersionManager};
+use pumas_library::config::PathsConfig;
 use pumas_library::{AppId, PluginLoader};
+use readiness::StartupReadiness;
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::runtime::Builder;
-use tracing::{info, warn, Level};
-use tracing_subscriber::FmtSubscriber;
+use tracing::{info, warn};
 
 const RPC_WORKER_THREADS: usize = 4;
 const RPC_MAX_BLOCKING_THREADS: usize = 16;
@@ -29,13 +35,17 @@ const VERSION_MANAGED_APPS: &[AppId] =
 #[command(name = "pumas-rpc")]
 #[command(about = "JSON-RPC server for Pumas Library")]
 struct Args {
+    /// Path to a `pumas-rpc.toml` config file; flags override its values
+    #[arg(long)]
+    config: Option<PathBuf>,
+
     /// Port to listen on (0 = auto-assign)
-    #[arg(short, long, default_value = "0")]
-    port: u16,
+    #[arg(short, long)]
+    port: Option<u16>,
 
-    /// Host to bind to
-    #[arg(long, default_value = "127.0.0.1")]
-    host: String,
+    /// Host to bind to (default: 127.0.0.1)
+    #[arg(long)]
+    host: Option<String>,
 
     /// Allow binding the RPC listener to a non-loopback interface.
     #[arg(long)]
@@ -45,27 +55,47 @@ struct Args {
     #[arg(short, long)]
     debug: bool,
 
-    /// Launcher root directory (defaults to current directory's parent)
+    /// Level for launcher-data/logs/pumas-rpc.log, or `off` (default: info)
+    #[arg(long)]
+    file_log_level: Option<String>,
+
+    /// Launcher root directory (defaults to $PUMAS_LIBRARY_ROOT, then the current directory's parent)
     #[arg(long)]
     launcher_root: Option<PathBuf>,
+
+    /// Model library directory (defaults to $PUMAS_MODELS_DIR, then <launcher-root>/shared-resources/models)
+    #[arg(long)]
+    models_dir: Option<PathBuf>,
+}
+
+impl Args {
+    /// Load the optional config file and apply CLI overrides on top of it.
+    fn into_config(self) -> Result<RpcConfig> {
+        let file = match &self.config {
+            Some(path) => FileConfig::load(path)?,
+            None => FileConfig::default(),
+        };
+        RpcConfig::resolve(
+            file,
+            CliOverrides {
+                launcher_root: self.launcher_root,
+                models_dir: self.models_dir,
+                host: self.host,
+                port: self.port,
+                allow_lan: self.allow_lan,
+                debug: self.debug,
+                file_log_level: self.file_log_level,
+            },
+        )
+    }
 }
 
 fn main() -> Result<()> {
-    let args = Args::parse();
-    validate_rpc_host(&args.host, args.allow_lan)?;
-
-    // Set up logging
-    let log_level = if args.debug {
-        Level::DEBUG
-    } else {
-        Level::INFO
-    };
-    FmtSubscriber::builder()
-        .with_max_level(log_level)
-        .with_target(false)
-        .with_thread_ids(false)
-        .compact()
-        .init();
+    let config = Args::parse().into_config()?;
+    validate_rpc_host(&config.host, config.allow_lan)?;
+
+    let launcher_root = resolve_launcher_root(config.launcher_root.clone())?;
+    logging::init(&config, &launcher_root);
 
     let runtime = Builder::new_multi_thread()
         .enable_all()
@@ -74,47 +104,62 @@ fn main() -> Result<()> {
         .thread_name("pumas-rpc")
         .build()?;
 
-    runtime.block_on(run(args))
+    runtime.block_on(run(config, launcher_root))
 }
 
-async fn run(args: Args) -> Result<()> {
-    info!("Starting Pumas RPC Server");
-
-    // Determine launcher root
-    let launcher_root = match args.launcher_root {
-        Some(path) => path,
-        None => {
-            // Default: assume we're in rust/target/*/pumas-rpc, go up to find project root
-            let exe_path = std::env::current_exe()?;
-            let mut path = exe_path.parent().unwrap().to_path_buf();
-
-            // Navigate up from target directory to find project root
-            while path.file_name().map(|n| n != "rust").unwrap_or(false) {
-                if let Some(parent) = path.parent() {
-                    path = parent.to_path_buf();
-                } else {
-                    break;
+/// Launcher root from the config, `PUMAS_LIBRARY_ROOT`, or the binary location.
+fn resolve_launcher_root(configured: Option<PathBuf>) -> Result<PathBuf> {
+    Ok(
+        match configured.or_else(PathsConfig::library_root_override) {
+            Some(path) => path,
+            None => {
+                // Default: assume we're in rust/target/*/pumas-rpc, go up to find project root
+                let exe_path = std::env::current_exe()?;
+                let mut path = exe_path.parent().unwrap().to_path_buf();
+
+                // Navigate up from target directory to find project root
+                while path.file_name().map(|n| n != "rust").unwrap_or(false) {
+                    if let Some(parent) = path.parent() {
+                        path = parent.to_path_buf();
+                    } else {
+                        break;
+                    }
                 }
-            }
 
-            // Go up one more level from rust/ to project root
-            path.parent()
-                .map(|p| p.to_path_buf())
-                .unwrap_or_else(|| std::env::current_dir().unwrap())
-        }
-    };
+                // Go up one more level from rust/ to project root
+                path.parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| std::env::current_dir().unwrap())
+            }
+        },
+    )
+}
 
+async fn run(config: RpcConfig, launcher_root: PathBuf) -> Result<()> {
+    info!("Starting Pumas RPC Server");
     info!("Launcher root: {}", launcher_root.display());
 
     // Create the core API instance (model library, system utilities)
     // Use builder with auto_create_dirs so first-run (e.g. portable AppImage)
     // creates the directory structure automatically.
-    let api = pumas_library::PumasApi::builder(&launcher_root)
-        .auto_create_dirs(true)
-        .build()
-        .await?;
+    let readiness = Arc::new(StartupReadiness::new());
+    let mut builder = pumas_library::PumasApi::builder(&launcher_root).auto_create_dirs(true);
+    if let Some(models_dir) = &config.models_dir {
+        let models_dir = std::path::absolute(models_dir)?;
+        info!("Model library: {}", models_dir.display());
+        builder = builder.with_model_library_dir(models_dir);
+    }
+    if let Some(token) = &config.hf_token {
+        builder = builder.with_hf_auth_token(token.clone());
+    }
+    if let Some(concurrency) = config.download_concurrency {
+        builder = builder.with_max_active_downloads(concurrency);
+    }
+    let api = builder.build().await?;
+    readiness.record_ok("model_library");
 
-    let version_managers = initialize_version_managers(&launcher_root).await;
+    let version_managers =
+        initialize_version_managers(&launcher_root, &api.http_settings(), &readiness).await;
     info!("Initialized {} version manager(s)", version_managers.len());
 
     // Initialize custom nodes manager
@@ -123,7 +168,7 @@ async fn run(args: Args) -> Result<()> {
     info!("Custom nodes manager initialized");
 
     // Initialize size calculator
-    let cache_dir = launcher_root.join("launcher-data").join("cache");
+    let cache_dir = PathsConfig::cache_dir(&launcher_root);
     let size_calculator = SizeCalculator::new_with_cache(cache_dir).await;
     info!("Size calculator initialized");
 
@@ -132,6 +177,7 @@ async fn run(args: Args) -> Result<()> {
     let plugin_loader = match PluginLoader::new_async(plugins_dir.clone()).await {
         Ok(loader) => {
             info!("Plugin loader initialized ({} plugins)", loader.count());
+            readiness.record_ok("plugin_loader");
             loader
         }
         Err(e) => {
@@ -139,6 +185,7 @@ async fn run(args: Args) -> Result<()> {
                 "Failed to initialize plugin loader: {}, using empty loader",
                 e
             );
+            readiness.record_failure("plugin_loader", e);
             PluginLoader::new_async(std::env::temp_dir().join("pumas-plugins-fallback"))
                 .await
                 .unwrap()
@@ -153,8 +200,9 @@ async fn run(args: Args) -> Result<()> {
         size_calculator,
         plugin_loader,
         launcher_root,
-        &args.host,
-        args.port,
+        &config.host,
+        config.port,
+        readiness.clone(),
     )
     .await?;
     let addr = server.addr();
@@ -163,6 +211,16 @@ async fn run(args: Args) -> Result<()> {
     // This format must match what python-bridge.ts expects
     println!("RPC_PORT={}", addr.port());
 
+    // Second stdout line once startup background work has finished, carrying
+    // the JSON readiness snapshot (including failed subsystems).
+    tokio::spawn(async move {
+        readiness.wait_ready().await;
+        match serde_json::to_string(&readiness.snapshot()) {
+            Ok(snapshot) => println!("RPC_READY={}", snapshot),
+            Err(err) => warn!("Failed to serialize readiness snapshot: {}", err),
+        }
+    });
+
     info!("RPC server running on {}", addr);
 
     // Wait for shutdown signal
@@ -173,17 +231,25 @@ async fn run(args: Args) -> Result<()> {
     Ok(())
 }
 
-async fn initialize_version_managers(launcher_root: &Path) -> HashMap<String, VersionManager> {
+async fn initialize_version_managers(
+    launcher_root: &Path,
+    http: &pumas_library::network::HttpSettings,
+    readiness: &StartupReadiness,
+) -> HashMap<String, VersionManager> {
     let mut version_managers = HashMap::new();
 
     for app_id in VERSION_MANAGED_APPS {
-        match VersionManager::new(launcher_root, *app_id).await {
+        let subsystem = format!("version_manager:{}", app_id.as_str());
+        match VersionManager::with_http_settings(launcher_root, app_id.clone(), http.clone()).await
+        {
             Ok(manager) => {
                 info!("{app_id} version manager initialized successfully");
+                readiness.record_ok(subsystem);
                 version_managers.insert(app_id.as_str().to_string(), manager);
             }
             Err(error) => {
                 warn!("Failed to initialize {app_id} version manager: {error}");
+                readiness.record_failure(subsystem, error);
             }
         }
     }
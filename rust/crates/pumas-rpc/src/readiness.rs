@@ -0,0 +1,179 @@
+//! Startup readiness tracking.
+//!
+//! The listener accepts requests as soon as it binds, but some subsystems
+//! finish initializing afterwards (orphan model adoption and download recovery
+//! in particular). This module records per-subsystem outcomes and flips a
+//! readiness flag once startup work is complete, so clients can wait for
+//! `RPC_READY=` on stdout or poll `get_readiness` instead of racing startup.
+
+use serde::Serialize;
+use std::sync::Mutex;
+use tokio::sync::watch;
+
+/// Initialization outcome for one startup subsystem.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SubsystemStatus {
+    pub name: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Point-in-time readiness report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessSnapshot {
+    /// True once every startup step has finished, successfully or not.
+    pub ready: bool,
+    /// Subsystems in the order they reported.
+    pub subsystems: Vec<SubsystemStatus>,
+    /// Names of subsystems that failed to initialize.
+    pub failed: Vec<String>,
+}
+
+/// Shared startup readiness state.
+pub struct StartupReadiness {
+    subsystems: Mutex<Vec<SubsystemStatus>>,
+    ready: watch::Sender<bool>,
+}
+
+impl Default for StartupReadiness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StartupReadiness {
+    pub fn new() -> Self {
+        Self {
+            subsystems: Mutex::new(Vec::new()),
+            ready: watch::Sender::new(false),
+        }
+    }
+
+    /// Record that a subsystem initialized successfully.
+    pub fn record_ok(&self, name: impl Into<String>) {
+        self.record(SubsystemStatus {
+            name: name.into(),
+            ok: true,
+            error: None,
+        });
+    }
+
+    /// Record that a subsystem failed to initialize.
+    pub fn record_failure(&self, name: impl Into<String>, error: impl ToString) {
+        self.record(SubsystemStatus {
+            name: name.into(),
+            ok: false,
+            error: Some(error.to_string()),
+        });
+    }
+
+    /// Mark startup complete.
+    pub fn mark_ready(&self) {
+        self.ready.send_replace(true);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        *self.ready.borrow()
+    }
+
+    /// Wait until [`mark_ready`](Self::mark_ready) has been called.
+    pub async fn wait_ready(&self) {
+        let mut receiver = self.ready.subscribe();
+        // The sender lives in `self`, so the channel cannot close while borrowed.
+        let _ = receiver.wait_for(|ready| *ready).await;
+    }
+
+    pub fn snapshot(&self) -> ReadinessSnapshot {
+        let subsystems = self.lock_subsystems().clone();
+        let failed = subsystems
+            .iter()
+            .filter(|status| !status.ok)
+            .map(|status| status.name.clone())
+            .collect();
+        ReadinessSnapshot {
+            ready: self.is_ready(),
+            subsystems,
+            failed,
+        }
+    }
+
+    /// Error recorded for a subsystem that failed to initialize, if any.
+    pub fn failure(&self, name: &str) -> Option<String> {
+        self.lock_subsystems()
+            .iter()
+            .find(|status| status.name == name && !status.ok)
+            .and_then(|status| status.error.clone())
+    }
+
+    fn record(&self, status: SubsystemStatus) {
+        let mut subsystems = self.lock_subsystems();
+        match subsystems.iter_mut().find(|item| item.name == status.name) {
+            Some(existing) => *existing = status,
+            None => subsystems.push(status),
+        }
+    }
+
+    fn lock_subsystems(&self) -> std::sync::MutexGuard<'_, Vec<SubsystemStatus>> {
+        self.subsystems
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn snapshot_lists_failed_subsystems() {
+        let readiness = StartupReadiness::new();
+        readiness.record_ok("model_library");
+        readiness.record_failure("version_manager:comfyui", "git not found");
+
+        let snapshot = readiness.snapshot();
+
+        assert!(!snapshot.ready);
+        assert_eq!(snapshot.subsystems.len(), 2);
+        assert_eq!(snapshot.failed, vec!["version_manager:comfyui".to_string()]);
+        assert_eq!(
+            snapshot.subsystems[1].error.as_deref(),
+            Some("git not found")
+        );
+        assert_eq!(
+            readiness.failure("version_manager:comfyui").as_deref(),
+            Some("git not found")
+        );
+        assert_eq!(readiness.failure("model_library"), None);
+    }
+
+    #[test]
+    fn recording_a_subsystem_twice_replaces_its_status() {
+        let readiness = StartupReadiness::new();
+        readiness.record_failure("model_index", "busy");
+        readiness.record_ok("model_index");
+
+        let snapshot = readiness.snapshot();
+        assert_eq!(snapshot.subsystems.len(), 1);
+        assert!(snapshot.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn wait_ready_resolves_after_mark_ready() {
+        let readiness = Arc::new(StartupReadiness::new());
+        let waiter = {
+            let readiness = readiness.clone();
+            tokio::spawn(async move { readiness.wait_ready().await })
+        };
+
+        readiness.mark_ready();
+
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(readiness.snapshot().ready);
+    }
+}
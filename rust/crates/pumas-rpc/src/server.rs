@@ -1,11 +1,13 @@
 //! HTTP server implementation using Axum.
 
 use crate::handlers::{
-    handle_health, handle_model_download_update_events, handle_model_library_update_events,
-    handle_openai_models, handle_openai_proxy, handle_rpc, handle_runtime_profile_update_events,
-    handle_serving_status_update_events, handle_status_telemetry_update_events,
+    handle_health, handle_library_events, handle_model_download_update_events,
+    handle_model_library_update_events, handle_openai_models, handle_openai_proxy, handle_rpc,
+    handle_runtime_profile_update_events, handle_serving_status_update_events,
+    handle_status_telemetry_update_events,
 };
 use crate::provider_clients::{LlamaCppRouterClient, OllamaClientFactory};
+use crate::readiness::StartupReadiness;
 use crate::shortcut::ShortcutManager;
 use axum::{
     extract::DefaultBodyLimit,
@@ -63,6 +65,8 @@ pub struct AppState {
     pub ollama_client_factory: OllamaClientFactory,
     /// Shared ONNX Runtime session manager for in-process embedding serving.
     pub onnx_session_manager: OnnxSessionManager<OnnxEmbeddingBackendKind>,
+    /// Startup subsystem outcomes and the full-initialization flag.
+    pub readiness: Arc<StartupReadiness>,
 }
 
 /// Owned handle for the running HTTP server task.
@@ -101,6 +105,8 @@ impl Drop for ServerHandle {
 /// Start the JSON-RPC HTTP server.
 ///
 /// Returns an owned handle that exposes the actual bound address and server task.
+/// `readiness` is marked ready once library startup background work (orphan
+/// adoption and interrupted-download recovery) has finished.
 #[allow(clippy::too_many_arguments)]
 pub async fn start_server(
     api: PumasApi,
@@ -111,15 +117,18 @@ pub async fn start_server(
     launcher_root: PathBuf,
     host: &str,
     port: u16,
+    readiness: Arc<StartupReadiness>,
 ) -> anyhow::Result<ServerHandle> {
     // Initialize shortcut manager
     let shortcut_manager = match ShortcutManager::new_async(&launcher_root).await {
         Ok(mgr) => {
             info!("Shortcut manager initialized");
+            readiness.record_ok("shortcut_manager");
             Some(mgr)
         }
         Err(e) => {
             warn!("Failed to initialize shortcut manager: {}", e);
+            readiness.record_failure("shortcut_manager", e);
             None
         }
     };
@@ -131,7 +140,7 @@ pub async fn start_server(
     let actual_addr = listener.local_addr()?;
     let gateway_base_url = RuntimeEndpointUrl::parse(format!("http://{actual_addr}/v1"))
         .map_err(|message| anyhow::anyhow!("invalid gateway base URL: {message}"))?;
-    let ollama_client_factory = build_ollama_client_factory()?;
+    let ollama_client_factory = build_ollama_client_factory(&api.http_settings())?;
     let onnx_session_manager = OnnxSessionManager::new(
         OnnxEmbeddingBackendKind::real(),
         ONNX_MAX_CONCURRENT_OPERATIONS,
@@ -151,7 +160,9 @@ pub async fn start_server(
         llama_cpp_router_client: LlamaCppRouterClient::new(provider_http_client),
         ollama_client_factory,
         onnx_session_manager,
+        readiness,
     });
+    spawn_startup_warmup(state.clone());
 
     // Configure CORS for local development and packaged renderer diagnostics.
     let cors = CorsLayer::new()
@@ -184,6 +195,7 @@ pub async fn start_server(
             "/events/status-telemetry-updates",
             get(handle_status_telemetry_update_events),
         )
+        .route("/events/library-events", get(handle_library_events))
         .route("/v1/models", get(handle_openai_models))
         .route("/v1/chat/completions", post(handle_openai_proxy))
         .route("/v1/completions", post(handle_openai_proxy))
@@ -212,6 +224,16 @@ pub async fn start_server(
     })
 }
 
+/// Wait for library startup work (orphan adoption, download recovery), then mark startup ready.
+fn spawn_startup_warmup(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        state.api.wait_for_startup_tasks().await;
+        state.readiness.record_ok("library_startup_tasks");
+        state.readiness.mark_ready();
+        info!("Startup initialization complete");
+    });
+}
+
 fn build_gateway_http_client() -> anyhow::Result<reqwest::Client> {
     Ok(reqwest::Client::builder()
         .timeout(GATEWAY_PROXY_TIMEOUT)
@@ -225,8 +247,10 @@ fn build_provider_http_client() -> anyhow::Result<reqwest::Client> {
         .build()?)
 }
 
-fn build_ollama_client_factory() -> anyhow::Result<OllamaClientFactory> {
-    let http_clients = pumas_app_manager::OllamaHttpClients::new()
+fn build_ollama_client_factory(
+    http: &pumas_library::network::HttpSettings,
+) -> anyhow::Result<OllamaClientFactory> {
+    let http_clients = pumas_app_manager::OllamaHttpClients::with_http_settings(http)
         .map_err(|err| anyhow::anyhow!("failed to build Ollama HTTP clients: {err}"))?;
     Ok(OllamaClientFactory::new(http_clients))
 }
@@ -300,6 +324,7 @@ mod tests {
             launcher_root,
             "127.0.0.1",
             0,
+            Arc::new(StartupReadiness::new()),
         )
         .await;
         let server = match result {
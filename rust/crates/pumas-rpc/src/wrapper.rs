@@ -96,9 +96,14 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
         | "unserve_model"
         | "get_launcher_version"
         | "check_launcher_updates"
+        | "preview_launcher_update"
         | "apply_launcher_update"
+        | "stage_launcher_update"
+        | "build_launcher_update"
         | "restart_launcher"
         | "get_network_status"
+        | "get_health_summary"
+        | "generate_diagnostic_bundle"
         | "get_library_status"
         | "get_link_health"
         | "import_model"
@@ -108,6 +113,7 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
         | "cancel_model_download"
         | "pause_model_download"
         | "resume_model_download"
+        | "reorder_model_download"
         | "resume_partial_download"
         | "list_model_downloads"
         | "search_hf_models"
@@ -121,6 +127,9 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
         | "detect_sharded_sets"
         | "validate_file_type"
         | "refresh_model_index"
+        | "start_rebuild_model_index"
+        | "get_rebuild_progress"
+        | "cancel_rebuild_model_index"
         | "refresh_model_mappings"
         | "get_file_link_count"
         | "check_files_writable"
@@ -158,6 +167,7 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
         | "get_inference_settings"
         | "update_inference_settings"
         | "update_model_notes"
+        | "update_model_tags"
         | "list_models_needing_review"
         | "submit_model_review"
         | "reset_model_review"
@@ -179,6 +189,7 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
         | "update_custom_node"
         | "remove_custom_node"
         | "toggle_patch"
+        | "restore_patch_backup"
         | "toggle_menu"
         | "toggle_desktop"
         | "set_default_version" => {
@@ -198,6 +209,13 @@ pub fn wrap_response(method: &str, result: Value) -> Value {
             })
         }
 
+        "get_patch_state" => {
+            json!({
+                "success": true,
+                "state": result
+            })
+        }
+
         "get_default_version" => {
             json!({
                 "success": true,
@@ -187,6 +187,14 @@ impl Drop for RpcServerHandle {
 
 /// Start the RPC binary and wait until `/health` is ready.
 async fn start_rpc_server(launcher_root: &std::path::Path) -> Result<RpcServerHandle, String> {
+    start_rpc_server_with_env(launcher_root, &[]).await
+}
+
+/// Start the RPC binary with extra environment variables.
+async fn start_rpc_server_with_env(
+    launcher_root: &std::path::Path,
+    envs: &[(&str, &std::path::Path)],
+) -> Result<RpcServerHandle, String> {
     let binary = if let Ok(path) = std::env::var("CARGO_BIN_EXE_pumas-rpc") {
         PathBuf::from(path)
     } else {
@@ -217,6 +225,7 @@ async fn start_rpc_server(launcher_root: &std::path::Path) -> Result<RpcServerHa
         .arg("0")
         .arg("--launcher-root")
         .arg(launcher_root)
+        .envs(envs.iter().copied())
         .stdout(Stdio::piped())
         .stderr(Stdio::null())
         .spawn()
@@ -993,6 +1002,49 @@ mod tests {
         server.stop().await;
     }
 
+    #[tokio::test]
+    async fn test_library_event_stream_reports_model_deletion() {
+        if !can_bind_local_tcp_for_tests() {
+            return;
+        }
+        let env = create_test_env();
+        let model_id = "llm/llama/sse-library-event";
+        create_indexable_test_model(env.path(), model_id, "SSE Library Event");
+        let server = start_rpc_server(env.path()).await.unwrap();
+        let port = server.port;
+        let listed = rpc_call(port, "get_models", json!({})).await.unwrap();
+        assert!(listed
+            .get("models")
+            .and_then(|value| value.as_object())
+            .is_some_and(|models| models.contains_key(model_id)));
+
+        let client = reqwest::Client::new();
+        let response = client
+            .get(format!("http://127.0.0.1:{}/events/library-events", port))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let mut stream = response.bytes_stream();
+
+        let deleted = rpc_call(
+            port,
+            "delete_model_with_cascade",
+            json!({"model_id": model_id}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(deleted.get("success").and_then(|v| v.as_bool()), Some(true));
+
+        let body = read_stream_until_contains(&mut stream, model_id, Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(body.contains("event: library-event"));
+        assert!(body.contains("\"type\":\"model_deleted\""));
+
+        server.stop().await;
+    }
+
     #[tokio::test]
     async fn test_migration_report_prune_rejects_negative_keep_latest() {
         if !can_bind_local_tcp_for_tests() {
@@ -1094,6 +1146,126 @@ mod tests {
         server.stop().await;
     }
 
+    /// Write a valid safetensors file whose tensor data is a sparse hole, so
+    /// it is cheap on disk but slow to hash.
+    fn create_large_sparse_safetensors(path: &std::path::Path, data_len: u64) {
+        let header = format!(
+            r#"{{"weight":{{"dtype":"U8","shape":[{data_len}],"data_offsets":[0,{data_len}]}}}}"#
+        );
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        std::fs::write(path, &bytes).unwrap();
+        let file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.set_len(bytes.len() as u64 + data_len).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_quick_rpc_stays_responsive_during_long_hash() {
+        if !can_bind_local_tcp_for_tests() {
+            return;
+        }
+        let env = create_test_env();
+        let gate = env.path().join("hash-gate");
+        std::fs::create_dir_all(&gate).unwrap();
+        let server = start_rpc_server_with_env(env.path(), &[("PUMAS_TEST_HASH_GATE", &gate)])
+            .await
+            .unwrap();
+        let port = server.port;
+
+        // One import per RPC worker thread: if hashing ran on the async
+        // workers, every worker would be held at the gate and the quick
+        // call could not be answered.
+        const IMPORTS: usize = 4;
+        let mut imports = Vec::new();
+        for index in 0..IMPORTS {
+            let source = env.path().join(format!("gated-{index}.safetensors"));
+            create_large_sparse_safetensors(&source, 1024);
+            imports.push(tokio::spawn(async move {
+                rpc_call(
+                    port,
+                    "import_model",
+                    json!({
+                        "local_path": source.to_string_lossy(),
+                        "family": "test",
+                        "official_name": format!("Gated {index}"),
+                        "model_type": "llm",
+                        "security_acknowledged": true,
+                        "by_reference": true
+                    }),
+                )
+                .await
+            }));
+        }
+
+        let hashing_started = || {
+            std::fs::read_dir(&gate)
+                .unwrap()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_name().to_string_lossy().starts_with("hashing-"))
+                .count()
+        };
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while hashing_started() < IMPORTS {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("imports never reached the hash gate");
+
+        let response = rpc_call(port, "health_check", json!({})).await.unwrap();
+        assert_eq!(response.get("status").and_then(|v| v.as_str()), Some("ok"));
+        assert!(
+            imports.iter().all(|import| !import.is_finished()),
+            "an import finished while its hash was held at the gate"
+        );
+
+        std::fs::write(gate.join("release"), b"").unwrap();
+        for import in imports {
+            let response = import.await.unwrap().unwrap();
+            assert_eq!(response["success"], json!(true), "{response}");
+        }
+        server.stop().await;
+    }
+
+    #[tokio::test]
+    async fn test_import_model_preview_reports_destination_without_importing() {
+        if !can_bind_local_tcp_for_tests() {
+            return;
+        }
+        let env = create_test_env();
+        let server = start_rpc_server(env.path()).await.unwrap();
+        let source = env.path().join("preview.safetensors");
+        create_large_sparse_safetensors(&source, 1024);
+
+        let response = rpc_call(
+            server.port,
+            "import_model",
+            json!({
+                "local_path": source.to_string_lossy(),
+                "family": "test",
+                "official_name": "Preview Model",
+                "model_type": "llm",
+                "preview": true
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response["success"], json!(true));
+        assert_eq!(response["preview"], json!(true));
+        let report = &response["report"];
+        assert_eq!(report["valid"], json!(true));
+        assert_eq!(report["already_exists"], json!(false));
+        assert_eq!(report["model_type"], json!("llm"));
+        assert_eq!(report["security_tier"], json!("safe"));
+        let destination = report["destination"].as_str().unwrap();
+        assert!(destination.ends_with("preview_model"), "{destination}");
+        assert!(!std::path::Path::new(destination).exists());
+        assert!(!std::path::Path::new(destination).parent().unwrap().exists());
+
+        server.stop().await;
+    }
+
     // Note: These tests require the RPC server to be running.
     // In CI, you would start the server as part of the test setup.
     // For local development, run: cargo run --release -- --port <port> --launcher_root <path>
@@ -244,6 +244,7 @@ pub struct ElixirModelImportSpec {
     pub subtype: Option<String>,
     pub tags: Option<Vec<String>>,
     pub security_acknowledged: Option<bool>,
+    pub by_reference: Option<bool>,
 }
 
 /// Model import result as an Elixir struct.
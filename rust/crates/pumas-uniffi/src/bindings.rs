@@ -135,6 +135,7 @@ impl From<pumas_library::PumasError> for FfiError {
                 message: format!("Model not found: {}", model_id),
             },
             PumasError::ImportFailed { message } => FfiError::Model { message },
+            PumasError::ImportCancelled | PumasError::IndexRebuildCancelled => FfiError::Cancelled,
             PumasError::DownloadFailed { url, message } => FfiError::Download {
                 message: format!("{}: {}", url, message),
             },
@@ -505,6 +506,12 @@ mod tests {
             filename: Some("model.safetensors".to_string()),
             filenames: None,
             pipeline_tag: Some("text-to-image".to_string()),
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: None,
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         let request = ffi_request.into_core().unwrap();
@@ -520,6 +527,11 @@ mod tests {
         assert!(request.download_url.is_none());
         assert!(request.model_card_json.is_none());
         assert!(request.license_status.is_none());
+        assert!(!request.skip_hash_verification);
+        assert_eq!(
+            request.priority,
+            pumas_library::model_library::DownloadPriority::Normal
+        );
     }
 
     #[test]
@@ -533,6 +545,12 @@ mod tests {
             filename: None,
             filenames: None,
             pipeline_tag: None,
+            skip_hash_verification: false,
+            max_concurrent_files: None,
+            rate_limit: None,
+            priority: None,
+            include_patterns: None,
+            exclude_patterns: None,
         };
 
         let error = ffi_request.into_core().unwrap_err();
@@ -550,6 +568,8 @@ mod tests {
             subtype: None,
             tags: None,
             security_acknowledged: None,
+            by_reference: None,
+            dedup: None,
         };
 
         let error = spec.into_core().unwrap_err();
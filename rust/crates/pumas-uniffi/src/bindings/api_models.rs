@@ -1,14 +1,25 @@
 use super::{
-    canonicalize_existing_local_path_string, FfiDeleteModelResponse, FfiError,
-    FfiInferenceParamSchema, FfiModelImportResult, FfiModelImportSpec, FfiModelRecord, FfiPumasApi,
-    FfiReclassifyResult, FfiSearchResult,
+    canonicalize_existing_local_path_string, FfiBatchImportProgress, FfiDeleteModelResponse,
+    FfiError, FfiInferenceParamSchema, FfiModelImportResult, FfiModelImportSpec, FfiModelRecord,
+    FfiPumasApi, FfiReclassifyResult, FfiSearchResult,
 };
+use pumas_library::cancel::CancellationToken;
 
 #[uniffi::export(async_runtime = "tokio")]
 impl FfiPumasApi {
-    /// List all models in the library.
-    pub async fn list_models(&self) -> Result<Vec<FfiModelRecord>, FfiError> {
-        let models = self.primary().list_models().await.map_err(FfiError::from)?;
+    /// List models in the library.
+    ///
+    /// Models that are not ready yet are left out unless `include_incomplete`
+    /// is set.
+    pub async fn list_models(
+        &self,
+        include_incomplete: bool,
+    ) -> Result<Vec<FfiModelRecord>, FfiError> {
+        let models = self
+            .primary()
+            .list_models(include_incomplete)
+            .await
+            .map_err(FfiError::from)?;
         Ok(models.into_iter().map(FfiModelRecord::from).collect())
     }
 
@@ -28,10 +39,11 @@ impl FfiPumasApi {
         query: String,
         limit: u64,
         offset: u64,
+        include_incomplete: bool,
     ) -> Result<FfiSearchResult, FfiError> {
         let result = self
             .primary()
-            .search_models(&query, limit as usize, offset as usize)
+            .search_models(&query, limit as usize, offset as usize, include_incomplete)
             .await
             .map_err(FfiError::from)?;
         Ok(FfiSearchResult::from(result))
@@ -67,45 +79,45 @@ impl FfiPumasApi {
         &self,
         specs: Vec<FfiModelImportSpec>,
     ) -> Vec<FfiModelImportResult> {
-        let mut core_specs = Vec::with_capacity(specs.len());
-        for spec in specs {
-            match spec.into_core() {
-                Ok(mut core_spec) => {
-                    match canonicalize_existing_local_path_string(core_spec.path, "path").await {
-                        Ok(path) => {
-                            core_spec.path = path;
-                            core_specs.push(core_spec);
-                        }
-                        Err(err) => {
-                            return vec![FfiModelImportResult {
-                                path: String::new(),
-                                success: false,
-                                model_path: None,
-                                error: Some(err.to_string()),
-                                security_tier: None,
-                            }];
-                        }
-                    }
-                }
-                Err(err) => {
-                    return vec![FfiModelImportResult {
-                        path: String::new(),
-                        success: false,
-                        model_path: None,
-                        error: Some(err.to_string()),
-                        security_tier: None,
-                    }];
-                }
+        let core_specs = match batch_specs_into_core(specs).await {
+            Ok(core_specs) => core_specs,
+            Err(err) => {
+                return vec![FfiModelImportResult {
+                    path: String::new(),
+                    success: false,
+                    model_path: None,
+                    error: Some(err.to_string()),
+                    security_tier: None,
+                }];
             }
-        }
+        };
         self.primary()
-            .import_models_batch(core_specs)
+            .import_models_batch(core_specs, &CancellationToken::new())
             .await
             .into_iter()
             .map(FfiModelImportResult::from)
             .collect()
     }
 
+    /// Start a batch import in the background and return its batch ID.
+    ///
+    /// Poll [`get_batch_import_progress`](Self::get_batch_import_progress)
+    /// with the ID for aggregate progress and per-item results.
+    pub async fn start_import_models_batch(
+        &self,
+        specs: Vec<FfiModelImportSpec>,
+    ) -> Result<String, FfiError> {
+        let core_specs = batch_specs_into_core(specs).await?;
+        Ok(self.primary().start_import_models_batch(core_specs))
+    }
+
+    /// Get aggregate progress for a batch started with `start_import_models_batch`.
+    pub fn get_batch_import_progress(&self, batch_id: String) -> Option<FfiBatchImportProgress> {
+        self.primary()
+            .get_batch_import_progress(&batch_id)
+            .map(FfiBatchImportProgress::from)
+    }
+
     /// Rebuild the full-text search index for all models.
     pub async fn rebuild_model_index(&self) -> Result<u64, FfiError> {
         let count = self
@@ -116,6 +128,14 @@ impl FfiPumasApi {
         Ok(count as u64)
     }
 
+    /// Reload one model's metadata and re-upsert its index row.
+    pub async fn refresh_model(&self, model_id: String) -> Result<(), FfiError> {
+        self.primary()
+            .refresh_model(&model_id)
+            .await
+            .map_err(FfiError::from)
+    }
+
     /// Re-detect a model's type and move it to the correct directory if misclassified.
     ///
     /// Returns the new model_id if the model was reclassified, None if unchanged.
@@ -172,3 +192,16 @@ impl FfiPumasApi {
             .map_err(FfiError::from)
     }
 }
+
+/// Validate batch specs and canonicalize their source paths.
+async fn batch_specs_into_core(
+    specs: Vec<FfiModelImportSpec>,
+) -> Result<Vec<pumas_library::models::ModelImportSpec>, FfiError> {
+    let mut core_specs = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let mut core_spec = spec.into_core()?;
+        core_spec.path = canonicalize_existing_local_path_string(core_spec.path, "path").await?;
+        core_specs.push(core_spec);
+    }
+    Ok(core_specs)
+}
@@ -93,6 +93,42 @@ impl From<pumas_library::models::DownloadStatus> for FfiDownloadStatus {
     }
 }
 
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiDownloadPriority {
+    High,
+    Normal,
+    Low,
+}
+
+impl From<FfiDownloadPriority> for pumas_library::model_library::DownloadPriority {
+    fn from(p: FfiDownloadPriority) -> Self {
+        match p {
+            FfiDownloadPriority::High => Self::High,
+            FfiDownloadPriority::Normal => Self::Normal,
+            FfiDownloadPriority::Low => Self::Low,
+        }
+    }
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiDedupStrategy {
+    Copy,
+    Hardlink,
+    Reflink,
+    Auto,
+}
+
+impl From<FfiDedupStrategy> for pumas_library::models::DedupStrategy {
+    fn from(s: FfiDedupStrategy) -> Self {
+        match s {
+            FfiDedupStrategy::Copy => Self::Copy,
+            FfiDedupStrategy::Hardlink => Self::Hardlink,
+            FfiDedupStrategy::Reflink => Self::Reflink,
+            FfiDedupStrategy::Auto => Self::Auto,
+        }
+    }
+}
+
 #[derive(uniffi::Record)]
 pub struct FfiModelImportSpec {
     pub path: String,
@@ -103,6 +139,8 @@ pub struct FfiModelImportSpec {
     pub subtype: Option<String>,
     pub tags: Option<Vec<String>>,
     pub security_acknowledged: Option<bool>,
+    pub by_reference: Option<bool>,
+    pub dedup: Option<FfiDedupStrategy>,
 }
 
 impl FfiModelImportSpec {
@@ -116,6 +154,8 @@ impl FfiModelImportSpec {
             subtype: self.subtype,
             tags: self.tags,
             security_acknowledged: self.security_acknowledged,
+            by_reference: self.by_reference,
+            dedup: self.dedup.map(Into::into),
         })
     }
 }
@@ -141,6 +181,63 @@ impl From<pumas_library::models::ModelImportResult> for FfiModelImportResult {
     }
 }
 
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiImportStage {
+    Copying,
+    Hashing,
+    WritingMetadata,
+    Indexing,
+    Syncing,
+    Complete,
+}
+
+impl From<pumas_library::models::ImportStage> for FfiImportStage {
+    fn from(s: pumas_library::models::ImportStage) -> Self {
+        use pumas_library::models::ImportStage;
+        match s {
+            ImportStage::Copying => FfiImportStage::Copying,
+            ImportStage::Hashing => FfiImportStage::Hashing,
+            ImportStage::WritingMetadata => FfiImportStage::WritingMetadata,
+            ImportStage::Indexing => FfiImportStage::Indexing,
+            ImportStage::Syncing => FfiImportStage::Syncing,
+            ImportStage::Complete => FfiImportStage::Complete,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct FfiBatchImportProgress {
+    pub total: u64,
+    pub completed: u64,
+    pub current: Option<String>,
+    pub current_index: Option<u64>,
+    pub stage: FfiImportStage,
+    pub succeeded: u64,
+    pub failed: u64,
+    pub results: Vec<FfiModelImportResult>,
+    pub progress: f32,
+}
+
+impl From<pumas_library::BatchImportProgress> for FfiBatchImportProgress {
+    fn from(p: pumas_library::BatchImportProgress) -> Self {
+        Self {
+            total: p.total as u64,
+            completed: p.completed as u64,
+            current: p.current,
+            current_index: p.current_index.map(|index| index as u64),
+            stage: FfiImportStage::from(p.stage),
+            succeeded: p.succeeded as u64,
+            failed: p.failed as u64,
+            results: p
+                .results
+                .into_iter()
+                .map(FfiModelImportResult::from)
+                .collect(),
+            progress: p.progress,
+        }
+    }
+}
+
 #[derive(uniffi::Record)]
 pub struct FfiModelDownloadProgress {
     pub download_id: String,
@@ -154,6 +251,9 @@ pub struct FfiModelDownloadProgress {
     pub total_bytes: Option<u64>,
     pub speed: Option<f64>,
     pub eta_seconds: Option<f64>,
+    pub queue_position: Option<u32>,
+    pub stalled: bool,
+    pub skipped_files: Vec<String>,
     pub error: Option<String>,
 }
 
@@ -171,6 +271,9 @@ impl From<pumas_library::models::ModelDownloadProgress> for FfiModelDownloadProg
             total_bytes: p.total_bytes,
             speed: p.speed,
             eta_seconds: p.eta_seconds,
+            queue_position: p.queue_position,
+            stalled: p.stalled,
+            skipped_files: p.skipped_files,
             error: p.error,
         }
     }
@@ -420,6 +523,12 @@ pub struct FfiDownloadRequest {
     pub filename: Option<String>,
     pub filenames: Option<Vec<String>>,
     pub pipeline_tag: Option<String>,
+    pub skip_hash_verification: bool,
+    pub max_concurrent_files: Option<u32>,
+    pub rate_limit: Option<u64>,
+    pub priority: Option<FfiDownloadPriority>,
+    pub include_patterns: Option<Vec<String>>,
+    pub exclude_patterns: Option<Vec<String>>,
 }
 
 impl FfiDownloadRequest {
@@ -439,6 +548,12 @@ impl FfiDownloadRequest {
             download_url: None,
             model_card_json: None,
             license_status: None,
+            skip_hash_verification: self.skip_hash_verification,
+            max_concurrent_files: self.max_concurrent_files,
+            rate_limit: self.rate_limit,
+            priority: self.priority.map(Into::into).unwrap_or_default(),
+            include_patterns: self.include_patterns,
+            exclude_patterns: self.exclude_patterns,
         })
     }
 }
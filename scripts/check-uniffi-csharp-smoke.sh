@@ -69,7 +69,7 @@ require_generated_text 'namespace uniffi.pumas_uniffi;'
 require_generated_text 'public class FfiPumasApi'
 require_generated_text 'public record FfiApiConfig'
 require_generated_text 'public record FfiDownloadRequest'
-require_generated_text 'Task<List<FfiModelRecord>> ListModels()'
+require_generated_text 'Task<List<FfiModelRecord>> ListModels(bool @includeIncomplete)'
 require_generated_text 'Task<String> StartHfDownload(FfiDownloadRequest @request)'
 require_generated_text 'public static String Version()'
 
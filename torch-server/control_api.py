@@ -65,6 +65,7 @@ class ConfigureRequest(BaseModel):
     api_port: Optional[int] = None
     max_loaded_models: Optional[int] = None
     lan_access: Optional[bool] = None
+    auto_evict: Optional[bool] = None
 
     @field_validator("api_port")
     @classmethod
@@ -102,17 +103,17 @@ async def list_slots(request: Request):
 
 @router.post("/load")
 async def load_model(req: LoadModelRequest, request: Request):
-    """Load a model into a new slot."""
+    """Load a model into a new slot, evicting the LRU slot when full."""
     manager = request.app.state.model_manager
 
     try:
-        slot = await manager.load(
+        result = await manager.load(
             model_path=req.model_path,
             model_name=req.model_name,
             device_str=req.device,
             model_type=req.model_type,
         )
-        return {"success": True, "slot": slot.to_dict()}
+        return {"success": True, "slot": result.slot.to_dict(), "evicted": result.evicted}
     except RuntimeError as e:
         raise HTTPException(status_code=409, detail=str(e))
     except ValueError as e:
@@ -208,6 +209,10 @@ async def configure(req: ConfigureRequest, request: Request):
             raise HTTPException(status_code=409, detail=str(e))
         next_config["max_loaded_models"] = req.max_loaded_models
 
+    if req.auto_evict is not None:
+        manager.set_auto_evict(req.auto_evict)
+        next_config["auto_evict"] = req.auto_evict
+
     if req.lan_access is not None:
         next_config["lan_access"] = req.lan_access
         if req.lan_access:
@@ -2,6 +2,7 @@
 
 import asyncio
 import logging
+import time
 import uuid
 from dataclasses import dataclass, field
 from enum import Enum
@@ -43,8 +44,12 @@ class ModelSlot:
     gpu_memory_bytes: Optional[int] = None
     ram_memory_bytes: Optional[int] = None
     model_type: Optional[str] = None
+    last_used_at: float = field(default_factory=time.time)
     _loaded: Optional[LoadedModel] = field(default=None, repr=False)
 
+    def touch(self) -> None:
+        self.last_used_at = time.time()
+
     def to_dict(self) -> dict:
         return {
             "slot_id": self.slot_id,
@@ -55,15 +60,32 @@ class ModelSlot:
             "gpu_memory_bytes": self.gpu_memory_bytes,
             "ram_memory_bytes": self.ram_memory_bytes,
             "model_type": self.model_type,
+            "last_used_at": self.last_used_at,
         }
 
 
+@dataclass
+class LoadResult:
+    slot: ModelSlot
+    evicted: Optional[dict] = None
+
+
 class ModelManager:
-    """Manages model loading/unloading with slot-based multi-model support."""
+    """Manages model loading/unloading with slot-based multi-model support.
 
-    def __init__(self, device_manager: DeviceManager, max_loaded_models: int = 4):
+    When every slot is in use and ``auto_evict`` is enabled, loading a new model
+    unloads the least-recently-used ready slot to make room.
+    """
+
+    def __init__(
+        self,
+        device_manager: DeviceManager,
+        max_loaded_models: int = 4,
+        auto_evict: bool = True,
+    ):
         self.device_manager = device_manager
         self.max_loaded_models = max_loaded_models
+        self.auto_evict = auto_evict
         self.slots: dict[str, ModelSlot] = {}
         self._device_locks: dict[str, asyncio.Lock] = {}
         self._registry_lock = asyncio.Lock()
@@ -90,24 +112,34 @@ class ModelManager:
                 )
             self.max_loaded_models = max_loaded_models
 
+    def set_auto_evict(self, enabled: bool) -> None:
+        """Enable or disable LRU eviction when all slots are in use."""
+        self.auto_evict = enabled
+
     async def load(
         self,
         model_path: str,
         model_name: str,
         device_str: str = "auto",
         model_type: Optional[str] = None,
-    ) -> ModelSlot:
-        """Load a model into a new slot."""
+    ) -> LoadResult:
+        """Load a model into a new slot, evicting the LRU slot if required."""
         resolved_device = self.device_manager.resolve_device(device_str)
         device_label = str(resolved_device)
+        evicted: Optional[ModelSlot] = None
+        evicted_info: Optional[dict] = None
 
         async with self._registry_lock:
             active_count = self._active_slot_count()
             if active_count >= self.max_loaded_models:
-                raise RuntimeError(
-                    f"Maximum loaded models ({self.max_loaded_models}) reached. "
-                    "Unload a model first."
-                )
+                evicted = self._least_recently_used_slot() if self.auto_evict else None
+                if evicted is None:
+                    raise RuntimeError(
+                        f"Maximum loaded models ({self.max_loaded_models}) reached. "
+                        "Unload a model first."
+                    )
+                evicted_info = evicted.to_dict()
+                evicted.state = SlotState.UNLOADING
 
             slot_id = str(uuid.uuid4())[:8]
             slot = ModelSlot(
@@ -122,6 +154,15 @@ class ModelManager:
             lock = self._get_device_lock(device_label)
 
         try:
+            if evicted is not None:
+                logger.info(
+                    "Evicting least-recently-used model %s (slot %s) to load %s",
+                    evicted.model_name,
+                    evicted.slot_id,
+                    model_name,
+                )
+                await self._release_slot(evicted)
+
             async with lock:
                 loaded = await self._load_model(model_path, resolved_device, model_type)
 
@@ -129,6 +170,7 @@ class ModelManager:
                 slot._loaded = loaded
                 slot.model_type = loaded.model_type or model_type
                 slot.state = SlotState.READY
+                slot.touch()
 
                 # Update memory usage
                 if resolved_device.type == "cuda":
@@ -148,7 +190,7 @@ class ModelManager:
             logger.exception("Unexpected failure while loading model %s", model_name)
             raise
 
-        return slot
+        return LoadResult(slot=slot, evicted=evicted_info)
 
     def _load_sync(
         self, model_path: str, device: torch.device, model_type: Optional[str]
@@ -182,6 +224,11 @@ class ModelManager:
                 raise RuntimeError(f"Cannot unload loading slot: {slot_id}")
             slot.state = SlotState.UNLOADING
 
+        await self._release_slot(slot)
+
+    async def _release_slot(self, slot: ModelSlot) -> None:
+        """Free a slot already marked UNLOADING and remove it from the registry."""
+        slot_id = slot.slot_id
         try:
             if slot._loaded is not None:
                 device = slot._loaded.device
@@ -211,6 +258,7 @@ class ModelManager:
         """Get a loaded model by name for inference."""
         for slot in self.slots.values():
             if slot.model_name == model_name and slot.state == SlotState.READY and slot._loaded:
+                slot.touch()
                 return slot._loaded
         return None
 
@@ -223,6 +271,12 @@ class ModelManager:
             1 for slot in self.slots.values() if slot.state in (SlotState.READY, SlotState.LOADING)
         )
 
+    def _least_recently_used_slot(self) -> Optional[ModelSlot]:
+        ready = [slot for slot in self.slots.values() if slot.state == SlotState.READY]
+        if not ready:
+            return None
+        return min(ready, key=lambda slot: slot.last_used_at)
+
     async def _mark_slot_error(self, slot_id: str) -> None:
         async with self._registry_lock:
             slot = self.slots.get(slot_id)
@@ -31,7 +31,12 @@ from validation import (
 logger = logging.getLogger(__name__)
 
 
-def create_app(host: str = "127.0.0.1", port: int = 8400, max_models: int = 4) -> FastAPI:
+def create_app(
+    host: str = "127.0.0.1",
+    port: int = 8400,
+    max_models: int = 4,
+    auto_evict: bool = True,
+) -> FastAPI:
     """Create and configure the FastAPI application."""
     lan_access = not is_loopback_host(host)
     host = validate_bind_host(host, lan_access=lan_access)
@@ -43,6 +48,7 @@ def create_app(host: str = "127.0.0.1", port: int = 8400, max_models: int = 4) -
     model_manager = ModelManager(
         device_manager=device_manager,
         max_loaded_models=max_models,
+        auto_evict=auto_evict,
     )
     app = FastAPI(title="Torch Inference Server", version="0.1.0")
 
@@ -54,6 +60,7 @@ def create_app(host: str = "127.0.0.1", port: int = 8400, max_models: int = 4) -
         "api_port": port,
         "max_loaded_models": max_models,
         "lan_access": lan_access,
+        "auto_evict": auto_evict,
     }
 
     if lan_access:
@@ -95,13 +102,24 @@ def parse_args() -> argparse.Namespace:
     parser.add_argument("--host", default="127.0.0.1", help="Bind address")
     parser.add_argument("--port", type=int, default=8400, help="Listen port")
     parser.add_argument("--max-models", type=int, default=4, help="Max concurrent loaded models")
+    parser.add_argument(
+        "--no-auto-evict",
+        dest="auto_evict",
+        action="store_false",
+        help="Reject loads when all slots are full instead of evicting the LRU model",
+    )
     return parser.parse_args()
 
 
 if __name__ == "__main__":
     args = parse_args()
     try:
-        application = create_app(host=args.host, port=args.port, max_models=args.max_models)
+        application = create_app(
+            host=args.host,
+            port=args.port,
+            max_models=args.max_models,
+            auto_evict=args.auto_evict,
+        )
     except ValueError as exc:
         print(f"Invalid Torch server configuration: {exc}", file=sys.stderr)
         sys.exit(2)
@@ -68,7 +68,11 @@ class _TestModelManager(ModelManager):
 
 class ModelManagerConcurrencyTests(unittest.IsolatedAsyncioTestCase):
     async def test_concurrent_loads_reserve_slots_before_expensive_load(self):
-        manager = _TestModelManager(device_manager=_FakeDeviceManager(), max_loaded_models=1)
+        manager = _TestModelManager(
+            device_manager=_FakeDeviceManager(),
+            max_loaded_models=1,
+            auto_evict=False,
+        )
 
         results = await asyncio.gather(
             manager.load("/tmp/model-a", "model-a"),
@@ -94,5 +98,37 @@ class ModelManagerConcurrencyTests(unittest.IsolatedAsyncioTestCase):
         self.assertEqual(manager.max_loaded_models, 2)
 
 
+class ModelManagerEvictionTests(unittest.IsolatedAsyncioTestCase):
+    async def test_full_manager_evicts_least_recently_used_ready_slot(self):
+        manager = _TestModelManager(device_manager=_FakeDeviceManager(), max_loaded_models=2)
+        first = (await manager.load("/tmp/model-a", "model-a")).slot
+        second = (await manager.load("/tmp/model-b", "model-b")).slot
+        first.last_used_at = 1.0
+        second.last_used_at = 2.0
+        manager.get_model_for_inference("model-a")
+
+        result = await manager.load("/tmp/model-c", "model-c")
+
+        self.assertIsNotNone(result.evicted)
+        self.assertEqual(result.evicted["slot_id"], second.slot_id)
+        self.assertEqual(
+            sorted(slot.model_name for slot in manager.slots.values()),
+            ["model-a", "model-c"],
+        )
+
+    async def test_disabled_auto_evict_rejects_load_when_full(self):
+        manager = _TestModelManager(
+            device_manager=_FakeDeviceManager(),
+            max_loaded_models=1,
+            auto_evict=False,
+        )
+        await manager.load("/tmp/model-a", "model-a")
+
+        with self.assertRaises(RuntimeError):
+            await manager.load("/tmp/model-b", "model-b")
+
+        self.assertEqual([slot.model_name for slot in manager.slots.values()], ["model-a"])
+
+
 if __name__ == "__main__":
     unittest.main()